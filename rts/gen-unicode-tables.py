@@ -0,0 +1,423 @@
+#!/usr/bin/env python3
+"""
+Generates the Unicode data tables of the RTS from the Unicode Character Database (UCD).
+
+Usage:
+
+    ./gen-unicode-tables.py <UCD directory>
+
+The UCD directory needs to contain the following files of the Unicode version given in
+`UNICODE_VERSION` below, as published at https://www.unicode.org/Public/<version>/ucd/:
+
+  - UnicodeData.txt
+  - CompositionExclusions.txt
+  - PropertyValueAliases.txt
+  - Scripts.txt
+  - DerivedCoreProperties.txt
+  - extracted/DerivedNumericValues.txt
+  - auxiliary/GraphemeBreakProperty.txt
+  - auxiliary/WordBreakProperty.txt
+  - auxiliary/GraphemeBreakTest.txt
+  - NormalizationTest.txt
+  - emoji/emoji-data.txt (published at https://www.unicode.org/Public/<version>/ucd/emoji/)
+
+The generated files are checked in, so this only needs to be run when updating the Unicode
+version. The test data in motoko-rts-tests is copied from NormalizationTest.txt and
+GraphemeBreakTest.txt.
+"""
+
+import os
+import sys
+
+from fractions import Fraction
+
+UNICODE_VERSION = (14, 0, 0)
+
+RTS_DIR = os.path.dirname(os.path.abspath(__file__))
+NORMALIZATION_TABLES = os.path.join(RTS_DIR, "motoko-rts/src/normalization/tables.rs")
+CHAR_TABLES = os.path.join(RTS_DIR, "motoko-rts/src/char/tables.rs")
+NORMALIZATION_TEST_DATA = os.path.join(RTS_DIR, "motoko-rts-tests/data/normalization-test.txt")
+GRAPHEME_BREAK_TEST_DATA = os.path.join(RTS_DIR, "motoko-rts-tests/data/grapheme-break-test.txt")
+
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+HANGUL_VOWELS = range(0x1161, 0x1176)
+HANGUL_TRAILING = range(0x11A8, 0x11C3)
+
+
+def read_unicode_data(ucd_dir):
+    """Returns a dict mapping code points to the fields of their UnicodeData.txt entries.
+    `<..., First>`/`<..., Last>` ranges are expanded."""
+    chars = {}
+    range_first = None
+    with open(os.path.join(ucd_dir, "UnicodeData.txt")) as f:
+        for line in f:
+            fields = line.rstrip("\n").split(";")
+            cp = int(fields[0], 16)
+            name = fields[1]
+            if name.endswith(", First>"):
+                range_first = cp
+                continue
+            if name.endswith(", Last>"):
+                for c in range(range_first, cp + 1):
+                    chars[c] = fields
+                range_first = None
+                continue
+            chars[cp] = fields
+    return chars
+
+
+def read_code_point_list(path):
+    cps = set()
+    with open(path) as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if line:
+                cps.add(int(line.split()[0], 16))
+    return cps
+
+
+def read_property_file(path, field=1):
+    """Reads a UCD property file with `<code point or range> ; <value>` lines into a dict mapping
+    code points to values. `field` selects the value for files with multiple value fields."""
+    values = {}
+    with open(path) as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            fields = [field.strip() for field in line.split(";")]
+            cps, value = fields[0], fields[field]
+            first, _, last = cps.partition("..")
+            for cp in range(int(first, 16), int(last or first, 16) + 1):
+                values[cp] = value
+    return values
+
+
+def read_property_set(path, value):
+    """Returns the set of code points with the binary property `value` in a UCD property file,
+    which can list several properties"""
+    cps = set()
+    with open(path) as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            fields = [field.strip() for field in line.split(";")]
+            if fields[1] != value:
+                continue
+            first, _, last = fields[0].partition("..")
+            cps.update(range(int(first, 16), int(last or first, 16) + 1))
+    return cps
+
+
+def read_script_codes(ucd_dir):
+    """Returns a dict mapping script names to their ISO 15924 codes"""
+    codes = {}
+    with open(os.path.join(ucd_dir, "PropertyValueAliases.txt")) as f:
+        for line in f:
+            fields = [field.strip() for field in line.split("#")[0].split(";")]
+            if fields[0] == "sc":
+                codes[fields[2]] = fields[1]
+    return codes
+
+
+def to_ranges(cps):
+    """Collapses a set of code points into a sorted list of inclusive ranges"""
+    ranges = []
+    for cp in sorted(cps):
+        if ranges and ranges[-1][1] == cp - 1:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp])
+    return ranges
+
+
+def write_rust_array(out, doc, name, ty, items, per_line):
+    for line in doc.strip().split("\n"):
+        out.write("/// %s\n" % line if line else "///\n")
+    out.write("pub(super) static %s: [%s; %d] = [\n" % (name, ty, len(items)))
+    for i in range(0, len(items), per_line):
+        out.write("    " + " ".join(item + "," for item in items[i:i + per_line]) + "\n")
+    out.write("];\n\n")
+
+
+def to_partition(values, default):
+    """Collapses a dict mapping code points to values into a list of `(first, value)` pairs, each
+    starting a run of code points with the same value that lasts until the next pair. Code points
+    without a value get `default`."""
+    runs = []
+    for cp in range(0x110000):
+        value = values.get(cp, default)
+        if not runs or runs[-1][1] != value:
+            runs.append((cp, value))
+    return runs
+
+
+def write_rust_consts(out, prefix, names):
+    for i, name in enumerate(names):
+        out.write("pub const %s_%s: u8 = %d;\n" % (prefix, name.upper(), i))
+    out.write("\n")
+
+
+def write_header(out):
+    out.write("// Generated by rts/gen-unicode-tables.py from the Unicode Character Database, version\n")
+    out.write("// %d.%d.%d. Do not edit.\n\n" % UNICODE_VERSION)
+    out.write("#![cfg_attr(rustfmt, rustfmt_skip)]\n\n")
+
+
+#
+# Normalization
+#
+
+def gen_normalization_tables(chars, exclusions):
+    combining_classes = {}
+    decompositions = {}  # code point -> (is_compatibility, [code points])
+
+    for cp, fields in chars.items():
+        ccc = int(fields[3])
+        if ccc != 0:
+            combining_classes[cp] = ccc
+        if fields[5]:
+            parts = fields[5].split()
+            compat = parts[0].startswith("<")
+            if compat:
+                parts = parts[1:]
+            decompositions[cp] = (compat, [int(p, 16) for p in parts])
+
+    # Primary composites: canonical decompositions that are not singletons, do not start with a
+    # non-starter, and are not explicitly excluded
+    compositions = []
+    for cp, (compat, parts) in decompositions.items():
+        if compat or len(parts) != 2:
+            continue
+        if cp in exclusions or combining_classes.get(cp, 0) != 0 \
+                or combining_classes.get(parts[0], 0) != 0:
+            continue
+        compositions.append((parts[0], parts[1], cp))
+    compositions.sort()
+
+    # Characters that can combine with a preceding character (NFC_QC=Maybe)
+    composition_seconds = set(second for (_, second, _) in compositions)
+    composition_seconds.update(HANGUL_VOWELS)
+    composition_seconds.update(HANGUL_TRAILING)
+
+    # Combining class ranges
+    ccc_ranges = []
+    for cp in sorted(combining_classes):
+        ccc = combining_classes[cp]
+        if ccc_ranges and ccc_ranges[-1][1] == cp - 1 and ccc_ranges[-1][2] == ccc:
+            ccc_ranges[-1][1] = cp
+        else:
+            ccc_ranges.append([cp, cp, ccc])
+
+    decomposition_entries = []
+    decomposition_chars = []
+    for cp in sorted(decompositions):
+        compat, parts = decompositions[cp]
+        offset = len(decomposition_chars)
+        assert offset < (1 << 16) and len(parts) < (1 << 5)
+        decomposition_chars.extend(parts)
+        data = offset | (len(parts) << 16) | ((1 << 31) if compat else 0)
+        decomposition_entries.append("(0x%X, 0x%X)" % (cp, data))
+
+    with open(NORMALIZATION_TABLES, "w") as out:
+        write_header(out)
+        out.write("pub const UNICODE_VERSION: (u8, u8, u8) = (%d, %d, %d);\n\n"
+                  % UNICODE_VERSION)
+        write_rust_array(
+            out,
+            "Non-zero canonical combining classes, as `(first, last, class)` ranges",
+            "COMBINING_CLASSES", "(u32, u32, u8)",
+            ["(0x%X, 0x%X, %d)" % tuple(r) for r in ccc_ranges], 4)
+        write_rust_array(
+            out,
+            """
+Decomposition mappings (excluding Hangul syllables), as `(code point, data)`, sorted by code
+point. `data` holds the offset of the mapping in `DECOMPOSITION_CHARS` in bits 0-15, the length of
+the mapping in bits 16-20, and whether the mapping is a compatibility mapping in bit 31.
+
+The mappings are not applied recursively.
+""",
+            "DECOMPOSITIONS", "(u32, u32)", decomposition_entries, 4)
+        write_rust_array(
+            out, "Characters of the decomposition mappings in `DECOMPOSITIONS`",
+            "DECOMPOSITION_CHARS", "u32", ["0x%X" % c for c in decomposition_chars], 10)
+        write_rust_array(
+            out,
+            "Primary composites (excluding Hangul syllables), as `(first, second, composite)`, sorted\nby `(first, second)`",
+            "COMPOSITIONS", "(u32, u32, u32)", ["(0x%X, 0x%X, 0x%X)" % c for c in compositions], 3)
+        write_rust_array(
+            out,
+            "Characters that can combine with a preceding character (`NFC_Quick_Check=Maybe`), as\n`(first, last)` ranges",
+            "COMPOSITION_SECONDS", "(u32, u32)",
+            ["(0x%X, 0x%X)" % tuple(r) for r in to_ranges(composition_seconds)], 4)
+
+
+#
+# Character properties
+#
+
+GENERAL_CATEGORIES = [
+    "Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps", "Pe", "Pi",
+    "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs", "Co", "Cn",
+]
+
+GRAPHEME_BREAKS = [
+    "Other", "CR", "LF", "Control", "Extend", "ZWJ", "Regional_Indicator", "Prepend",
+    "SpacingMark", "L", "V", "T", "LV", "LVT",
+]
+
+WORD_BREAKS = [
+    "Other", "CR", "LF", "Newline", "Extend", "ZWJ", "Regional_Indicator", "Format", "Katakana",
+    "Hebrew_Letter", "ALetter", "Single_Quote", "Double_Quote", "MidNumLet", "MidLetter", "MidNum",
+    "Numeric", "ExtendNumLet", "WSegSpace",
+]
+
+
+def partition_entries(values, default, names):
+    return ["0x%X" % (first << 8 | names.index(value))
+            for (first, value) in to_partition(values, default)]
+
+
+def gen_char_tables(chars, ucd_dir):
+    categories = {cp: fields[2] for cp, fields in chars.items()}
+    scripts = read_property_file(os.path.join(ucd_dir, "Scripts.txt"))
+    script_codes = read_script_codes(ucd_dir)
+    grapheme_breaks = read_property_file(
+        os.path.join(ucd_dir, "auxiliary/GraphemeBreakProperty.txt"))
+    word_breaks = read_property_file(os.path.join(ucd_dir, "auxiliary/WordBreakProperty.txt"))
+    extended_pictographic = read_property_set(
+        os.path.join(ucd_dir, "emoji/emoji-data.txt"), "Extended_Pictographic")
+    alphabetic = read_property_set(
+        os.path.join(ucd_dir, "DerivedCoreProperties.txt"), "Alphabetic")
+
+    # Scripts ordered by their ISO 15924 codes, with Unknown (the default) in the table
+    script_names = sorted(set(scripts.values()) | {"Unknown"}, key=lambda name: script_codes[name])
+    assert len(script_names) < 256
+
+    # Numeric values, including the ones of CJK ideographs from Unihan, which are not in
+    # UnicodeData.txt. Stored as runs of consecutive code points with consecutive integer values (or
+    # a single code point with a fractional value), with the distinct values of the run starts
+    # stored separately as fractions.
+    numeric_properties = read_property_file(
+        os.path.join(ucd_dir, "extracted/DerivedNumericValues.txt"), field=3)
+    numeric_runs = []
+    for cp in sorted(numeric_properties):
+        value = Fraction(numeric_properties[cp])
+        if numeric_runs and numeric_runs[-1][1] == cp - 1 and value.denominator == 1 \
+                and numeric_runs[-1][2] + (cp - numeric_runs[-1][0]) == value \
+                and cp - numeric_runs[-1][0] < 256:
+            numeric_runs[-1][1] = cp
+        else:
+            numeric_runs.append([cp, cp, value])
+    numeric_values = sorted(set(value for (_, _, value) in numeric_runs))
+    assert len(numeric_values) < 256
+    assert all(abs(value.numerator) < (1 << 63) and value.denominator < (1 << 32)
+               for value in numeric_values)
+
+    with open(CHAR_TABLES, "w") as out:
+        write_header(out)
+        out.write("// General categories, in the order of PropertyValueAliases.txt\n")
+        write_rust_consts(out, "GC", GENERAL_CATEGORIES)
+        out.write("// Grapheme_Cluster_Break property values\n")
+        write_rust_consts(out, "GCB", GRAPHEME_BREAKS)
+        out.write("// Word_Break property values\n")
+        write_rust_consts(out, "WB", WORD_BREAKS)
+
+        partition_doc = """
+%s, as `first << 8 | value` for runs of code points with the same
+value starting at `first`. The first run starts at 0, a run lasts until the start of the next one.
+"""
+        write_rust_array(
+            out, partition_doc % "General categories (`GC_*`)", "GENERAL_CATEGORIES", "u32",
+            partition_entries(categories, "Cn", GENERAL_CATEGORIES), 8)
+        write_rust_array(
+            out, partition_doc % "Scripts (indices into `SCRIPT_CODES`)", "SCRIPTS", "u32",
+            partition_entries(scripts, "Unknown", script_names), 8)
+        write_rust_array(
+            out, "ISO 15924 codes of the scripts in `SCRIPTS`", "SCRIPT_CODES", "[u8; 4]",
+            ['*b"%s"' % script_codes[name] for name in script_names], 8)
+        write_rust_array(
+            out, partition_doc % "Grapheme_Cluster_Break property values (`GCB_*`)",
+            "GRAPHEME_BREAKS", "u32", partition_entries(grapheme_breaks, "Other", GRAPHEME_BREAKS),
+            8)
+        write_rust_array(
+            out, partition_doc % "Word_Break property values (`WB_*`)", "WORD_BREAKS", "u32",
+            partition_entries(word_breaks, "Other", WORD_BREAKS), 8)
+        write_rust_array(
+            out, "Extended_Pictographic characters, as `(first, last)` ranges",
+            "EXTENDED_PICTOGRAPHIC", "(u32, u32)",
+            ["(0x%X, 0x%X)" % tuple(r) for r in to_ranges(extended_pictographic)], 4)
+        write_rust_array(
+            out, "Alphabetic characters, as `(first, last)` ranges", "ALPHABETIC", "(u32, u32)",
+            ["(0x%X, 0x%X)" % tuple(r) for r in to_ranges(alphabetic)], 4)
+        write_rust_array(
+            out,
+            """
+Characters with a numeric value, as `first << 8 | index` for runs of consecutive code points
+whose values increase by one, starting with the value at `index` in `NUMERIC_VALUES`. The lengths
+of the runs are in `NUMERIC_RUN_LENGTHS`.
+""",
+            "NUMERIC_RUNS", "u32",
+            ["0x%X" % (first << 8 | numeric_values.index(value))
+             for (first, _, value) in numeric_runs], 8)
+        write_rust_array(
+            out, "Lengths minus one of the runs in `NUMERIC_RUNS`", "NUMERIC_RUN_LENGTHS", "u8",
+            ["%d" % (last - first) for (first, last, _) in numeric_runs], 16)
+        write_rust_array(
+            out, "Numeric values, as `(numerator, denominator)`", "NUMERIC_VALUES", "(i64, u32)",
+            ["(%d, %d)" % (value.numerator, value.denominator) for value in numeric_values], 4)
+
+
+def gen_normalization_test_data(ucd_dir):
+    with open(os.path.join(ucd_dir, "NormalizationTest.txt"), encoding="utf-8") as f:
+        tests = [line.split("#")[0].strip() for line in f]
+
+    with open(NORMALIZATION_TEST_DATA, "w", encoding="utf-8") as out:
+        out.write("# Normalization conformance data for Unicode %d.%d.%d, from the UCD file\n"
+                  % UNICODE_VERSION)
+        out.write("# NormalizationTest.txt with comments removed: source; NFC; NFD; NFKC; NFKD;\n")
+        out.write("#\n")
+        out.write("# Generated by rts/gen-unicode-tables.py. Do not edit.\n")
+        for test in tests:
+            if test:
+                out.write(test + "\n")
+
+
+#
+# Grapheme cluster test data
+#
+
+def gen_grapheme_break_test_data(ucd_dir):
+    with open(os.path.join(ucd_dir, "auxiliary/GraphemeBreakTest.txt"), encoding="utf-8") as f:
+        tests = [line.split("#")[0].strip() for line in f]
+
+    with open(GRAPHEME_BREAK_TEST_DATA, "w", encoding="utf-8") as out:
+        out.write("# Grapheme cluster conformance data for Unicode %d.%d.%d, from the UCD file\n"
+                  % UNICODE_VERSION)
+        out.write("# GraphemeBreakTest.txt with comments removed. \u00f7 marks a boundary, \u00d7 marks\n")
+        out.write("# no boundary.\n")
+        out.write("#\n")
+        out.write("# Generated by rts/gen-unicode-tables.py. Do not edit.\n")
+        for test in tests:
+            if test:
+                out.write(test + "\n")
+
+
+def main():
+    if len(sys.argv) != 2:
+        print(__doc__)
+        sys.exit(1)
+
+    ucd_dir = sys.argv[1]
+    chars = read_unicode_data(ucd_dir)
+    exclusions = read_code_point_list(os.path.join(ucd_dir, "CompositionExclusions.txt"))
+
+    gen_normalization_tables(chars, exclusions)
+    gen_char_tables(chars, ucd_dir)
+    gen_normalization_test_data(ucd_dir)
+    gen_grapheme_break_test_data(ucd_dir)
+
+
+if __name__ == "__main__":
+    main()
@@ -1,14 +1,19 @@
+use crate::assert_traps;
 use crate::memory::TestMemory;
 
 use motoko_rts::bigint::{self, *};
 use motoko_rts::buf::Buf;
+use motoko_rts::memory::{alloc_blob, Memory};
+use motoko_rts::text::{blob_of_text, text_concat, text_of_str};
 use motoko_rts::types::{Bytes, SkewedPtr, Words};
 
+use proptest::test_runner::{Config, TestCaseError, TestCaseResult, TestRunner};
+
 // mp functions below are implemented separately for tests as we can't modify mp_int source code to
 // pass a generic heap argument (then monomorphise it for IC).
 
 // This global is used to pass a reference to heap to the mp functions
-static mut HEAP: *mut TestMemory = std::ptr::null_mut();
+pub(crate) static mut HEAP: *mut TestMemory = std::ptr::null_mut();
 
 #[no_mangle]
 unsafe extern "C" fn mp_calloc(n_elems: usize, elem_size: Bytes<usize>) -> *mut libc::c_void {
@@ -28,7 +33,7 @@ pub unsafe fn test() {
     println!("Testing BigInt ...");
 
     // Not sure how much we will need in these tests but 1G should be enough
-    let mut heap = TestMemory::new(Words(1024 * 1024));
+    let mut heap = TestMemory::new(Words(16 * 1024 * 1024));
     HEAP = &mut heap;
 
     assert!(bigint_eq(
@@ -62,8 +67,664 @@ pub unsafe fn test() {
         test_bigint_sleb128(bigint_neg(plus_one));
     }
 
+    //
+    // Compact representation
+    //
+
+    test_compact();
+
+    //
+    // Error messages
+    //
+
+    test_traps();
+
+    //
+    // Number-theoretic operations
+    //
+
+    let values: [i128; 12] = [
+        0,
+        1,
+        -1,
+        2,
+        6,
+        -15,
+        35,
+        97,
+        1 << 32,
+        -(1 << 40) - 3,
+        1_000_000_007,
+        (1 << 62) - 57,
+    ];
+
+    for a in values.iter().copied() {
+        for b in values.iter().copied() {
+            test_gcd_lcm(a, b);
+            if b != 0 {
+                test_divmod(&mut heap, a, b);
+            }
+        }
+    }
+
+    let moduli: [i128; 6] = [1, 2, 7, 97, 1_000_000_007, (1 << 62) - 57];
+    for base in values.iter().copied() {
+        for m in moduli.iter().copied() {
+            for exp in [0u32, 1, 2, 5, 64, 1000].iter().copied() {
+                let expected = mod_pow_ref(base, exp, m);
+                let result = bigint_modpow(
+                    bigint_of_i128(base),
+                    bigint_of_word32(exp),
+                    bigint_of_i128(m),
+                );
+                assert!(bigint_eq(result, bigint_of_i128(expected)));
+            }
+
+            if m > 1 && gcd_ref(base, m) == 1 {
+                let inv = bigint_modinv(bigint_of_i128(base), bigint_of_i128(m));
+                let expected = mod_inv_ref(base, m);
+                assert!(bigint_eq(inv, bigint_of_i128(expected)));
+
+                // Negative exponents use the inverse
+                let result = bigint_modpow(
+                    bigint_of_i128(base),
+                    bigint_neg(bigint_of_word32(2)),
+                    bigint_of_i128(m),
+                );
+                let expected = (expected * expected) % m;
+                assert!(bigint_eq(result, bigint_of_i128(expected)));
+            }
+        }
+    }
+
+    for n in [
+        0u64,
+        1,
+        2,
+        3,
+        4,
+        15,
+        16,
+        17,
+        1 << 40,
+        (1 << 62) + 12345,
+        u64::MAX,
+    ]
+    .iter()
+    .copied()
+    {
+        let expected = isqrt_ref(n);
+        let result = bigint_isqrt(bigint_of_i128(n as i128));
+        assert!(bigint_eq(result, bigint_of_i128(expected as i128)));
+    }
+
+    let two_pow_100 = bigint_pow(two, bigint_of_word32(100));
+    let two_pow_200 = bigint_pow(two, bigint_of_word32(200));
+    assert!(bigint_eq(bigint_isqrt(two_pow_200), two_pow_100));
+    assert!(bigint_eq(
+        bigint_isqrt(bigint_sub(two_pow_200, one)),
+        bigint_sub(two_pow_100, one)
+    ));
+
+    //
+    // Conversion to and from text
+    //
+
+    for n in values.iter().copied() {
+        for radix in [2, 8, 10, 16, 36].iter().copied() {
+            test_text_conversion(&mut heap, n, radix);
+        }
+    }
+
+    let two_pow_200_str = "1606938044258990275541962092341162602522202993782792835301376";
+    let text = bigint_to_text(&mut heap, two_pow_200, 10);
+    assert_eq!(text_to_string(&mut heap, text), two_pow_200_str);
+    let text = text_of_str(&mut heap, two_pow_200_str);
+    assert!(bigint_eq(bigint_of_text(text, 10), two_pow_200));
+
+    let text = text_of_str(&mut heap, "-0");
+    let zero = bigint_of_text(text, 10);
+    assert!(bigint_eq(zero, bigint_of_word32(0)));
+    let text = bigint_to_text(&mut heap, zero, 10);
+    assert_eq!(text_to_string(&mut heap, text), "0");
+
+    //
+    // Conversion to and from blobs
+    //
+
+    for (n, unsigned, signed) in [
+        (0, &[0x00][..], &[0x00][..]),
+        (1, &[0x01], &[0x01]),
+        (127, &[0x7F], &[0x7F]),
+        (128, &[0x80], &[0x00, 0x80]),
+        (255, &[0xFF], &[0x00, 0xFF]),
+        (256, &[0x01, 0x00], &[0x01, 0x00]),
+        (-1, &[], &[0xFF]),
+        (-128, &[], &[0x80]),
+        (-129, &[], &[0xFF, 0x7F]),
+        (-(1 << 40) - 3, &[], &[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFD]),
+        (
+            1 << 64,
+            &[0x01, 0, 0, 0, 0, 0, 0, 0, 0],
+            &[0x01, 0, 0, 0, 0, 0, 0, 0, 0],
+        ),
+    ]
+    .iter()
+    .copied()
+    {
+        let n_big = bigint_of_i128(n);
+        if n >= 0 {
+            test_blob_encoding(&mut heap, n_big, unsigned, false);
+        }
+        test_blob_encoding(&mut heap, n_big, signed, true);
+    }
+
+    let blob = bigint_to_blob(&mut heap, two_pow_200, BIG_ENDIAN, 0);
+    assert_eq!(blob_bytes(blob).len(), 26);
+    assert!(bigint_eq(
+        bigint_of_blob(&mut heap, blob, BIG_ENDIAN, false),
+        two_pow_200
+    ));
+
+    //
+    // Conversion to and from floats
+    //
+
+    let pow2 = |exp: u32| bigint_pow(two, bigint_of_word32(exp));
+    let two_pow_53 = pow2(53);
+    let two_pow_54 = pow2(54);
+    let max_float_tie = bigint_sub(pow2(1024), pow2(970));
+    let above_tie = bigint_add(bigint_mul(bigint_add(two_pow_53, one), pow2(100)), one);
+    for (n, expected) in [
+        (bigint_of_word32(0), 0.0),
+        (one, 1.0),
+        (bigint_neg(one), -1.0),
+        (two_pow_53, 9007199254740992.0),
+        // Ties round to even
+        (bigint_add(two_pow_53, one), 9007199254740992.0),
+        (
+            bigint_add(two_pow_53, bigint_of_word32(3)),
+            9007199254740996.0,
+        ),
+        (bigint_add(two_pow_54, two), 18014398509481984.0),
+        (
+            bigint_add(two_pow_54, bigint_of_word32(6)),
+            18014398509481992.0,
+        ),
+        (bigint_sub(pow2(64), one), 18446744073709551616.0),
+        // Dropped bits beyond the 64 most significant ones break ties
+        (above_tie, 9007199254740994.0 * 2f64.powi(100)),
+        (bigint_neg(above_tie), -9007199254740994.0 * 2f64.powi(100)),
+        (bigint_sub(max_float_tie, one), f64::MAX),
+        (max_float_tie, f64::INFINITY),
+        (bigint_neg(pow2(1024)), f64::NEG_INFINITY),
+        (pow2(1023), 2f64.powi(1023)),
+    ]
+    .iter()
+    .copied()
+    {
+        assert_eq!(bigint_to_float64(n).to_bits(), expected.to_bits());
+    }
+
+    for (f, expected) in [
+        (0.0, bigint_of_word32(0)),
+        (-0.0, bigint_of_word32(0)),
+        (0.99, bigint_of_word32(0)),
+        (-0.99, bigint_of_word32(0)),
+        (f64::MIN_POSITIVE / 2.0, bigint_of_word32(0)),
+        (1.5, one),
+        (-2.5, bigint_neg(two)),
+        (4503599627370495.5, bigint_sub(pow2(52), one)),
+        (9007199254740992.0, two_pow_53),
+        (2f64.powi(1023), pow2(1023)),
+        (-2f64.powi(1023), bigint_neg(pow2(1023))),
+    ]
+    .iter()
+    .copied()
+    {
+        assert!(bigint_eq(bigint_of_float64(f), expected));
+    }
+
     HEAP = std::ptr::null_mut();
     drop(heap);
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 1_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    proptest_runner
+        .run(
+            &(proptest::num::i128::ANY, proptest::num::f64::NORMAL),
+            |(n, f)| {
+                let mut heap = TestMemory::new(Words(1024 * 1024));
+                HEAP = &mut heap;
+                let result = float_prop(n, f);
+                HEAP = std::ptr::null_mut();
+                result
+            },
+        )
+        .unwrap();
+
+    // All two byte blobs round-trip
+    for byte0 in 0..=255u8 {
+        let mut heap = TestMemory::new(Words(1024 * 1024));
+        HEAP = &mut heap;
+        for byte1 in 0..=255u8 {
+            test_blob_roundtrip(&mut heap, &[byte0, byte1]);
+        }
+        HEAP = std::ptr::null_mut();
+    }
+
+    //
+    // Bitwise operations
+    //
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 1_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    proptest_runner
+        .run(
+            &(
+                proptest::num::i64::ANY,
+                proptest::num::i64::ANY,
+                0u32..64,
+                0u32..130,
+            ),
+            |(a, b, shift, bit)| {
+                let mut heap = TestMemory::new(Words(1024 * 1024));
+                HEAP = &mut heap;
+                let result = bitwise_prop(a, b, shift, bit);
+                HEAP = std::ptr::null_mut();
+                result
+            },
+        )
+        .unwrap();
+}
+
+/// Checks float conversions against the correctly rounded conversion from `i128`, and round-trips
+/// of floats through their integer part
+unsafe fn float_prop(n: i128, f: f64) -> TestCaseResult {
+    let n_big = bigint_of_i128(n);
+    if bigint_to_float64(n_big).to_bits() != (n as f64).to_bits() {
+        return Err(TestCaseError::Fail("bigint_to_float64".into()));
+    }
+
+    if f.abs() < 2f64.powi(127) && !bigint_eq(bigint_of_float64(f), bigint_of_i128(f as i128)) {
+        return Err(TestCaseError::Fail("bigint_of_float64".into()));
+    }
+
+    if bigint_to_float64(bigint_of_float64(f)) != f.trunc() {
+        return Err(TestCaseError::Fail("float round-trip".into()));
+    }
+
+    Ok(())
+}
+
+/// Checks bitwise operations against `i128`. Operands are shifted to also have bits set beyond 64
+/// bits.
+unsafe fn bitwise_prop(a: i64, b: i64, shift: u32, bit: u32) -> TestCaseResult {
+    let a = i128::from(a) << shift;
+    let b = i128::from(b);
+    let a_big = bigint_of_i128(a);
+    let b_big = bigint_of_i128(b);
+
+    let check = |name: &str, result: SkewedPtr, expected: i128| {
+        if bigint_eq(result, bigint_of_i128(expected)) {
+            Ok(())
+        } else {
+            Err(TestCaseError::Fail(name.to_owned().into()))
+        }
+    };
+
+    check("bigint_and", bigint_and(a_big, b_big), a & b)?;
+    check("bigint_or", bigint_or(a_big, b_big), a | b)?;
+    check("bigint_xor", bigint_xor(a_big, b_big), a ^ b)?;
+    check("bigint_not", bigint_not(a_big), !a)?;
+    check("bigint_rsh", bigint_rsh(a_big, shift as i32), a >> shift)?;
+    check(
+        "bigint_rsh",
+        bigint_rsh(b_big, bit as i32),
+        b >> bit.min(127),
+    )?;
+
+    // Bits above 127 are copies of the sign bit
+    if bigint_test_bit(a_big, bit) != (a >> bit.min(127) & 1 == 1) {
+        return Err(TestCaseError::Fail("bigint_test_bit".into()));
+    }
+
+    if bit < 127 {
+        check("bigint_set_bit", bigint_set_bit(a_big, bit), a | (1 << bit))?;
+    }
+
+    if bigint_popcount(a_big) != a.abs().count_ones() {
+        return Err(TestCaseError::Fail("bigint_popcount".into()));
+    }
+
+    Ok(())
+}
+
+unsafe fn bigint_of_i128(n: i128) -> SkewedPtr {
+    let two_pow_32 = bigint_mul(bigint_of_word32(1 << 16), bigint_of_word32(1 << 16));
+    let abs = if n < 0 { (-n) as u128 } else { n as u128 };
+    let mut i = bigint_of_word32(0);
+    for shift in [96, 64, 32, 0].iter() {
+        let word = bigint_of_word32((abs >> shift) as u32);
+        i = bigint_add(bigint_mul(i, two_pow_32), word);
+    }
+    if n < 0 {
+        bigint_neg(i)
+    } else {
+        i
+    }
+}
+
+fn gcd_ref(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+fn mod_pow_ref(base: i128, mut exp: u32, m: i128) -> i128 {
+    let mut base = base.rem_euclid(m);
+    let mut result = 1 % m;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result
+}
+
+fn mod_inv_ref(a: i128, m: i128) -> i128 {
+    // Extended Euclid
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        let tmp = old_r - q * r;
+        old_r = r;
+        r = tmp;
+        let tmp = old_s - q * s;
+        old_s = s;
+        s = tmp;
+    }
+    old_s.rem_euclid(m)
+}
+
+fn isqrt_ref(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while (r as u128) * (r as u128) > n as u128 {
+        r -= 1;
+    }
+    while ((r + 1) as u128) * ((r + 1) as u128) <= n as u128 {
+        r += 1;
+    }
+    r
+}
+
+fn to_radix_ref(n: i128, radix: u32) -> String {
+    let mut abs = if n < 0 { (-n) as u128 } else { n as u128 };
+    let mut digits = vec![];
+    loop {
+        digits.push(std::char::from_digit((abs % radix as u128) as u32, radix).unwrap());
+        abs /= radix as u128;
+        if abs == 0 {
+            break;
+        }
+    }
+    if n < 0 {
+        digits.push('-');
+    }
+    digits.iter().rev().collect()
+}
+
+unsafe fn text_to_string<M: Memory>(mem: &mut M, text: SkewedPtr) -> String {
+    let blob = blob_of_text(mem, text).as_blob();
+    let bytes = std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+unsafe fn test_text_conversion(heap: &mut TestMemory, n: i128, radix: u32) {
+    let expected = to_radix_ref(n, radix);
+    let n_big = bigint_of_i128(n);
+
+    let text = bigint_to_text(heap, n_big, radix);
+    assert_eq!(text_to_string(heap, text), expected);
+    assert!(bigint_eq(bigint_of_text(text, radix), n_big));
+
+    // Upper case digits, explicit `+` sign
+    let upper = if n < 0 {
+        expected.to_uppercase()
+    } else {
+        format!("+{}", expected.to_uppercase())
+    };
+    let text = text_of_str(heap, &upper);
+    assert!(bigint_eq(bigint_of_text(text, radix), n_big));
+
+    // Separators between every two digits, in a concatenation of single characters
+    let (sign, digits) = expected.split_at(if n < 0 { 1 } else { 0 });
+    let digits: Vec<String> = digits.chars().map(|c| c.to_string()).collect();
+    let separated = format!("{}{}", sign, digits.join("_"));
+    let mut text = text_of_str(heap, "");
+    for c in separated.chars() {
+        let c_text = text_of_str(heap, &c.to_string());
+        text = text_concat(heap, text, c_text);
+    }
+    assert!(bigint_eq(bigint_of_text(text, radix), n_big));
+}
+
+unsafe fn make_blob<M: Memory>(mem: &mut M, bytes: &[u8]) -> SkewedPtr {
+    let blob = alloc_blob(mem, Bytes(bytes.len() as u32));
+    for (i, byte) in bytes.iter().enumerate() {
+        blob.as_blob().set(i as u32, *byte);
+    }
+    blob
+}
+
+unsafe fn blob_bytes(blob: SkewedPtr) -> Vec<u8> {
+    let blob = blob.as_blob();
+    std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize()).to_vec()
+}
+
+/// Checks the minimal big endian encoding of a number, the little endian and padded encodings
+/// derived from it, and decoding
+unsafe fn test_blob_encoding(heap: &mut TestMemory, n: SkewedPtr, expected: &[u8], signed: bool) {
+    let to_blob = if signed {
+        bigint_to_blob_signed::<TestMemory>
+    } else {
+        bigint_to_blob::<TestMemory>
+    };
+
+    let blob = to_blob(heap, n, BIG_ENDIAN, 0);
+    assert_eq!(blob_bytes(blob), expected);
+    assert!(bigint_eq(bigint_of_blob(heap, blob, BIG_ENDIAN, signed), n));
+
+    let little_endian: Vec<u8> = expected.iter().rev().copied().collect();
+    let blob = to_blob(heap, n, LITTLE_ENDIAN, 0);
+    assert_eq!(blob_bytes(blob), little_endian);
+    assert!(bigint_eq(
+        bigint_of_blob(heap, blob, LITTLE_ENDIAN, signed),
+        n
+    ));
+
+    let pad = if signed && expected[0] & 0x80 != 0 {
+        0xFF
+    } else {
+        0x00
+    };
+    let mut padded = vec![pad; 3];
+    padded.extend_from_slice(expected);
+    let blob = to_blob(heap, n, BIG_ENDIAN, padded.len() as u32);
+    assert_eq!(blob_bytes(blob), padded);
+    assert!(bigint_eq(bigint_of_blob(heap, blob, BIG_ENDIAN, signed), n));
+}
+
+unsafe fn test_blob_roundtrip(heap: &mut TestMemory, bytes: &[u8]) {
+    for endianness in [LITTLE_ENDIAN, BIG_ENDIAN].iter().copied() {
+        let blob = make_blob(heap, bytes);
+
+        let n = bigint_of_blob(heap, blob, endianness, false);
+        let width = bytes.len() as u32;
+        assert_eq!(
+            blob_bytes(bigint_to_blob(heap, n, endianness, width)),
+            bytes
+        );
+
+        let n = bigint_of_blob(heap, blob, endianness, true);
+        assert_eq!(
+            blob_bytes(bigint_to_blob_signed(heap, n, endianness, width)),
+            bytes
+        );
+    }
+}
+
+unsafe fn test_gcd_lcm(a: i128, b: i128) {
+    let gcd = gcd_ref(a, b);
+    let lcm = if gcd == 0 { 0 } else { (a / gcd * b).abs() };
+    assert!(bigint_eq(
+        bigint_gcd(bigint_of_i128(a), bigint_of_i128(b)),
+        bigint_of_i128(gcd)
+    ));
+    assert!(bigint_eq(
+        bigint_lcm(bigint_of_i128(a), bigint_of_i128(b)),
+        bigint_of_i128(lcm)
+    ));
+}
+
+unsafe fn test_compact() {
+    let max = bigint_of_word32((1 << 30) - 1);
+    let min = bigint_neg(bigint_of_word32(1 << 30));
+    assert!(max.is_tagged_scalar());
+    assert_eq!(max.0 as u32, ((1 << 30) - 1) << 1);
+    assert!(min.is_tagged_scalar());
+    assert!(!bigint_of_word32(1 << 30).is_tagged_scalar());
+
+    let one = bigint_of_word32(1);
+    assert!(one.is_tagged_scalar());
+    assert!(!bigint_add(max, one).is_tagged_scalar());
+    assert!(!bigint_sub(min, one).is_tagged_scalar());
+    assert!(bigint_eq(bigint_add(max, one), bigint_of_i128(1 << 30)));
+    assert!(bigint_eq(
+        bigint_sub(min, one),
+        bigint_of_i128(-(1 << 30) - 1)
+    ));
+
+    // Results of operations on boxed numbers are compact when possible
+    let big = bigint_of_i128(1 << 40);
+    assert!(!big.is_tagged_scalar());
+    let diff = bigint_sub(big, bigint_sub(big, bigint_of_word32(7)));
+    assert!(diff.is_tagged_scalar());
+    assert!(bigint_eq(diff, bigint_of_word32(7)));
+    assert!(bigint_div(big, big).is_tagged_scalar());
+
+    // Mixed representations, and results overflowing `i64`
+    let values: [i128; 8] = [
+        0,
+        -3,
+        (1 << 30) - 1,
+        -(1 << 30),
+        1 << 40,
+        -(1 << 62),
+        i64::MAX as i128,
+        i64::MIN as i128,
+    ];
+    for a in values.iter().copied() {
+        for b in values.iter().copied() {
+            let a_big = bigint_of_i128(a);
+            let b_big = bigint_of_i128(b);
+            assert!(bigint_eq(bigint_add(a_big, b_big), bigint_of_i128(a + b)));
+            assert!(bigint_eq(bigint_sub(a_big, b_big), bigint_of_i128(a - b)));
+            assert!(bigint_eq(bigint_mul(a_big, b_big), bigint_of_i128(a * b)));
+            assert_eq!(bigint_lt(a_big, b_big), a < b);
+            assert_eq!(bigint_ge(a_big, b_big), a >= b);
+            if b != 0 {
+                assert!(bigint_eq(bigint_div(a_big, b_big), bigint_of_i128(a / b)));
+                assert!(bigint_eq(bigint_rem(a_big, b_big), bigint_of_i128(a % b)));
+            }
+        }
+        assert!(bigint_eq(bigint_neg(bigint_of_i128(a)), bigint_of_i128(-a)));
+        assert_eq!(bigint_isneg(bigint_of_i128(a)), a < 0);
+    }
+}
+
+fn test_traps() {
+    let (zero, minus_one, big) = unsafe {
+        (
+            bigint_of_word32(0),
+            bigint_neg(bigint_of_word32(1)),
+            bigint_of_i128(1 << 80),
+        )
+    };
+
+    assert_traps(
+        || unsafe {
+            bigint_div(big, zero);
+        },
+        "division by zero",
+    );
+    assert_traps(
+        || unsafe {
+            bigint_rem(minus_one, zero);
+        },
+        "division by zero",
+    );
+    assert_traps(
+        || unsafe {
+            bigint_divmod(&mut *HEAP, big, zero);
+        },
+        "division by zero",
+    );
+
+    assert_traps(
+        || unsafe {
+            bigint_to_word32_trap(minus_one);
+        },
+        "value out of range for Nat32",
+    );
+    assert_traps(
+        || unsafe {
+            bigint_to_word32_trap(big);
+        },
+        "value out of range for Nat32",
+    );
+    assert_traps(
+        || unsafe {
+            bigint_to_word64_trap(big);
+        },
+        "value out of range for Nat64",
+    );
+
+    assert_traps(
+        || unsafe {
+            bigint_pow(big, big);
+        },
+        "bigint_pow: exponent out of range",
+    );
+
+    let mut buf = [0u8; 10];
+    let buf_ptr = buf.as_mut_ptr() as usize;
+    assert_traps(
+        || unsafe { bigint_leb128_encode(minus_one, buf_ptr as *mut u8) },
+        "bigint_leb128_encode: negative number",
+    );
+}
+
+unsafe fn test_divmod(heap: &mut TestMemory, a: i128, b: i128) {
+    let tuple = bigint_divmod(heap, bigint_of_i128(a), bigint_of_i128(b)).as_array();
+    assert_eq!(tuple.len(), 2);
+    assert!(bigint_eq(tuple.get(0), bigint_of_i128(a / b)));
+    assert!(bigint_eq(tuple.get(1), bigint_of_i128(a % b)));
 }
 
 // Check leb128 encode/decode roundtrip
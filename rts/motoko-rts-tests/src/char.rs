@@ -0,0 +1,265 @@
+//! Character property tests
+
+use motoko_rts::char::*;
+
+use std::convert::TryFrom;
+
+pub unsafe fn test() {
+    println!("Testing character properties ...");
+
+    test_general_category();
+    test_numeric_value();
+    test_script();
+    test_grapheme_break();
+    test_word_break();
+}
+
+fn chars() -> impl Iterator<Item = char> {
+    (0u32..0xD800)
+        .chain(0xE000..0x110000)
+        .map(|c| char::try_from(c).unwrap())
+}
+
+unsafe fn test_general_category() {
+    println!("  Testing general category");
+
+    for (c, gc) in [
+        ('A', GC_LU),
+        ('a', GC_LL),
+        ('\u{1C5}', GC_LT),
+        ('\u{2B0}', GC_LM),
+        ('\u{5D0}', GC_LO),
+        ('\u{301}', GC_MN),
+        ('\u{903}', GC_MC),
+        ('\u{20DD}', GC_ME),
+        ('7', GC_ND),
+        ('\u{2167}', GC_NL),
+        ('\u{BD}', GC_NO),
+        ('_', GC_PC),
+        ('-', GC_PD),
+        ('(', GC_PS),
+        (')', GC_PE),
+        ('\u{AB}', GC_PI),
+        ('\u{BB}', GC_PF),
+        ('!', GC_PO),
+        ('+', GC_SM),
+        ('$', GC_SC),
+        ('^', GC_SK),
+        ('\u{A9}', GC_SO),
+        (' ', GC_ZS),
+        ('\u{2028}', GC_ZL),
+        ('\u{2029}', GC_ZP),
+        ('\n', GC_CC),
+        ('\u{200B}', GC_CF),
+        ('\u{E000}', GC_CO),
+        ('\u{10FFFD}', GC_CO),
+        ('\u{378}', GC_CN),
+        ('\u{10FFFF}', GC_CN),
+        ('\u{20000}', GC_LO),
+        ('\u{2A6DF}', GC_LO),
+        ('\u{10570}', GC_LU),
+    ]
+    .iter()
+    {
+        assert_eq!(general_category(*c as u32), *gc, "{:?}", c);
+    }
+    // Other_Alphabetic marks are alphabetic, other marks are not. U+0870 is a letter added in
+    // Unicode 14.
+    for (c, alphabetic) in [
+        ('a', true),
+        ('\u{345}', true),
+        ('\u{93E}', true),
+        ('\u{870}', true),
+        ('\u{301}', false),
+        ('7', false),
+        ('_', false),
+    ]
+    .iter()
+    {
+        assert_eq!(is_alphabetic(*c as u32), *alphabetic, "{:?}", c);
+    }
+    assert!(is_alphanumeric(0x870));
+
+    assert_eq!(general_category(0xD800), GC_CS);
+    assert_eq!(general_category(0xDFFF), GC_CS);
+
+    for c in chars() {
+        let gc = general_category(c as u32);
+        assert_eq!(is_control(c as u32), c.is_control(), "{:?}", c);
+        assert_eq!(is_numeric(c as u32), (GC_ND..=GC_NO).contains(&gc));
+        assert_eq!(is_punctuation(c as u32), (GC_PC..=GC_PO).contains(&gc));
+        // Letters and letter numbers are alphabetic
+        if (GC_LU..=GC_LO).contains(&gc) || gc == GC_NL {
+            assert!(is_alphabetic(c as u32), "{:?}", c);
+        }
+        assert_eq!(
+            is_alphanumeric(c as u32),
+            is_alphabetic(c as u32) || is_numeric(c as u32)
+        );
+        if c.is_ascii() {
+            assert_eq!(is_numeric(c as u32), c.is_ascii_digit());
+            assert_eq!(
+                is_punctuation(c as u32),
+                "!\"#%&'()*,-./:;?@[\\]_{}".contains(c)
+            );
+            assert_eq!(is_alphanumeric(c as u32), c.is_ascii_alphanumeric());
+        }
+    }
+}
+
+unsafe fn test_numeric_value() {
+    println!("  Testing numeric values");
+
+    for (c, value) in [
+        ('0', Some((0, 1))),
+        ('7', Some((7, 1))),
+        ('a', None),
+        ('\u{BD}', Some((1, 2))),
+        ('\u{F33}', Some((-1, 2))),
+        ('\u{2167}', Some((8, 1))),
+        ('\u{216F}', Some((1000, 1))),
+        ('\u{2469}', Some((10, 1))),
+        ('\u{3405}', Some((5, 1))),
+        ('\u{4E00}', Some((1, 1))),
+        ('\u{4E07}', Some((10000, 1))),
+        ('\u{5146}', Some((1_000_000_000_000, 1))),
+        ('\u{1D7CE}', Some((0, 1))),
+        ('\u{1D7FF}', Some((9, 1))),
+        ('\u{1F10C}', Some((0, 1))),
+        ('\u{16B61}', Some((1_000_000_000_000, 1))),
+        ('\u{109F6}', Some((1, 12))),
+    ]
+    .iter()
+    {
+        assert_eq!(numeric_value(*c as u32), *value, "{:?}", c);
+    }
+
+    // Decimal digits come in contiguous 0-9 sequences
+    for c in chars() {
+        if general_category(c as u32) == GC_ND {
+            let (value, denominator) = numeric_value(c as u32).unwrap();
+            assert_eq!(denominator, 1);
+            assert!((0..10).contains(&value));
+            assert_eq!(general_category(c as u32 - value as u32), GC_ND, "{:?}", c);
+            assert_eq!(numeric_value(c as u32 - value as u32), Some((0, 1)));
+        }
+        if is_numeric(c as u32) {
+            assert!(numeric_value(c as u32).is_some(), "{:?}", c);
+        }
+    }
+}
+
+fn test_script() {
+    println!("  Testing scripts");
+
+    for (c, script) in [
+        ('a', b"Latn"),
+        ('\u{3B1}', b"Grek"),
+        ('\u{430}', b"Cyrl"),
+        ('\u{5D0}', b"Hebr"),
+        ('\u{3042}', b"Hira"),
+        ('\u{30A2}', b"Kana"),
+        ('\u{4E00}', b"Hani"),
+        ('\u{AC00}', b"Hang"),
+        ('\u{2C00}', b"Glag"),
+        ('\u{16F00}', b"Plrd"),
+        ('\u{10570}', b"Vith"),
+        (' ', b"Zyyy"),
+        ('1', b"Zyyy"),
+        ('\u{301}', b"Zinh"),
+        ('\u{378}', b"Zzzz"),
+        ('\u{E000}', b"Zzzz"),
+        ('\u{10FFFF}', b"Zzzz"),
+    ]
+    .iter()
+    {
+        assert_eq!(script_code(*c as u32), *script, "{:?}", c);
+    }
+}
+
+fn test_grapheme_break() {
+    println!("  Testing grapheme cluster break property");
+
+    for (c, gcb) in [
+        ('a', GCB_OTHER),
+        ('\r', GCB_CR),
+        ('\n', GCB_LF),
+        ('\u{0}', GCB_CONTROL),
+        ('\u{2028}', GCB_CONTROL),
+        ('\u{301}', GCB_EXTEND),
+        ('\u{200C}', GCB_EXTEND),
+        ('\u{200D}', GCB_ZWJ),
+        ('\u{1F1E6}', GCB_REGIONAL_INDICATOR),
+        ('\u{600}', GCB_PREPEND),
+        ('\u{903}', GCB_SPACINGMARK),
+        ('\u{1100}', GCB_L),
+        ('\u{1161}', GCB_V),
+        ('\u{11A8}', GCB_T),
+        ('\u{1F600}', GCB_OTHER),
+        ('\u{378}', GCB_OTHER),
+    ]
+    .iter()
+    {
+        assert_eq!(grapheme_break(*c as u32), *gcb, "{:?}", c);
+    }
+
+    // Hangul syllables are LV or LVT depending on whether they have a trailing consonant
+    for c in 0xAC00..0xD7A4 {
+        let expected = if (c - 0xAC00) % 28 == 0 {
+            GCB_LV
+        } else {
+            GCB_LVT
+        };
+        assert_eq!(grapheme_break(c), expected);
+    }
+
+    for (c, pictographic) in [
+        ('a', false),
+        ('\u{A9}', true),
+        ('\u{AE}', true),
+        ('\u{2764}', true),
+        ('\u{1F600}', true),
+        ('\u{1F1E6}', false),
+        ('\u{1FFFD}', true),
+    ]
+    .iter()
+    {
+        assert_eq!(
+            is_extended_pictographic(*c as u32),
+            *pictographic,
+            "{:?}",
+            c
+        );
+    }
+}
+
+fn test_word_break() {
+    println!("  Testing word break property");
+
+    for (c, wb) in [
+        ('!', WB_OTHER),
+        ('\r', WB_CR),
+        ('\n', WB_LF),
+        ('\u{2028}', WB_NEWLINE),
+        ('\u{301}', WB_EXTEND),
+        ('\u{200D}', WB_ZWJ),
+        ('\u{1F1E6}', WB_REGIONAL_INDICATOR),
+        ('\u{AD}', WB_FORMAT),
+        ('\u{30A2}', WB_KATAKANA),
+        ('\u{5D0}', WB_HEBREW_LETTER),
+        ('a', WB_ALETTER),
+        ('\'', WB_SINGLE_QUOTE),
+        ('"', WB_DOUBLE_QUOTE),
+        ('.', WB_MIDNUMLET),
+        (':', WB_MIDLETTER),
+        (',', WB_MIDNUM),
+        ('7', WB_NUMERIC),
+        ('_', WB_EXTENDNUMLET),
+        (' ', WB_WSEGSPACE),
+        ('\u{4E00}', WB_OTHER),
+    ]
+    .iter()
+    {
+        assert_eq!(word_break(*c as u32), *wb, "{:?}", c);
+    }
+}
@@ -0,0 +1,181 @@
+//! UTF-16 and Latin-1 conversion tests
+
+use crate::assert_traps;
+use crate::memory::TestMemory;
+
+use motoko_rts::encoding::{
+    latin1_of_text, text_of_latin1, text_of_utf16, utf16_of_text, UTF16, UTF16_BE, UTF16_LE,
+};
+use motoko_rts::memory::{alloc_blob, Memory};
+use motoko_rts::text::{blob_of_text, text_concat, text_of_str};
+use motoko_rts::types::{Bytes, SkewedPtr, Words};
+
+use proptest::test_runner::{Config, TestCaseError, TestCaseResult, TestRunner};
+
+pub unsafe fn test() {
+    println!("Testing UTF-16 and Latin-1 conversions ...");
+
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    println!("  Testing UTF-16 decoding");
+    let str = "a\u{E9}\u{20AC}\u{1F600}";
+    let le = [0x61, 0, 0xE9, 0, 0xAC, 0x20, 0x3D, 0xD8, 0x00, 0xDE];
+    let be = [0, 0x61, 0, 0xE9, 0x20, 0xAC, 0xD8, 0x3D, 0xDE, 0x00];
+    assert_eq!(decode_utf16(&mut mem, &le, UTF16_LE, false), str);
+    assert_eq!(decode_utf16(&mut mem, &be, UTF16_BE, false), str);
+    assert_eq!(decode_utf16(&mut mem, &be, UTF16, false), str);
+    assert_eq!(
+        decode_utf16(&mut mem, &[&[0xFF, 0xFE][..], &le].concat(), UTF16, false),
+        str
+    );
+    assert_eq!(
+        decode_utf16(&mut mem, &[&[0xFE, 0xFF][..], &be].concat(), UTF16, false),
+        str
+    );
+    // Byte order marks are only removed when detecting the byte order
+    assert_eq!(
+        decode_utf16(
+            &mut mem,
+            &[&[0xFF, 0xFE][..], &le].concat(),
+            UTF16_LE,
+            false
+        ),
+        format!("\u{FEFF}{}", str)
+    );
+    assert_eq!(decode_utf16(&mut mem, &[], UTF16, false), "");
+
+    println!("  Testing lossy UTF-16 decoding");
+    for (bytes, expected) in [
+        (&[0x61, 0, 0x3D, 0xD8][..], "a\u{FFFD}"),
+        (&[0x3D, 0xD8, 0x61, 0][..], "\u{FFFD}a"),
+        (
+            &[0x00, 0xDE, 0x3D, 0xD8, 0x00, 0xDE][..],
+            "\u{FFFD}\u{1F600}",
+        ),
+        (&[0x61, 0, 0x62][..], "a\u{FFFD}"),
+        (&[0x3D, 0xD8, 0x3D, 0xD8][..], "\u{FFFD}\u{FFFD}"),
+    ]
+    .iter()
+    {
+        assert_eq!(decode_utf16(&mut mem, bytes, UTF16_LE, true), *expected);
+    }
+
+    println!("  Testing UTF-16 encoding");
+    let text = text_of_str(&mut mem, str);
+    assert_eq!(blob_bytes(utf16_of_text(&mut mem, text, UTF16_LE)), le);
+    assert_eq!(blob_bytes(utf16_of_text(&mut mem, text, UTF16_BE)), be);
+    assert_eq!(
+        blob_bytes(utf16_of_text(&mut mem, text, UTF16)),
+        [&[0xFE, 0xFF][..], &be].concat()
+    );
+
+    println!("  Testing Latin-1");
+    let all_bytes: Vec<u8> = (0..=255).collect();
+    let blob = make_blob(&mut mem, &all_bytes);
+    let latin1 = text_of_latin1(&mut mem, blob);
+    let latin1_str: String = (0..=255u8).map(char::from).collect();
+    assert_eq!(text_to_string(&mut mem, latin1), latin1_str);
+    assert_eq!(
+        blob_bytes(latin1_of_text(&mut mem, latin1, false)),
+        all_bytes
+    );
+
+    let text = text_of_str(&mut mem, "a\u{20AC}b\u{1F600}\u{FF}");
+    assert_eq!(
+        blob_bytes(latin1_of_text(&mut mem, text, true)),
+        b"a?b?\xFF"
+    );
+
+    // Offsets of characters not in Latin-1 are byte offsets in the UTF-8 text
+    assert_traps(
+        || {
+            let mut mem = TestMemory::new(Words(1024));
+            let text = text_of_str(&mut mem, "\u{FF}\u{E9}b\u{20AC}");
+            latin1_of_text(&mut mem, text, false);
+        },
+        "latin1_of_text: character not in Latin-1 at offset 5",
+    );
+
+    drop(mem);
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 1_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    proptest_runner
+        .run(
+            &proptest::collection::vec(proptest::string::string_regex(".{0, 20}").unwrap(), 1..5),
+            |strs| {
+                let mut mem = TestMemory::new(Words(1024 * 1024));
+                utf16_roundtrip(&mut mem, strs)
+            },
+        )
+        .unwrap();
+}
+
+unsafe fn make_blob<M: Memory>(mem: &mut M, bytes: &[u8]) -> SkewedPtr {
+    let blob = alloc_blob(mem, Bytes(bytes.len() as u32));
+    for (i, byte) in bytes.iter().enumerate() {
+        blob.as_blob().set(i as u32, *byte);
+    }
+    blob
+}
+
+unsafe fn blob_bytes(blob: SkewedPtr) -> Vec<u8> {
+    let blob = blob.as_blob();
+    std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize()).to_vec()
+}
+
+unsafe fn text_to_string<M: Memory>(mem: &mut M, text: SkewedPtr) -> String {
+    String::from_utf8(blob_bytes(blob_of_text(mem, text))).unwrap()
+}
+
+unsafe fn decode_utf16<M: Memory>(mem: &mut M, bytes: &[u8], encoding: u32, lossy: bool) -> String {
+    let blob = make_blob(mem, bytes);
+    let text = text_of_utf16(mem, blob, encoding, lossy);
+    text_to_string(mem, text)
+}
+
+fn utf16_roundtrip<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
+    unsafe {
+        let mut text = text_of_str(mem, "");
+        for str in &strs {
+            let str_obj = text_of_str(mem, str);
+            text = text_concat(mem, text, str_obj);
+        }
+
+        let expected = strs.concat();
+        let units: Vec<u16> = expected.encode_utf16().collect();
+
+        for encoding in [UTF16_LE, UTF16_BE, UTF16].iter() {
+            let blob = utf16_of_text(mem, text, *encoding);
+
+            let expected_bytes: Vec<u8> = match *encoding {
+                UTF16_LE => units
+                    .iter()
+                    .flat_map(|unit| unit.to_le_bytes().to_vec())
+                    .collect(),
+                UTF16_BE => units
+                    .iter()
+                    .flat_map(|unit| unit.to_be_bytes().to_vec())
+                    .collect(),
+                _ => std::iter::once(0xFEFF)
+                    .chain(units.iter().copied())
+                    .flat_map(|unit: u16| unit.to_be_bytes().to_vec())
+                    .collect(),
+            };
+            if blob_bytes(blob) != expected_bytes {
+                return Err(TestCaseError::Fail("utf16_of_text".into()));
+            }
+
+            let decoded = text_of_utf16(mem, blob, *encoding, false);
+            if text_to_string(mem, decoded) != expected {
+                return Err(TestCaseError::Fail("text_of_utf16".into()));
+            }
+        }
+
+        Ok(())
+    }
+}
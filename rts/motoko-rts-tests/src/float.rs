@@ -0,0 +1,707 @@
+use crate::assert_traps;
+use crate::memory::TestMemory;
+
+use motoko_rts::float::*;
+use motoko_rts::text::{text_concat, text_of_str};
+use motoko_rts::types::Words;
+
+use proptest::test_runner::{Config, TestCaseError, TestCaseResult, TestRunner};
+
+pub unsafe fn test() {
+    println!("Testing float formatting ...");
+
+    let pi = std::f64::consts::PI;
+
+    for (prec, expected) in &[
+        (0, "3"),
+        (1, "3.1"),
+        (4, "3.1416"),
+        (9, "3.141592654"),
+        (16, "3.1415926535897931"),
+        (17, "3.14159265358979312"),
+        (20, "3.14159265358979311600"),
+    ] {
+        assert_eq!(format(pi, *prec, FMT_FIXED), *expected);
+    }
+
+    for (prec, expected) in &[
+        (0, "3e+00"),
+        (1, "3.1e+00"),
+        (4, "3.1416e+00"),
+        (20, "3.14159265358979311600e+00"),
+    ] {
+        assert_eq!(format(pi, *prec, FMT_EXPONENT), *expected);
+    }
+
+    for (prec, expected) in &[
+        (0, "3"),
+        (1, "3"),
+        (4, "3.142"),
+        (16, "3.141592653589793"),
+        (20, "3.141592653589793116"),
+    ] {
+        assert_eq!(format(pi, *prec, FMT_GENERAL), *expected);
+    }
+
+    for (prec, expected) in &[
+        (0, "0x2p+1"),
+        (1, "0x1.9p+1"),
+        (4, "0x1.9220p+1"),
+        (9, "0x1.921fb5444p+1"),
+        (12, "0x1.921fb54442d2p+1"),
+        (13, "0x1.921fb54442d18p+1"),
+        (16, "0x1.921fb54442d18000p+1"),
+    ] {
+        assert_eq!(format(pi, *prec, FMT_HEX), *expected);
+    }
+
+    // Ties round to even
+    assert_eq!(format(0.125, 2, FMT_FIXED), "0.12");
+    assert_eq!(format(0.375, 2, FMT_FIXED), "0.38");
+    assert_eq!(format(2.5, 0, FMT_FIXED), "2");
+    assert_eq!(format(3.5, 0, FMT_FIXED), "4");
+    assert_eq!(format(0.5, 0, FMT_FIXED), "0");
+    assert_eq!(format(1.5, 0, FMT_EXPONENT), "2e+00");
+    assert_eq!(format(9.5, 0, FMT_EXPONENT), "1e+01");
+    assert_eq!(format(0.09375, 0, FMT_HEX), "0x2p-4");
+    assert_eq!(format(0.09375, 1, FMT_HEX), "0x1.8p-4");
+
+    // Signs, zeros and special values
+    assert_eq!(format(-0.0, 6, FMT_FIXED), "-0.000000");
+    assert_eq!(format(0.0, 6, FMT_EXPONENT), "0.000000e+00");
+    assert_eq!(format(-0.0, 6, FMT_GENERAL), "-0");
+    assert_eq!(format(0.0, 2, FMT_HEX), "0x0.00p+0");
+    assert_eq!(format(0.0, 0, FMT_SHORTEST), "0");
+    assert_eq!(format(-0.0, 0, FMT_SHORTEST), "-0");
+    for mode in FMT_FIXED..=FMT_SHORTEST {
+        assert_eq!(format(f64::INFINITY, 6, mode), "inf");
+        assert_eq!(format(f64::NEG_INFINITY, 6, mode), "-inf");
+        assert_eq!(format(f64::NAN.copysign(1.0), 6, mode), "nan");
+    }
+
+    // Extreme values and precisions
+    let min_subnormal = f64::from_bits(1);
+    assert_eq!(format(min_subnormal, 0, FMT_EXPONENT), "5e-324");
+    assert_eq!(format(min_subnormal, 0, FMT_HEX), "0x1p-1074");
+    assert_eq!(format(f64::from_bits(3), 1, FMT_HEX), "0x1.8p-1073");
+    assert_eq!(format(f64::MAX, 0, FMT_HEX), "0x2p+1023");
+    assert_eq!(format(f64::MAX, 4, FMT_GENERAL), "1.798e+308");
+    assert_eq!(format(1e308, 0, FMT_FIXED).len(), 309);
+    assert_eq!(
+        format(min_subnormal, 255, FMT_FIXED),
+        reference(min_subnormal, 255, FMT_FIXED)
+    );
+    assert_eq!(
+        format(min_subnormal, 255, FMT_EXPONENT),
+        reference(min_subnormal, 255, FMT_EXPONENT)
+    );
+    assert_eq!(
+        format(f64::MAX, 255, FMT_FIXED),
+        reference(f64::MAX, 255, FMT_FIXED)
+    );
+    assert_eq!(format(1.0, 255, FMT_HEX).len(), 2 + 1 + 1 + 255 + 3);
+
+    // Shortest representation
+    for (f, expected) in &[
+        (pi, "3.141592653589793"),
+        (0.1, "0.1"),
+        (0.3, "0.3"),
+        (0.1 + 0.2, "0.30000000000000004"),
+        (123.0, "123"),
+        (-1.5, "-1.5"),
+        (1e-4, "0.0001"),
+        (1e-5, "1e-05"),
+        (1e16, "10000000000000000"),
+        (1e17, "1e+17"),
+        (1e23, "1e+23"),
+        (2f64.powi(-1022), "2.2250738585072014e-308"),
+        (min_subnormal, "5e-324"),
+        (f64::MAX, "1.7976931348623157e+308"),
+    ] {
+        assert_eq!(format(*f, 0, FMT_SHORTEST), *expected);
+    }
+
+    // Powers of two have a smaller distance to the next float below, so the shortest
+    // representation may be above the float
+    for exp in -1074..1024 {
+        check_shortest(2f64.powi(exp)).unwrap();
+    }
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 1_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    proptest_runner
+        .run(
+            &(
+                proptest::num::f64::NORMAL
+                    | proptest::num::f64::SUBNORMAL
+                    | proptest::num::f64::ZERO,
+                0u32..40,
+                FMT_FIXED..FMT_HEX,
+            ),
+            |(f, prec, mode)| {
+                let formatted = format(f, prec, mode);
+                let expected = reference(f, prec, mode);
+                if formatted != expected {
+                    return Err(TestCaseError::Fail(
+                        format!("{:?} != {:?}", formatted, expected).into(),
+                    ));
+                }
+                check_shortest(f)
+            },
+        )
+        .unwrap();
+
+    // Bit patterns of floats with short decimal representations, for ties and near-ties
+    proptest_runner
+        .run(
+            &(
+                -100_000i64..100_000,
+                -30i32..30,
+                0u32..8,
+                FMT_FIXED..FMT_HEX,
+            ),
+            |(n, exp, prec, mode)| {
+                let f = n as f64 * 2f64.powi(exp);
+                let formatted = format(f, prec, mode);
+                let expected = reference(f, prec, mode);
+                if formatted != expected {
+                    return Err(TestCaseError::Fail(
+                        format!("{:?} != {:?}", formatted, expected).into(),
+                    ));
+                }
+                check_shortest(f)
+            },
+        )
+        .unwrap();
+
+    test_float32_shortest();
+    test_parsing();
+}
+
+unsafe fn test_float32_shortest() {
+    for (f, expected) in &[
+        (1.1f32, "1.1"),
+        (0.1, "0.1"),
+        (-2.5, "-2.5"),
+        (16777216.0, "16777216"),
+        (f32::from_bits(1), "1e-45"),
+        (f32::MIN_POSITIVE, "1.1754944e-38"),
+        (f32::MAX, "3.4028235e+38"),
+        (-0.0, "-0"),
+        (f32::INFINITY, "inf"),
+        (f32::NAN, "nan"),
+    ] {
+        assert_eq!(format_f32(*f), *expected);
+    }
+
+    for exp in -149..128 {
+        check_shortest_f32(2f32.powi(exp)).unwrap();
+    }
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 10_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    proptest_runner
+        .run(
+            &(proptest::num::f32::NORMAL
+                | proptest::num::f32::SUBNORMAL
+                | proptest::num::f32::ZERO),
+            |f| check_shortest_f32(f),
+        )
+        .unwrap();
+}
+
+unsafe fn test_parsing() {
+    println!("Testing float parsing ...");
+
+    for (text, expected) in &[
+        ("0", 0.0),
+        ("-0", -0.0),
+        ("+1", 1.0),
+        ("1.", 1.0),
+        ("1.5", 1.5),
+        ("-001.250", -1.25),
+        ("0.000_125", 0.000125),
+        ("1_000.000_5", 1000.0005),
+        ("1e3", 1000.0),
+        ("1.E+3", 1000.0),
+        ("25e-1", 2.5),
+        ("1_0e1_0", 1e11),
+        ("0.1", 0.1),
+        ("0.30000000000000004", 0.1 + 0.2),
+        ("9007199254740993", 9007199254740992.0),
+        ("9007199254740995", 9007199254740996.0),
+        ("1.7976931348623157e308", f64::MAX),
+        ("1.7976931348623158e308", f64::MAX),
+        ("1.7976931348623159e308", f64::INFINITY),
+        ("1e400", f64::INFINITY),
+        ("2.2250738585072014e-308", 2f64.powi(-1022)),
+        ("4.9406564584124654e-324", f64::from_bits(1)),
+        ("2.4703282292062328e-324", f64::from_bits(1)),
+        ("2.4703282292062327e-324", 0.0),
+        ("1e-400", 0.0),
+        ("0e999999999999", 0.0),
+        ("0x0", 0.0),
+        ("0x1p-2", 0.25),
+        ("-0X1.8P1", -3.0),
+        ("0xA_Bp0", 171.0),
+        ("0x1e3", 483.0),
+        ("0x1.fffffffffffff8p0", 2.0),
+        ("0x1.fffffffffffff7p0", 2.0 - 2f64.powi(-52)),
+        ("0x1.fffffffffffffp1023", f64::MAX),
+        ("0x1p1024", f64::INFINITY),
+        ("0x1p-1074", f64::from_bits(1)),
+        ("0x1p-1075", 0.0),
+        ("0x1.0000000000001p-1075", f64::from_bits(1)),
+        ("0x3p-1075", f64::from_bits(2)),
+        ("0x0.000000000000000000001p-990", f64::from_bits(1)),
+        ("inf", f64::INFINITY),
+        ("-Infinity", f64::NEG_INFINITY),
+    ] {
+        let parsed = parse(text);
+        assert_eq!(
+            parsed.to_bits(),
+            expected.to_bits(),
+            "{:?} parsed as {:e}",
+            text,
+            parsed
+        );
+    }
+
+    assert!(parse("nan").is_nan());
+    assert!(parse("-NaN").is_sign_negative());
+
+    for (text, error) in &[
+        ("", "missing digits at offset 0"),
+        ("-", "missing digits at offset 1"),
+        ("--1", "invalid character at offset 1"),
+        (".5", "invalid character at offset 0"),
+        ("1.5x", "invalid character at offset 3"),
+        ("1 ", "invalid character at offset 1"),
+        ("1e", "missing exponent digits at offset 2"),
+        ("1e+", "missing exponent digits at offset 3"),
+        ("1e1.5", "invalid character at offset 3"),
+        ("1p3", "invalid character at offset 1"),
+        ("_1", "misplaced separator at offset 0"),
+        ("1__0", "misplaced separator at offset 2"),
+        ("1_.0", "misplaced separator at offset 2"),
+        ("1_", "misplaced separator at offset 2"),
+        ("1e_3", "misplaced separator at offset 2"),
+        ("0x", "missing digits at offset 2"),
+        ("0x.8", "missing digits at offset 2"),
+        ("0xp1", "missing digits at offset 2"),
+        ("0x1p", "missing exponent digits at offset 4"),
+        ("00x1", "invalid character at offset 2"),
+        ("0_x1", "misplaced separator at offset 2"),
+        ("inx", "invalid character at offset 2"),
+        ("in", "unexpected end of text at offset 2"),
+        ("nan1", "invalid character at offset 3"),
+        ("infinityy", "invalid character at offset 8"),
+    ] {
+        assert_traps(
+            || unsafe {
+                parse(text);
+            },
+            &format!("float_of_text: {}", error),
+        );
+    }
+
+    // Exact halfway points, even with more digits than the parser keeps
+    for f in &[
+        0.0,
+        f64::from_bits(1),
+        2f64.powi(-1022) - f64::from_bits(1),
+        2f64.powi(-1022),
+        1.0,
+        9007199254740992.0,
+        f64::MAX / 2.0,
+    ] {
+        check_midpoint(*f).unwrap();
+    }
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 1_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    // Formatting round-trips through parsing
+    proptest_runner
+        .run(&proptest::num::u64::ANY, |bits| {
+            let f = f64::from_bits(bits);
+            for (prec, mode) in &[
+                (0, FMT_SHORTEST),
+                (16, FMT_EXPONENT),
+                (17, FMT_GENERAL),
+                (13, FMT_HEX),
+                (20, FMT_HEX),
+            ] {
+                let formatted = format(f, *prec, *mode);
+                let parsed = parse(&formatted);
+                if parsed.to_bits() != bits && !(f.is_nan() && parsed.is_nan()) {
+                    return Err(TestCaseError::Fail(
+                        format!("{:?} parsed as {:e}, expected {:e}", formatted, parsed, f).into(),
+                    ));
+                }
+            }
+            if f.is_finite() && f != f64::MAX && f != -f64::MAX {
+                check_midpoint(f.abs())?;
+            }
+            Ok(())
+        })
+        .unwrap();
+
+    // Decimal literals agree with the standard library
+    proptest_runner
+        .run(
+            &(proptest::num::u64::ANY, 0usize..21, -350i32..330),
+            |(mant, point, exp)| {
+                let digits = mant.to_string();
+                let point = std::cmp::min(point, digits.len());
+                let text = format!("{}.{}e{}", &digits[..point], &digits[point..], exp);
+                let text = if point == 0 {
+                    format!("0{}", text)
+                } else {
+                    text
+                };
+                let parsed = parse(&text);
+                let expected = text.parse::<f64>().unwrap();
+                if parsed.to_bits() != expected.to_bits() {
+                    return Err(TestCaseError::Fail(
+                        format!("{:?} parsed as {:e}, expected {:e}", text, parsed, expected)
+                            .into(),
+                    ));
+                }
+                Ok(())
+            },
+        )
+        .unwrap();
+}
+
+/// Checks parsing of the halfway point between a non-negative float and the next float, and of
+/// numbers just above and below it, with many trailing digits
+unsafe fn check_midpoint(f: f64) -> TestCaseResult {
+    let next = f64::from_bits(f.to_bits() + 1);
+    let even = if f.to_bits() % 2 == 0 { f } else { next };
+    let (mid, exp) = midpoint(f);
+
+    let padding = "0".repeat(100);
+    let cases = [
+        (format!("{}e{}", mid, exp), even),
+        (format!("-{}e{}", mid, exp), -even),
+        (format!("{}{}1e{}", mid, padding, exp - 101), next),
+        (
+            format!("{}{}9e{}", decrement(&mid), "9".repeat(100), exp - 101),
+            f,
+        ),
+    ];
+
+    for (text, expected) in cases.iter() {
+        let parsed = parse(text);
+        if parsed.to_bits() != expected.to_bits() {
+            return Err(TestCaseError::Fail(
+                format!("{:?} parsed as {:e}, expected {:e}", text, parsed, expected).into(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The exact halfway point between a non-negative float and the next float, as
+/// `digits * 10 ** exp`
+fn midpoint(f: f64) -> (String, i32) {
+    let (a, a_exp) = exact_digits(f);
+    let (b, b_exp) = exact_digits(f64::from_bits(f.to_bits() + 1));
+
+    // Scale both to integers, with an extra zero digit so that halving the sum is exact
+    let exp = std::cmp::min(a_exp + 1 - a.len() as i32, b_exp + 1 - b.len() as i32) - 1;
+    let scaled = |digits: &[u8], digits_exp: i32| -> Vec<u8> {
+        let mut scaled = digits.to_vec();
+        scaled.resize((digits_exp + 1 - exp) as usize, 0);
+        scaled.reverse();
+        scaled
+    };
+    let (a, b) = (scaled(&a, a_exp), scaled(&b, b_exp));
+
+    // Add, least significant digit first
+    let mut sum = vec![];
+    let mut carry = 0;
+    for i in 0..std::cmp::max(a.len(), b.len()) + 1 {
+        let digit = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+        sum.push(digit % 10);
+        carry = digit / 10;
+    }
+
+    // Halve, most significant digit first
+    let mut half = String::new();
+    let mut rem = 0;
+    for digit in sum.iter().rev() {
+        let n = rem * 10 + digit;
+        if !half.is_empty() || n / 2 != 0 {
+            half.push(char::from(b'0' + n / 2));
+        }
+        rem = n % 2;
+    }
+
+    (half, exp)
+}
+
+/// Subtracts one from a decimal number
+fn decrement(digits: &str) -> String {
+    let mut digits = digits.as_bytes().to_vec();
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'0' {
+            *digit = b'9';
+        } else {
+            *digit -= 1;
+            break;
+        }
+    }
+    String::from_utf8(digits).unwrap()
+}
+
+/// Parses a text made from chunks of `s`, to exercise concatenations
+unsafe fn parse(s: &str) -> f64 {
+    let mut heap = TestMemory::new(Words(64 * 1024));
+    let mut text = text_of_str(&mut heap, "");
+    for chunk in s.as_bytes().chunks(5) {
+        let chunk = text_of_str(&mut heap, std::str::from_utf8(chunk).unwrap());
+        text = text_concat(&mut heap, text, chunk);
+    }
+    float_of_text(text)
+}
+
+unsafe fn format(f: f64, prec: u32, mode: u32) -> String {
+    let mut heap = TestMemory::new(Words(64 * 1024));
+    // Arguments are Nat8 in vanilla representation
+    let text = float_fmt(&mut heap, f, prec << 24, mode << 24);
+    let blob = text.as_blob();
+    let bytes = std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+unsafe fn format_f32(f: f32) -> String {
+    let mut heap = TestMemory::new(Words(64 * 1024));
+    let text = float32_fmt_shortest(&mut heap, f);
+    let blob = text.as_blob();
+    let bytes = std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+/// Like `check_shortest`, for an `f32`. Only the length is compared to the standard library.
+unsafe fn check_shortest_f32(f: f32) -> TestCaseResult {
+    let formatted = format_f32(f);
+
+    if formatted.parse::<f32>().map(f32::to_bits) != Ok(f.to_bits()) {
+        return Err(TestCaseError::Fail(
+            format!("{:?} does not round-trip to {:e}", formatted, f).into(),
+        ));
+    }
+
+    let std_digits = significant_digits(&format!("{:e}", f));
+    if significant_digits(&formatted).len() != std_digits.len() {
+        return Err(TestCaseError::Fail(
+            format!(
+                "{:?} is not the shortest representation of {:e}",
+                formatted, f
+            )
+            .into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the shortest representation converts back to the same float, and has the length of
+/// the shortest representation from the standard library
+unsafe fn check_shortest(f: f64) -> TestCaseResult {
+    let formatted = format(f, 0, FMT_SHORTEST);
+
+    if formatted.parse::<f64>().map(f64::to_bits) != Ok(f.to_bits()) {
+        return Err(TestCaseError::Fail(
+            format!("{:?} does not round-trip to {:e}", formatted, f).into(),
+        ));
+    }
+
+    // The standard library finds the shortest length, but breaks ties differently. We want the
+    // closest representation, rounded half to even, unless only the other neighbour round-trips.
+    let std_digits = significant_digits(&format!("{:e}", f));
+    let (exact, exp) = exact_digits(f);
+    let (rounded, rounded_exp) = round_digits(&exact, exp, std_digits.len() as i32);
+    let rounded: String = rounded.iter().map(|d| char::from(b'0' + d)).collect();
+    let expected =
+        match format!("{}e{}", rounded, rounded_exp + 1 - rounded.len() as i32).parse::<f64>() {
+            Ok(g) if g == f.abs() => significant_digits(&rounded),
+            _ => std_digits,
+        };
+
+    if significant_digits(&formatted) != expected {
+        return Err(TestCaseError::Fail(
+            format!(
+                "{:?} is not the shortest representation of {:e}",
+                formatted, f
+            )
+            .into(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn significant_digits(s: &str) -> String {
+    let mantissa = s
+        .split('e')
+        .next()
+        .unwrap()
+        .replace(|c| c == '-' || c == '.', "");
+    mantissa
+        .trim_start_matches('0')
+        .trim_end_matches('0')
+        .to_string()
+}
+
+/// Reference implementation of the decimal modes, based on the exact decimal expansion from the
+/// standard library
+fn reference(f: f64, prec: u32, mode: u32) -> String {
+    let sign = if f.is_sign_negative() { "-" } else { "" };
+    let (digits, exp) = exact_digits(f);
+
+    let body = match mode {
+        FMT_FIXED => ref_fixed(&digits, exp, prec),
+        FMT_EXPONENT => ref_exponent(&digits, exp, prec),
+        FMT_GENERAL => {
+            let p = std::cmp::max(prec, 1);
+            let (_, rounded_exp) = round_digits(&digits, exp, p as i32);
+            if rounded_exp < p as i32 && rounded_exp >= -4 {
+                strip_fraction_zeros(&ref_fixed(
+                    &digits,
+                    exp,
+                    (p as i32 - 1 - rounded_exp) as u32,
+                ))
+            } else {
+                let formatted = ref_exponent(&digits, exp, p - 1);
+                let e = formatted.find('e').unwrap();
+                strip_fraction_zeros(&formatted[..e]) + &formatted[e..]
+            }
+        }
+        _ => unreachable!(),
+    };
+
+    format!("{}{}", sign, body)
+}
+
+fn ref_fixed(digits: &[u8], exp: i32, prec: u32) -> String {
+    // Rounding to `exp + 1 + prec` digits leaves `prec` digits after the point
+    let (rounded, rounded_exp) = round_digits(digits, exp, exp + 1 + prec as i32);
+
+    // The rounded number as an integer, scaled by 10 ** prec
+    let mut int: String = rounded.iter().map(|d| char::from(b'0' + d)).collect();
+    let scale = rounded_exp + 1 - rounded.len() as i32 + prec as i32;
+    for _ in 0..scale {
+        int.push('0');
+    }
+    while int.len() < prec as usize + 1 {
+        int.insert(0, '0');
+    }
+
+    let int_len = int.len() - prec as usize;
+    if prec == 0 {
+        int
+    } else {
+        format!("{}.{}", &int[..int_len], &int[int_len..])
+    }
+}
+
+fn ref_exponent(digits: &[u8], exp: i32, prec: u32) -> String {
+    let (mut rounded, mut rounded_exp) = round_digits(digits, exp, prec as i32 + 1);
+    if rounded.is_empty() {
+        rounded_exp = 0;
+    }
+    rounded.resize(prec as usize + 1, 0);
+
+    let mut s = String::new();
+    s.push(char::from(b'0' + rounded[0]));
+    if prec != 0 {
+        s.push('.');
+        s.extend(rounded[1..].iter().map(|d| char::from(b'0' + d)));
+    }
+    s.push_str(&format!(
+        "e{}{:02}",
+        if rounded_exp < 0 { '-' } else { '+' },
+        rounded_exp.abs()
+    ));
+    s
+}
+
+fn strip_fraction_zeros(s: &str) -> String {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+/// Digits of the exact decimal expansion of `|f|`, with the exponent of the first digit. Zero has
+/// no digits.
+fn exact_digits(f: f64) -> (Vec<u8>, i32) {
+    // Every float has at most 767 significant decimal digits
+    let s = format!("{:.800e}", f.abs());
+    let e = s.find('e').unwrap();
+    let exp = s[e + 1..].parse::<i32>().unwrap();
+    let mut digits: Vec<u8> = s[..e]
+        .bytes()
+        .filter(|b| *b != b'.')
+        .map(|b| b - b'0')
+        .collect();
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    (digits, exp)
+}
+
+/// Rounds digits with exponent `exp` of the first digit to `n` digits, half to even. Returns the
+/// rounded digits and the exponent of their first digit.
+fn round_digits(digits: &[u8], exp: i32, n: i32) -> (Vec<u8>, i32) {
+    if n < 0 || digits.is_empty() {
+        return (vec![], exp);
+    }
+
+    let n = n as usize;
+    if n >= digits.len() {
+        return (digits.to_vec(), exp);
+    }
+
+    let mut kept = digits[..n].to_vec();
+    let rest = &digits[n..];
+    let above_half = rest[0] > 5 || (rest[0] == 5 && rest[1..].iter().any(|d| *d != 0));
+    let half = rest[0] == 5 && rest[1..].iter().all(|d| *d == 0);
+    let odd = kept.last().map_or(false, |d| d % 2 == 1);
+
+    if above_half || (half && odd) {
+        // Increment, with carry
+        let mut i = kept.len();
+        loop {
+            if i == 0 {
+                kept.insert(0, 1);
+                return (kept, exp + 1);
+            }
+            i -= 1;
+            if kept[i] == 9 {
+                kept[i] = 0;
+            } else {
+                kept[i] += 1;
+                break;
+            }
+        }
+    }
+
+    (kept, exp)
+}
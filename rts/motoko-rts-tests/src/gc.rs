@@ -6,7 +6,7 @@
 // To convert an offset into an address, add heap array's address to the offset.
 
 mod heap;
-mod utils;
+pub mod utils;
 
 use heap::MotokoHeap;
 use utils::{get_scalar_value, read_word, unskew_pointer, ObjectIdx, GC, GC_IMPLS, WORD_SIZE};
@@ -315,6 +315,8 @@ impl GC {
         let heap_base = heap.heap_base_address() as u32;
         let static_roots = skew(heap.static_root_array_address());
         let closure_table_ptr_address = heap.closure_table_ptr_address() as *mut SkewedPtr;
+        // No text indices in the test heaps
+        let mut text_index_cache = SkewedPtr(0);
 
         let heap_1 = heap.clone();
         let heap_2 = heap.clone();
@@ -331,6 +333,7 @@ impl GC {
                         move |hp| heap_2.set_heap_ptr_address(hp as usize),
                         static_roots,
                         closure_table_ptr_address,
+                        &mut text_index_cache,
                         // note_live_size
                         |_live_size| {},
                         // note_reclaimed
@@ -350,6 +353,7 @@ impl GC {
                         move |hp| heap_2.set_heap_ptr_address(hp as usize),
                         static_roots,
                         closure_table_ptr_address,
+                        &mut text_index_cache,
                         // note_live_size
                         |_live_size| {},
                         // note_reclaimed
@@ -0,0 +1,673 @@
+mod candid;
+mod decode;
+mod fields;
+mod pretty;
+mod subtype;
+
+use crate::memory::TestMemory;
+use crate::{assert_idl_traps, assert_traps};
+
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::budget::{self, Limits};
+use motoko_rts::idl::*;
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts::types::Words;
+
+pub unsafe fn test() {
+    println!("Testing IDL decoding ...");
+
+    test_valid_messages();
+    test_invalid_messages();
+    test_deep_nesting();
+    test_budget();
+    test_find_field();
+
+    decode::test();
+    fields::test();
+    pretty::test();
+    subtype::test();
+}
+
+/// Messages that are decoded by skipping all arguments, from `test/run-deser/*.bin` and the
+/// `//CALL` lines of `test/run-drun/idl-*.mo`, and other hand-written ones
+const VALID: &[(&str, &str)] = &[
+    ("empty argument list", "4449444c0000"),
+    // run-deser/five.bin
+    (
+        "five texts",
+        "4449444c00057171717171034f6e650354776f05546872656504466f75720446697665",
+    ),
+    // run-deser/annot-lit.bin
+    (
+        "fixed-width numbers",
+        "4449444c00107b7b77777a7a767679797575787874740\
+         0ff807f0000ffff0080ff7f00000000ffffffff00000080ffffff7f0000000000000000ffffffffffffffff\
+         0000000000000080ffffffffffffff7f",
+    ),
+    // run-deser/future.bin
+    (
+        "future type",
+        "4449444c016602abcd0200710400deadbeef03466f6f",
+    ),
+    // run-drun/idl-any.mo
+    ("null", "4449444c00027f7103466f6f"),
+    ("bool", "4449444c00027e710103466f6f"),
+    ("nat", "4449444c00027d718080800803466f6f"),
+    ("int", "4449444c00027c718080800803466f6f"),
+    ("nat8", "4449444c00027b71ab03466f6f"),
+    ("nat16", "4449444c00027a71abcd03466f6f"),
+    ("nat32", "4449444c00027971deadbeef03466f6f"),
+    ("nat64", "4449444c00027871deadbeefcaffee6603466f6f"),
+    ("int8", "4449444c00027771ab03466f6f"),
+    ("int16", "4449444c00027671abcd03466f6f"),
+    ("int32", "4449444c00027571deadbeef03466f6f"),
+    ("int64", "4449444c00027471deadbeefcaffee6603466f6f"),
+    ("float32", "4449444c00027371deadbeef03466f6f"),
+    ("float64", "4449444c00027271deadbeefcaffee6603466f6f"),
+    ("text", "4449444c0002717103466f6f03466f6f"),
+    ("reserved", "4449444c0002707103466f6f"),
+    ("opaque principal", "4449444c000268710003466f6f"),
+    ("principal", "4449444c000268710104deadbeef03466f6f"),
+    ("opt none", "4449444c016e710200710003466f6f"),
+    ("opt some", "4449444c016e710200710103466f6f03466f6f"),
+    ("empty vec", "4449444c016d710200710003466f6f"),
+    ("vec", "4449444c016d71020071020003466f6f03466f6f"),
+    ("empty record", "4449444c016c0002007103466f6f"),
+    ("record", "4449444c016c01800175020071deadbeef03466f6f"),
+    (
+        "record with two fields",
+        "4449444c016c02007180017502007103e29883deadbeef03466f6f",
+    ),
+    ("variant", "4449444c016b0180017502007100deadbeef03466f6f"),
+    (
+        "variant, first field",
+        "4449444c016b0200718001750200710003e2988303466f6f",
+    ),
+    (
+        "variant, second field",
+        "4449444c016b02007180017502007101deadbeef03466f6f",
+    ),
+    (
+        "future value",
+        "4449444c016602abcd0200710400deadbeef03466f6f",
+    ),
+    // run-drun/idl-record.mo
+    ("pair", "4449444c016c020071017c010004486579212a"),
+    (
+        "record with hashed fields",
+        "4449444c016c02b99adecb0171f1fee18d037c010004486579212a",
+    ),
+    // run-drun/idl-func.mo
+    ("func type in table", "4449444c026e016a017c0000010000"),
+    // Others
+    (
+        "func reference",
+        "4449444c016a0000000100010104deadbeef03666f6f",
+    ),
+    ("func with annotation", "4449444c016a0000010201000101000166"),
+    (
+        "func with composite_query annotation",
+        "4449444c016a0000010301000101000166",
+    ),
+    ("query func with results", "4449444c016a00017c010100"),
+    ("oneway func with results", "4449444c016a00017c010200"),
+    (
+        "func with duplicate annotations",
+        "4449444c016a000002010100",
+    ),
+    ("query and oneway func", "4449444c016a000002010200"),
+    ("oneway func with arguments", "4449444c016a017c00010200"),
+    ("service reference", "4449444c01690001000104deadbeef"),
+    (
+        "service with methods",
+        "4449444c0269020161010162016a00000001000100",
+    ),
+    (
+        "service method names with common prefix",
+        "4449444c026902016101026162016a00000000",
+    ),
+    (
+        "service with a composite_query method",
+        "4449444c0269010161016a000001030100010100",
+    ),
+    (
+        "record in a vector in the same record",
+        "4449444c026c0100016d0001000100",
+    ),
+    (
+        "last field with max tag",
+        "4449444c016c020071ffffffff0f7f010003466f6f",
+    ),
+];
+
+/// Messages that only decode in extended mode, used for stable variables
+const VALID_EXTENDED: &[(&str, &str)] = &[
+    (
+        "mutable box contents",
+        "4449444c010171010000000000000000000003466f6f",
+    ),
+    ("mutable box reference", "4449444c01017101000100000000"),
+];
+
+/// Messages with their IDL error message, covering all `idl_trap_with` calls in `idl.rs` and
+/// `buf.rs`
+const INVALID: &[(&str, &str, &str)] = &[
+    (
+        "empty input",
+        "",
+        "empty input. Expected Candid-encoded argument, but received a zero-length argument",
+    ),
+    ("wrong magic bytes", "4449445800", "missing magic bytes"),
+    ("short magic bytes", "444944", "word read out of buffer"),
+    ("type table too large", "4449444c0500", "too many types"),
+    (
+        "type table size overflow",
+        "4449444c8080808010",
+        "leb128_decode: overflow",
+    ),
+    (
+        "type overflow",
+        "4449444c01ffffffffff0100",
+        "sleb128_decode: overflow",
+    ),
+    (
+        "type index in table",
+        "4449444c01000000",
+        "illegal type table",
+    ),
+    (
+        "alias in non-extended mode",
+        "4449444c0101710100",
+        "illegal type table",
+    ),
+    (
+        "primitive type in table",
+        "4449444c017100",
+        "primitive type in type table",
+    ),
+    (
+        "opt argument out of range",
+        "4449444c016e0500",
+        "invalid type argument",
+    ),
+    (
+        "vec argument not a type",
+        "4449444c016d6500",
+        "invalid type argument",
+    ),
+    (
+        "main type out of range",
+        "4449444c000100",
+        "invalid type argument",
+    ),
+    (
+        "record fields out of order",
+        "4449444c016c0201710071000000",
+        "variant or record tag out of order",
+    ),
+    (
+        "duplicate record fields",
+        "4449444c016c0200710071000000",
+        "variant or record tag out of order",
+    ),
+    (
+        "variant tag after max tag",
+        "4449444c016b02ffffffff0f7100710000",
+        "variant or record tag out of order",
+    ),
+    (
+        "func with unknown annotation",
+        "4449444c016a000002ff0401000101000166",
+        "func annotation not within 1..3",
+    ),
+    (
+        "service with a method of unknown annotation",
+        "4449444c0269010161016a000001800100010100",
+        "func annotation not within 1..3",
+    ),
+    (
+        "truncated func annotations",
+        "4449444c016a00000201",
+        "byte read out of buffer",
+    ),
+    (
+        "service methods out of order",
+        "4449444c026902016201016101",
+        "service method names out of order",
+    ),
+    (
+        "duplicate service methods",
+        "4449444c026902016101016101",
+        "service method names out of order",
+    ),
+    (
+        "service method prefix out of order",
+        "4449444c02690202616201016101",
+        "service method names out of order",
+    ),
+    (
+        "service method of primitive type",
+        "4449444c016901016171000000",
+        "service method arg not a constructor type",
+    ),
+    (
+        "service method of non-func type",
+        "4449444c0269010161016e7100",
+        "service method arg not a function type",
+    ),
+    (
+        "service method of future type",
+        "4449444c02690101610166000000",
+        "service method arg not a function type",
+    ),
+    (
+        "service method of service type",
+        "4449444c01690101610000",
+        "service method arg not a function type",
+    ),
+    (
+        "truncated type table",
+        "4449444c016c01",
+        "byte read out of buffer",
+    ),
+    (
+        "truncated method name",
+        "4449444c0169010561000000",
+        "advance out of buffer",
+    ),
+    (
+        "truncated nat",
+        "4449444c00017d80",
+        "byte read out of buffer",
+    ),
+    (
+        "truncated nat64",
+        "4449444c0001780102",
+        "advance out of buffer",
+    ),
+    (
+        "truncated text",
+        "4449444c00017105666f",
+        "advance out of buffer",
+    ),
+    (
+        "truncated future value",
+        "4449444c01660001000400dead",
+        "advance out of buffer",
+    ),
+    (
+        "invalid bool",
+        "4449444c00017e02",
+        "skip_any: byte tag not 0 or 1",
+    ),
+    (
+        "invalid opt tag",
+        "4449444c016e7f010002",
+        "skip_any: byte tag not 0 or 1",
+    ),
+    (
+        "empty value",
+        "4449444c00016f",
+        "skip_any: encountered empty",
+    ),
+    (
+        "recursive record",
+        "4449444c016c0100000100",
+        "skip_any: recursive record",
+    ),
+    (
+        "mutually recursive records",
+        "4449444c026c0100016c0100000100",
+        "skip_any: recursive record",
+    ),
+    (
+        "variant tag too large",
+        "4449444c016b01007f010001",
+        "skip_any: variant tag too large",
+    ),
+    (
+        "opaque func reference",
+        "4449444c016a000000010000",
+        "skip_any: skipping references",
+    ),
+    (
+        "opaque service reference in func",
+        "4449444c016a00000001000100",
+        "skip_any: skipping references",
+    ),
+    (
+        "opaque service reference",
+        "4449444c016900010000",
+        "skip_any: skipping references",
+    ),
+    (
+        "future value with references",
+        "4449444c01660001000001",
+        "skip_any: skipping references",
+    ),
+];
+
+unsafe fn test_valid_messages() {
+    for (name, message) in VALID {
+        decode(name, &hex(message), false);
+        // Everything valid is also valid in extended mode
+        decode(name, &hex(message), true);
+    }
+
+    for (name, message) in VALID_EXTENDED {
+        decode(name, &hex(message), true);
+    }
+}
+
+unsafe fn test_invalid_messages() {
+    for (name, message, error) in INVALID {
+        let bytes = hex(message);
+        assert_idl_traps(|| decode(name, &bytes, false), error);
+    }
+
+    // Method names and text values are checked for UTF-8, which is an RTS error
+    let method_name = hex("4449444c01690101800000");
+    assert_traps(
+        || decode("invalid method name", &method_name, false),
+        "utf8_validate: string is not UTF-8",
+    );
+
+    let text = hex("4449444c00017101ff");
+    assert_traps(
+        || decode("invalid text", &text, false),
+        "utf8_validate: string is not UTF-8",
+    );
+
+    // Unknown primitive types are rejected by `parse_idl_header`, but `skip_any` also checks
+    assert_idl_traps(
+        || {
+            let mut mem = TestMemory::new(Words(1024));
+            let bytes = [0u8];
+            let mut buf = buf_of(&bytes);
+            skip_any(&mut mem, &mut buf, std::ptr::null_mut(), -30, 0);
+        },
+        "skip_any: unknown prim",
+    );
+}
+
+/// `skip_any` allows up to 100 nested records
+unsafe fn test_deep_nesting() {
+    // Type `i` is a record with a single field of type `i + 1`, the last one has a null field
+    let nested = |n_records: u8| {
+        let mut bytes = hex("4449444c");
+        bytes.push(n_records);
+        for i in 1..n_records {
+            bytes.extend_from_slice(&[0x6c, 0x01, 0x00]);
+            // SLEB128 of the next type index
+            if i < 64 {
+                bytes.push(i);
+            } else {
+                bytes.extend_from_slice(&[i | 0x80, 0x00]);
+            }
+        }
+        bytes.extend_from_slice(&hex("6c01007f0100"));
+        bytes
+    };
+
+    decode("100 nested records", &nested(100), false);
+
+    let too_deep = nested(101);
+    assert_idl_traps(
+        || decode("101 nested records", &too_deep, false),
+        "skip_any: too deeply nested record",
+    );
+
+    // Values nested through `opt` and `variant` are skipped without recursion. Type 0 is a list
+    // `opt record { 0 : nat8; 1 : 0 }`, the value has 4,000 elements.
+    let mut list = hex("4449444c026e016c02007b01000100");
+    for _ in 0..4_000 {
+        list.extend_from_slice(&[1, 42]);
+    }
+    list.push(0);
+    decode("long list", &list, false);
+
+    // Type 0 is `variant { 0 : null; 1 : 0 }`, the value has 9,999 nested variants
+    let mut variant = hex("4449444c016b02007f01000100");
+    variant.extend(std::iter::repeat(1).take(9_998));
+    variant.push(0);
+    decode("nested variants", &variant, false);
+}
+
+unsafe fn test_budget() {
+    // Vectors of zero-sized values
+    decode("vec null", &hex("4449444c016d7f0100a08d06"), false);
+    assert_idl_traps(
+        || decode("long vec null", &hex("4449444c016d7f0100a18d06"), false),
+        "decoding budget exceeded: vector of zero-sized values too long",
+    );
+    assert_idl_traps(
+        || {
+            decode(
+                "long vec of empty records",
+                &hex("4449444c026d016c000100a18d06"),
+                false,
+            )
+        },
+        "decoding budget exceeded: vector of zero-sized values too long",
+    );
+
+    // `vec (vec null)` with 30 vectors of 100,000 nulls
+    let mut nested = hex("4449444c026d016d7f01001e");
+    for _ in 0..30 {
+        nested.extend_from_slice(&hex("a08d06"));
+    }
+    assert_idl_traps(
+        || decode("nested vec null", &nested, false),
+        "decoding budget exceeded: too many values",
+    );
+
+    // The number of values depends on the message size. `vec nat8` with 10 elements has 11
+    // values in a 20 byte message.
+    let vec_nat8 = hex("4449444c016d7b01000a00010203040506070809");
+    budget::set_limits(Limits {
+        values_per_byte: 0,
+        values_base: 11,
+        ..Limits::DEFAULT
+    });
+    decode("vec nat8", &vec_nat8, false);
+    budget::set_limits(Limits {
+        values_per_byte: 0,
+        values_base: 10,
+        ..Limits::DEFAULT
+    });
+    assert_idl_traps(
+        || decode("vec nat8", &vec_nat8, false),
+        "decoding budget exceeded: too many values",
+    );
+    budget::set_limits(Limits {
+        values_per_byte: 1,
+        values_base: 0,
+        ..Limits::DEFAULT
+    });
+    decode("vec nat8", &vec_nat8, false);
+    budget::set_limits(Limits::DEFAULT);
+
+    // Type 0 is `vec 0`, the value has `n` nested vectors
+    let nested = |n: usize| {
+        let mut bytes = hex("4449444c016d000100");
+        bytes.extend(std::iter::repeat(1).take(n - 1));
+        bytes.push(0);
+        bytes
+    };
+    decode("10000 nested vectors", &nested(10_000), false);
+    let too_deep = nested(10_001);
+    assert_idl_traps(
+        || decode("10001 nested vectors", &too_deep, false),
+        "decoding budget exceeded: values nested too deeply",
+    );
+
+    budget::set_limits(Limits {
+        max_depth: 100,
+        ..Limits::DEFAULT
+    });
+    decode("100 nested vectors", &nested(100), false);
+    let too_deep = nested(101);
+    assert_idl_traps(
+        || decode("101 nested vectors", &too_deep, false),
+        "decoding budget exceeded: values nested too deeply",
+    );
+
+    // The nesting depth is bounded by the size of the stack used for skipping
+    budget::set_limits(Limits {
+        max_depth: budget::MAX_DEPTH + 1,
+        ..Limits::DEFAULT
+    });
+    assert_eq!(budget::limits().max_depth, budget::MAX_DEPTH);
+    budget::set_limits(Limits::DEFAULT);
+
+    // Limits can be set from generated code
+    budget::idl_set_limits(0, 1, 0, 1);
+    assert_eq!(
+        budget::limits(),
+        Limits {
+            values_per_byte: 0,
+            values_base: 1,
+            max_zero_sized_vec_len: 0,
+            max_depth: 1
+        }
+    );
+    budget::set_limits(Limits::DEFAULT);
+}
+
+unsafe fn test_find_field() {
+    // A record with fields 1 : nat8, 3 : text, 5 : nat16, followed by a nat8
+    let bytes = hex("4449444c016c03017b0371057a02007b2a03466f6f34121f");
+
+    // Find an existing field after skipping others
+    let mut mem = TestMemory::new(Words(1024));
+    let (mut buf, typtbl, mut tb, mut n) = record_fields(&mut mem, &bytes);
+    assert_eq!(
+        find_field(&mut mem, &mut tb, &mut buf, typtbl, 3, &mut n),
+        1
+    );
+    assert_eq!(n, 1);
+    assert_eq!(sleb128_decode(&mut tb), IDL_PRIM_text);
+    skip_any(&mut mem, &mut buf, typtbl, IDL_PRIM_text, 0);
+
+    // A missing field between existing ones leaves the next field to be read
+    assert_eq!(
+        find_field(&mut mem, &mut tb, &mut buf, typtbl, 4, &mut n),
+        0
+    );
+    assert_eq!(n, 1);
+    assert_eq!(
+        find_field(&mut mem, &mut tb, &mut buf, typtbl, 5, &mut n),
+        1
+    );
+    assert_eq!(n, 0);
+    assert_eq!(sleb128_decode(&mut tb), IDL_PRIM_nat16);
+    skip_any(&mut mem, &mut buf, typtbl, IDL_PRIM_nat16, 0);
+    assert_eq!(*buf.ptr, 0x1f);
+
+    // A missing field after all existing ones skips the whole record
+    let mut mem = TestMemory::new(Words(1024));
+    let (mut buf, typtbl, mut tb, mut n) = record_fields(&mut mem, &bytes);
+    assert_eq!(
+        find_field(&mut mem, &mut tb, &mut buf, typtbl, 6, &mut n),
+        0
+    );
+    assert_eq!(n, 0);
+    assert_eq!(*buf.ptr, 0x1f);
+
+    // Skip the fields after the first one
+    let mut mem = TestMemory::new(Words(1024));
+    let (mut buf, typtbl, mut tb, mut n) = record_fields(&mut mem, &bytes);
+    assert_eq!(
+        find_field(&mut mem, &mut tb, &mut buf, typtbl, 1, &mut n),
+        1
+    );
+    assert_eq!(sleb128_decode(&mut tb), IDL_PRIM_nat8);
+    assert_eq!(*buf.ptr, 0x2a);
+    skip_any(&mut mem, &mut buf, typtbl, IDL_PRIM_nat8, 0);
+    skip_fields(&mut mem, &mut tb, &mut buf, typtbl, &mut n);
+    assert_eq!(n, 0);
+    assert_eq!(*buf.ptr, 0x1f);
+}
+
+/// Parses the header of a message with a record as the first argument, returns the data buffer
+/// at the record value, the type table, and the type buffer at the first field with the number of
+/// fields
+unsafe fn record_fields(mem: &mut TestMemory, bytes: &[u8]) -> (Buf, *mut *mut u8, Buf, u8) {
+    let mut buf = buf_of(bytes);
+    let (typtbl, main_types) = parse_header(mem, &mut buf, false);
+
+    let mut main_types = Buf {
+        ptr: main_types,
+        end: buf.end,
+    };
+    leb128_decode(&mut main_types);
+    let t = sleb128_decode(&mut main_types);
+
+    let mut tb = Buf {
+        ptr: *typtbl.add(t as usize),
+        end: buf.end,
+    };
+    assert_eq!(sleb128_decode(&mut tb), IDL_CON_record);
+    let n = leb128_decode(&mut tb) as u8;
+
+    (buf, typtbl, tb, n)
+}
+
+/// Parses the header, then skips all arguments and checks that the whole message is consumed
+unsafe fn decode(name: &str, bytes: &[u8], extended: bool) {
+    // `skip_any` allocates its stack for deeply nested values
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    let mut buf = buf_of(bytes);
+    let (typtbl, main_types) = parse_header(&mut mem, &mut buf, extended);
+
+    let mut main_types = Buf {
+        ptr: main_types,
+        end: buf.end,
+    };
+    for _ in 0..leb128_decode(&mut main_types) {
+        let t = sleb128_decode(&mut main_types);
+        skip_any(&mut mem, &mut buf, typtbl, t, 0);
+    }
+
+    let (ptr, end) = (buf.ptr, buf.end);
+    assert_eq!(ptr, end, "{}: message not fully consumed", name);
+}
+
+/// Returns the type table and the main types
+unsafe fn parse_header(
+    mem: &mut TestMemory,
+    buf: &mut Buf,
+    extended: bool,
+) -> (*mut *mut u8, *mut u8) {
+    let mut typtbl = std::ptr::null_mut();
+    let mut typtbl_size = 0;
+    let mut main_types = std::ptr::null_mut();
+    parse_idl_header(
+        mem,
+        extended,
+        buf,
+        &mut typtbl,
+        &mut typtbl_size,
+        &mut main_types,
+    );
+    (typtbl, main_types)
+}
+
+fn buf_of(bytes: &[u8]) -> Buf {
+    let ptr = bytes.as_ptr() as *mut u8;
+    Buf {
+        ptr,
+        end: unsafe { ptr.add(bytes.len()) },
+    }
+}
+
+/// Parses hex digits, ignoring whitespace
+fn hex(s: &str) -> Vec<u8> {
+    let digits: Vec<u8> = s
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .map(|b| (b as char).to_digit(16).unwrap() as u8)
+        .collect();
+    assert!(digits.len() % 2 == 0, "odd number of hex digits: {}", s);
+    digits.chunks(2).map(|d| d[0] << 4 | d[1]).collect()
+}
@@ -0,0 +1,335 @@
+//! Builds Candid message headers from types in textual syntax, e.g.
+//!
+//! ```
+//! header(&[("list", "opt record { nat; list }")], &["list", "text"])
+//! ```
+//!
+//! returns a header with a type table for `list` and main types `list` and `text`. Definitions
+//! need to be constructed types, they can refer to each other. Supported syntax:
+//!
+//! - primitive types, `principal`, `blob`
+//! - `opt t`, `vec t`, and `alias t` for the mutable values of extended messages
+//! - `record { f : t; ... }` and `variant { f : t; ... }`, where `f` is a name or a number. Record
+//!   fields without names are numbered, variant fields without types are `null`.
+//! - `func (t, ...) -> (t, ...) annotation ...`, where an annotation is a name or a number
+//! - `service { m : func_type; ... }`, where `func_type` is a `func` type without the `func`
+//!   keyword, or the name of a definition
+
+use motoko_rts::idl::*;
+
+use std::collections::HashMap;
+
+/// Returns a message header for the given type definitions and main types
+pub fn header(defs: &[(&str, &str)], main_types: &[&str]) -> Vec<u8> {
+    let mut table = Table {
+        entries: vec![vec![]; defs.len()],
+        names: HashMap::new(),
+    };
+    for (i, (name, _)) in defs.iter().enumerate() {
+        table.names.insert(name.to_string(), i as i32);
+    }
+
+    for (i, (name, def)) in defs.iter().enumerate() {
+        let mut parser = Parser::new(def);
+        let entry = parser
+            .constructed(&mut table)
+            .unwrap_or_else(|| panic!("definition of {} is not a constructed type", name));
+        parser.end();
+        table.entries[i] = entry;
+    }
+
+    let main_types: Vec<i32> = main_types
+        .iter()
+        .map(|t| {
+            let mut parser = Parser::new(t);
+            let t = parser.typ(&mut table);
+            parser.end();
+            t
+        })
+        .collect();
+
+    let mut bytes = b"DIDL".to_vec();
+    leb128(&mut bytes, table.entries.len() as u32);
+    for entry in &table.entries {
+        bytes.extend_from_slice(entry);
+    }
+    leb128(&mut bytes, main_types.len() as u32);
+    for t in main_types {
+        sleb128(&mut bytes, t);
+    }
+    bytes
+}
+
+/// Candid field hash
+pub fn idl_hash(name: &str) -> u32 {
+    name.bytes()
+        .fold(0u32, |h, c| h.wrapping_mul(223).wrapping_add(u32::from(c)))
+}
+
+pub fn leb128(bytes: &mut Vec<u8>, mut n: u32) {
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+pub fn sleb128(bytes: &mut Vec<u8>, mut n: i32) {
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if (n == 0 && byte & 0x40 == 0) || (n == -1 && byte & 0x40 != 0) {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+struct Table {
+    entries: Vec<Vec<u8>>,
+    names: HashMap<String, i32>,
+}
+
+impl Table {
+    fn add(&mut self, entry: Vec<u8>) -> i32 {
+        self.entries.push(entry);
+        self.entries.len() as i32 - 1
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<&'a str>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Parser<'a> {
+        let mut tokens = vec![];
+        let mut rest = s.trim_start();
+        while !rest.is_empty() {
+            let len = if rest.starts_with("->") {
+                2
+            } else if rest.starts_with(|c: char| c.is_alphanumeric() || c == '_') {
+                rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or_else(|| rest.len())
+            } else {
+                1
+            };
+            tokens.push(&rest[..len]);
+            rest = rest[len..].trim_start();
+        }
+        Parser { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> &'a str {
+        let token = self.peek().expect("unexpected end of type");
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, token: &str) {
+        let next = self.next();
+        assert_eq!(next, token, "unexpected token");
+    }
+
+    fn end(&self) {
+        assert_eq!(self.peek(), None, "unexpected token");
+    }
+
+    /// Parses a type, returns its primitive type code or type table index
+    fn typ(&mut self, table: &mut Table) -> i32 {
+        let prim = match self.peek() {
+            Some("null") => IDL_PRIM_null,
+            Some("bool") => IDL_PRIM_bool,
+            Some("nat") => IDL_PRIM_nat,
+            Some("int") => IDL_PRIM_int,
+            Some("nat8") => IDL_PRIM_nat8,
+            Some("nat16") => IDL_PRIM_nat16,
+            Some("nat32") => IDL_PRIM_nat32,
+            Some("nat64") => IDL_PRIM_nat64,
+            Some("int8") => IDL_PRIM_int8,
+            Some("int16") => IDL_PRIM_int16,
+            Some("int32") => IDL_PRIM_int32,
+            Some("int64") => IDL_PRIM_int64,
+            Some("float32") => IDL_PRIM_float32,
+            Some("float64") => IDL_PRIM_float64,
+            Some("text") => IDL_PRIM_text,
+            Some("reserved") => IDL_PRIM_reserved,
+            Some("empty") => IDL_PRIM_empty,
+            Some("principal") => IDL_REF_principal,
+            _ => 0,
+        };
+        if prim != 0 {
+            self.pos += 1;
+            return prim;
+        }
+
+        match self.constructed(table) {
+            Some(entry) => table.add(entry),
+            None => {
+                let name = self.next();
+                *table
+                    .names
+                    .get(name)
+                    .unwrap_or_else(|| panic!("unknown type {}", name))
+            }
+        }
+    }
+
+    /// Parses a constructed type, returns its type table entry
+    fn constructed(&mut self, table: &mut Table) -> Option<Vec<u8>> {
+        let mut entry = vec![];
+        match self.peek()? {
+            "opt" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_opt);
+                let t = self.typ(table);
+                sleb128(&mut entry, t);
+            }
+            "vec" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_vec);
+                let t = self.typ(table);
+                sleb128(&mut entry, t);
+            }
+            "alias" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_alias);
+                let t = self.typ(table);
+                sleb128(&mut entry, t);
+            }
+            "blob" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_vec);
+                sleb128(&mut entry, IDL_PRIM_nat8);
+            }
+            "record" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_record);
+                self.fields(table, &mut entry, true);
+            }
+            "variant" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_variant);
+                self.fields(table, &mut entry, false);
+            }
+            "func" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_func);
+                self.func_type(table, &mut entry);
+            }
+            "service" => {
+                self.pos += 1;
+                sleb128(&mut entry, IDL_CON_service);
+                self.methods(table, &mut entry);
+            }
+            _ => return None,
+        }
+        Some(entry)
+    }
+
+    fn fields(&mut self, table: &mut Table, entry: &mut Vec<u8>, record: bool) {
+        self.expect("{");
+        let mut fields = vec![];
+        while self.peek() != Some("}") {
+            let field = if self.tokens.get(self.pos + 1) == Some(&":") {
+                let name = self.next();
+                self.pos += 1;
+                let id = name.parse().unwrap_or_else(|_| idl_hash(name));
+                (id, self.typ(table))
+            } else if record {
+                (fields.len() as u32, self.typ(table))
+            } else {
+                (idl_hash(self.next()), IDL_PRIM_null)
+            };
+            fields.push(field);
+            if self.peek() != Some("}") {
+                self.expect(";");
+            }
+        }
+        self.pos += 1;
+
+        fields.sort_by_key(|(id, _)| *id);
+        leb128(entry, fields.len() as u32);
+        for (id, t) in fields {
+            leb128(entry, id);
+            sleb128(entry, t);
+        }
+    }
+
+    fn func_type(&mut self, table: &mut Table, entry: &mut Vec<u8>) {
+        self.types(table, entry);
+        self.expect("->");
+        self.types(table, entry);
+
+        let mut annotations = vec![];
+        loop {
+            match self.peek() {
+                Some("query") => annotations.push(1),
+                Some("oneway") => annotations.push(2),
+                Some("composite_query") => annotations.push(3),
+                Some(a) if a.parse::<u8>().is_ok() => annotations.push(a.parse().unwrap()),
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        leb128(entry, annotations.len() as u32);
+        entry.extend_from_slice(&annotations);
+    }
+
+    fn types(&mut self, table: &mut Table, entry: &mut Vec<u8>) {
+        self.expect("(");
+        let mut types = vec![];
+        while self.peek() != Some(")") {
+            types.push(self.typ(table));
+            if self.peek() != Some(")") {
+                self.expect(",");
+            }
+        }
+        self.pos += 1;
+
+        leb128(entry, types.len() as u32);
+        for t in types {
+            sleb128(entry, t);
+        }
+    }
+
+    fn methods(&mut self, table: &mut Table, entry: &mut Vec<u8>) {
+        self.expect("{");
+        let mut methods = vec![];
+        while self.peek() != Some("}") {
+            let name = self.next();
+            self.expect(":");
+            let t = if self.peek() == Some("(") {
+                let mut func = vec![];
+                sleb128(&mut func, IDL_CON_func);
+                self.func_type(table, &mut func);
+                table.add(func)
+            } else {
+                self.typ(table)
+            };
+            methods.push((name, t));
+            if self.peek() != Some("}") {
+                self.expect(";");
+            }
+        }
+        self.pos += 1;
+
+        methods.sort();
+        leb128(entry, methods.len() as u32);
+        for (name, t) in methods {
+            leb128(entry, name.len() as u32);
+            entry.extend_from_slice(name.as_bytes());
+            sleb128(entry, t);
+        }
+    }
+}
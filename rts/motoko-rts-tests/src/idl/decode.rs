@@ -0,0 +1,505 @@
+use super::candid::{header, idl_hash};
+use super::{buf_of, hex, VALID, VALID_EXTENDED};
+use crate::bigint::HEAP;
+use crate::memory::TestMemory;
+
+use motoko_rts::bigint::{bigint_eq, bigint_neg, bigint_of_word32, bigint_to_word64_trap};
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::budget::{self, Limits};
+use motoko_rts::idl::decode::{decode_message, decode_value, DecodeError, Field, Message, Value};
+use motoko_rts::idl::try_parse_idl_header;
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts::types::Words;
+
+pub unsafe fn test() {
+    println!("  Testing decoding ...");
+
+    let mut mp_heap = TestMemory::new(Words(1024 * 1024));
+    HEAP = &mut mp_heap;
+
+    test_valid_messages();
+    test_primitive_values();
+    test_constructed_values();
+    test_errors();
+    test_limits();
+    test_max_vec_elems();
+
+    HEAP = std::ptr::null_mut();
+}
+
+/// Everything `skip_any` accepts is decoded
+unsafe fn test_valid_messages() {
+    for (name, message) in VALID {
+        let bytes = hex(message);
+        with_message(&bytes, false, |result| {
+            if let Err(err) = result {
+                panic!("{}: {:?}", name, err)
+            }
+        });
+    }
+
+    for (name, message) in VALID_EXTENDED {
+        let bytes = hex(message);
+        with_message(&bytes, true, |result| {
+            if let Err(err) = result {
+                panic!("{}: {:?}", name, err)
+            }
+        });
+    }
+}
+
+unsafe fn test_primitive_values() {
+    let types = [
+        "null",
+        "bool",
+        "nat",
+        "nat",
+        "int",
+        "int",
+        "nat8",
+        "nat16",
+        "nat32",
+        "nat64",
+        "int8",
+        "int16",
+        "int32",
+        "int64",
+        "float32",
+        "float64",
+        "text",
+        "reserved",
+        "principal",
+        "principal",
+    ];
+    let values = "\
+        01\
+        808080808001\
+        7b\
+        8080808080807f\
+        7f\
+        ff\
+        3412\
+        78563412\
+        efcdab9078563412\
+        80\
+        feff\
+        ffffffff\
+        0000000000000080\
+        0000c03f\
+        000000000000f8bf\
+        03e29883\
+        \
+        0103abcdef\
+        00";
+    let bytes = message(&[], &types, values);
+
+    with_args(&bytes, |args| {
+        assert_eq!(args.len(), types.len());
+        assert!(matches!(args[0], Value::Null));
+        assert!(matches!(args[1], Value::Bool(true)));
+        assert!(matches!(args[2], Value::Nat(n) if bigint_to_word64_trap(n) == 1 << 35));
+        assert!(matches!(args[3], Value::Nat(n) if bigint_eq(n, bigint_of_word32(123))));
+        assert!(
+            matches!(args[4], Value::Int(n) if bigint_to_word64_trap(bigint_neg(n)) == 1 << 42)
+        );
+        assert!(matches!(args[5], Value::Int(n) if bigint_eq(n, bigint_neg(bigint_of_word32(1)))));
+        assert!(matches!(args[6], Value::Nat8(0xff)));
+        assert!(matches!(args[7], Value::Nat16(0x1234)));
+        assert!(matches!(args[8], Value::Nat32(0x12345678)));
+        assert!(matches!(args[9], Value::Nat64(0x1234567890abcdef)));
+        assert!(matches!(args[10], Value::Int8(-128)));
+        assert!(matches!(args[11], Value::Int16(-2)));
+        assert!(matches!(args[12], Value::Int32(-1)));
+        assert!(matches!(args[13], Value::Int64(std::i64::MIN)));
+        assert!(matches!(args[14], Value::Float32(f) if f == 1.5));
+        assert!(matches!(args[15], Value::Float64(f) if f == -1.5));
+        assert!(matches!(args[16], Value::Text("☃")));
+        assert!(matches!(args[17], Value::Reserved));
+        assert!(matches!(
+            args[18],
+            Value::Principal(Some([0xab, 0xcd, 0xef]))
+        ));
+        assert!(matches!(args[19], Value::Principal(None)));
+    });
+}
+
+unsafe fn test_constructed_values() {
+    // opt, vec and blob
+    let bytes = message(
+        &[],
+        &["opt nat8", "opt nat8", "vec opt nat8", "blob", "vec text"],
+        "00 0107 03 00 0102 0103 0400010203 020161026263",
+    );
+    with_args(&bytes, |args| {
+        assert!(matches!(args[0], Value::Opt(None)));
+        assert!(matches!(args[1], Value::Opt(Some(Value::Nat8(7)))));
+        match args[2] {
+            Value::Vec {
+                elems:
+                    [Value::Opt(None), Value::Opt(Some(Value::Nat8(2))), Value::Opt(Some(Value::Nat8(3)))],
+                len: 3,
+            } => {}
+            _ => panic!("vec opt nat8"),
+        }
+        assert!(matches!(args[3], Value::Blob([0, 1, 2, 3])));
+        assert!(matches!(
+            args[4],
+            Value::Vec {
+                elems: [Value::Text("a"), Value::Text("bc")],
+                len: 2
+            }
+        ));
+    });
+
+    // Records, with fields in the order of their ids, and variants
+    let bytes = message(
+        &[],
+        &[
+            "record { name : text; age : nat8; 0 : bool }",
+            "record { nat8; text }",
+            "variant { ok : nat8; err : text }",
+            "variant { ok : nat8; err : text }",
+        ],
+        "00 02 03 616263 01 0162 01 03 787878 00 2a",
+    );
+    with_args(&bytes, |args| {
+        let (age, name) = (idl_hash("age"), idl_hash("name"));
+        assert!(age < name);
+        match args[0] {
+            Value::Record(
+                [Field {
+                    id: 0,
+                    value: Value::Bool(false),
+                }, Field {
+                    id: id1,
+                    value: Value::Nat8(2),
+                }, Field {
+                    id: id2,
+                    value: Value::Text("abc"),
+                }],
+            ) if *id1 == age && *id2 == name => {}
+            _ => panic!("record with named fields"),
+        }
+        match args[1] {
+            Value::Record(
+                [Field {
+                    id: 0,
+                    value: Value::Nat8(1),
+                }, Field {
+                    id: 1,
+                    value: Value::Text("b"),
+                }],
+            ) => {}
+            _ => panic!("tuple"),
+        }
+        // Cases are sorted by id, `ok` comes first
+        assert!(idl_hash("ok") < idl_hash("err"));
+        assert!(matches!(
+            args[2],
+            Value::Variant(Field {
+                id,
+                value: Value::Text("xxx"),
+            }) if *id == idl_hash("err")
+        ));
+        assert!(matches!(
+            args[3],
+            Value::Variant(Field {
+                id,
+                value: Value::Nat8(42),
+            }) if *id == idl_hash("ok")
+        ));
+    });
+
+    // References
+    let bytes = message(
+        &[],
+        &["func (nat) -> ()", "service { m : () -> () }"],
+        "01 01 02abcd 03666f6f 01 01ef",
+    );
+    with_args(&bytes, |args| {
+        assert!(matches!(
+            args[0],
+            Value::Func {
+                service: [0xab, 0xcd],
+                method: "foo",
+            }
+        ));
+        assert!(matches!(args[1], Value::Service([0xef])));
+    });
+
+    // A recursive type
+    let bytes = message(
+        &[("list", "opt record { nat8; list }")],
+        &["list"],
+        "01 01 01 02 00",
+    );
+    with_args(&bytes, |args| {
+        let mut list = &args[0];
+        for i in 1..=2 {
+            match list {
+                Value::Opt(Some(Value::Record(
+                    [Field {
+                        value: Value::Nat8(n),
+                        ..
+                    }, Field { value: tail, .. }],
+                ))) if *n == i => list = tail,
+                _ => panic!("list element {}", i),
+            }
+        }
+        assert!(matches!(list, Value::Opt(None)));
+    });
+
+    // Future types
+    let bytes = hex("4449444c016602abcd01000400deadbeef");
+    with_args(&bytes, |args| {
+        assert!(matches!(args[0], Value::Future([0xde, 0xad, 0xbe, 0xef])));
+    });
+
+    // Mutable values in extended messages
+    let bytes = hex("4449444c 01 0171 02 0000 00 0000000000000000 03466f6f 01 f0ffffff");
+    with_message(&bytes, true, |result| match result.unwrap().args {
+        [Value::Alias(Value::Text("Foo")), Value::AliasRef(-16)] => {}
+        _ => panic!("aliases"),
+    });
+}
+
+/// Malformed headers, with the error and its offset in the message. The errors are those
+/// `parse_idl_header` traps with.
+const HEADER_ERRORS: &[(&str, &str, u32)] = &[
+    (
+        "",
+        "empty input. Expected Candid-encoded argument, but received a zero-length argument",
+        0,
+    ),
+    ("4449445800", "missing magic bytes", 0),
+    ("444944", "word read out of buffer", 0),
+    ("4449444c0500", "too many types", 5),
+    ("4449444c8080808010", "leb128_decode: overflow", 4),
+    ("4449444c01ffffffffff0100", "sleb128_decode: overflow", 5),
+    ("4449444c016c01", "byte read out of buffer", 7),
+    ("4449444c01000000", "illegal type table", 5),
+    ("4449444c017100", "primitive type in type table", 5),
+    ("4449444c016e0500", "invalid type argument", 6),
+    ("4449444c000100", "invalid type argument", 6),
+    (
+        "4449444c016c0201710071000000",
+        "variant or record tag out of order",
+        9,
+    ),
+    (
+        "4449444c016a0000010400",
+        "func annotation not within 1..3",
+        9,
+    ),
+    (
+        "4449444c026902016201016101",
+        "service method names out of order",
+        11,
+    ),
+    (
+        "4449444c01690101800000",
+        "utf8_validate: string is not UTF-8",
+        8,
+    ),
+    (
+        "4449444c016901016171000000",
+        "service method arg not a constructor type",
+        9,
+    ),
+    (
+        "4449444c0269010161016e7100",
+        "service method arg not a function type",
+        9,
+    ),
+    ("4449444c0169010561000000", "advance out of buffer", 8),
+];
+
+/// Malformed values, with the error and its offset in the message, including the header
+const ERRORS: &[(&[&str], &str, &str, u32)] = &[
+    (&["nat32"], "010203", "unexpected end of message", 7),
+    (&["nat"], "8080", "unexpected end of message", 7),
+    (&["text"], "0461", "unexpected end of message", 8),
+    (&["text"], "ffffffff7f", "leb128 overflow", 7),
+    (&["text"], "01ff", "text is not valid UTF-8", 7),
+    (&["bool"], "02", "byte tag not 0 or 1", 7),
+    (&["opt nat8"], "0207", "byte tag not 0 or 1", 9),
+    (&["empty"], "", "value of type empty", 7),
+    (
+        &["variant { a; b }"],
+        "02",
+        "variant index out of range",
+        13,
+    ),
+    (
+        &["service {}"],
+        "00",
+        "opaque references are not supported",
+        9,
+    ),
+    (
+        &["func () -> ()"],
+        "00",
+        "opaque references are not supported",
+        11,
+    ),
+    (
+        &["func () -> ()"],
+        "0100",
+        "opaque references are not supported",
+        12,
+    ),
+    (&["vec nat8"], "05010203", "unexpected end of message", 10),
+    (
+        &["vec nat16"],
+        "ffffff0f0100",
+        "vector longer than the rest of the message",
+        9,
+    ),
+    (&["nat8"], "0102", "bytes left after the arguments", 8),
+    (&["nat8", "nat8"], "01", "unexpected end of message", 9),
+];
+
+unsafe fn test_errors() {
+    for (message, msg, offset) in HEADER_ERRORS {
+        let bytes = hex(message);
+        let expected = DecodeError {
+            msg,
+            offset: *offset,
+        };
+        with_message(&bytes, false, |result| match result {
+            Ok(_) => panic!("{} decoded", message),
+            Err(err) => assert_eq!(err, expected, "{}", message),
+        });
+    }
+
+    for (types, values, msg, offset) in ERRORS {
+        let bytes = message(&[], types, values);
+        let expected = DecodeError {
+            msg,
+            offset: *offset,
+        };
+        with_message(&bytes, false, |result| match result {
+            Ok(_) => panic!("{:?} decoded", types),
+            Err(err) => assert_eq!(err, expected, "{:?}", types),
+        });
+    }
+
+    // Opaque references in future types
+    let bytes = hex("4449444c01660001000001");
+    with_message(&bytes, false, |result| {
+        assert_eq!(
+            result.err().map(|err| err.msg),
+            Some("opaque references are not supported")
+        );
+    });
+}
+
+unsafe fn test_limits() {
+    let error = |bytes: &[u8]| {
+        let mut msg = None;
+        with_message(bytes, false, |result| msg = result.err().map(|err| err.msg));
+        msg
+    };
+
+    // Vectors of zero-sized values
+    assert_eq!(error(&hex("4449444c016d7f0100a08d06")), None);
+    assert_eq!(
+        error(&hex("4449444c016d7f0100a18d06")),
+        Some("vector of zero-sized values too long")
+    );
+
+    // A list of 1,000 elements is fine, one more is nested too deeply
+    let list = |n| {
+        let mut bytes = message(&[("list", "opt list")], &["list"], "");
+        bytes.extend(std::iter::repeat(1).take(n));
+        bytes.push(0);
+        bytes
+    };
+    assert_eq!(error(&list(999)), None);
+    assert_eq!(error(&list(1_000)), Some("values nested too deeply"));
+
+    // The limits set for `skip_any` apply
+    budget::set_limits(Limits {
+        max_depth: 10,
+        ..Limits::DEFAULT
+    });
+    assert_eq!(error(&list(9)), None);
+    assert_eq!(error(&list(10)), Some("values nested too deeply"));
+
+    // With one value per byte, a `vec opt null` with as many elements as its header has bytes
+    // is fine, one more element is not. It takes `1 + 2 * n` values for `header_len + 1 + n`
+    // bytes.
+    budget::set_limits(Limits {
+        values_per_byte: 1,
+        values_base: 0,
+        ..Limits::DEFAULT
+    });
+    let vec = |n: u8| {
+        let mut bytes = message(&[], &["vec opt null"], "");
+        bytes.push(n);
+        bytes.extend(std::iter::repeat(1).take(n as usize));
+        bytes
+    };
+    let header_len = vec(0).len() as u8 - 1;
+    assert_eq!(error(&vec(header_len)), None);
+    assert_eq!(error(&vec(header_len + 1)), Some("too many values"));
+
+    budget::set_limits(Limits::DEFAULT);
+}
+
+/// Only the first elements of a vector are decoded with a limit, the others are skipped
+unsafe fn test_max_vec_elems() {
+    let bytes = message(
+        &[],
+        &["vec nat16", "nat8"],
+        "05 0100 0200 0300 0400 0500 07",
+    );
+    for max_vec_elems in 0..=6 {
+        let mut mem = TestMemory::new(Words(1024 * 1024));
+        let mut buf = buf_of(&bytes);
+        let header = try_parse_idl_header(&mut mem, false, &mut buf).unwrap();
+        let mut main_types = Buf {
+            ptr: header.main_types,
+            end: buf.end,
+        };
+        assert_eq!(leb128_decode(&mut main_types), 2);
+        let t = sleb128_decode(&mut main_types);
+        match decode_value(&mut mem, &mut buf, header.typtbl, t, max_vec_elems).unwrap() {
+            Value::Vec { elems, len } => {
+                assert_eq!(len, 5);
+                assert_eq!(elems.len(), std::cmp::min(max_vec_elems, 5) as usize);
+                for (i, elem) in elems.iter().enumerate() {
+                    assert!(matches!(*elem, Value::Nat16(n) if n == i as u16 + 1));
+                }
+            }
+            _ => panic!("vec nat16"),
+        }
+        assert_eq!(*buf.ptr, 7);
+    }
+}
+
+/// A message with arguments of the given types, and their values in hex
+fn message(defs: &[(&str, &str)], types: &[&str], values: &str) -> Vec<u8> {
+    let mut bytes = header(defs, types);
+    bytes.extend_from_slice(&hex(values));
+    bytes
+}
+
+/// Decodes the message, calls `f` with the result
+unsafe fn with_message<F: FnOnce(Result<Message, DecodeError>)>(
+    bytes: &[u8],
+    extended: bool,
+    f: F,
+) {
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    let mut buf = buf_of(bytes);
+    f(decode_message(&mut mem, &mut buf, extended))
+}
+
+/// Decodes the message, calls `f` with the arguments
+unsafe fn with_args<F: FnOnce(&[Value])>(bytes: &[u8], f: F) {
+    with_message(bytes, false, |result| match result {
+        Ok(message) => f(message.args),
+        Err(err) => panic!("{:?}", err),
+    })
+}
@@ -0,0 +1,193 @@
+use super::candid::{self, header};
+use super::{buf_of, parse_header};
+use crate::assert_idl_traps;
+use crate::memory::TestMemory;
+
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::fields::{
+    find_hash_collision, idl_check_field_hashes, idl_hash, idl_hash_text,
+};
+use motoko_rts::idl::*;
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts::memory::{alloc_array, Memory};
+use motoko_rts::text::{text_concat, text_of_str};
+use motoko_rts::types::{SkewedPtr, Words};
+
+pub unsafe fn test() {
+    println!("  Testing field ids ...");
+
+    test_hash();
+    test_hash_collisions();
+    test_find_field();
+}
+
+unsafe fn test_hash() {
+    assert_eq!(idl_hash(b""), 0);
+    assert_eq!(idl_hash(b"ok"), 24860);
+    assert_eq!(idl_hash(b"err"), 5048165);
+    assert_eq!(idl_hash(b"name"), 1224700491);
+
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    for name in &[
+        "",
+        "a",
+        "ok",
+        "name",
+        "_1_",
+        "a longer field name",
+        "händler",
+    ] {
+        let expected = candid::idl_hash(name);
+        assert_eq!(idl_hash(name.as_bytes()), expected);
+        let text = text_of_str(&mut mem, name);
+        assert_eq!(idl_hash_text(&mut mem, text), expected);
+
+        // As concatenations of single characters, nested either way
+        let mut left = text_of_str(&mut mem, "");
+        let mut right = text_of_str(&mut mem, "");
+        for (c, rev_c) in name.chars().zip(name.chars().rev()) {
+            let c = text_of_str(&mut mem, &c.to_string());
+            left = text_concat(&mut mem, left, c);
+            let rev_c = text_of_str(&mut mem, &rev_c.to_string());
+            right = text_concat(&mut mem, rev_c, right);
+        }
+        assert_eq!(idl_hash_text(&mut mem, left), expected, "{}", name);
+        assert_eq!(idl_hash_text(&mut mem, right), expected, "{}", name);
+    }
+
+    // A text built by a million appends, nested too deeply to hash recursively
+    let mut mem = TestMemory::new(Words(16 * 1024 * 1024));
+    let n = 1_000_000;
+    let x = text_of_str(&mut mem, "x");
+    let mut text = text_of_str(&mut mem, "");
+    for _ in 0..n {
+        text = text_concat(&mut mem, text, x);
+    }
+    assert_eq!(idl_hash_text(&mut mem, text), idl_hash(&vec![b'x'; n]));
+}
+
+unsafe fn test_hash_collisions() {
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    // `dnctwrq` and `sbusnjd` have the same hash
+    assert_eq!(candid::idl_hash("dnctwrq"), candid::idl_hash("sbusnjd"));
+
+    let cases: &[(&[&str], Option<(u32, u32)>)] = &[
+        (&[], None),
+        (&["a"], None),
+        (&["a", "b", "ok", "err"], None),
+        (&["a", "b", "a"], None),
+        (&["dnctwrq", "dnctwrq"], None),
+        (&["dnctwrq", "a", "sbusnjd"], Some((0, 2))),
+        (&["sbusnjd", "dnctwrq", "dnctwrq"], Some((0, 1))),
+        (&["dnctwrq", "dnctwrq", "sbusnjd"], Some((1, 2))),
+    ];
+    for (names, expected) in cases {
+        let array = names_array(&mut mem, names);
+        assert_eq!(
+            find_hash_collision(&mut mem, array),
+            *expected,
+            "{:?}",
+            names
+        );
+    }
+
+    let array = names_array(&mut mem, &["a", "b"]);
+    idl_check_field_hashes(&mut mem, array);
+
+    assert_idl_traps(
+        || {
+            let mut mem = TestMemory::new(Words(1024 * 1024));
+            let array = names_array(&mut mem, &["x", "sbusnjd", "dnctwrq"]);
+            idl_check_field_hashes(&mut mem, array);
+        },
+        "field names \"sbusnjd\" and \"dnctwrq\" have the same hash",
+    );
+}
+
+/// Looks up fields in a large record, following a smaller one in the type table
+unsafe fn test_find_field() {
+    let fields: Vec<String> = (0..200).map(|i| format!("{} : nat8", 2 * i)).collect();
+    let record = format!("record {{ {} }}", fields.join("; "));
+    let mut bytes = header(&[], &["record { 1 : nat8; 3 : nat8 }", &record]);
+    bytes.extend(&[0xa, 0xb]);
+    bytes.extend(0..200u8);
+    bytes.push(0xff);
+
+    // Look up all ids, as the code generated for decoding a record does
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    let (mut buf, typtbl, mut tb, mut n) = big_record(&mut mem, &bytes);
+    for id in 0..400 {
+        let found = id % 2 == 0;
+        assert_eq!(
+            find_field(&mut mem, &mut tb, &mut buf, typtbl, id, &mut n),
+            found as u32,
+            "{}",
+            id
+        );
+        if found {
+            assert_eq!(sleb128_decode(&mut tb), IDL_PRIM_nat8);
+            assert_eq!(*buf.ptr, (id / 2) as u8);
+            skip_any(&mut mem, &mut buf, typtbl, IDL_PRIM_nat8, 0);
+            assert_eq!(u32::from(n), 199 - id / 2);
+        } else {
+            assert_eq!(u32::from(n), 200 - (id + 1) / 2);
+        }
+    }
+    assert_eq!(n, 0);
+    assert_eq!(*buf.ptr, 0xff);
+
+    // Look up a field in the middle, then skip the rest
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    let (mut buf, typtbl, mut tb, mut n) = big_record(&mut mem, &bytes);
+    assert_eq!(
+        find_field(&mut mem, &mut tb, &mut buf, typtbl, 250, &mut n),
+        1
+    );
+    assert_eq!(n, 74);
+    assert_eq!(sleb128_decode(&mut tb), IDL_PRIM_nat8);
+    assert_eq!(*buf.ptr, 125);
+    skip_any(&mut mem, &mut buf, typtbl, IDL_PRIM_nat8, 0);
+    assert_eq!(
+        find_field(&mut mem, &mut tb, &mut buf, typtbl, 251, &mut n),
+        0
+    );
+    assert_eq!(n, 74);
+    skip_fields(&mut mem, &mut tb, &mut buf, typtbl, &mut n);
+    assert_eq!(*buf.ptr, 0xff);
+}
+
+/// Parses the header of the message in `test_find_field`, skips the first argument, returns the
+/// data buffer at the second argument, the type table, and the type buffer at the first field of
+/// the second argument with the number of fields
+unsafe fn big_record(mem: &mut TestMemory, bytes: &[u8]) -> (Buf, *mut *mut u8, Buf, u8) {
+    let mut buf = buf_of(bytes);
+    let (typtbl, main_types) = parse_header(mem, &mut buf, false);
+
+    let mut main_types = Buf {
+        ptr: main_types,
+        end: buf.end,
+    };
+    assert_eq!(leb128_decode(&mut main_types), 2);
+    let small = sleb128_decode(&mut main_types);
+    let big = sleb128_decode(&mut main_types);
+    skip_any(mem, &mut buf, typtbl, small, 0);
+
+    let mut tb = Buf {
+        ptr: *typtbl.add(big as usize),
+        end: buf.end,
+    };
+    assert_eq!(sleb128_decode(&mut tb), IDL_CON_record);
+    let n = leb128_decode(&mut tb) as u8;
+
+    (buf, typtbl, tb, n)
+}
+
+unsafe fn names_array<M: Memory>(mem: &mut M, names: &[&str]) -> SkewedPtr {
+    let array = alloc_array(mem, names.len() as u32);
+    for (i, name) in names.iter().enumerate() {
+        let text = text_of_str(mem, name);
+        array.as_array().set(i as u32, text);
+    }
+    array
+}
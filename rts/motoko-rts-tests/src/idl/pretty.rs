@@ -0,0 +1,241 @@
+use super::candid::header;
+use super::{buf_of, hex};
+use crate::assert_idl_traps;
+use crate::bigint::HEAP;
+use crate::memory::TestMemory;
+
+use motoko_rts::idl::pretty::{idl_to_text, write_message, TextLimits};
+use motoko_rts::memory::{alloc_blob, Memory};
+use motoko_rts::text::blob_of_text;
+use motoko_rts::types::{Bytes, SkewedPtr, Words};
+
+pub unsafe fn test() {
+    println!("  Testing pretty-printing ...");
+
+    let mut mp_heap = TestMemory::new(Words(1024 * 1024));
+    HEAP = &mut mp_heap;
+
+    test_primitive_values();
+    test_constructed_values();
+    test_references();
+    test_truncation();
+    test_errors();
+    test_idl_to_text();
+
+    HEAP = std::ptr::null_mut();
+}
+
+unsafe fn test_primitive_values() {
+    let bytes = message(
+        &[],
+        &[
+            "null", "bool", "nat", "int", "nat8", "int16", "float64", "text", "reserved",
+        ],
+        "01 2a 7f ff feff 000000000000f83f 03 61220a",
+    );
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "(null : null, true : bool, 42 : nat, -1 : int, 255 : nat8, -2 : int16, 1.5 : float64, \
+         \"a\\\"\\n\" : text, null : reserved)"
+    );
+
+    // `float32` values are shown with the shortest digits of the `f32`
+    let bytes = message(&[], &["float32", "float32"], "cdcc8c3f 0000c07f");
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "(1.1 : float32, nan : float32)"
+    );
+}
+
+unsafe fn test_constructed_values() {
+    let bytes = message(
+        &[("list", "opt record { nat; list }")],
+        &[
+            "list",
+            "blob",
+            "record { a : nat; b : text }",
+            "variant { ok : nat; err }",
+            "opt text",
+            "record {}",
+        ],
+        "01 01 01 02 00  03 0041ff  05 0178  01  00",
+    );
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "type t0 = opt t1; type t1 = record { nat; t0 }; type t2 = blob; \
+         type t3 = record { 97 : nat; 98 : text }; type t4 = variant { 24860 : nat; 5048165 : null }; \
+         type t5 = opt text; type t6 = record {}; \
+         (opt record { 1; opt record { 2; null } } : t0, blob \"\\00A\\ff\" : t2, \
+         record { 97 = 5; 98 = \"x\" } : t3, variant { 5048165 } : t4, null : t5, record {} : t6)"
+    );
+}
+
+unsafe fn test_references() {
+    let bytes = message(
+        &[],
+        &[
+            "principal",
+            "func (nat) -> () query",
+            "func () -> () composite_query oneway",
+            "service { m : () -> () }",
+        ],
+        "01 01 04  01 01 00 03666f6f  01 01 00 0166  01 00",
+    );
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "type t0 = func (nat) -> () query; \
+         type t1 = func () -> () composite_query oneway; \
+         type t2 = func () -> (); type t3 = service { m : t2 }; \
+         (principal \"2vxsx-fae\" : principal, func \"aaaaa-aa\".foo : t0, \
+         func \"aaaaa-aa\".f : t1, service \"aaaaa-aa\" : t3)"
+    );
+}
+
+unsafe fn test_truncation() {
+    let limits = TextLimits {
+        max_vec_elems: 3,
+        max_blob_bytes: 2,
+        max_text_bytes: 2,
+        ..TextLimits::DEFAULT
+    };
+
+    let bytes = message(
+        &[],
+        &["vec nat16", "blob", "text", "vec null"],
+        "05 0100 0200 0300 0400 0500  04 61626364  04 61c3a962  00",
+    );
+    assert_eq!(
+        pretty(&bytes, &limits),
+        "type t0 = vec nat16; type t1 = blob; type t2 = vec null; \
+         (vec { 1; 2; 3; /* and 2 more */ } : t0, blob \"ab\" /* and 2 more bytes */ : t1, \
+         \"a\" /* and 3 more bytes */ : text, vec {} : t2)"
+    );
+
+    // Without elements shown, only the length of vectors is
+    let limits = TextLimits {
+        max_vec_elems: 0,
+        ..TextLimits::DEFAULT
+    };
+    let bytes = message(&[], &["vec nat16", "vec null"], "02 0100 0200  00");
+    assert_eq!(
+        pretty(&bytes, &limits),
+        "type t0 = vec nat16; type t1 = vec null; (vec { /* 2 elements */ } : t0, vec {} : t1)"
+    );
+
+    // Elements that are not shown are skipped with `skip_any`, which traps if they are malformed
+    let limits = TextLimits {
+        max_vec_elems: 1,
+        ..TextLimits::DEFAULT
+    };
+    let bytes = message(&[], &["vec bool"], "02 01 02");
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "type t0 = vec bool; (/* byte tag not 0 or 1 at byte 11 */)"
+    );
+    assert_idl_traps(
+        || {
+            pretty(&bytes, &limits);
+        },
+        "skip_any: byte tag not 0 or 1",
+    );
+
+    // The output is cut off at `max_len`, at a character boundary
+    let bytes = message(&[], &["text"], "06 c3a9c3a9c3a9");
+    let full = "(\"ééé\" : text)";
+    assert_eq!(pretty(&bytes, &TextLimits::DEFAULT), full);
+    for max_len in 0..full.len() as u32 {
+        let limits = TextLimits {
+            max_len,
+            ..TextLimits::DEFAULT
+        };
+        let mut expected_len = max_len as usize;
+        while !full.is_char_boundary(expected_len) {
+            expected_len -= 1;
+        }
+        assert_eq!(
+            pretty(&bytes, &limits),
+            format!("{}…", &full[..expected_len])
+        );
+    }
+}
+
+unsafe fn test_errors() {
+    // Decoding errors are shown with the offset in the message
+    let bytes = message(&[], &["nat8", "bool"], "05 02");
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "(5 : nat8, /* byte tag not 0 or 1 at byte 9 */)"
+    );
+
+    let bytes = message(&[], &["nat8", "text"], "05 05 6162");
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "(5 : nat8, /* unexpected end of message at byte 10 */)"
+    );
+
+    let bytes = message(&[], &["nat8"], "05 0000");
+    assert_eq!(
+        pretty(&bytes, &TextLimits::DEFAULT),
+        "(5 : nat8) /* and 2 bytes after the arguments */"
+    );
+}
+
+unsafe fn test_idl_to_text() {
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    let bytes = message(&[], &["vec nat8", "int"], "02 0102 7e");
+    let blob = make_blob(&mut mem, &bytes);
+    let text = idl_to_text(&mut mem, blob);
+    assert_eq!(
+        text_to_string(&mut mem, text),
+        "type t0 = blob; (blob \"\\01\\02\" : t0, -2 : int)"
+    );
+
+    // Long messages are cut off
+    let mut values = String::from("14");
+    for _ in 0..20 {
+        values += "14";
+        for _ in 0..20 {
+            values += &format!("40{}", "ff".repeat(64));
+        }
+    }
+    let bytes = message(&[], &["vec vec blob"], &values);
+    let blob = make_blob(&mut mem, &bytes);
+    let text = idl_to_text(&mut mem, blob);
+    let text = text_to_string(&mut mem, text);
+    assert_eq!(text.len(), TextLimits::DEFAULT.max_len as usize + "…".len());
+    assert!(text.starts_with(
+        "type t0 = blob; type t1 = vec t0; type t2 = vec t1; (vec { vec { blob \"\\ff"
+    ));
+    assert!(text.ends_with("…"));
+}
+
+/// A message with arguments of the given types, and their values in hex
+fn message(defs: &[(&str, &str)], types: &[&str], values: &str) -> Vec<u8> {
+    let mut bytes = header(defs, types);
+    bytes.extend_from_slice(&hex(values));
+    bytes
+}
+
+/// Renders the message with the given limits
+unsafe fn pretty(bytes: &[u8], limits: &TextLimits) -> String {
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+    let mut buf = buf_of(bytes);
+    let mut out = String::new();
+    write_message(&mut mem, &mut out, &mut buf, false, limits);
+    out
+}
+
+unsafe fn make_blob<M: Memory>(mem: &mut M, bytes: &[u8]) -> SkewedPtr {
+    let blob = alloc_blob(mem, Bytes(bytes.len() as u32));
+    for (i, byte) in bytes.iter().enumerate() {
+        blob.as_blob().set(i as u32, *byte);
+    }
+    blob
+}
+
+unsafe fn text_to_string<M: Memory>(mem: &mut M, text: SkewedPtr) -> String {
+    let blob = blob_of_text(mem, text).as_blob();
+    let bytes = std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
@@ -0,0 +1,328 @@
+use super::buf_of;
+use super::candid::header;
+use crate::assert_idl_traps;
+use crate::memory::TestMemory;
+
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::subtype::is_subtype;
+use motoko_rts::idl::*;
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts::types::Words;
+
+pub unsafe fn test() {
+    println!("  Testing subtyping ...");
+
+    for (t1, t2, expected) in SUBTYPES {
+        check(&[], t1, &[], t2, *expected);
+    }
+
+    test_recursive_types();
+    test_aliases();
+    test_deep_nesting();
+}
+
+/// `(t1, t2, t1 <: t2)`, mostly following the subtyping tests of the Candid test suite
+const SUBTYPES: &[(&str, &str, bool)] = &[
+    // Primitive types
+    ("nat", "nat", true),
+    ("nat", "int", true),
+    ("int", "nat", false),
+    ("nat8", "nat", false),
+    ("nat", "nat64", false),
+    ("int32", "int64", false),
+    ("float32", "float64", false),
+    ("text", "text", true),
+    ("text", "blob", false),
+    ("principal", "principal", true),
+    ("null", "bool", false),
+    // `reserved` and `empty`
+    ("nat", "reserved", true),
+    ("record { a : nat }", "reserved", true),
+    ("reserved", "nat", false),
+    ("reserved", "reserved", true),
+    ("empty", "nat", true),
+    ("empty", "record { a : nat }", true),
+    ("nat", "empty", false),
+    ("empty", "empty", true),
+    // `opt`
+    ("null", "opt nat", true),
+    ("opt nat", "opt nat", true),
+    ("opt nat", "opt int", true),
+    ("opt int", "opt nat", true),
+    ("nat", "opt nat", true),
+    ("text", "opt nat", true),
+    ("opt opt nat", "opt nat", true),
+    ("opt nat", "nat", false),
+    ("opt nat", "null", false),
+    // `vec`
+    ("vec nat", "vec int", true),
+    ("vec int", "vec nat", false),
+    ("blob", "vec nat8", true),
+    ("vec nat", "vec opt text", true),
+    ("vec nat", "opt vec int", true),
+    ("vec nat", "nat", false),
+    // Records
+    ("record {}", "record {}", true),
+    ("record { a : nat }", "record {}", true),
+    ("record { a : nat; b : text }", "record { b : text }", true),
+    ("record { a : nat; b : text }", "record { a : int }", true),
+    ("record { a : int }", "record { a : nat }", false),
+    ("record {}", "record { a : nat }", false),
+    ("record { b : nat }", "record { a : nat }", false),
+    ("record {}", "record { a : opt nat }", true),
+    ("record {}", "record { a : null }", true),
+    ("record {}", "record { a : reserved }", true),
+    (
+        "record { b : nat }",
+        "record { a : opt nat; b : int }",
+        true,
+    ),
+    ("record { a : nat }", "record { a : opt text }", true),
+    ("record { nat; text }", "record { nat }", true),
+    ("record { nat }", "record { nat; text }", false),
+    ("record { 1 : text }", "record { text }", false),
+    (
+        "record { a : nat; c : nat; e : nat }",
+        "record { b : opt nat; c : int; d : null; e : nat }",
+        true,
+    ),
+    // Variants
+    ("variant {}", "variant {}", true),
+    ("variant {}", "variant { a }", true),
+    ("variant { a }", "variant { a; b }", true),
+    ("variant { a; b }", "variant { a }", false),
+    ("variant { b }", "variant { a }", false),
+    ("variant { a : nat }", "variant { a : int }", true),
+    ("variant { a : int }", "variant { a : nat }", false),
+    ("variant { a; c }", "variant { a; b; c }", true),
+    ("variant { a; b; c }", "variant { a; c }", false),
+    ("variant { a }", "record {}", false),
+    // Functions
+    ("func () -> ()", "func () -> ()", true),
+    ("func (int) -> (nat)", "func (nat) -> (int)", true),
+    ("func (nat) -> (int)", "func (int) -> (nat)", false),
+    ("func (int) -> ()", "func (int, text) -> ()", true),
+    ("func (int) -> ()", "func () -> ()", false),
+    ("func (int, opt text) -> ()", "func (int) -> ()", true),
+    ("func () -> (nat, text)", "func () -> (nat)", true),
+    ("func () -> (nat)", "func () -> (nat, text)", false),
+    ("func () -> (nat)", "func () -> (nat, opt text)", true),
+    ("func () -> () query", "func () -> () query", true),
+    ("func () -> () query", "func () -> ()", false),
+    ("func () -> ()", "func () -> () query", false),
+    ("func () -> () oneway", "func () -> ()", false),
+    (
+        "func () -> () composite_query",
+        "func () -> () composite_query",
+        true,
+    ),
+    (
+        "func () -> () composite_query",
+        "func () -> () query",
+        false,
+    ),
+    (
+        "func () -> () query",
+        "func () -> () composite_query",
+        false,
+    ),
+    ("func () -> () composite_query", "func () -> ()", false),
+    ("func () -> () query query", "func () -> () query", true),
+    (
+        "func () -> () query oneway",
+        "func () -> () oneway query",
+        true,
+    ),
+    ("func () -> () query oneway", "func () -> () query", false),
+    ("func () -> ()", "service {}", false),
+    // Services
+    ("service {}", "service {}", true),
+    ("service { m : () -> () }", "service {}", true),
+    ("service {}", "service { m : () -> () }", false),
+    (
+        "service { a : () -> (); b : (int) -> (nat) }",
+        "service { b : (nat) -> (int) }",
+        true,
+    ),
+    (
+        "service { b : (nat) -> (int) }",
+        "service { b : (int) -> (nat) }",
+        false,
+    ),
+    (
+        "service { a : () -> () }",
+        "service { b : () -> () }",
+        false,
+    ),
+    (
+        "service { a : () -> (); c : () -> () }",
+        "service { a : () -> (); b : () -> (); c : () -> () }",
+        false,
+    ),
+    (
+        "service { m : () -> () }",
+        "service { m : () -> () query }",
+        false,
+    ),
+    (
+        "service { m : () -> () composite_query }",
+        "service { m : () -> () composite_query }",
+        true,
+    ),
+    (
+        "service { m : (service { a : () -> () }) -> () }",
+        "service { m : (service {}) -> () }",
+        false,
+    ),
+    (
+        "service { m : () -> (service { a : () -> () }) }",
+        "service { m : () -> (service {}) }",
+        true,
+    ),
+];
+
+unsafe fn test_recursive_types() {
+    let list_nat = &[("list", "opt record { nat; list }")];
+    let list_int = &[("list", "opt record { int; list }")];
+    let list_ext = &[("list", "opt record { 0 : nat; 1 : list; 2 : opt text }")];
+    let list_text = &[("list", "opt record { text; list }")];
+    let tree = &[(
+        "tree",
+        "variant { leaf : nat; node : record { tree; tree } }",
+    )];
+    let tree_int = &[(
+        "tree",
+        "variant { leaf : int; node : record { tree; tree } }",
+    )];
+    let stream = &[(
+        "stream",
+        "record { head : nat; next : func () -> (stream) }",
+    )];
+    let stream_int = &[(
+        "stream",
+        "record { head : int; next : func () -> (stream) }",
+    )];
+
+    check(list_nat, "list", list_nat, "list", true);
+    check(list_nat, "list", list_int, "list", true);
+    check(list_nat, "list", list_ext, "list", true);
+    check(list_ext, "list", list_nat, "list", true);
+    // Fine because of the `opt` rule, the tail of a list of texts would be decoded as `null`
+    check(list_text, "list", list_nat, "list", true);
+    check(tree, "tree", tree_int, "tree", true);
+    check(tree_int, "tree", tree, "tree", false);
+    check(stream, "stream", stream_int, "stream", true);
+    check(stream_int, "stream", stream, "stream", false);
+
+    // An infinite record type and its unrolling
+    let inf = &[("r", "record { a : r }")];
+    let inf_unrolled = &[("r1", "record { a : r2 }"), ("r2", "record { a : r1 }")];
+    check(inf, "r", inf_unrolled, "r1", true);
+    check(inf_unrolled, "r2", inf, "r", true);
+    let inf_nat = &[("r", "record { a : r; b : nat }")];
+    check(inf_nat, "r", inf, "r", true);
+    check(inf, "r", inf_nat, "r", false);
+
+    // Functions taking themselves as arguments. As the argument type is contravariant, this makes
+    // the result type invariant.
+    let f = &[("f", "func (f) -> (nat)")];
+    let f_int = &[("f", "func (f) -> (int)")];
+    check(f, "f", f, "f", true);
+    check(f, "f", f_int, "f", false);
+    check(f_int, "f", f, "f", false);
+
+    // Mutually recursive services
+    let a = &[
+        ("a", "service { get : () -> (b) }"),
+        ("b", "service { get : () -> (a); put : (nat) -> () }"),
+    ];
+    let b = &[("a", "service { get : () -> (a) }")];
+    check(a, "a", b, "a", true);
+    check(b, "a", a, "a", false);
+}
+
+unsafe fn test_aliases() {
+    let nat = &[("cell", "alias nat")];
+    let int = &[("cell", "alias int")];
+    let opt_nat = &[("cell", "alias opt nat")];
+    let rec = &[("cell", "alias record { a : nat; b : nat }")];
+    let rec_a = &[("cell", "alias record { a : nat }")];
+
+    check(nat, "cell", nat, "cell", true);
+    check(nat, "cell", int, "cell", false);
+    check(int, "cell", nat, "cell", false);
+    check(nat, "cell", opt_nat, "cell", false);
+    check(rec, "cell", rec_a, "cell", false);
+    check(rec, "cell", rec, "cell", true);
+    check(nat, "cell", &[], "nat", false);
+    check(nat, "cell", &[], "reserved", true);
+
+    // Without the alias, the first record type would be a subtype of the second
+    let rec = &[("r", "record { a : nat; next : alias r }")];
+    let rec_next = &[("r", "record { next : alias r }")];
+    check(rec, "r", rec, "r", true);
+    check(rec, "r", rec_next, "r", false);
+    check(rec_next, "r", rec, "r", false);
+}
+
+unsafe fn test_deep_nesting() {
+    let nested = |n| "vec ".repeat(n) + "nat";
+    check(&[], &nested(1000), &[], &nested(1000), true);
+    assert_idl_traps(
+        || check(&[], &nested(1002), &[], &nested(1002), true),
+        "idl_sub: too deeply nested types",
+    );
+}
+
+/// Checks that `t1 <: t2` iff `expected`, with `t1` and `t2` given in textual syntax, with the
+/// type definitions `defs1` and `defs2`
+unsafe fn check(
+    defs1: &[(&str, &str)],
+    t1: &str,
+    defs2: &[(&str, &str)],
+    t2: &str,
+    expected: bool,
+) {
+    let bytes1 = header(defs1, &[t1]);
+    let bytes2 = header(defs2, &[t2]);
+    let mut mem = TestMemory::new(Words(256 * 1024));
+    let (tt1, t1_idx) = type_table(&mut mem, &bytes1);
+    let (tt2, t2_idx) = type_table(&mut mem, &bytes2);
+    assert_eq!(
+        is_subtype(&mut mem, tt1, tt2, t1_idx, t2_idx),
+        expected,
+        "{} <: {}",
+        t1,
+        t2
+    );
+}
+
+/// Parses an extended message header with one main type, returns the type table and the main type
+unsafe fn type_table(mem: &mut TestMemory, bytes: &[u8]) -> (TypeTable, i32) {
+    let mut buf = buf_of(bytes);
+    let mut typtbl = std::ptr::null_mut();
+    let mut typtbl_size = 0;
+    let mut main_types = std::ptr::null_mut();
+    parse_idl_header(
+        mem,
+        true,
+        &mut buf,
+        &mut typtbl,
+        &mut typtbl_size,
+        &mut main_types,
+    );
+
+    let mut main_types = Buf {
+        ptr: main_types,
+        end: buf.end,
+    };
+    assert_eq!(leb128_decode(&mut main_types), 1);
+    let t = sleb128_decode(&mut main_types);
+
+    let tt = TypeTable {
+        entries: typtbl,
+        end: buf.end,
+        size: typtbl_size,
+    };
+    (tt, t)
+}
@@ -50,6 +50,15 @@ pub unsafe fn test() {
         0b0000_1000,
     ]); // i32::MAX + 1
 
+    check_signed_decode_overflow(&[
+        0b1111_1111,
+        0b1111_1111,
+        0b1111_1111,
+        0b1111_1111,
+        0b1111_1111,
+        0b0111_1111,
+    ]); // -1, but too long
+
     check_unsigned_decode_overflow(&[
         0b1000_0000,
         0b1000_0000,
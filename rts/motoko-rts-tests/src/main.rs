@@ -2,14 +2,21 @@
 
 mod bigint;
 mod bitmap;
+mod char;
 mod closure_table;
 mod crc32;
+mod encoding;
+mod float;
 mod gc;
+mod idl;
 mod leb128;
 mod mark_stack;
+mod math;
 mod memory;
+mod normalization;
 mod principal_id;
 mod text;
+mod text_builder;
 mod utf8;
 
 use motoko_rts::types::Bytes;
@@ -17,23 +24,115 @@ use motoko_rts::types::Bytes;
 #[macro_use]
 extern crate maplit;
 
+/// Test modules, by name so that trap cases can be run in a separate process
+const TESTS: &[(&str, unsafe fn())] = &[
+    ("bigint", bigint::test),
+    ("bitmap", bitmap::test),
+    ("char", char::test),
+    ("closure_table", closure_table::test),
+    ("crc32", crc32::test),
+    ("encoding", encoding::test),
+    ("float", float::test),
+    ("gc", gc::test),
+    ("idl", idl::test),
+    ("leb128", leb128::test),
+    ("mark_stack", mark_stack::test),
+    ("math", math::test),
+    ("normalization", normalization::test),
+    ("principal_id", principal_id::test),
+    ("text", text::test),
+    ("text_builder", text_builder::test),
+    ("utf8", utf8::test),
+];
+
 fn main() {
     if std::mem::size_of::<usize>() != 4 {
         println!("Motoko RTS only works on 32-bit architectures");
         std::process::exit(1);
     }
 
+    let args: Vec<String> = std::env::args().collect();
+
     unsafe {
-        bigint::test();
-        bitmap::test();
-        closure_table::test();
-        crc32::test();
-        gc::test();
-        leb128::test();
-        mark_stack::test();
-        principal_id::test();
-        text::test();
-        utf8::test();
+        if let [_, cmd, module, case] = args.as_slice() {
+            if cmd == "trap" {
+                run_trap_case(module, case.parse().unwrap());
+            }
+        }
+
+        for &(module, test) in TESTS {
+            TEST_MODULE = module;
+            TRAP_CASE_IDX = 0;
+            test();
+        }
+
+        run_trap_cases();
+    }
+}
+
+// Trap cases
+//
+// A trap does not return to the RTS and can't unwind through the `extern "C"` `rts_trap`, so each
+// `assert_traps` case runs in a process of its own: `motoko-rts-tests trap <module> <case>` runs
+// the tests of `<module>` up to the `<case>`th trap case (skipping the ones before it, so that
+// the state set up by the module's tests is the same as in the full run), which must then trap
+// with the expected message.
+
+/// Name of the module being tested
+static mut TEST_MODULE: &str = "";
+
+/// Index of the next trap case in the module being tested
+static mut TRAP_CASE_IDX: u32 = 0;
+
+/// Trap cases seen in the full run: module, case index, expected message
+static mut TRAP_CASES: Vec<(&str, u32, String)> = Vec::new();
+
+/// When running a single trap case: its index, and the expected message once the case is reached
+static mut TRAP_CASE_RUN: Option<u32> = None;
+static mut TRAP_CASE_EXPECTED: Option<String> = None;
+
+unsafe fn run_trap_case(module: &str, case: u32) -> ! {
+    let &(module, test) = TESTS
+        .iter()
+        .find(|(name, _)| *name == module)
+        .unwrap_or_else(|| panic!("unknown test module {:?}", module));
+
+    TEST_MODULE = module;
+    TRAP_CASE_RUN = Some(case);
+    test();
+
+    println!("{} trap case {} not reached", module, case);
+    std::process::exit(1);
+}
+
+#[cfg(not(target_os = "wasi"))]
+unsafe fn run_trap_cases() {
+    let exe = std::env::current_exe().unwrap();
+
+    for (module, case, msg) in TRAP_CASES.iter() {
+        let output = std::process::Command::new(&exe)
+            .args(&["trap", module, &case.to_string()])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{} trap case {} (expected {:?}) failed:\n{}",
+            module,
+            case,
+            msg,
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+
+    println!("{} trap cases passed", TRAP_CASES.len());
+}
+
+// WASI can't spawn processes, the trap cases are printed and the test runner runs them (see
+// `test` in rts/Makefile)
+#[cfg(target_os = "wasi")]
+unsafe fn run_trap_cases() {
+    for (module, case, _) in TRAP_CASES.iter() {
+        println!("trap case: {} {}", module, case);
     }
 }
 
@@ -41,19 +140,52 @@ fn main() {
 #[no_mangle]
 extern "C" fn rts_trap(ptr: *const u8, len: Bytes<u32>) -> ! {
     let msg = unsafe { std::slice::from_raw_parts(ptr, len.0 as usize) };
-    match core::str::from_utf8(msg) {
-        Err(err) => panic!(
-            "rts_trap_with called with non-UTF8 string (error={:?}, string={:?})",
-            err, msg
-        ),
-        Ok(str) => panic!("rts_trap_with: {:?}", str),
+    let msg = match core::str::from_utf8(msg) {
+        Err(err) => {
+            println!(
+                "rts_trap_with called with non-UTF8 string (error={:?}, string={:?})",
+                err, msg
+            );
+            std::process::exit(1);
+        }
+        Ok(str) => str,
+    };
+
+    match unsafe { TRAP_CASE_EXPECTED.as_deref() } {
+        Some(expected) if expected == msg => std::process::exit(0),
+        Some(expected) => println!("rts_trap_with: {:?}, expected {:?}", msg, expected),
+        None => println!("rts_trap_with: {:?}", msg),
     }
+    std::process::exit(1);
 }
 
-// Called by RTS BigInt functions to panic. Normally generated by the compiler
-#[no_mangle]
-extern "C" fn bigint_trap() -> ! {
-    panic!("bigint_trap called");
+/// Checks that `f` traps with the given RTS error message
+fn assert_traps<F: FnOnce()>(f: F, msg: &str) {
+    assert_traps_with(f, &format!("RTS error: {}", msg));
+}
+
+/// Checks that `f` traps with the given IDL error message
+fn assert_idl_traps<F: FnOnce()>(f: F, msg: &str) {
+    assert_traps_with(f, &format!("IDL error: {}", msg));
+}
+
+/// Records a trap case in the full run, runs it when it's the one being run (see `run_trap_case`)
+fn assert_traps_with<F: FnOnce()>(f: F, msg: &str) {
+    unsafe {
+        let case = TRAP_CASE_IDX;
+        TRAP_CASE_IDX += 1;
+
+        match TRAP_CASE_RUN {
+            None => TRAP_CASES.push((TEST_MODULE, case, msg.to_string())),
+            Some(run) if run == case => {
+                TRAP_CASE_EXPECTED = Some(msg.to_string());
+                f();
+                println!("expected a trap with {:?}", msg);
+                std::process::exit(1);
+            }
+            Some(_) => {}
+        }
+    }
 }
 
 // Called by the RTS for debug prints
@@ -0,0 +1,302 @@
+// Reference inputs include multiples of pi etc.
+#![allow(clippy::approx_constant)]
+
+use motoko_rts::math::*;
+
+pub fn test() {
+    println!("Testing elementary functions ...");
+
+    test_reference_values();
+    test_special_values();
+}
+
+/// Checks results against correctly rounded values computed with mpmath. Errors are below 1 ULP
+/// (2 ULP for `atan2`, `sinh`, `cosh` and `tanh`), so results are at most that many ULPs away
+/// from the correctly rounded value.
+fn test_reference_values() {
+    let unary: &[(&str, extern "C" fn(f64) -> f64, f64, f64)] = &[
+        ("sin", sin, 0.5, 0.479425538604203),
+        ("sin", sin, -1.0, -0.8414709848078965),
+        ("sin", sin, 3.0, 0.1411200080598672),
+        ("sin", sin, 1.0e-09, 1.0e-09),
+        ("sin", sin, 100.0, -0.5063656411097588),
+        ("sin", sin, 1000000.0, -0.34999350217129294),
+        ("sin", sin, -123456.789, 0.9986640823432246),
+        ("sin", sin, 1.0e+22, -0.8522008497671888),
+        ("sin", sin, 1.7976931348623157e+308, 0.004961954789184062),
+        ("sin", sin, 5.319372648326541e+255, 1.0),
+        ("sin", sin, 3.141592653589793, 1.2246467991473532e-16),
+        ("sin", sin, 2.2250738585072014e-308, 2.2250738585072014e-308),
+        ("cos", cos, 0.5, 0.8775825618903728),
+        ("cos", cos, -1.0, 0.5403023058681398),
+        ("cos", cos, 3.0, -0.9899924966004454),
+        ("cos", cos, 1.0e-09, 1.0),
+        ("cos", cos, 100.0, 0.8623188722876839),
+        ("cos", cos, 1000000.0, 0.9367521275331447),
+        ("cos", cos, -123456.789, 0.05167253271870138),
+        ("cos", cos, 1.0e+22, 0.523214785395139),
+        ("cos", cos, 1.7976931348623157e+308, -0.9999876894265599),
+        ("cos", cos, 5.319372648326541e+255, -4.687165924254628e-19),
+        ("cos", cos, 1.5707963267948966, 6.123233995736766e-17),
+        ("tan", tan, 0.5, 0.5463024898437905),
+        ("tan", tan, -1.0, -1.5574077246549023),
+        ("tan", tan, 1.5, 14.101419947171719),
+        ("tan", tan, 1.0e-09, 1.0e-09),
+        ("tan", tan, 100.0, -0.5872139151569291),
+        ("tan", tan, 1000000.0, -0.373624453987599),
+        ("tan", tan, 1.0e+22, -1.6287782256068988),
+        ("tan", tan, 1.7976931348623157e+308, -0.004962015874444895),
+        ("tan", tan, 1.5707963267948966, 1.633123935319537e+16),
+        ("tan", tan, 0.7, 0.8422883804630794),
+        ("asin", asin, 0.5, 0.5235987755982989),
+        ("asin", asin, -0.3, -0.3046926540153975),
+        ("asin", asin, 0.98, 1.3704614844717768),
+        ("asin", asin, 1.0e-09, 1.0e-09),
+        ("asin", asin, -0.999999, -1.5693821131146521),
+        ("acos", acos, 0.5, 1.0471975511965979),
+        ("acos", acos, -0.3, 1.8754889808102941),
+        ("acos", acos, 0.98, 0.20033484232311968),
+        ("acos", acos, 1.0e-09, 1.5707963257948967),
+        ("acos", acos, -0.999999, 3.1401784399095485),
+        ("atan", atan, 0.5, 0.4636476090008061),
+        ("atan", atan, -1.0, -0.7853981633974483),
+        ("atan", atan, 2.0, 1.1071487177940904),
+        ("atan", atan, 1.0e-09, 1.0e-09),
+        ("atan", atan, 10000000000.0, 1.5707963266948965),
+        ("atan", atan, -0.7, -0.6107259643892086),
+        ("atan", atan, 1.3, 0.9151007005533605),
+        ("exp", exp, 1.0, 2.718281828459045),
+        ("exp", exp, -1.0, 0.36787944117144233),
+        ("exp", exp, 0.1, 1.1051709180756477),
+        ("exp", exp, 7.4225, 1673.211311592986),
+        ("exp", exp, 100.0, 2.6881171418161356e+43),
+        ("exp", exp, -100.0, 3.720075976020836e-44),
+        ("exp", exp, 709.0, 8.218407461554972e+307),
+        ("exp", exp, -740.0, 4.2e-322),
+        ("exp", exp, 1.0e-10, 1.0000000001),
+        ("log", log, 2.0, 0.6931471805599453),
+        ("log", log, 0.5, -0.6931471805599453),
+        ("log", log, 10.0, 2.302585092994046),
+        ("log", log, 1.0000001, 9.999999505838704e-08),
+        ("log", log, 1.0e+300, 690.7755278982137),
+        ("log", log, 5.0e-324, -744.4400719213812),
+        ("log", log, 7.4225, 2.004515927655778),
+        ("log2", log2, 3.0, 1.584962500721156),
+        ("log2", log2, 0.1, -3.321928094887362),
+        ("log2", log2, 1.0e+300, 996.5784284662087),
+        ("log2", log2, 5.0e-324, -1074.0),
+        ("log2", log2, 1.0000001, 1.4426949695965583e-07),
+        ("log2", log2, 1024.0, 10.0),
+        ("log10", log10, 2.0, 0.3010299956639812),
+        ("log10", log10, 0.3, -0.5228787452803376),
+        ("log10", log10, 1.0e+300, 300.0),
+        ("log10", log10, 5.0e-324, -323.3062153431158),
+        ("log10", log10, 1.0000001, 4.3429446044209946e-08),
+        ("log10", log10, 1000.0, 3.0),
+        ("sinh", sinh, 0.5, 0.5210953054937474),
+        ("sinh", sinh, -1.0, -1.1752011936438014),
+        ("sinh", sinh, 1.0e-09, 1.0e-09),
+        ("sinh", sinh, 20.0, 242582597.70489514),
+        ("sinh", sinh, 710.0, 1.1169973830808555e+308),
+        ("sinh", sinh, -0.3, -0.3045202934471426),
+        ("cosh", cosh, 0.5, 1.1276259652063807),
+        ("cosh", cosh, -1.0, 1.5430806348152437),
+        ("cosh", cosh, 1.0e-09, 1.0),
+        ("cosh", cosh, 20.0, 242582597.70489514),
+        ("cosh", cosh, 710.0, 1.1169973830808555e+308),
+        ("cosh", cosh, 0.3, 1.0453385141288605),
+        ("tanh", tanh, 0.5, 0.46211715726000974),
+        ("tanh", tanh, -1.0, -0.7615941559557649),
+        ("tanh", tanh, 1.0e-09, 1.0e-09),
+        ("tanh", tanh, 0.3, 0.2913126124515909),
+        ("tanh", tanh, 5.0, 0.9999092042625951),
+        ("tanh", tanh, 22.0, 1.0),
+    ];
+
+    for &(name, f, x, expected) in unary {
+        check_ulps(name, f(x), expected, &format!("{}({:?})", name, x));
+    }
+
+    let binary: &[(&str, extern "C" fn(f64, f64) -> f64, f64, f64, f64)] = &[
+        ("atan2", atan2, 1.0, 2.0, 0.4636476090008061),
+        ("atan2", atan2, -1.0, -2.0, -2.677945044588987),
+        ("atan2", atan2, 3.0, -0.5, 1.7359450042095235),
+        ("atan2", atan2, 1.0e-300, 1.0e+300, 0.0),
+        ("atan2", atan2, 1.0e+300, -1.0e-300, 1.5707963267948966),
+        ("atan2", atan2, 0.3, 0.3, 0.7853981633974483),
+        ("pow", pow, 2.0, 0.5, 1.4142135623730951),
+        ("pow", pow, 10.0, -3.0, 0.001),
+        ("pow", pow, 1.5, 100.5, 4.9793371709318016e+17),
+        (
+            "pow",
+            pow,
+            0.9999998578297812,
+            -4217248197.554918,
+            2.4473786319278813e+260,
+        ),
+        ("pow", pow, -2.0, 11.0, -2048.0),
+        ("pow", pow, 2.0, -1074.0, 5.0e-324),
+        ("pow", pow, 7.0, 0.3333333333333333, 1.912931182772389),
+        ("pow", pow, 1.0e-05, 60.5, 3.162277660168395e-303),
+        ("hypot", hypot, 3.0, 4.0, 5.0),
+        ("hypot", hypot, 1.0e+300, 1.0e+300, 1.4142135623730952e+300),
+        ("hypot", hypot, 1.0e-310, 3.0e-310, 3.1622776601684e-310),
+        ("hypot", hypot, 1.0, 1.0e-10, 1.0),
+        ("hypot", hypot, -5.5, 2.25, 5.942432162002357),
+    ];
+
+    for &(name, f, x, y, expected) in binary {
+        check_ulps(
+            name,
+            f(x, y),
+            expected,
+            &format!("{}({:?}, {:?})", name, x, y),
+        );
+    }
+}
+
+fn check_ulps(name: &str, result: f64, expected: f64, call: &str) {
+    let max_ulps = match name {
+        "atan2" | "sinh" | "cosh" | "tanh" => 2,
+        _ => 1,
+    };
+
+    if expected.is_infinite() {
+        assert_eq!(result, expected, "{}", call);
+        return;
+    }
+
+    let ulps = (ordered_bits(result) - ordered_bits(expected)).abs();
+    assert!(
+        ulps <= max_ulps,
+        "{} = {:?}, expected {:?} ({} ULPs off)",
+        call,
+        result,
+        expected,
+        ulps
+    );
+}
+
+/// Maps floats to integers, such that consecutive floats map to consecutive integers
+fn ordered_bits(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN - bits
+    } else {
+        bits
+    }
+}
+
+fn test_special_values() {
+    let inf = f64::INFINITY;
+    let nan = f64::NAN;
+    let pi = std::f64::consts::PI;
+
+    let unary: &[(&str, extern "C" fn(f64) -> f64, f64, f64)] = &[
+        ("sin", sin, -0.0, -0.0),
+        ("sin", sin, inf, nan),
+        ("sin", sin, nan, nan),
+        ("cos", cos, -0.0, 1.0),
+        ("cos", cos, -inf, nan),
+        ("tan", tan, -0.0, -0.0),
+        ("tan", tan, inf, nan),
+        ("asin", asin, -0.0, -0.0),
+        ("asin", asin, 1.0, pi / 2.0),
+        ("asin", asin, 1.5, nan),
+        ("acos", acos, 1.0, 0.0),
+        ("acos", acos, -1.0, pi),
+        ("acos", acos, -1.5, nan),
+        ("atan", atan, -0.0, -0.0),
+        ("atan", atan, inf, pi / 2.0),
+        ("atan", atan, -inf, -pi / 2.0),
+        ("atan", atan, nan, nan),
+        ("exp", exp, 0.0, 1.0),
+        ("exp", exp, inf, inf),
+        ("exp", exp, -inf, 0.0),
+        ("exp", exp, 710.0, inf),
+        ("exp", exp, -746.0, 0.0),
+        ("exp", exp, nan, nan),
+        ("log", log, 1.0, 0.0),
+        ("log", log, 0.0, -inf),
+        ("log", log, -0.0, -inf),
+        ("log", log, -1.0, nan),
+        ("log", log, inf, inf),
+        ("log2", log2, 1.0, 0.0),
+        ("log2", log2, 0.5, -1.0),
+        ("log2", log2, 5e-324, -1074.0),
+        ("log2", log2, 0.0, -inf),
+        ("log2", log2, -inf, nan),
+        ("log10", log10, 1.0, 0.0),
+        ("log10", log10, 1e22, 22.0),
+        ("log10", log10, 1e-300, -300.0),
+        ("log10", log10, 0.0, -inf),
+        ("log10", log10, -2.0, nan),
+        ("sinh", sinh, -0.0, -0.0),
+        ("sinh", sinh, -inf, -inf),
+        ("sinh", sinh, 711.0, inf),
+        ("cosh", cosh, -0.0, 1.0),
+        ("cosh", cosh, -inf, inf),
+        ("cosh", cosh, -711.0, inf),
+        ("tanh", tanh, -0.0, -0.0),
+        ("tanh", tanh, inf, 1.0),
+        ("tanh", tanh, -inf, -1.0),
+        ("tanh", tanh, nan, nan),
+    ];
+
+    for &(name, f, x, expected) in unary {
+        check_exact(f(x), expected, &format!("{}({:?})", name, x));
+    }
+
+    let binary: &[(&str, extern "C" fn(f64, f64) -> f64, f64, f64, f64)] = &[
+        ("atan2", atan2, 0.0, -0.0, pi),
+        ("atan2", atan2, -0.0, -0.0, -pi),
+        ("atan2", atan2, -0.0, 1.0, -0.0),
+        ("atan2", atan2, 1.0, 0.0, pi / 2.0),
+        ("atan2", atan2, -inf, inf, -pi / 4.0),
+        ("atan2", atan2, inf, -inf, 3.0 * pi / 4.0),
+        ("atan2", atan2, 1.0, -inf, pi),
+        ("atan2", atan2, nan, 1.0, nan),
+        ("pow", pow, nan, 0.0, 1.0),
+        ("pow", pow, 1.0, nan, 1.0),
+        ("pow", pow, -1.0, inf, 1.0),
+        ("pow", pow, 2.0, nan, nan),
+        ("pow", pow, 0.5, inf, 0.0),
+        ("pow", pow, 0.5, -inf, inf),
+        ("pow", pow, -0.0, 3.0, -0.0),
+        ("pow", pow, -0.0, -3.0, -inf),
+        ("pow", pow, 0.0, -0.5, inf),
+        ("pow", pow, -inf, 3.0, -inf),
+        ("pow", pow, -inf, -2.0, 0.0),
+        ("pow", pow, -8.0, 1.0 / 3.0, nan),
+        ("pow", pow, -2.0, 3.0, -8.0),
+        ("pow", pow, 2.0, 1024.0, inf),
+        ("pow", pow, -2.0, 1025.0, -inf),
+        ("pow", pow, 2.0, -1075.0, 0.0),
+        ("pow", pow, 10.0, 22.0, 1e22),
+        ("hypot", hypot, inf, nan, inf),
+        ("hypot", hypot, nan, -inf, inf),
+        ("hypot", hypot, nan, 1.0, nan),
+        ("hypot", hypot, -0.0, -0.0, 0.0),
+        ("hypot", hypot, -3.0, 0.0, 3.0),
+        ("hypot", hypot, 1e308, 1e308, 1.4142135623730952e308),
+    ];
+
+    for &(name, f, x, y, expected) in binary {
+        check_exact(f(x, y), expected, &format!("{}({:?}, {:?})", name, x, y));
+    }
+}
+
+fn check_exact(result: f64, expected: f64, call: &str) {
+    if expected.is_nan() {
+        assert!(result.is_nan(), "{} = {:?}, expected NaN", call, result);
+    } else {
+        assert_eq!(
+            result.to_bits(),
+            expected.to_bits(),
+            "{} = {:?}, expected {:?}",
+            call,
+            result,
+            expected
+        );
+    }
+}
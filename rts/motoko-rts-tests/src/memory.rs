@@ -1,4 +1,5 @@
 use motoko_rts::memory::Memory;
+use motoko_rts::text_index::text_index_cache_reset;
 use motoko_rts::types::{skew, SkewedPtr, Words};
 
 pub struct TestMemory {
@@ -11,9 +12,22 @@ impl TestMemory {
         let bytes = size.to_bytes().0;
         let heap = vec![0u8; bytes as usize].into_boxed_slice();
         let hp = heap.as_ptr() as usize;
+        // The table of character indices is allocated in the heap, drop the one of the previous
+        // heap
+        unsafe { text_index_cache_reset() };
         TestMemory { heap, hp }
     }
 
+    /// Address of the next allocation
+    pub fn heap_ptr(&self) -> usize {
+        self.hp
+    }
+
+    /// Sets the address of the next allocation, e.g. after a GC
+    pub fn set_heap_ptr(&mut self, hp: usize) {
+        self.hp = hp;
+    }
+
     unsafe fn grow_memory(&mut self, ptr: usize) {
         let heap_end = self.heap.as_ptr() as usize + self.heap.len();
         if ptr > heap_end {
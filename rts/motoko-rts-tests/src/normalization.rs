@@ -0,0 +1,219 @@
+//! Unicode normalization tests, using the conformance data in `data/normalization-test.txt`
+
+use crate::memory::TestMemory;
+
+use motoko_rts::memory::Memory;
+use motoko_rts::normalization::{
+    text_eq_normalized, text_is_normalized, text_normalize, NFC, NFD, NFKC, NFKD, UNICODE_VERSION,
+};
+use motoko_rts::text::{blob_of_text, text_compare, text_concat, text_of_str, text_singleton};
+use motoko_rts::types::{SkewedPtr, Words};
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use proptest::test_runner::{Config, TestCaseError, TestCaseResult, TestRunner};
+
+static TEST_DATA: &str = include_str!("../data/normalization-test.txt");
+
+/// Latin letters, combining diacritics, Latin-1 and Latin Extended-A letters, Hangul jamo and
+/// syllables, and Latin ligatures
+static PROPTEST_REGEX: &str = "[a-zA-Z\u{300}-\u{36F}\u{C0}-\u{17F}\u{1100}-\u{1175}\u{11A8}-\u{11C2}\u{AC00}-\u{AC40}\u{FB00}-\u{FB06}]{0,10}";
+
+static FORMS: [(u32, &str); 4] = [(NFC, "NFC"), (NFD, "NFD"), (NFKC, "NFKC"), (NFKD, "NFKD")];
+
+pub unsafe fn test() {
+    println!("Testing Unicode normalization ...");
+
+    let mut mem = TestMemory::new(Words(16 * 1024 * 1024));
+
+    println!("  Testing conformance data");
+    assert!(TEST_DATA.starts_with(&format!(
+        "# Normalization conformance data for Unicode {}.{}.{},",
+        UNICODE_VERSION.0, UNICODE_VERSION.1, UNICODE_VERSION.2
+    )));
+
+    let mut part1_chars: HashSet<u32> = HashSet::new();
+    let mut in_part1 = false;
+
+    for line in TEST_DATA.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('@') {
+            in_part1 = line.starts_with("@Part1");
+            continue;
+        }
+
+        let columns: Vec<String> = line.split(';').take(5).map(parse_column).collect();
+        assert_eq!(columns.len(), 5, "malformed test line: {}", line);
+
+        if in_part1 {
+            let mut chars = columns[0].chars();
+            part1_chars.insert(chars.next().unwrap() as u32);
+            assert!(chars.next().is_none());
+        }
+
+        check_conformance_line(&mut mem, &columns);
+    }
+
+    println!("  Testing characters not in conformance data");
+    for c in (0..0xD800).chain(0xE000..0x110000) {
+        if part1_chars.contains(&c) {
+            continue;
+        }
+        let text = text_singleton(&mut mem, c);
+        for (form, name) in FORMS.iter() {
+            assert!(
+                text_is_normalized(&mut mem, text, *form),
+                "{:#X} not in {}",
+                c,
+                name
+            );
+        }
+    }
+
+    println!("  Testing normalized equality");
+    let composed = text_of_str(&mut mem, "caf\u{E9}");
+    let decomposed = text_of_str(&mut mem, "cafe\u{301}");
+    assert_ne!(text_compare(composed, decomposed), 0);
+    for (form, _) in FORMS.iter() {
+        assert!(text_eq_normalized(&mut mem, composed, decomposed, *form));
+    }
+    let ligature = text_of_str(&mut mem, "\u{FB01}");
+    let fi = text_of_str(&mut mem, "fi");
+    assert!(!text_eq_normalized(&mut mem, ligature, fi, NFC));
+    assert!(text_eq_normalized(&mut mem, ligature, fi, NFKC));
+
+    println!("  Testing long combining character sequences");
+    let marks = "\u{301}\u{323}\u{31B}".repeat(20);
+    assert_eq!(
+        normalize(&mut mem, &format!("o{}", marks), NFD),
+        format!(
+            "o{}{}{}",
+            "\u{31B}".repeat(20),
+            "\u{323}".repeat(20),
+            "\u{301}".repeat(20)
+        )
+    );
+    assert_eq!(
+        normalize(&mut mem, &format!("o{}", marks), NFC),
+        format!(
+            "\u{1EE3}{}{}{}",
+            "\u{31B}".repeat(19),
+            "\u{323}".repeat(19),
+            "\u{301}".repeat(20)
+        )
+    );
+
+    drop(mem);
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 1_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    proptest_runner
+        .run(
+            &proptest::collection::vec(
+                proptest::string::string_regex(PROPTEST_REGEX).unwrap(),
+                1..5,
+            ),
+            |strs| {
+                let mut mem = TestMemory::new(Words(1024 * 1024));
+                normalization_prop(&mut mem, strs)
+            },
+        )
+        .unwrap();
+}
+
+fn parse_column(column: &str) -> String {
+    column
+        .split(' ')
+        .map(|c| char::try_from(u32::from_str_radix(c, 16).unwrap()).unwrap())
+        .collect()
+}
+
+unsafe fn text_to_string<M: Memory>(mem: &mut M, text: SkewedPtr) -> String {
+    let blob = blob_of_text(mem, text).as_blob();
+    let bytes = std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+unsafe fn normalize<M: Memory>(mem: &mut M, s: &str, form: u32) -> String {
+    let text = text_of_str(mem, s);
+    let normalized = text_normalize(mem, text, form);
+    let result = text_to_string(mem, normalized);
+
+    // `text_is_normalized` needs to agree with `text_normalize`
+    assert_eq!(
+        text_is_normalized(mem, text, form),
+        result == s,
+        "text_is_normalized({:?}, {})",
+        s,
+        form
+    );
+
+    result
+}
+
+/// Checks the invariants given in the header of NormalizationTest.txt
+unsafe fn check_conformance_line<M: Memory>(mem: &mut M, c: &[String]) {
+    for i in 0..3 {
+        assert_eq!(normalize(mem, &c[i], NFC), c[1], "NFC of {:?}", c[0]);
+        assert_eq!(normalize(mem, &c[i], NFD), c[2], "NFD of {:?}", c[0]);
+    }
+    for i in 3..5 {
+        assert_eq!(normalize(mem, &c[i], NFC), c[3], "NFC of {:?}", c[0]);
+        assert_eq!(normalize(mem, &c[i], NFD), c[4], "NFD of {:?}", c[0]);
+    }
+    for i in 0..5 {
+        assert_eq!(normalize(mem, &c[i], NFKC), c[3], "NFKC of {:?}", c[0]);
+        assert_eq!(normalize(mem, &c[i], NFKD), c[4], "NFKD of {:?}", c[0]);
+    }
+}
+
+fn normalization_prop<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
+    unsafe {
+        // Build a concatenation to check normalizing ropes
+        let mut text = text_of_str(mem, "");
+        for str in &strs {
+            let str_obj = text_of_str(mem, str);
+            text = text_concat(mem, text, str_obj);
+        }
+
+        let concat = strs.concat();
+
+        for (form, name) in FORMS.iter() {
+            let normalized = text_normalize(mem, text, *form);
+            let normalized_str = text_to_string(mem, normalized);
+
+            if normalize(mem, &concat, *form) != normalized_str {
+                return Err(TestCaseError::Fail(
+                    format!("{} of concatenation", name).into(),
+                ));
+            }
+
+            // Normalization is idempotent
+            if !text_is_normalized(mem, normalized, *form) {
+                return Err(TestCaseError::Fail(
+                    format!("{} not idempotent", name).into(),
+                ));
+            }
+
+            // Composed forms are the composition of the decomposed forms
+            if *form == NFC || *form == NFKC {
+                let decomposed_form = if *form == NFC { NFD } else { NFKD };
+                let decomposed = text_normalize(mem, text, decomposed_form);
+                if text_compare(text_normalize(mem, decomposed, *form), normalized) != 0 {
+                    return Err(TestCaseError::Fail(
+                        format!("{} of decomposition", name).into(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
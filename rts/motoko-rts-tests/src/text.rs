@@ -1,13 +1,20 @@
 //! Text and text iterator tests
 
+use crate::gc::utils::{GC, GC_IMPLS};
 use crate::memory::TestMemory;
 
-use motoko_rts::memory::Memory;
+use motoko_rts::gc::copying::copying_gc_internal;
+use motoko_rts::gc::mark_compact::compacting_gc_internal;
+use motoko_rts::memory::{alloc_array, Memory};
 use motoko_rts::text::{
-    blob_of_text, decode_code_point, text_compare, text_concat, text_len, text_of_str,
-    text_singleton, text_size,
+    blob_of_text, decode_code_point, text_char_at, text_compare, text_concat, text_len,
+    text_of_str, text_singleton, text_size, text_slice_chars,
+};
+use motoko_rts::text_index::text_index_cache_loc;
+use motoko_rts::text_iter::{
+    text_grapheme_iter, text_grapheme_iter_done, text_grapheme_iter_next, text_iter,
+    text_iter_done, text_iter_next, text_iter_rev, text_iter_rev_done, text_iter_rev_next,
 };
-use motoko_rts::text_iter::{text_iter, text_iter_done, text_iter_next};
 use motoko_rts::types::{Bytes, SkewedPtr, Words, TAG_BLOB};
 
 use std::convert::TryFrom;
@@ -16,6 +23,8 @@ use proptest::test_runner::{Config, TestCaseError, TestCaseResult, TestRunner};
 
 static STR: &str = "abcdefgh";
 
+static GRAPHEME_TEST_DATA: &str = include_str!("../data/grapheme-break-test.txt");
+
 struct TextIter<'a, M: Memory> {
     obj: SkewedPtr,
     mem: &'a mut M,
@@ -45,6 +54,64 @@ impl<'a, M: Memory> Iterator for TextIter<'a, M> {
     }
 }
 
+struct TextIterRev<'a, M: Memory> {
+    obj: SkewedPtr,
+    mem: &'a mut M,
+}
+
+impl<'a, M: Memory> TextIterRev<'a, M> {
+    fn from_text(mem: &'a mut M, text: SkewedPtr) -> Self {
+        TextIterRev {
+            obj: unsafe { text_iter_rev(mem, text) },
+            mem,
+        }
+    }
+}
+
+impl<'a, M: Memory> Iterator for TextIterRev<'a, M> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        unsafe {
+            if text_iter_rev_done(self.obj) == 1 {
+                None
+            } else {
+                let next = text_iter_rev_next(self.mem, self.obj);
+                Some(char::try_from(next).unwrap())
+            }
+        }
+    }
+}
+
+struct GraphemeIter<'a, M: Memory> {
+    obj: SkewedPtr,
+    mem: &'a mut M,
+}
+
+impl<'a, M: Memory> GraphemeIter<'a, M> {
+    fn from_text(mem: &'a mut M, text: SkewedPtr) -> Self {
+        GraphemeIter {
+            obj: unsafe { text_grapheme_iter(mem, text) },
+            mem,
+        }
+    }
+}
+
+impl<'a, M: Memory> Iterator for GraphemeIter<'a, M> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        unsafe {
+            if text_grapheme_iter_done(self.obj) == 1 {
+                None
+            } else {
+                let next = text_grapheme_iter_next(self.mem, self.obj);
+                Some(TextIter::from_text(self.mem, next).collect())
+            }
+        }
+    }
+}
+
 pub unsafe fn test() {
     println!("Testing text and text iterators ...");
 
@@ -76,6 +143,39 @@ pub unsafe fn test() {
     println!("  Testing concatenation");
     concat1(&mut mem);
 
+    println!("  Testing grapheme cluster conformance data");
+    grapheme_conformance(&mut mem);
+
+    println!("  Testing long grapheme clusters");
+    let long_cluster = format!("e{}", "\u{301}\u{323}".repeat(40));
+    let strs = [
+        "ab",
+        &long_cluster,
+        "\r\n\r\n",
+        "\u{1F1E6}\u{1F1E8}\u{1F1E6}",
+    ];
+    let text = concat_strs(&mut mem, &strs);
+    assert_eq!(
+        GraphemeIter::from_text(&mut mem, text).collect::<Vec<_>>(),
+        vec![
+            "a",
+            "b",
+            &long_cluster,
+            "\r\n",
+            "\r\n",
+            "\u{1F1E6}\u{1F1E8}",
+            "\u{1F1E6}"
+        ]
+    );
+
+    println!("  Testing character indexing");
+    char_indexing(&mut mem);
+
+    println!("  Testing character indexing across GC");
+    for gc in GC_IMPLS.iter() {
+        char_indexing_gc(*gc);
+    }
+
     drop(mem);
 
     let mut proptest_runner = TestRunner::new(Config {
@@ -95,6 +195,188 @@ pub unsafe fn test() {
         .unwrap();
 }
 
+unsafe fn concat_strs<M: Memory>(mem: &mut M, strs: &[&str]) -> SkewedPtr {
+    let mut obj = text_of_str(mem, "");
+    for str in strs {
+        let str_obj = text_of_str(mem, str);
+        obj = text_concat(mem, obj, str_obj);
+    }
+    obj
+}
+
+/// Checks `text_len`, `text_char_at` and `text_slice_chars` on ASCII and non-ASCII texts large
+/// enough to be indexed, as blobs and as concatenations, twice to also use the cached indices
+unsafe fn char_indexing<M: Memory>(mem: &mut M) {
+    let ascii = "abcdefghijklmnopqrstuvwxyz".repeat(50);
+    let mixed = "a\u{E9}\u{20AC}\u{1F600}bc".repeat(100);
+    let parts = [ascii.as_str(), mixed.as_str(), "xyz", mixed.as_str()];
+
+    for str in parts
+        .iter()
+        .copied()
+        .chain(std::iter::once(parts.concat().as_str()))
+    {
+        let chars: Vec<char> = str.chars().collect();
+        let blob = text_of_str(mem, str);
+        let concat = concat_strs(mem, &[&str[..3], &str[3..]]);
+
+        for _ in 0..2 {
+            for text in [blob, concat].iter().copied() {
+                assert_eq!(text_len(mem, text), chars.len() as u32);
+
+                for (i, c) in chars.iter().enumerate() {
+                    assert_eq!(text_char_at(mem, text, i as u32), *c as u32);
+                }
+
+                for (start, end) in [
+                    (0, 0),
+                    (0, chars.len()),
+                    (1, 65),
+                    (63, 64),
+                    (64, 200),
+                    (chars.len() / 2, chars.len()),
+                    (chars.len(), chars.len()),
+                ]
+                .iter()
+                .copied()
+                .filter(|(_, end)| *end <= chars.len())
+                {
+                    let slice = text_slice_chars(mem, text, start as u32, end as u32);
+                    assert_eq!(
+                        TextIter::from_text(mem, slice).collect::<String>(),
+                        chars[start..end].iter().collect::<String>()
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks that character indices built before a GC are moved with their blobs and are used after
+/// the GC
+unsafe fn char_indexing_gc(gc: GC) {
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    // Objects below the heap base are static: the static roots, and the root of the text
+    let static_roots = alloc_array(&mut mem, 0);
+    let root = alloc_array(&mut mem, 1).as_array().payload_addr();
+    let heap_base = mem.heap_ptr();
+
+    // Garbage before the text, so that the blobs and indices are moved
+    text_of_str(&mut mem, &"garbage".repeat(100));
+
+    let mixed = "a\u{E9}\u{20AC}\u{1F600}bc".repeat(100);
+    let ascii = "abcdefghijklmnopqrstuvwxyz".repeat(50);
+    let str = [mixed.as_str(), ascii.as_str(), mixed.as_str()].concat();
+    let chars: Vec<char> = str.chars().collect();
+
+    let text = concat_strs(
+        &mut mem,
+        &[
+            &mixed,
+            &ascii,
+            &mixed[..mixed.len() / 2],
+            &mixed[mixed.len() / 2..],
+        ],
+    );
+    for (i, c) in chars.iter().enumerate() {
+        assert_eq!(text_char_at(&mut mem, text, i as u32), *c as u32);
+    }
+
+    // The GC callbacks access the heap pointer while the GC has the memory
+    let mem_ptr: *mut TestMemory = &mut mem;
+    *root = text;
+    match gc {
+        GC::Copying => copying_gc_internal(
+            &mut *mem_ptr,
+            heap_base as u32,
+            // get_hp
+            || (*mem_ptr).heap_ptr(),
+            // set_hp
+            |hp| (*mem_ptr).set_heap_ptr(hp as usize),
+            static_roots,
+            root,
+            text_index_cache_loc(),
+            // note_live_size
+            |_live_size| {},
+            // note_reclaimed
+            |_reclaimed| {},
+        ),
+        GC::MarkCompact => compacting_gc_internal(
+            &mut *mem_ptr,
+            heap_base as u32,
+            // get_hp
+            || (*mem_ptr).heap_ptr(),
+            // set_hp
+            |hp| (*mem_ptr).set_heap_ptr(hp as usize),
+            static_roots,
+            root,
+            text_index_cache_loc(),
+            // note_live_size
+            |_live_size| {},
+            // note_reclaimed
+            |_reclaimed| {},
+        ),
+    }
+    assert_ne!((*root).0, text.0, "{:?}: text not moved", gc);
+    let text = *root;
+
+    // Lookups use the moved indices, nothing is allocated
+    let hp = mem.heap_ptr();
+    assert_eq!(text_len(&mut mem, text), chars.len() as u32);
+    for (i, c) in chars.iter().enumerate() {
+        assert_eq!(text_char_at(&mut mem, text, i as u32), *c as u32);
+    }
+    assert_eq!(mem.heap_ptr(), hp, "{:?}: indices rebuilt after GC", gc);
+
+    let slice = text_slice_chars(&mut mem, text, 50, 2000);
+    assert_eq!(
+        TextIter::from_text(&mut mem, slice).collect::<String>(),
+        chars[50..2000].iter().collect::<String>()
+    );
+}
+
+/// Checks grapheme cluster iteration on the test cases of GraphemeBreakTest.txt, both on a blob and
+/// on a concatenation of the individual characters
+unsafe fn grapheme_conformance<M: Memory>(mem: &mut M) {
+    for line in GRAPHEME_TEST_DATA.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let clusters: Vec<String> = line
+            .trim_matches(|c| c == '\u{F7}' || c == ' ')
+            .split('\u{F7}')
+            .map(|cluster| {
+                cluster
+                    .split('\u{D7}')
+                    .map(|c| char::try_from(u32::from_str_radix(c.trim(), 16).unwrap()).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let str = clusters.concat();
+
+        let blob = text_of_str(mem, &str);
+        assert_eq!(
+            GraphemeIter::from_text(mem, blob).collect::<Vec<_>>(),
+            clusters,
+            "{}",
+            line
+        );
+
+        let chars: Vec<String> = str.chars().map(|c| c.to_string()).collect();
+        let chars: Vec<&str> = chars.iter().map(|c| c.as_str()).collect();
+        let concat = concat_strs(mem, &chars);
+        assert_eq!(
+            GraphemeIter::from_text(mem, concat).collect::<Vec<_>>(),
+            clusters,
+            "{}",
+            line
+        );
+    }
+}
+
 unsafe fn concat1<M: Memory>(mem: &mut M) {
     // A simple test extracted from a QuickCheck generated test case
     let strs = ["a", "öabcdef", "y"];
@@ -108,7 +390,7 @@ unsafe fn concat1<M: Memory>(mem: &mut M) {
     let expected = strs.concat();
 
     // Check number of characters
-    assert_eq!(text_len(obj), expected.chars().count() as u32);
+    assert_eq!(text_len(mem, obj), expected.chars().count() as u32);
 
     // Check text size in bytes
     assert_eq!(text_size(obj), Bytes(expected.len() as u32));
@@ -117,7 +399,7 @@ unsafe fn concat1<M: Memory>(mem: &mut M) {
     let text_blob = blob_of_text(mem, obj);
 
     // Check number of characters in blob
-    assert_eq!(text_len(text_blob), expected.chars().count() as u32);
+    assert_eq!(text_len(mem, text_blob), expected.chars().count() as u32);
 
     // Check blob size in bytes
     assert_eq!(text_size(text_blob), Bytes(expected.len() as u32));
@@ -131,6 +413,12 @@ unsafe fn concat1<M: Memory>(mem: &mut M) {
 
     // Check concat iteration
     assert_eq!(TextIter::from_text(mem, obj).collect::<String>(), expected);
+
+    // Check reverse iteration
+    assert_eq!(
+        TextIterRev::from_text(mem, obj).collect::<String>(),
+        expected.chars().rev().collect::<String>()
+    );
 }
 
 fn concat_prop<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
@@ -144,7 +432,7 @@ fn concat_prop<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
         let expected = strs.concat();
 
         // Check number of characters
-        if text_len(obj) != expected.chars().count() as u32 {
+        if text_len(mem, obj) != expected.chars().count() as u32 {
             return Err(TestCaseError::Fail("text_len".into()));
         }
 
@@ -157,7 +445,7 @@ fn concat_prop<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
         let text_blob = blob_of_text(mem, obj);
 
         // Check number of characters in blob
-        if text_len(text_blob) != expected.chars().count() as u32 {
+        if text_len(mem, text_blob) != expected.chars().count() as u32 {
             return Err(TestCaseError::Fail("blob text_len".into()));
         }
 
@@ -182,6 +470,34 @@ fn concat_prop<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
             return Err(TestCaseError::Fail("iteration".into()));
         }
 
+        // Check reverse iteration
+        if TextIterRev::from_text(mem, obj).collect::<String>()
+            != expected.chars().rev().collect::<String>()
+        {
+            return Err(TestCaseError::Fail("reverse iteration".into()));
+        }
+
+        // Grapheme clusters partition the text
+        if GraphemeIter::from_text(mem, obj).collect::<String>() != expected {
+            return Err(TestCaseError::Fail("grapheme cluster iteration".into()));
+        }
+
+        // Check indexing by character
+        let chars: Vec<char> = expected.chars().collect();
+        for (i, c) in chars.iter().enumerate() {
+            if text_char_at(mem, obj, i as u32) != *c as u32 {
+                return Err(TestCaseError::Fail("text_char_at".into()));
+            }
+        }
+
+        let (start, end) = (chars.len() / 3, chars.len() * 2 / 3);
+        let slice = text_slice_chars(mem, obj, start as u32, end as u32);
+        if TextIter::from_text(mem, slice).collect::<String>()
+            != chars[start..end].iter().collect::<String>()
+        {
+            return Err(TestCaseError::Fail("text_slice_chars".into()));
+        }
+
         Ok(())
     }
 }
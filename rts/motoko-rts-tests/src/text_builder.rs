@@ -0,0 +1,131 @@
+//! Text builder tests
+
+use crate::memory::TestMemory;
+
+use motoko_rts::memory::{alloc_blob, Memory};
+use motoko_rts::text::{blob_of_text, text_concat, text_of_str};
+use motoko_rts::text_builder::{
+    text_builder_append_char, text_builder_append_text, text_builder_append_utf8,
+    text_builder_freeze, text_builder_new, text_builder_size, TextBuilderWriter,
+};
+use motoko_rts::types::{object_size, size_of, Bytes, SkewedPtr, TextBuilder, Words, TAG_BLOB};
+
+use std::fmt::Write;
+
+use proptest::test_runner::{Config, TestCaseError, TestCaseResult, TestRunner};
+
+pub unsafe fn test() {
+    println!("Testing text builders ...");
+
+    let mut mem = TestMemory::new(Words(1024 * 1024));
+
+    println!("  Testing appends");
+    let heap_start = mem.alloc_words(Words(0)).unskew();
+    let builder = text_builder_new(&mut mem);
+    assert_eq!(object_size(builder.unskew()).0, size_of::<TextBuilder>().0);
+    let empty = text_builder_freeze(&mut mem, builder);
+    assert_eq!(text_to_string(&mut mem, empty), "");
+
+    let hello = text_of_str(&mut mem, "hello ");
+    let world = text_of_str(&mut mem, "world, this is a concatenation");
+    let concat = text_concat(&mut mem, hello, world);
+    text_builder_append_text(&mut mem, builder, concat);
+    text_builder_append_char(&mut mem, builder, ' ' as u32);
+    text_builder_append_char(&mut mem, builder, 0x1F600);
+    let blob = make_blob(&mut mem, "caf\u{E9}".as_bytes());
+    text_builder_append_utf8(&mut mem, builder, blob);
+    let expected = "hello world, this is a concatenation \u{1F600}caf\u{E9}";
+    assert_eq!(text_builder_size(builder), Bytes(expected.len() as u32));
+
+    let text = text_builder_freeze(&mut mem, builder);
+    assert_eq!(text.tag(), TAG_BLOB);
+    assert_eq!(text_to_string(&mut mem, text), expected);
+    assert_eq!(text_builder_size(builder), Bytes(0));
+
+    // Shrinking the buffer leaves a heap that can be walked
+    println!("  Testing heap layout after freezing");
+    let heap_end = mem.alloc_words(Words(0)).unskew();
+    let mut p = heap_start;
+    while p < heap_end {
+        p += object_size(p).to_bytes().as_usize();
+    }
+    assert_eq!(p, heap_end);
+
+    println!("  Testing reuse after freezing");
+    text_builder_append_char(&mut mem, builder, 'x' as u32);
+    let text2 = text_builder_freeze(&mut mem, builder);
+    assert_eq!(text_to_string(&mut mem, text2), "x");
+    assert_eq!(text_to_string(&mut mem, text), expected);
+
+    println!("  Testing formatted writes");
+    let mut writer = TextBuilderWriter {
+        mem: &mut mem,
+        builder,
+    };
+    write!(&mut writer, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+    let text = text_builder_freeze(&mut mem, builder);
+    assert_eq!(text_to_string(&mut mem, text), "1 + 2 = 3");
+
+    drop(mem);
+
+    let mut proptest_runner = TestRunner::new(Config {
+        cases: 1_000,
+        failure_persistence: None,
+        ..Default::default()
+    });
+
+    proptest_runner
+        .run(
+            &proptest::collection::vec(proptest::string::string_regex(".{0, 20}").unwrap(), 0..50),
+            |strs| {
+                let mut mem = TestMemory::new(Words(1024 * 1024));
+                builder_prop(&mut mem, strs)
+            },
+        )
+        .unwrap();
+}
+
+unsafe fn make_blob<M: Memory>(mem: &mut M, bytes: &[u8]) -> SkewedPtr {
+    let blob = alloc_blob(mem, Bytes(bytes.len() as u32));
+    for (i, byte) in bytes.iter().enumerate() {
+        blob.as_blob().set(i as u32, *byte);
+    }
+    blob
+}
+
+unsafe fn text_to_string<M: Memory>(mem: &mut M, text: SkewedPtr) -> String {
+    let blob = blob_of_text(mem, text).as_blob();
+    let bytes = std::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+/// Appends the strings in different ways, checks the frozen text
+fn builder_prop<M: Memory>(mem: &mut M, strs: Vec<String>) -> TestCaseResult {
+    unsafe {
+        let builder = text_builder_new(mem);
+        for (i, str) in strs.iter().enumerate() {
+            match i % 3 {
+                0 => {
+                    let text = text_of_str(mem, str);
+                    text_builder_append_text(mem, builder, text);
+                }
+                1 => {
+                    for c in str.chars() {
+                        text_builder_append_char(mem, builder, c as u32);
+                    }
+                }
+                _ => {
+                    let blob = make_blob(mem, str.as_bytes());
+                    text_builder_append_utf8(mem, builder, blob);
+                }
+            }
+        }
+
+        let text = text_builder_freeze(mem, builder);
+        if text_to_string(mem, text) != strs.concat() {
+            return Err(TestCaseError::Fail("text_builder_freeze".into()));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+//! Looks up record fields in the first argument of an arbitrary message, the way generated code
+//! deserializes records: fields are looked up in increasing tag order, and the remaining ones are
+//! skipped at the end.
+//!
+//! Input format: a byte with the extended mode flag in the lowest bit and the number of fields to
+//! look up (up to 7) in the upper bits, that many little-endian `u32` tags, then the message.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::{find_field, skip_any, skip_fields, IDL_CON_record};
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts_fuzz::*;
+
+use std::convert::TryInto;
+
+fuzz_target!(|data: &[u8]| {
+    let (flags, rest) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    let extended = flags & 1 != 0;
+    let n_tags = ((flags >> 1) & 0b111) as usize;
+    if rest.len() < n_tags * 4 {
+        return;
+    }
+    let (tags, message) = rest.split_at(n_tags * 4);
+    let mut tags: Vec<u32> = tags
+        .chunks(4)
+        .map(|tag| u32::from_le_bytes(tag.try_into().unwrap()))
+        .collect();
+    tags.sort_unstable();
+    tags.dedup();
+
+    let mut mem = FuzzMemory::default();
+    let mut buf = buf_of(message);
+    catch_traps(|| unsafe {
+        let mut header = parse_header(&mut mem, &mut buf, extended);
+        if leb128_decode(&mut header.main_types) == 0 {
+            return;
+        }
+
+        // Only records have fields
+        let t = sleb128_decode(&mut header.main_types);
+        if t < 0 {
+            return;
+        }
+        let mut tb = Buf {
+            ptr: *header.typtbl.add(t as usize),
+            end: buf.end,
+        };
+        if sleb128_decode(&mut tb) != IDL_CON_record {
+            return;
+        }
+        let mut n = leb128_decode(&mut tb) as u8;
+
+        for &tag in &tags {
+            if find_field(&mut mem, &mut tb, &mut buf, header.typtbl, tag, &mut n) == 1 {
+                let it = sleb128_decode(&mut tb);
+                skip_any(&mut mem, &mut buf, header.typtbl, it, 0);
+            }
+            assert_in_bounds(buf.ptr, message);
+            assert_in_bounds(tb.ptr, message);
+        }
+
+        skip_fields(&mut mem, &mut tb, &mut buf, header.typtbl, &mut n);
+        assert_in_bounds(buf.ptr, message);
+    });
+});
@@ -0,0 +1,26 @@
+//! Parses the header of an arbitrary message. The first byte of the input selects extended mode.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use motoko_rts_fuzz::*;
+
+fuzz_target!(|data: &[u8]| {
+    let (extended, message) = match data.split_first() {
+        Some((flags, message)) => (flags & 1 != 0, message),
+        None => return,
+    };
+
+    let mut mem = FuzzMemory::default();
+    let mut buf = buf_of(message);
+    let header = match catch_traps(|| unsafe { parse_header(&mut mem, &mut buf, extended) }) {
+        Some(header) => header,
+        None => return,
+    };
+
+    assert_in_bounds(buf.ptr, message);
+    assert_in_bounds(header.main_types.ptr, message);
+    for i in 0..header.typtbl_size as usize {
+        assert_in_bounds(unsafe { *header.typtbl.add(i) }, message);
+    }
+});
@@ -0,0 +1,27 @@
+//! Parses the header of an arbitrary message and skips all arguments, like a canister method that
+//! ignores its arguments. The first byte of the input selects extended mode.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use motoko_rts::idl::skip_any;
+use motoko_rts::leb128::{leb128_decode, sleb128_decode};
+use motoko_rts_fuzz::*;
+
+fuzz_target!(|data: &[u8]| {
+    let (extended, message) = match data.split_first() {
+        Some((flags, message)) => (flags & 1 != 0, message),
+        None => return,
+    };
+
+    let mut mem = FuzzMemory::default();
+    let mut buf = buf_of(message);
+    catch_traps(|| unsafe {
+        let mut header = parse_header(&mut mem, &mut buf, extended);
+        for _ in 0..leb128_decode(&mut header.main_types) {
+            let t = sleb128_decode(&mut header.main_types);
+            skip_any(&mut mem, &mut buf, header.typtbl, t, 0);
+            assert_in_bounds(buf.ptr, message);
+        }
+    });
+});
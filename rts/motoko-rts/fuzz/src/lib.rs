@@ -0,0 +1,153 @@
+//! Support code for the fuzz targets in `fuzz_targets/`: a `Memory` implementation, the functions
+//! the RTS expects to be provided at link time, and a way to tell RTS traps apart from crashes.
+//!
+//! A trap is the expected outcome for most inputs: on the IC it rejects the message. Everything
+//! else (panics, crashes, buffer pointers moving past the end of the input, timeouts) is a bug.
+//! Sanitizers are not available for 32-bit targets, so the fuzz targets check the bounds of the
+//! buffers themselves.
+
+#![feature(ffi_returns_twice)]
+
+#[cfg(not(target_pointer_width = "32"))]
+compile_error!(
+    "Motoko RTS only works on 32-bit architectures, use --target i686-unknown-linux-gnu"
+);
+
+use motoko_rts::buf::Buf;
+use motoko_rts::idl::parse_idl_header;
+use motoko_rts::memory::Memory;
+use motoko_rts::types::{skew, Bytes, SkewedPtr, Words};
+
+/// A `Memory` that allocates every object on the Rust heap. Objects are freed when the
+/// `FuzzMemory` is dropped.
+#[derive(Default)]
+pub struct FuzzMemory {
+    objects: Vec<Box<[u32]>>,
+}
+
+impl Memory for FuzzMemory {
+    unsafe fn alloc_words(&mut self, n: Words<u32>) -> SkewedPtr {
+        let object = vec![0u32; n.0 as usize].into_boxed_slice();
+        let ptr = object.as_ptr() as usize;
+        self.objects.push(object);
+        skew(ptr)
+    }
+}
+
+/// glibc's `jmp_buf`, with room to spare
+#[repr(C, align(8))]
+struct JmpBuf([u8; 256]);
+
+extern "C" {
+    #[ffi_returns_twice]
+    fn _setjmp(env: *mut JmpBuf) -> i32;
+    fn longjmp(env: *mut JmpBuf, val: i32) -> !;
+}
+
+/// Where `rts_trap` returns to, set by `catch_traps`
+static mut TRAP_ENV: *mut JmpBuf = std::ptr::null_mut();
+
+// Called by the RTS to trap. Jumps back to `catch_traps`: unwinding through the RTS's `extern "C"`
+// functions is not allowed.
+#[no_mangle]
+extern "C" fn rts_trap(ptr: *const u8, len: Bytes<u32>) -> ! {
+    unsafe {
+        if TRAP_ENV.is_null() {
+            let msg = std::slice::from_raw_parts(ptr, len.0 as usize);
+            panic!(
+                "trap outside of catch_traps: {:?}",
+                String::from_utf8_lossy(msg)
+            );
+        }
+        longjmp(TRAP_ENV, 1)
+    }
+}
+
+// Called by the RTS for debug prints
+#[no_mangle]
+unsafe extern "C" fn print_ptr(ptr: usize, len: u32) {
+    let str: &[u8] = core::slice::from_raw_parts(ptr as *const u8, len as usize);
+    println!("[RTS] {}", String::from_utf8_lossy(str));
+}
+
+/// Runs `f`, returning `None` if it traps. Panics are reported as crashes by the panic hook
+/// installed by libFuzzer.
+///
+/// A trap jumps over the frames of `f` and the RTS without running destructors, so values owned by
+/// those frames are leaked. `f` should only capture references, and the RTS doesn't own values
+/// with destructors. State that `f` leaves behind after a trap must not be used, as on the IC,
+/// where a trap rolls back the message's changes.
+pub fn catch_traps<R, F: FnOnce() -> R>(f: F) -> Option<R> {
+    let mut f = Some(f);
+    let mut result = None;
+    let mut env = JmpBuf([0; 256]);
+    unsafe {
+        if _setjmp(&mut env) == 0 {
+            // Only memory is used after `_setjmp` returns a second time
+            run_with_trap_env(&mut env, &mut || result = Some((f.take().unwrap())()));
+        }
+        TRAP_ENV = std::ptr::null_mut();
+    }
+    result
+}
+
+#[inline(never)]
+unsafe fn run_with_trap_env(env: *mut JmpBuf, f: &mut dyn FnMut()) {
+    TRAP_ENV = env;
+    f();
+}
+
+/// Returns a `Buf` for reading `bytes`
+pub fn buf_of(bytes: &[u8]) -> Buf {
+    Buf {
+        ptr: bytes.as_ptr() as *mut u8,
+        end: unsafe { bytes.as_ptr().add(bytes.len()) as *mut u8 },
+    }
+}
+
+/// The result of `parse_idl_header`
+pub struct Header {
+    pub typtbl: *mut *mut u8,
+    pub typtbl_size: u32,
+    /// The list of main types, starting with the number of arguments
+    pub main_types: Buf,
+}
+
+/// Parses the header of the message in `buf`, leaving `buf` at the argument values. The main types
+/// buffer ends at the end of the message, as `parse_idl_header` has already checked the main types
+/// and only the arguments' values follow them.
+pub unsafe fn parse_header(mem: &mut FuzzMemory, buf: &mut Buf, extended: bool) -> Header {
+    let mut typtbl = std::ptr::null_mut();
+    let mut typtbl_size = 0;
+    let mut main_types = std::ptr::null_mut();
+    parse_idl_header(
+        mem,
+        extended,
+        buf,
+        &mut typtbl,
+        &mut typtbl_size,
+        &mut main_types,
+    );
+    Header {
+        typtbl,
+        typtbl_size,
+        main_types: Buf {
+            ptr: main_types,
+            end: buf.end,
+        },
+    }
+}
+
+/// Checks that a pointer into the input, e.g. the current position of a `Buf`, did not move past
+/// its end
+pub fn assert_in_bounds(ptr: *const u8, bytes: &[u8]) {
+    let start = bytes.as_ptr() as usize;
+    let end = start + bytes.len();
+    assert!(
+        start <= ptr as usize && ptr as usize <= end,
+        "pointer {:#x} out of input bounds {:#x}..{:#x}",
+        ptr as usize,
+        start,
+        end
+    );
+}
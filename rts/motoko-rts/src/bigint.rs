@@ -33,9 +33,15 @@ This scheme makes the following assumptions:
 
 use crate::buf::{read_byte, Buf};
 use crate::mem_utils::memcpy_bytes;
-use crate::memory::Memory;
+use crate::memory::{alloc_array, alloc_blob, Memory};
+use crate::print::WriteBuf;
+use crate::rts_trap_with;
+use crate::text::text_of_ptr_size;
 use crate::tommath_bindings::*;
-use crate::types::{size_of, skew, BigInt, Bytes, SkewedPtr, TAG_BIGINT};
+use crate::types::{size_of, skew, BigInt, Bytes, SkewedPtr, TAG_BIGINT, TAG_BLOB};
+
+use core::cmp::Ordering;
+use core::fmt::Write;
 
 use motoko_rts_macros::ic_mem_fn;
 
@@ -59,7 +65,7 @@ pub unsafe fn mp_calloc<M: Memory>(
     debug_assert_eq!(elem_size.0, core::mem::size_of::<mp_digit>());
     // Overflow check for the following multiplication
     if n_elems > 1 << 30 {
-        bigint_trap();
+        rts_trap_with("bigint: number too large");
     }
     let size = Bytes((n_elems * elem_size.0) as u32);
     let payload = mp_alloc(mem, size) as *mut u32;
@@ -114,27 +120,36 @@ Most libtommath operations return an enum to signal error codes. These are (see
    MP_BUF   = -5   /* buffer overflow, supplied buffer too small */
 
 We will never hit MP_MEM, because our allocation functions trap if they cannot allocate. But the
-others can happen (e.g. division by 0). In that case, we call a trap function provided by the
-compiler.
+others can happen (e.g. division by 0). Wrappers check the arguments for errors that users can
+cause before calling libtommath, and trap with a specific message. Remaining errors are reported
+by `check`, with the name of the operation and the description of the error code.
 */
 
-// Trap function generated by compiler. Originally added in e2ca6a1. I think this could be
-// simplified now by calling rts_trap and removing generated code from the compiler.
-extern "C" {
-    fn bigint_trap() -> !;
-}
-
-unsafe fn check(err: mp_err) {
+/// Traps when a libtommath operation done for `op` (the name of the RTS function, or the
+/// libtommath function for internal operations) returns an error
+unsafe fn check(err: mp_err, op: &str) {
     if err != 0 {
-        bigint_trap();
+        trap_mp_err(err, op);
     }
 }
 
+unsafe fn trap_mp_err(err: mp_err, op: &str) -> ! {
+    let mut buf = [0u8; 100];
+    let mut fmt = WriteBuf::new(&mut buf);
+    let _ = match err {
+        -2 => write!(&mut fmt, "{}: out of memory", op),
+        -3 => write!(&mut fmt, "{}: invalid argument", op),
+        -4 => write!(&mut fmt, "{}: maximum iterations reached", op),
+        -5 => write!(&mut fmt, "{}: buffer too small", op),
+        _ => write!(&mut fmt, "{}: unknown error {}", op, err),
+    };
+    rts_trap_with(fmt.as_str())
+}
+
 unsafe fn mp_get_u32(p: *const mp_int) -> u32 {
     mp_get_i32(p) as u32
 }
 
-#[cfg(feature = "ic")]
 unsafe fn mp_get_u64(p: *const mp_int) -> u64 {
     mp_get_i64(p) as u64
 }
@@ -150,12 +165,18 @@ unsafe fn mp_iszero(p: *const mp_int) -> bool {
 // Allocates a mp_int on the stack
 unsafe fn tmp_bigint() -> mp_int {
     let mut i: mp_int = core::mem::zeroed();
-    check(mp_init(&mut i));
+    check(mp_init(&mut i), "mp_init");
     i
 }
 
-// Persists an mp_int from the stack on the heap
+// Persists an mp_int from the stack on the heap. Numbers that fit in the compact representation
+// are returned as tagged scalars, the heap object is then garbage.
 unsafe fn persist_bigint(i: mp_int) -> SkewedPtr {
+    if mp_count_bits(&i) <= 31 {
+        if let Some(n) = compact(i64::from(mp_get_i32(&i))) {
+            return n;
+        }
+    }
     if i.dp == core::ptr::null_mut() {
         panic!("persist_bigint: dp == NULL?");
     }
@@ -167,33 +188,148 @@ unsafe fn persist_bigint(i: mp_int) -> SkewedPtr {
     skew(r as usize)
 }
 
-#[no_mangle]
-pub unsafe extern "C" fn bigint_of_word32(w: u32) -> SkewedPtr {
+/*
+Compact representation
+----------------------
+
+The compiler represents numbers in range `[-2^30, 2^30)` as tagged scalars: the number shifted
+left by one bit, with the lowest bit (which is always set in skewed pointers) cleared. See
+`BitTagged` and `MakeCompact` in compile.ml.
+
+All functions in this module that take numbers accept both representations, and all functions
+that return numbers return the compact representation when possible. Arithmetic on numbers that
+fit in an `i64` is done without libtommath when the result does not overflow, and only allocates
+when the result is not compact.
+*/
+
+const COMPACT_MIN: i64 = -(1 << 30);
+const COMPACT_MAX: i64 = (1 << 30) - 1;
+
+/// Value of a number in compact representation
+fn compact_value(n: SkewedPtr) -> i32 {
+    (n.0 as u32 as i32) >> 1
+}
+
+/// Compact representation of a number, if it is in range
+fn compact(n: i64) -> Option<SkewedPtr> {
+    if n >= COMPACT_MIN && n <= COMPACT_MAX {
+        Some(SkewedPtr(((n as i32) << 1) as u32 as usize))
+    } else {
+        None
+    }
+}
+
+unsafe fn persist_i64(n: i64) -> SkewedPtr {
+    match compact(n) {
+        Some(n) => n,
+        None => {
+            let mut i = tmp_bigint();
+            mp_set_i64(&mut i, n);
+            persist_bigint(i)
+        }
+    }
+}
+
+/// A number argument in either representation. Compact numbers are converted to a `mp_int` with
+/// digits on the stack on demand, so that they can be passed to libtommath without allocation.
+struct Operand {
+    n: SkewedPtr,
+    mp_int: mp_int,
+    // 2 digits are enough for 31 bits
+    digits: [mp_digit; 2],
+}
+
+impl Operand {
+    unsafe fn new(n: SkewedPtr) -> Operand {
+        Operand {
+            n,
+            mp_int: core::mem::zeroed(),
+            digits: [0; 2],
+        }
+    }
+
+    /// Pointer to the number as `mp_int`, valid as long as the operand is not moved
+    unsafe fn ptr(&mut self) -> *const mp_int {
+        if self.n.is_tagged_scalar() {
+            self.mp_int.alloc = self.digits.len() as i32;
+            self.mp_int.dp = self.digits.as_mut_ptr();
+            mp_set_i32(&mut self.mp_int, compact_value(self.n));
+            &self.mp_int
+        } else {
+            self.n.as_bigint().mp_int_ptr()
+        }
+    }
+
+    /// Value of the number, if it fits in an `i64`
+    unsafe fn small_value(&mut self) -> Option<i64> {
+        if self.n.is_tagged_scalar() {
+            Some(i64::from(compact_value(self.n)))
+        } else {
+            let p = self.ptr();
+            if mp_count_bits(p) <= 63 {
+                Some(mp_get_i64(p))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Applies `small` to the values of two numbers when both fit in an `i64` and the result does not
+/// overflow, `big` otherwise
+unsafe fn arith<F: FnOnce(*const mp_int, *const mp_int, *mut mp_int) -> mp_err>(
+    op: &str,
+    a: SkewedPtr,
+    b: SkewedPtr,
+    small: fn(i64, i64) -> Option<i64>,
+    big: F,
+) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
+    if let (Some(a), Some(b)) = (a.small_value(), b.small_value()) {
+        if let Some(n) = small(a, b) {
+            return persist_i64(n);
+        }
+    }
     let mut i = tmp_bigint();
-    mp_set_u32(&mut i, w);
+    check(big(a.ptr(), b.ptr(), &mut i), op);
     persist_bigint(i)
 }
 
+unsafe fn compare(a: SkewedPtr, b: SkewedPtr) -> Ordering {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
+    match (a.small_value(), b.small_value()) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        _ => mp_cmp(a.ptr(), b.ptr()).cmp(&0),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_of_word32(w: u32) -> SkewedPtr {
+    persist_i64(i64::from(w))
+}
+
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn bigint_of_int32(j: i32) -> SkewedPtr {
-    let mut i = tmp_bigint();
-    mp_set_i32(&mut i, j);
-    persist_bigint(i)
+    persist_i64(i64::from(j))
 }
 
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn bigint_to_word32_wrap(p: SkewedPtr) -> u32 {
-    mp_get_u32(p.as_bigint().mp_int_ptr())
+    let mut p = Operand::new(p);
+    mp_get_u32(p.ptr())
 }
 
 #[no_mangle]
-unsafe extern "C" fn bigint_to_word32_trap(p: SkewedPtr) -> u32 {
-    let mp_int = p.as_bigint().mp_int_ptr();
+pub unsafe extern "C" fn bigint_to_word32_trap(p: SkewedPtr) -> u32 {
+    let mut p = Operand::new(p);
+    let mp_int = p.ptr();
 
     if mp_isneg(mp_int) || mp_count_bits(mp_int) > 32 {
-        bigint_trap();
+        rts_trap_with("value out of range for Nat32");
     }
 
     mp_get_u32(mp_int)
@@ -203,7 +339,8 @@ unsafe extern "C" fn bigint_to_word32_trap(p: SkewedPtr) -> u32 {
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn bigint_to_word32_trap_with(p: SkewedPtr, msg: SkewedPtr) -> u32 {
-    let mp_int = p.as_bigint().mp_int_ptr();
+    let mut p = Operand::new(p);
+    let mp_int = p.ptr();
 
     if mp_isneg(mp_int) || mp_count_bits(mp_int) > 32 {
         crate::rts_trap(msg.as_blob().payload_addr(), msg.as_blob().len());
@@ -215,16 +352,17 @@ unsafe extern "C" fn bigint_to_word32_trap_with(p: SkewedPtr, msg: SkewedPtr) ->
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn bigint_to_word64_wrap(p: SkewedPtr) -> u64 {
-    mp_get_u64(p.as_bigint().mp_int_ptr())
+    let mut p = Operand::new(p);
+    mp_get_u64(p.ptr())
 }
 
-#[cfg(feature = "ic")]
 #[no_mangle]
-unsafe extern "C" fn bigint_to_word64_trap(p: SkewedPtr) -> u64 {
-    let mp_int = p.as_bigint().mp_int_ptr();
+pub unsafe extern "C" fn bigint_to_word64_trap(p: SkewedPtr) -> u64 {
+    let mut p = Operand::new(p);
+    let mp_int = p.ptr();
 
     if mp_isneg(mp_int) || mp_count_bits(mp_int) > 64 {
-        bigint_trap();
+        rts_trap_with("value out of range for Nat64");
     }
 
     mp_get_u64(mp_int)
@@ -233,6 +371,9 @@ unsafe extern "C" fn bigint_to_word64_trap(p: SkewedPtr) -> u64 {
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn bigint_of_word64(w: u64) -> SkewedPtr {
+    if w <= i64::MAX as u64 {
+        return persist_i64(w as i64);
+    }
     let mut i = tmp_bigint();
     mp_set_u64(&mut i, w);
     persist_bigint(i)
@@ -241,159 +382,391 @@ unsafe extern "C" fn bigint_of_word64(w: u64) -> SkewedPtr {
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn bigint_of_int64(j: i64) -> SkewedPtr {
+    persist_i64(j)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_eq(a: SkewedPtr, b: SkewedPtr) -> bool {
+    compare(a, b) == Ordering::Equal
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_lt(a: SkewedPtr, b: SkewedPtr) -> bool {
+    compare(a, b) == Ordering::Less
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_gt(a: SkewedPtr, b: SkewedPtr) -> bool {
+    compare(a, b) == Ordering::Greater
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_le(a: SkewedPtr, b: SkewedPtr) -> bool {
+    compare(a, b) != Ordering::Greater
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_ge(a: SkewedPtr, b: SkewedPtr) -> bool {
+    compare(a, b) != Ordering::Less
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_add(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    arith("bigint_add", a, b, i64::checked_add, |a, b, i| {
+        mp_add(a, b, i)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_sub(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    arith("bigint_sub", a, b, i64::checked_sub, |a, b, i| {
+        mp_sub(a, b, i)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_mul(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    arith("bigint_mul", a, b, i64::checked_mul, |a, b, i| {
+        mp_mul(a, b, i)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_pow(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
+    let b = b.ptr();
+    if mp_isneg(b) || mp_count_bits(b) > 32 {
+        rts_trap_with("bigint_pow: exponent out of range");
+    }
+    let exp = mp_get_u32(b);
     let mut i = tmp_bigint();
-    mp_set_i64(&mut i, j);
+    check(mp_expt_u32(a.ptr(), exp, &mut i), "bigint_pow");
     persist_bigint(i)
 }
 
+unsafe fn check_divisor(b: SkewedPtr) {
+    if mp_iszero(Operand::new(b).ptr()) {
+        rts_trap_with("division by zero");
+    }
+}
+
 #[no_mangle]
-pub unsafe extern "C" fn bigint_eq(a: SkewedPtr, b: SkewedPtr) -> bool {
-    mp_cmp(a.as_bigint().mp_int_ptr(), b.as_bigint().mp_int_ptr()) == 0
+pub unsafe extern "C" fn bigint_div(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    check_divisor(b);
+    arith("bigint_div", a, b, i64::checked_div, |a, b, i| {
+        mp_div(a, b, i, core::ptr::null_mut())
+    })
 }
 
-#[cfg(feature = "ic")]
 #[no_mangle]
-unsafe extern "C" fn bigint_lt(a: SkewedPtr, b: SkewedPtr) -> bool {
-    mp_cmp(a.as_bigint().mp_int_ptr(), b.as_bigint().mp_int_ptr()) < 0
+pub unsafe extern "C" fn bigint_rem(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    check_divisor(b);
+    arith("bigint_rem", a, b, i64::checked_rem, |a, b, i| {
+        mp_div(a, b, core::ptr::null_mut(), i)
+    })
 }
 
-#[cfg(feature = "ic")]
 #[no_mangle]
-unsafe extern "C" fn bigint_gt(a: SkewedPtr, b: SkewedPtr) -> bool {
-    mp_cmp(a.as_bigint().mp_int_ptr(), b.as_bigint().mp_int_ptr()) > 0
+pub unsafe extern "C" fn bigint_neg(a: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    if let Some(n) = a.small_value().and_then(i64::checked_neg) {
+        return persist_i64(n);
+    }
+    let mut i = tmp_bigint();
+    check(mp_neg(a.ptr(), &mut i), "bigint_neg");
+    persist_bigint(i)
 }
 
 #[cfg(feature = "ic")]
 #[no_mangle]
-unsafe extern "C" fn bigint_le(a: SkewedPtr, b: SkewedPtr) -> bool {
-    mp_cmp(a.as_bigint().mp_int_ptr(), b.as_bigint().mp_int_ptr()) <= 0
+unsafe extern "C" fn bigint_abs(a: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    if let Some(n) = a.small_value().and_then(i64::checked_abs) {
+        return persist_i64(n);
+    }
+    let mut i = tmp_bigint();
+    check(mp_abs(a.ptr(), &mut i), "bigint_abs");
+    persist_bigint(i)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn bigint_isneg(a: SkewedPtr) -> bool {
+    if a.is_tagged_scalar() {
+        return compact_value(a) < 0;
+    }
+    let mut a = Operand::new(a);
+    mp_isneg(a.ptr())
 }
 
 #[cfg(feature = "ic")]
 #[no_mangle]
-unsafe extern "C" fn bigint_ge(a: SkewedPtr, b: SkewedPtr) -> bool {
-    mp_cmp(a.as_bigint().mp_int_ptr(), b.as_bigint().mp_int_ptr()) >= 0
+unsafe extern "C" fn bigint_lsh(a: SkewedPtr, b: i32) -> SkewedPtr {
+    if b < 0 {
+        rts_trap_with("bigint_lsh: negative shift amount");
+    }
+    let mut a = Operand::new(a);
+    let mut i = tmp_bigint();
+    check(mp_mul_2d(a.ptr(), b, &mut i), "bigint_lsh");
+    persist_bigint(i)
 }
 
+/// Arithmetic right shift, rounding towards negative infinity
 #[no_mangle]
-pub unsafe extern "C" fn bigint_add(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_rsh(a: SkewedPtr, b: i32) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    if b < 0 {
+        rts_trap_with("bigint_rsh: negative shift amount");
+    }
     let mut i = tmp_bigint();
-    check(mp_add(
-        a.as_bigint().mp_int_ptr(),
-        b.as_bigint().mp_int_ptr(),
-        &mut i,
-    ));
+    check(mp_signed_rsh(a.ptr(), b, &mut i), "bigint_rsh");
     persist_bigint(i)
 }
 
+// Bitwise operations below treat numbers as two's complement with infinite sign extension, so
+// for example `-1` has all bits set.
+
 #[no_mangle]
-pub unsafe extern "C" fn bigint_sub(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_and(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
     let mut i = tmp_bigint();
-    check(mp_sub(
-        a.as_bigint().mp_int_ptr(),
-        b.as_bigint().mp_int_ptr(),
-        &mut i,
-    ));
+    check(mp_and(a.ptr(), b.ptr(), &mut i), "bigint_and");
     persist_bigint(i)
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn bigint_mul(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_or(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
     let mut i = tmp_bigint();
-    check(mp_mul(
-        a.as_bigint().mp_int_ptr(),
-        b.as_bigint().mp_int_ptr(),
-        &mut i,
-    ));
+    check(mp_or(a.ptr(), b.ptr(), &mut i), "bigint_or");
     persist_bigint(i)
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn bigint_pow(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
-    let exp = bigint_to_word32_trap(b);
+pub unsafe extern "C" fn bigint_xor(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
     let mut i = tmp_bigint();
-    check(mp_expt_u32(a.as_bigint().mp_int_ptr(), exp, &mut i));
+    check(mp_xor(a.ptr(), b.ptr(), &mut i), "bigint_xor");
     persist_bigint(i)
 }
 
-#[cfg(feature = "ic")]
+/// Bitwise complement, `-a - 1`
 #[no_mangle]
-unsafe extern "C" fn bigint_div(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_not(a: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
     let mut i = tmp_bigint();
-    check(mp_div(
-        a.as_bigint().mp_int_ptr(),
-        b.as_bigint().mp_int_ptr(),
-        &mut i,
-        core::ptr::null_mut(),
-    ));
+    check(mp_complement(a.ptr(), &mut i), "bigint_not");
     persist_bigint(i)
 }
 
-#[cfg(feature = "ic")]
+/// Returns whether bit `bit` of the two's complement representation is set
+#[no_mangle]
+pub unsafe extern "C" fn bigint_test_bit(a: SkewedPtr, bit: u32) -> bool {
+    let mut a = Operand::new(a);
+    let a = a.ptr();
+
+    // Bits of a negative number are the inverted bits of its complement, which is non-negative
+    let neg = mp_isneg(a);
+    let mut tmp = tmp_bigint();
+    if neg {
+        check(mp_complement(a, &mut tmp), "bigint_test_bit");
+    } else {
+        check(mp_copy(a, &mut tmp), "bigint_test_bit");
+    }
+
+    if bit >= mp_count_bits(&tmp) as u32 {
+        return neg;
+    }
+
+    check(
+        mp_div_2d(&tmp, bit as i32, &mut tmp, core::ptr::null_mut()),
+        "bigint_test_bit",
+    );
+    (mp_get_u32(&tmp) & 1 == 1) != neg
+}
+
+/// Returns the number with bit `bit` of the two's complement representation set
 #[no_mangle]
-unsafe extern "C" fn bigint_rem(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_set_bit(a: SkewedPtr, bit: u32) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    if bit > i32::MAX as u32 {
+        rts_trap_with("bigint_set_bit: bit index too large");
+    }
+    let mut mask = tmp_bigint();
+    check(mp_2expt(&mut mask, bit as i32), "bigint_set_bit");
     let mut i = tmp_bigint();
-    check(mp_div(
-        a.as_bigint().mp_int_ptr(),
-        b.as_bigint().mp_int_ptr(),
-        core::ptr::null_mut(),
-        &mut i,
-    ));
+    check(mp_or(a.ptr(), &mask, &mut i), "bigint_set_bit");
     persist_bigint(i)
 }
 
+/// Number of set bits in the magnitude of the number
 #[no_mangle]
-pub unsafe extern "C" fn bigint_neg(a: SkewedPtr) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_popcount(a: SkewedPtr) -> u32 {
+    let mut a = Operand::new(a);
+    let a = a.ptr();
+    // Unused bits of digits are always zero
+    let mut count = 0;
+    for i in 0..(*a).used as usize {
+        count += (*(*a).dp.add(i)).count_ones();
+    }
+    count
+}
+
+#[no_mangle]
+unsafe extern "C" fn bigint_count_bits(a: SkewedPtr) -> i32 {
+    let mut a = Operand::new(a);
+    mp_count_bits(a.ptr())
+}
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0) = 0`.
+#[no_mangle]
+pub unsafe extern "C" fn bigint_gcd(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
     let mut i = tmp_bigint();
-    check(mp_neg(a.as_bigint().mp_int_ptr(), &mut i));
+    check(mp_gcd(a.ptr(), b.ptr(), &mut i), "bigint_gcd");
     persist_bigint(i)
 }
 
-#[cfg(feature = "ic")]
+/// Least common multiple, always non-negative. `lcm(a, 0) = 0`.
 #[no_mangle]
-unsafe extern "C" fn bigint_abs(a: SkewedPtr) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_lcm(a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
+    let a = a.ptr();
+    let b = b.ptr();
     let mut i = tmp_bigint();
-    check(mp_abs(a.as_bigint().mp_int_ptr(), &mut i));
+    // libtommath divides by the gcd, which is 0 when both are 0
+    if !mp_iszero(a) && !mp_iszero(b) {
+        check(mp_lcm(a, b, &mut i), "bigint_lcm");
+    }
     persist_bigint(i)
 }
 
-#[cfg(feature = "ic")]
+/// `base ** exp mod modulus`, in range `[0, modulus)`. The modulus must be positive. A negative
+/// exponent is allowed when the base is invertible modulo the modulus.
 #[no_mangle]
-unsafe extern "C" fn bigint_isneg(a: SkewedPtr) -> bool {
-    mp_isneg(a.as_bigint().mp_int_ptr())
+pub unsafe extern "C" fn bigint_modpow(
+    base: SkewedPtr,
+    exp: SkewedPtr,
+    modulus: SkewedPtr,
+) -> SkewedPtr {
+    let mut base = Operand::new(base);
+    let mut exp = Operand::new(exp);
+    let mut modulus = Operand::new(modulus);
+    let base = base.ptr();
+    let exp = exp.ptr();
+    let modulus = modulus.ptr();
+
+    if mp_isneg(modulus) || mp_iszero(modulus) {
+        rts_trap_with("bigint_modpow: modulus must be positive");
+    }
+
+    let mut i = tmp_bigint();
+
+    // Everything is 0 modulo 1
+    if mp_cmp_d(modulus, 1) == 0 {
+        return persist_bigint(i);
+    }
+
+    // Reduce the base first, so that libtommath only sees non-negative bases
+    let mut base_mod = tmp_bigint();
+    check(mp_mod(base, modulus, &mut base_mod), "bigint_modpow");
+
+    if mp_isneg(exp) && !is_invertible("bigint_modpow", &base_mod, modulus) {
+        rts_trap_with("bigint_modpow: base not invertible for negative exponent");
+    }
+
+    check(mp_exptmod(&base_mod, exp, modulus, &mut i), "bigint_modpow");
+    persist_bigint(i)
 }
 
-#[cfg(feature = "ic")]
+unsafe fn is_invertible(op: &str, a: *const mp_int, modulus: *const mp_int) -> bool {
+    let mut gcd = tmp_bigint();
+    check(mp_gcd(a, modulus, &mut gcd), op);
+    mp_cmp_d(&gcd, 1) == 0
+}
+
+/// Inverse of `a` modulo `modulus`, in range `[0, modulus)`. Traps when the modulus is not greater
+/// than 1, or when `a` and the modulus are not coprime.
 #[no_mangle]
-unsafe extern "C" fn bigint_lsh(a: SkewedPtr, b: i32) -> SkewedPtr {
+pub unsafe extern "C" fn bigint_modinv(a: SkewedPtr, modulus: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let mut modulus = Operand::new(modulus);
+    let a = a.ptr();
+    let modulus = modulus.ptr();
+
+    if mp_isneg(modulus) || mp_cmp_d(modulus, 1) <= 0 {
+        rts_trap_with("bigint_modinv: modulus must be greater than 1");
+    }
+
+    if !is_invertible("bigint_modinv", a, modulus) {
+        rts_trap_with("bigint_modinv: not invertible");
+    }
+
     let mut i = tmp_bigint();
-    check(mp_mul_2d(a.as_bigint().mp_int_ptr(), b, &mut i));
+    check(mp_invmod(a, modulus, &mut i), "bigint_modinv");
     persist_bigint(i)
 }
 
+/// Integer square root: the largest `r` with `r * r <= a`. Traps on negative numbers.
 #[no_mangle]
-unsafe extern "C" fn bigint_count_bits(a: SkewedPtr) -> i32 {
-    mp_count_bits(a.as_bigint().mp_int_ptr())
+pub unsafe extern "C" fn bigint_isqrt(a: SkewedPtr) -> SkewedPtr {
+    let mut a = Operand::new(a);
+    let a = a.ptr();
+
+    if mp_isneg(a) {
+        rts_trap_with("bigint_isqrt: negative argument");
+    }
+
+    let mut i = tmp_bigint();
+    check(mp_sqrt(a, &mut i), "bigint_isqrt");
+    persist_bigint(i)
+}
+
+/// Quotient and remainder of a division, as a tuple `(a / b, a % b)`. Same rounding as
+/// `bigint_div` and `bigint_rem` (quotient rounded towards zero, remainder has the sign of `a`).
+#[ic_mem_fn]
+pub unsafe fn bigint_divmod<M: Memory>(mem: &mut M, a: SkewedPtr, b: SkewedPtr) -> SkewedPtr {
+    check_divisor(b);
+
+    let mut a = Operand::new(a);
+    let mut b = Operand::new(b);
+    let mut q = tmp_bigint();
+    let mut r = tmp_bigint();
+    check(mp_div(a.ptr(), b.ptr(), &mut q, &mut r), "bigint_divmod");
+
+    let q = persist_bigint(q);
+    let r = persist_bigint(r);
+
+    let tuple = alloc_array(mem, 2);
+    let tuple_array = tuple.as_array();
+    tuple_array.set(0, q);
+    tuple_array.set(1, r);
+    tuple
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn bigint_leb128_size(a: SkewedPtr) -> u32 {
-    if mp_iszero(a.as_bigint().mp_int_ptr()) {
+    let mut a = Operand::new(a);
+    let a = a.ptr();
+    if mp_iszero(a) {
         1
     } else {
-        (bigint_count_bits(a) as u32 + 6) / 7 // divide by 7, round up
+        (mp_count_bits(a) as u32 + 6) / 7 // divide by 7, round up
     }
 }
 
 // `add_bit` argument is to make this work for both leb and sleb encoding
-unsafe fn bigint_leb128_encode_go(tmp: *mut mp_int, mut buf: *mut u8, add_bit: bool) {
-    if mp_isneg(tmp) {
-        bigint_trap();
-    }
+unsafe fn bigint_leb128_encode_go(op: &str, tmp: *mut mp_int, mut buf: *mut u8, add_bit: bool) {
+    debug_assert!(!mp_isneg(tmp));
 
     loop {
         let byte = mp_get_u32(tmp) as u8;
-        check(mp_div_2d(tmp, 7, tmp, core::ptr::null_mut()));
+        check(mp_div_2d(tmp, 7, tmp, core::ptr::null_mut()), op);
         if !mp_iszero(tmp) || (add_bit && byte & (1 << 6) != 0) {
             *buf = byte | (1 << 7);
             buf = buf.add(1);
@@ -407,17 +780,24 @@ unsafe fn bigint_leb128_encode_go(tmp: *mut mp_int, mut buf: *mut u8, add_bit: b
 #[no_mangle]
 pub unsafe extern "C" fn bigint_leb128_encode(n: SkewedPtr, buf: *mut u8) {
     let mut tmp: mp_int = core::mem::zeroed(); // or core::mem::uninitialized?
-    check(mp_init_copy(&mut tmp, n.as_bigint().mp_int_ptr()));
-    bigint_leb128_encode_go(&mut tmp, buf, false)
+    check(
+        mp_init_copy(&mut tmp, Operand::new(n).ptr()),
+        "bigint_leb128_encode",
+    );
+    if mp_isneg(&tmp) {
+        rts_trap_with("bigint_leb128_encode: negative number");
+    }
+    bigint_leb128_encode_go("bigint_leb128_encode", &mut tmp, buf, false)
 }
 
 #[no_mangle]
 unsafe extern "C" fn bigint_2complement_bits(n: SkewedPtr) -> u32 {
-    let mp_int = n.as_bigint().mp_int_ptr();
+    let mut n = Operand::new(n);
+    let mp_int = n.ptr();
     if mp_isneg(mp_int) {
         let mut tmp: mp_int = core::mem::zeroed(); // or core::mem::uninitialized?
-        check(mp_init_copy(&mut tmp, mp_int));
-        check(mp_incr(&mut tmp));
+        check(mp_init_copy(&mut tmp, mp_int), "bigint_2complement_bits");
+        check(mp_incr(&mut tmp), "bigint_2complement_bits");
         1 + mp_count_bits(&tmp) as u32
     } else {
         1 + mp_count_bits(mp_int) as u32
@@ -432,18 +812,24 @@ pub unsafe extern "C" fn bigint_sleb128_size(n: SkewedPtr) -> u32 {
 #[no_mangle]
 pub unsafe extern "C" fn bigint_sleb128_encode(n: SkewedPtr, buf: *mut u8) {
     let mut tmp: mp_int = core::mem::zeroed(); // or core::mem::uninitialized?
-    check(mp_init_copy(&mut tmp, n.as_bigint().mp_int_ptr()));
+    check(
+        mp_init_copy(&mut tmp, Operand::new(n).ptr()),
+        "bigint_sleb128_encode",
+    );
 
     if mp_isneg(&tmp) {
         // Turn negatiave numbers into the two's complement of the right size
         let bytes = bigint_sleb128_size(n);
         let mut big: mp_int = core::mem::zeroed();
-        check(mp_init(&mut big));
-        check(mp_2expt(&mut big, 7 * bytes as i32));
-        check(mp_add(&mut tmp, &big, &mut tmp));
-        bigint_leb128_encode_go(&mut tmp, buf, false)
+        check(mp_init(&mut big), "bigint_sleb128_encode");
+        check(
+            mp_2expt(&mut big, 7 * bytes as i32),
+            "bigint_sleb128_encode",
+        );
+        check(mp_add(&mut tmp, &big, &mut tmp), "bigint_sleb128_encode");
+        bigint_leb128_encode_go("bigint_sleb128_encode", &mut tmp, buf, false)
     } else {
-        bigint_leb128_encode_go(&mut tmp, buf, true)
+        bigint_leb128_encode_go("bigint_sleb128_encode", &mut tmp, buf, true)
     }
 }
 
@@ -456,8 +842,8 @@ pub unsafe extern "C" fn bigint_leb128_decode(buf: *mut Buf) -> SkewedPtr {
     loop {
         let byte = read_byte(buf);
         mp_set_u32(&mut tmp, (byte & 0b0111_1111) as u32);
-        check(mp_mul_2d(&mut tmp, shift, &mut tmp));
-        check(mp_add(&mut i, &tmp, &mut i));
+        check(mp_mul_2d(&mut tmp, shift, &mut tmp), "bigint_leb128_decode");
+        check(mp_add(&mut i, &tmp, &mut i), "bigint_leb128_decode");
         shift += 7;
 
         if byte & 0b1000_0000 == 0 {
@@ -478,8 +864,11 @@ pub unsafe extern "C" fn bigint_sleb128_decode(buf: *mut Buf) -> SkewedPtr {
     loop {
         let byte = read_byte(buf);
         mp_set_u32(&mut tmp, (byte & 0b0111_1111) as u32);
-        check(mp_mul_2d(&mut tmp, shift, &mut tmp));
-        check(mp_add(&mut i, &tmp, &mut i));
+        check(
+            mp_mul_2d(&mut tmp, shift, &mut tmp),
+            "bigint_sleb128_decode",
+        );
+        check(mp_add(&mut i, &tmp, &mut i), "bigint_sleb128_decode");
         last_sign_bit_set = byte & 0b0100_0000 != 0;
         shift += 7;
 
@@ -491,9 +880,431 @@ pub unsafe extern "C" fn bigint_sleb128_decode(buf: *mut Buf) -> SkewedPtr {
     if last_sign_bit_set {
         // Negative number, un-2-complement it
         let mut big = tmp_bigint();
-        check(mp_2expt(&mut big, shift));
-        check(mp_sub(&mut i, &big, &mut i));
+        check(mp_2expt(&mut big, shift), "bigint_sleb128_decode");
+        check(mp_sub(&mut i, &big, &mut i), "bigint_sleb128_decode");
+    }
+
+    persist_bigint(i)
+}
+
+//
+// Conversion to and from blobs
+//
+
+/// Least significant byte first
+pub const LITTLE_ENDIAN: u32 = 0;
+
+/// Most significant byte first
+pub const BIG_ENDIAN: u32 = 1;
+
+unsafe fn check_endianness(fn_name: &str, endianness: u32) {
+    if endianness > BIG_ENDIAN {
+        let mut buf = [0u8; 100];
+        let mut fmt = WriteBuf::new(&mut buf);
+        let _ = write!(&mut fmt, "{}: unknown endianness", fn_name);
+        rts_trap_with(fmt.as_str());
+    }
+}
+
+unsafe fn reverse_bytes(bytes: *mut u8, len: u32) {
+    core::slice::from_raw_parts_mut(bytes, len as usize).reverse();
+}
+
+/// Writes a non-negative number to a new blob of the given width, which needs to be large enough
+unsafe fn non_negative_to_blob<M: Memory>(
+    mem: &mut M,
+    op: &str,
+    n: *const mp_int,
+    endianness: u32,
+    width: u32,
+) -> SkewedPtr {
+    let size = mp_ubin_size(n) as u32;
+    debug_assert!(size <= width);
+
+    let blob = alloc_blob(mem, Bytes(width));
+    let payload = blob.as_blob().payload_addr();
+
+    let padding = width - size;
+    for i in 0..padding {
+        *payload.add(i as usize) = 0;
+    }
+
+    let mut written = 0;
+    check(
+        mp_to_ubin(
+            n,
+            payload.add(padding as usize),
+            size as usize,
+            &mut written,
+        ),
+        op,
+    );
+
+    if endianness == LITTLE_ENDIAN {
+        reverse_bytes(payload, width);
+    }
+
+    blob
+}
+
+unsafe fn trap_width(fn_name: &str, width: u32) -> ! {
+    let mut buf = [0u8; 100];
+    let mut fmt = WriteBuf::new(&mut buf);
+    let _ = write!(
+        &mut fmt,
+        "{}: number does not fit in {} bytes",
+        fn_name, width
+    );
+    rts_trap_with(fmt.as_str())
+}
+
+/// Encodes a non-negative number as bytes in the given endianness (`LITTLE_ENDIAN` or
+/// `BIG_ENDIAN`). With `width` 0 the minimal number of bytes is used (one byte for 0), otherwise
+/// the result is padded to `width` bytes. Traps on negative numbers and numbers that do not fit.
+#[ic_mem_fn]
+pub unsafe fn bigint_to_blob<M: Memory>(
+    mem: &mut M,
+    n: SkewedPtr,
+    endianness: u32,
+    width: u32,
+) -> SkewedPtr {
+    let mut n = Operand::new(n);
+    check_endianness("bigint_to_blob", endianness);
+
+    let n = n.ptr();
+    if mp_isneg(n) {
+        rts_trap_with("bigint_to_blob: negative number");
+    }
+
+    let size = core::cmp::max(mp_ubin_size(n) as u32, 1);
+    if width != 0 && size > width {
+        trap_width("bigint_to_blob", width);
+    }
+
+    non_negative_to_blob(
+        mem,
+        "bigint_to_blob",
+        n,
+        endianness,
+        if width == 0 { size } else { width },
+    )
+}
+
+/// Like `bigint_to_blob`, but encodes numbers in two's complement, so negative numbers are
+/// allowed. The minimal encoding has enough bytes for the sign bit.
+#[ic_mem_fn]
+pub unsafe fn bigint_to_blob_signed<M: Memory>(
+    mem: &mut M,
+    n: SkewedPtr,
+    endianness: u32,
+    width: u32,
+) -> SkewedPtr {
+    check_endianness("bigint_to_blob_signed", endianness);
+
+    let size = (bigint_2complement_bits(n) + 7) / 8;
+    if width != 0 && size > width {
+        trap_width("bigint_to_blob_signed", width);
+    }
+    let width = if width == 0 { size } else { width };
+
+    let mut n = Operand::new(n);
+    let n = n.ptr();
+    if mp_isneg(n) {
+        // Encode `2 ** (8 * width) + n`
+        let mut tmp = tmp_bigint();
+        check(
+            mp_2expt(&mut tmp, 8 * width as i32),
+            "bigint_to_blob_signed",
+        );
+        check(mp_add(&tmp, n, &mut tmp), "bigint_to_blob_signed");
+        non_negative_to_blob(mem, "bigint_to_blob_signed", &tmp, endianness, width)
+    } else {
+        non_negative_to_blob(mem, "bigint_to_blob_signed", n, endianness, width)
+    }
+}
+
+/// Decodes bytes in the given endianness as a non-negative number, or as a number in two's
+/// complement when `signed` is true. An empty blob decodes as 0.
+#[ic_mem_fn]
+pub unsafe fn bigint_of_blob<M: Memory>(
+    mem: &mut M,
+    blob: SkewedPtr,
+    endianness: u32,
+    signed: bool,
+) -> SkewedPtr {
+    check_endianness("bigint_of_blob", endianness);
+
+    let len = blob.as_blob().len().0;
+    let mut bytes = blob.as_blob().payload_addr();
+
+    // libtommath reads big endian
+    if endianness == LITTLE_ENDIAN && len > 1 {
+        let copy = alloc_blob(mem, Bytes(len));
+        let copy_bytes = copy.as_blob().payload_addr();
+        memcpy_bytes(copy_bytes as usize, bytes as usize, Bytes(len));
+        reverse_bytes(copy_bytes, len);
+        bytes = copy_bytes;
+    }
+
+    let mut i = tmp_bigint();
+    check(mp_from_ubin(&mut i, bytes, len as usize), "bigint_of_blob");
+
+    if signed && len != 0 && *bytes & 0x80 != 0 {
+        // Negative, subtract `2 ** (8 * len)`
+        let mut tmp = tmp_bigint();
+        check(mp_2expt(&mut tmp, 8 * len as i32), "bigint_of_blob");
+        check(mp_sub(&i, &tmp, &mut i), "bigint_of_blob");
+    }
+
+    persist_bigint(i)
+}
+
+//
+// Conversion to and from floats
+//
+
+/// Multiplies a float with `2 ** exp`, exactly unless the result overflows
+fn scale_float(mut f: f64, mut exp: u32) -> f64 {
+    while exp != 0 && f.is_finite() {
+        let step = core::cmp::min(exp, 1023);
+        f *= f64::from_bits(u64::from(1023 + step) << 52);
+        exp -= step;
+    }
+    f
+}
+
+/// Converts the number to the nearest float, rounding ties to even. Numbers too large for a float
+/// become infinity.
+#[no_mangle]
+pub unsafe extern "C" fn bigint_to_float64(n: SkewedPtr) -> f64 {
+    let mut n = Operand::new(n);
+    let n = n.ptr();
+
+    let bits = mp_count_bits(n) as u32;
+
+    let magnitude = if bits <= 64 {
+        // Conversion from `u64` rounds correctly
+        mp_get_mag_u64(n) as f64
+    } else {
+        // Take the 64 most significant bits, and set the least significant one of them if any of
+        // the dropped bits are set. This does not change the rounding of the 53 bit mantissa, but
+        // makes sure ties are only detected when the dropped bits are all zero.
+        let shift = bits - 64;
+        let mut tmp = tmp_bigint();
+        check(
+            mp_div_2d(n, shift as i32, &mut tmp, core::ptr::null_mut()),
+            "bigint_to_float64",
+        );
+        let mut top = mp_get_mag_u64(&tmp);
+        if (mp_cnt_lsb(n) as u32) < shift {
+            top |= 1;
+        }
+        scale_float(top as f64, shift)
+    };
+
+    if mp_isneg(n) {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Converts a float to a number, rounding towards zero. Traps on NaN and infinity.
+#[no_mangle]
+pub unsafe extern "C" fn bigint_of_float64(f: f64) -> SkewedPtr {
+    if f.is_nan() {
+        rts_trap_with("bigint_of_float64: NaN");
+    }
+    if f.is_infinite() {
+        rts_trap_with("bigint_of_float64: infinity");
+    }
+
+    let bits = f.to_bits();
+    let biased_exp = ((bits >> 52) & 0x7FF) as i32;
+    let mut i = tmp_bigint();
+
+    // Anything below 1 (including subnormals) truncates to 0
+    if biased_exp >= 1023 {
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        // The float is `mantissa * 2 ** exp`
+        let exp = biased_exp - 1075;
+        if exp < 0 {
+            mp_set_u64(&mut i, mantissa >> -exp);
+        } else {
+            mp_set_u64(&mut i, mantissa);
+            check(mp_mul_2d(&i, exp, &mut i), "bigint_of_float64");
+        }
+        if f < 0.0 {
+            check(mp_neg(&i, &mut i), "bigint_of_float64");
+        }
     }
 
     persist_bigint(i)
 }
+
+//
+// Conversion to and from text
+//
+
+unsafe fn check_radix(fn_name: &str, radix: u32) {
+    if radix < 2 || radix > 36 {
+        let mut buf = [0u8; 100];
+        let mut fmt = WriteBuf::new(&mut buf);
+        let _ = write!(&mut fmt, "{}: radix must be between 2 and 36", fn_name);
+        rts_trap_with(fmt.as_str());
+    }
+}
+
+/// Converts the number to text in the given radix (2 to 36), with a `-` prefix for negative
+/// numbers and lower case letters for digits above 9
+#[ic_mem_fn]
+pub unsafe fn bigint_to_text<M: Memory>(mem: &mut M, n: SkewedPtr, radix: u32) -> SkewedPtr {
+    let mut n = Operand::new(n);
+    check_radix("bigint_to_text", radix);
+
+    let n = n.ptr();
+
+    // Size including the sign and the terminating NUL
+    let mut size = 0;
+    check(mp_radix_size(n, radix as i32, &mut size), "bigint_to_text");
+
+    let buf = alloc_blob(mem, Bytes(size as u32));
+    let buf_ptr = buf.as_blob().payload_addr();
+
+    let mut written = 0;
+    check(
+        mp_to_radix(
+            n,
+            buf_ptr as *mut _,
+            size as usize,
+            &mut written,
+            radix as i32,
+        ),
+        "bigint_to_text",
+    );
+
+    // `written` includes the NUL
+    let len = written - 1;
+    let str = core::slice::from_raw_parts_mut(buf_ptr, len);
+    str.make_ascii_lowercase();
+
+    text_of_ptr_size(mem, buf_ptr, Bytes(len as u32))
+}
+
+/// State of `bigint_of_text`. Digits are collected in a chunk that fits into a `mp_digit`, and
+/// added to the result when the chunk is full.
+struct TextParser {
+    radix: u32,
+    result: mp_int,
+    chunk: u32,
+    /// `radix ** (number of digits in chunk)`
+    chunk_scale: u32,
+    negative: bool,
+    seen_digit: bool,
+    /// Whether the last byte was a separator
+    after_separator: bool,
+    offset: u32,
+}
+
+/// Chunks need to fit into `mp_digit`, which has 28 bits in the configuration we use
+const MAX_CHUNK_SCALE: u32 = 1 << 28;
+
+impl TextParser {
+    unsafe fn trap_at(&self, msg: &str) -> ! {
+        let mut buf = [0u8; 100];
+        let mut fmt = WriteBuf::new(&mut buf);
+        let _ = write!(
+            &mut fmt,
+            "bigint_of_text: {} at offset {}",
+            msg, self.offset
+        );
+        rts_trap_with(fmt.as_str())
+    }
+
+    unsafe fn flush_chunk(&mut self) {
+        check(
+            mp_mul_d(&self.result, self.chunk_scale, &mut self.result),
+            "bigint_of_text",
+        );
+        check(
+            mp_add_d(&self.result, self.chunk, &mut self.result),
+            "bigint_of_text",
+        );
+        self.chunk = 0;
+        self.chunk_scale = 1;
+    }
+
+    unsafe fn byte(&mut self, byte: u8) {
+        if self.offset == 0 && (byte == b'+' || byte == b'-') {
+            self.negative = byte == b'-';
+        } else if byte == b'_' {
+            if !self.seen_digit || self.after_separator {
+                self.trap_at("misplaced separator");
+            }
+            self.after_separator = true;
+        } else {
+            let digit = match (byte as char).to_digit(self.radix) {
+                Some(digit) => digit,
+                None => self.trap_at("invalid digit"),
+            };
+            if u64::from(self.chunk_scale) * u64::from(self.radix) >= u64::from(MAX_CHUNK_SCALE) {
+                self.flush_chunk();
+            }
+            self.chunk = self.chunk * self.radix + digit;
+            self.chunk_scale *= self.radix;
+            self.seen_digit = true;
+            self.after_separator = false;
+        }
+        self.offset += 1;
+    }
+
+    /// Feeds the bytes of a text to the parser, following concatenations without flattening them
+    unsafe fn text(&mut self, text: SkewedPtr) {
+        if text.tag() == TAG_BLOB {
+            let blob = text.as_blob();
+            for i in 0..blob.len().0 {
+                self.byte(blob.get(i));
+            }
+        } else {
+            let concat = text.as_concat();
+            self.text(concat.text1());
+            self.text(concat.text2());
+        }
+    }
+}
+
+/// Parses a number in the given radix (2 to 36). The number can have a `+` or `-` sign, and
+/// digits can be separated with `_`. Letters for digits above 9 can be upper or lower case.
+#[no_mangle]
+pub unsafe extern "C" fn bigint_of_text(text: SkewedPtr, radix: u32) -> SkewedPtr {
+    check_radix("bigint_of_text", radix);
+
+    let mut parser = TextParser {
+        radix,
+        result: tmp_bigint(),
+        chunk: 0,
+        chunk_scale: 1,
+        negative: false,
+        seen_digit: false,
+        after_separator: false,
+        offset: 0,
+    };
+
+    parser.text(text);
+
+    if !parser.seen_digit {
+        parser.trap_at("missing digits");
+    }
+
+    if parser.after_separator {
+        parser.trap_at("misplaced separator");
+    }
+
+    parser.flush_chunk();
+
+    let mut result = parser.result;
+    if parser.negative {
+        check(mp_neg(&result, &mut result), "bigint_of_text");
+    }
+    persist_bigint(result)
+}
@@ -11,7 +11,6 @@ pub struct Buf {
 }
 
 impl Buf {
-    #[cfg(feature = "ic")]
     pub(crate) unsafe fn advance(self: *mut Self, n: u32) {
         advance(self, n)
     }
@@ -29,7 +28,6 @@ pub(crate) unsafe fn read_byte(buf: *mut Buf) -> u8 {
     byte
 }
 
-#[cfg(feature = "ic")]
 /// Read a little-endian word
 pub(crate) unsafe fn read_word(buf: *mut Buf) -> u32 {
     if (*buf).ptr.add(3) >= (*buf).end {
@@ -44,7 +42,6 @@ pub(crate) unsafe fn read_word(buf: *mut Buf) -> u32 {
     word
 }
 
-#[cfg(feature = "ic")]
 unsafe fn advance(buf: *mut Buf, n: u32) {
     if (*buf).ptr.add(n as usize) > (*buf).end {
         idl_trap_with("advance out of buffer");
@@ -54,7 +51,6 @@ unsafe fn advance(buf: *mut Buf, n: u32) {
 }
 
 /// Can also be used for sleb
-#[cfg(feature = "ic")]
 #[no_mangle]
 pub(crate) unsafe extern "C" fn skip_leb128(buf: *mut Buf) {
     loop {
@@ -1,3 +1,119 @@
+//! Character properties
+
+use motoko_rts_macros::ic_mem_fn;
+
+mod tables;
+
+use tables::*;
+
+// Values of the property queries below
+pub use tables::{
+    GCB_CONTROL, GCB_CR, GCB_EXTEND, GCB_L, GCB_LF, GCB_LV, GCB_LVT, GCB_OTHER, GCB_PREPEND,
+    GCB_REGIONAL_INDICATOR, GCB_SPACINGMARK, GCB_T, GCB_V, GCB_ZWJ, GC_CC, GC_CF, GC_CN, GC_CO,
+    GC_CS, GC_LL, GC_LM, GC_LO, GC_LT, GC_LU, GC_MC, GC_ME, GC_MN, GC_ND, GC_NL, GC_NO, GC_PC,
+    GC_PD, GC_PE, GC_PF, GC_PI, GC_PO, GC_PS, GC_SC, GC_SK, GC_SM, GC_SO, GC_ZL, GC_ZP, GC_ZS,
+    WB_ALETTER, WB_CR, WB_DOUBLE_QUOTE, WB_EXTEND, WB_EXTENDNUMLET, WB_FORMAT, WB_HEBREW_LETTER,
+    WB_KATAKANA, WB_LF, WB_MIDLETTER, WB_MIDNUM, WB_MIDNUMLET, WB_NEWLINE, WB_NUMERIC, WB_OTHER,
+    WB_REGIONAL_INDICATOR, WB_SINGLE_QUOTE, WB_WSEGSPACE, WB_ZWJ,
+};
+
+/// Finds the value of a code point in one of the run-encoded tables in `tables.rs`
+fn run_value(table: &[u32], c: u32) -> u8 {
+    // Tables start with a run at 0, so there is always a run starting at or before `c`
+    let idx = match table.binary_search_by(|entry| (entry >> 8).cmp(&c)) {
+        Ok(idx) => idx,
+        Err(idx) => idx - 1,
+    };
+    table[idx] as u8
+}
+
+/// General category of a character, as one of the `GC_*` constants
+pub fn general_category(c: u32) -> u8 {
+    run_value(&GENERAL_CATEGORIES, c)
+}
+
+/// ISO 15924 code of the script of a character. `Zyyy` for common and `Zinh` for inherited
+/// characters, `Zzzz` for unassigned code points.
+pub fn script_code(c: u32) -> &'static [u8; 4] {
+    &SCRIPT_CODES[run_value(&SCRIPTS, c) as usize]
+}
+
+/// Grapheme_Cluster_Break property value of a character, as one of the `GCB_*` constants
+pub fn grapheme_break(c: u32) -> u8 {
+    run_value(&GRAPHEME_BREAKS, c)
+}
+
+/// Word_Break property value of a character, as one of the `WB_*` constants
+pub fn word_break(c: u32) -> u8 {
+    run_value(&WORD_BREAKS, c)
+}
+
+/// Whether a code point is in one of the `(first, last)` ranges of a table in `tables.rs`
+fn in_ranges(table: &[(u32, u32)], c: u32) -> bool {
+    table
+        .binary_search_by(|&(first, last)| {
+            if last < c {
+                core::cmp::Ordering::Less
+            } else if first > c {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Whether a character has the Extended_Pictographic property, used in grapheme cluster and word
+/// segmentation
+pub fn is_extended_pictographic(c: u32) -> bool {
+    // Fast path for ASCII, which has no pictographs
+    c >= 0xA9 && in_ranges(&EXTENDED_PICTOGRAPHIC, c)
+}
+
+/// Whether a character has the Alphabetic property
+pub fn is_alphabetic(c: u32) -> bool {
+    in_ranges(&ALPHABETIC, c)
+}
+
+/// Numeric value of a character as `(numerator, denominator)`, if it has one
+pub fn numeric_value(c: u32) -> Option<(i64, u32)> {
+    let idx = match NUMERIC_RUNS.binary_search_by(|entry| (entry >> 8).cmp(&c)) {
+        Ok(idx) => idx,
+        Err(0) => return None,
+        Err(idx) => idx - 1,
+    };
+
+    let first = NUMERIC_RUNS[idx] >> 8;
+    let offset = c - first;
+    if offset > u32::from(NUMERIC_RUN_LENGTHS[idx]) {
+        return None;
+    }
+
+    let (numerator, denominator) = NUMERIC_VALUES[(NUMERIC_RUNS[idx] & 0xFF) as usize];
+    // Runs longer than one character only have integer values
+    Some((numerator + i64::from(offset), denominator))
+}
+
+/// Whether a character is in one of the number categories (Nd, Nl, No)
+pub fn is_numeric(c: u32) -> bool {
+    (GC_ND..=GC_NO).contains(&general_category(c))
+}
+
+/// Whether a character is in one of the punctuation categories (Pc, Pd, Ps, Pe, Pi, Pf, Po)
+pub fn is_punctuation(c: u32) -> bool {
+    (GC_PC..=GC_PO).contains(&general_category(c))
+}
+
+/// Whether a character is a control character (category Cc)
+pub fn is_control(c: u32) -> bool {
+    general_category(c) == GC_CC
+}
+
+/// Whether a character is alphabetic or numeric
+pub fn is_alphanumeric(c: u32) -> bool {
+    is_alphabetic(c) || is_numeric(c)
+}
+
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn char_to_upper(c: u32) -> u32 {
@@ -41,5 +157,64 @@ unsafe extern "C" fn char_is_lowercase(c: u32) -> u32 {
 #[cfg(feature = "ic")]
 #[no_mangle]
 unsafe extern "C" fn char_is_alphabetic(c: u32) -> u32 {
-    core::char::from_u32_unchecked(c).is_alphabetic().into()
+    is_alphabetic(c).into()
+}
+
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_general_category(c: u32) -> u32 {
+    general_category(c).into()
+}
+
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_is_numeric(c: u32) -> u32 {
+    is_numeric(c).into()
+}
+
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_is_alphanumeric(c: u32) -> u32 {
+    is_alphanumeric(c).into()
+}
+
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_is_control(c: u32) -> u32 {
+    is_control(c).into()
+}
+
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_is_punctuation(c: u32) -> u32 {
+    is_punctuation(c).into()
+}
+
+/// Numeric value of a character, NaN if it does not have one
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_numeric_value(c: u32) -> f64 {
+    match numeric_value(c) {
+        Some((numerator, denominator)) => numerator as f64 / f64::from(denominator),
+        None => f64::NAN,
+    }
+}
+
+/// Returns the ISO 15924 code of the script of a character as text
+#[ic_mem_fn(ic_only)]
+unsafe fn char_script<M: crate::memory::Memory>(mem: &mut M, c: u32) -> crate::types::SkewedPtr {
+    let code = script_code(c);
+    crate::text::text_of_ptr_size(mem, code.as_ptr(), crate::types::Bytes(code.len() as u32))
+}
+
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_grapheme_break(c: u32) -> u32 {
+    grapheme_break(c).into()
+}
+
+#[cfg(feature = "ic")]
+#[no_mangle]
+unsafe extern "C" fn char_word_break(c: u32) -> u32 {
+    word_break(c).into()
 }
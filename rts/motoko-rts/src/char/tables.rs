@@ -0,0 +1,1654 @@
+// Generated by rts/gen-unicode-tables.py from the Unicode Character Database, version
+// 14.0.0. Do not edit.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+// General categories, in the order of PropertyValueAliases.txt
+pub const GC_LU: u8 = 0;
+pub const GC_LL: u8 = 1;
+pub const GC_LT: u8 = 2;
+pub const GC_LM: u8 = 3;
+pub const GC_LO: u8 = 4;
+pub const GC_MN: u8 = 5;
+pub const GC_MC: u8 = 6;
+pub const GC_ME: u8 = 7;
+pub const GC_ND: u8 = 8;
+pub const GC_NL: u8 = 9;
+pub const GC_NO: u8 = 10;
+pub const GC_PC: u8 = 11;
+pub const GC_PD: u8 = 12;
+pub const GC_PS: u8 = 13;
+pub const GC_PE: u8 = 14;
+pub const GC_PI: u8 = 15;
+pub const GC_PF: u8 = 16;
+pub const GC_PO: u8 = 17;
+pub const GC_SM: u8 = 18;
+pub const GC_SC: u8 = 19;
+pub const GC_SK: u8 = 20;
+pub const GC_SO: u8 = 21;
+pub const GC_ZS: u8 = 22;
+pub const GC_ZL: u8 = 23;
+pub const GC_ZP: u8 = 24;
+pub const GC_CC: u8 = 25;
+pub const GC_CF: u8 = 26;
+pub const GC_CS: u8 = 27;
+pub const GC_CO: u8 = 28;
+pub const GC_CN: u8 = 29;
+
+// Grapheme_Cluster_Break property values
+pub const GCB_OTHER: u8 = 0;
+pub const GCB_CR: u8 = 1;
+pub const GCB_LF: u8 = 2;
+pub const GCB_CONTROL: u8 = 3;
+pub const GCB_EXTEND: u8 = 4;
+pub const GCB_ZWJ: u8 = 5;
+pub const GCB_REGIONAL_INDICATOR: u8 = 6;
+pub const GCB_PREPEND: u8 = 7;
+pub const GCB_SPACINGMARK: u8 = 8;
+pub const GCB_L: u8 = 9;
+pub const GCB_V: u8 = 10;
+pub const GCB_T: u8 = 11;
+pub const GCB_LV: u8 = 12;
+pub const GCB_LVT: u8 = 13;
+
+// Word_Break property values
+pub const WB_OTHER: u8 = 0;
+pub const WB_CR: u8 = 1;
+pub const WB_LF: u8 = 2;
+pub const WB_NEWLINE: u8 = 3;
+pub const WB_EXTEND: u8 = 4;
+pub const WB_ZWJ: u8 = 5;
+pub const WB_REGIONAL_INDICATOR: u8 = 6;
+pub const WB_FORMAT: u8 = 7;
+pub const WB_KATAKANA: u8 = 8;
+pub const WB_HEBREW_LETTER: u8 = 9;
+pub const WB_ALETTER: u8 = 10;
+pub const WB_SINGLE_QUOTE: u8 = 11;
+pub const WB_DOUBLE_QUOTE: u8 = 12;
+pub const WB_MIDNUMLET: u8 = 13;
+pub const WB_MIDLETTER: u8 = 14;
+pub const WB_MIDNUM: u8 = 15;
+pub const WB_NUMERIC: u8 = 16;
+pub const WB_EXTENDNUMLET: u8 = 17;
+pub const WB_WSEGSPACE: u8 = 18;
+
+/// General categories (`GC_*`), as `first << 8 | value` for runs of code points with the same
+/// value starting at `first`. The first run starts at 0, a run lasts until the start of the next one.
+pub(super) static GENERAL_CATEGORIES: [u32; 3968] = [
+    0x19, 0x2016, 0x2111, 0x2413, 0x2511, 0x280D, 0x290E, 0x2A11,
+    0x2B12, 0x2C11, 0x2D0C, 0x2E11, 0x3008, 0x3A11, 0x3C12, 0x3F11,
+    0x4100, 0x5B0D, 0x5C11, 0x5D0E, 0x5E14, 0x5F0B, 0x6014, 0x6101,
+    0x7B0D, 0x7C12, 0x7D0E, 0x7E12, 0x7F19, 0xA016, 0xA111, 0xA213,
+    0xA615, 0xA711, 0xA814, 0xA915, 0xAA04, 0xAB0F, 0xAC12, 0xAD1A,
+    0xAE15, 0xAF14, 0xB015, 0xB112, 0xB20A, 0xB414, 0xB501, 0xB611,
+    0xB814, 0xB90A, 0xBA04, 0xBB10, 0xBC0A, 0xBF11, 0xC000, 0xD712,
+    0xD800, 0xDF01, 0xF712, 0xF801, 0x10000, 0x10101, 0x10200, 0x10301,
+    0x10400, 0x10501, 0x10600, 0x10701, 0x10800, 0x10901, 0x10A00, 0x10B01,
+    0x10C00, 0x10D01, 0x10E00, 0x10F01, 0x11000, 0x11101, 0x11200, 0x11301,
+    0x11400, 0x11501, 0x11600, 0x11701, 0x11800, 0x11901, 0x11A00, 0x11B01,
+    0x11C00, 0x11D01, 0x11E00, 0x11F01, 0x12000, 0x12101, 0x12200, 0x12301,
+    0x12400, 0x12501, 0x12600, 0x12701, 0x12800, 0x12901, 0x12A00, 0x12B01,
+    0x12C00, 0x12D01, 0x12E00, 0x12F01, 0x13000, 0x13101, 0x13200, 0x13301,
+    0x13400, 0x13501, 0x13600, 0x13701, 0x13900, 0x13A01, 0x13B00, 0x13C01,
+    0x13D00, 0x13E01, 0x13F00, 0x14001, 0x14100, 0x14201, 0x14300, 0x14401,
+    0x14500, 0x14601, 0x14700, 0x14801, 0x14A00, 0x14B01, 0x14C00, 0x14D01,
+    0x14E00, 0x14F01, 0x15000, 0x15101, 0x15200, 0x15301, 0x15400, 0x15501,
+    0x15600, 0x15701, 0x15800, 0x15901, 0x15A00, 0x15B01, 0x15C00, 0x15D01,
+    0x15E00, 0x15F01, 0x16000, 0x16101, 0x16200, 0x16301, 0x16400, 0x16501,
+    0x16600, 0x16701, 0x16800, 0x16901, 0x16A00, 0x16B01, 0x16C00, 0x16D01,
+    0x16E00, 0x16F01, 0x17000, 0x17101, 0x17200, 0x17301, 0x17400, 0x17501,
+    0x17600, 0x17701, 0x17800, 0x17A01, 0x17B00, 0x17C01, 0x17D00, 0x17E01,
+    0x18100, 0x18301, 0x18400, 0x18501, 0x18600, 0x18801, 0x18900, 0x18C01,
+    0x18E00, 0x19201, 0x19300, 0x19501, 0x19600, 0x19901, 0x19C00, 0x19E01,
+    0x19F00, 0x1A101, 0x1A200, 0x1A301, 0x1A400, 0x1A501, 0x1A600, 0x1A801,
+    0x1A900, 0x1AA01, 0x1AC00, 0x1AD01, 0x1AE00, 0x1B001, 0x1B100, 0x1B401,
+    0x1B500, 0x1B601, 0x1B700, 0x1B901, 0x1BB04, 0x1BC00, 0x1BD01, 0x1C004,
+    0x1C400, 0x1C502, 0x1C601, 0x1C700, 0x1C802, 0x1C901, 0x1CA00, 0x1CB02,
+    0x1CC01, 0x1CD00, 0x1CE01, 0x1CF00, 0x1D001, 0x1D100, 0x1D201, 0x1D300,
+    0x1D401, 0x1D500, 0x1D601, 0x1D700, 0x1D801, 0x1D900, 0x1DA01, 0x1DB00,
+    0x1DC01, 0x1DE00, 0x1DF01, 0x1E000, 0x1E101, 0x1E200, 0x1E301, 0x1E400,
+    0x1E501, 0x1E600, 0x1E701, 0x1E800, 0x1E901, 0x1EA00, 0x1EB01, 0x1EC00,
+    0x1ED01, 0x1EE00, 0x1EF01, 0x1F100, 0x1F202, 0x1F301, 0x1F400, 0x1F501,
+    0x1F600, 0x1F901, 0x1FA00, 0x1FB01, 0x1FC00, 0x1FD01, 0x1FE00, 0x1FF01,
+    0x20000, 0x20101, 0x20200, 0x20301, 0x20400, 0x20501, 0x20600, 0x20701,
+    0x20800, 0x20901, 0x20A00, 0x20B01, 0x20C00, 0x20D01, 0x20E00, 0x20F01,
+    0x21000, 0x21101, 0x21200, 0x21301, 0x21400, 0x21501, 0x21600, 0x21701,
+    0x21800, 0x21901, 0x21A00, 0x21B01, 0x21C00, 0x21D01, 0x21E00, 0x21F01,
+    0x22000, 0x22101, 0x22200, 0x22301, 0x22400, 0x22501, 0x22600, 0x22701,
+    0x22800, 0x22901, 0x22A00, 0x22B01, 0x22C00, 0x22D01, 0x22E00, 0x22F01,
+    0x23000, 0x23101, 0x23200, 0x23301, 0x23A00, 0x23C01, 0x23D00, 0x23F01,
+    0x24100, 0x24201, 0x24300, 0x24701, 0x24800, 0x24901, 0x24A00, 0x24B01,
+    0x24C00, 0x24D01, 0x24E00, 0x24F01, 0x29404, 0x29501, 0x2B003, 0x2C214,
+    0x2C603, 0x2D214, 0x2E003, 0x2E514, 0x2EC03, 0x2ED14, 0x2EE03, 0x2EF14,
+    0x30005, 0x37000, 0x37101, 0x37200, 0x37301, 0x37403, 0x37514, 0x37600,
+    0x37701, 0x3781D, 0x37A03, 0x37B01, 0x37E11, 0x37F00, 0x3801D, 0x38414,
+    0x38600, 0x38711, 0x38800, 0x38B1D, 0x38C00, 0x38D1D, 0x38E00, 0x39001,
+    0x39100, 0x3A21D, 0x3A300, 0x3AC01, 0x3CF00, 0x3D001, 0x3D200, 0x3D501,
+    0x3D800, 0x3D901, 0x3DA00, 0x3DB01, 0x3DC00, 0x3DD01, 0x3DE00, 0x3DF01,
+    0x3E000, 0x3E101, 0x3E200, 0x3E301, 0x3E400, 0x3E501, 0x3E600, 0x3E701,
+    0x3E800, 0x3E901, 0x3EA00, 0x3EB01, 0x3EC00, 0x3ED01, 0x3EE00, 0x3EF01,
+    0x3F400, 0x3F501, 0x3F612, 0x3F700, 0x3F801, 0x3F900, 0x3FB01, 0x3FD00,
+    0x43001, 0x46000, 0x46101, 0x46200, 0x46301, 0x46400, 0x46501, 0x46600,
+    0x46701, 0x46800, 0x46901, 0x46A00, 0x46B01, 0x46C00, 0x46D01, 0x46E00,
+    0x46F01, 0x47000, 0x47101, 0x47200, 0x47301, 0x47400, 0x47501, 0x47600,
+    0x47701, 0x47800, 0x47901, 0x47A00, 0x47B01, 0x47C00, 0x47D01, 0x47E00,
+    0x47F01, 0x48000, 0x48101, 0x48215, 0x48305, 0x48807, 0x48A00, 0x48B01,
+    0x48C00, 0x48D01, 0x48E00, 0x48F01, 0x49000, 0x49101, 0x49200, 0x49301,
+    0x49400, 0x49501, 0x49600, 0x49701, 0x49800, 0x49901, 0x49A00, 0x49B01,
+    0x49C00, 0x49D01, 0x49E00, 0x49F01, 0x4A000, 0x4A101, 0x4A200, 0x4A301,
+    0x4A400, 0x4A501, 0x4A600, 0x4A701, 0x4A800, 0x4A901, 0x4AA00, 0x4AB01,
+    0x4AC00, 0x4AD01, 0x4AE00, 0x4AF01, 0x4B000, 0x4B101, 0x4B200, 0x4B301,
+    0x4B400, 0x4B501, 0x4B600, 0x4B701, 0x4B800, 0x4B901, 0x4BA00, 0x4BB01,
+    0x4BC00, 0x4BD01, 0x4BE00, 0x4BF01, 0x4C000, 0x4C201, 0x4C300, 0x4C401,
+    0x4C500, 0x4C601, 0x4C700, 0x4C801, 0x4C900, 0x4CA01, 0x4CB00, 0x4CC01,
+    0x4CD00, 0x4CE01, 0x4D000, 0x4D101, 0x4D200, 0x4D301, 0x4D400, 0x4D501,
+    0x4D600, 0x4D701, 0x4D800, 0x4D901, 0x4DA00, 0x4DB01, 0x4DC00, 0x4DD01,
+    0x4DE00, 0x4DF01, 0x4E000, 0x4E101, 0x4E200, 0x4E301, 0x4E400, 0x4E501,
+    0x4E600, 0x4E701, 0x4E800, 0x4E901, 0x4EA00, 0x4EB01, 0x4EC00, 0x4ED01,
+    0x4EE00, 0x4EF01, 0x4F000, 0x4F101, 0x4F200, 0x4F301, 0x4F400, 0x4F501,
+    0x4F600, 0x4F701, 0x4F800, 0x4F901, 0x4FA00, 0x4FB01, 0x4FC00, 0x4FD01,
+    0x4FE00, 0x4FF01, 0x50000, 0x50101, 0x50200, 0x50301, 0x50400, 0x50501,
+    0x50600, 0x50701, 0x50800, 0x50901, 0x50A00, 0x50B01, 0x50C00, 0x50D01,
+    0x50E00, 0x50F01, 0x51000, 0x51101, 0x51200, 0x51301, 0x51400, 0x51501,
+    0x51600, 0x51701, 0x51800, 0x51901, 0x51A00, 0x51B01, 0x51C00, 0x51D01,
+    0x51E00, 0x51F01, 0x52000, 0x52101, 0x52200, 0x52301, 0x52400, 0x52501,
+    0x52600, 0x52701, 0x52800, 0x52901, 0x52A00, 0x52B01, 0x52C00, 0x52D01,
+    0x52E00, 0x52F01, 0x5301D, 0x53100, 0x5571D, 0x55903, 0x55A11, 0x56001,
+    0x58911, 0x58A0C, 0x58B1D, 0x58D15, 0x58F13, 0x5901D, 0x59105, 0x5BE0C,
+    0x5BF05, 0x5C011, 0x5C105, 0x5C311, 0x5C405, 0x5C611, 0x5C705, 0x5C81D,
+    0x5D004, 0x5EB1D, 0x5EF04, 0x5F311, 0x5F51D, 0x6001A, 0x60612, 0x60911,
+    0x60B13, 0x60C11, 0x60E15, 0x61005, 0x61B11, 0x61C1A, 0x61D11, 0x62004,
+    0x64003, 0x64104, 0x64B05, 0x66008, 0x66A11, 0x66E04, 0x67005, 0x67104,
+    0x6D411, 0x6D504, 0x6D605, 0x6DD1A, 0x6DE15, 0x6DF05, 0x6E503, 0x6E705,
+    0x6E915, 0x6EA05, 0x6EE04, 0x6F008, 0x6FA04, 0x6FD15, 0x6FF04, 0x70011,
+    0x70E1D, 0x70F1A, 0x71004, 0x71105, 0x71204, 0x73005, 0x74B1D, 0x74D04,
+    0x7A605, 0x7B104, 0x7B21D, 0x7C008, 0x7CA04, 0x7EB05, 0x7F403, 0x7F615,
+    0x7F711, 0x7FA03, 0x7FB1D, 0x7FD05, 0x7FE13, 0x80004, 0x81605, 0x81A03,
+    0x81B05, 0x82403, 0x82505, 0x82803, 0x82905, 0x82E1D, 0x83011, 0x83F1D,
+    0x84004, 0x85905, 0x85C1D, 0x85E11, 0x85F1D, 0x86004, 0x86B1D, 0x87004,
+    0x88814, 0x88904, 0x88F1D, 0x8901A, 0x8921D, 0x89805, 0x8A004, 0x8C903,
+    0x8CA05, 0x8E21A, 0x8E305, 0x90306, 0x90404, 0x93A05, 0x93B06, 0x93C05,
+    0x93D04, 0x93E06, 0x94105, 0x94906, 0x94D05, 0x94E06, 0x95004, 0x95105,
+    0x95804, 0x96205, 0x96411, 0x96608, 0x97011, 0x97103, 0x97204, 0x98105,
+    0x98206, 0x9841D, 0x98504, 0x98D1D, 0x98F04, 0x9911D, 0x99304, 0x9A91D,
+    0x9AA04, 0x9B11D, 0x9B204, 0x9B31D, 0x9B604, 0x9BA1D, 0x9BC05, 0x9BD04,
+    0x9BE06, 0x9C105, 0x9C51D, 0x9C706, 0x9C91D, 0x9CB06, 0x9CD05, 0x9CE04,
+    0x9CF1D, 0x9D706, 0x9D81D, 0x9DC04, 0x9DE1D, 0x9DF04, 0x9E205, 0x9E41D,
+    0x9E608, 0x9F004, 0x9F213, 0x9F40A, 0x9FA15, 0x9FB13, 0x9FC04, 0x9FD11,
+    0x9FE05, 0x9FF1D, 0xA0105, 0xA0306, 0xA041D, 0xA0504, 0xA0B1D, 0xA0F04,
+    0xA111D, 0xA1304, 0xA291D, 0xA2A04, 0xA311D, 0xA3204, 0xA341D, 0xA3504,
+    0xA371D, 0xA3804, 0xA3A1D, 0xA3C05, 0xA3D1D, 0xA3E06, 0xA4105, 0xA431D,
+    0xA4705, 0xA491D, 0xA4B05, 0xA4E1D, 0xA5105, 0xA521D, 0xA5904, 0xA5D1D,
+    0xA5E04, 0xA5F1D, 0xA6608, 0xA7005, 0xA7204, 0xA7505, 0xA7611, 0xA771D,
+    0xA8105, 0xA8306, 0xA841D, 0xA8504, 0xA8E1D, 0xA8F04, 0xA921D, 0xA9304,
+    0xAA91D, 0xAAA04, 0xAB11D, 0xAB204, 0xAB41D, 0xAB504, 0xABA1D, 0xABC05,
+    0xABD04, 0xABE06, 0xAC105, 0xAC61D, 0xAC705, 0xAC906, 0xACA1D, 0xACB06,
+    0xACD05, 0xACE1D, 0xAD004, 0xAD11D, 0xAE004, 0xAE205, 0xAE41D, 0xAE608,
+    0xAF011, 0xAF113, 0xAF21D, 0xAF904, 0xAFA05, 0xB001D, 0xB0105, 0xB0206,
+    0xB041D, 0xB0504, 0xB0D1D, 0xB0F04, 0xB111D, 0xB1304, 0xB291D, 0xB2A04,
+    0xB311D, 0xB3204, 0xB341D, 0xB3504, 0xB3A1D, 0xB3C05, 0xB3D04, 0xB3E06,
+    0xB3F05, 0xB4006, 0xB4105, 0xB451D, 0xB4706, 0xB491D, 0xB4B06, 0xB4D05,
+    0xB4E1D, 0xB5505, 0xB5706, 0xB581D, 0xB5C04, 0xB5E1D, 0xB5F04, 0xB6205,
+    0xB641D, 0xB6608, 0xB7015, 0xB7104, 0xB720A, 0xB781D, 0xB8205, 0xB8304,
+    0xB841D, 0xB8504, 0xB8B1D, 0xB8E04, 0xB911D, 0xB9204, 0xB961D, 0xB9904,
+    0xB9B1D, 0xB9C04, 0xB9D1D, 0xB9E04, 0xBA01D, 0xBA304, 0xBA51D, 0xBA804,
+    0xBAB1D, 0xBAE04, 0xBBA1D, 0xBBE06, 0xBC005, 0xBC106, 0xBC31D, 0xBC606,
+    0xBC91D, 0xBCA06, 0xBCD05, 0xBCE1D, 0xBD004, 0xBD11D, 0xBD706, 0xBD81D,
+    0xBE608, 0xBF00A, 0xBF315, 0xBF913, 0xBFA15, 0xBFB1D, 0xC0005, 0xC0106,
+    0xC0405, 0xC0504, 0xC0D1D, 0xC0E04, 0xC111D, 0xC1204, 0xC291D, 0xC2A04,
+    0xC3A1D, 0xC3C05, 0xC3D04, 0xC3E05, 0xC4106, 0xC451D, 0xC4605, 0xC491D,
+    0xC4A05, 0xC4E1D, 0xC5505, 0xC571D, 0xC5804, 0xC5B1D, 0xC5D04, 0xC5E1D,
+    0xC6004, 0xC6205, 0xC641D, 0xC6608, 0xC701D, 0xC7711, 0xC780A, 0xC7F15,
+    0xC8004, 0xC8105, 0xC8206, 0xC8411, 0xC8504, 0xC8D1D, 0xC8E04, 0xC911D,
+    0xC9204, 0xCA91D, 0xCAA04, 0xCB41D, 0xCB504, 0xCBA1D, 0xCBC05, 0xCBD04,
+    0xCBE06, 0xCBF05, 0xCC006, 0xCC51D, 0xCC605, 0xCC706, 0xCC91D, 0xCCA06,
+    0xCCC05, 0xCCE1D, 0xCD506, 0xCD71D, 0xCDD04, 0xCDF1D, 0xCE004, 0xCE205,
+    0xCE41D, 0xCE608, 0xCF01D, 0xCF104, 0xCF31D, 0xD0005, 0xD0206, 0xD0404,
+    0xD0D1D, 0xD0E04, 0xD111D, 0xD1204, 0xD3B05, 0xD3D04, 0xD3E06, 0xD4105,
+    0xD451D, 0xD4606, 0xD491D, 0xD4A06, 0xD4D05, 0xD4E04, 0xD4F15, 0xD501D,
+    0xD5404, 0xD5706, 0xD580A, 0xD5F04, 0xD6205, 0xD641D, 0xD6608, 0xD700A,
+    0xD7915, 0xD7A04, 0xD801D, 0xD8105, 0xD8206, 0xD841D, 0xD8504, 0xD971D,
+    0xD9A04, 0xDB21D, 0xDB304, 0xDBC1D, 0xDBD04, 0xDBE1D, 0xDC004, 0xDC71D,
+    0xDCA05, 0xDCB1D, 0xDCF06, 0xDD205, 0xDD51D, 0xDD605, 0xDD71D, 0xDD806,
+    0xDE01D, 0xDE608, 0xDF01D, 0xDF206, 0xDF411, 0xDF51D, 0xE0104, 0xE3105,
+    0xE3204, 0xE3405, 0xE3B1D, 0xE3F13, 0xE4004, 0xE4603, 0xE4705, 0xE4F11,
+    0xE5008, 0xE5A11, 0xE5C1D, 0xE8104, 0xE831D, 0xE8404, 0xE851D, 0xE8604,
+    0xE8B1D, 0xE8C04, 0xEA41D, 0xEA504, 0xEA61D, 0xEA704, 0xEB105, 0xEB204,
+    0xEB405, 0xEBD04, 0xEBE1D, 0xEC004, 0xEC51D, 0xEC603, 0xEC71D, 0xEC805,
+    0xECE1D, 0xED008, 0xEDA1D, 0xEDC04, 0xEE01D, 0xF0004, 0xF0115, 0xF0411,
+    0xF1315, 0xF1411, 0xF1515, 0xF1805, 0xF1A15, 0xF2008, 0xF2A0A, 0xF3415,
+    0xF3505, 0xF3615, 0xF3705, 0xF3815, 0xF3905, 0xF3A0D, 0xF3B0E, 0xF3C0D,
+    0xF3D0E, 0xF3E06, 0xF4004, 0xF481D, 0xF4904, 0xF6D1D, 0xF7105, 0xF7F06,
+    0xF8005, 0xF8511, 0xF8605, 0xF8804, 0xF8D05, 0xF981D, 0xF9905, 0xFBD1D,
+    0xFBE15, 0xFC605, 0xFC715, 0xFCD1D, 0xFCE15, 0xFD011, 0xFD515, 0xFD911,
+    0xFDB1D, 0x100004, 0x102B06, 0x102D05, 0x103106, 0x103205, 0x103806, 0x103905,
+    0x103B06, 0x103D05, 0x103F04, 0x104008, 0x104A11, 0x105004, 0x105606, 0x105805,
+    0x105A04, 0x105E05, 0x106104, 0x106206, 0x106504, 0x106706, 0x106E04, 0x107105,
+    0x107504, 0x108205, 0x108306, 0x108505, 0x108706, 0x108D05, 0x108E04, 0x108F06,
+    0x109008, 0x109A06, 0x109D05, 0x109E15, 0x10A000, 0x10C61D, 0x10C700, 0x10C81D,
+    0x10CD00, 0x10CE1D, 0x10D001, 0x10FB11, 0x10FC03, 0x10FD01, 0x110004, 0x12491D,
+    0x124A04, 0x124E1D, 0x125004, 0x12571D, 0x125804, 0x12591D, 0x125A04, 0x125E1D,
+    0x126004, 0x12891D, 0x128A04, 0x128E1D, 0x129004, 0x12B11D, 0x12B204, 0x12B61D,
+    0x12B804, 0x12BF1D, 0x12C004, 0x12C11D, 0x12C204, 0x12C61D, 0x12C804, 0x12D71D,
+    0x12D804, 0x13111D, 0x131204, 0x13161D, 0x131804, 0x135B1D, 0x135D05, 0x136011,
+    0x13690A, 0x137D1D, 0x138004, 0x139015, 0x139A1D, 0x13A000, 0x13F61D, 0x13F801,
+    0x13FE1D, 0x14000C, 0x140104, 0x166D15, 0x166E11, 0x166F04, 0x168016, 0x168104,
+    0x169B0D, 0x169C0E, 0x169D1D, 0x16A004, 0x16EB11, 0x16EE09, 0x16F104, 0x16F91D,
+    0x170004, 0x171205, 0x171506, 0x17161D, 0x171F04, 0x173205, 0x173406, 0x173511,
+    0x17371D, 0x174004, 0x175205, 0x17541D, 0x176004, 0x176D1D, 0x176E04, 0x17711D,
+    0x177205, 0x17741D, 0x178004, 0x17B405, 0x17B606, 0x17B705, 0x17BE06, 0x17C605,
+    0x17C706, 0x17C905, 0x17D411, 0x17D703, 0x17D811, 0x17DB13, 0x17DC04, 0x17DD05,
+    0x17DE1D, 0x17E008, 0x17EA1D, 0x17F00A, 0x17FA1D, 0x180011, 0x18060C, 0x180711,
+    0x180B05, 0x180E1A, 0x180F05, 0x181008, 0x181A1D, 0x182004, 0x184303, 0x184404,
+    0x18791D, 0x188004, 0x188505, 0x188704, 0x18A905, 0x18AA04, 0x18AB1D, 0x18B004,
+    0x18F61D, 0x190004, 0x191F1D, 0x192005, 0x192306, 0x192705, 0x192906, 0x192C1D,
+    0x193006, 0x193205, 0x193306, 0x193905, 0x193C1D, 0x194015, 0x19411D, 0x194411,
+    0x194608, 0x195004, 0x196E1D, 0x197004, 0x19751D, 0x198004, 0x19AC1D, 0x19B004,
+    0x19CA1D, 0x19D008, 0x19DA0A, 0x19DB1D, 0x19DE15, 0x1A0004, 0x1A1705, 0x1A1906,
+    0x1A1B05, 0x1A1C1D, 0x1A1E11, 0x1A2004, 0x1A5506, 0x1A5605, 0x1A5706, 0x1A5805,
+    0x1A5F1D, 0x1A6005, 0x1A6106, 0x1A6205, 0x1A6306, 0x1A6505, 0x1A6D06, 0x1A7305,
+    0x1A7D1D, 0x1A7F05, 0x1A8008, 0x1A8A1D, 0x1A9008, 0x1A9A1D, 0x1AA011, 0x1AA703,
+    0x1AA811, 0x1AAE1D, 0x1AB005, 0x1ABE07, 0x1ABF05, 0x1ACF1D, 0x1B0005, 0x1B0406,
+    0x1B0504, 0x1B3405, 0x1B3506, 0x1B3605, 0x1B3B06, 0x1B3C05, 0x1B3D06, 0x1B4205,
+    0x1B4306, 0x1B4504, 0x1B4D1D, 0x1B5008, 0x1B5A11, 0x1B6115, 0x1B6B05, 0x1B7415,
+    0x1B7D11, 0x1B7F1D, 0x1B8005, 0x1B8206, 0x1B8304, 0x1BA106, 0x1BA205, 0x1BA606,
+    0x1BA805, 0x1BAA06, 0x1BAB05, 0x1BAE04, 0x1BB008, 0x1BBA04, 0x1BE605, 0x1BE706,
+    0x1BE805, 0x1BEA06, 0x1BED05, 0x1BEE06, 0x1BEF05, 0x1BF206, 0x1BF41D, 0x1BFC11,
+    0x1C0004, 0x1C2406, 0x1C2C05, 0x1C3406, 0x1C3605, 0x1C381D, 0x1C3B11, 0x1C4008,
+    0x1C4A1D, 0x1C4D04, 0x1C5008, 0x1C5A04, 0x1C7803, 0x1C7E11, 0x1C8001, 0x1C891D,
+    0x1C9000, 0x1CBB1D, 0x1CBD00, 0x1CC011, 0x1CC81D, 0x1CD005, 0x1CD311, 0x1CD405,
+    0x1CE106, 0x1CE205, 0x1CE904, 0x1CED05, 0x1CEE04, 0x1CF405, 0x1CF504, 0x1CF706,
+    0x1CF805, 0x1CFA04, 0x1CFB1D, 0x1D0001, 0x1D2C03, 0x1D6B01, 0x1D7803, 0x1D7901,
+    0x1D9B03, 0x1DC005, 0x1E0000, 0x1E0101, 0x1E0200, 0x1E0301, 0x1E0400, 0x1E0501,
+    0x1E0600, 0x1E0701, 0x1E0800, 0x1E0901, 0x1E0A00, 0x1E0B01, 0x1E0C00, 0x1E0D01,
+    0x1E0E00, 0x1E0F01, 0x1E1000, 0x1E1101, 0x1E1200, 0x1E1301, 0x1E1400, 0x1E1501,
+    0x1E1600, 0x1E1701, 0x1E1800, 0x1E1901, 0x1E1A00, 0x1E1B01, 0x1E1C00, 0x1E1D01,
+    0x1E1E00, 0x1E1F01, 0x1E2000, 0x1E2101, 0x1E2200, 0x1E2301, 0x1E2400, 0x1E2501,
+    0x1E2600, 0x1E2701, 0x1E2800, 0x1E2901, 0x1E2A00, 0x1E2B01, 0x1E2C00, 0x1E2D01,
+    0x1E2E00, 0x1E2F01, 0x1E3000, 0x1E3101, 0x1E3200, 0x1E3301, 0x1E3400, 0x1E3501,
+    0x1E3600, 0x1E3701, 0x1E3800, 0x1E3901, 0x1E3A00, 0x1E3B01, 0x1E3C00, 0x1E3D01,
+    0x1E3E00, 0x1E3F01, 0x1E4000, 0x1E4101, 0x1E4200, 0x1E4301, 0x1E4400, 0x1E4501,
+    0x1E4600, 0x1E4701, 0x1E4800, 0x1E4901, 0x1E4A00, 0x1E4B01, 0x1E4C00, 0x1E4D01,
+    0x1E4E00, 0x1E4F01, 0x1E5000, 0x1E5101, 0x1E5200, 0x1E5301, 0x1E5400, 0x1E5501,
+    0x1E5600, 0x1E5701, 0x1E5800, 0x1E5901, 0x1E5A00, 0x1E5B01, 0x1E5C00, 0x1E5D01,
+    0x1E5E00, 0x1E5F01, 0x1E6000, 0x1E6101, 0x1E6200, 0x1E6301, 0x1E6400, 0x1E6501,
+    0x1E6600, 0x1E6701, 0x1E6800, 0x1E6901, 0x1E6A00, 0x1E6B01, 0x1E6C00, 0x1E6D01,
+    0x1E6E00, 0x1E6F01, 0x1E7000, 0x1E7101, 0x1E7200, 0x1E7301, 0x1E7400, 0x1E7501,
+    0x1E7600, 0x1E7701, 0x1E7800, 0x1E7901, 0x1E7A00, 0x1E7B01, 0x1E7C00, 0x1E7D01,
+    0x1E7E00, 0x1E7F01, 0x1E8000, 0x1E8101, 0x1E8200, 0x1E8301, 0x1E8400, 0x1E8501,
+    0x1E8600, 0x1E8701, 0x1E8800, 0x1E8901, 0x1E8A00, 0x1E8B01, 0x1E8C00, 0x1E8D01,
+    0x1E8E00, 0x1E8F01, 0x1E9000, 0x1E9101, 0x1E9200, 0x1E9301, 0x1E9400, 0x1E9501,
+    0x1E9E00, 0x1E9F01, 0x1EA000, 0x1EA101, 0x1EA200, 0x1EA301, 0x1EA400, 0x1EA501,
+    0x1EA600, 0x1EA701, 0x1EA800, 0x1EA901, 0x1EAA00, 0x1EAB01, 0x1EAC00, 0x1EAD01,
+    0x1EAE00, 0x1EAF01, 0x1EB000, 0x1EB101, 0x1EB200, 0x1EB301, 0x1EB400, 0x1EB501,
+    0x1EB600, 0x1EB701, 0x1EB800, 0x1EB901, 0x1EBA00, 0x1EBB01, 0x1EBC00, 0x1EBD01,
+    0x1EBE00, 0x1EBF01, 0x1EC000, 0x1EC101, 0x1EC200, 0x1EC301, 0x1EC400, 0x1EC501,
+    0x1EC600, 0x1EC701, 0x1EC800, 0x1EC901, 0x1ECA00, 0x1ECB01, 0x1ECC00, 0x1ECD01,
+    0x1ECE00, 0x1ECF01, 0x1ED000, 0x1ED101, 0x1ED200, 0x1ED301, 0x1ED400, 0x1ED501,
+    0x1ED600, 0x1ED701, 0x1ED800, 0x1ED901, 0x1EDA00, 0x1EDB01, 0x1EDC00, 0x1EDD01,
+    0x1EDE00, 0x1EDF01, 0x1EE000, 0x1EE101, 0x1EE200, 0x1EE301, 0x1EE400, 0x1EE501,
+    0x1EE600, 0x1EE701, 0x1EE800, 0x1EE901, 0x1EEA00, 0x1EEB01, 0x1EEC00, 0x1EED01,
+    0x1EEE00, 0x1EEF01, 0x1EF000, 0x1EF101, 0x1EF200, 0x1EF301, 0x1EF400, 0x1EF501,
+    0x1EF600, 0x1EF701, 0x1EF800, 0x1EF901, 0x1EFA00, 0x1EFB01, 0x1EFC00, 0x1EFD01,
+    0x1EFE00, 0x1EFF01, 0x1F0800, 0x1F1001, 0x1F161D, 0x1F1800, 0x1F1E1D, 0x1F2001,
+    0x1F2800, 0x1F3001, 0x1F3800, 0x1F4001, 0x1F461D, 0x1F4800, 0x1F4E1D, 0x1F5001,
+    0x1F581D, 0x1F5900, 0x1F5A1D, 0x1F5B00, 0x1F5C1D, 0x1F5D00, 0x1F5E1D, 0x1F5F00,
+    0x1F6001, 0x1F6800, 0x1F7001, 0x1F7E1D, 0x1F8001, 0x1F8802, 0x1F9001, 0x1F9802,
+    0x1FA001, 0x1FA802, 0x1FB001, 0x1FB51D, 0x1FB601, 0x1FB800, 0x1FBC02, 0x1FBD14,
+    0x1FBE01, 0x1FBF14, 0x1FC201, 0x1FC51D, 0x1FC601, 0x1FC800, 0x1FCC02, 0x1FCD14,
+    0x1FD001, 0x1FD41D, 0x1FD601, 0x1FD800, 0x1FDC1D, 0x1FDD14, 0x1FE001, 0x1FE800,
+    0x1FED14, 0x1FF01D, 0x1FF201, 0x1FF51D, 0x1FF601, 0x1FF800, 0x1FFC02, 0x1FFD14,
+    0x1FFF1D, 0x200016, 0x200B1A, 0x20100C, 0x201611, 0x20180F, 0x201910, 0x201A0D,
+    0x201B0F, 0x201D10, 0x201E0D, 0x201F0F, 0x202011, 0x202817, 0x202918, 0x202A1A,
+    0x202F16, 0x203011, 0x20390F, 0x203A10, 0x203B11, 0x203F0B, 0x204111, 0x204412,
+    0x20450D, 0x20460E, 0x204711, 0x205212, 0x205311, 0x20540B, 0x205511, 0x205F16,
+    0x20601A, 0x20651D, 0x20661A, 0x20700A, 0x207103, 0x20721D, 0x20740A, 0x207A12,
+    0x207D0D, 0x207E0E, 0x207F03, 0x20800A, 0x208A12, 0x208D0D, 0x208E0E, 0x208F1D,
+    0x209003, 0x209D1D, 0x20A013, 0x20C11D, 0x20D005, 0x20DD07, 0x20E105, 0x20E207,
+    0x20E505, 0x20F11D, 0x210015, 0x210200, 0x210315, 0x210700, 0x210815, 0x210A01,
+    0x210B00, 0x210E01, 0x211000, 0x211301, 0x211415, 0x211500, 0x211615, 0x211812,
+    0x211900, 0x211E15, 0x212400, 0x212515, 0x212600, 0x212715, 0x212800, 0x212915,
+    0x212A00, 0x212E15, 0x212F01, 0x213000, 0x213401, 0x213504, 0x213901, 0x213A15,
+    0x213C01, 0x213E00, 0x214012, 0x214500, 0x214601, 0x214A15, 0x214B12, 0x214C15,
+    0x214E01, 0x214F15, 0x21500A, 0x216009, 0x218300, 0x218401, 0x218509, 0x21890A,
+    0x218A15, 0x218C1D, 0x219012, 0x219515, 0x219A12, 0x219C15, 0x21A012, 0x21A115,
+    0x21A312, 0x21A415, 0x21A612, 0x21A715, 0x21AE12, 0x21AF15, 0x21CE12, 0x21D015,
+    0x21D212, 0x21D315, 0x21D412, 0x21D515, 0x21F412, 0x230015, 0x23080D, 0x23090E,
+    0x230A0D, 0x230B0E, 0x230C15, 0x232012, 0x232215, 0x23290D, 0x232A0E, 0x232B15,
+    0x237C12, 0x237D15, 0x239B12, 0x23B415, 0x23DC12, 0x23E215, 0x24271D, 0x244015,
+    0x244B1D, 0x24600A, 0x249C15, 0x24EA0A, 0x250015, 0x25B712, 0x25B815, 0x25C112,
+    0x25C215, 0x25F812, 0x260015, 0x266F12, 0x267015, 0x27680D, 0x27690E, 0x276A0D,
+    0x276B0E, 0x276C0D, 0x276D0E, 0x276E0D, 0x276F0E, 0x27700D, 0x27710E, 0x27720D,
+    0x27730E, 0x27740D, 0x27750E, 0x27760A, 0x279415, 0x27C012, 0x27C50D, 0x27C60E,
+    0x27C712, 0x27E60D, 0x27E70E, 0x27E80D, 0x27E90E, 0x27EA0D, 0x27EB0E, 0x27EC0D,
+    0x27ED0E, 0x27EE0D, 0x27EF0E, 0x27F012, 0x280015, 0x290012, 0x29830D, 0x29840E,
+    0x29850D, 0x29860E, 0x29870D, 0x29880E, 0x29890D, 0x298A0E, 0x298B0D, 0x298C0E,
+    0x298D0D, 0x298E0E, 0x298F0D, 0x29900E, 0x29910D, 0x29920E, 0x29930D, 0x29940E,
+    0x29950D, 0x29960E, 0x29970D, 0x29980E, 0x299912, 0x29D80D, 0x29D90E, 0x29DA0D,
+    0x29DB0E, 0x29DC12, 0x29FC0D, 0x29FD0E, 0x29FE12, 0x2B0015, 0x2B3012, 0x2B4515,
+    0x2B4712, 0x2B4D15, 0x2B741D, 0x2B7615, 0x2B961D, 0x2B9715, 0x2C0000, 0x2C3001,
+    0x2C6000, 0x2C6101, 0x2C6200, 0x2C6501, 0x2C6700, 0x2C6801, 0x2C6900, 0x2C6A01,
+    0x2C6B00, 0x2C6C01, 0x2C6D00, 0x2C7101, 0x2C7200, 0x2C7301, 0x2C7500, 0x2C7601,
+    0x2C7C03, 0x2C7E00, 0x2C8101, 0x2C8200, 0x2C8301, 0x2C8400, 0x2C8501, 0x2C8600,
+    0x2C8701, 0x2C8800, 0x2C8901, 0x2C8A00, 0x2C8B01, 0x2C8C00, 0x2C8D01, 0x2C8E00,
+    0x2C8F01, 0x2C9000, 0x2C9101, 0x2C9200, 0x2C9301, 0x2C9400, 0x2C9501, 0x2C9600,
+    0x2C9701, 0x2C9800, 0x2C9901, 0x2C9A00, 0x2C9B01, 0x2C9C00, 0x2C9D01, 0x2C9E00,
+    0x2C9F01, 0x2CA000, 0x2CA101, 0x2CA200, 0x2CA301, 0x2CA400, 0x2CA501, 0x2CA600,
+    0x2CA701, 0x2CA800, 0x2CA901, 0x2CAA00, 0x2CAB01, 0x2CAC00, 0x2CAD01, 0x2CAE00,
+    0x2CAF01, 0x2CB000, 0x2CB101, 0x2CB200, 0x2CB301, 0x2CB400, 0x2CB501, 0x2CB600,
+    0x2CB701, 0x2CB800, 0x2CB901, 0x2CBA00, 0x2CBB01, 0x2CBC00, 0x2CBD01, 0x2CBE00,
+    0x2CBF01, 0x2CC000, 0x2CC101, 0x2CC200, 0x2CC301, 0x2CC400, 0x2CC501, 0x2CC600,
+    0x2CC701, 0x2CC800, 0x2CC901, 0x2CCA00, 0x2CCB01, 0x2CCC00, 0x2CCD01, 0x2CCE00,
+    0x2CCF01, 0x2CD000, 0x2CD101, 0x2CD200, 0x2CD301, 0x2CD400, 0x2CD501, 0x2CD600,
+    0x2CD701, 0x2CD800, 0x2CD901, 0x2CDA00, 0x2CDB01, 0x2CDC00, 0x2CDD01, 0x2CDE00,
+    0x2CDF01, 0x2CE000, 0x2CE101, 0x2CE200, 0x2CE301, 0x2CE515, 0x2CEB00, 0x2CEC01,
+    0x2CED00, 0x2CEE01, 0x2CEF05, 0x2CF200, 0x2CF301, 0x2CF41D, 0x2CF911, 0x2CFD0A,
+    0x2CFE11, 0x2D0001, 0x2D261D, 0x2D2701, 0x2D281D, 0x2D2D01, 0x2D2E1D, 0x2D3004,
+    0x2D681D, 0x2D6F03, 0x2D7011, 0x2D711D, 0x2D7F05, 0x2D8004, 0x2D971D, 0x2DA004,
+    0x2DA71D, 0x2DA804, 0x2DAF1D, 0x2DB004, 0x2DB71D, 0x2DB804, 0x2DBF1D, 0x2DC004,
+    0x2DC71D, 0x2DC804, 0x2DCF1D, 0x2DD004, 0x2DD71D, 0x2DD804, 0x2DDF1D, 0x2DE005,
+    0x2E0011, 0x2E020F, 0x2E0310, 0x2E040F, 0x2E0510, 0x2E0611, 0x2E090F, 0x2E0A10,
+    0x2E0B11, 0x2E0C0F, 0x2E0D10, 0x2E0E11, 0x2E170C, 0x2E1811, 0x2E1A0C, 0x2E1B11,
+    0x2E1C0F, 0x2E1D10, 0x2E1E11, 0x2E200F, 0x2E2110, 0x2E220D, 0x2E230E, 0x2E240D,
+    0x2E250E, 0x2E260D, 0x2E270E, 0x2E280D, 0x2E290E, 0x2E2A11, 0x2E2F03, 0x2E3011,
+    0x2E3A0C, 0x2E3C11, 0x2E400C, 0x2E4111, 0x2E420D, 0x2E4311, 0x2E5015, 0x2E5211,
+    0x2E550D, 0x2E560E, 0x2E570D, 0x2E580E, 0x2E590D, 0x2E5A0E, 0x2E5B0D, 0x2E5C0E,
+    0x2E5D0C, 0x2E5E1D, 0x2E8015, 0x2E9A1D, 0x2E9B15, 0x2EF41D, 0x2F0015, 0x2FD61D,
+    0x2FF015, 0x2FFC1D, 0x300016, 0x300111, 0x300415, 0x300503, 0x300604, 0x300709,
+    0x30080D, 0x30090E, 0x300A0D, 0x300B0E, 0x300C0D, 0x300D0E, 0x300E0D, 0x300F0E,
+    0x30100D, 0x30110E, 0x301215, 0x30140D, 0x30150E, 0x30160D, 0x30170E, 0x30180D,
+    0x30190E, 0x301A0D, 0x301B0E, 0x301C0C, 0x301D0D, 0x301E0E, 0x302015, 0x302109,
+    0x302A05, 0x302E06, 0x30300C, 0x303103, 0x303615, 0x303809, 0x303B03, 0x303C04,
+    0x303D11, 0x303E15, 0x30401D, 0x304104, 0x30971D, 0x309905, 0x309B14, 0x309D03,
+    0x309F04, 0x30A00C, 0x30A104, 0x30FB11, 0x30FC03, 0x30FF04, 0x31001D, 0x310504,
+    0x31301D, 0x313104, 0x318F1D, 0x319015, 0x31920A, 0x319615, 0x31A004, 0x31C015,
+    0x31E41D, 0x31F004, 0x320015, 0x321F1D, 0x32200A, 0x322A15, 0x32480A, 0x325015,
+    0x32510A, 0x326015, 0x32800A, 0x328A15, 0x32B10A, 0x32C015, 0x340004, 0x4DC015,
+    0x4E0004, 0xA01503, 0xA01604, 0xA48D1D, 0xA49015, 0xA4C71D, 0xA4D004, 0xA4F803,
+    0xA4FE11, 0xA50004, 0xA60C03, 0xA60D11, 0xA61004, 0xA62008, 0xA62A04, 0xA62C1D,
+    0xA64000, 0xA64101, 0xA64200, 0xA64301, 0xA64400, 0xA64501, 0xA64600, 0xA64701,
+    0xA64800, 0xA64901, 0xA64A00, 0xA64B01, 0xA64C00, 0xA64D01, 0xA64E00, 0xA64F01,
+    0xA65000, 0xA65101, 0xA65200, 0xA65301, 0xA65400, 0xA65501, 0xA65600, 0xA65701,
+    0xA65800, 0xA65901, 0xA65A00, 0xA65B01, 0xA65C00, 0xA65D01, 0xA65E00, 0xA65F01,
+    0xA66000, 0xA66101, 0xA66200, 0xA66301, 0xA66400, 0xA66501, 0xA66600, 0xA66701,
+    0xA66800, 0xA66901, 0xA66A00, 0xA66B01, 0xA66C00, 0xA66D01, 0xA66E04, 0xA66F05,
+    0xA67007, 0xA67311, 0xA67405, 0xA67E11, 0xA67F03, 0xA68000, 0xA68101, 0xA68200,
+    0xA68301, 0xA68400, 0xA68501, 0xA68600, 0xA68701, 0xA68800, 0xA68901, 0xA68A00,
+    0xA68B01, 0xA68C00, 0xA68D01, 0xA68E00, 0xA68F01, 0xA69000, 0xA69101, 0xA69200,
+    0xA69301, 0xA69400, 0xA69501, 0xA69600, 0xA69701, 0xA69800, 0xA69901, 0xA69A00,
+    0xA69B01, 0xA69C03, 0xA69E05, 0xA6A004, 0xA6E609, 0xA6F005, 0xA6F211, 0xA6F81D,
+    0xA70014, 0xA71703, 0xA72014, 0xA72200, 0xA72301, 0xA72400, 0xA72501, 0xA72600,
+    0xA72701, 0xA72800, 0xA72901, 0xA72A00, 0xA72B01, 0xA72C00, 0xA72D01, 0xA72E00,
+    0xA72F01, 0xA73200, 0xA73301, 0xA73400, 0xA73501, 0xA73600, 0xA73701, 0xA73800,
+    0xA73901, 0xA73A00, 0xA73B01, 0xA73C00, 0xA73D01, 0xA73E00, 0xA73F01, 0xA74000,
+    0xA74101, 0xA74200, 0xA74301, 0xA74400, 0xA74501, 0xA74600, 0xA74701, 0xA74800,
+    0xA74901, 0xA74A00, 0xA74B01, 0xA74C00, 0xA74D01, 0xA74E00, 0xA74F01, 0xA75000,
+    0xA75101, 0xA75200, 0xA75301, 0xA75400, 0xA75501, 0xA75600, 0xA75701, 0xA75800,
+    0xA75901, 0xA75A00, 0xA75B01, 0xA75C00, 0xA75D01, 0xA75E00, 0xA75F01, 0xA76000,
+    0xA76101, 0xA76200, 0xA76301, 0xA76400, 0xA76501, 0xA76600, 0xA76701, 0xA76800,
+    0xA76901, 0xA76A00, 0xA76B01, 0xA76C00, 0xA76D01, 0xA76E00, 0xA76F01, 0xA77003,
+    0xA77101, 0xA77900, 0xA77A01, 0xA77B00, 0xA77C01, 0xA77D00, 0xA77F01, 0xA78000,
+    0xA78101, 0xA78200, 0xA78301, 0xA78400, 0xA78501, 0xA78600, 0xA78701, 0xA78803,
+    0xA78914, 0xA78B00, 0xA78C01, 0xA78D00, 0xA78E01, 0xA78F04, 0xA79000, 0xA79101,
+    0xA79200, 0xA79301, 0xA79600, 0xA79701, 0xA79800, 0xA79901, 0xA79A00, 0xA79B01,
+    0xA79C00, 0xA79D01, 0xA79E00, 0xA79F01, 0xA7A000, 0xA7A101, 0xA7A200, 0xA7A301,
+    0xA7A400, 0xA7A501, 0xA7A600, 0xA7A701, 0xA7A800, 0xA7A901, 0xA7AA00, 0xA7AF01,
+    0xA7B000, 0xA7B501, 0xA7B600, 0xA7B701, 0xA7B800, 0xA7B901, 0xA7BA00, 0xA7BB01,
+    0xA7BC00, 0xA7BD01, 0xA7BE00, 0xA7BF01, 0xA7C000, 0xA7C101, 0xA7C200, 0xA7C301,
+    0xA7C400, 0xA7C801, 0xA7C900, 0xA7CA01, 0xA7CB1D, 0xA7D000, 0xA7D101, 0xA7D21D,
+    0xA7D301, 0xA7D41D, 0xA7D501, 0xA7D600, 0xA7D701, 0xA7D800, 0xA7D901, 0xA7DA1D,
+    0xA7F203, 0xA7F500, 0xA7F601, 0xA7F704, 0xA7F803, 0xA7FA01, 0xA7FB04, 0xA80205,
+    0xA80304, 0xA80605, 0xA80704, 0xA80B05, 0xA80C04, 0xA82306, 0xA82505, 0xA82706,
+    0xA82815, 0xA82C05, 0xA82D1D, 0xA8300A, 0xA83615, 0xA83813, 0xA83915, 0xA83A1D,
+    0xA84004, 0xA87411, 0xA8781D, 0xA88006, 0xA88204, 0xA8B406, 0xA8C405, 0xA8C61D,
+    0xA8CE11, 0xA8D008, 0xA8DA1D, 0xA8E005, 0xA8F204, 0xA8F811, 0xA8FB04, 0xA8FC11,
+    0xA8FD04, 0xA8FF05, 0xA90008, 0xA90A04, 0xA92605, 0xA92E11, 0xA93004, 0xA94705,
+    0xA95206, 0xA9541D, 0xA95F11, 0xA96004, 0xA97D1D, 0xA98005, 0xA98306, 0xA98404,
+    0xA9B305, 0xA9B406, 0xA9B605, 0xA9BA06, 0xA9BC05, 0xA9BE06, 0xA9C111, 0xA9CE1D,
+    0xA9CF03, 0xA9D008, 0xA9DA1D, 0xA9DE11, 0xA9E004, 0xA9E505, 0xA9E603, 0xA9E704,
+    0xA9F008, 0xA9FA04, 0xA9FF1D, 0xAA0004, 0xAA2905, 0xAA2F06, 0xAA3105, 0xAA3306,
+    0xAA3505, 0xAA371D, 0xAA4004, 0xAA4305, 0xAA4404, 0xAA4C05, 0xAA4D06, 0xAA4E1D,
+    0xAA5008, 0xAA5A1D, 0xAA5C11, 0xAA6004, 0xAA7003, 0xAA7104, 0xAA7715, 0xAA7A04,
+    0xAA7B06, 0xAA7C05, 0xAA7D06, 0xAA7E04, 0xAAB005, 0xAAB104, 0xAAB205, 0xAAB504,
+    0xAAB705, 0xAAB904, 0xAABE05, 0xAAC004, 0xAAC105, 0xAAC204, 0xAAC31D, 0xAADB04,
+    0xAADD03, 0xAADE11, 0xAAE004, 0xAAEB06, 0xAAEC05, 0xAAEE06, 0xAAF011, 0xAAF204,
+    0xAAF303, 0xAAF506, 0xAAF605, 0xAAF71D, 0xAB0104, 0xAB071D, 0xAB0904, 0xAB0F1D,
+    0xAB1104, 0xAB171D, 0xAB2004, 0xAB271D, 0xAB2804, 0xAB2F1D, 0xAB3001, 0xAB5B14,
+    0xAB5C03, 0xAB6001, 0xAB6903, 0xAB6A14, 0xAB6C1D, 0xAB7001, 0xABC004, 0xABE306,
+    0xABE505, 0xABE606, 0xABE805, 0xABE906, 0xABEB11, 0xABEC06, 0xABED05, 0xABEE1D,
+    0xABF008, 0xABFA1D, 0xAC0004, 0xD7A41D, 0xD7B004, 0xD7C71D, 0xD7CB04, 0xD7FC1D,
+    0xD8001B, 0xE0001C, 0xF90004, 0xFA6E1D, 0xFA7004, 0xFADA1D, 0xFB0001, 0xFB071D,
+    0xFB1301, 0xFB181D, 0xFB1D04, 0xFB1E05, 0xFB1F04, 0xFB2912, 0xFB2A04, 0xFB371D,
+    0xFB3804, 0xFB3D1D, 0xFB3E04, 0xFB3F1D, 0xFB4004, 0xFB421D, 0xFB4304, 0xFB451D,
+    0xFB4604, 0xFBB214, 0xFBC31D, 0xFBD304, 0xFD3E0E, 0xFD3F0D, 0xFD4015, 0xFD5004,
+    0xFD901D, 0xFD9204, 0xFDC81D, 0xFDCF15, 0xFDD01D, 0xFDF004, 0xFDFC13, 0xFDFD15,
+    0xFE0005, 0xFE1011, 0xFE170D, 0xFE180E, 0xFE1911, 0xFE1A1D, 0xFE2005, 0xFE3011,
+    0xFE310C, 0xFE330B, 0xFE350D, 0xFE360E, 0xFE370D, 0xFE380E, 0xFE390D, 0xFE3A0E,
+    0xFE3B0D, 0xFE3C0E, 0xFE3D0D, 0xFE3E0E, 0xFE3F0D, 0xFE400E, 0xFE410D, 0xFE420E,
+    0xFE430D, 0xFE440E, 0xFE4511, 0xFE470D, 0xFE480E, 0xFE4911, 0xFE4D0B, 0xFE5011,
+    0xFE531D, 0xFE5411, 0xFE580C, 0xFE590D, 0xFE5A0E, 0xFE5B0D, 0xFE5C0E, 0xFE5D0D,
+    0xFE5E0E, 0xFE5F11, 0xFE6212, 0xFE630C, 0xFE6412, 0xFE671D, 0xFE6811, 0xFE6913,
+    0xFE6A11, 0xFE6C1D, 0xFE7004, 0xFE751D, 0xFE7604, 0xFEFD1D, 0xFEFF1A, 0xFF001D,
+    0xFF0111, 0xFF0413, 0xFF0511, 0xFF080D, 0xFF090E, 0xFF0A11, 0xFF0B12, 0xFF0C11,
+    0xFF0D0C, 0xFF0E11, 0xFF1008, 0xFF1A11, 0xFF1C12, 0xFF1F11, 0xFF2100, 0xFF3B0D,
+    0xFF3C11, 0xFF3D0E, 0xFF3E14, 0xFF3F0B, 0xFF4014, 0xFF4101, 0xFF5B0D, 0xFF5C12,
+    0xFF5D0E, 0xFF5E12, 0xFF5F0D, 0xFF600E, 0xFF6111, 0xFF620D, 0xFF630E, 0xFF6411,
+    0xFF6604, 0xFF7003, 0xFF7104, 0xFF9E03, 0xFFA004, 0xFFBF1D, 0xFFC204, 0xFFC81D,
+    0xFFCA04, 0xFFD01D, 0xFFD204, 0xFFD81D, 0xFFDA04, 0xFFDD1D, 0xFFE013, 0xFFE212,
+    0xFFE314, 0xFFE415, 0xFFE513, 0xFFE71D, 0xFFE815, 0xFFE912, 0xFFED15, 0xFFEF1D,
+    0xFFF91A, 0xFFFC15, 0xFFFE1D, 0x1000004, 0x1000C1D, 0x1000D04, 0x100271D, 0x1002804,
+    0x1003B1D, 0x1003C04, 0x1003E1D, 0x1003F04, 0x1004E1D, 0x1005004, 0x1005E1D, 0x1008004,
+    0x100FB1D, 0x1010011, 0x101031D, 0x101070A, 0x101341D, 0x1013715, 0x1014009, 0x101750A,
+    0x1017915, 0x1018A0A, 0x1018C15, 0x1018F1D, 0x1019015, 0x1019D1D, 0x101A015, 0x101A11D,
+    0x101D015, 0x101FD05, 0x101FE1D, 0x1028004, 0x1029D1D, 0x102A004, 0x102D11D, 0x102E005,
+    0x102E10A, 0x102FC1D, 0x1030004, 0x103200A, 0x103241D, 0x1032D04, 0x1034109, 0x1034204,
+    0x1034A09, 0x1034B1D, 0x1035004, 0x1037605, 0x1037B1D, 0x1038004, 0x1039E1D, 0x1039F11,
+    0x103A004, 0x103C41D, 0x103C804, 0x103D011, 0x103D109, 0x103D61D, 0x1040000, 0x1042801,
+    0x1045004, 0x1049E1D, 0x104A008, 0x104AA1D, 0x104B000, 0x104D41D, 0x104D801, 0x104FC1D,
+    0x1050004, 0x105281D, 0x1053004, 0x105641D, 0x1056F11, 0x1057000, 0x1057B1D, 0x1057C00,
+    0x1058B1D, 0x1058C00, 0x105931D, 0x1059400, 0x105961D, 0x1059701, 0x105A21D, 0x105A301,
+    0x105B21D, 0x105B301, 0x105BA1D, 0x105BB01, 0x105BD1D, 0x1060004, 0x107371D, 0x1074004,
+    0x107561D, 0x1076004, 0x107681D, 0x1078003, 0x107861D, 0x1078703, 0x107B11D, 0x107B203,
+    0x107BB1D, 0x1080004, 0x108061D, 0x1080804, 0x108091D, 0x1080A04, 0x108361D, 0x1083704,
+    0x108391D, 0x1083C04, 0x1083D1D, 0x1083F04, 0x108561D, 0x1085711, 0x108580A, 0x1086004,
+    0x1087715, 0x108790A, 0x1088004, 0x1089F1D, 0x108A70A, 0x108B01D, 0x108E004, 0x108F31D,
+    0x108F404, 0x108F61D, 0x108FB0A, 0x1090004, 0x109160A, 0x1091C1D, 0x1091F11, 0x1092004,
+    0x1093A1D, 0x1093F11, 0x109401D, 0x1098004, 0x109B81D, 0x109BC0A, 0x109BE04, 0x109C00A,
+    0x109D01D, 0x109D20A, 0x10A0004, 0x10A0105, 0x10A041D, 0x10A0505, 0x10A071D, 0x10A0C05,
+    0x10A1004, 0x10A141D, 0x10A1504, 0x10A181D, 0x10A1904, 0x10A361D, 0x10A3805, 0x10A3B1D,
+    0x10A3F05, 0x10A400A, 0x10A491D, 0x10A5011, 0x10A591D, 0x10A6004, 0x10A7D0A, 0x10A7F11,
+    0x10A8004, 0x10A9D0A, 0x10AA01D, 0x10AC004, 0x10AC815, 0x10AC904, 0x10AE505, 0x10AE71D,
+    0x10AEB0A, 0x10AF011, 0x10AF71D, 0x10B0004, 0x10B361D, 0x10B3911, 0x10B4004, 0x10B561D,
+    0x10B580A, 0x10B6004, 0x10B731D, 0x10B780A, 0x10B8004, 0x10B921D, 0x10B9911, 0x10B9D1D,
+    0x10BA90A, 0x10BB01D, 0x10C0004, 0x10C491D, 0x10C8000, 0x10CB31D, 0x10CC001, 0x10CF31D,
+    0x10CFA0A, 0x10D0004, 0x10D2405, 0x10D281D, 0x10D3008, 0x10D3A1D, 0x10E600A, 0x10E7F1D,
+    0x10E8004, 0x10EAA1D, 0x10EAB05, 0x10EAD0C, 0x10EAE1D, 0x10EB004, 0x10EB21D, 0x10F0004,
+    0x10F1D0A, 0x10F2704, 0x10F281D, 0x10F3004, 0x10F4605, 0x10F510A, 0x10F5511, 0x10F5A1D,
+    0x10F7004, 0x10F8205, 0x10F8611, 0x10F8A1D, 0x10FB004, 0x10FC50A, 0x10FCC1D, 0x10FE004,
+    0x10FF71D, 0x1100006, 0x1100105, 0x1100206, 0x1100304, 0x1103805, 0x1104711, 0x1104E1D,
+    0x110520A, 0x1106608, 0x1107005, 0x1107104, 0x1107305, 0x1107504, 0x110761D, 0x1107F05,
+    0x1108206, 0x1108304, 0x110B006, 0x110B305, 0x110B706, 0x110B905, 0x110BB11, 0x110BD1A,
+    0x110BE11, 0x110C205, 0x110C31D, 0x110CD1A, 0x110CE1D, 0x110D004, 0x110E91D, 0x110F008,
+    0x110FA1D, 0x1110005, 0x1110304, 0x1112705, 0x1112C06, 0x1112D05, 0x111351D, 0x1113608,
+    0x1114011, 0x1114404, 0x1114506, 0x1114704, 0x111481D, 0x1115004, 0x1117305, 0x1117411,
+    0x1117604, 0x111771D, 0x1118005, 0x1118206, 0x1118304, 0x111B306, 0x111B605, 0x111BF06,
+    0x111C104, 0x111C511, 0x111C905, 0x111CD11, 0x111CE06, 0x111CF05, 0x111D008, 0x111DA04,
+    0x111DB11, 0x111DC04, 0x111DD11, 0x111E01D, 0x111E10A, 0x111F51D, 0x1120004, 0x112121D,
+    0x1121304, 0x1122C06, 0x1122F05, 0x1123206, 0x1123405, 0x1123506, 0x1123605, 0x1123811,
+    0x1123E05, 0x1123F1D, 0x1128004, 0x112871D, 0x1128804, 0x112891D, 0x1128A04, 0x1128E1D,
+    0x1128F04, 0x1129E1D, 0x1129F04, 0x112A911, 0x112AA1D, 0x112B004, 0x112DF05, 0x112E006,
+    0x112E305, 0x112EB1D, 0x112F008, 0x112FA1D, 0x1130005, 0x1130206, 0x113041D, 0x1130504,
+    0x1130D1D, 0x1130F04, 0x113111D, 0x1131304, 0x113291D, 0x1132A04, 0x113311D, 0x1133204,
+    0x113341D, 0x1133504, 0x1133A1D, 0x1133B05, 0x1133D04, 0x1133E06, 0x1134005, 0x1134106,
+    0x113451D, 0x1134706, 0x113491D, 0x1134B06, 0x1134E1D, 0x1135004, 0x113511D, 0x1135706,
+    0x113581D, 0x1135D04, 0x1136206, 0x113641D, 0x1136605, 0x1136D1D, 0x1137005, 0x113751D,
+    0x1140004, 0x1143506, 0x1143805, 0x1144006, 0x1144205, 0x1144506, 0x1144605, 0x1144704,
+    0x1144B11, 0x1145008, 0x1145A11, 0x1145C1D, 0x1145D11, 0x1145E05, 0x1145F04, 0x114621D,
+    0x1148004, 0x114B006, 0x114B305, 0x114B906, 0x114BA05, 0x114BB06, 0x114BF05, 0x114C106,
+    0x114C205, 0x114C404, 0x114C611, 0x114C704, 0x114C81D, 0x114D008, 0x114DA1D, 0x1158004,
+    0x115AF06, 0x115B205, 0x115B61D, 0x115B806, 0x115BC05, 0x115BE06, 0x115BF05, 0x115C111,
+    0x115D804, 0x115DC05, 0x115DE1D, 0x1160004, 0x1163006, 0x1163305, 0x1163B06, 0x1163D05,
+    0x1163E06, 0x1163F05, 0x1164111, 0x1164404, 0x116451D, 0x1165008, 0x1165A1D, 0x1166011,
+    0x1166D1D, 0x1168004, 0x116AB05, 0x116AC06, 0x116AD05, 0x116AE06, 0x116B005, 0x116B606,
+    0x116B705, 0x116B804, 0x116B911, 0x116BA1D, 0x116C008, 0x116CA1D, 0x1170004, 0x1171B1D,
+    0x1171D05, 0x1172006, 0x1172205, 0x1172606, 0x1172705, 0x1172C1D, 0x1173008, 0x1173A0A,
+    0x1173C11, 0x1173F15, 0x1174004, 0x117471D, 0x1180004, 0x1182C06, 0x1182F05, 0x1183806,
+    0x1183905, 0x1183B11, 0x1183C1D, 0x118A000, 0x118C001, 0x118E008, 0x118EA0A, 0x118F31D,
+    0x118FF04, 0x119071D, 0x1190904, 0x1190A1D, 0x1190C04, 0x119141D, 0x1191504, 0x119171D,
+    0x1191804, 0x1193006, 0x119361D, 0x1193706, 0x119391D, 0x1193B05, 0x1193D06, 0x1193E05,
+    0x1193F04, 0x1194006, 0x1194104, 0x1194206, 0x1194305, 0x1194411, 0x119471D, 0x1195008,
+    0x1195A1D, 0x119A004, 0x119A81D, 0x119AA04, 0x119D106, 0x119D405, 0x119D81D, 0x119DA05,
+    0x119DC06, 0x119E005, 0x119E104, 0x119E211, 0x119E304, 0x119E406, 0x119E51D, 0x11A0004,
+    0x11A0105, 0x11A0B04, 0x11A3305, 0x11A3906, 0x11A3A04, 0x11A3B05, 0x11A3F11, 0x11A4705,
+    0x11A481D, 0x11A5004, 0x11A5105, 0x11A5706, 0x11A5905, 0x11A5C04, 0x11A8A05, 0x11A9706,
+    0x11A9805, 0x11A9A11, 0x11A9D04, 0x11A9E11, 0x11AA31D, 0x11AB004, 0x11AF91D, 0x11C0004,
+    0x11C091D, 0x11C0A04, 0x11C2F06, 0x11C3005, 0x11C371D, 0x11C3805, 0x11C3E06, 0x11C3F05,
+    0x11C4004, 0x11C4111, 0x11C461D, 0x11C5008, 0x11C5A0A, 0x11C6D1D, 0x11C7011, 0x11C7204,
+    0x11C901D, 0x11C9205, 0x11CA81D, 0x11CA906, 0x11CAA05, 0x11CB106, 0x11CB205, 0x11CB406,
+    0x11CB505, 0x11CB71D, 0x11D0004, 0x11D071D, 0x11D0804, 0x11D0A1D, 0x11D0B04, 0x11D3105,
+    0x11D371D, 0x11D3A05, 0x11D3B1D, 0x11D3C05, 0x11D3E1D, 0x11D3F05, 0x11D4604, 0x11D4705,
+    0x11D481D, 0x11D5008, 0x11D5A1D, 0x11D6004, 0x11D661D, 0x11D6704, 0x11D691D, 0x11D6A04,
+    0x11D8A06, 0x11D8F1D, 0x11D9005, 0x11D921D, 0x11D9306, 0x11D9505, 0x11D9606, 0x11D9705,
+    0x11D9804, 0x11D991D, 0x11DA008, 0x11DAA1D, 0x11EE004, 0x11EF305, 0x11EF506, 0x11EF711,
+    0x11EF91D, 0x11FB004, 0x11FB11D, 0x11FC00A, 0x11FD515, 0x11FDD13, 0x11FE115, 0x11FF21D,
+    0x11FFF11, 0x1200004, 0x1239A1D, 0x1240009, 0x1246F1D, 0x1247011, 0x124751D, 0x1248004,
+    0x125441D, 0x12F9004, 0x12FF111, 0x12FF31D, 0x1300004, 0x1342F1D, 0x134301A, 0x134391D,
+    0x1440004, 0x146471D, 0x1680004, 0x16A391D, 0x16A4004, 0x16A5F1D, 0x16A6008, 0x16A6A1D,
+    0x16A6E11, 0x16A7004, 0x16ABF1D, 0x16AC008, 0x16ACA1D, 0x16AD004, 0x16AEE1D, 0x16AF005,
+    0x16AF511, 0x16AF61D, 0x16B0004, 0x16B3005, 0x16B3711, 0x16B3C15, 0x16B4003, 0x16B4411,
+    0x16B4515, 0x16B461D, 0x16B5008, 0x16B5A1D, 0x16B5B0A, 0x16B621D, 0x16B6304, 0x16B781D,
+    0x16B7D04, 0x16B901D, 0x16E4000, 0x16E6001, 0x16E800A, 0x16E9711, 0x16E9B1D, 0x16F0004,
+    0x16F4B1D, 0x16F4F05, 0x16F5004, 0x16F5106, 0x16F881D, 0x16F8F05, 0x16F9303, 0x16FA01D,
+    0x16FE003, 0x16FE211, 0x16FE303, 0x16FE405, 0x16FE51D, 0x16FF006, 0x16FF21D, 0x1700004,
+    0x187F81D, 0x1880004, 0x18CD61D, 0x18D0004, 0x18D091D, 0x1AFF003, 0x1AFF41D, 0x1AFF503,
+    0x1AFFC1D, 0x1AFFD03, 0x1AFFF1D, 0x1B00004, 0x1B1231D, 0x1B15004, 0x1B1531D, 0x1B16404,
+    0x1B1681D, 0x1B17004, 0x1B2FC1D, 0x1BC0004, 0x1BC6B1D, 0x1BC7004, 0x1BC7D1D, 0x1BC8004,
+    0x1BC891D, 0x1BC9004, 0x1BC9A1D, 0x1BC9C15, 0x1BC9D05, 0x1BC9F11, 0x1BCA01A, 0x1BCA41D,
+    0x1CF0005, 0x1CF2E1D, 0x1CF3005, 0x1CF471D, 0x1CF5015, 0x1CFC41D, 0x1D00015, 0x1D0F61D,
+    0x1D10015, 0x1D1271D, 0x1D12915, 0x1D16506, 0x1D16705, 0x1D16A15, 0x1D16D06, 0x1D1731A,
+    0x1D17B05, 0x1D18315, 0x1D18505, 0x1D18C15, 0x1D1AA05, 0x1D1AE15, 0x1D1EB1D, 0x1D20015,
+    0x1D24205, 0x1D24515, 0x1D2461D, 0x1D2E00A, 0x1D2F41D, 0x1D30015, 0x1D3571D, 0x1D3600A,
+    0x1D3791D, 0x1D40000, 0x1D41A01, 0x1D43400, 0x1D44E01, 0x1D4551D, 0x1D45601, 0x1D46800,
+    0x1D48201, 0x1D49C00, 0x1D49D1D, 0x1D49E00, 0x1D4A01D, 0x1D4A200, 0x1D4A31D, 0x1D4A500,
+    0x1D4A71D, 0x1D4A900, 0x1D4AD1D, 0x1D4AE00, 0x1D4B601, 0x1D4BA1D, 0x1D4BB01, 0x1D4BC1D,
+    0x1D4BD01, 0x1D4C41D, 0x1D4C501, 0x1D4D000, 0x1D4EA01, 0x1D50400, 0x1D5061D, 0x1D50700,
+    0x1D50B1D, 0x1D50D00, 0x1D5151D, 0x1D51600, 0x1D51D1D, 0x1D51E01, 0x1D53800, 0x1D53A1D,
+    0x1D53B00, 0x1D53F1D, 0x1D54000, 0x1D5451D, 0x1D54600, 0x1D5471D, 0x1D54A00, 0x1D5511D,
+    0x1D55201, 0x1D56C00, 0x1D58601, 0x1D5A000, 0x1D5BA01, 0x1D5D400, 0x1D5EE01, 0x1D60800,
+    0x1D62201, 0x1D63C00, 0x1D65601, 0x1D67000, 0x1D68A01, 0x1D6A61D, 0x1D6A800, 0x1D6C112,
+    0x1D6C201, 0x1D6DB12, 0x1D6DC01, 0x1D6E200, 0x1D6FB12, 0x1D6FC01, 0x1D71512, 0x1D71601,
+    0x1D71C00, 0x1D73512, 0x1D73601, 0x1D74F12, 0x1D75001, 0x1D75600, 0x1D76F12, 0x1D77001,
+    0x1D78912, 0x1D78A01, 0x1D79000, 0x1D7A912, 0x1D7AA01, 0x1D7C312, 0x1D7C401, 0x1D7CA00,
+    0x1D7CB01, 0x1D7CC1D, 0x1D7CE08, 0x1D80015, 0x1DA0005, 0x1DA3715, 0x1DA3B05, 0x1DA6D15,
+    0x1DA7505, 0x1DA7615, 0x1DA8405, 0x1DA8515, 0x1DA8711, 0x1DA8C1D, 0x1DA9B05, 0x1DAA01D,
+    0x1DAA105, 0x1DAB01D, 0x1DF0001, 0x1DF0A04, 0x1DF0B01, 0x1DF1F1D, 0x1E00005, 0x1E0071D,
+    0x1E00805, 0x1E0191D, 0x1E01B05, 0x1E0221D, 0x1E02305, 0x1E0251D, 0x1E02605, 0x1E02B1D,
+    0x1E10004, 0x1E12D1D, 0x1E13005, 0x1E13703, 0x1E13E1D, 0x1E14008, 0x1E14A1D, 0x1E14E04,
+    0x1E14F15, 0x1E1501D, 0x1E29004, 0x1E2AE05, 0x1E2AF1D, 0x1E2C004, 0x1E2EC05, 0x1E2F008,
+    0x1E2FA1D, 0x1E2FF13, 0x1E3001D, 0x1E7E004, 0x1E7E71D, 0x1E7E804, 0x1E7EC1D, 0x1E7ED04,
+    0x1E7EF1D, 0x1E7F004, 0x1E7FF1D, 0x1E80004, 0x1E8C51D, 0x1E8C70A, 0x1E8D005, 0x1E8D71D,
+    0x1E90000, 0x1E92201, 0x1E94405, 0x1E94B03, 0x1E94C1D, 0x1E95008, 0x1E95A1D, 0x1E95E11,
+    0x1E9601D, 0x1EC710A, 0x1ECAC15, 0x1ECAD0A, 0x1ECB013, 0x1ECB10A, 0x1ECB51D, 0x1ED010A,
+    0x1ED2E15, 0x1ED2F0A, 0x1ED3E1D, 0x1EE0004, 0x1EE041D, 0x1EE0504, 0x1EE201D, 0x1EE2104,
+    0x1EE231D, 0x1EE2404, 0x1EE251D, 0x1EE2704, 0x1EE281D, 0x1EE2904, 0x1EE331D, 0x1EE3404,
+    0x1EE381D, 0x1EE3904, 0x1EE3A1D, 0x1EE3B04, 0x1EE3C1D, 0x1EE4204, 0x1EE431D, 0x1EE4704,
+    0x1EE481D, 0x1EE4904, 0x1EE4A1D, 0x1EE4B04, 0x1EE4C1D, 0x1EE4D04, 0x1EE501D, 0x1EE5104,
+    0x1EE531D, 0x1EE5404, 0x1EE551D, 0x1EE5704, 0x1EE581D, 0x1EE5904, 0x1EE5A1D, 0x1EE5B04,
+    0x1EE5C1D, 0x1EE5D04, 0x1EE5E1D, 0x1EE5F04, 0x1EE601D, 0x1EE6104, 0x1EE631D, 0x1EE6404,
+    0x1EE651D, 0x1EE6704, 0x1EE6B1D, 0x1EE6C04, 0x1EE731D, 0x1EE7404, 0x1EE781D, 0x1EE7904,
+    0x1EE7D1D, 0x1EE7E04, 0x1EE7F1D, 0x1EE8004, 0x1EE8A1D, 0x1EE8B04, 0x1EE9C1D, 0x1EEA104,
+    0x1EEA41D, 0x1EEA504, 0x1EEAA1D, 0x1EEAB04, 0x1EEBC1D, 0x1EEF012, 0x1EEF21D, 0x1F00015,
+    0x1F02C1D, 0x1F03015, 0x1F0941D, 0x1F0A015, 0x1F0AF1D, 0x1F0B115, 0x1F0C01D, 0x1F0C115,
+    0x1F0D01D, 0x1F0D115, 0x1F0F61D, 0x1F1000A, 0x1F10D15, 0x1F1AE1D, 0x1F1E615, 0x1F2031D,
+    0x1F21015, 0x1F23C1D, 0x1F24015, 0x1F2491D, 0x1F25015, 0x1F2521D, 0x1F26015, 0x1F2661D,
+    0x1F30015, 0x1F3FB14, 0x1F40015, 0x1F6D81D, 0x1F6DD15, 0x1F6ED1D, 0x1F6F015, 0x1F6FD1D,
+    0x1F70015, 0x1F7741D, 0x1F78015, 0x1F7D91D, 0x1F7E015, 0x1F7EC1D, 0x1F7F015, 0x1F7F11D,
+    0x1F80015, 0x1F80C1D, 0x1F81015, 0x1F8481D, 0x1F85015, 0x1F85A1D, 0x1F86015, 0x1F8881D,
+    0x1F89015, 0x1F8AE1D, 0x1F8B015, 0x1F8B21D, 0x1F90015, 0x1FA541D, 0x1FA6015, 0x1FA6E1D,
+    0x1FA7015, 0x1FA751D, 0x1FA7815, 0x1FA7D1D, 0x1FA8015, 0x1FA871D, 0x1FA9015, 0x1FAAD1D,
+    0x1FAB015, 0x1FABB1D, 0x1FAC015, 0x1FAC61D, 0x1FAD015, 0x1FADA1D, 0x1FAE015, 0x1FAE81D,
+    0x1FAF015, 0x1FAF71D, 0x1FB0015, 0x1FB931D, 0x1FB9415, 0x1FBCB1D, 0x1FBF008, 0x1FBFA1D,
+    0x2000004, 0x2A6E01D, 0x2A70004, 0x2B7391D, 0x2B74004, 0x2B81E1D, 0x2B82004, 0x2CEA21D,
+    0x2CEB004, 0x2EBE11D, 0x2F80004, 0x2FA1E1D, 0x3000004, 0x3134B1D, 0xE00011A, 0xE00021D,
+    0xE00201A, 0xE00801D, 0xE010005, 0xE01F01D, 0xF00001C, 0xFFFFE1D, 0x1000001C, 0x10FFFE1D,
+];
+
+/// Scripts (indices into `SCRIPT_CODES`), as `first << 8 | value` for runs of code points with the same
+/// value starting at `first`. The first run starts at 0, a run lasts until the start of the next one.
+pub(super) static SCRIPTS: [u32; 1638] = [
+    0xA0, 0x4144, 0x5BA0, 0x6144, 0x7BA0, 0xAA44, 0xABA0, 0xBA44,
+    0xBBA0, 0xC044, 0xD7A0, 0xD844, 0xF7A0, 0xF844, 0x2B9A0, 0x2E044,
+    0x2E5A0, 0x2EA0D, 0x2ECA0, 0x3009F, 0x3702B, 0x374A0, 0x3752B, 0x378A1,
+    0x37A2B, 0x37EA0, 0x37F2B, 0x380A1, 0x3842B, 0x385A0, 0x3862B, 0x387A0,
+    0x3882B, 0x38BA1, 0x38C2B, 0x38DA1, 0x38E2B, 0x3A2A1, 0x3A32B, 0x3E218,
+    0x3F02B, 0x4001B, 0x4859F, 0x4871B, 0x530A1, 0x53105, 0x557A1, 0x55905,
+    0x58BA1, 0x58D05, 0x590A1, 0x59132, 0x5C8A1, 0x5D032, 0x5EBA1, 0x5EF32,
+    0x5F5A1, 0x60003, 0x605A0, 0x60603, 0x60CA0, 0x60D03, 0x61BA0, 0x61C03,
+    0x61FA0, 0x62003, 0x640A0, 0x64103, 0x64B9F, 0x65603, 0x6709F, 0x67103,
+    0x6DDA0, 0x6DE03, 0x70084, 0x70EA1, 0x70F84, 0x74BA1, 0x74D84, 0x75003,
+    0x7808F, 0x7B2A1, 0x7C060, 0x7FBA1, 0x7FD60, 0x80075, 0x82EA1, 0x83075,
+    0x83FA1, 0x8404E, 0x85CA1, 0x85E4E, 0x85FA1, 0x86084, 0x86BA1, 0x87003,
+    0x88FA1, 0x89003, 0x892A1, 0x89803, 0x8E2A0, 0x8E303, 0x9001C, 0x9519F,
+    0x9551C, 0x964A0, 0x9661C, 0x9800B, 0x984A1, 0x9850B, 0x98DA1, 0x98F0B,
+    0x991A1, 0x9930B, 0x9A9A1, 0x9AA0B, 0x9B1A1, 0x9B20B, 0x9B3A1, 0x9B60B,
+    0x9BAA1, 0x9BC0B, 0x9C5A1, 0x9C70B, 0x9C9A1, 0x9CB0B, 0x9CFA1, 0x9D70B,
+    0x9D8A1, 0x9DC0B, 0x9DEA1, 0x9DF0B, 0x9E4A1, 0x9E60B, 0x9FFA1, 0xA012D,
+    0xA04A1, 0xA052D, 0xA0BA1, 0xA0F2D, 0xA11A1, 0xA132D, 0xA29A1, 0xA2A2D,
+    0xA31A1, 0xA322D, 0xA34A1, 0xA352D, 0xA37A1, 0xA382D, 0xA3AA1, 0xA3C2D,
+    0xA3DA1, 0xA3E2D, 0xA43A1, 0xA472D, 0xA49A1, 0xA4B2D, 0xA4EA1, 0xA512D,
+    0xA52A1, 0xA592D, 0xA5DA1, 0xA5E2D, 0xA5FA1, 0xA662D, 0xA77A1, 0xA812C,
+    0xA84A1, 0xA852C, 0xA8EA1, 0xA8F2C, 0xA92A1, 0xA932C, 0xAA9A1, 0xAAA2C,
+    0xAB1A1, 0xAB22C, 0xAB4A1, 0xAB52C, 0xABAA1, 0xABC2C, 0xAC6A1, 0xAC72C,
+    0xACAA1, 0xACB2C, 0xACEA1, 0xAD02C, 0xAD1A1, 0xAE02C, 0xAE4A1, 0xAE62C,
+    0xAF2A1, 0xAF92C, 0xB00A1, 0xB0165, 0xB04A1, 0xB0565, 0xB0DA1, 0xB0F65,
+    0xB11A1, 0xB1365, 0xB29A1, 0xB2A65, 0xB31A1, 0xB3265, 0xB34A1, 0xB3565,
+    0xB3AA1, 0xB3C65, 0xB45A1, 0xB4765, 0xB49A1, 0xB4B65, 0xB4EA1, 0xB5565,
+    0xB58A1, 0xB5C65, 0xB5EA1, 0xB5F65, 0xB64A1, 0xB6665, 0xB78A1, 0xB8289,
+    0xB84A1, 0xB8589, 0xB8BA1, 0xB8E89, 0xB91A1, 0xB9289, 0xB96A1, 0xB9989,
+    0xB9BA1, 0xB9C89, 0xB9DA1, 0xB9E89, 0xBA0A1, 0xBA389, 0xBA5A1, 0xBA889,
+    0xBABA1, 0xBAE89, 0xBBAA1, 0xBBE89, 0xBC3A1, 0xBC689, 0xBC9A1, 0xBCA89,
+    0xBCEA1, 0xBD089, 0xBD1A1, 0xBD789, 0xBD8A1, 0xBE689, 0xBFBA1, 0xC008C,
+    0xC0DA1, 0xC0E8C, 0xC11A1, 0xC128C, 0xC29A1, 0xC2A8C, 0xC3AA1, 0xC3C8C,
+    0xC45A1, 0xC468C, 0xC49A1, 0xC4A8C, 0xC4EA1, 0xC558C, 0xC57A1, 0xC588C,
+    0xC5BA1, 0xC5D8C, 0xC5EA1, 0xC608C, 0xC64A1, 0xC668C, 0xC70A1, 0xC778C,
+    0xC8040, 0xC8DA1, 0xC8E40, 0xC91A1, 0xC9240, 0xCA9A1, 0xCAA40, 0xCB4A1,
+    0xCB540, 0xCBAA1, 0xCBC40, 0xCC5A1, 0xCC640, 0xCC9A1, 0xCCA40, 0xCCEA1,
+    0xCD540, 0xCD7A1, 0xCDD40, 0xCDFA1, 0xCE040, 0xCE4A1, 0xCE640, 0xCF0A1,
+    0xCF140, 0xCF3A1, 0xD0055, 0xD0DA1, 0xD0E55, 0xD11A1, 0xD1255, 0xD45A1,
+    0xD4655, 0xD49A1, 0xD4A55, 0xD50A1, 0xD5455, 0xD64A1, 0xD6655, 0xD80A1,
+    0xD817D, 0xD84A1, 0xD857D, 0xD97A1, 0xD9A7D, 0xDB2A1, 0xDB37D, 0xDBCA1,
+    0xDBD7D, 0xDBEA1, 0xDC07D, 0xDC7A1, 0xDCA7D, 0xDCBA1, 0xDCF7D, 0xDD5A1,
+    0xDD67D, 0xDD7A1, 0xDD87D, 0xDE0A1, 0xDE67D, 0xDF0A1, 0xDF27D, 0xDF5A1,
+    0xE0190, 0xE3BA1, 0xE3FA0, 0xE4090, 0xE5CA1, 0xE8143, 0xE83A1, 0xE8443,
+    0xE85A1, 0xE8643, 0xE8BA1, 0xE8C43, 0xEA4A1, 0xEA543, 0xEA6A1, 0xEA743,
+    0xEBEA1, 0xEC043, 0xEC5A1, 0xEC643, 0xEC7A1, 0xEC843, 0xECEA1, 0xED043,
+    0xEDAA1, 0xEDC43, 0xEE0A1, 0xF0091, 0xF48A1, 0xF4991, 0xF6DA1, 0xF7191,
+    0xF98A1, 0xF9991, 0xFBDA1, 0xFBE91, 0xFCDA1, 0xFCE91, 0xFD5A0, 0xFD991,
+    0xFDBA1, 0x10005B, 0x10A025, 0x10C6A1, 0x10C725, 0x10C8A1, 0x10CD25, 0x10CEA1,
+    0x10D025, 0x10FBA0, 0x10FC25, 0x11002E, 0x120024, 0x1249A1, 0x124A24, 0x124EA1,
+    0x125024, 0x1257A1, 0x125824, 0x1259A1, 0x125A24, 0x125EA1, 0x126024, 0x1289A1,
+    0x128A24, 0x128EA1, 0x129024, 0x12B1A1, 0x12B224, 0x12B6A1, 0x12B824, 0x12BFA1,
+    0x12C024, 0x12C1A1, 0x12C224, 0x12C6A1, 0x12C824, 0x12D7A1, 0x12D824, 0x1311A1,
+    0x131224, 0x1316A1, 0x131824, 0x135BA1, 0x135D24, 0x137DA1, 0x138024, 0x139AA1,
+    0x13A016, 0x13F6A1, 0x13F816, 0x13FEA1, 0x140013, 0x168062, 0x169DA1, 0x16A074,
+    0x16EBA0, 0x16EE74, 0x16F9A1, 0x17008E, 0x1716A1, 0x171F8E, 0x172030, 0x1735A0,
+    0x1737A1, 0x174011, 0x1754A1, 0x176085, 0x176DA1, 0x176E85, 0x1771A1, 0x177285,
+    0x1774A1, 0x17803D, 0x17DEA1, 0x17E03D, 0x17EAA1, 0x17F03D, 0x17FAA1, 0x180057,
+    0x1802A0, 0x180457, 0x1805A0, 0x180657, 0x181AA1, 0x182057, 0x1879A1, 0x188057,
+    0x18ABA1, 0x18B013, 0x18F6A1, 0x190046, 0x191FA1, 0x192046, 0x192CA1, 0x193046,
+    0x193CA1, 0x194046, 0x1941A1, 0x194446, 0x195087, 0x196EA1, 0x197087, 0x1975A1,
+    0x198088, 0x19ACA1, 0x19B088, 0x19CAA1, 0x19D088, 0x19DBA1, 0x19DE88, 0x19E03D,
+    0x1A0010, 0x1A1CA1, 0x1A1E10, 0x1A2042, 0x1A5FA1, 0x1A6042, 0x1A7DA1, 0x1A7F42,
+    0x1A8AA1, 0x1A9042, 0x1A9AA1, 0x1AA042, 0x1AAEA1, 0x1AB09F, 0x1ACFA1, 0x1B0007,
+    0x1B4DA1, 0x1B5007, 0x1B7FA1, 0x1B8082, 0x1BC00A, 0x1BF4A1, 0x1BFC0A, 0x1C0045,
+    0x1C38A1, 0x1C3B45, 0x1C4AA1, 0x1C4D45, 0x1C5063, 0x1C801B, 0x1C89A1, 0x1C9025,
+    0x1CBBA1, 0x1CBD25, 0x1CC082, 0x1CC8A1, 0x1CD09F, 0x1CD3A0, 0x1CD49F, 0x1CE1A0,
+    0x1CE29F, 0x1CE9A0, 0x1CED9F, 0x1CEEA0, 0x1CF49F, 0x1CF5A0, 0x1CF89F, 0x1CFAA0,
+    0x1CFBA1, 0x1D0044, 0x1D262B, 0x1D2B1B, 0x1D2C44, 0x1D5D2B, 0x1D6244, 0x1D662B,
+    0x1D6B44, 0x1D781B, 0x1D7944, 0x1DBF2B, 0x1DC09F, 0x1E0044, 0x1F002B, 0x1F16A1,
+    0x1F182B, 0x1F1EA1, 0x1F202B, 0x1F46A1, 0x1F482B, 0x1F4EA1, 0x1F502B, 0x1F58A1,
+    0x1F592B, 0x1F5AA1, 0x1F5B2B, 0x1F5CA1, 0x1F5D2B, 0x1F5EA1, 0x1F5F2B, 0x1F7EA1,
+    0x1F802B, 0x1FB5A1, 0x1FB62B, 0x1FC5A1, 0x1FC62B, 0x1FD4A1, 0x1FD62B, 0x1FDCA1,
+    0x1FDD2B, 0x1FF0A1, 0x1FF22B, 0x1FF5A1, 0x1FF62B, 0x1FFFA1, 0x2000A0, 0x200C9F,
+    0x200EA0, 0x2065A1, 0x2066A0, 0x207144, 0x2072A1, 0x2074A0, 0x207F44, 0x2080A0,
+    0x208FA1, 0x209044, 0x209DA1, 0x20A0A0, 0x20C1A1, 0x20D09F, 0x20F1A1, 0x2100A0,
+    0x21262B, 0x2127A0, 0x212A44, 0x212CA0, 0x213244, 0x2133A0, 0x214E44, 0x214FA0,
+    0x216044, 0x2189A0, 0x218CA1, 0x2190A0, 0x2427A1, 0x2440A0, 0x244BA1, 0x2460A0,
+    0x28000F, 0x2900A0, 0x2B74A1, 0x2B76A0, 0x2B96A1, 0x2B97A0, 0x2C0026, 0x2C6044,
+    0x2C8018, 0x2CF4A1, 0x2CF918, 0x2D0025, 0x2D26A1, 0x2D2725, 0x2D28A1, 0x2D2D25,
+    0x2D2EA1, 0x2D308D, 0x2D68A1, 0x2D6F8D, 0x2D71A1, 0x2D7F8D, 0x2D8024, 0x2D97A1,
+    0x2DA024, 0x2DA7A1, 0x2DA824, 0x2DAFA1, 0x2DB024, 0x2DB7A1, 0x2DB824, 0x2DBFA1,
+    0x2DC024, 0x2DC7A1, 0x2DC824, 0x2DCFA1, 0x2DD024, 0x2DD7A1, 0x2DD824, 0x2DDFA1,
+    0x2DE01B, 0x2E00A0, 0x2E5EA1, 0x2E802F, 0x2E9AA1, 0x2E9B2F, 0x2EF4A1, 0x2F002F,
+    0x2FD6A1, 0x2FF0A0, 0x2FFCA1, 0x3000A0, 0x30052F, 0x3006A0, 0x30072F, 0x3008A0,
+    0x30212F, 0x302A9F, 0x302E2E, 0x3030A0, 0x30382F, 0x303CA0, 0x3040A1, 0x304133,
+    0x3097A1, 0x30999F, 0x309BA0, 0x309D33, 0x30A0A0, 0x30A13B, 0x30FBA0, 0x30FD3B,
+    0x3100A1, 0x31050D, 0x3130A1, 0x31312E, 0x318FA1, 0x3190A0, 0x31A00D, 0x31C0A0,
+    0x31E4A1, 0x31F03B, 0x32002E, 0x321FA1, 0x3220A0, 0x32602E, 0x327FA0, 0x32D03B,
+    0x32FFA0, 0x33003B, 0x3358A0, 0x34002F, 0x4DC0A0, 0x4E002F, 0xA0009D, 0xA48DA1,
+    0xA4909D, 0xA4C7A1, 0xA4D049, 0xA50096, 0xA62CA1, 0xA6401B, 0xA6A008, 0xA6F8A1,
+    0xA700A0, 0xA72244, 0xA788A0, 0xA78B44, 0xA7CBA1, 0xA7D044, 0xA7D2A1, 0xA7D344,
+    0xA7D4A1, 0xA7D544, 0xA7DAA1, 0xA7F244, 0xA80083, 0xA82DA1, 0xA830A0, 0xA83AA1,
+    0xA8406C, 0xA878A1, 0xA88077, 0xA8C6A1, 0xA8CE77, 0xA8DAA1, 0xA8E01C, 0xA9003A,
+    0xA92EA0, 0xA92F3A, 0xA93072, 0xA954A1, 0xA95F72, 0xA9602E, 0xA97DA1, 0xA98039,
+    0xA9CEA1, 0xA9CFA0, 0xA9D039, 0xA9DAA1, 0xA9DE39, 0xA9E05B, 0xA9FFA1, 0xAA0015,
+    0xAA37A1, 0xAA4015, 0xAA4EA1, 0xAA5015, 0xAA5AA1, 0xAA5C15, 0xAA605B, 0xAA808B,
+    0xAAC3A1, 0xAADB8B, 0xAAE059, 0xAAF7A1, 0xAB0124, 0xAB07A1, 0xAB0924, 0xAB0FA1,
+    0xAB1124, 0xAB17A1, 0xAB2024, 0xAB27A1, 0xAB2824, 0xAB2FA1, 0xAB3044, 0xAB5BA0,
+    0xAB5C44, 0xAB652B, 0xAB6644, 0xAB6AA0, 0xAB6CA1, 0xAB7016, 0xABC059, 0xABEEA1,
+    0xABF059, 0xABFAA1, 0xAC002E, 0xD7A4A1, 0xD7B02E, 0xD7C7A1, 0xD7CB2E, 0xD7FCA1,
+    0xF9002F, 0xFA6EA1, 0xFA702F, 0xFADAA1, 0xFB0044, 0xFB07A1, 0xFB1305, 0xFB18A1,
+    0xFB1D32, 0xFB37A1, 0xFB3832, 0xFB3DA1, 0xFB3E32, 0xFB3FA1, 0xFB4032, 0xFB42A1,
+    0xFB4332, 0xFB45A1, 0xFB4632, 0xFB5003, 0xFBC3A1, 0xFBD303, 0xFD3EA0, 0xFD4003,
+    0xFD90A1, 0xFD9203, 0xFDC8A1, 0xFDCF03, 0xFDD0A1, 0xFDF003, 0xFE009F, 0xFE10A0,
+    0xFE1AA1, 0xFE209F, 0xFE2E1B, 0xFE30A0, 0xFE53A1, 0xFE54A0, 0xFE67A1, 0xFE68A0,
+    0xFE6CA1, 0xFE7003, 0xFE75A1, 0xFE7603, 0xFEFDA1, 0xFEFFA0, 0xFF00A1, 0xFF01A0,
+    0xFF2144, 0xFF3BA0, 0xFF4144, 0xFF5BA0, 0xFF663B, 0xFF70A0, 0xFF713B, 0xFF9EA0,
+    0xFFA02E, 0xFFBFA1, 0xFFC22E, 0xFFC8A1, 0xFFCA2E, 0xFFD0A1, 0xFFD22E, 0xFFD8A1,
+    0xFFDA2E, 0xFFDDA1, 0xFFE0A0, 0xFFE7A1, 0xFFE8A0, 0xFFEFA1, 0xFFF9A0, 0xFFFEA1,
+    0x1000048, 0x1000CA1, 0x1000D48, 0x10027A1, 0x1002848, 0x1003BA1, 0x1003C48, 0x1003EA1,
+    0x1003F48, 0x1004EA1, 0x1005048, 0x1005EA1, 0x1008048, 0x100FBA1, 0x10100A0, 0x10103A1,
+    0x10107A0, 0x10134A1, 0x10137A0, 0x101402B, 0x1018FA1, 0x10190A0, 0x1019DA1, 0x101A02B,
+    0x101A1A1, 0x101D0A0, 0x101FD9F, 0x101FEA1, 0x102804A, 0x1029DA1, 0x102A014, 0x102D1A1,
+    0x102E09F, 0x102E1A0, 0x102FCA1, 0x1030038, 0x10324A1, 0x1032D38, 0x1033029, 0x1034BA1,
+    0x103506B, 0x1037BA1, 0x1038095, 0x1039EA1, 0x1039F95, 0x103A09A, 0x103C4A1, 0x103C89A,
+    0x103D6A1, 0x104001F, 0x1045079, 0x1048067, 0x1049EA1, 0x104A067, 0x104AAA1, 0x104B066,
+    0x104D4A1, 0x104D866, 0x104FCA1, 0x1050022, 0x10528A1, 0x1053001, 0x10564A1, 0x1056F01,
+    0x1057097, 0x1057BA1, 0x1057C97, 0x1058BA1, 0x1058C97, 0x10593A1, 0x1059497, 0x10596A1,
+    0x1059797, 0x105A2A1, 0x105A397, 0x105B2A1, 0x105B397, 0x105BAA1, 0x105BB97, 0x105BDA1,
+    0x1060047, 0x10737A1, 0x1074047, 0x10756A1, 0x1076047, 0x10768A1, 0x1078044, 0x10786A1,
+    0x1078744, 0x107B1A1, 0x107B244, 0x107BBA1, 0x108001A, 0x10806A1, 0x108081A, 0x10809A1,
+    0x1080A1A, 0x10836A1, 0x108371A, 0x10839A1, 0x1083C1A, 0x1083DA1, 0x1083F1A, 0x1084004,
+    0x10856A1, 0x1085704, 0x1086069, 0x108805E, 0x1089FA1, 0x108A75E, 0x108B0A1, 0x108E031,
+    0x108F3A1, 0x108F431, 0x108F6A1, 0x108FB31, 0x109006F, 0x1091CA1, 0x1091F6F, 0x109204B,
+    0x1093AA1, 0x1093F4B, 0x10940A1, 0x1098054, 0x109A053, 0x109B8A1, 0x109BC53, 0x109D0A1,
+    0x109D253, 0x10A003C, 0x10A04A1, 0x10A053C, 0x10A07A1, 0x10A0C3C, 0x10A14A1, 0x10A153C,
+    0x10A18A1, 0x10A193C, 0x10A36A1, 0x10A383C, 0x10A3BA1, 0x10A3F3C, 0x10A49A1, 0x10A503C,
+    0x10A59A1, 0x10A6076, 0x10A805D, 0x10AA0A1, 0x10AC04F, 0x10AE7A1, 0x10AEB4F, 0x10AF7A1,
+    0x10B0006, 0x10B36A1, 0x10B3906, 0x10B4071, 0x10B56A1, 0x10B5871, 0x10B606D, 0x10B73A1,
+    0x10B786D, 0x10B806E, 0x10B92A1, 0x10B996E, 0x10B9DA1, 0x10BA96E, 0x10BB0A1, 0x10C0064,
+    0x10C49A1, 0x10C8037, 0x10CB3A1, 0x10CC037, 0x10CF3A1, 0x10CFA37, 0x10D0073, 0x10D28A1,
+    0x10D3073, 0x10D3AA1, 0x10E6003, 0x10E7FA1, 0x10E809C, 0x10EAAA1, 0x10EAB9C, 0x10EAEA1,
+    0x10EB09C, 0x10EB2A1, 0x10F007F, 0x10F28A1, 0x10F307E, 0x10F5AA1, 0x10F7068, 0x10F8AA1,
+    0x10FB017, 0x10FCCA1, 0x10FE023, 0x10FF7A1, 0x110000E, 0x1104EA1, 0x110520E, 0x11076A1,
+    0x1107F0E, 0x1108041, 0x110C3A1, 0x110CD41, 0x110CEA1, 0x110D080, 0x110E9A1, 0x110F080,
+    0x110FAA1, 0x1110012, 0x11135A1, 0x1113612, 0x11148A1, 0x111504C, 0x11177A1, 0x111807A,
+    0x111E0A1, 0x111E17D, 0x111F5A1, 0x112003E, 0x11212A1, 0x112133E, 0x1123FA1, 0x112805A,
+    0x11287A1, 0x112885A, 0x11289A1, 0x1128A5A, 0x1128EA1, 0x1128F5A, 0x1129EA1, 0x1129F5A,
+    0x112AAA1, 0x112B07C, 0x112EBA1, 0x112F07C, 0x112FAA1, 0x113002A, 0x11304A1, 0x113052A,
+    0x1130DA1, 0x1130F2A, 0x11311A1, 0x113132A, 0x11329A1, 0x1132A2A, 0x11331A1, 0x113322A,
+    0x11334A1, 0x113352A, 0x1133AA1, 0x1133B9F, 0x1133C2A, 0x11345A1, 0x113472A, 0x11349A1,
+    0x1134B2A, 0x1134EA1, 0x113502A, 0x11351A1, 0x113572A, 0x11358A1, 0x1135D2A, 0x11364A1,
+    0x113662A, 0x1136DA1, 0x113702A, 0x11375A1, 0x114005F, 0x1145CA1, 0x1145D5F, 0x11462A1,
+    0x1148092, 0x114C8A1, 0x114D092, 0x114DAA1, 0x115807B, 0x115B6A1, 0x115B87B, 0x115DEA1,
+    0x1160056, 0x11645A1, 0x1165056, 0x1165AA1, 0x1166057, 0x1166DA1, 0x1168086, 0x116BAA1,
+    0x116C086, 0x116CAA1, 0x1170002, 0x1171BA1, 0x1171D02, 0x1172CA1, 0x1173002, 0x11747A1,
+    0x118001E, 0x1183CA1, 0x118A098, 0x118F3A1, 0x118FF98, 0x119001D, 0x11907A1, 0x119091D,
+    0x1190AA1, 0x1190C1D, 0x11914A1, 0x119151D, 0x11917A1, 0x119181D, 0x11936A1, 0x119371D,
+    0x11939A1, 0x1193B1D, 0x11947A1, 0x119501D, 0x1195AA1, 0x119A05C, 0x119A8A1, 0x119AA5C,
+    0x119D8A1, 0x119DA5C, 0x119E5A1, 0x11A009E, 0x11A48A1, 0x11A5081, 0x11AA3A1, 0x11AB013,
+    0x11AC06A, 0x11AF9A1, 0x11C000C, 0x11C09A1, 0x11C0A0C, 0x11C37A1, 0x11C380C, 0x11C46A1,
+    0x11C500C, 0x11C6DA1, 0x11C7050, 0x11C90A1, 0x11C9250, 0x11CA8A1, 0x11CA950, 0x11CB7A1,
+    0x11D0028, 0x11D07A1, 0x11D0828, 0x11D0AA1, 0x11D0B28, 0x11D37A1, 0x11D3A28, 0x11D3BA1,
+    0x11D3C28, 0x11D3EA1, 0x11D3F28, 0x11D48A1, 0x11D5028, 0x11D5AA1, 0x11D6027, 0x11D66A1,
+    0x11D6727, 0x11D69A1, 0x11D6A27, 0x11D8FA1, 0x11D9027, 0x11D92A1, 0x11D9327, 0x11D99A1,
+    0x11DA027, 0x11DAAA1, 0x11EE04D, 0x11EF9A1, 0x11FB049, 0x11FB1A1, 0x11FC089, 0x11FF2A1,
+    0x11FFF89, 0x120009B, 0x1239AA1, 0x124009B, 0x1246FA1, 0x124709B, 0x12475A1, 0x124809B,
+    0x12544A1, 0x12F9019, 0x12FF3A1, 0x1300021, 0x1342FA1, 0x1343021, 0x13439A1, 0x1440034,
+    0x14647A1, 0x1680008, 0x16A39A1, 0x16A4058, 0x16A5FA1, 0x16A6058, 0x16A6AA1, 0x16A6E58,
+    0x16A7093, 0x16ABFA1, 0x16AC093, 0x16ACAA1, 0x16AD009, 0x16AEEA1, 0x16AF009, 0x16AF6A1,
+    0x16B0035, 0x16B46A1, 0x16B5035, 0x16B5AA1, 0x16B5B35, 0x16B62A1, 0x16B6335, 0x16B78A1,
+    0x16B7D35, 0x16B90A1, 0x16E4051, 0x16E9BA1, 0x16F0070, 0x16F4BA1, 0x16F4F70, 0x16F88A1,
+    0x16F8F70, 0x16FA0A1, 0x16FE08A, 0x16FE161, 0x16FE22F, 0x16FE43F, 0x16FE5A1, 0x16FF02F,
+    0x16FF2A1, 0x170008A, 0x187F8A1, 0x188008A, 0x18B003F, 0x18CD6A1, 0x18D008A, 0x18D09A1,
+    0x1AFF03B, 0x1AFF4A1, 0x1AFF53B, 0x1AFFCA1, 0x1AFFD3B, 0x1AFFFA1, 0x1B0003B, 0x1B00133,
+    0x1B1203B, 0x1B123A1, 0x1B15033, 0x1B153A1, 0x1B1643B, 0x1B168A1, 0x1B17061, 0x1B2FCA1,
+    0x1BC0020, 0x1BC6BA1, 0x1BC7020, 0x1BC7DA1, 0x1BC8020, 0x1BC89A1, 0x1BC9020, 0x1BC9AA1,
+    0x1BC9C20, 0x1BCA0A0, 0x1BCA4A1, 0x1CF009F, 0x1CF2EA1, 0x1CF309F, 0x1CF47A1, 0x1CF50A0,
+    0x1CFC4A1, 0x1D000A0, 0x1D0F6A1, 0x1D100A0, 0x1D127A1, 0x1D129A0, 0x1D1679F, 0x1D16AA0,
+    0x1D17B9F, 0x1D183A0, 0x1D1859F, 0x1D18CA0, 0x1D1AA9F, 0x1D1AEA0, 0x1D1EBA1, 0x1D2002B,
+    0x1D246A1, 0x1D2E0A0, 0x1D2F4A1, 0x1D300A0, 0x1D357A1, 0x1D360A0, 0x1D379A1, 0x1D400A0,
+    0x1D455A1, 0x1D456A0, 0x1D49DA1, 0x1D49EA0, 0x1D4A0A1, 0x1D4A2A0, 0x1D4A3A1, 0x1D4A5A0,
+    0x1D4A7A1, 0x1D4A9A0, 0x1D4ADA1, 0x1D4AEA0, 0x1D4BAA1, 0x1D4BBA0, 0x1D4BCA1, 0x1D4BDA0,
+    0x1D4C4A1, 0x1D4C5A0, 0x1D506A1, 0x1D507A0, 0x1D50BA1, 0x1D50DA0, 0x1D515A1, 0x1D516A0,
+    0x1D51DA1, 0x1D51EA0, 0x1D53AA1, 0x1D53BA0, 0x1D53FA1, 0x1D540A0, 0x1D545A1, 0x1D546A0,
+    0x1D547A1, 0x1D54AA0, 0x1D551A1, 0x1D552A0, 0x1D6A6A1, 0x1D6A8A0, 0x1D7CCA1, 0x1D7CEA0,
+    0x1D80078, 0x1DA8CA1, 0x1DA9B78, 0x1DAA0A1, 0x1DAA178, 0x1DAB0A1, 0x1DF0044, 0x1DF1FA1,
+    0x1E00026, 0x1E007A1, 0x1E00826, 0x1E019A1, 0x1E01B26, 0x1E022A1, 0x1E02326, 0x1E025A1,
+    0x1E02626, 0x1E02BA1, 0x1E10036, 0x1E12DA1, 0x1E13036, 0x1E13EA1, 0x1E14036, 0x1E14AA1,
+    0x1E14E36, 0x1E150A1, 0x1E29094, 0x1E2AFA1, 0x1E2C099, 0x1E2FAA1, 0x1E2FF99, 0x1E300A1,
+    0x1E7E024, 0x1E7E7A1, 0x1E7E824, 0x1E7ECA1, 0x1E7ED24, 0x1E7EFA1, 0x1E7F024, 0x1E7FFA1,
+    0x1E80052, 0x1E8C5A1, 0x1E8C752, 0x1E8D7A1, 0x1E90000, 0x1E94CA1, 0x1E95000, 0x1E95AA1,
+    0x1E95E00, 0x1E960A1, 0x1EC71A0, 0x1ECB5A1, 0x1ED01A0, 0x1ED3EA1, 0x1EE0003, 0x1EE04A1,
+    0x1EE0503, 0x1EE20A1, 0x1EE2103, 0x1EE23A1, 0x1EE2403, 0x1EE25A1, 0x1EE2703, 0x1EE28A1,
+    0x1EE2903, 0x1EE33A1, 0x1EE3403, 0x1EE38A1, 0x1EE3903, 0x1EE3AA1, 0x1EE3B03, 0x1EE3CA1,
+    0x1EE4203, 0x1EE43A1, 0x1EE4703, 0x1EE48A1, 0x1EE4903, 0x1EE4AA1, 0x1EE4B03, 0x1EE4CA1,
+    0x1EE4D03, 0x1EE50A1, 0x1EE5103, 0x1EE53A1, 0x1EE5403, 0x1EE55A1, 0x1EE5703, 0x1EE58A1,
+    0x1EE5903, 0x1EE5AA1, 0x1EE5B03, 0x1EE5CA1, 0x1EE5D03, 0x1EE5EA1, 0x1EE5F03, 0x1EE60A1,
+    0x1EE6103, 0x1EE63A1, 0x1EE6403, 0x1EE65A1, 0x1EE6703, 0x1EE6BA1, 0x1EE6C03, 0x1EE73A1,
+    0x1EE7403, 0x1EE78A1, 0x1EE7903, 0x1EE7DA1, 0x1EE7E03, 0x1EE7FA1, 0x1EE8003, 0x1EE8AA1,
+    0x1EE8B03, 0x1EE9CA1, 0x1EEA103, 0x1EEA4A1, 0x1EEA503, 0x1EEAAA1, 0x1EEAB03, 0x1EEBCA1,
+    0x1EEF003, 0x1EEF2A1, 0x1F000A0, 0x1F02CA1, 0x1F030A0, 0x1F094A1, 0x1F0A0A0, 0x1F0AFA1,
+    0x1F0B1A0, 0x1F0C0A1, 0x1F0C1A0, 0x1F0D0A1, 0x1F0D1A0, 0x1F0F6A1, 0x1F100A0, 0x1F1AEA1,
+    0x1F1E6A0, 0x1F20033, 0x1F201A0, 0x1F203A1, 0x1F210A0, 0x1F23CA1, 0x1F240A0, 0x1F249A1,
+    0x1F250A0, 0x1F252A1, 0x1F260A0, 0x1F266A1, 0x1F300A0, 0x1F6D8A1, 0x1F6DDA0, 0x1F6EDA1,
+    0x1F6F0A0, 0x1F6FDA1, 0x1F700A0, 0x1F774A1, 0x1F780A0, 0x1F7D9A1, 0x1F7E0A0, 0x1F7ECA1,
+    0x1F7F0A0, 0x1F7F1A1, 0x1F800A0, 0x1F80CA1, 0x1F810A0, 0x1F848A1, 0x1F850A0, 0x1F85AA1,
+    0x1F860A0, 0x1F888A1, 0x1F890A0, 0x1F8AEA1, 0x1F8B0A0, 0x1F8B2A1, 0x1F900A0, 0x1FA54A1,
+    0x1FA60A0, 0x1FA6EA1, 0x1FA70A0, 0x1FA75A1, 0x1FA78A0, 0x1FA7DA1, 0x1FA80A0, 0x1FA87A1,
+    0x1FA90A0, 0x1FAADA1, 0x1FAB0A0, 0x1FABBA1, 0x1FAC0A0, 0x1FAC6A1, 0x1FAD0A0, 0x1FADAA1,
+    0x1FAE0A0, 0x1FAE8A1, 0x1FAF0A0, 0x1FAF7A1, 0x1FB00A0, 0x1FB93A1, 0x1FB94A0, 0x1FBCBA1,
+    0x1FBF0A0, 0x1FBFAA1, 0x200002F, 0x2A6E0A1, 0x2A7002F, 0x2B739A1, 0x2B7402F, 0x2B81EA1,
+    0x2B8202F, 0x2CEA2A1, 0x2CEB02F, 0x2EBE1A1, 0x2F8002F, 0x2FA1EA1, 0x300002F, 0x3134BA1,
+    0xE0001A0, 0xE0002A1, 0xE0020A0, 0xE0080A1, 0xE01009F, 0xE01F0A1,
+];
+
+/// ISO 15924 codes of the scripts in `SCRIPTS`
+pub(super) static SCRIPT_CODES: [[u8; 4]; 162] = [
+    *b"Adlm", *b"Aghb", *b"Ahom", *b"Arab", *b"Armi", *b"Armn", *b"Avst", *b"Bali",
+    *b"Bamu", *b"Bass", *b"Batk", *b"Beng", *b"Bhks", *b"Bopo", *b"Brah", *b"Brai",
+    *b"Bugi", *b"Buhd", *b"Cakm", *b"Cans", *b"Cari", *b"Cham", *b"Cher", *b"Chrs",
+    *b"Copt", *b"Cpmn", *b"Cprt", *b"Cyrl", *b"Deva", *b"Diak", *b"Dogr", *b"Dsrt",
+    *b"Dupl", *b"Egyp", *b"Elba", *b"Elym", *b"Ethi", *b"Geor", *b"Glag", *b"Gong",
+    *b"Gonm", *b"Goth", *b"Gran", *b"Grek", *b"Gujr", *b"Guru", *b"Hang", *b"Hani",
+    *b"Hano", *b"Hatr", *b"Hebr", *b"Hira", *b"Hluw", *b"Hmng", *b"Hmnp", *b"Hung",
+    *b"Ital", *b"Java", *b"Kali", *b"Kana", *b"Khar", *b"Khmr", *b"Khoj", *b"Kits",
+    *b"Knda", *b"Kthi", *b"Lana", *b"Laoo", *b"Latn", *b"Lepc", *b"Limb", *b"Lina",
+    *b"Linb", *b"Lisu", *b"Lyci", *b"Lydi", *b"Mahj", *b"Maka", *b"Mand", *b"Mani",
+    *b"Marc", *b"Medf", *b"Mend", *b"Merc", *b"Mero", *b"Mlym", *b"Modi", *b"Mong",
+    *b"Mroo", *b"Mtei", *b"Mult", *b"Mymr", *b"Nand", *b"Narb", *b"Nbat", *b"Newa",
+    *b"Nkoo", *b"Nshu", *b"Ogam", *b"Olck", *b"Orkh", *b"Orya", *b"Osge", *b"Osma",
+    *b"Ougr", *b"Palm", *b"Pauc", *b"Perm", *b"Phag", *b"Phli", *b"Phlp", *b"Phnx",
+    *b"Plrd", *b"Prti", *b"Rjng", *b"Rohg", *b"Runr", *b"Samr", *b"Sarb", *b"Saur",
+    *b"Sgnw", *b"Shaw", *b"Shrd", *b"Sidd", *b"Sind", *b"Sinh", *b"Sogd", *b"Sogo",
+    *b"Sora", *b"Soyo", *b"Sund", *b"Sylo", *b"Syrc", *b"Tagb", *b"Takr", *b"Tale",
+    *b"Talu", *b"Taml", *b"Tang", *b"Tavt", *b"Telu", *b"Tfng", *b"Tglg", *b"Thaa",
+    *b"Thai", *b"Tibt", *b"Tirh", *b"Tnsa", *b"Toto", *b"Ugar", *b"Vaii", *b"Vith",
+    *b"Wara", *b"Wcho", *b"Xpeo", *b"Xsux", *b"Yezi", *b"Yiii", *b"Zanb", *b"Zinh",
+    *b"Zyyy", *b"Zzzz",
+];
+
+/// Grapheme_Cluster_Break property values (`GCB_*`), as `first << 8 | value` for runs of code points with the same
+/// value starting at `first`. The first run starts at 0, a run lasts until the start of the next one.
+pub(super) static GRAPHEME_BREAKS: [u32; 1675] = [
+    0x3, 0xA02, 0xB03, 0xD01, 0xE03, 0x2000, 0x7F03, 0xA000,
+    0xAD03, 0xAE00, 0x30004, 0x37000, 0x48304, 0x48A00, 0x59104, 0x5BE00,
+    0x5BF04, 0x5C000, 0x5C104, 0x5C300, 0x5C404, 0x5C600, 0x5C704, 0x5C800,
+    0x60007, 0x60600, 0x61004, 0x61B00, 0x61C03, 0x61D00, 0x64B04, 0x66000,
+    0x67004, 0x67100, 0x6D604, 0x6DD07, 0x6DE00, 0x6DF04, 0x6E500, 0x6E704,
+    0x6E900, 0x6EA04, 0x6EE00, 0x70F07, 0x71000, 0x71104, 0x71200, 0x73004,
+    0x74B00, 0x7A604, 0x7B100, 0x7EB04, 0x7F400, 0x7FD04, 0x7FE00, 0x81604,
+    0x81A00, 0x81B04, 0x82400, 0x82504, 0x82800, 0x82904, 0x82E00, 0x85904,
+    0x85C00, 0x89007, 0x89200, 0x89804, 0x8A000, 0x8CA04, 0x8E207, 0x8E304,
+    0x90308, 0x90400, 0x93A04, 0x93B08, 0x93C04, 0x93D00, 0x93E08, 0x94104,
+    0x94908, 0x94D04, 0x94E08, 0x95000, 0x95104, 0x95800, 0x96204, 0x96400,
+    0x98104, 0x98208, 0x98400, 0x9BC04, 0x9BD00, 0x9BE04, 0x9BF08, 0x9C104,
+    0x9C500, 0x9C708, 0x9C900, 0x9CB08, 0x9CD04, 0x9CE00, 0x9D704, 0x9D800,
+    0x9E204, 0x9E400, 0x9FE04, 0x9FF00, 0xA0104, 0xA0308, 0xA0400, 0xA3C04,
+    0xA3D00, 0xA3E08, 0xA4104, 0xA4300, 0xA4704, 0xA4900, 0xA4B04, 0xA4E00,
+    0xA5104, 0xA5200, 0xA7004, 0xA7200, 0xA7504, 0xA7600, 0xA8104, 0xA8308,
+    0xA8400, 0xABC04, 0xABD00, 0xABE08, 0xAC104, 0xAC600, 0xAC704, 0xAC908,
+    0xACA00, 0xACB08, 0xACD04, 0xACE00, 0xAE204, 0xAE400, 0xAFA04, 0xB0000,
+    0xB0104, 0xB0208, 0xB0400, 0xB3C04, 0xB3D00, 0xB3E04, 0xB4008, 0xB4104,
+    0xB4500, 0xB4708, 0xB4900, 0xB4B08, 0xB4D04, 0xB4E00, 0xB5504, 0xB5800,
+    0xB6204, 0xB6400, 0xB8204, 0xB8300, 0xBBE04, 0xBBF08, 0xBC004, 0xBC108,
+    0xBC300, 0xBC608, 0xBC900, 0xBCA08, 0xBCD04, 0xBCE00, 0xBD704, 0xBD800,
+    0xC0004, 0xC0108, 0xC0404, 0xC0500, 0xC3C04, 0xC3D00, 0xC3E04, 0xC4108,
+    0xC4500, 0xC4604, 0xC4900, 0xC4A04, 0xC4E00, 0xC5504, 0xC5700, 0xC6204,
+    0xC6400, 0xC8104, 0xC8208, 0xC8400, 0xCBC04, 0xCBD00, 0xCBE08, 0xCBF04,
+    0xCC008, 0xCC204, 0xCC308, 0xCC500, 0xCC604, 0xCC708, 0xCC900, 0xCCA08,
+    0xCCC04, 0xCCE00, 0xCD504, 0xCD700, 0xCE204, 0xCE400, 0xD0004, 0xD0208,
+    0xD0400, 0xD3B04, 0xD3D00, 0xD3E04, 0xD3F08, 0xD4104, 0xD4500, 0xD4608,
+    0xD4900, 0xD4A08, 0xD4D04, 0xD4E07, 0xD4F00, 0xD5704, 0xD5800, 0xD6204,
+    0xD6400, 0xD8104, 0xD8208, 0xD8400, 0xDCA04, 0xDCB00, 0xDCF04, 0xDD008,
+    0xDD204, 0xDD500, 0xDD604, 0xDD700, 0xDD808, 0xDDF04, 0xDE000, 0xDF208,
+    0xDF400, 0xE3104, 0xE3200, 0xE3308, 0xE3404, 0xE3B00, 0xE4704, 0xE4F00,
+    0xEB104, 0xEB200, 0xEB308, 0xEB404, 0xEBD00, 0xEC804, 0xECE00, 0xF1804,
+    0xF1A00, 0xF3504, 0xF3600, 0xF3704, 0xF3800, 0xF3904, 0xF3A00, 0xF3E08,
+    0xF4000, 0xF7104, 0xF7F08, 0xF8004, 0xF8500, 0xF8604, 0xF8800, 0xF8D04,
+    0xF9800, 0xF9904, 0xFBD00, 0xFC604, 0xFC700, 0x102D04, 0x103108, 0x103204,
+    0x103800, 0x103904, 0x103B08, 0x103D04, 0x103F00, 0x105608, 0x105804, 0x105A00,
+    0x105E04, 0x106100, 0x107104, 0x107500, 0x108204, 0x108300, 0x108408, 0x108504,
+    0x108700, 0x108D04, 0x108E00, 0x109D04, 0x109E00, 0x110009, 0x11600A, 0x11A80B,
+    0x120000, 0x135D04, 0x136000, 0x171204, 0x171508, 0x171600, 0x173204, 0x173408,
+    0x173500, 0x175204, 0x175400, 0x177204, 0x177400, 0x17B404, 0x17B608, 0x17B704,
+    0x17BE08, 0x17C604, 0x17C708, 0x17C904, 0x17D400, 0x17DD04, 0x17DE00, 0x180B04,
+    0x180E03, 0x180F04, 0x181000, 0x188504, 0x188700, 0x18A904, 0x18AA00, 0x192004,
+    0x192308, 0x192704, 0x192908, 0x192C00, 0x193008, 0x193204, 0x193308, 0x193904,
+    0x193C00, 0x1A1704, 0x1A1908, 0x1A1B04, 0x1A1C00, 0x1A5508, 0x1A5604, 0x1A5708,
+    0x1A5804, 0x1A5F00, 0x1A6004, 0x1A6100, 0x1A6204, 0x1A6300, 0x1A6504, 0x1A6D08,
+    0x1A7304, 0x1A7D00, 0x1A7F04, 0x1A8000, 0x1AB004, 0x1ACF00, 0x1B0004, 0x1B0408,
+    0x1B0500, 0x1B3404, 0x1B3B08, 0x1B3C04, 0x1B3D08, 0x1B4204, 0x1B4308, 0x1B4500,
+    0x1B6B04, 0x1B7400, 0x1B8004, 0x1B8208, 0x1B8300, 0x1BA108, 0x1BA204, 0x1BA608,
+    0x1BA804, 0x1BAA08, 0x1BAB04, 0x1BAE00, 0x1BE604, 0x1BE708, 0x1BE804, 0x1BEA08,
+    0x1BED04, 0x1BEE08, 0x1BEF04, 0x1BF208, 0x1BF400, 0x1C2408, 0x1C2C04, 0x1C3408,
+    0x1C3604, 0x1C3800, 0x1CD004, 0x1CD300, 0x1CD404, 0x1CE108, 0x1CE204, 0x1CE900,
+    0x1CED04, 0x1CEE00, 0x1CF404, 0x1CF500, 0x1CF708, 0x1CF804, 0x1CFA00, 0x1DC004,
+    0x1E0000, 0x200B03, 0x200C04, 0x200D05, 0x200E03, 0x201000, 0x202803, 0x202F00,
+    0x206003, 0x207000, 0x20D004, 0x20F100, 0x2CEF04, 0x2CF200, 0x2D7F04, 0x2D8000,
+    0x2DE004, 0x2E0000, 0x302A04, 0x303000, 0x309904, 0x309B00, 0xA66F04, 0xA67300,
+    0xA67404, 0xA67E00, 0xA69E04, 0xA6A000, 0xA6F004, 0xA6F200, 0xA80204, 0xA80300,
+    0xA80604, 0xA80700, 0xA80B04, 0xA80C00, 0xA82308, 0xA82504, 0xA82708, 0xA82800,
+    0xA82C04, 0xA82D00, 0xA88008, 0xA88200, 0xA8B408, 0xA8C404, 0xA8C600, 0xA8E004,
+    0xA8F200, 0xA8FF04, 0xA90000, 0xA92604, 0xA92E00, 0xA94704, 0xA95208, 0xA95400,
+    0xA96009, 0xA97D00, 0xA98004, 0xA98308, 0xA98400, 0xA9B304, 0xA9B408, 0xA9B604,
+    0xA9BA08, 0xA9BC04, 0xA9BE08, 0xA9C100, 0xA9E504, 0xA9E600, 0xAA2904, 0xAA2F08,
+    0xAA3104, 0xAA3308, 0xAA3504, 0xAA3700, 0xAA4304, 0xAA4400, 0xAA4C04, 0xAA4D08,
+    0xAA4E00, 0xAA7C04, 0xAA7D00, 0xAAB004, 0xAAB100, 0xAAB204, 0xAAB500, 0xAAB704,
+    0xAAB900, 0xAABE04, 0xAAC000, 0xAAC104, 0xAAC200, 0xAAEB08, 0xAAEC04, 0xAAEE08,
+    0xAAF000, 0xAAF508, 0xAAF604, 0xAAF700, 0xABE308, 0xABE504, 0xABE608, 0xABE804,
+    0xABE908, 0xABEB00, 0xABEC08, 0xABED04, 0xABEE00, 0xAC000C, 0xAC010D, 0xAC1C0C,
+    0xAC1D0D, 0xAC380C, 0xAC390D, 0xAC540C, 0xAC550D, 0xAC700C, 0xAC710D, 0xAC8C0C,
+    0xAC8D0D, 0xACA80C, 0xACA90D, 0xACC40C, 0xACC50D, 0xACE00C, 0xACE10D, 0xACFC0C,
+    0xACFD0D, 0xAD180C, 0xAD190D, 0xAD340C, 0xAD350D, 0xAD500C, 0xAD510D, 0xAD6C0C,
+    0xAD6D0D, 0xAD880C, 0xAD890D, 0xADA40C, 0xADA50D, 0xADC00C, 0xADC10D, 0xADDC0C,
+    0xADDD0D, 0xADF80C, 0xADF90D, 0xAE140C, 0xAE150D, 0xAE300C, 0xAE310D, 0xAE4C0C,
+    0xAE4D0D, 0xAE680C, 0xAE690D, 0xAE840C, 0xAE850D, 0xAEA00C, 0xAEA10D, 0xAEBC0C,
+    0xAEBD0D, 0xAED80C, 0xAED90D, 0xAEF40C, 0xAEF50D, 0xAF100C, 0xAF110D, 0xAF2C0C,
+    0xAF2D0D, 0xAF480C, 0xAF490D, 0xAF640C, 0xAF650D, 0xAF800C, 0xAF810D, 0xAF9C0C,
+    0xAF9D0D, 0xAFB80C, 0xAFB90D, 0xAFD40C, 0xAFD50D, 0xAFF00C, 0xAFF10D, 0xB00C0C,
+    0xB00D0D, 0xB0280C, 0xB0290D, 0xB0440C, 0xB0450D, 0xB0600C, 0xB0610D, 0xB07C0C,
+    0xB07D0D, 0xB0980C, 0xB0990D, 0xB0B40C, 0xB0B50D, 0xB0D00C, 0xB0D10D, 0xB0EC0C,
+    0xB0ED0D, 0xB1080C, 0xB1090D, 0xB1240C, 0xB1250D, 0xB1400C, 0xB1410D, 0xB15C0C,
+    0xB15D0D, 0xB1780C, 0xB1790D, 0xB1940C, 0xB1950D, 0xB1B00C, 0xB1B10D, 0xB1CC0C,
+    0xB1CD0D, 0xB1E80C, 0xB1E90D, 0xB2040C, 0xB2050D, 0xB2200C, 0xB2210D, 0xB23C0C,
+    0xB23D0D, 0xB2580C, 0xB2590D, 0xB2740C, 0xB2750D, 0xB2900C, 0xB2910D, 0xB2AC0C,
+    0xB2AD0D, 0xB2C80C, 0xB2C90D, 0xB2E40C, 0xB2E50D, 0xB3000C, 0xB3010D, 0xB31C0C,
+    0xB31D0D, 0xB3380C, 0xB3390D, 0xB3540C, 0xB3550D, 0xB3700C, 0xB3710D, 0xB38C0C,
+    0xB38D0D, 0xB3A80C, 0xB3A90D, 0xB3C40C, 0xB3C50D, 0xB3E00C, 0xB3E10D, 0xB3FC0C,
+    0xB3FD0D, 0xB4180C, 0xB4190D, 0xB4340C, 0xB4350D, 0xB4500C, 0xB4510D, 0xB46C0C,
+    0xB46D0D, 0xB4880C, 0xB4890D, 0xB4A40C, 0xB4A50D, 0xB4C00C, 0xB4C10D, 0xB4DC0C,
+    0xB4DD0D, 0xB4F80C, 0xB4F90D, 0xB5140C, 0xB5150D, 0xB5300C, 0xB5310D, 0xB54C0C,
+    0xB54D0D, 0xB5680C, 0xB5690D, 0xB5840C, 0xB5850D, 0xB5A00C, 0xB5A10D, 0xB5BC0C,
+    0xB5BD0D, 0xB5D80C, 0xB5D90D, 0xB5F40C, 0xB5F50D, 0xB6100C, 0xB6110D, 0xB62C0C,
+    0xB62D0D, 0xB6480C, 0xB6490D, 0xB6640C, 0xB6650D, 0xB6800C, 0xB6810D, 0xB69C0C,
+    0xB69D0D, 0xB6B80C, 0xB6B90D, 0xB6D40C, 0xB6D50D, 0xB6F00C, 0xB6F10D, 0xB70C0C,
+    0xB70D0D, 0xB7280C, 0xB7290D, 0xB7440C, 0xB7450D, 0xB7600C, 0xB7610D, 0xB77C0C,
+    0xB77D0D, 0xB7980C, 0xB7990D, 0xB7B40C, 0xB7B50D, 0xB7D00C, 0xB7D10D, 0xB7EC0C,
+    0xB7ED0D, 0xB8080C, 0xB8090D, 0xB8240C, 0xB8250D, 0xB8400C, 0xB8410D, 0xB85C0C,
+    0xB85D0D, 0xB8780C, 0xB8790D, 0xB8940C, 0xB8950D, 0xB8B00C, 0xB8B10D, 0xB8CC0C,
+    0xB8CD0D, 0xB8E80C, 0xB8E90D, 0xB9040C, 0xB9050D, 0xB9200C, 0xB9210D, 0xB93C0C,
+    0xB93D0D, 0xB9580C, 0xB9590D, 0xB9740C, 0xB9750D, 0xB9900C, 0xB9910D, 0xB9AC0C,
+    0xB9AD0D, 0xB9C80C, 0xB9C90D, 0xB9E40C, 0xB9E50D, 0xBA000C, 0xBA010D, 0xBA1C0C,
+    0xBA1D0D, 0xBA380C, 0xBA390D, 0xBA540C, 0xBA550D, 0xBA700C, 0xBA710D, 0xBA8C0C,
+    0xBA8D0D, 0xBAA80C, 0xBAA90D, 0xBAC40C, 0xBAC50D, 0xBAE00C, 0xBAE10D, 0xBAFC0C,
+    0xBAFD0D, 0xBB180C, 0xBB190D, 0xBB340C, 0xBB350D, 0xBB500C, 0xBB510D, 0xBB6C0C,
+    0xBB6D0D, 0xBB880C, 0xBB890D, 0xBBA40C, 0xBBA50D, 0xBBC00C, 0xBBC10D, 0xBBDC0C,
+    0xBBDD0D, 0xBBF80C, 0xBBF90D, 0xBC140C, 0xBC150D, 0xBC300C, 0xBC310D, 0xBC4C0C,
+    0xBC4D0D, 0xBC680C, 0xBC690D, 0xBC840C, 0xBC850D, 0xBCA00C, 0xBCA10D, 0xBCBC0C,
+    0xBCBD0D, 0xBCD80C, 0xBCD90D, 0xBCF40C, 0xBCF50D, 0xBD100C, 0xBD110D, 0xBD2C0C,
+    0xBD2D0D, 0xBD480C, 0xBD490D, 0xBD640C, 0xBD650D, 0xBD800C, 0xBD810D, 0xBD9C0C,
+    0xBD9D0D, 0xBDB80C, 0xBDB90D, 0xBDD40C, 0xBDD50D, 0xBDF00C, 0xBDF10D, 0xBE0C0C,
+    0xBE0D0D, 0xBE280C, 0xBE290D, 0xBE440C, 0xBE450D, 0xBE600C, 0xBE610D, 0xBE7C0C,
+    0xBE7D0D, 0xBE980C, 0xBE990D, 0xBEB40C, 0xBEB50D, 0xBED00C, 0xBED10D, 0xBEEC0C,
+    0xBEED0D, 0xBF080C, 0xBF090D, 0xBF240C, 0xBF250D, 0xBF400C, 0xBF410D, 0xBF5C0C,
+    0xBF5D0D, 0xBF780C, 0xBF790D, 0xBF940C, 0xBF950D, 0xBFB00C, 0xBFB10D, 0xBFCC0C,
+    0xBFCD0D, 0xBFE80C, 0xBFE90D, 0xC0040C, 0xC0050D, 0xC0200C, 0xC0210D, 0xC03C0C,
+    0xC03D0D, 0xC0580C, 0xC0590D, 0xC0740C, 0xC0750D, 0xC0900C, 0xC0910D, 0xC0AC0C,
+    0xC0AD0D, 0xC0C80C, 0xC0C90D, 0xC0E40C, 0xC0E50D, 0xC1000C, 0xC1010D, 0xC11C0C,
+    0xC11D0D, 0xC1380C, 0xC1390D, 0xC1540C, 0xC1550D, 0xC1700C, 0xC1710D, 0xC18C0C,
+    0xC18D0D, 0xC1A80C, 0xC1A90D, 0xC1C40C, 0xC1C50D, 0xC1E00C, 0xC1E10D, 0xC1FC0C,
+    0xC1FD0D, 0xC2180C, 0xC2190D, 0xC2340C, 0xC2350D, 0xC2500C, 0xC2510D, 0xC26C0C,
+    0xC26D0D, 0xC2880C, 0xC2890D, 0xC2A40C, 0xC2A50D, 0xC2C00C, 0xC2C10D, 0xC2DC0C,
+    0xC2DD0D, 0xC2F80C, 0xC2F90D, 0xC3140C, 0xC3150D, 0xC3300C, 0xC3310D, 0xC34C0C,
+    0xC34D0D, 0xC3680C, 0xC3690D, 0xC3840C, 0xC3850D, 0xC3A00C, 0xC3A10D, 0xC3BC0C,
+    0xC3BD0D, 0xC3D80C, 0xC3D90D, 0xC3F40C, 0xC3F50D, 0xC4100C, 0xC4110D, 0xC42C0C,
+    0xC42D0D, 0xC4480C, 0xC4490D, 0xC4640C, 0xC4650D, 0xC4800C, 0xC4810D, 0xC49C0C,
+    0xC49D0D, 0xC4B80C, 0xC4B90D, 0xC4D40C, 0xC4D50D, 0xC4F00C, 0xC4F10D, 0xC50C0C,
+    0xC50D0D, 0xC5280C, 0xC5290D, 0xC5440C, 0xC5450D, 0xC5600C, 0xC5610D, 0xC57C0C,
+    0xC57D0D, 0xC5980C, 0xC5990D, 0xC5B40C, 0xC5B50D, 0xC5D00C, 0xC5D10D, 0xC5EC0C,
+    0xC5ED0D, 0xC6080C, 0xC6090D, 0xC6240C, 0xC6250D, 0xC6400C, 0xC6410D, 0xC65C0C,
+    0xC65D0D, 0xC6780C, 0xC6790D, 0xC6940C, 0xC6950D, 0xC6B00C, 0xC6B10D, 0xC6CC0C,
+    0xC6CD0D, 0xC6E80C, 0xC6E90D, 0xC7040C, 0xC7050D, 0xC7200C, 0xC7210D, 0xC73C0C,
+    0xC73D0D, 0xC7580C, 0xC7590D, 0xC7740C, 0xC7750D, 0xC7900C, 0xC7910D, 0xC7AC0C,
+    0xC7AD0D, 0xC7C80C, 0xC7C90D, 0xC7E40C, 0xC7E50D, 0xC8000C, 0xC8010D, 0xC81C0C,
+    0xC81D0D, 0xC8380C, 0xC8390D, 0xC8540C, 0xC8550D, 0xC8700C, 0xC8710D, 0xC88C0C,
+    0xC88D0D, 0xC8A80C, 0xC8A90D, 0xC8C40C, 0xC8C50D, 0xC8E00C, 0xC8E10D, 0xC8FC0C,
+    0xC8FD0D, 0xC9180C, 0xC9190D, 0xC9340C, 0xC9350D, 0xC9500C, 0xC9510D, 0xC96C0C,
+    0xC96D0D, 0xC9880C, 0xC9890D, 0xC9A40C, 0xC9A50D, 0xC9C00C, 0xC9C10D, 0xC9DC0C,
+    0xC9DD0D, 0xC9F80C, 0xC9F90D, 0xCA140C, 0xCA150D, 0xCA300C, 0xCA310D, 0xCA4C0C,
+    0xCA4D0D, 0xCA680C, 0xCA690D, 0xCA840C, 0xCA850D, 0xCAA00C, 0xCAA10D, 0xCABC0C,
+    0xCABD0D, 0xCAD80C, 0xCAD90D, 0xCAF40C, 0xCAF50D, 0xCB100C, 0xCB110D, 0xCB2C0C,
+    0xCB2D0D, 0xCB480C, 0xCB490D, 0xCB640C, 0xCB650D, 0xCB800C, 0xCB810D, 0xCB9C0C,
+    0xCB9D0D, 0xCBB80C, 0xCBB90D, 0xCBD40C, 0xCBD50D, 0xCBF00C, 0xCBF10D, 0xCC0C0C,
+    0xCC0D0D, 0xCC280C, 0xCC290D, 0xCC440C, 0xCC450D, 0xCC600C, 0xCC610D, 0xCC7C0C,
+    0xCC7D0D, 0xCC980C, 0xCC990D, 0xCCB40C, 0xCCB50D, 0xCCD00C, 0xCCD10D, 0xCCEC0C,
+    0xCCED0D, 0xCD080C, 0xCD090D, 0xCD240C, 0xCD250D, 0xCD400C, 0xCD410D, 0xCD5C0C,
+    0xCD5D0D, 0xCD780C, 0xCD790D, 0xCD940C, 0xCD950D, 0xCDB00C, 0xCDB10D, 0xCDCC0C,
+    0xCDCD0D, 0xCDE80C, 0xCDE90D, 0xCE040C, 0xCE050D, 0xCE200C, 0xCE210D, 0xCE3C0C,
+    0xCE3D0D, 0xCE580C, 0xCE590D, 0xCE740C, 0xCE750D, 0xCE900C, 0xCE910D, 0xCEAC0C,
+    0xCEAD0D, 0xCEC80C, 0xCEC90D, 0xCEE40C, 0xCEE50D, 0xCF000C, 0xCF010D, 0xCF1C0C,
+    0xCF1D0D, 0xCF380C, 0xCF390D, 0xCF540C, 0xCF550D, 0xCF700C, 0xCF710D, 0xCF8C0C,
+    0xCF8D0D, 0xCFA80C, 0xCFA90D, 0xCFC40C, 0xCFC50D, 0xCFE00C, 0xCFE10D, 0xCFFC0C,
+    0xCFFD0D, 0xD0180C, 0xD0190D, 0xD0340C, 0xD0350D, 0xD0500C, 0xD0510D, 0xD06C0C,
+    0xD06D0D, 0xD0880C, 0xD0890D, 0xD0A40C, 0xD0A50D, 0xD0C00C, 0xD0C10D, 0xD0DC0C,
+    0xD0DD0D, 0xD0F80C, 0xD0F90D, 0xD1140C, 0xD1150D, 0xD1300C, 0xD1310D, 0xD14C0C,
+    0xD14D0D, 0xD1680C, 0xD1690D, 0xD1840C, 0xD1850D, 0xD1A00C, 0xD1A10D, 0xD1BC0C,
+    0xD1BD0D, 0xD1D80C, 0xD1D90D, 0xD1F40C, 0xD1F50D, 0xD2100C, 0xD2110D, 0xD22C0C,
+    0xD22D0D, 0xD2480C, 0xD2490D, 0xD2640C, 0xD2650D, 0xD2800C, 0xD2810D, 0xD29C0C,
+    0xD29D0D, 0xD2B80C, 0xD2B90D, 0xD2D40C, 0xD2D50D, 0xD2F00C, 0xD2F10D, 0xD30C0C,
+    0xD30D0D, 0xD3280C, 0xD3290D, 0xD3440C, 0xD3450D, 0xD3600C, 0xD3610D, 0xD37C0C,
+    0xD37D0D, 0xD3980C, 0xD3990D, 0xD3B40C, 0xD3B50D, 0xD3D00C, 0xD3D10D, 0xD3EC0C,
+    0xD3ED0D, 0xD4080C, 0xD4090D, 0xD4240C, 0xD4250D, 0xD4400C, 0xD4410D, 0xD45C0C,
+    0xD45D0D, 0xD4780C, 0xD4790D, 0xD4940C, 0xD4950D, 0xD4B00C, 0xD4B10D, 0xD4CC0C,
+    0xD4CD0D, 0xD4E80C, 0xD4E90D, 0xD5040C, 0xD5050D, 0xD5200C, 0xD5210D, 0xD53C0C,
+    0xD53D0D, 0xD5580C, 0xD5590D, 0xD5740C, 0xD5750D, 0xD5900C, 0xD5910D, 0xD5AC0C,
+    0xD5AD0D, 0xD5C80C, 0xD5C90D, 0xD5E40C, 0xD5E50D, 0xD6000C, 0xD6010D, 0xD61C0C,
+    0xD61D0D, 0xD6380C, 0xD6390D, 0xD6540C, 0xD6550D, 0xD6700C, 0xD6710D, 0xD68C0C,
+    0xD68D0D, 0xD6A80C, 0xD6A90D, 0xD6C40C, 0xD6C50D, 0xD6E00C, 0xD6E10D, 0xD6FC0C,
+    0xD6FD0D, 0xD7180C, 0xD7190D, 0xD7340C, 0xD7350D, 0xD7500C, 0xD7510D, 0xD76C0C,
+    0xD76D0D, 0xD7880C, 0xD7890D, 0xD7A400, 0xD7B00A, 0xD7C700, 0xD7CB0B, 0xD7FC00,
+    0xFB1E04, 0xFB1F00, 0xFE0004, 0xFE1000, 0xFE2004, 0xFE3000, 0xFEFF03, 0xFF0000,
+    0xFF9E04, 0xFFA000, 0xFFF003, 0xFFFC00, 0x101FD04, 0x101FE00, 0x102E004, 0x102E100,
+    0x1037604, 0x1037B00, 0x10A0104, 0x10A0400, 0x10A0504, 0x10A0700, 0x10A0C04, 0x10A1000,
+    0x10A3804, 0x10A3B00, 0x10A3F04, 0x10A4000, 0x10AE504, 0x10AE700, 0x10D2404, 0x10D2800,
+    0x10EAB04, 0x10EAD00, 0x10F4604, 0x10F5100, 0x10F8204, 0x10F8600, 0x1100008, 0x1100104,
+    0x1100208, 0x1100300, 0x1103804, 0x1104700, 0x1107004, 0x1107100, 0x1107304, 0x1107500,
+    0x1107F04, 0x1108208, 0x1108300, 0x110B008, 0x110B304, 0x110B708, 0x110B904, 0x110BB00,
+    0x110BD07, 0x110BE00, 0x110C204, 0x110C300, 0x110CD07, 0x110CE00, 0x1110004, 0x1110300,
+    0x1112704, 0x1112C08, 0x1112D04, 0x1113500, 0x1114508, 0x1114700, 0x1117304, 0x1117400,
+    0x1118004, 0x1118208, 0x1118300, 0x111B308, 0x111B604, 0x111BF08, 0x111C100, 0x111C207,
+    0x111C400, 0x111C904, 0x111CD00, 0x111CE08, 0x111CF04, 0x111D000, 0x1122C08, 0x1122F04,
+    0x1123208, 0x1123404, 0x1123508, 0x1123604, 0x1123800, 0x1123E04, 0x1123F00, 0x112DF04,
+    0x112E008, 0x112E304, 0x112EB00, 0x1130004, 0x1130208, 0x1130400, 0x1133B04, 0x1133D00,
+    0x1133E04, 0x1133F08, 0x1134004, 0x1134108, 0x1134500, 0x1134708, 0x1134900, 0x1134B08,
+    0x1134E00, 0x1135704, 0x1135800, 0x1136208, 0x1136400, 0x1136604, 0x1136D00, 0x1137004,
+    0x1137500, 0x1143508, 0x1143804, 0x1144008, 0x1144204, 0x1144508, 0x1144604, 0x1144700,
+    0x1145E04, 0x1145F00, 0x114B004, 0x114B108, 0x114B304, 0x114B908, 0x114BA04, 0x114BB08,
+    0x114BD04, 0x114BE08, 0x114BF04, 0x114C108, 0x114C204, 0x114C400, 0x115AF04, 0x115B008,
+    0x115B204, 0x115B600, 0x115B808, 0x115BC04, 0x115BE08, 0x115BF04, 0x115C100, 0x115DC04,
+    0x115DE00, 0x1163008, 0x1163304, 0x1163B08, 0x1163D04, 0x1163E08, 0x1163F04, 0x1164100,
+    0x116AB04, 0x116AC08, 0x116AD04, 0x116AE08, 0x116B004, 0x116B608, 0x116B704, 0x116B800,
+    0x1171D04, 0x1172000, 0x1172204, 0x1172608, 0x1172704, 0x1172C00, 0x1182C08, 0x1182F04,
+    0x1183808, 0x1183904, 0x1183B00, 0x1193004, 0x1193108, 0x1193600, 0x1193708, 0x1193900,
+    0x1193B04, 0x1193D08, 0x1193E04, 0x1193F07, 0x1194008, 0x1194107, 0x1194208, 0x1194304,
+    0x1194400, 0x119D108, 0x119D404, 0x119D800, 0x119DA04, 0x119DC08, 0x119E004, 0x119E100,
+    0x119E408, 0x119E500, 0x11A0104, 0x11A0B00, 0x11A3304, 0x11A3908, 0x11A3A07, 0x11A3B04,
+    0x11A3F00, 0x11A4704, 0x11A4800, 0x11A5104, 0x11A5708, 0x11A5904, 0x11A5C00, 0x11A8407,
+    0x11A8A04, 0x11A9708, 0x11A9804, 0x11A9A00, 0x11C2F08, 0x11C3004, 0x11C3700, 0x11C3804,
+    0x11C3E08, 0x11C3F04, 0x11C4000, 0x11C9204, 0x11CA800, 0x11CA908, 0x11CAA04, 0x11CB108,
+    0x11CB204, 0x11CB408, 0x11CB504, 0x11CB700, 0x11D3104, 0x11D3700, 0x11D3A04, 0x11D3B00,
+    0x11D3C04, 0x11D3E00, 0x11D3F04, 0x11D4607, 0x11D4704, 0x11D4800, 0x11D8A08, 0x11D8F00,
+    0x11D9004, 0x11D9200, 0x11D9308, 0x11D9504, 0x11D9608, 0x11D9704, 0x11D9800, 0x11EF304,
+    0x11EF508, 0x11EF700, 0x1343003, 0x1343900, 0x16AF004, 0x16AF500, 0x16B3004, 0x16B3700,
+    0x16F4F04, 0x16F5000, 0x16F5108, 0x16F8800, 0x16F8F04, 0x16F9300, 0x16FE404, 0x16FE500,
+    0x16FF008, 0x16FF200, 0x1BC9D04, 0x1BC9F00, 0x1BCA003, 0x1BCA400, 0x1CF0004, 0x1CF2E00,
+    0x1CF3004, 0x1CF4700, 0x1D16504, 0x1D16608, 0x1D16704, 0x1D16A00, 0x1D16D08, 0x1D16E04,
+    0x1D17303, 0x1D17B04, 0x1D18300, 0x1D18504, 0x1D18C00, 0x1D1AA04, 0x1D1AE00, 0x1D24204,
+    0x1D24500, 0x1DA0004, 0x1DA3700, 0x1DA3B04, 0x1DA6D00, 0x1DA7504, 0x1DA7600, 0x1DA8404,
+    0x1DA8500, 0x1DA9B04, 0x1DAA000, 0x1DAA104, 0x1DAB000, 0x1E00004, 0x1E00700, 0x1E00804,
+    0x1E01900, 0x1E01B04, 0x1E02200, 0x1E02304, 0x1E02500, 0x1E02604, 0x1E02B00, 0x1E13004,
+    0x1E13700, 0x1E2AE04, 0x1E2AF00, 0x1E2EC04, 0x1E2F000, 0x1E8D004, 0x1E8D700, 0x1E94404,
+    0x1E94B00, 0x1F1E606, 0x1F20000, 0x1F3FB04, 0x1F40000, 0xE000003, 0xE002004, 0xE008003,
+    0xE010004, 0xE01F003, 0xE100000,
+];
+
+/// Word_Break property values (`WB_*`), as `first << 8 | value` for runs of code points with the same
+/// value starting at `first`. The first run starts at 0, a run lasts until the start of the next one.
+pub(super) static WORD_BREAKS: [u32; 1802] = [
+    0x0, 0xA02, 0xB03, 0xD01, 0xE00, 0x2012, 0x2100, 0x220C,
+    0x2300, 0x270B, 0x2800, 0x2C0F, 0x2D00, 0x2E0D, 0x2F00, 0x3010,
+    0x3A0E, 0x3B0F, 0x3C00, 0x410A, 0x5B00, 0x5F11, 0x6000, 0x610A,
+    0x7B00, 0x8503, 0x8600, 0xAA0A, 0xAB00, 0xAD07, 0xAE00, 0xB50A,
+    0xB600, 0xB70E, 0xB800, 0xBA0A, 0xBB00, 0xC00A, 0xD700, 0xD80A,
+    0xF700, 0xF80A, 0x2D800, 0x2DE0A, 0x30004, 0x3700A, 0x37500, 0x3760A,
+    0x37800, 0x37A0A, 0x37E0F, 0x37F0A, 0x38000, 0x3860A, 0x3870E, 0x3880A,
+    0x38B00, 0x38C0A, 0x38D00, 0x38E0A, 0x3A200, 0x3A30A, 0x3F600, 0x3F70A,
+    0x48200, 0x48304, 0x48A0A, 0x53000, 0x5310A, 0x55700, 0x5590A, 0x55D00,
+    0x55E0A, 0x55F0E, 0x5600A, 0x5890F, 0x58A0A, 0x58B00, 0x59104, 0x5BE00,
+    0x5BF04, 0x5C000, 0x5C104, 0x5C300, 0x5C404, 0x5C600, 0x5C704, 0x5C800,
+    0x5D009, 0x5EB00, 0x5EF09, 0x5F30A, 0x5F40E, 0x5F500, 0x60007, 0x60600,
+    0x60C0F, 0x60E00, 0x61004, 0x61B00, 0x61C07, 0x61D00, 0x6200A, 0x64B04,
+    0x66010, 0x66A00, 0x66B10, 0x66C0F, 0x66D00, 0x66E0A, 0x67004, 0x6710A,
+    0x6D400, 0x6D50A, 0x6D604, 0x6DD07, 0x6DE00, 0x6DF04, 0x6E50A, 0x6E704,
+    0x6E900, 0x6EA04, 0x6EE0A, 0x6F010, 0x6FA0A, 0x6FD00, 0x6FF0A, 0x70000,
+    0x70F07, 0x7100A, 0x71104, 0x7120A, 0x73004, 0x74B00, 0x74D0A, 0x7A604,
+    0x7B10A, 0x7B200, 0x7C010, 0x7CA0A, 0x7EB04, 0x7F40A, 0x7F600, 0x7F80F,
+    0x7F900, 0x7FA0A, 0x7FB00, 0x7FD04, 0x7FE00, 0x8000A, 0x81604, 0x81A0A,
+    0x81B04, 0x8240A, 0x82504, 0x8280A, 0x82904, 0x82E00, 0x8400A, 0x85904,
+    0x85C00, 0x8600A, 0x86B00, 0x8700A, 0x88800, 0x8890A, 0x88F00, 0x89007,
+    0x89200, 0x89804, 0x8A00A, 0x8CA04, 0x8E207, 0x8E304, 0x9040A, 0x93A04,
+    0x93D0A, 0x93E04, 0x9500A, 0x95104, 0x9580A, 0x96204, 0x96400, 0x96610,
+    0x97000, 0x9710A, 0x98104, 0x98400, 0x9850A, 0x98D00, 0x98F0A, 0x99100,
+    0x9930A, 0x9A900, 0x9AA0A, 0x9B100, 0x9B20A, 0x9B300, 0x9B60A, 0x9BA00,
+    0x9BC04, 0x9BD0A, 0x9BE04, 0x9C500, 0x9C704, 0x9C900, 0x9CB04, 0x9CE0A,
+    0x9CF00, 0x9D704, 0x9D800, 0x9DC0A, 0x9DE00, 0x9DF0A, 0x9E204, 0x9E400,
+    0x9E610, 0x9F00A, 0x9F200, 0x9FC0A, 0x9FD00, 0x9FE04, 0x9FF00, 0xA0104,
+    0xA0400, 0xA050A, 0xA0B00, 0xA0F0A, 0xA1100, 0xA130A, 0xA2900, 0xA2A0A,
+    0xA3100, 0xA320A, 0xA3400, 0xA350A, 0xA3700, 0xA380A, 0xA3A00, 0xA3C04,
+    0xA3D00, 0xA3E04, 0xA4300, 0xA4704, 0xA4900, 0xA4B04, 0xA4E00, 0xA5104,
+    0xA5200, 0xA590A, 0xA5D00, 0xA5E0A, 0xA5F00, 0xA6610, 0xA7004, 0xA720A,
+    0xA7504, 0xA7600, 0xA8104, 0xA8400, 0xA850A, 0xA8E00, 0xA8F0A, 0xA9200,
+    0xA930A, 0xAA900, 0xAAA0A, 0xAB100, 0xAB20A, 0xAB400, 0xAB50A, 0xABA00,
+    0xABC04, 0xABD0A, 0xABE04, 0xAC600, 0xAC704, 0xACA00, 0xACB04, 0xACE00,
+    0xAD00A, 0xAD100, 0xAE00A, 0xAE204, 0xAE400, 0xAE610, 0xAF000, 0xAF90A,
+    0xAFA04, 0xB0000, 0xB0104, 0xB0400, 0xB050A, 0xB0D00, 0xB0F0A, 0xB1100,
+    0xB130A, 0xB2900, 0xB2A0A, 0xB3100, 0xB320A, 0xB3400, 0xB350A, 0xB3A00,
+    0xB3C04, 0xB3D0A, 0xB3E04, 0xB4500, 0xB4704, 0xB4900, 0xB4B04, 0xB4E00,
+    0xB5504, 0xB5800, 0xB5C0A, 0xB5E00, 0xB5F0A, 0xB6204, 0xB6400, 0xB6610,
+    0xB7000, 0xB710A, 0xB7200, 0xB8204, 0xB830A, 0xB8400, 0xB850A, 0xB8B00,
+    0xB8E0A, 0xB9100, 0xB920A, 0xB9600, 0xB990A, 0xB9B00, 0xB9C0A, 0xB9D00,
+    0xB9E0A, 0xBA000, 0xBA30A, 0xBA500, 0xBA80A, 0xBAB00, 0xBAE0A, 0xBBA00,
+    0xBBE04, 0xBC300, 0xBC604, 0xBC900, 0xBCA04, 0xBCE00, 0xBD00A, 0xBD100,
+    0xBD704, 0xBD800, 0xBE610, 0xBF000, 0xC0004, 0xC050A, 0xC0D00, 0xC0E0A,
+    0xC1100, 0xC120A, 0xC2900, 0xC2A0A, 0xC3A00, 0xC3C04, 0xC3D0A, 0xC3E04,
+    0xC4500, 0xC4604, 0xC4900, 0xC4A04, 0xC4E00, 0xC5504, 0xC5700, 0xC580A,
+    0xC5B00, 0xC5D0A, 0xC5E00, 0xC600A, 0xC6204, 0xC6400, 0xC6610, 0xC7000,
+    0xC800A, 0xC8104, 0xC8400, 0xC850A, 0xC8D00, 0xC8E0A, 0xC9100, 0xC920A,
+    0xCA900, 0xCAA0A, 0xCB400, 0xCB50A, 0xCBA00, 0xCBC04, 0xCBD0A, 0xCBE04,
+    0xCC500, 0xCC604, 0xCC900, 0xCCA04, 0xCCE00, 0xCD504, 0xCD700, 0xCDD0A,
+    0xCDF00, 0xCE00A, 0xCE204, 0xCE400, 0xCE610, 0xCF000, 0xCF10A, 0xCF300,
+    0xD0004, 0xD040A, 0xD0D00, 0xD0E0A, 0xD1100, 0xD120A, 0xD3B04, 0xD3D0A,
+    0xD3E04, 0xD4500, 0xD4604, 0xD4900, 0xD4A04, 0xD4E0A, 0xD4F00, 0xD540A,
+    0xD5704, 0xD5800, 0xD5F0A, 0xD6204, 0xD6400, 0xD6610, 0xD7000, 0xD7A0A,
+    0xD8000, 0xD8104, 0xD8400, 0xD850A, 0xD9700, 0xD9A0A, 0xDB200, 0xDB30A,
+    0xDBC00, 0xDBD0A, 0xDBE00, 0xDC00A, 0xDC700, 0xDCA04, 0xDCB00, 0xDCF04,
+    0xDD500, 0xDD604, 0xDD700, 0xDD804, 0xDE000, 0xDE610, 0xDF000, 0xDF204,
+    0xDF400, 0xE3104, 0xE3200, 0xE3404, 0xE3B00, 0xE4704, 0xE4F00, 0xE5010,
+    0xE5A00, 0xEB104, 0xEB200, 0xEB404, 0xEBD00, 0xEC804, 0xECE00, 0xED010,
+    0xEDA00, 0xF000A, 0xF0100, 0xF1804, 0xF1A00, 0xF2010, 0xF2A00, 0xF3504,
+    0xF3600, 0xF3704, 0xF3800, 0xF3904, 0xF3A00, 0xF3E04, 0xF400A, 0xF4800,
+    0xF490A, 0xF6D00, 0xF7104, 0xF8500, 0xF8604, 0xF880A, 0xF8D04, 0xF9800,
+    0xF9904, 0xFBD00, 0xFC604, 0xFC700, 0x102B04, 0x103F00, 0x104010, 0x104A00,
+    0x105604, 0x105A00, 0x105E04, 0x106100, 0x106204, 0x106500, 0x106704, 0x106E00,
+    0x107104, 0x107500, 0x108204, 0x108E00, 0x108F04, 0x109010, 0x109A04, 0x109E00,
+    0x10A00A, 0x10C600, 0x10C70A, 0x10C800, 0x10CD0A, 0x10CE00, 0x10D00A, 0x10FB00,
+    0x10FC0A, 0x124900, 0x124A0A, 0x124E00, 0x12500A, 0x125700, 0x12580A, 0x125900,
+    0x125A0A, 0x125E00, 0x12600A, 0x128900, 0x128A0A, 0x128E00, 0x12900A, 0x12B100,
+    0x12B20A, 0x12B600, 0x12B80A, 0x12BF00, 0x12C00A, 0x12C100, 0x12C20A, 0x12C600,
+    0x12C80A, 0x12D700, 0x12D80A, 0x131100, 0x13120A, 0x131600, 0x13180A, 0x135B00,
+    0x135D04, 0x136000, 0x13800A, 0x139000, 0x13A00A, 0x13F600, 0x13F80A, 0x13FE00,
+    0x14010A, 0x166D00, 0x166F0A, 0x168012, 0x16810A, 0x169B00, 0x16A00A, 0x16EB00,
+    0x16EE0A, 0x16F900, 0x17000A, 0x171204, 0x171600, 0x171F0A, 0x173204, 0x173500,
+    0x17400A, 0x175204, 0x175400, 0x17600A, 0x176D00, 0x176E0A, 0x177100, 0x177204,
+    0x177400, 0x17B404, 0x17D400, 0x17DD04, 0x17DE00, 0x17E010, 0x17EA00, 0x180B04,
+    0x180E07, 0x180F04, 0x181010, 0x181A00, 0x18200A, 0x187900, 0x18800A, 0x188504,
+    0x18870A, 0x18A904, 0x18AA0A, 0x18AB00, 0x18B00A, 0x18F600, 0x19000A, 0x191F00,
+    0x192004, 0x192C00, 0x193004, 0x193C00, 0x194610, 0x195000, 0x19D010, 0x19DA00,
+    0x1A000A, 0x1A1704, 0x1A1C00, 0x1A5504, 0x1A5F00, 0x1A6004, 0x1A7D00, 0x1A7F04,
+    0x1A8010, 0x1A8A00, 0x1A9010, 0x1A9A00, 0x1AB004, 0x1ACF00, 0x1B0004, 0x1B050A,
+    0x1B3404, 0x1B450A, 0x1B4D00, 0x1B5010, 0x1B5A00, 0x1B6B04, 0x1B7400, 0x1B8004,
+    0x1B830A, 0x1BA104, 0x1BAE0A, 0x1BB010, 0x1BBA0A, 0x1BE604, 0x1BF400, 0x1C000A,
+    0x1C2404, 0x1C3800, 0x1C4010, 0x1C4A00, 0x1C4D0A, 0x1C5010, 0x1C5A0A, 0x1C7E00,
+    0x1C800A, 0x1C8900, 0x1C900A, 0x1CBB00, 0x1CBD0A, 0x1CC000, 0x1CD004, 0x1CD300,
+    0x1CD404, 0x1CE90A, 0x1CED04, 0x1CEE0A, 0x1CF404, 0x1CF50A, 0x1CF704, 0x1CFA0A,
+    0x1CFB00, 0x1D000A, 0x1DC004, 0x1E000A, 0x1F1600, 0x1F180A, 0x1F1E00, 0x1F200A,
+    0x1F4600, 0x1F480A, 0x1F4E00, 0x1F500A, 0x1F5800, 0x1F590A, 0x1F5A00, 0x1F5B0A,
+    0x1F5C00, 0x1F5D0A, 0x1F5E00, 0x1F5F0A, 0x1F7E00, 0x1F800A, 0x1FB500, 0x1FB60A,
+    0x1FBD00, 0x1FBE0A, 0x1FBF00, 0x1FC20A, 0x1FC500, 0x1FC60A, 0x1FCD00, 0x1FD00A,
+    0x1FD400, 0x1FD60A, 0x1FDC00, 0x1FE00A, 0x1FED00, 0x1FF20A, 0x1FF500, 0x1FF60A,
+    0x1FFD00, 0x200012, 0x200700, 0x200812, 0x200B00, 0x200C04, 0x200D05, 0x200E07,
+    0x201000, 0x20180D, 0x201A00, 0x20240D, 0x202500, 0x20270E, 0x202803, 0x202A07,
+    0x202F11, 0x203000, 0x203F11, 0x204100, 0x20440F, 0x204500, 0x205411, 0x205500,
+    0x205F12, 0x206007, 0x206500, 0x206607, 0x207000, 0x20710A, 0x207200, 0x207F0A,
+    0x208000, 0x20900A, 0x209D00, 0x20D004, 0x20F100, 0x21020A, 0x210300, 0x21070A,
+    0x210800, 0x210A0A, 0x211400, 0x21150A, 0x211600, 0x21190A, 0x211E00, 0x21240A,
+    0x212500, 0x21260A, 0x212700, 0x21280A, 0x212900, 0x212A0A, 0x212E00, 0x212F0A,
+    0x213A00, 0x213C0A, 0x214000, 0x21450A, 0x214A00, 0x214E0A, 0x214F00, 0x21600A,
+    0x218900, 0x24B60A, 0x24EA00, 0x2C000A, 0x2CE500, 0x2CEB0A, 0x2CEF04, 0x2CF20A,
+    0x2CF400, 0x2D000A, 0x2D2600, 0x2D270A, 0x2D2800, 0x2D2D0A, 0x2D2E00, 0x2D300A,
+    0x2D6800, 0x2D6F0A, 0x2D7000, 0x2D7F04, 0x2D800A, 0x2D9700, 0x2DA00A, 0x2DA700,
+    0x2DA80A, 0x2DAF00, 0x2DB00A, 0x2DB700, 0x2DB80A, 0x2DBF00, 0x2DC00A, 0x2DC700,
+    0x2DC80A, 0x2DCF00, 0x2DD00A, 0x2DD700, 0x2DD80A, 0x2DDF00, 0x2DE004, 0x2E0000,
+    0x2E2F0A, 0x2E3000, 0x300012, 0x300100, 0x30050A, 0x300600, 0x302A04, 0x303000,
+    0x303108, 0x303600, 0x303B0A, 0x303D00, 0x309904, 0x309B08, 0x309D00, 0x30A008,
+    0x30FB00, 0x30FC08, 0x310000, 0x31050A, 0x313000, 0x31310A, 0x318F00, 0x31A00A,
+    0x31C000, 0x31F008, 0x320000, 0x32D008, 0x32FF00, 0x330008, 0x335800, 0xA0000A,
+    0xA48D00, 0xA4D00A, 0xA4FE00, 0xA5000A, 0xA60D00, 0xA6100A, 0xA62010, 0xA62A0A,
+    0xA62C00, 0xA6400A, 0xA66F04, 0xA67300, 0xA67404, 0xA67E00, 0xA67F0A, 0xA69E04,
+    0xA6A00A, 0xA6F004, 0xA6F200, 0xA7080A, 0xA7CB00, 0xA7D00A, 0xA7D200, 0xA7D30A,
+    0xA7D400, 0xA7D50A, 0xA7DA00, 0xA7F20A, 0xA80204, 0xA8030A, 0xA80604, 0xA8070A,
+    0xA80B04, 0xA80C0A, 0xA82304, 0xA82800, 0xA82C04, 0xA82D00, 0xA8400A, 0xA87400,
+    0xA88004, 0xA8820A, 0xA8B404, 0xA8C600, 0xA8D010, 0xA8DA00, 0xA8E004, 0xA8F20A,
+    0xA8F800, 0xA8FB0A, 0xA8FC00, 0xA8FD0A, 0xA8FF04, 0xA90010, 0xA90A0A, 0xA92604,
+    0xA92E00, 0xA9300A, 0xA94704, 0xA95400, 0xA9600A, 0xA97D00, 0xA98004, 0xA9840A,
+    0xA9B304, 0xA9C100, 0xA9CF0A, 0xA9D010, 0xA9DA00, 0xA9E504, 0xA9E600, 0xA9F010,
+    0xA9FA00, 0xAA000A, 0xAA2904, 0xAA3700, 0xAA400A, 0xAA4304, 0xAA440A, 0xAA4C04,
+    0xAA4E00, 0xAA5010, 0xAA5A00, 0xAA7B04, 0xAA7E00, 0xAAB004, 0xAAB100, 0xAAB204,
+    0xAAB500, 0xAAB704, 0xAAB900, 0xAABE04, 0xAAC000, 0xAAC104, 0xAAC200, 0xAAE00A,
+    0xAAEB04, 0xAAF000, 0xAAF20A, 0xAAF504, 0xAAF700, 0xAB010A, 0xAB0700, 0xAB090A,
+    0xAB0F00, 0xAB110A, 0xAB1700, 0xAB200A, 0xAB2700, 0xAB280A, 0xAB2F00, 0xAB300A,
+    0xAB6A00, 0xAB700A, 0xABE304, 0xABEB00, 0xABEC04, 0xABEE00, 0xABF010, 0xABFA00,
+    0xAC000A, 0xD7A400, 0xD7B00A, 0xD7C700, 0xD7CB0A, 0xD7FC00, 0xFB000A, 0xFB0700,
+    0xFB130A, 0xFB1800, 0xFB1D09, 0xFB1E04, 0xFB1F09, 0xFB2900, 0xFB2A09, 0xFB3700,
+    0xFB3809, 0xFB3D00, 0xFB3E09, 0xFB3F00, 0xFB4009, 0xFB4200, 0xFB4309, 0xFB4500,
+    0xFB4609, 0xFB500A, 0xFBB200, 0xFBD30A, 0xFD3E00, 0xFD500A, 0xFD9000, 0xFD920A,
+    0xFDC800, 0xFDF00A, 0xFDFC00, 0xFE0004, 0xFE100F, 0xFE1100, 0xFE130E, 0xFE140F,
+    0xFE1500, 0xFE2004, 0xFE3000, 0xFE3311, 0xFE3500, 0xFE4D11, 0xFE500F, 0xFE5100,
+    0xFE520D, 0xFE5300, 0xFE540F, 0xFE550E, 0xFE5600, 0xFE700A, 0xFE7500, 0xFE760A,
+    0xFEFD00, 0xFEFF07, 0xFF0000, 0xFF070D, 0xFF0800, 0xFF0C0F, 0xFF0D00, 0xFF0E0D,
+    0xFF0F00, 0xFF1010, 0xFF1A0E, 0xFF1B0F, 0xFF1C00, 0xFF210A, 0xFF3B00, 0xFF3F11,
+    0xFF4000, 0xFF410A, 0xFF5B00, 0xFF6608, 0xFF9E04, 0xFFA00A, 0xFFBF00, 0xFFC20A,
+    0xFFC800, 0xFFCA0A, 0xFFD000, 0xFFD20A, 0xFFD800, 0xFFDA0A, 0xFFDD00, 0xFFF907,
+    0xFFFC00, 0x100000A, 0x1000C00, 0x1000D0A, 0x1002700, 0x100280A, 0x1003B00, 0x1003C0A,
+    0x1003E00, 0x1003F0A, 0x1004E00, 0x100500A, 0x1005E00, 0x100800A, 0x100FB00, 0x101400A,
+    0x1017500, 0x101FD04, 0x101FE00, 0x102800A, 0x1029D00, 0x102A00A, 0x102D100, 0x102E004,
+    0x102E100, 0x103000A, 0x1032000, 0x1032D0A, 0x1034B00, 0x103500A, 0x1037604, 0x1037B00,
+    0x103800A, 0x1039E00, 0x103A00A, 0x103C400, 0x103C80A, 0x103D000, 0x103D10A, 0x103D600,
+    0x104000A, 0x1049E00, 0x104A010, 0x104AA00, 0x104B00A, 0x104D400, 0x104D80A, 0x104FC00,
+    0x105000A, 0x1052800, 0x105300A, 0x1056400, 0x105700A, 0x1057B00, 0x1057C0A, 0x1058B00,
+    0x1058C0A, 0x1059300, 0x105940A, 0x1059600, 0x105970A, 0x105A200, 0x105A30A, 0x105B200,
+    0x105B30A, 0x105BA00, 0x105BB0A, 0x105BD00, 0x106000A, 0x1073700, 0x107400A, 0x1075600,
+    0x107600A, 0x1076800, 0x107800A, 0x1078600, 0x107870A, 0x107B100, 0x107B20A, 0x107BB00,
+    0x108000A, 0x1080600, 0x108080A, 0x1080900, 0x1080A0A, 0x1083600, 0x108370A, 0x1083900,
+    0x1083C0A, 0x1083D00, 0x1083F0A, 0x1085600, 0x108600A, 0x1087700, 0x108800A, 0x1089F00,
+    0x108E00A, 0x108F300, 0x108F40A, 0x108F600, 0x109000A, 0x1091600, 0x109200A, 0x1093A00,
+    0x109800A, 0x109B800, 0x109BE0A, 0x109C000, 0x10A000A, 0x10A0104, 0x10A0400, 0x10A0504,
+    0x10A0700, 0x10A0C04, 0x10A100A, 0x10A1400, 0x10A150A, 0x10A1800, 0x10A190A, 0x10A3600,
+    0x10A3804, 0x10A3B00, 0x10A3F04, 0x10A4000, 0x10A600A, 0x10A7D00, 0x10A800A, 0x10A9D00,
+    0x10AC00A, 0x10AC800, 0x10AC90A, 0x10AE504, 0x10AE700, 0x10B000A, 0x10B3600, 0x10B400A,
+    0x10B5600, 0x10B600A, 0x10B7300, 0x10B800A, 0x10B9200, 0x10C000A, 0x10C4900, 0x10C800A,
+    0x10CB300, 0x10CC00A, 0x10CF300, 0x10D000A, 0x10D2404, 0x10D2800, 0x10D3010, 0x10D3A00,
+    0x10E800A, 0x10EAA00, 0x10EAB04, 0x10EAD00, 0x10EB00A, 0x10EB200, 0x10F000A, 0x10F1D00,
+    0x10F270A, 0x10F2800, 0x10F300A, 0x10F4604, 0x10F5100, 0x10F700A, 0x10F8204, 0x10F8600,
+    0x10FB00A, 0x10FC500, 0x10FE00A, 0x10FF700, 0x1100004, 0x110030A, 0x1103804, 0x1104700,
+    0x1106610, 0x1107004, 0x110710A, 0x1107304, 0x110750A, 0x1107600, 0x1107F04, 0x110830A,
+    0x110B004, 0x110BB00, 0x110BD07, 0x110BE00, 0x110C204, 0x110C300, 0x110CD07, 0x110CE00,
+    0x110D00A, 0x110E900, 0x110F010, 0x110FA00, 0x1110004, 0x111030A, 0x1112704, 0x1113500,
+    0x1113610, 0x1114000, 0x111440A, 0x1114504, 0x111470A, 0x1114800, 0x111500A, 0x1117304,
+    0x1117400, 0x111760A, 0x1117700, 0x1118004, 0x111830A, 0x111B304, 0x111C10A, 0x111C500,
+    0x111C904, 0x111CD00, 0x111CE04, 0x111D010, 0x111DA0A, 0x111DB00, 0x111DC0A, 0x111DD00,
+    0x112000A, 0x1121200, 0x112130A, 0x1122C04, 0x1123800, 0x1123E04, 0x1123F00, 0x112800A,
+    0x1128700, 0x112880A, 0x1128900, 0x1128A0A, 0x1128E00, 0x1128F0A, 0x1129E00, 0x1129F0A,
+    0x112A900, 0x112B00A, 0x112DF04, 0x112EB00, 0x112F010, 0x112FA00, 0x1130004, 0x1130400,
+    0x113050A, 0x1130D00, 0x1130F0A, 0x1131100, 0x113130A, 0x1132900, 0x1132A0A, 0x1133100,
+    0x113320A, 0x1133400, 0x113350A, 0x1133A00, 0x1133B04, 0x1133D0A, 0x1133E04, 0x1134500,
+    0x1134704, 0x1134900, 0x1134B04, 0x1134E00, 0x113500A, 0x1135100, 0x1135704, 0x1135800,
+    0x1135D0A, 0x1136204, 0x1136400, 0x1136604, 0x1136D00, 0x1137004, 0x1137500, 0x114000A,
+    0x1143504, 0x114470A, 0x1144B00, 0x1145010, 0x1145A00, 0x1145E04, 0x1145F0A, 0x1146200,
+    0x114800A, 0x114B004, 0x114C40A, 0x114C600, 0x114C70A, 0x114C800, 0x114D010, 0x114DA00,
+    0x115800A, 0x115AF04, 0x115B600, 0x115B804, 0x115C100, 0x115D80A, 0x115DC04, 0x115DE00,
+    0x116000A, 0x1163004, 0x1164100, 0x116440A, 0x1164500, 0x1165010, 0x1165A00, 0x116800A,
+    0x116AB04, 0x116B80A, 0x116B900, 0x116C010, 0x116CA00, 0x1171D04, 0x1172C00, 0x1173010,
+    0x1173A00, 0x118000A, 0x1182C04, 0x1183B00, 0x118A00A, 0x118E010, 0x118EA00, 0x118FF0A,
+    0x1190700, 0x119090A, 0x1190A00, 0x1190C0A, 0x1191400, 0x119150A, 0x1191700, 0x119180A,
+    0x1193004, 0x1193600, 0x1193704, 0x1193900, 0x1193B04, 0x1193F0A, 0x1194004, 0x119410A,
+    0x1194204, 0x1194400, 0x1195010, 0x1195A00, 0x119A00A, 0x119A800, 0x119AA0A, 0x119D104,
+    0x119D800, 0x119DA04, 0x119E10A, 0x119E200, 0x119E30A, 0x119E404, 0x119E500, 0x11A000A,
+    0x11A0104, 0x11A0B0A, 0x11A3304, 0x11A3A0A, 0x11A3B04, 0x11A3F00, 0x11A4704, 0x11A4800,
+    0x11A500A, 0x11A5104, 0x11A5C0A, 0x11A8A04, 0x11A9A00, 0x11A9D0A, 0x11A9E00, 0x11AB00A,
+    0x11AF900, 0x11C000A, 0x11C0900, 0x11C0A0A, 0x11C2F04, 0x11C3700, 0x11C3804, 0x11C400A,
+    0x11C4100, 0x11C5010, 0x11C5A00, 0x11C720A, 0x11C9000, 0x11C9204, 0x11CA800, 0x11CA904,
+    0x11CB700, 0x11D000A, 0x11D0700, 0x11D080A, 0x11D0A00, 0x11D0B0A, 0x11D3104, 0x11D3700,
+    0x11D3A04, 0x11D3B00, 0x11D3C04, 0x11D3E00, 0x11D3F04, 0x11D460A, 0x11D4704, 0x11D4800,
+    0x11D5010, 0x11D5A00, 0x11D600A, 0x11D6600, 0x11D670A, 0x11D6900, 0x11D6A0A, 0x11D8A04,
+    0x11D8F00, 0x11D9004, 0x11D9200, 0x11D9304, 0x11D980A, 0x11D9900, 0x11DA010, 0x11DAA00,
+    0x11EE00A, 0x11EF304, 0x11EF700, 0x11FB00A, 0x11FB100, 0x120000A, 0x1239A00, 0x124000A,
+    0x1246F00, 0x124800A, 0x1254400, 0x12F900A, 0x12FF100, 0x130000A, 0x1342F00, 0x1343007,
+    0x1343900, 0x144000A, 0x1464700, 0x168000A, 0x16A3900, 0x16A400A, 0x16A5F00, 0x16A6010,
+    0x16A6A00, 0x16A700A, 0x16ABF00, 0x16AC010, 0x16ACA00, 0x16AD00A, 0x16AEE00, 0x16AF004,
+    0x16AF500, 0x16B000A, 0x16B3004, 0x16B3700, 0x16B400A, 0x16B4400, 0x16B5010, 0x16B5A00,
+    0x16B630A, 0x16B7800, 0x16B7D0A, 0x16B9000, 0x16E400A, 0x16E8000, 0x16F000A, 0x16F4B00,
+    0x16F4F04, 0x16F500A, 0x16F5104, 0x16F8800, 0x16F8F04, 0x16F930A, 0x16FA000, 0x16FE00A,
+    0x16FE200, 0x16FE30A, 0x16FE404, 0x16FE500, 0x16FF004, 0x16FF200, 0x1AFF008, 0x1AFF400,
+    0x1AFF508, 0x1AFFC00, 0x1AFFD08, 0x1AFFF00, 0x1B00008, 0x1B00100, 0x1B12008, 0x1B12300,
+    0x1B16408, 0x1B16800, 0x1BC000A, 0x1BC6B00, 0x1BC700A, 0x1BC7D00, 0x1BC800A, 0x1BC8900,
+    0x1BC900A, 0x1BC9A00, 0x1BC9D04, 0x1BC9F00, 0x1BCA007, 0x1BCA400, 0x1CF0004, 0x1CF2E00,
+    0x1CF3004, 0x1CF4700, 0x1D16504, 0x1D16A00, 0x1D16D04, 0x1D17307, 0x1D17B04, 0x1D18300,
+    0x1D18504, 0x1D18C00, 0x1D1AA04, 0x1D1AE00, 0x1D24204, 0x1D24500, 0x1D4000A, 0x1D45500,
+    0x1D4560A, 0x1D49D00, 0x1D49E0A, 0x1D4A000, 0x1D4A20A, 0x1D4A300, 0x1D4A50A, 0x1D4A700,
+    0x1D4A90A, 0x1D4AD00, 0x1D4AE0A, 0x1D4BA00, 0x1D4BB0A, 0x1D4BC00, 0x1D4BD0A, 0x1D4C400,
+    0x1D4C50A, 0x1D50600, 0x1D5070A, 0x1D50B00, 0x1D50D0A, 0x1D51500, 0x1D5160A, 0x1D51D00,
+    0x1D51E0A, 0x1D53A00, 0x1D53B0A, 0x1D53F00, 0x1D5400A, 0x1D54500, 0x1D5460A, 0x1D54700,
+    0x1D54A0A, 0x1D55100, 0x1D5520A, 0x1D6A600, 0x1D6A80A, 0x1D6C100, 0x1D6C20A, 0x1D6DB00,
+    0x1D6DC0A, 0x1D6FB00, 0x1D6FC0A, 0x1D71500, 0x1D7160A, 0x1D73500, 0x1D7360A, 0x1D74F00,
+    0x1D7500A, 0x1D76F00, 0x1D7700A, 0x1D78900, 0x1D78A0A, 0x1D7A900, 0x1D7AA0A, 0x1D7C300,
+    0x1D7C40A, 0x1D7CC00, 0x1D7CE10, 0x1D80000, 0x1DA0004, 0x1DA3700, 0x1DA3B04, 0x1DA6D00,
+    0x1DA7504, 0x1DA7600, 0x1DA8404, 0x1DA8500, 0x1DA9B04, 0x1DAA000, 0x1DAA104, 0x1DAB000,
+    0x1DF000A, 0x1DF1F00, 0x1E00004, 0x1E00700, 0x1E00804, 0x1E01900, 0x1E01B04, 0x1E02200,
+    0x1E02304, 0x1E02500, 0x1E02604, 0x1E02B00, 0x1E1000A, 0x1E12D00, 0x1E13004, 0x1E1370A,
+    0x1E13E00, 0x1E14010, 0x1E14A00, 0x1E14E0A, 0x1E14F00, 0x1E2900A, 0x1E2AE04, 0x1E2AF00,
+    0x1E2C00A, 0x1E2EC04, 0x1E2F010, 0x1E2FA00, 0x1E7E00A, 0x1E7E700, 0x1E7E80A, 0x1E7EC00,
+    0x1E7ED0A, 0x1E7EF00, 0x1E7F00A, 0x1E7FF00, 0x1E8000A, 0x1E8C500, 0x1E8D004, 0x1E8D700,
+    0x1E9000A, 0x1E94404, 0x1E94B0A, 0x1E94C00, 0x1E95010, 0x1E95A00, 0x1EE000A, 0x1EE0400,
+    0x1EE050A, 0x1EE2000, 0x1EE210A, 0x1EE2300, 0x1EE240A, 0x1EE2500, 0x1EE270A, 0x1EE2800,
+    0x1EE290A, 0x1EE3300, 0x1EE340A, 0x1EE3800, 0x1EE390A, 0x1EE3A00, 0x1EE3B0A, 0x1EE3C00,
+    0x1EE420A, 0x1EE4300, 0x1EE470A, 0x1EE4800, 0x1EE490A, 0x1EE4A00, 0x1EE4B0A, 0x1EE4C00,
+    0x1EE4D0A, 0x1EE5000, 0x1EE510A, 0x1EE5300, 0x1EE540A, 0x1EE5500, 0x1EE570A, 0x1EE5800,
+    0x1EE590A, 0x1EE5A00, 0x1EE5B0A, 0x1EE5C00, 0x1EE5D0A, 0x1EE5E00, 0x1EE5F0A, 0x1EE6000,
+    0x1EE610A, 0x1EE6300, 0x1EE640A, 0x1EE6500, 0x1EE670A, 0x1EE6B00, 0x1EE6C0A, 0x1EE7300,
+    0x1EE740A, 0x1EE7800, 0x1EE790A, 0x1EE7D00, 0x1EE7E0A, 0x1EE7F00, 0x1EE800A, 0x1EE8A00,
+    0x1EE8B0A, 0x1EE9C00, 0x1EEA10A, 0x1EEA400, 0x1EEA50A, 0x1EEAA00, 0x1EEAB0A, 0x1EEBC00,
+    0x1F1300A, 0x1F14A00, 0x1F1500A, 0x1F16A00, 0x1F1700A, 0x1F18A00, 0x1F1E606, 0x1F20000,
+    0x1F3FB04, 0x1F40000, 0x1FBF010, 0x1FBFA00, 0xE000107, 0xE000200, 0xE002004, 0xE008000,
+    0xE010004, 0xE01F000,
+];
+
+/// Extended_Pictographic characters, as `(first, last)` ranges
+pub(super) static EXTENDED_PICTOGRAPHIC: [(u32, u32); 78] = [
+    (0xA9, 0xA9), (0xAE, 0xAE), (0x203C, 0x203C), (0x2049, 0x2049),
+    (0x2122, 0x2122), (0x2139, 0x2139), (0x2194, 0x2199), (0x21A9, 0x21AA),
+    (0x231A, 0x231B), (0x2328, 0x2328), (0x2388, 0x2388), (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3), (0x23F8, 0x23FA), (0x24C2, 0x24C2), (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6), (0x25C0, 0x25C0), (0x25FB, 0x25FE), (0x2600, 0x2605),
+    (0x2607, 0x2612), (0x2614, 0x2685), (0x2690, 0x2705), (0x2708, 0x2712),
+    (0x2714, 0x2714), (0x2716, 0x2716), (0x271D, 0x271D), (0x2721, 0x2721),
+    (0x2728, 0x2728), (0x2733, 0x2734), (0x2744, 0x2744), (0x2747, 0x2747),
+    (0x274C, 0x274C), (0x274E, 0x274E), (0x2753, 0x2755), (0x2757, 0x2757),
+    (0x2763, 0x2767), (0x2795, 0x2797), (0x27A1, 0x27A1), (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF), (0x2934, 0x2935), (0x2B05, 0x2B07), (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50), (0x2B55, 0x2B55), (0x3030, 0x3030), (0x303D, 0x303D),
+    (0x3297, 0x3297), (0x3299, 0x3299), (0x1F000, 0x1F0FF), (0x1F10D, 0x1F10F),
+    (0x1F12F, 0x1F12F), (0x1F16C, 0x1F171), (0x1F17E, 0x1F17F), (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A), (0x1F1AD, 0x1F1E5), (0x1F201, 0x1F20F), (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F), (0x1F232, 0x1F23A), (0x1F23C, 0x1F23F), (0x1F249, 0x1F3FA),
+    (0x1F400, 0x1F53D), (0x1F546, 0x1F64F), (0x1F680, 0x1F6FF), (0x1F774, 0x1F77F),
+    (0x1F7D5, 0x1F7FF), (0x1F80C, 0x1F80F), (0x1F848, 0x1F84F), (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F), (0x1F8AE, 0x1F8FF), (0x1F90C, 0x1F93A), (0x1F93C, 0x1F945),
+    (0x1F947, 0x1FAFF), (0x1FC00, 0x1FFFD),
+];
+
+/// Alphabetic characters, as `(first, last)` ranges
+pub(super) static ALPHABETIC: [(u32, u32); 722] = [
+    (0x41, 0x5A), (0x61, 0x7A), (0xAA, 0xAA), (0xB5, 0xB5),
+    (0xBA, 0xBA), (0xC0, 0xD6), (0xD8, 0xF6), (0xF8, 0x2C1),
+    (0x2C6, 0x2D1), (0x2E0, 0x2E4), (0x2EC, 0x2EC), (0x2EE, 0x2EE),
+    (0x345, 0x345), (0x370, 0x374), (0x376, 0x377), (0x37A, 0x37D),
+    (0x37F, 0x37F), (0x386, 0x386), (0x388, 0x38A), (0x38C, 0x38C),
+    (0x38E, 0x3A1), (0x3A3, 0x3F5), (0x3F7, 0x481), (0x48A, 0x52F),
+    (0x531, 0x556), (0x559, 0x559), (0x560, 0x588), (0x5B0, 0x5BD),
+    (0x5BF, 0x5BF), (0x5C1, 0x5C2), (0x5C4, 0x5C5), (0x5C7, 0x5C7),
+    (0x5D0, 0x5EA), (0x5EF, 0x5F2), (0x610, 0x61A), (0x620, 0x657),
+    (0x659, 0x65F), (0x66E, 0x6D3), (0x6D5, 0x6DC), (0x6E1, 0x6E8),
+    (0x6ED, 0x6EF), (0x6FA, 0x6FC), (0x6FF, 0x6FF), (0x710, 0x73F),
+    (0x74D, 0x7B1), (0x7CA, 0x7EA), (0x7F4, 0x7F5), (0x7FA, 0x7FA),
+    (0x800, 0x817), (0x81A, 0x82C), (0x840, 0x858), (0x860, 0x86A),
+    (0x870, 0x887), (0x889, 0x88E), (0x8A0, 0x8C9), (0x8D4, 0x8DF),
+    (0x8E3, 0x8E9), (0x8F0, 0x93B), (0x93D, 0x94C), (0x94E, 0x950),
+    (0x955, 0x963), (0x971, 0x983), (0x985, 0x98C), (0x98F, 0x990),
+    (0x993, 0x9A8), (0x9AA, 0x9B0), (0x9B2, 0x9B2), (0x9B6, 0x9B9),
+    (0x9BD, 0x9C4), (0x9C7, 0x9C8), (0x9CB, 0x9CC), (0x9CE, 0x9CE),
+    (0x9D7, 0x9D7), (0x9DC, 0x9DD), (0x9DF, 0x9E3), (0x9F0, 0x9F1),
+    (0x9FC, 0x9FC), (0xA01, 0xA03), (0xA05, 0xA0A), (0xA0F, 0xA10),
+    (0xA13, 0xA28), (0xA2A, 0xA30), (0xA32, 0xA33), (0xA35, 0xA36),
+    (0xA38, 0xA39), (0xA3E, 0xA42), (0xA47, 0xA48), (0xA4B, 0xA4C),
+    (0xA51, 0xA51), (0xA59, 0xA5C), (0xA5E, 0xA5E), (0xA70, 0xA75),
+    (0xA81, 0xA83), (0xA85, 0xA8D), (0xA8F, 0xA91), (0xA93, 0xAA8),
+    (0xAAA, 0xAB0), (0xAB2, 0xAB3), (0xAB5, 0xAB9), (0xABD, 0xAC5),
+    (0xAC7, 0xAC9), (0xACB, 0xACC), (0xAD0, 0xAD0), (0xAE0, 0xAE3),
+    (0xAF9, 0xAFC), (0xB01, 0xB03), (0xB05, 0xB0C), (0xB0F, 0xB10),
+    (0xB13, 0xB28), (0xB2A, 0xB30), (0xB32, 0xB33), (0xB35, 0xB39),
+    (0xB3D, 0xB44), (0xB47, 0xB48), (0xB4B, 0xB4C), (0xB56, 0xB57),
+    (0xB5C, 0xB5D), (0xB5F, 0xB63), (0xB71, 0xB71), (0xB82, 0xB83),
+    (0xB85, 0xB8A), (0xB8E, 0xB90), (0xB92, 0xB95), (0xB99, 0xB9A),
+    (0xB9C, 0xB9C), (0xB9E, 0xB9F), (0xBA3, 0xBA4), (0xBA8, 0xBAA),
+    (0xBAE, 0xBB9), (0xBBE, 0xBC2), (0xBC6, 0xBC8), (0xBCA, 0xBCC),
+    (0xBD0, 0xBD0), (0xBD7, 0xBD7), (0xC00, 0xC03), (0xC05, 0xC0C),
+    (0xC0E, 0xC10), (0xC12, 0xC28), (0xC2A, 0xC39), (0xC3D, 0xC44),
+    (0xC46, 0xC48), (0xC4A, 0xC4C), (0xC55, 0xC56), (0xC58, 0xC5A),
+    (0xC5D, 0xC5D), (0xC60, 0xC63), (0xC80, 0xC83), (0xC85, 0xC8C),
+    (0xC8E, 0xC90), (0xC92, 0xCA8), (0xCAA, 0xCB3), (0xCB5, 0xCB9),
+    (0xCBD, 0xCC4), (0xCC6, 0xCC8), (0xCCA, 0xCCC), (0xCD5, 0xCD6),
+    (0xCDD, 0xCDE), (0xCE0, 0xCE3), (0xCF1, 0xCF2), (0xD00, 0xD0C),
+    (0xD0E, 0xD10), (0xD12, 0xD3A), (0xD3D, 0xD44), (0xD46, 0xD48),
+    (0xD4A, 0xD4C), (0xD4E, 0xD4E), (0xD54, 0xD57), (0xD5F, 0xD63),
+    (0xD7A, 0xD7F), (0xD81, 0xD83), (0xD85, 0xD96), (0xD9A, 0xDB1),
+    (0xDB3, 0xDBB), (0xDBD, 0xDBD), (0xDC0, 0xDC6), (0xDCF, 0xDD4),
+    (0xDD6, 0xDD6), (0xDD8, 0xDDF), (0xDF2, 0xDF3), (0xE01, 0xE3A),
+    (0xE40, 0xE46), (0xE4D, 0xE4D), (0xE81, 0xE82), (0xE84, 0xE84),
+    (0xE86, 0xE8A), (0xE8C, 0xEA3), (0xEA5, 0xEA5), (0xEA7, 0xEB9),
+    (0xEBB, 0xEBD), (0xEC0, 0xEC4), (0xEC6, 0xEC6), (0xECD, 0xECD),
+    (0xEDC, 0xEDF), (0xF00, 0xF00), (0xF40, 0xF47), (0xF49, 0xF6C),
+    (0xF71, 0xF81), (0xF88, 0xF97), (0xF99, 0xFBC), (0x1000, 0x1036),
+    (0x1038, 0x1038), (0x103B, 0x103F), (0x1050, 0x108F), (0x109A, 0x109D),
+    (0x10A0, 0x10C5), (0x10C7, 0x10C7), (0x10CD, 0x10CD), (0x10D0, 0x10FA),
+    (0x10FC, 0x1248), (0x124A, 0x124D), (0x1250, 0x1256), (0x1258, 0x1258),
+    (0x125A, 0x125D), (0x1260, 0x1288), (0x128A, 0x128D), (0x1290, 0x12B0),
+    (0x12B2, 0x12B5), (0x12B8, 0x12BE), (0x12C0, 0x12C0), (0x12C2, 0x12C5),
+    (0x12C8, 0x12D6), (0x12D8, 0x1310), (0x1312, 0x1315), (0x1318, 0x135A),
+    (0x1380, 0x138F), (0x13A0, 0x13F5), (0x13F8, 0x13FD), (0x1401, 0x166C),
+    (0x166F, 0x167F), (0x1681, 0x169A), (0x16A0, 0x16EA), (0x16EE, 0x16F8),
+    (0x1700, 0x1713), (0x171F, 0x1733), (0x1740, 0x1753), (0x1760, 0x176C),
+    (0x176E, 0x1770), (0x1772, 0x1773), (0x1780, 0x17B3), (0x17B6, 0x17C8),
+    (0x17D7, 0x17D7), (0x17DC, 0x17DC), (0x1820, 0x1878), (0x1880, 0x18AA),
+    (0x18B0, 0x18F5), (0x1900, 0x191E), (0x1920, 0x192B), (0x1930, 0x1938),
+    (0x1950, 0x196D), (0x1970, 0x1974), (0x1980, 0x19AB), (0x19B0, 0x19C9),
+    (0x1A00, 0x1A1B), (0x1A20, 0x1A5E), (0x1A61, 0x1A74), (0x1AA7, 0x1AA7),
+    (0x1ABF, 0x1AC0), (0x1ACC, 0x1ACE), (0x1B00, 0x1B33), (0x1B35, 0x1B43),
+    (0x1B45, 0x1B4C), (0x1B80, 0x1BA9), (0x1BAC, 0x1BAF), (0x1BBA, 0x1BE5),
+    (0x1BE7, 0x1BF1), (0x1C00, 0x1C36), (0x1C4D, 0x1C4F), (0x1C5A, 0x1C7D),
+    (0x1C80, 0x1C88), (0x1C90, 0x1CBA), (0x1CBD, 0x1CBF), (0x1CE9, 0x1CEC),
+    (0x1CEE, 0x1CF3), (0x1CF5, 0x1CF6), (0x1CFA, 0x1CFA), (0x1D00, 0x1DBF),
+    (0x1DE7, 0x1DF4), (0x1E00, 0x1F15), (0x1F18, 0x1F1D), (0x1F20, 0x1F45),
+    (0x1F48, 0x1F4D), (0x1F50, 0x1F57), (0x1F59, 0x1F59), (0x1F5B, 0x1F5B),
+    (0x1F5D, 0x1F5D), (0x1F5F, 0x1F7D), (0x1F80, 0x1FB4), (0x1FB6, 0x1FBC),
+    (0x1FBE, 0x1FBE), (0x1FC2, 0x1FC4), (0x1FC6, 0x1FCC), (0x1FD0, 0x1FD3),
+    (0x1FD6, 0x1FDB), (0x1FE0, 0x1FEC), (0x1FF2, 0x1FF4), (0x1FF6, 0x1FFC),
+    (0x2071, 0x2071), (0x207F, 0x207F), (0x2090, 0x209C), (0x2102, 0x2102),
+    (0x2107, 0x2107), (0x210A, 0x2113), (0x2115, 0x2115), (0x2119, 0x211D),
+    (0x2124, 0x2124), (0x2126, 0x2126), (0x2128, 0x2128), (0x212A, 0x212D),
+    (0x212F, 0x2139), (0x213C, 0x213F), (0x2145, 0x2149), (0x214E, 0x214E),
+    (0x2160, 0x2188), (0x24B6, 0x24E9), (0x2C00, 0x2CE4), (0x2CEB, 0x2CEE),
+    (0x2CF2, 0x2CF3), (0x2D00, 0x2D25), (0x2D27, 0x2D27), (0x2D2D, 0x2D2D),
+    (0x2D30, 0x2D67), (0x2D6F, 0x2D6F), (0x2D80, 0x2D96), (0x2DA0, 0x2DA6),
+    (0x2DA8, 0x2DAE), (0x2DB0, 0x2DB6), (0x2DB8, 0x2DBE), (0x2DC0, 0x2DC6),
+    (0x2DC8, 0x2DCE), (0x2DD0, 0x2DD6), (0x2DD8, 0x2DDE), (0x2DE0, 0x2DFF),
+    (0x2E2F, 0x2E2F), (0x3005, 0x3007), (0x3021, 0x3029), (0x3031, 0x3035),
+    (0x3038, 0x303C), (0x3041, 0x3096), (0x309D, 0x309F), (0x30A1, 0x30FA),
+    (0x30FC, 0x30FF), (0x3105, 0x312F), (0x3131, 0x318E), (0x31A0, 0x31BF),
+    (0x31F0, 0x31FF), (0x3400, 0x4DBF), (0x4E00, 0xA48C), (0xA4D0, 0xA4FD),
+    (0xA500, 0xA60C), (0xA610, 0xA61F), (0xA62A, 0xA62B), (0xA640, 0xA66E),
+    (0xA674, 0xA67B), (0xA67F, 0xA6EF), (0xA717, 0xA71F), (0xA722, 0xA788),
+    (0xA78B, 0xA7CA), (0xA7D0, 0xA7D1), (0xA7D3, 0xA7D3), (0xA7D5, 0xA7D9),
+    (0xA7F2, 0xA805), (0xA807, 0xA827), (0xA840, 0xA873), (0xA880, 0xA8C3),
+    (0xA8C5, 0xA8C5), (0xA8F2, 0xA8F7), (0xA8FB, 0xA8FB), (0xA8FD, 0xA8FF),
+    (0xA90A, 0xA92A), (0xA930, 0xA952), (0xA960, 0xA97C), (0xA980, 0xA9B2),
+    (0xA9B4, 0xA9BF), (0xA9CF, 0xA9CF), (0xA9E0, 0xA9EF), (0xA9FA, 0xA9FE),
+    (0xAA00, 0xAA36), (0xAA40, 0xAA4D), (0xAA60, 0xAA76), (0xAA7A, 0xAABE),
+    (0xAAC0, 0xAAC0), (0xAAC2, 0xAAC2), (0xAADB, 0xAADD), (0xAAE0, 0xAAEF),
+    (0xAAF2, 0xAAF5), (0xAB01, 0xAB06), (0xAB09, 0xAB0E), (0xAB11, 0xAB16),
+    (0xAB20, 0xAB26), (0xAB28, 0xAB2E), (0xAB30, 0xAB5A), (0xAB5C, 0xAB69),
+    (0xAB70, 0xABEA), (0xAC00, 0xD7A3), (0xD7B0, 0xD7C6), (0xD7CB, 0xD7FB),
+    (0xF900, 0xFA6D), (0xFA70, 0xFAD9), (0xFB00, 0xFB06), (0xFB13, 0xFB17),
+    (0xFB1D, 0xFB28), (0xFB2A, 0xFB36), (0xFB38, 0xFB3C), (0xFB3E, 0xFB3E),
+    (0xFB40, 0xFB41), (0xFB43, 0xFB44), (0xFB46, 0xFBB1), (0xFBD3, 0xFD3D),
+    (0xFD50, 0xFD8F), (0xFD92, 0xFDC7), (0xFDF0, 0xFDFB), (0xFE70, 0xFE74),
+    (0xFE76, 0xFEFC), (0xFF21, 0xFF3A), (0xFF41, 0xFF5A), (0xFF66, 0xFFBE),
+    (0xFFC2, 0xFFC7), (0xFFCA, 0xFFCF), (0xFFD2, 0xFFD7), (0xFFDA, 0xFFDC),
+    (0x10000, 0x1000B), (0x1000D, 0x10026), (0x10028, 0x1003A), (0x1003C, 0x1003D),
+    (0x1003F, 0x1004D), (0x10050, 0x1005D), (0x10080, 0x100FA), (0x10140, 0x10174),
+    (0x10280, 0x1029C), (0x102A0, 0x102D0), (0x10300, 0x1031F), (0x1032D, 0x1034A),
+    (0x10350, 0x1037A), (0x10380, 0x1039D), (0x103A0, 0x103C3), (0x103C8, 0x103CF),
+    (0x103D1, 0x103D5), (0x10400, 0x1049D), (0x104B0, 0x104D3), (0x104D8, 0x104FB),
+    (0x10500, 0x10527), (0x10530, 0x10563), (0x10570, 0x1057A), (0x1057C, 0x1058A),
+    (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1),
+    (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x10600, 0x10736), (0x10740, 0x10755),
+    (0x10760, 0x10767), (0x10780, 0x10785), (0x10787, 0x107B0), (0x107B2, 0x107BA),
+    (0x10800, 0x10805), (0x10808, 0x10808), (0x1080A, 0x10835), (0x10837, 0x10838),
+    (0x1083C, 0x1083C), (0x1083F, 0x10855), (0x10860, 0x10876), (0x10880, 0x1089E),
+    (0x108E0, 0x108F2), (0x108F4, 0x108F5), (0x10900, 0x10915), (0x10920, 0x10939),
+    (0x10980, 0x109B7), (0x109BE, 0x109BF), (0x10A00, 0x10A03), (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A13), (0x10A15, 0x10A17), (0x10A19, 0x10A35), (0x10A60, 0x10A7C),
+    (0x10A80, 0x10A9C), (0x10AC0, 0x10AC7), (0x10AC9, 0x10AE4), (0x10B00, 0x10B35),
+    (0x10B40, 0x10B55), (0x10B60, 0x10B72), (0x10B80, 0x10B91), (0x10C00, 0x10C48),
+    (0x10C80, 0x10CB2), (0x10CC0, 0x10CF2), (0x10D00, 0x10D27), (0x10E80, 0x10EA9),
+    (0x10EAB, 0x10EAC), (0x10EB0, 0x10EB1), (0x10F00, 0x10F1C), (0x10F27, 0x10F27),
+    (0x10F30, 0x10F45), (0x10F70, 0x10F81), (0x10FB0, 0x10FC4), (0x10FE0, 0x10FF6),
+    (0x11000, 0x11045), (0x11071, 0x11075), (0x11082, 0x110B8), (0x110C2, 0x110C2),
+    (0x110D0, 0x110E8), (0x11100, 0x11132), (0x11144, 0x11147), (0x11150, 0x11172),
+    (0x11176, 0x11176), (0x11180, 0x111BF), (0x111C1, 0x111C4), (0x111CE, 0x111CF),
+    (0x111DA, 0x111DA), (0x111DC, 0x111DC), (0x11200, 0x11211), (0x11213, 0x11234),
+    (0x11237, 0x11237), (0x1123E, 0x1123E), (0x11280, 0x11286), (0x11288, 0x11288),
+    (0x1128A, 0x1128D), (0x1128F, 0x1129D), (0x1129F, 0x112A8), (0x112B0, 0x112E8),
+    (0x11300, 0x11303), (0x11305, 0x1130C), (0x1130F, 0x11310), (0x11313, 0x11328),
+    (0x1132A, 0x11330), (0x11332, 0x11333), (0x11335, 0x11339), (0x1133D, 0x11344),
+    (0x11347, 0x11348), (0x1134B, 0x1134C), (0x11350, 0x11350), (0x11357, 0x11357),
+    (0x1135D, 0x11363), (0x11400, 0x11441), (0x11443, 0x11445), (0x11447, 0x1144A),
+    (0x1145F, 0x11461), (0x11480, 0x114C1), (0x114C4, 0x114C5), (0x114C7, 0x114C7),
+    (0x11580, 0x115B5), (0x115B8, 0x115BE), (0x115D8, 0x115DD), (0x11600, 0x1163E),
+    (0x11640, 0x11640), (0x11644, 0x11644), (0x11680, 0x116B5), (0x116B8, 0x116B8),
+    (0x11700, 0x1171A), (0x1171D, 0x1172A), (0x11740, 0x11746), (0x11800, 0x11838),
+    (0x118A0, 0x118DF), (0x118FF, 0x11906), (0x11909, 0x11909), (0x1190C, 0x11913),
+    (0x11915, 0x11916), (0x11918, 0x11935), (0x11937, 0x11938), (0x1193B, 0x1193C),
+    (0x1193F, 0x11942), (0x119A0, 0x119A7), (0x119AA, 0x119D7), (0x119DA, 0x119DF),
+    (0x119E1, 0x119E1), (0x119E3, 0x119E4), (0x11A00, 0x11A32), (0x11A35, 0x11A3E),
+    (0x11A50, 0x11A97), (0x11A9D, 0x11A9D), (0x11AB0, 0x11AF8), (0x11C00, 0x11C08),
+    (0x11C0A, 0x11C36), (0x11C38, 0x11C3E), (0x11C40, 0x11C40), (0x11C72, 0x11C8F),
+    (0x11C92, 0x11CA7), (0x11CA9, 0x11CB6), (0x11D00, 0x11D06), (0x11D08, 0x11D09),
+    (0x11D0B, 0x11D36), (0x11D3A, 0x11D3A), (0x11D3C, 0x11D3D), (0x11D3F, 0x11D41),
+    (0x11D43, 0x11D43), (0x11D46, 0x11D47), (0x11D60, 0x11D65), (0x11D67, 0x11D68),
+    (0x11D6A, 0x11D8E), (0x11D90, 0x11D91), (0x11D93, 0x11D96), (0x11D98, 0x11D98),
+    (0x11EE0, 0x11EF6), (0x11FB0, 0x11FB0), (0x12000, 0x12399), (0x12400, 0x1246E),
+    (0x12480, 0x12543), (0x12F90, 0x12FF0), (0x13000, 0x1342E), (0x14400, 0x14646),
+    (0x16800, 0x16A38), (0x16A40, 0x16A5E), (0x16A70, 0x16ABE), (0x16AD0, 0x16AED),
+    (0x16B00, 0x16B2F), (0x16B40, 0x16B43), (0x16B63, 0x16B77), (0x16B7D, 0x16B8F),
+    (0x16E40, 0x16E7F), (0x16F00, 0x16F4A), (0x16F4F, 0x16F87), (0x16F8F, 0x16F9F),
+    (0x16FE0, 0x16FE1), (0x16FE3, 0x16FE3), (0x16FF0, 0x16FF1), (0x17000, 0x187F7),
+    (0x18800, 0x18CD5), (0x18D00, 0x18D08), (0x1AFF0, 0x1AFF3), (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE), (0x1B000, 0x1B122), (0x1B150, 0x1B152), (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB), (0x1BC00, 0x1BC6A), (0x1BC70, 0x1BC7C), (0x1BC80, 0x1BC88),
+    (0x1BC90, 0x1BC99), (0x1BC9E, 0x1BC9E), (0x1D400, 0x1D454), (0x1D456, 0x1D49C),
+    (0x1D49E, 0x1D49F), (0x1D4A2, 0x1D4A2), (0x1D4A5, 0x1D4A6), (0x1D4A9, 0x1D4AC),
+    (0x1D4AE, 0x1D4B9), (0x1D4BB, 0x1D4BB), (0x1D4BD, 0x1D4C3), (0x1D4C5, 0x1D505),
+    (0x1D507, 0x1D50A), (0x1D50D, 0x1D514), (0x1D516, 0x1D51C), (0x1D51E, 0x1D539),
+    (0x1D53B, 0x1D53E), (0x1D540, 0x1D544), (0x1D546, 0x1D546), (0x1D54A, 0x1D550),
+    (0x1D552, 0x1D6A5), (0x1D6A8, 0x1D6C0), (0x1D6C2, 0x1D6DA), (0x1D6DC, 0x1D6FA),
+    (0x1D6FC, 0x1D714), (0x1D716, 0x1D734), (0x1D736, 0x1D74E), (0x1D750, 0x1D76E),
+    (0x1D770, 0x1D788), (0x1D78A, 0x1D7A8), (0x1D7AA, 0x1D7C2), (0x1D7C4, 0x1D7CB),
+    (0x1DF00, 0x1DF1E), (0x1E000, 0x1E006), (0x1E008, 0x1E018), (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024), (0x1E026, 0x1E02A), (0x1E100, 0x1E12C), (0x1E137, 0x1E13D),
+    (0x1E14E, 0x1E14E), (0x1E290, 0x1E2AD), (0x1E2C0, 0x1E2EB), (0x1E7E0, 0x1E7E6),
+    (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE), (0x1E7F0, 0x1E7FE), (0x1E800, 0x1E8C4),
+    (0x1E900, 0x1E943), (0x1E947, 0x1E947), (0x1E94B, 0x1E94B), (0x1EE00, 0x1EE03),
+    (0x1EE05, 0x1EE1F), (0x1EE21, 0x1EE22), (0x1EE24, 0x1EE24), (0x1EE27, 0x1EE27),
+    (0x1EE29, 0x1EE32), (0x1EE34, 0x1EE37), (0x1EE39, 0x1EE39), (0x1EE3B, 0x1EE3B),
+    (0x1EE42, 0x1EE42), (0x1EE47, 0x1EE47), (0x1EE49, 0x1EE49), (0x1EE4B, 0x1EE4B),
+    (0x1EE4D, 0x1EE4F), (0x1EE51, 0x1EE52), (0x1EE54, 0x1EE54), (0x1EE57, 0x1EE57),
+    (0x1EE59, 0x1EE59), (0x1EE5B, 0x1EE5B), (0x1EE5D, 0x1EE5D), (0x1EE5F, 0x1EE5F),
+    (0x1EE61, 0x1EE62), (0x1EE64, 0x1EE64), (0x1EE67, 0x1EE6A), (0x1EE6C, 0x1EE72),
+    (0x1EE74, 0x1EE77), (0x1EE79, 0x1EE7C), (0x1EE7E, 0x1EE7E), (0x1EE80, 0x1EE89),
+    (0x1EE8B, 0x1EE9B), (0x1EEA1, 0x1EEA3), (0x1EEA5, 0x1EEA9), (0x1EEAB, 0x1EEBB),
+    (0x1F130, 0x1F149), (0x1F150, 0x1F169), (0x1F170, 0x1F189), (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B738), (0x2B740, 0x2B81D), (0x2B820, 0x2CEA1), (0x2CEB0, 0x2EBE0),
+    (0x2F800, 0x2FA1D), (0x30000, 0x3134A),
+];
+
+/// Characters with a numeric value, as `first << 8 | index` for runs of consecutive code points
+/// whose values increase by one, starting with the value at `index` in `NUMERIC_VALUES`. The lengths
+/// of the runs are in `NUMERIC_RUN_LENGTHS`.
+pub(super) static NUMERIC_RUNS: [u32; 779] = [
+    0x3001, 0xB226, 0xB924, 0xBC15, 0xBD1A, 0xBE1F, 0x66001, 0x6F001,
+    0x7C001, 0x96601, 0x9E601, 0x9F40B, 0x9F50F, 0x9F613, 0x9F715, 0x9F81F,
+    0x9F937, 0xA6601, 0xAE601, 0xB6601, 0xB7215, 0xB731A, 0xB741F, 0xB750B,
+    0xB760F, 0xB7713, 0xBE601, 0xBF143, 0xBF24C, 0xC6601, 0xC7801, 0xC7C24,
+    0xCE601, 0xD5803, 0xD5906, 0xD5A08, 0xD5B0A, 0xD5C0D, 0xD5D11, 0xD5E14,
+    0xD6601, 0xD7143, 0xD724C, 0xD7315, 0xD741A, 0xD751F, 0xD760B, 0xD770F,
+    0xD7813, 0xDE601, 0xE5001, 0xED001, 0xF2001, 0xF2A1A, 0xF2B25, 0xF2C27,
+    0xF2D29, 0xF2E2B, 0xF2F2D, 0xF302F, 0xF3131, 0xF3233, 0xF3300, 0x104001,
+    0x109001, 0x136924, 0x137339, 0x13743B, 0x13753D, 0x13763E, 0x13773F, 0x137840,
+    0x137941, 0x137A42, 0x137B43, 0x137C55, 0x16EE38, 0x17E001, 0x17F001, 0x181001,
+    0x194601, 0x19D001, 0x19DA24, 0x1A8001, 0x1A9001, 0x1B5001, 0x1BB001, 0x1C4001,
+    0x1C5001, 0x207001, 0x20742A, 0x208001, 0x215010, 0x21510E, 0x21520D, 0x215316,
+    0x21541E, 0x215514, 0x215618, 0x21571C, 0x215820, 0x215912, 0x215A21, 0x215B0F,
+    0x215C17, 0x215D1D, 0x215E22, 0x215F24, 0x216024, 0x216C3E, 0x216D43, 0x216E47,
+    0x216F4C, 0x217024, 0x217C3E, 0x217D43, 0x217E47, 0x217F4C, 0x21804C, 0x218150,
+    0x218255, 0x21852E, 0x21863E, 0x218759, 0x21885E, 0x218901, 0x246024, 0x247424,
+    0x248824, 0x24EA01, 0x24EB36, 0x24F524, 0x24FF01, 0x277624, 0x278024, 0x278A24,
+    0x2CFD1A, 0x300701, 0x302124, 0x303835, 0x303939, 0x303A3B, 0x319224, 0x322024,
+    0x324835, 0x324939, 0x324A3B, 0x324B3D, 0x324C3E, 0x324D3F, 0x324E40, 0x324F41,
+    0x32513A, 0x328024, 0x32B13C, 0x34052C, 0x348326, 0x382A2C, 0x3B4D30, 0x4E0024,
+    0x4E0330, 0x4E0755, 0x4E0928, 0x4E5D34, 0x4E8C26, 0x4E942C, 0x4E962A, 0x4EBF6C,
+    0x4EC035, 0x4EDF4C, 0x4EE828, 0x4F0D2C, 0x4F7043, 0x51046C, 0x51466E, 0x516926,
+    0x516B32, 0x516D2E, 0x534135, 0x53434C, 0x534439, 0x53453B, 0x534C3D, 0x53C128,
+    0x53C228, 0x53C328, 0x53C428, 0x56DB2A, 0x58F124, 0x58F924, 0x5E7A24, 0x5EFE34,
+    0x5EFF39, 0x5F0C24, 0x5F1026, 0x62FE35, 0x634C32, 0x67D230, 0x6F0630, 0x739634,
+    0x767E43, 0x80862A, 0x842C55, 0x8CAE26, 0x8CB326, 0x8D3026, 0x96214C, 0x96462E,
+    0x964C43, 0x96782E, 0x96F601, 0xA62001, 0xA6E624, 0xA6EF01, 0xA83015, 0xA8311A,
+    0xA8321F, 0xA8330B, 0xA8340F, 0xA83513, 0xA8D001, 0xA90001, 0xA9D001, 0xA9F001,
+    0xAA5001, 0xABF001, 0xF96B28, 0xF97335, 0xF97826, 0xF9B201, 0xF9D12E, 0xF9D32E,
+    0xF9FD35, 0xFF1001, 0x1010724, 0x1011139, 0x101123B, 0x101133D, 0x101143E, 0x101153F,
+    0x1011640, 0x1011741, 0x1011842, 0x1011943, 0x1011A44, 0x1011B45, 0x1011C46, 0x1011D47,
+    0x1011E48, 0x1011F49, 0x101204A, 0x101214B, 0x101224C, 0x101234D, 0x101244E, 0x101254F,
+    0x1012650, 0x1012751, 0x1012852, 0x1012953, 0x1012A54, 0x1012B55, 0x1012C56, 0x1012D57,
+    0x1012E58, 0x1012F59, 0x101305A, 0x101315B, 0x101325C, 0x101335D, 0x1014015, 0x101411A,
+    0x1014224, 0x101432C, 0x101443E, 0x1014547, 0x1014650, 0x1014759, 0x101482C, 0x1014935,
+    0x1014A3E, 0x1014B43, 0x1014C47, 0x1014D4C, 0x1014E50, 0x1014F2C, 0x1015035, 0x101513E,
+    0x1015243, 0x1015347, 0x101544C, 0x1015555, 0x1015659, 0x1015735, 0x1015824, 0x1015924,
+    0x1015A24, 0x1015C26, 0x1015D26, 0x1015E26, 0x1015F2C, 0x1016035, 0x1016135, 0x1016235,
+    0x1016335, 0x1016435, 0x101653B, 0x101663E, 0x101673E, 0x101683E, 0x101693E, 0x1016A43,
+    0x1016B45, 0x1016C47, 0x1016D47, 0x1016E47, 0x1016F47, 0x1017047, 0x101714C, 0x1017250,
+    0x101732C, 0x101743E, 0x101751A, 0x101761A, 0x101771E, 0x101781F, 0x1018A01, 0x1018B15,
+    0x102E124, 0x102EB39, 0x102EC3B, 0x102ED3D, 0x102EE3E, 0x102EF3F, 0x102F040, 0x102F141,
+    0x102F242, 0x102F343, 0x102F444, 0x102F545, 0x102F646, 0x102F747, 0x102F848, 0x102F949,
+    0x102FA4A, 0x102FB4B, 0x1032024, 0x103212C, 0x1032235, 0x103233E, 0x1034142, 0x1034A4B,
+    0x103D124, 0x103D335, 0x103D439, 0x103D543, 0x104A001, 0x1085824, 0x1085B35, 0x1085C39,
+    0x1085D43, 0x1085E4C, 0x1085F55, 0x1087924, 0x1087E35, 0x1087F39, 0x108A724, 0x108AB2A,
+    0x108AD35, 0x108AE39, 0x108AF43, 0x108FB24, 0x108FC2C, 0x108FD35, 0x108FE39, 0x108FF43,
+    0x1091624, 0x1091735, 0x1091839, 0x1091943, 0x1091A26, 0x109BC23, 0x109BD1A, 0x109C024,
+    0x109CA39, 0x109CB3B, 0x109CC3D, 0x109CD3E, 0x109CE3F, 0x109CF40, 0x109D243, 0x109D344,
+    0x109D445, 0x109D546, 0x109D647, 0x109D748, 0x109D849, 0x109D94A, 0x109DA4B, 0x109DB4C,
+    0x109DC4D, 0x109DD4E, 0x109DE4F, 0x109DF50, 0x109E051, 0x109E152, 0x109E253, 0x109E354,
+    0x109E455, 0x109E556, 0x109E657, 0x109E758, 0x109E859, 0x109E95A, 0x109EA5B, 0x109EB5C,
+    0x109EC5D, 0x109ED5E, 0x109EE5F, 0x109EF61, 0x109F062, 0x109F164, 0x109F265, 0x109F366,
+    0x109F467, 0x109F568, 0x109F60C, 0x109F712, 0x109F815, 0x109F916, 0x109FA19, 0x109FB1A,
+    0x109FC1B, 0x109FD1E, 0x109FE1F, 0x109FF21, 0x10A4024, 0x10A4435, 0x10A4539, 0x10A4643,
+    0x10A474C, 0x10A481A, 0x10A7D24, 0x10A7E3E, 0x10A9D24, 0x10A9E35, 0x10A9F39, 0x10AEB24,
+    0x10AEC2C, 0x10AED35, 0x10AEE39, 0x10AEF43, 0x10B5824, 0x10B5C35, 0x10B5D39, 0x10B5E43,
+    0x10B5F4C, 0x10B7824, 0x10B7C35, 0x10B7D39, 0x10B7E43, 0x10B7F4C, 0x10BA924, 0x10BAD35,
+    0x10BAE39, 0x10BAF43, 0x10CFA24, 0x10CFB2C, 0x10CFC35, 0x10CFD3E, 0x10CFE43, 0x10CFF4C,
+    0x10D3001, 0x10E6024, 0x10E6A39, 0x10E6B3B, 0x10E6C3D, 0x10E6D3E, 0x10E6E3F, 0x10E6F40,
+    0x10E7041, 0x10E7142, 0x10E7243, 0x10E7344, 0x10E7445, 0x10E7546, 0x10E7647, 0x10E7748,
+    0x10E7849, 0x10E794A, 0x10E7A4B, 0x10E7B1A, 0x10E7C15, 0x10E7D16, 0x10E7E1E, 0x10F1D24,
+    0x10F2235, 0x10F2339, 0x10F243B, 0x10F2543, 0x10F261A, 0x10F5124, 0x10F5235, 0x10F5339,
+    0x10F5443, 0x10FC524, 0x10FC935, 0x10FCA39, 0x10FCB43, 0x1105224, 0x1105C39, 0x1105D3B,
+    0x1105E3D, 0x1105F3E, 0x110603F, 0x1106140, 0x1106241, 0x1106342, 0x1106443, 0x110654C,
+    0x1106601, 0x110F001, 0x1113601, 0x111D001, 0x111E124, 0x111EB39, 0x111EC3B, 0x111ED3D,
+    0x111EE3E, 0x111EF3F, 0x111F040, 0x111F141, 0x111F242, 0x111F343, 0x111F44C, 0x112F001,
+    0x1145001, 0x114D001, 0x1165001, 0x116C001, 0x1173001, 0x1173B39, 0x118E001, 0x118EB39,
+    0x118EC3B, 0x118ED3D, 0x118EE3E, 0x118EF3F, 0x118F040, 0x118F141, 0x118F242, 0x1195001,
+    0x11C5001, 0x11C5A24, 0x11C6439, 0x11C653B, 0x11C663D, 0x11C673E, 0x11C683F, 0x11C6940,
+    0x11C6A41, 0x11C6B42, 0x11C6C43, 0x11D5001, 0x11DA001, 0x11FC002, 0x11FC103, 0x11FC204,
+    0x11FC305, 0x11FC406, 0x11FC507, 0x11FC608, 0x11FC709, 0x11FC80A, 0x11FC90B, 0x11FCA0B,
+    0x11FCB0D, 0x11FCC0F, 0x11FCD11, 0x11FCE13, 0x11FCF14, 0x11FD015, 0x11FD11A, 0x11FD21A,
+    0x11FD31F, 0x11FD402, 0x1240026, 0x1240828, 0x1240F2A, 0x1241524, 0x1241E24, 0x1242326,
+    0x1242528, 0x1242C24, 0x1242F28, 0x1243260, 0x1243363, 0x1243424, 0x1243728, 0x1243A28,
+    0x1243B28, 0x1243D2A, 0x1243E2A, 0x1243F2A, 0x124402E, 0x1244230, 0x1244330, 0x1244532,
+    0x1244734, 0x1244834, 0x1244934, 0x1244A26, 0x1244F24, 0x124532A, 0x124552C, 0x1245626,
+    0x1245824, 0x1245A16, 0x1245B1E, 0x1245C21, 0x1245D16, 0x1245E1E, 0x1245F0F, 0x1246015,
+    0x1246112, 0x1246215, 0x1246315, 0x124641A, 0x1246516, 0x124661E, 0x124673D, 0x124683E,
+    0x124692A, 0x16A6001, 0x16AC001, 0x16B5001, 0x16B5B35, 0x16B5C43, 0x16B5D55, 0x16B5E69,
+    0x16B5F6C, 0x16B606D, 0x16B616E, 0x16E8001, 0x16E9424, 0x1D2E001, 0x1D36024, 0x1D36A39,
+    0x1D36B3B, 0x1D36C3D, 0x1D36D3E, 0x1D36E3F, 0x1D36F40, 0x1D37041, 0x1D37142, 0x1D37224,
+    0x1D37724, 0x1D3782C, 0x1D7CE01, 0x1D7D801, 0x1D7E201, 0x1D7EC01, 0x1D7F601, 0x1E14001,
+    0x1E2F001, 0x1E8C724, 0x1E95001, 0x1EC7124, 0x1EC7B39, 0x1EC7C3B, 0x1EC7D3D, 0x1EC7E3E,
+    0x1EC7F3F, 0x1EC8040, 0x1EC8141, 0x1EC8242, 0x1EC8343, 0x1EC8444, 0x1EC8545, 0x1EC8646,
+    0x1EC8747, 0x1EC8848, 0x1EC8949, 0x1EC8A4A, 0x1EC8B4B, 0x1EC8C4C, 0x1EC8D4D, 0x1EC8E4E,
+    0x1EC8F4F, 0x1EC9050, 0x1EC9151, 0x1EC9252, 0x1EC9353, 0x1EC9454, 0x1EC9555, 0x1EC9656,
+    0x1EC9757, 0x1EC9858, 0x1EC9959, 0x1EC9A5A, 0x1EC9B5B, 0x1EC9C5C, 0x1EC9D5D, 0x1EC9E5E,
+    0x1EC9F5F, 0x1ECA05E, 0x1ECA16A, 0x1ECA26B, 0x1ECA324, 0x1ECAD15, 0x1ECAE1A, 0x1ECAF1F,
+    0x1ECB124, 0x1ECB355, 0x1ECB45E, 0x1ED0124, 0x1ED0B39, 0x1ED0C3B, 0x1ED0D3D, 0x1ED0E3E,
+    0x1ED0F3F, 0x1ED1040, 0x1ED1141, 0x1ED1242, 0x1ED1343, 0x1ED1444, 0x1ED1545, 0x1ED1646,
+    0x1ED1747, 0x1ED1848, 0x1ED1949, 0x1ED1A4A, 0x1ED1B4B, 0x1ED1C4C, 0x1ED1D4D, 0x1ED1E4E,
+    0x1ED1F4F, 0x1ED2050, 0x1ED2151, 0x1ED2252, 0x1ED2353, 0x1ED2454, 0x1ED2555, 0x1ED2656,
+    0x1ED2757, 0x1ED2858, 0x1ED2959, 0x1ED2A5A, 0x1ED2B5B, 0x1ED2C5C, 0x1ED2D5D, 0x1ED2F26,
+    0x1ED3846, 0x1ED3948, 0x1ED3A4D, 0x1ED3B55, 0x1ED3C1A, 0x1ED3D12, 0x1F10001, 0x1F10101,
+    0x1F10B01, 0x1F10C01, 0x1FBF001, 0x2000130, 0x200642A, 0x200E22A, 0x201212C, 0x2092A24,
+    0x209833B, 0x2098C3D, 0x2099C3D, 0x20AEA2E, 0x20AFD28, 0x20B1928, 0x2239026, 0x2299828,
+    0x23B1B28, 0x2626D2A, 0x2F89034,
+];
+
+/// Lengths minus one of the runs in `NUMERIC_RUNS`
+pub(super) static NUMERIC_RUN_LENGTHS: [u8; 779] = [
+    9, 1, 0, 0, 0, 0, 9, 9, 9, 9, 9, 0, 0, 0, 0, 0,
+    0, 9, 9, 9, 0, 0, 0, 0, 0, 0, 10, 0, 0, 9, 3, 2,
+    9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0,
+    0, 9, 9, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+    9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 9, 9, 9,
+    9, 9, 0, 9, 9, 9, 9, 9, 9, 0, 5, 9, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0,
+    0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 19,
+    19, 0, 9, 9, 0, 9, 9, 9, 0, 0, 8, 0, 0, 0, 3, 9,
+    0, 0, 0, 0, 0, 0, 0, 0, 14, 9, 14, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 9, 8, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9,
+    9, 9, 0, 0, 0, 0, 0, 0, 0, 9, 9, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    1, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 4, 0, 0, 3, 1,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 9,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 3, 0, 0, 0, 0, 3, 0, 0, 0, 0, 3, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 9, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 9, 9, 10, 0, 10, 0,
+    0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 6, 5, 8, 4, 1,
+    6, 2, 2, 0, 0, 2, 2, 0, 1, 0, 0, 0, 1, 0, 1, 1,
+    0, 0, 0, 4, 3, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 5, 9, 9, 9, 0, 0, 0, 0,
+    0, 0, 0, 19, 2, 19, 9, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+    0, 0, 9, 9, 9, 9, 9, 9, 9, 8, 9, 9, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 8, 0, 0, 0, 1, 0, 0, 9, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8,
+    0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 9, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// Numeric values, as `(numerator, denominator)`
+pub(super) static NUMERIC_VALUES: [(i64, u32); 111] = [
+    (-1, 2), (0, 1), (1, 320), (1, 160),
+    (1, 80), (1, 64), (1, 40), (1, 32),
+    (3, 80), (3, 64), (1, 20), (1, 16),
+    (1, 12), (1, 10), (1, 9), (1, 8),
+    (1, 7), (3, 20), (1, 6), (3, 16),
+    (1, 5), (1, 4), (1, 3), (3, 8),
+    (2, 5), (5, 12), (1, 2), (7, 12),
+    (3, 5), (5, 8), (2, 3), (3, 4),
+    (4, 5), (5, 6), (7, 8), (11, 12),
+    (1, 1), (3, 2), (2, 1), (5, 2),
+    (3, 1), (7, 2), (4, 1), (9, 2),
+    (5, 1), (11, 2), (6, 1), (13, 2),
+    (7, 1), (15, 2), (8, 1), (17, 2),
+    (9, 1), (10, 1), (11, 1), (16, 1),
+    (17, 1), (20, 1), (21, 1), (30, 1),
+    (36, 1), (40, 1), (50, 1), (60, 1),
+    (70, 1), (80, 1), (90, 1), (100, 1),
+    (200, 1), (300, 1), (400, 1), (500, 1),
+    (600, 1), (700, 1), (800, 1), (900, 1),
+    (1000, 1), (2000, 1), (3000, 1), (4000, 1),
+    (5000, 1), (6000, 1), (7000, 1), (8000, 1),
+    (9000, 1), (10000, 1), (20000, 1), (30000, 1),
+    (40000, 1), (50000, 1), (60000, 1), (70000, 1),
+    (80000, 1), (90000, 1), (100000, 1), (200000, 1),
+    (216000, 1), (300000, 1), (400000, 1), (432000, 1),
+    (500000, 1), (600000, 1), (700000, 1), (800000, 1),
+    (900000, 1), (1000000, 1), (10000000, 1), (20000000, 1),
+    (100000000, 1), (10000000000, 1), (1000000000000, 1),
+];
+
@@ -1,6 +1,10 @@
 #![allow(dead_code)]
 
+use crate::buf::Buf;
+use crate::idl::pretty::{write_message, TextLimits};
+use crate::memory::Memory;
 use crate::print::*;
+use crate::text_builder::{text_builder_freeze, text_builder_new, TextBuilderWriter};
 use crate::types::*;
 
 use core::fmt::Write;
@@ -116,6 +120,14 @@ unsafe fn print_heap(heap_start: u32, heap_end: u32) {
     }
 }
 
+/// Print the Candid message in `buf`, advancing `buf` past the message
+pub unsafe fn print_idl_message<M: Memory>(mem: &mut M, buf: *mut Buf, extended: bool) {
+    let builder = text_builder_new(mem);
+    let mut out = TextBuilderWriter { mem, builder };
+    write_message(mem, &mut out, buf, extended, &TextLimits::DEFAULT);
+    print_blob(text_builder_freeze(mem, builder));
+}
+
 unsafe fn print_tagged_scalar(buf: &mut WriteBuf, p: usize) {
     let _ = write!(buf, "<Scalar {:#x}>", p);
 }
@@ -226,6 +238,15 @@ pub(crate) unsafe fn print_boxed_object(buf: &mut WriteBuf, p: usize) {
                 (*concat).text2.0
             );
         }
+        TAG_TEXT_BUILDER => {
+            let builder = obj as *const TextBuilder;
+            let _ = write!(
+                buf,
+                "<TextBuilder n_bytes={:#x} buf={:#x}>",
+                (*builder).n_bytes.0,
+                (*builder).buf.0
+            );
+        }
         other => {
             let _ = write!(buf, "<??? {} ???>", other);
         }
@@ -0,0 +1,278 @@
+//! Conversions between text and blobs in encodings other than UTF-8: UTF-16 (little or big endian,
+//! or with a byte order mark) and Latin-1 (ISO 8859-1)
+//!
+//! Decoding functions validate their input and trap with the byte offset of the first invalid
+//! sequence, unless asked to decode lossily, in which case invalid sequences are replaced with
+//! U+FFFD REPLACEMENT CHARACTER. Latin-1 can only encode the first 256 code points, other
+//! characters either trap (with the byte offset of the character in the UTF-8 text) or are replaced
+//! with `?`.
+
+use crate::memory::{alloc_blob, Memory};
+use crate::print::WriteBuf;
+use crate::rts_trap_with;
+use crate::text::{alloc_text_blob, blob_of_text};
+use crate::types::{Bytes, SkewedPtr};
+
+use core::fmt::Write;
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// UTF-16, little endian, without a byte order mark
+pub const UTF16_LE: u32 = 0;
+
+/// UTF-16, big endian, without a byte order mark
+pub const UTF16_BE: u32 = 1;
+
+/// UTF-16 with a byte order mark. When decoding, the byte order is detected from the byte order
+/// mark, and is big endian when there is none. Encoding uses big endian with a byte order mark.
+pub const UTF16: u32 = 2;
+
+const REPLACEMENT_CHAR: u32 = 0xFFFD;
+
+unsafe fn trap_at(msg: &str, position: &str, offset: u32) -> ! {
+    let mut buf = [0u8; 100];
+    let mut fmt = WriteBuf::new(&mut buf);
+    let _ = write!(&mut fmt, "{} at {} {}", msg, position, offset);
+    rts_trap_with(fmt.as_str())
+}
+
+unsafe fn blob_bytes<'a>(blob: SkewedPtr) -> &'a [u8] {
+    let blob = blob.as_blob();
+    core::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize())
+}
+
+unsafe fn text_as_str<'a>(mem: &mut impl Memory, text: SkewedPtr) -> &'a str {
+    core::str::from_utf8_unchecked(blob_bytes(blob_of_text(mem, text)))
+}
+
+fn utf8_len(c: u32) -> u32 {
+    if c < 0x80 {
+        1
+    } else if c < 0x800 {
+        2
+    } else if c < 0x10000 {
+        3
+    } else {
+        4
+    }
+}
+
+/// Writes the UTF-8 encoding of a character to `dst`, returns the number of bytes written
+unsafe fn write_utf8(dst: *mut u8, c: u32) -> u32 {
+    let mut buf = [0u8; 4];
+    let encoded = core::char::from_u32_unchecked(c).encode_utf8(&mut buf);
+    core::ptr::copy_nonoverlapping(encoded.as_ptr(), dst, encoded.len());
+    encoded.len() as u32
+}
+
+//
+// UTF-16
+//
+
+/// Decodes UTF-16 code units from a byte slice
+struct Utf16Decoder<'a> {
+    bytes: &'a [u8],
+    big_endian: bool,
+    lossy: bool,
+    pos: usize,
+}
+
+impl<'a> Utf16Decoder<'a> {
+    fn new(bytes: &'a [u8], encoding: u32, lossy: bool) -> Self {
+        let (big_endian, pos) = match encoding {
+            UTF16_LE => (false, 0),
+            UTF16_BE => (true, 0),
+            _ => match bytes {
+                [0xFF, 0xFE, ..] => (false, 2),
+                [0xFE, 0xFF, ..] => (true, 2),
+                _ => (true, 0),
+            },
+        };
+        Utf16Decoder {
+            bytes,
+            big_endian,
+            lossy,
+            pos,
+        }
+    }
+
+    fn code_unit_at(&self, pos: usize) -> Option<u32> {
+        if pos + 1 >= self.bytes.len() {
+            return None;
+        }
+        let (b1, b2) = (self.bytes[pos], self.bytes[pos + 1]);
+        Some(if self.big_endian {
+            u32::from(b1) << 8 | u32::from(b2)
+        } else {
+            u32::from(b2) << 8 | u32::from(b1)
+        })
+    }
+
+    /// Returns the next character, or `None` at the end of the input. Traps on invalid input,
+    /// unless decoding lossily.
+    unsafe fn next(&mut self) -> Option<u32> {
+        if self.pos >= self.bytes.len() {
+            return None;
+        }
+
+        let start = self.pos;
+        let unit = match self.code_unit_at(start) {
+            Some(unit) => unit,
+            None => {
+                // A single byte left
+                if !self.lossy {
+                    trap_at("text_of_utf16: truncated code unit", "offset", start as u32);
+                }
+                self.pos = self.bytes.len();
+                return Some(REPLACEMENT_CHAR);
+            }
+        };
+        self.pos += 2;
+
+        match unit {
+            0xD800..=0xDBFF => match self.code_unit_at(self.pos) {
+                Some(low @ 0xDC00..=0xDFFF) => {
+                    self.pos += 2;
+                    Some(0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00))
+                }
+                _ => self.unpaired_surrogate(start),
+            },
+            0xDC00..=0xDFFF => self.unpaired_surrogate(start),
+            _ => Some(unit),
+        }
+    }
+
+    unsafe fn unpaired_surrogate(&self, offset: usize) -> Option<u32> {
+        if !self.lossy {
+            trap_at("text_of_utf16: unpaired surrogate", "offset", offset as u32);
+        }
+        Some(REPLACEMENT_CHAR)
+    }
+}
+
+/// Decodes a UTF-16 blob (in one of the encodings `UTF16_LE`, `UTF16_BE`, `UTF16`) into text.
+/// Invalid input traps, or is replaced with U+FFFD when `lossy` is true.
+#[ic_mem_fn]
+pub unsafe fn text_of_utf16<M: Memory>(
+    mem: &mut M,
+    blob: SkewedPtr,
+    encoding: u32,
+    lossy: bool,
+) -> SkewedPtr {
+    if encoding > UTF16 {
+        rts_trap_with("text_of_utf16: unknown encoding");
+    }
+
+    let bytes = blob_bytes(blob);
+
+    // Validate and compute size of the text first
+    let mut size = 0;
+    let mut decoder = Utf16Decoder::new(bytes, encoding, lossy);
+    while let Some(c) = decoder.next() {
+        size += utf8_len(c);
+    }
+
+    let text = alloc_text_blob(mem, Bytes(size));
+    let mut dst = text.as_blob().payload_addr();
+    let mut decoder = Utf16Decoder::new(bytes, encoding, lossy);
+    while let Some(c) = decoder.next() {
+        dst = dst.add(write_utf8(dst, c) as usize);
+    }
+
+    text
+}
+
+/// Encodes text as UTF-16 (in one of the encodings `UTF16_LE`, `UTF16_BE`, `UTF16`)
+#[ic_mem_fn]
+pub unsafe fn utf16_of_text<M: Memory>(mem: &mut M, text: SkewedPtr, encoding: u32) -> SkewedPtr {
+    if encoding > UTF16 {
+        rts_trap_with("utf16_of_text: unknown encoding");
+    }
+
+    let str = text_as_str(mem, text);
+    let bom = encoding == UTF16;
+    let big_endian = encoding != UTF16_LE;
+
+    let mut size = if bom { 2 } else { 0 };
+    for c in str.chars() {
+        size += c.len_utf16() as u32 * 2;
+    }
+
+    let blob = alloc_blob(mem, Bytes(size));
+    let mut dst = blob.as_blob().payload_addr();
+
+    let mut write_unit = |unit: u16| {
+        let bytes = if big_endian {
+            unit.to_be_bytes()
+        } else {
+            unit.to_le_bytes()
+        };
+        *dst = bytes[0];
+        *dst.add(1) = bytes[1];
+        dst = dst.add(2);
+    };
+
+    if bom {
+        write_unit(0xFEFF);
+    }
+
+    let mut units = [0u16; 2];
+    for c in str.chars() {
+        for unit in c.encode_utf16(&mut units) {
+            write_unit(*unit);
+        }
+    }
+
+    blob
+}
+
+//
+// Latin-1
+//
+
+/// Decodes a Latin-1 blob into text. All byte sequences are valid Latin-1.
+#[ic_mem_fn]
+pub unsafe fn text_of_latin1<M: Memory>(mem: &mut M, blob: SkewedPtr) -> SkewedPtr {
+    let bytes = blob_bytes(blob);
+
+    let mut size = 0;
+    for byte in bytes {
+        size += utf8_len(u32::from(*byte));
+    }
+
+    let text = alloc_text_blob(mem, Bytes(size));
+    let mut dst = text.as_blob().payload_addr();
+    for byte in bytes {
+        dst = dst.add(write_utf8(dst, u32::from(*byte)) as usize);
+    }
+
+    text
+}
+
+/// Encodes text as Latin-1. Characters above U+00FF trap, or are replaced with `?` when `lossy`
+/// is true.
+#[ic_mem_fn]
+pub unsafe fn latin1_of_text<M: Memory>(mem: &mut M, text: SkewedPtr, lossy: bool) -> SkewedPtr {
+    let str = text_as_str(mem, text);
+
+    let mut size = 0;
+    for (offset, c) in str.char_indices() {
+        if u32::from(c) > 0xFF && !lossy {
+            trap_at(
+                "latin1_of_text: character not in Latin-1",
+                "offset",
+                offset as u32,
+            );
+        }
+        size += 1;
+    }
+
+    let blob = alloc_blob(mem, Bytes(size));
+    let mut dst = blob.as_blob().payload_addr();
+    for c in str.chars() {
+        *dst = if u32::from(c) > 0xFF { b'?' } else { c as u8 };
+        dst = dst.add(1);
+    }
+
+    blob
+}
@@ -1,37 +1,1136 @@
-use crate::memory::Memory;
-use crate::text::text_of_ptr_size;
-use crate::types::{Bytes, SkewedPtr};
+//! Float formatting and parsing, without libc
+//!
+//! Decimal modes are computed from the exact decimal expansion of the number (every finite float
+//! has one, with at most 767 significant digits), and rounded half to even, like C's `printf`.
+//! The shortest mode finds the shortest decimal that converts back to the same float, choosing
+//! the closest one when there are several. It also formats `f32`s, with the shortest decimal
+//! that converts back to the same `f32`.
+//!
+//! Parsing converts decimal literals with the simple decimal conversion algorithm: the decimal is
+//! scaled by powers of two until it has 53 bits before the point, then rounded. This is slow for
+//! long inputs, but always correctly rounded.
+
+use crate::memory::{alloc_blob, Memory};
+use crate::print::WriteBuf;
+use crate::rts_trap_with;
+use crate::types::{Bytes, SkewedPtr, TAG_BLOB};
+
+use core::cmp::Ordering;
+use core::fmt::Write;
 
 use motoko_rts_macros::ic_mem_fn;
 
+/// Fixed notation, `%.*f`
+pub const FMT_FIXED: u32 = 0;
+
+/// Exponent notation, `%.*e`
+pub const FMT_EXPONENT: u32 = 1;
+
+/// Fixed or exponent notation, depending on the exponent, `%.*g`
+pub const FMT_GENERAL: u32 = 2;
+
+/// Hexadecimal exponent notation, `%.*a`
+pub const FMT_HEX: u32 = 3;
+
+/// Shortest representation that converts back to the same float. The precision is ignored.
+pub const FMT_SHORTEST: u32 = 4;
+
 // The meaning of the `mode` parameter is documented in motoko-base, function Float.format()
 #[ic_mem_fn]
-unsafe fn float_fmt<M: Memory>(mem: &mut M, a: f64, prec: u32, mode: u32) -> SkewedPtr {
-    // prec and mode are tagged (TODO (osa): what tag???)
+pub unsafe fn float_fmt<M: Memory>(mem: &mut M, a: f64, prec: u32, mode: u32) -> SkewedPtr {
+    // prec and mode are Nat8s, in the upper byte of their vanilla representation
     let mode = mode >> 24;
-    let prec = core::cmp::min(prec >> 24, 100) as usize;
-
-    // 110 bytes needed for max precision (TODO (osa): why? how?)
-    let buf = [0u8; 120];
-
-    // NB. Using snprintf because I think only 0 and 3 are supposed by Rust's built-in formatter
-    let fmt = match mode {
-        0 => "%.*f\0",
-        1 => "%.*e\0",
-        2 => "%.*g\0",
-        3 => "%.*a\0",
-        _ => panic!("float_fmt: unrecognized mode"),
+    let prec = prec >> 24;
+
+    if mode > FMT_SHORTEST {
+        rts_trap_with("float_fmt: unrecognized mode");
+    }
+
+    format_to_blob(mem, |out| format(out, a, prec, mode))
+}
+
+/// Formats an `f32` in the shortest representation that converts back to the same `f32`, as
+/// `float_fmt` does for an `f64` in `FMT_SHORTEST` mode. Returns a blob.
+pub unsafe fn float32_fmt_shortest<M: Memory>(mem: &mut M, a: f32) -> SkewedPtr {
+    format_to_blob(mem, |out| format_f32_shortest(out, a))
+}
+
+unsafe fn format_to_blob<M: Memory, F: Fn(&mut Output)>(mem: &mut M, format: F) -> SkewedPtr {
+    // Format twice: first to get the length, then to write the text
+    let mut out = Output {
+        buf: core::ptr::null_mut(),
+        len: 0,
+    };
+    format(&mut out);
+
+    let blob = alloc_blob(mem, Bytes(out.len));
+
+    let mut out = Output {
+        buf: blob.as_blob().payload_addr(),
+        len: 0,
+    };
+    format(&mut out);
+
+    blob
+}
+
+/// Formatter output. Bytes are only written when `buf` is not null, but always counted.
+struct Output {
+    buf: *mut u8,
+    len: u32,
+}
+
+impl Output {
+    unsafe fn push(&mut self, byte: u8) {
+        if !self.buf.is_null() {
+            *self.buf.add(self.len as usize) = byte;
+        }
+        self.len += 1;
+    }
+
+    unsafe fn push_str(&mut self, str: &str) {
+        for byte in str.bytes() {
+            self.push(byte);
+        }
+    }
+
+    unsafe fn push_digit(&mut self, digit: u8) {
+        self.push(b"0123456789abcdef"[digit as usize]);
+    }
+}
+
+unsafe fn format(out: &mut Output, f: f64, prec: u32, mode: u32) {
+    let (negative, mant, exp) = decompose(f);
+
+    if negative {
+        out.push(b'-');
+    }
+
+    if f.is_nan() {
+        out.push_str("nan");
+    } else if f.is_infinite() {
+        out.push_str("inf");
+    } else {
+        match mode {
+            FMT_FIXED => fmt_fixed(out, mant, exp, prec),
+            FMT_EXPONENT => fmt_exponent(out, mant, exp, prec),
+            FMT_GENERAL => fmt_general(out, mant, exp, prec),
+            FMT_HEX => fmt_hex(out, mant, exp, prec),
+            _ => fmt_shortest(out, mant, exp, F64),
+        }
+    }
+}
+
+unsafe fn format_f32_shortest(out: &mut Output, f: f32) {
+    let (negative, mant, exp) = decompose_f32(f);
+
+    if negative {
+        out.push(b'-');
+    }
+
+    if f.is_nan() {
+        out.push_str("nan");
+    } else if f.is_infinite() {
+        out.push_str("inf");
+    } else {
+        fmt_shortest(out, mant, exp, F32);
+    }
+}
+
+/// A binary float format, by the number of fraction bits and the exponent of the subnormals
+#[derive(Clone, Copy)]
+struct Binary {
+    fraction_bits: u32,
+    min_exp: i32,
+}
+
+const F64: Binary = Binary {
+    fraction_bits: 52,
+    min_exp: -1074,
+};
+
+const F32: Binary = Binary {
+    fraction_bits: 23,
+    min_exp: -149,
+};
+
+/// Splits a float into sign, mantissa and exponent, with `|f| = mant * 2 ** exp` for finite `f`
+fn decompose(f: f64) -> (bool, u64, i32) {
+    let bits = f.to_bits();
+    let negative = bits >> 63 != 0;
+    let biased_exp = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    if biased_exp == 0 {
+        // Subnormal
+        (negative, fraction, -1074)
+    } else {
+        (negative, fraction | (1 << 52), biased_exp - 1075)
+    }
+}
+
+/// Like `decompose`, for an `f32`
+fn decompose_f32(f: f32) -> (bool, u64, i32) {
+    let bits = f.to_bits();
+    let negative = bits >> 31 != 0;
+    let biased_exp = ((bits >> 23) & 0xFF) as i32;
+    let fraction = u64::from(bits & ((1 << 23) - 1));
+    if biased_exp == 0 {
+        // Subnormal
+        (negative, fraction, -149)
+    } else {
+        (negative, fraction | (1 << 23), biased_exp - 150)
+    }
+}
+
+unsafe fn fmt_fixed(out: &mut Output, mant: u64, exp: i32, prec: u32) {
+    let mut d = Decimal::exact(mant, exp);
+    d.round(d.point + prec as i32);
+    write_fixed(out, &d, prec);
+}
+
+unsafe fn fmt_exponent(out: &mut Output, mant: u64, exp: i32, prec: u32) {
+    let mut d = Decimal::exact(mant, exp);
+    d.round(prec as i32 + 1);
+    write_exponent(out, &d, prec);
+}
+
+unsafe fn fmt_general(out: &mut Output, mant: u64, exp: i32, prec: u32) {
+    let prec = core::cmp::max(prec, 1) as i32;
+
+    let mut d = Decimal::exact(mant, exp);
+    d.round(prec);
+
+    // Trailing zeros are not printed
+    let x = d.exponent();
+    if x < prec && x >= -4 {
+        let frac_digits = core::cmp::min(prec - 1 - x, d.len as i32 - d.point);
+        write_fixed(out, &d, core::cmp::max(frac_digits, 0) as u32);
+    } else {
+        let frac_digits = core::cmp::min(prec - 1, d.len as i32 - 1);
+        write_exponent(out, &d, core::cmp::max(frac_digits, 0) as u32);
+    }
+}
+
+unsafe fn fmt_shortest(out: &mut Output, mant: u64, exp: i32, binary: Binary) {
+    let d = shortest(mant, exp, binary);
+    let x = d.exponent();
+    if x < 17 && x >= -4 {
+        write_fixed(out, &d, core::cmp::max(d.len as i32 - d.point, 0) as u32);
+    } else {
+        write_exponent(out, &d, core::cmp::max(d.len as i32 - 1, 0) as u32);
+    }
+}
+
+/// Writes the number with `frac_digits` digits after the decimal point, assuming it is rounded
+unsafe fn write_fixed(out: &mut Output, d: &Decimal, frac_digits: u32) {
+    if d.point <= 0 {
+        out.push(b'0');
+    } else {
+        for exp in (0..d.point).rev() {
+            out.push_digit(d.digit(exp));
+        }
+    }
+
+    if frac_digits != 0 {
+        out.push(b'.');
+        for i in 1..=frac_digits {
+            out.push_digit(d.digit(-(i as i32)));
+        }
+    }
+}
+
+/// Writes the number in exponent notation with `frac_digits` digits after the decimal point,
+/// assuming it is rounded
+unsafe fn write_exponent(out: &mut Output, d: &Decimal, frac_digits: u32) {
+    let x = d.exponent();
+
+    out.push_digit(d.digit(x));
+    if frac_digits != 0 {
+        out.push(b'.');
+        for i in 1..=frac_digits {
+            out.push_digit(d.digit(x - i as i32));
+        }
+    }
+
+    out.push(b'e');
+    out.push(if x < 0 { b'-' } else { b'+' });
+    write_exponent_digits(out, x, 2);
+}
+
+/// Writes the absolute value of an exponent in decimal, with at least `min_digits` digits
+unsafe fn write_exponent_digits(out: &mut Output, x: i32, min_digits: u32) {
+    let x = (if x < 0 { -x } else { x }) as u32;
+    let mut n_digits = 1;
+    while n_digits < 4 && x >= 10u32.pow(n_digits) {
+        n_digits += 1;
+    }
+    for i in (0..core::cmp::max(n_digits, min_digits)).rev() {
+        out.push_digit((x / 10u32.pow(i) % 10) as u8);
+    }
+}
+
+unsafe fn fmt_hex(out: &mut Output, mant: u64, exp: i32, prec: u32) {
+    out.push_str("0x");
+
+    if mant == 0 {
+        out.push(b'0');
+        if prec != 0 {
+            out.push(b'.');
+            for _ in 0..prec {
+                out.push(b'0');
+            }
+        }
+        out.push_str("p+0");
+        return;
+    }
+
+    // Normalize subnormals, so that the number is `1.xxx * 2 ** exp` with 13 hexadecimal digits
+    // after the point
+    let mut mant = mant;
+    let mut exp = exp + 52;
+    while mant < 1 << 52 {
+        mant <<= 1;
+        exp -= 1;
+    }
+
+    // Round to `prec` digits, half to even. The leading digit may become 2.
+    let n_digits = core::cmp::min(prec, 13);
+    let dropped_bits = 4 * (13 - n_digits);
+    if dropped_bits != 0 {
+        let half = 1 << (dropped_bits - 1);
+        let rest = mant & ((1 << dropped_bits) - 1);
+        mant >>= dropped_bits;
+        if rest > half || (rest == half && mant % 2 == 1) {
+            mant += 1;
+        }
+    }
+
+    out.push_digit((mant >> (4 * n_digits)) as u8);
+    if prec != 0 {
+        out.push(b'.');
+        for i in (0..n_digits).rev() {
+            out.push_digit(((mant >> (4 * i)) & 0xF) as u8);
+        }
+        for _ in n_digits..prec {
+            out.push(b'0');
+        }
+    }
+
+    out.push(b'p');
+    out.push(if exp < 0 { b'-' } else { b'+' });
+    write_exponent_digits(out, exp, 1);
+}
+
+/// The shortest decimal that converts back to `mant * 2 ** exp` in the `binary` format (with
+/// `mant` as returned by `decompose`), closest to the float when there are several
+fn shortest(mant: u64, exp: i32, binary: Binary) -> Decimal {
+    let v = Decimal::exact(mant, exp);
+    if mant == 0 {
+        return v;
+    }
+
+    // Decimals strictly between the halfway points to the neighbouring floats convert to `v`. The
+    // lower neighbour is closer when `v` is a power of two, unless it's subnormal.
+    let low = if mant == 1 << binary.fraction_bits && exp > binary.min_exp {
+        Decimal::exact(4 * mant - 1, exp - 2)
+    } else {
+        Decimal::exact(2 * mant - 1, exp - 1)
     };
+    let high = Decimal::exact(2 * mant + 1, exp - 1);
+
+    // Halfway points convert to the float with the even mantissa
+    let inclusive = mant % 2 == 0;
+
+    for n_digits in 1..17 {
+        if v.len <= n_digits as usize {
+            return v;
+        }
+
+        // The decimals with `n_digits` digits closest to `v`. If any decimal with `n_digits`
+        // digits converts to `v`, one of these does.
+        let mut down = v;
+        down.truncate(n_digits);
+        let mut up = down;
+        up.round_up_at(n_digits);
+
+        let down_ok = match down.cmp(&low) {
+            Ordering::Greater => true,
+            Ordering::Equal => inclusive,
+            Ordering::Less => false,
+        };
+        let up_ok = match up.cmp(&high) {
+            Ordering::Less => true,
+            Ordering::Equal => inclusive,
+            Ordering::Greater => false,
+        };
+
+        match (down_ok, up_ok) {
+            (true, true) => {
+                let mut rounded = v;
+                rounded.round(n_digits);
+                return rounded;
+            }
+            (true, false) => return down,
+            (false, true) => return up,
+            (false, false) => {}
+        }
+    }
+
+    // 17 digits always identify a float
+    let mut rounded = v;
+    rounded.round(17);
+    rounded
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ParserState {
+    /// Before the sign and the mantissa
+    Start,
+    /// In the integer part of the mantissa
+    Integer,
+    /// In the fraction part of the mantissa
+    Fraction,
+    /// After the exponent marker, before its sign
+    ExponentStart,
+    /// In the exponent
+    Exponent,
+    /// In `inf`, `infinity` or `nan`
+    Word,
+}
+
+/// Exponents are saturated at this value while parsing, which is far beyond the range of floats
+const MAX_PARSED_EXPONENT: i32 = 1_000_000;
+
+/// State of `float_of_text`
+struct FloatParser {
+    state: ParserState,
+    negative: bool,
+    hex: bool,
+    /// Mantissa of decimal literals
+    decimal: Decimal,
+    /// Mantissa of hexadecimal literals, up to 15 significant digits
+    hex_mant: u64,
+    /// Whether non-zero hexadecimal digits after `hex_mant` were dropped
+    hex_sticky: bool,
+    /// Binary exponent of `hex_mant`
+    hex_exp: i32,
+    exp: i32,
+    exp_negative: bool,
+    /// Letters of `inf`, `infinity` or `nan`, lower case
+    word: [u8; 8],
+    word_len: usize,
+    /// Whether the mantissa or exponent being parsed has a digit
+    seen_digit: bool,
+    /// Whether the mantissa is a single `0` so far, which may be followed by `x`
+    zero_prefix: bool,
+    /// Whether the last byte was a digit
+    after_digit: bool,
+    /// Whether the last byte was a separator
+    after_separator: bool,
+    offset: u32,
+}
+
+impl FloatParser {
+    fn new() -> FloatParser {
+        FloatParser {
+            state: ParserState::Start,
+            negative: false,
+            hex: false,
+            decimal: Decimal::zero(),
+            hex_mant: 0,
+            hex_sticky: false,
+            hex_exp: 0,
+            exp: 0,
+            exp_negative: false,
+            word: [0; 8],
+            word_len: 0,
+            seen_digit: false,
+            zero_prefix: false,
+            after_digit: false,
+            after_separator: false,
+            offset: 0,
+        }
+    }
+
+    unsafe fn trap_at(&self, msg: &str) -> ! {
+        let mut buf = [0u8; 100];
+        let mut fmt = WriteBuf::new(&mut buf);
+        let _ = write!(&mut fmt, "float_of_text: {} at offset {}", msg, self.offset);
+        rts_trap_with(fmt.as_str())
+    }
+
+    fn digit_value(&self, byte: u8) -> Option<u32> {
+        let radix = match self.state {
+            ParserState::Word => return None,
+            ParserState::Integer | ParserState::Fraction if self.hex => 16,
+            _ => 10,
+        };
+        (byte as char).to_digit(radix)
+    }
+
+    unsafe fn byte(&mut self, byte: u8) {
+        let digit = self.digit_value(byte);
+        let zero_prefix = self.zero_prefix;
+        self.zero_prefix = false;
+
+        if byte == b'_' {
+            if !self.after_digit {
+                self.trap_at("misplaced separator");
+            }
+            self.after_digit = false;
+            self.after_separator = true;
+            self.offset += 1;
+            return;
+        }
+
+        if self.after_separator && digit.is_none() {
+            self.trap_at("misplaced separator");
+        }
+        self.after_digit = digit.is_some();
+        self.after_separator = false;
+
+        match (self.state, digit) {
+            (ParserState::Start, Some(digit)) => {
+                self.state = ParserState::Integer;
+                self.zero_prefix = digit == 0;
+                self.mantissa_digit(digit);
+            }
+            (ParserState::Start, None) if self.offset == 0 && (byte == b'+' || byte == b'-') => {
+                self.negative = byte == b'-';
+            }
+            (ParserState::Start, None) | (ParserState::Word, None)
+                if byte.is_ascii_alphabetic() =>
+            {
+                self.state = ParserState::Word;
+                let word_len = self.word_len;
+                if word_len == self.word.len() {
+                    self.trap_at("invalid character");
+                }
+                self.word[word_len] = byte.to_ascii_lowercase();
+                self.word_len += 1;
+                let word = &self.word[..self.word_len];
+                if !b"infinity".starts_with(word) && !b"nan".starts_with(word) {
+                    self.trap_at("invalid character");
+                }
+            }
+            (ParserState::Integer, Some(digit)) | (ParserState::Fraction, Some(digit)) => {
+                self.mantissa_digit(digit);
+            }
+            (ParserState::Integer, None) if zero_prefix && (byte == b'x' || byte == b'X') => {
+                self.hex = true;
+                self.seen_digit = false;
+            }
+            (ParserState::Integer, None) if byte == b'.' => {
+                if !self.seen_digit {
+                    self.trap_at("missing digits");
+                }
+                self.state = ParserState::Fraction;
+            }
+            (ParserState::Integer, None) | (ParserState::Fraction, None)
+                if (!self.hex && (byte == b'e' || byte == b'E'))
+                    || (self.hex && (byte == b'p' || byte == b'P')) =>
+            {
+                if !self.seen_digit {
+                    self.trap_at("missing digits");
+                }
+                self.state = ParserState::ExponentStart;
+                self.seen_digit = false;
+            }
+            (ParserState::ExponentStart, None) if byte == b'+' || byte == b'-' => {
+                self.state = ParserState::Exponent;
+                self.exp_negative = byte == b'-';
+            }
+            (ParserState::ExponentStart, Some(digit)) | (ParserState::Exponent, Some(digit)) => {
+                self.state = ParserState::Exponent;
+                self.seen_digit = true;
+                self.exp = core::cmp::min(self.exp * 10 + digit as i32, MAX_PARSED_EXPONENT);
+            }
+            _ => self.trap_at("invalid character"),
+        }
+
+        self.offset += 1;
+    }
+
+    fn mantissa_digit(&mut self, digit: u32) {
+        self.seen_digit = true;
+        let fraction = self.state == ParserState::Fraction;
+
+        if self.hex {
+            if self.hex_mant == 0 && digit == 0 {
+                // Leading zero
+                if fraction {
+                    self.hex_exp = self.hex_exp.saturating_sub(4);
+                }
+            } else if self.hex_mant >> 56 == 0 {
+                self.hex_mant = (self.hex_mant << 4) | u64::from(digit);
+                if fraction {
+                    self.hex_exp = self.hex_exp.saturating_sub(4);
+                }
+            } else {
+                self.hex_sticky |= digit != 0;
+                if !fraction {
+                    self.hex_exp = self.hex_exp.saturating_add(4);
+                }
+            }
+        } else {
+            let d = &mut self.decimal;
+            if d.len == 0 && digit == 0 {
+                // Leading zero
+                if fraction {
+                    d.point = d.point.saturating_sub(1);
+                }
+            } else {
+                if d.len < MAX_DIGITS {
+                    d.digits[d.len] = digit as u8;
+                    d.len += 1;
+                } else if digit != 0 {
+                    d.truncated = true;
+                }
+                if !fraction {
+                    d.point = d.point.saturating_add(1);
+                }
+            }
+        }
+    }
+
+    /// Feeds the bytes of a text to the parser, following concatenations without flattening them
+    unsafe fn text(&mut self, text: SkewedPtr) {
+        if text.tag() == TAG_BLOB {
+            let blob = text.as_blob();
+            for i in 0..blob.len().0 {
+                self.byte(blob.get(i));
+            }
+        } else {
+            let concat = text.as_concat();
+            self.text(concat.text1());
+            self.text(concat.text2());
+        }
+    }
+
+    unsafe fn finish(&mut self) -> f64 {
+        if self.after_separator {
+            self.trap_at("misplaced separator");
+        }
+
+        if !self.seen_digit && self.state != ParserState::Word {
+            self.trap_at(match self.state {
+                ParserState::ExponentStart | ParserState::Exponent => "missing exponent digits",
+                _ => "missing digits",
+            });
+        }
+
+        let exp = if self.exp_negative {
+            -self.exp
+        } else {
+            self.exp
+        };
+
+        let magnitude = match self.state {
+            ParserState::Word => match &self.word[..self.word_len] {
+                b"inf" | b"infinity" => f64::INFINITY,
+                b"nan" => f64::NAN,
+                _ => self.trap_at("unexpected end of text"),
+            },
+            _ if self.hex => hex_to_f64(
+                self.hex_mant,
+                self.hex_sticky,
+                self.hex_exp.saturating_add(exp),
+            ),
+            _ => {
+                let mut d = self.decimal;
+                d.strip_zeros();
+                d.point = d.point.saturating_add(exp);
+                d.to_f64()
+            }
+        };
+
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// Closest float to `mant * 2 ** exp`, half to even. `sticky` means that the mantissa is followed
+/// by non-zero bits.
+fn hex_to_f64(mant: u64, sticky: bool, exp: i32) -> f64 {
+    if mant == 0 {
+        return 0.0;
+    }
+
+    // One more bit stands in for the dropped bits, which are below all bits of `mant`
+    debug_assert!(mant >> 62 == 0);
+    let mant = (mant << 1) | u64::from(sticky);
+    let exp = exp.saturating_sub(1);
+
+    // The number is in [2 ** top, 2 ** (top + 1))
+    let top = exp.saturating_add(63 - mant.leading_zeros() as i32);
+    if top > 1023 {
+        return f64::INFINITY;
+    }
+
+    // Exponent of the last bit of the result, subnormals have fewer bits
+    let mut lsb = core::cmp::max(top - 52, -1074);
+    let shift = lsb.saturating_sub(exp);
+
+    let mut result = if shift <= 0 {
+        mant << -shift
+    } else if shift > 64 {
+        0
+    } else {
+        let mant = u128::from(mant);
+        let kept = (mant >> shift) as u64;
+        let rest = mant & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if rest > half || (rest == half && kept % 2 == 1) {
+            kept + 1
+        } else {
+            kept
+        }
+    };
+
+    // Rounding up may carry into the next exponent
+    if result == 1 << 53 {
+        result >>= 1;
+        lsb += 1;
+    }
+
+    if result >> 52 == 0 {
+        // Subnormal
+        f64::from_bits(result)
+    } else {
+        let biased_exp = lsb + 1075;
+        if biased_exp >= 0x7FF {
+            f64::INFINITY
+        } else {
+            f64::from_bits(((biased_exp as u64) << 52) | (result & ((1 << 52) - 1)))
+        }
+    }
+}
+
+/// Parses a float literal: a decimal number with an optional fraction and `e` exponent, a
+/// hexadecimal number (`0x`) with an optional fraction and `p` exponent (binary, written in
+/// decimal), `inf`, `infinity` or `nan`. All can have a `+` or `-` sign, digits can be separated
+/// with `_`, and letters can be upper or lower case. The result is correctly rounded.
+#[no_mangle]
+pub unsafe extern "C" fn float_of_text(text: SkewedPtr) -> f64 {
+    let mut parser = FloatParser::new();
+    parser.text(text);
+    parser.finish()
+}
+
+/// Number of 32-bit limbs needed for `mant * 5 ** 1076` and `mant * 2 ** 970` with
+/// `mant < 2 ** 55`, the largest numbers we convert to decimal
+const BIG_LIMBS: usize = 81;
+
+/// Unsigned integer, as far as needed for conversion to decimal
+struct Big {
+    /// Least significant limb first
+    limbs: [u32; BIG_LIMBS],
+    /// Number of used limbs, the most significant one is not zero
+    len: usize,
+}
+
+impl Big {
+    fn new(n: u64) -> Big {
+        let mut big = Big {
+            limbs: [0; BIG_LIMBS],
+            len: 2,
+        };
+        big.limbs[0] = n as u32;
+        big.limbs[1] = (n >> 32) as u32;
+        big.normalize();
+        big
+    }
+
+    fn normalize(&mut self) {
+        while self.len != 0 && self.limbs[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    fn mul_small(&mut self, x: u32) {
+        let mut carry = 0;
+        for limb in self.limbs[..self.len].iter_mut() {
+            let product = u64::from(*limb) * u64::from(x) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            self.limbs[self.len] = carry as u32;
+            self.len += 1;
+        }
+    }
+
+    /// Divides in place, returns the remainder
+    fn div_small(&mut self, x: u32) -> u32 {
+        let mut rem = 0;
+        for limb in self.limbs[..self.len].iter_mut().rev() {
+            let n = (rem << 32) | u64::from(*limb);
+            *limb = (n / u64::from(x)) as u32;
+            rem = n % u64::from(x);
+        }
+        self.normalize();
+        rem as u32
+    }
+}
+
+/// Maximum number of digits in a `Decimal`. The numbers in `Big` have at most 769 digits
+/// (`2 ** 55 * 5 ** 1076`), so they are exact. Parsed numbers may have more digits, which only
+/// matter for rounding when the rest is exactly half way between two floats.
+const MAX_DIGITS: usize = 800;
+
+/// Largest shift of a `Decimal` in one step, such that the intermediate values fit into `u64`
+const MAX_SHIFT: i32 = 60;
+
+/// A non-negative decimal `0.d[0] d[1] ... d[len - 1] * 10 ** point`, without leading and
+/// trailing zero digits. Zero has no digits.
+#[derive(Clone, Copy)]
+struct Decimal {
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    point: i32,
+    /// Whether non-zero digits after `digits` were dropped
+    truncated: bool,
+}
+
+impl Decimal {
+    fn zero() -> Decimal {
+        Decimal {
+            digits: [0; MAX_DIGITS],
+            len: 0,
+            point: 0,
+            truncated: false,
+        }
+    }
+
+    /// Exact value of `mant * 2 ** exp`
+    fn exact(mant: u64, exp: i32) -> Decimal {
+        let mut big = Big::new(mant);
+
+        // `mant * 2 ** exp = big * 10 ** -frac_digits`
+        let frac_digits = if exp >= 0 {
+            for _ in 0..exp / 31 {
+                big.mul_small(1 << 31);
+            }
+            big.mul_small(1 << (exp % 31));
+            0
+        } else {
+            let frac_digits = -exp;
+            for _ in 0..frac_digits / 13 {
+                big.mul_small(1_220_703_125); // 5 ** 13
+            }
+            big.mul_small(5u32.pow((frac_digits % 13) as u32));
+            frac_digits
+        };
+
+        // Split into base 10 ** 9 chunks, least significant first
+        let mut chunks = [0u32; MAX_DIGITS / 9 + 1];
+        let mut n_chunks = 0;
+        while big.len != 0 {
+            chunks[n_chunks] = big.div_small(1_000_000_000);
+            n_chunks += 1;
+        }
+
+        let mut d = Decimal::zero();
+
+        for (i, chunk) in chunks[..n_chunks].iter().rev().enumerate() {
+            for exp10 in (0..9).rev() {
+                let digit = (chunk / 10u32.pow(exp10) % 10) as u8;
+                // Skip leading zeros of the most significant chunk
+                if i != 0 || d.len != 0 || digit != 0 {
+                    d.digits[d.len] = digit;
+                    d.len += 1;
+                }
+            }
+        }
+
+        d.point = if d.len == 0 {
+            0
+        } else {
+            d.len as i32 - frac_digits
+        };
+        d.strip_zeros();
+        d
+    }
+
+    fn strip_zeros(&mut self) {
+        while self.len != 0 && self.digits[self.len - 1] == 0 {
+            self.len -= 1;
+        }
+    }
+
+    /// Decimal exponent of the first digit, as in exponent notation. 0 for zero.
+    fn exponent(&self) -> i32 {
+        if self.len == 0 {
+            0
+        } else {
+            self.point - 1
+        }
+    }
+
+    /// The digit with weight `10 ** exp`
+    fn digit(&self, exp: i32) -> u8 {
+        let idx = self.point - 1 - exp;
+        if idx >= 0 && (idx as usize) < self.len {
+            self.digits[idx as usize]
+        } else {
+            0
+        }
+    }
+
+    /// Drops all but the first `n_digits` digits, which may be negative
+    fn truncate(&mut self, n_digits: i32) {
+        if n_digits < self.len as i32 {
+            self.len = core::cmp::max(n_digits, 0) as usize;
+            self.strip_zeros();
+        }
+    }
+
+    /// Adds one unit of the digit at index `n_digits - 1`, for a number with at most `n_digits`
+    /// digits
+    fn round_up_at(&mut self, n_digits: i32) {
+        debug_assert!(n_digits >= 0 && self.len <= n_digits as usize);
+        let n_digits = n_digits as usize;
+
+        for digit in self.digits[self.len..n_digits].iter_mut() {
+            *digit = 0;
+        }
+        self.len = n_digits;
+
+        let mut idx = n_digits;
+        loop {
+            if idx == 0 {
+                // Carry out of the first digit, all digits were 9
+                self.digits[0] = 1;
+                self.len = 1;
+                self.point += 1;
+                return;
+            }
+            idx -= 1;
+            if self.digits[idx] == 9 {
+                self.digits[idx] = 0;
+            } else {
+                self.digits[idx] += 1;
+                break;
+            }
+        }
+
+        self.strip_zeros();
+    }
+
+    /// Rounds to `n_digits` digits, half to even. `n_digits` may be negative or larger than the
+    /// number of digits.
+    fn round(&mut self, n_digits: i32) {
+        if n_digits >= self.len as i32 {
+            return;
+        }
+
+        let round_up = n_digits >= 0 && self.rounds_up_at(n_digits as usize);
+
+        self.truncate(n_digits);
+        if round_up {
+            self.round_up_at(n_digits);
+        }
+    }
+
+    /// Whether rounding to `n_digits < len` digits, half to even, rounds up
+    fn rounds_up_at(&self, n_digits: usize) -> bool {
+        let next = self.digits[n_digits];
+        // There are no trailing zeros, so the number is above the half way point when there are
+        // more digits after `next`
+        next > 5
+            || (next == 5
+                && (n_digits + 1 < self.len
+                    || self.truncated
+                    || (n_digits > 0 && self.digits[n_digits - 1] % 2 == 1)))
+    }
+
+    /// The number rounded to an integer, half to even. The number needs to be less than
+    /// `10 ** 19`.
+    fn rounded_integer(&self) -> u64 {
+        debug_assert!(self.point <= 19);
+        let mut n = 0;
+        for exp in (0..core::cmp::max(self.point, 0)).rev() {
+            n = n * 10 + u64::from(self.digit(exp));
+        }
+        if self.point >= 0
+            && (self.point as usize) < self.len
+            && self.rounds_up_at(self.point as usize)
+        {
+            n += 1;
+        }
+        n
+    }
+
+    /// Multiplies by `2 ** shift`, which may be negative
+    fn shift(&mut self, shift: i32) {
+        let mut shift = shift;
+        while shift > MAX_SHIFT {
+            self.shift_left(MAX_SHIFT as u32);
+            shift -= MAX_SHIFT;
+        }
+        while shift < -MAX_SHIFT {
+            self.shift_right(MAX_SHIFT as u32);
+            shift += MAX_SHIFT;
+        }
+        if shift > 0 {
+            self.shift_left(shift as u32);
+        } else if shift < 0 {
+            self.shift_right(-shift as u32);
+        }
+    }
+
+    /// Multiplies by `2 ** k`, for `k <= MAX_SHIFT`
+    fn shift_left(&mut self, k: u32) {
+        // Multiplying by `2 ** 60` adds at most 19 digits. Digits are written from the end.
+        const EXTRA: usize = 19;
+        let mut buf = [0u8; MAX_DIGITS + EXTRA];
+        let end = self.len + EXTRA;
+        let mut w = end;
+
+        let mut n: u64 = 0;
+        for digit in self.digits[..self.len].iter().rev() {
+            n += u64::from(*digit) << k;
+            w -= 1;
+            buf[w] = (n % 10) as u8;
+            n /= 10;
+        }
+        while n != 0 {
+            w -= 1;
+            buf[w] = (n % 10) as u8;
+            n /= 10;
+        }
+
+        let new_len = end - w;
+        self.point += (new_len - self.len) as i32;
+        self.len = core::cmp::min(new_len, MAX_DIGITS);
+        if buf[w + self.len..end].iter().any(|digit| *digit != 0) {
+            self.truncated = true;
+        }
+        self.digits[..self.len].copy_from_slice(&buf[w..w + self.len]);
+        self.strip_zeros();
+    }
+
+    /// Divides by `2 ** k`, for `k <= MAX_SHIFT`
+    fn shift_right(&mut self, k: u32) {
+        let mut r = 0; // read index
+        let mut w = 0; // write index
+        let mut n: u64 = 0;
+
+        // Read digits until the first digit of the result is known
+        while n >> k == 0 {
+            if r >= self.len {
+                if n == 0 {
+                    return;
+                }
+                while n >> k == 0 {
+                    n *= 10;
+                    r += 1;
+                }
+                break;
+            }
+            n = n * 10 + u64::from(self.digits[r]);
+            r += 1;
+        }
+
+        self.point -= r as i32 - 1;
+
+        let mask = (1 << k) - 1;
+        while r < self.len {
+            let digit = n >> k;
+            n &= mask;
+            self.digits[w] = digit as u8;
+            w += 1;
+            n = n * 10 + u64::from(self.digits[r]);
+            r += 1;
+        }
+        while n != 0 {
+            let digit = n >> k;
+            n &= mask;
+            if w < MAX_DIGITS {
+                self.digits[w] = digit as u8;
+                w += 1;
+            } else if digit != 0 {
+                self.truncated = true;
+            }
+            n *= 10;
+        }
+
+        self.len = w;
+        self.strip_zeros();
+    }
+
+    /// Converts to the closest float, half to even
+    fn to_f64(mut self) -> f64 {
+        // Exponent bias of doubles
+        const BIAS: i32 = -1023;
+
+        if self.len == 0 || self.point < -330 {
+            return 0.0;
+        }
+        if self.point > 310 {
+            return f64::INFINITY;
+        }
+
+        // Scale into [1/2, 1) with powers of two, tracking the binary exponent. The table gives
+        // the largest shift that doesn't overshoot for a given decimal exponent.
+        const POWERS: [i32; 9] = [1, 3, 6, 9, 13, 16, 19, 23, 26];
+        let mut exp = 0;
+        while self.point > 0 {
+            let n = POWERS.get(self.point as usize).cloned().unwrap_or(27);
+            self.shift(-n);
+            exp += n;
+        }
+        while self.point < 0 || (self.point == 0 && self.digits[0] < 5) {
+            let n = POWERS.get(-self.point as usize).cloned().unwrap_or(27);
+            self.shift(n);
+            exp -= n;
+        }
+
+        // The number is now `2 * self * 2 ** exp`, with `2 * self` in [1, 2)
+        exp -= 1;
+
+        // Subnormals have fewer bits of precision
+        if exp < BIAS + 1 {
+            let n = BIAS + 1 - exp;
+            self.shift(-n);
+            exp += n;
+        }
+
+        if exp - BIAS >= 0x7FF {
+            return f64::INFINITY;
+        }
+
+        // The decimal is still in [1/2, 1), get 53 bits
+        self.shift(53);
+        let mut mant = self.rounded_integer();
+
+        // Rounding up may carry into the next exponent
+        if mant == 1 << 53 {
+            mant >>= 1;
+            exp += 1;
+            if exp - BIAS >= 0x7FF {
+                return f64::INFINITY;
+            }
+        }
+
+        if mant & (1 << 52) == 0 {
+            // Subnormal
+            exp = BIAS;
+        }
 
-    let n_written = libc::snprintf(
-        buf.as_ptr() as *mut _,
-        120,
-        fmt.as_ptr() as *const _,
-        prec,
-        a as libc::c_double,
-    );
+        f64::from_bits((mant & ((1 << 52) - 1)) | (((exp - BIAS) as u64) << 52))
+    }
 
-    assert!(n_written > 0);
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        if self.len == 0 || other.len == 0 {
+            return self.len.cmp(&other.len);
+        }
 
-    text_of_ptr_size(mem, buf.as_ptr(), Bytes(n_written as u32))
+        self.point
+            .cmp(&other.point)
+            .then_with(|| self.digits[..self.len].cmp(&other.digits[..other.len]))
+    }
 }
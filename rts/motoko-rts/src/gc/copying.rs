@@ -15,6 +15,7 @@ unsafe fn copying_gc<M: Memory>(mem: &mut M) {
         |hp| crate::memory::ic::HP = hp,
         crate::memory::ic::get_static_roots(),
         crate::closure_table::closure_table_loc(),
+        crate::text_index::text_index_cache_loc(),
         // note_live_size
         |live_size| {
             crate::memory::ic::MAX_LIVE = ::core::cmp::max(crate::memory::ic::MAX_LIVE, live_size)
@@ -37,6 +38,7 @@ pub unsafe fn copying_gc_internal<
     mut set_hp: SetHp,
     static_roots: SkewedPtr,
     closure_table_loc: *mut SkewedPtr,
+    text_index_cache_loc: *mut SkewedPtr,
     note_live_size: NoteLiveSize,
     note_reclaimed: NoteReclaimed,
 ) {
@@ -58,6 +60,15 @@ pub unsafe fn copying_gc_internal<
         );
     }
 
+    if (*text_index_cache_loc).unskew() >= begin_from_space {
+        evac(
+            mem,
+            begin_from_space,
+            begin_to_space,
+            text_index_cache_loc as usize,
+        );
+    }
+
     // Scavenge to-space
     let mut p = begin_to_space;
     while p < get_hp() {
@@ -27,6 +27,7 @@ unsafe fn compacting_gc<M: Memory>(mem: &mut M) {
         |hp| crate::memory::ic::HP = hp,
         crate::memory::ic::get_static_roots(),
         crate::closure_table::closure_table_loc(),
+        crate::text_index::text_index_cache_loc(),
         // note_live_size
         |live_size| {
             crate::memory::ic::MAX_LIVE = ::core::cmp::max(crate::memory::ic::MAX_LIVE, live_size)
@@ -49,6 +50,7 @@ pub unsafe fn compacting_gc_internal<
     set_hp: SetHp,
     static_roots: SkewedPtr,
     closure_table_ptr_loc: *mut SkewedPtr,
+    text_index_cache_ptr_loc: *mut SkewedPtr,
     note_live_size: NoteLiveSize,
     note_reclaimed: NoteReclaimed,
 ) {
@@ -61,6 +63,7 @@ pub unsafe fn compacting_gc_internal<
         old_hp,
         static_roots,
         closure_table_ptr_loc,
+        text_index_cache_ptr_loc,
     );
 
     let reclaimed = old_hp - (get_hp() as u32);
@@ -77,6 +80,7 @@ unsafe fn mark_compact<M: Memory, SetHp: Fn(u32)>(
     heap_end: u32,
     static_roots: SkewedPtr,
     closure_table_ptr_loc: *mut SkewedPtr,
+    text_index_cache_ptr_loc: *mut SkewedPtr,
 ) {
     let mem_size = Bytes(heap_end - heap_base);
 
@@ -93,6 +97,12 @@ unsafe fn mark_compact<M: Memory, SetHp: Fn(u32)>(
         thread(closure_table_ptr_loc);
     }
 
+    if (*text_index_cache_ptr_loc).unskew() >= heap_base as usize {
+        // Like the closure table, the location of the table is outside of the dynamic heap
+        mark_object(mem, *text_index_cache_ptr_loc, heap_base);
+        thread(text_index_cache_ptr_loc);
+    }
+
     mark_stack(mem, heap_base);
 
     update_refs(set_hp, heap_base);
@@ -222,13 +232,13 @@ unsafe fn unthread(obj: *mut Obj, new_loc: u32) {
     // NOTE: For this to work heap addresses need to be greater than the largest value for object
     // headers. Currently this holds. TODO: Document this better.
     let mut header = (*obj).tag;
-    while header > TAG_NULL {
-        // TODO: is `header > TAG_NULL` the best way to distinguish a tag from a pointer?
+    while header > TAG_TEXT_BUILDER {
+        // TODO: is `header > TAG_TEXT_BUILDER` the best way to distinguish a tag from a pointer?
         let tmp = (*(header as *mut Obj)).tag;
         (*(header as *mut SkewedPtr)) = skew(new_loc as usize);
         header = tmp;
     }
     // At the end of the chain is the original header for the object
-    debug_assert!(header >= TAG_OBJECT && header <= TAG_NULL);
+    debug_assert!(header >= TAG_OBJECT && header <= TAG_TEXT_BUILDER);
     (*obj).tag = header;
 }
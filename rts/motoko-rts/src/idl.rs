@@ -1,11 +1,19 @@
 #![allow(non_upper_case_globals)]
 
+pub mod budget;
+pub mod decode;
+pub mod fields;
+pub mod pretty;
+pub mod subtype;
+
 use crate::buf::{read_byte, read_word, skip_leb128, Buf};
-use crate::idl_trap_with;
-use crate::leb128::{leb128_decode, sleb128_decode};
+use crate::leb128::{leb128_decode, leb128_decode_checked, sleb128_decode, sleb128_decode_checked};
 use crate::memory::{alloc_blob, Memory};
-use crate::types::Words;
-use crate::utf8::utf8_validate;
+use crate::types::{size_of, Bytes, Words};
+use crate::utf8::{utf8_valid, utf8_validate, INVALID_UTF8};
+use crate::{idl_trap_with, rts_trap_with};
+use decode::DecodeError;
+use fields::FieldIndex;
 
 use core::cmp::min;
 
@@ -15,59 +23,174 @@ use motoko_rts_macros::ic_mem_fn;
 // IDL constants
 //
 
-const IDL_PRIM_null: i32 = -1;
-const IDL_PRIM_bool: i32 = -2;
-const IDL_PRIM_nat: i32 = -3;
-const IDL_PRIM_int: i32 = -4;
-const IDL_PRIM_nat8: i32 = -5;
-const IDL_PRIM_nat16: i32 = -6;
-const IDL_PRIM_nat32: i32 = -7;
-const IDL_PRIM_nat64: i32 = -8;
-const IDL_PRIM_int8: i32 = -9;
-const IDL_PRIM_int16: i32 = -10;
-const IDL_PRIM_int32: i32 = -11;
-const IDL_PRIM_int64: i32 = -12;
-const IDL_PRIM_float32: i32 = -13;
-const IDL_PRIM_float64: i32 = -14;
-const IDL_PRIM_text: i32 = -15;
-const IDL_PRIM_reserved: i32 = -16;
-const IDL_PRIM_empty: i32 = -17;
-
-const IDL_CON_opt: i32 = -18;
-const IDL_CON_vec: i32 = -19;
-const IDL_CON_record: i32 = -20;
-const IDL_CON_variant: i32 = -21;
-const IDL_CON_func: i32 = -22;
-const IDL_CON_service: i32 = -23;
-
-const IDL_REF_principal: i32 = -24;
-
-const IDL_CON_alias: i32 = 1;
-
-const IDL_PRIM_lowest: i32 = -17;
+pub const IDL_PRIM_null: i32 = -1;
+pub const IDL_PRIM_bool: i32 = -2;
+pub const IDL_PRIM_nat: i32 = -3;
+pub const IDL_PRIM_int: i32 = -4;
+pub const IDL_PRIM_nat8: i32 = -5;
+pub const IDL_PRIM_nat16: i32 = -6;
+pub const IDL_PRIM_nat32: i32 = -7;
+pub const IDL_PRIM_nat64: i32 = -8;
+pub const IDL_PRIM_int8: i32 = -9;
+pub const IDL_PRIM_int16: i32 = -10;
+pub const IDL_PRIM_int32: i32 = -11;
+pub const IDL_PRIM_int64: i32 = -12;
+pub const IDL_PRIM_float32: i32 = -13;
+pub const IDL_PRIM_float64: i32 = -14;
+pub const IDL_PRIM_text: i32 = -15;
+pub const IDL_PRIM_reserved: i32 = -16;
+pub const IDL_PRIM_empty: i32 = -17;
+
+pub const IDL_CON_opt: i32 = -18;
+pub const IDL_CON_vec: i32 = -19;
+pub const IDL_CON_record: i32 = -20;
+pub const IDL_CON_variant: i32 = -21;
+pub const IDL_CON_func: i32 = -22;
+pub const IDL_CON_service: i32 = -23;
+
+pub const IDL_REF_principal: i32 = -24;
+
+// Function annotations
+pub const IDL_FUNC_query: u8 = 1;
+pub const IDL_FUNC_oneway: u8 = 2;
+pub const IDL_FUNC_composite_query: u8 = 3;
+
+pub const IDL_CON_alias: i32 = 1;
+
+pub const IDL_PRIM_lowest: i32 = -17;
+
+/// A type table, as returned by `parse_idl_header`
+#[derive(Clone, Copy)]
+pub struct TypeTable {
+    /// Pointers to the type table entries
+    pub entries: *mut *mut u8,
+    /// End of the message the entries point into
+    pub end: *mut u8,
+    /// Number of entries
+    pub size: u32,
+}
 
 unsafe fn is_primitive_type(ty: i32) -> bool {
     ty < 0 && (ty >= IDL_PRIM_lowest || ty == IDL_REF_principal)
 }
 
-unsafe fn check_typearg(ty: i32, n_types: u32) {
-    // Arguments to type constructors can be primitive types or type indices
-    if !(is_primitive_type(ty) || (ty >= 0 && (ty as u32) < n_types)) {
-        idl_trap_with("invalid type argument");
+/// Reads a message header. Errors have the messages `parse_idl_header` traps with.
+struct HeaderReader {
+    buf: *mut Buf,
+    /// Error offsets are relative to this
+    start: *mut u8,
+}
+
+impl HeaderReader {
+    /// An error at the current position
+    unsafe fn error(&self, msg: &'static str) -> DecodeError {
+        self.error_at((*self.buf).ptr, msg)
+    }
+
+    unsafe fn error_at(&self, p: *mut u8, msg: &'static str) -> DecodeError {
+        DecodeError {
+            msg,
+            offset: p.offset_from(self.start) as u32,
+        }
+    }
+
+    unsafe fn byte(&self) -> Result<u8, DecodeError> {
+        if (*self.buf).ptr >= (*self.buf).end {
+            return Err(self.error("byte read out of buffer"));
+        }
+        Ok(read_byte(self.buf))
+    }
+
+    unsafe fn word(&self) -> Result<u32, DecodeError> {
+        if (*self.buf).ptr.add(3) >= (*self.buf).end {
+            return Err(self.error("word read out of buffer"));
+        }
+        Ok(read_word(self.buf))
+    }
+
+    unsafe fn advance(&self, n: u32) -> Result<(), DecodeError> {
+        if (*self.buf).ptr.add(n as usize) > (*self.buf).end {
+            return Err(self.error("advance out of buffer"));
+        }
+        self.buf.advance(n);
+        Ok(())
+    }
+
+    /// Checks that the (S)LEB128 number at the current position ends before the end of the
+    /// message, so that it can be read without trapping
+    unsafe fn check_leb128(&self) -> Result<(), DecodeError> {
+        let mut p = (*self.buf).ptr;
+        while p < (*self.buf).end {
+            if *p & 0b1000_0000 == 0 {
+                return Ok(());
+            }
+            p = p.add(1);
+        }
+        Err(self.error_at((*self.buf).end, "byte read out of buffer"))
+    }
+
+    unsafe fn leb128(&self) -> Result<u32, DecodeError> {
+        self.check_leb128()?;
+        let p = (*self.buf).ptr;
+        leb128_decode_checked(self.buf).ok_or_else(|| self.error_at(p, "leb128_decode: overflow"))
+    }
+
+    unsafe fn sleb128(&self) -> Result<i32, DecodeError> {
+        self.check_leb128()?;
+        let p = (*self.buf).ptr;
+        sleb128_decode_checked(self.buf).ok_or_else(|| self.error_at(p, "sleb128_decode: overflow"))
+    }
+
+    /// Reads an argument to a type constructor, which can be a primitive type or a type index
+    unsafe fn typearg(&self, n_types: u32) -> Result<i32, DecodeError> {
+        let p = (*self.buf).ptr;
+        let ty = self.sleb128()?;
+        if !(is_primitive_type(ty) || (ty >= 0 && (ty as u32) < n_types)) {
+            return Err(self.error_at(p, "invalid type argument"));
+        }
+        Ok(ty)
+    }
+}
+
+unsafe fn parse_func(r: &HeaderReader, n_types: u32) -> Result<(), DecodeError> {
+    // Arg types
+    for _ in 0..r.leb128()? {
+        r.typearg(n_types)?;
+    }
+
+    // Ret types
+    for _ in 0..r.leb128()? {
+        r.typearg(n_types)?;
+    }
+
+    // Annotations. The Candid spec ("Binary Format") only defines `T(query) = i8(1)`,
+    // `T(oneway) = i8(2)` and `T(composite_query) = i8(3)`, other bytes are not a type.
+    for _ in 0..r.leb128()? {
+        let p = (*r.buf).ptr;
+        let a = r.byte()?;
+        if !(IDL_FUNC_query <= a && a <= IDL_FUNC_composite_query) {
+            return Err(r.error_at(p, "func annotation not within 1..3"));
+        }
     }
+
+    Ok(())
 }
 
-unsafe fn parse_fields(buf: *mut Buf, n_types: u32) {
+/// Parses the fields of a record or variant type, returns the number of fields
+unsafe fn parse_fields(r: &HeaderReader, n_types: u32) -> Result<u32, DecodeError> {
+    let n_fields = r.leb128()?;
     let mut next_valid = 0;
-    for n in (1..=leb128_decode(buf)).rev() {
-        let tag = leb128_decode(buf);
+    for n in (1..=n_fields).rev() {
+        let p = (*r.buf).ptr;
+        let tag = r.leb128()?;
         if (tag < next_valid) || (tag == 0xFFFFFFFF && n > 1) {
-            idl_trap_with("variant or record tag out of order");
+            return Err(r.error_at(p, "variant or record tag out of order"));
         }
-        next_valid = tag + 1;
-        let t = sleb128_decode(buf);
-        check_typearg(t, n_types);
+        // Only the last tag can be 0xFFFFFFFF, so wrapping doesn't matter
+        next_valid = tag.wrapping_add(1);
+        r.typearg(n_types)?;
     }
+    Ok(n_fields)
 }
 
 // NB. This function assumes the allocation does not need to survive GC
@@ -75,6 +198,14 @@ unsafe fn alloc<M: Memory>(mem: &mut M, size: Words<u32>) -> *mut u8 {
     alloc_blob(mem, size.to_bytes()).as_blob().payload_addr()
 }
 
+/// A message header, as returned by `try_parse_idl_header`
+#[derive(Clone, Copy)]
+pub struct Header {
+    pub typtbl: TypeTable,
+    /// The number of main types, followed by the main types
+    pub main_types: *mut u8,
+}
+
 /// This function parses the IDL magic header and type description. It
 ///
 /// * traps if the type description is not well-formed. In particular, it traps if any index into
@@ -83,7 +214,8 @@ unsafe fn alloc<M: Memory>(mem: &mut M, size: Words<u32>) -> *mut u8 {
 /// * returns a pointer to the first byte after the IDL header
 ///
 /// * allocates a type description table, and returns it
-///   (via pointer argument, for lack of multi-value returns in C ABI)
+///   (via pointer argument, for lack of multi-value returns in C ABI). The table is preceded by
+///   an index of the record fields, used by `find_field`.
 ///
 /// * returns the size of that type description table
 ///   (again via pointer argument, for lack of multi-value returns in C ABI)
@@ -91,7 +223,7 @@ unsafe fn alloc<M: Memory>(mem: &mut M, size: Words<u32>) -> *mut u8 {
 /// * returns a pointer to the beginning of the list of main types
 ///   (again via pointer argument, for lack of multi-value returns in C ABI)
 #[ic_mem_fn]
-unsafe fn parse_idl_header<M: Memory>(
+pub unsafe fn parse_idl_header<M: Memory>(
     mem: &mut M,
     extended: bool,
     buf: *mut Buf,
@@ -99,83 +231,97 @@ unsafe fn parse_idl_header<M: Memory>(
     typtbl_size_out: *mut u32,
     main_types_out: *mut *mut u8,
 ) {
+    match try_parse_idl_header(mem, extended, buf) {
+        Ok(header) => {
+            *typtbl_out = header.typtbl.entries;
+            *typtbl_size_out = header.typtbl.size;
+            *main_types_out = header.main_types;
+        }
+        // Method names that are not UTF-8 are an RTS error, as with `utf8_validate`
+        Err(err) if err.msg == INVALID_UTF8 => rts_trap_with(err.msg),
+        Err(err) => idl_trap_with(err.msg),
+    }
+}
+
+/// Like `parse_idl_header`, but returns an error instead of trapping if the header is malformed.
+/// Error offsets are relative to the position of `buf` when called. `buf` is left at the first
+/// byte after the header.
+pub unsafe fn try_parse_idl_header<M: Memory>(
+    mem: &mut M,
+    extended: bool,
+    buf: *mut Buf,
+) -> Result<Header, DecodeError> {
+    let r = HeaderReader {
+        buf,
+        start: (*buf).ptr,
+    };
+
     if (*buf).ptr == (*buf).end {
-        idl_trap_with("empty input. Expected Candid-encoded argument, but received a zero-length argument");
+        return Err(r.error(
+            "empty input. Expected Candid-encoded argument, but received a zero-length argument",
+        ));
     }
 
+    budget::reset((*buf).end.offset_from((*buf).ptr) as u32);
+
     // Magic bytes (DIDL)
-    if read_word(buf) != 0x4C444944 {
-        idl_trap_with("missing magic bytes");
+    if r.word()? != 0x4C444944 {
+        return Err(r.error_at(r.start, "missing magic bytes"));
     }
 
     // Create a table for the type description
-    let n_types = leb128_decode(buf);
+    let n_types = r.leb128()?;
 
     // Early sanity check
     if (*buf).ptr.add(n_types as usize) >= (*buf).end {
-        idl_trap_with("too many types");
+        return Err(r.error("too many types"));
     }
 
-    // Let the caller know about the table size
-    *typtbl_size_out = n_types;
+    // Allocate the type table to be passed out, with space for the field index before the entries
+    let typtbl = alloc(mem, size_of::<FieldIndex>() + Words(n_types)) as *mut FieldIndex;
+    let typtbl = typtbl.add(1) as *mut *mut u8;
 
-    // Allocate the type table to be passed out
-    let typtbl: *mut *mut u8 = alloc(mem, Words(n_types)) as *mut _;
+    // Total number of record fields, for the field index
+    let mut n_fields = 0;
 
     // Go through the table
     for i in 0..n_types {
-        *typtbl.add(i as usize) = (*buf).ptr;
+        let p = (*buf).ptr;
+        *typtbl.add(i as usize) = p;
 
-        let ty = sleb128_decode(buf);
+        let ty = r.sleb128()?;
 
         if extended && ty == IDL_CON_alias {
             // internal
             // See Note [mutable stable values] in codegen/compile.ml
-            let t = sleb128_decode(buf);
-            check_typearg(t, n_types);
+            r.typearg(n_types)?;
         } else if ty >= 0 {
-            idl_trap_with("illegal type table"); // illegal
+            return Err(r.error_at(p, "illegal type table")); // illegal
         } else if is_primitive_type(ty) {
             // illegal
-            idl_trap_with("primitive type in type table");
+            return Err(r.error_at(p, "primitive type in type table"));
         } else if ty == IDL_CON_opt {
-            let t = sleb128_decode(buf);
-            check_typearg(t, n_types);
+            r.typearg(n_types)?;
         } else if ty == IDL_CON_vec {
-            let t = sleb128_decode(buf);
-            check_typearg(t, n_types);
+            r.typearg(n_types)?;
         } else if ty == IDL_CON_record {
-            parse_fields(buf, n_types);
+            n_fields += parse_fields(&r, n_types)?;
         } else if ty == IDL_CON_variant {
-            parse_fields(buf, n_types);
+            parse_fields(&r, n_types)?;
         } else if ty == IDL_CON_func {
-            // Arg types
-            for _ in 0..leb128_decode(buf) {
-                let t = sleb128_decode(buf);
-                check_typearg(t, n_types);
-            }
-            // Ret types
-            for _ in 0..leb128_decode(buf) {
-                let t = sleb128_decode(buf);
-                check_typearg(t, n_types);
-            }
-            // Annotations
-            for _ in 0..leb128_decode(buf) {
-                let a = read_byte(buf);
-                if !(1 <= a && a <= 2) {
-                    idl_trap_with("func annotation not within 1..2");
-                }
-            }
+            parse_func(&r, n_types)?;
         } else if ty == IDL_CON_service {
             let mut last_len: u32 = 0 as u32;
             let mut last_p = core::ptr::null_mut();
-            for _ in 0..leb128_decode(buf) {
+            for _ in 0..r.leb128()? {
                 // Name
-                let len = leb128_decode(buf);
+                let len = r.leb128()?;
                 let p = (*buf).ptr;
-                buf.advance(len);
+                r.advance(len)?;
                 // Method names must be valid unicode
-                utf8_validate(p as *const _, len);
+                if !utf8_valid(p as *const _, len) {
+                    return Err(r.error_at(p, INVALID_UTF8));
+                }
                 // Method names must be in order
                 if last_p != core::ptr::null_mut() {
                     let cmp = libc::memcmp(
@@ -184,27 +330,26 @@ unsafe fn parse_idl_header<M: Memory>(
                         min(last_len, len) as usize,
                     );
                     if cmp > 0 || (cmp == 0 && last_len >= len) {
-                        idl_trap_with("service method names out of order");
+                        return Err(r.error_at(p, "service method names out of order"));
                     }
                 }
                 last_len = len;
                 last_p = p;
 
                 // Type
-                let t = sleb128_decode(buf);
-                check_typearg(t, n_types);
+                r.typearg(n_types)?;
             }
         } else {
             // Future type
-            let n = leb128_decode(buf);
-            buf.advance(n);
+            let n = r.leb128()?;
+            r.advance(n)?;
         }
     }
 
     // Now that we have the indices, we can go through it again
     // and validate that all service method types are really function types
     // (We could not do that in the first run because of possible forward
-    // references
+    // references. The entries have been read once, so reading them again does not trap.)
     for i in 0..n_types {
         // do not modify the main buf
         let mut tmp_buf = Buf {
@@ -219,9 +364,10 @@ unsafe fn parse_idl_header<M: Memory>(
                 let len = leb128_decode(&mut tmp_buf);
                 Buf::advance(&mut tmp_buf, len);
                 // Type
+                let p = tmp_buf.ptr;
                 let t = sleb128_decode(&mut tmp_buf);
                 if !(t >= 0 && (t as u32) < n_types) {
-                    idl_trap_with("service method arg not a constructor type");
+                    return Err(r.error_at(p, "service method arg not a constructor type"));
                 }
                 let mut tmp_buf2 = Buf {
                     end: (*buf).end,
@@ -229,20 +375,29 @@ unsafe fn parse_idl_header<M: Memory>(
                 };
                 let mty = sleb128_decode(&mut tmp_buf2);
                 if mty != IDL_CON_func {
-                    idl_trap_with("service method arg not a function type");
+                    return Err(r.error_at(p, "service method arg not a function type"));
                 }
             }
         }
     }
 
+    let tt = TypeTable {
+        entries: typtbl,
+        end: (*buf).end,
+        size: n_types,
+    };
+    FieldIndex::of(typtbl).build(mem, tt, n_fields);
+
     // Now read the main types
-    *main_types_out = (*buf).ptr;
-    for _ in 0..leb128_decode(buf) {
-        let t = sleb128_decode(buf);
-        check_typearg(t, n_types);
+    let main_types = (*buf).ptr;
+    for _ in 0..r.leb128()? {
+        r.typearg(n_types)?;
     }
 
-    *typtbl_out = typtbl;
+    Ok(Header {
+        typtbl: tt,
+        main_types,
+    })
 }
 
 // used for opt, bool, references...
@@ -266,17 +421,167 @@ unsafe fn skip_text(buf: *mut Buf) {
     utf8_validate(p as *const _, len);
 }
 
+/// A composite value that `skip_any` is in the middle of skipping
+#[derive(Clone, Copy)]
+enum Frame {
+    /// An `opt`, `variant` or alias value, done when its only contained value is done
+    Single,
+    /// A `vec` value
+    Vec {
+        elem_ty: i32,
+        len: u32,
+        /// Number of elements left, including the one being skipped
+        left: u32,
+        /// Start of the first element, to detect zero-sized elements
+        first_elem: *mut u8,
+    },
+    /// A `record` value
+    Record {
+        ty: i32,
+        /// Points into the type table, after the type of the field being skipped
+        fields: *mut u8,
+        /// Number of fields left, including the one being skipped
+        left: u32,
+        /// Number of directly enclosing records, including this one
+        nesting: i32,
+    },
+}
+
+/// Number of frames `skip_any` keeps on the Rust stack. Values nested more deeply are rare, for
+/// those the frames are moved to the heap.
+const SKIP_STACK_INIT_LEN: usize = 32;
+
+/// The values being skipped by `skip_any`, innermost last
+struct SkipStack {
+    frames: *mut Frame,
+    cap: usize,
+    len: usize,
+}
+
+impl SkipStack {
+    unsafe fn frames(&self) -> &[Frame] {
+        core::slice::from_raw_parts(self.frames, self.len)
+    }
+
+    unsafe fn top(&mut self) -> Option<&mut Frame> {
+        core::slice::from_raw_parts_mut(self.frames, self.len).last_mut()
+    }
+
+    /// Pushes a frame, moving the frames to a heap object of twice the size when the stack is full.
+    /// The nesting depth of values is limited by the decoding budget, which bounds the size.
+    unsafe fn push<M: Memory>(&mut self, mem: &mut M, frame: Frame) {
+        if self.len == self.cap {
+            let cap = self.cap * 2;
+            let size = Bytes((cap * core::mem::size_of::<Frame>()) as u32);
+            let frames = alloc_blob(mem, size).as_blob().payload_addr() as *mut Frame;
+            core::ptr::copy_nonoverlapping(self.frames, frames, self.len);
+            self.frames = frames;
+            self.cap = cap;
+        }
+        *self.frames.add(self.len) = frame;
+        self.len += 1;
+    }
+
+    fn pop(&mut self) {
+        self.len -= 1;
+    }
+}
+
 // Assumes buf is the encoding of type t, and fast-forwards past that
 // Assumes all type references in the typtbl are already checked
 //
-// This is currently implemented recursively, but we could
-// do this in a loop (by maintaing a stack of the t arguments)
-#[no_mangle]
-unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth: i32) {
+// Contained values are skipped in a loop, using a `SkipStack` for the enclosing values, so that
+// deeply nested values do not overflow the Wasm stack.
+//
+// `depth` is the number of records directly enclosing the value, it is 0 when called from
+// generated code.
+#[ic_mem_fn]
+pub unsafe fn skip_any<M: Memory>(
+    mem: &mut M,
+    buf: *mut Buf,
+    typtbl: *mut *mut u8,
+    t: i32,
+    depth: i32,
+) {
     if depth > 100 {
         idl_trap_with("skip_any: too deeply nested record");
     }
 
+    let mut init_frames = [Frame::Single; SKIP_STACK_INIT_LEN];
+    let mut stack = SkipStack {
+        frames: init_frames.as_mut_ptr(),
+        cap: SKIP_STACK_INIT_LEN,
+        len: 0,
+    };
+    let mut t = t;
+
+    'values: loop {
+        budget::enter_value();
+
+        // Skip the value of type `t`, or push it on the stack and continue with its first contained
+        // value
+        if let Some((frame, first_ty)) = start_value(buf, typtbl, t, stack.frames(), depth) {
+            stack.push(mem, frame);
+            t = first_ty;
+            continue 'values;
+        }
+        budget::leave_value();
+
+        // The value is done, continue with the next value contained in the enclosing ones
+        while let Some(frame) = stack.top() {
+            match frame {
+                Frame::Single => {}
+                Frame::Vec {
+                    elem_ty,
+                    len,
+                    left,
+                    first_elem,
+                } => {
+                    // All elements have the same size, check the first one
+                    if *left == *len && (*buf).ptr == *first_elem {
+                        budget::check_zero_sized_vec_len(*len);
+                    }
+                    *left -= 1;
+                    if *left > 0 {
+                        t = *elem_ty;
+                        continue 'values;
+                    }
+                }
+                Frame::Record { fields, left, .. } => {
+                    *left -= 1;
+                    if *left > 0 {
+                        let mut tb = Buf {
+                            ptr: *fields,
+                            end: (*buf).end,
+                        };
+                        skip_leb128(&mut tb);
+                        t = sleb128_decode(&mut tb);
+                        *fields = tb.ptr;
+                        continue 'values;
+                    }
+                }
+            }
+
+            stack.pop();
+            budget::leave_value();
+        }
+
+        return;
+    }
+}
+
+/// Skips a value of type `t` that has no contained values. For values with contained values,
+/// returns the frame for the value and the type of the first contained value.
+///
+/// `enclosing` are the values enclosing this one, `depth` is the number of records enclosing
+/// all of them.
+unsafe fn start_value(
+    buf: *mut Buf,
+    typtbl: *mut *mut u8,
+    t: i32,
+    enclosing: &[Frame],
+    depth: i32,
+) -> Option<(Frame, i32)> {
     if t < 0 {
         // Primitive type
         match t {
@@ -312,94 +617,125 @@ unsafe extern "C" fn skip_any(buf: *mut Buf, typtbl: *mut *mut u8, t: i32, depth
                 idl_trap_with("skip_any: unknown prim");
             }
         }
-    } else {
-        // t >= 0
-        let mut tb = Buf {
-            ptr: *typtbl.add(t as usize),
-            end: (*buf).end,
-        };
-        let tc = sleb128_decode(&mut tb);
-        match tc {
-            IDL_CON_opt => {
-                let it = sleb128_decode(&mut tb);
-                if read_byte_tag(buf) != 0 {
-                    skip_any(buf, typtbl, it, 0);
-                }
+        return None;
+    }
+
+    // t >= 0
+    let mut tb = Buf {
+        ptr: *typtbl.add(t as usize),
+        end: (*buf).end,
+    };
+    let tc = sleb128_decode(&mut tb);
+    match tc {
+        IDL_CON_opt => {
+            let it = sleb128_decode(&mut tb);
+            if read_byte_tag(buf) != 0 {
+                return Some((Frame::Single, it));
             }
-            IDL_CON_vec => {
-                let it = sleb128_decode(&mut tb);
-                for _ in 0..leb128_decode(buf) {
-                    skip_any(buf, typtbl, it, 0);
-                }
+        }
+        IDL_CON_vec => {
+            let it = sleb128_decode(&mut tb);
+            let len = leb128_decode(buf);
+            if len > 0 {
+                let frame = Frame::Vec {
+                    elem_ty: it,
+                    len,
+                    left: len,
+                    first_elem: (*buf).ptr,
+                };
+                return Some((frame, it));
             }
-            IDL_CON_record => {
-                for _ in 0..leb128_decode(&mut tb) {
-                    skip_leb128(&mut tb);
-                    let it = sleb128_decode(&mut tb);
-                    // This is just a quick check; we should be keeping
-                    // track of all enclosing records to detect larger loops
-                    if it == t {
+        }
+        IDL_CON_record => {
+            // A record that directly contains itself has no finite values
+            for frame in enclosing.iter().rev() {
+                match *frame {
+                    Frame::Record { ty, .. } if ty == t => {
                         idl_trap_with("skip_any: recursive record");
                     }
-                    skip_any(buf, typtbl, it, depth + 1);
+                    Frame::Record { .. } => {}
+                    _ => break,
                 }
             }
-            IDL_CON_variant => {
-                let n = leb128_decode(&mut tb);
-                let i = leb128_decode(buf);
-                if i >= n {
-                    idl_trap_with("skip_any: variant tag too large");
-                }
-                for _ in 0..i {
-                    skip_leb128(&mut tb);
-                    skip_leb128(&mut tb);
+
+            let n = leb128_decode(&mut tb);
+            if n > 0 {
+                let nesting = match enclosing.last() {
+                    Some(Frame::Record { nesting, .. }) => nesting + 1,
+                    _ => depth + 1,
+                };
+                if nesting > 100 {
+                    idl_trap_with("skip_any: too deeply nested record");
                 }
+
                 skip_leb128(&mut tb);
                 let it = sleb128_decode(&mut tb);
-                skip_any(buf, typtbl, it, 0);
+                let frame = Frame::Record {
+                    ty: t,
+                    fields: tb.ptr,
+                    left: n,
+                    nesting,
+                };
+                return Some((frame, it));
             }
-            IDL_CON_func => {
-                if read_byte_tag(buf) == 0 {
-                    idl_trap_with("skip_any: skipping references");
-                } else {
-                    if read_byte_tag(buf) == 0 {
-                        idl_trap_with("skip_any: skipping references");
-                    } else {
-                        skip_blob(buf)
-                    }
-                    skip_text(buf)
-                }
+        }
+        IDL_CON_variant => {
+            let n = leb128_decode(&mut tb);
+            let i = leb128_decode(buf);
+            if i >= n {
+                idl_trap_with("skip_any: variant tag too large");
+            }
+            for _ in 0..i {
+                skip_leb128(&mut tb);
+                skip_leb128(&mut tb);
             }
-            IDL_CON_service => {
+            skip_leb128(&mut tb);
+            let it = sleb128_decode(&mut tb);
+            return Some((Frame::Single, it));
+        }
+        IDL_CON_func => {
+            if read_byte_tag(buf) == 0 {
+                idl_trap_with("skip_any: skipping references");
+            } else {
                 if read_byte_tag(buf) == 0 {
                     idl_trap_with("skip_any: skipping references");
                 } else {
                     skip_blob(buf)
                 }
+                skip_text(buf)
             }
-            IDL_CON_alias => {
-                // See Note [mutable stable values] in codegen/compile.ml
-                let it = sleb128_decode(&mut tb);
-                let tag = read_byte_tag(buf);
-                if tag == 0 {
-                    buf.advance(8);
-                    // this is the contents (not a reference)
-                    skip_any(buf, typtbl, it, 0);
-                } else {
-                    buf.advance(4);
-                }
+        }
+        IDL_CON_service => {
+            if read_byte_tag(buf) == 0 {
+                idl_trap_with("skip_any: skipping references");
+            } else {
+                skip_blob(buf)
             }
-            _ => {
-                // Future type
-                let n_data = leb128_decode(buf);
-                let n_ref = leb128_decode(buf);
-                buf.advance(n_data);
-                if n_ref > 0 {
-                    idl_trap_with("skip_any: skipping references");
-                }
+        }
+        IDL_CON_alias => {
+            // See Note [mutable stable values] in codegen/compile.ml
+            let it = sleb128_decode(&mut tb);
+            let tag = read_byte_tag(buf);
+            if tag == 0 {
+                buf.advance(8);
+                // this is the contents (not a reference)
+                return Some((Frame::Single, it));
+            } else {
+                buf.advance(4);
+            }
+        }
+        _ => {
+            // Future type
+            let n_data = leb128_decode(buf);
+            let n_ref = leb128_decode(buf);
+            buf.advance(n_data);
+            if n_ref > 0 {
+                idl_trap_with("skip_any: skipping references");
             }
         }
     }
+
+    None
 }
 
 /*
@@ -428,41 +764,57 @@ If the tag does not exist:
   b:     points at the value corresponding to that field
          or at the value past the record
   n:     the number of fields left, including the field pointed to by tb
+
+The field is looked up by binary search in the field index of the type table, see `FieldIndex`.
+Only the values of the skipped fields are read one by one.
 */
-#[no_mangle]
-unsafe extern "C" fn find_field(
+#[ic_mem_fn]
+pub unsafe fn find_field<M: Memory>(
+    mem: &mut M,
     tb: *mut Buf,
     buf: *mut Buf,
     typtbl: *mut *mut u8,
     tag: u32,
     n: *mut u8,
 ) -> u32 {
-    while *n > 0 {
-        let last_p = (*tb).ptr;
-        let this_tag = leb128_decode(tb);
-        if this_tag < tag {
-            let it = sleb128_decode(tb);
-            skip_any(buf, typtbl, it, 0);
-            *n -= 1;
-        } else if tag == this_tag {
-            *n -= 1;
-            return 1;
-        } else {
-            // Rewind reading tag
-            (*tb).ptr = last_p;
-            return 0;
-        }
+    if *n == 0 {
+        return 0;
+    }
+
+    let (found, i) = match FieldIndex::of(typtbl).lookup((*tb).ptr, u32::from(*n), tag) {
+        Ok(i) => (true, i),
+        Err(i) => (false, i),
+    };
+
+    // Skip the fields before
+    for _ in 0..i {
+        skip_leb128(tb);
+        let it = sleb128_decode(tb);
+        skip_any(mem, buf, typtbl, it, 0);
+        *n -= 1;
     }
 
-    0
+    if found {
+        skip_leb128(tb);
+        *n -= 1;
+        1
+    } else {
+        0
+    }
 }
 
-#[no_mangle]
-unsafe extern "C" fn skip_fields(tb: *mut Buf, buf: *mut Buf, typtbl: *mut *mut u8, n: *mut u8) {
+#[ic_mem_fn]
+pub unsafe fn skip_fields<M: Memory>(
+    mem: &mut M,
+    tb: *mut Buf,
+    buf: *mut Buf,
+    typtbl: *mut *mut u8,
+    n: *mut u8,
+) {
     while *n > 0 {
         skip_leb128(tb);
         let it = sleb128_decode(tb);
-        skip_any(buf, typtbl, it, 0);
+        skip_any(mem, buf, typtbl, it, 0);
         *n -= 1;
     }
 }
@@ -0,0 +1,112 @@
+//! Limits on the work done when skipping the values of a Candid message.
+//!
+//! Most values take at least one byte of the message, so the work done is bounded by the message
+//! size. Values of some types (`null`, `reserved`, records with only such fields) take no space,
+//! so a few bytes of a `vec null` can make us visit billions of values. To prevent this, every
+//! message gets a budget of values to visit, proportional to its size, and the length of vectors
+//! of zero-sized values and the nesting depth of values are limited.
+//!
+//! The budget is reset by `parse_idl_header`, which is called once for each message.
+
+use crate::idl_trap_with;
+
+/// Decoding limits, see `idl_set_limits`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Limits {
+    /// Number of values that can be visited per byte of the message
+    pub values_per_byte: u32,
+    /// Number of values that can be visited in every message, in addition to `values_per_byte`
+    pub values_base: u32,
+    /// Maximum length of a vector with elements that take no space in the message
+    pub max_zero_sized_vec_len: u32,
+    /// Maximum nesting depth of values
+    pub max_depth: u32,
+}
+
+impl Limits {
+    pub const DEFAULT: Limits = Limits {
+        values_per_byte: 8,
+        values_base: 1_000_000,
+        max_zero_sized_vec_len: 100_000,
+        max_depth: MAX_DEPTH,
+    };
+}
+
+/// Upper bound for `Limits::max_depth`. `skip_any` keeps a frame for each enclosing value, so this
+/// bounds the memory it uses.
+pub const MAX_DEPTH: u32 = 10_000;
+
+static mut LIMITS: Limits = Limits::DEFAULT;
+
+// Number of values that can still be visited in the current message
+static mut VALUES_LEFT: u32 = Limits::DEFAULT.values_base;
+
+// Nesting depth of the value being visited
+static mut DEPTH: u32 = 0;
+
+/// Sets the decoding limits, for generated code, see `set_limits`
+#[no_mangle]
+pub unsafe extern "C" fn idl_set_limits(
+    values_per_byte: u32,
+    values_base: u32,
+    max_zero_sized_vec_len: u32,
+    max_depth: u32,
+) {
+    set_limits(Limits {
+        values_per_byte,
+        values_base,
+        max_zero_sized_vec_len,
+        max_depth,
+    });
+}
+
+/// Sets the decoding limits for the following messages. `max_depth` is capped at `MAX_DEPTH`.
+pub unsafe fn set_limits(limits: Limits) {
+    LIMITS = Limits {
+        max_depth: core::cmp::min(limits.max_depth, MAX_DEPTH),
+        ..limits
+    };
+}
+
+pub unsafe fn limits() -> Limits {
+    LIMITS
+}
+
+/// Resets the budget for a message of the given size
+pub(crate) unsafe fn reset(message_size: u32) {
+    VALUES_LEFT = values_for(message_size);
+    DEPTH = 0;
+}
+
+/// Number of values that can be visited in a message of the given size
+pub(crate) unsafe fn values_for(message_size: u32) -> u32 {
+    LIMITS
+        .values_base
+        .saturating_add(message_size.saturating_mul(LIMITS.values_per_byte))
+}
+
+/// Called when we start visiting a value, traps when the number of values or the nesting depth
+/// exceeds the limits
+pub(crate) unsafe fn enter_value() {
+    if VALUES_LEFT == 0 {
+        idl_trap_with("decoding budget exceeded: too many values");
+    }
+    VALUES_LEFT -= 1;
+
+    if DEPTH >= LIMITS.max_depth {
+        idl_trap_with("decoding budget exceeded: values nested too deeply");
+    }
+    DEPTH += 1;
+}
+
+/// Called when we are done with a value started with `enter_value`
+pub(crate) unsafe fn leave_value() {
+    DEPTH -= 1;
+}
+
+/// Called for vectors with elements that take no space in the message
+pub(crate) unsafe fn check_zero_sized_vec_len(len: u32) {
+    if len > LIMITS.max_zero_sized_vec_len {
+        idl_trap_with("decoding budget exceeded: vector of zero-sized values too long");
+    }
+}
@@ -0,0 +1,472 @@
+//! Decodes Candid values into a tree of `Value`s, for tests and debugging tools that need to look
+//! into a message. Generated code does not use this, it decodes arguments into Motoko values
+//! itself.
+//!
+//! Unlike `skip_any`, the decoder does not trap on malformed values, but returns an error saying
+//! what is wrong and where, so it can also be used to validate messages, headers included.
+//!
+//! The tree is allocated on the heap, like the type table, and is only valid until the next GC.
+//! Texts, blobs and principals point into the message.
+
+use super::*;
+use crate::bigint::{bigint_leb128_decode, bigint_sleb128_decode};
+use crate::leb128::leb128_decode_checked;
+use crate::types::{Bytes, SkewedPtr};
+
+use core::mem::{align_of, size_of};
+
+/// Maximum nesting depth of decoded values. The decoder is recursive, so this is lower than
+/// `budget::MAX_DEPTH`.
+pub const MAX_DEPTH: u32 = 1_000;
+
+/// A decoded value
+#[derive(Clone, Copy)]
+pub enum Value<'a> {
+    Null,
+    Bool(bool),
+    Nat(SkewedPtr),
+    Int(SkewedPtr),
+    Nat8(u8),
+    Nat16(u16),
+    Nat32(u32),
+    Nat64(u64),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Float32(f32),
+    Float64(f64),
+    Text(&'a str),
+    Reserved,
+    /// `None` for an opaque reference
+    Principal(Option<&'a [u8]>),
+    Opt(Option<&'a Value<'a>>),
+    /// A `vec nat8`
+    Blob(&'a [u8]),
+    /// The decoded elements of a vector, all of them unless decoded with a limit, and the length
+    /// of the vector
+    Vec {
+        elems: &'a [Value<'a>],
+        len: u32,
+    },
+    /// Fields in the order of the type, i.e. sorted by id
+    Record(&'a [Field<'a>]),
+    Variant(&'a Field<'a>),
+    Func {
+        service: &'a [u8],
+        method: &'a str,
+    },
+    Service(&'a [u8]),
+    /// First occurrence of a mutable value in an extended message, see Note [mutable stable
+    /// values] in codegen/compile.ml
+    Alias(&'a Value<'a>),
+    /// Later occurrence of a mutable value, with the offset written by the encoder
+    AliasRef(i32),
+    /// Value of a type from a future version of Candid, as its data bytes
+    Future(&'a [u8]),
+}
+
+/// A record field or variant case
+#[derive(Clone, Copy)]
+pub struct Field<'a> {
+    pub id: u32,
+    pub value: Value<'a>,
+}
+
+/// A decoded message
+pub struct Message<'a> {
+    pub typtbl: TypeTable,
+    /// Types of the arguments
+    pub types: &'a [i32],
+    pub args: &'a [Value<'a>],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodeError {
+    pub msg: &'static str,
+    /// Offset in the message where the problem was found
+    pub offset: u32,
+}
+
+/// Decodes a whole message, header and arguments. Returns an error if the header or any argument
+/// is malformed, or there are bytes left after the arguments.
+///
+/// The returned tree can be used until the next GC, and while the message is alive.
+pub unsafe fn decode_message<'a, M: Memory>(
+    mem: &mut M,
+    buf: *mut Buf,
+    extended: bool,
+) -> Result<Message<'a>, DecodeError> {
+    let start = (*buf).ptr;
+
+    let Header { typtbl, main_types } = try_parse_idl_header(mem, extended, buf)?;
+
+    let mut decoder = Decoder::new(mem, typtbl, start, buf, u32::MAX);
+
+    // The main types are checked by `try_parse_idl_header`
+    let mut main_types = Buf {
+        ptr: main_types,
+        end: (*buf).end,
+    };
+    let n_args = leb128_decode(&mut main_types);
+    let types = decoder.alloc_array::<i32>(buf, n_args)?;
+    let args = decoder.alloc_array::<Value>(buf, n_args)?;
+    for i in 0..n_args as usize {
+        let t = sleb128_decode(&mut main_types);
+        *types.add(i) = t;
+        *args.add(i) = decoder.value(buf, t, 0)?;
+    }
+
+    if (*buf).ptr != (*buf).end {
+        return Err(decoder.error(buf, "bytes left after the arguments"));
+    }
+
+    Ok(Message {
+        typtbl,
+        types: core::slice::from_raw_parts(types, n_args as usize),
+        args: core::slice::from_raw_parts(args, n_args as usize),
+    })
+}
+
+/// Decodes a value of type `t` from `buf`, which is advanced past the value. Error offsets are
+/// relative to the position of `buf` when called.
+///
+/// Only the first `max_vec_elems` elements of a vector are decoded. The other elements are
+/// skipped with `skip_any`, which traps if they are malformed.
+///
+/// The returned tree can be used until the next GC, and while the message is alive.
+pub unsafe fn decode_value<'a, M: Memory>(
+    mem: &mut M,
+    buf: *mut Buf,
+    typtbl: TypeTable,
+    t: i32,
+    max_vec_elems: u32,
+) -> Result<Value<'a>, DecodeError> {
+    let mut decoder = Decoder::new(mem, typtbl, (*buf).ptr, buf, max_vec_elems);
+    decoder.value(buf, t, 0)
+}
+
+struct Decoder<'m, M: Memory> {
+    mem: &'m mut M,
+    typtbl: TypeTable,
+    /// Error offsets are relative to this
+    start: *mut u8,
+    /// Number of values that can still be decoded, as in `budget`
+    values_left: u32,
+    max_zero_sized_vec_len: u32,
+    max_depth: u32,
+    /// Number of elements decoded of a vector, the others are skipped
+    max_vec_elems: u32,
+}
+
+impl<'m, M: Memory> Decoder<'m, M> {
+    unsafe fn new(
+        mem: &'m mut M,
+        typtbl: TypeTable,
+        start: *mut u8,
+        buf: *mut Buf,
+        max_vec_elems: u32,
+    ) -> Self {
+        let limits = budget::limits();
+        let message_size = (*buf).end.offset_from(start) as u32;
+        Decoder {
+            mem,
+            typtbl,
+            start,
+            values_left: budget::values_for(message_size),
+            max_zero_sized_vec_len: limits.max_zero_sized_vec_len,
+            max_depth: min(limits.max_depth, MAX_DEPTH),
+            max_vec_elems,
+        }
+    }
+
+    /// An error at the current position
+    unsafe fn error(&self, buf: *mut Buf, msg: &'static str) -> DecodeError {
+        self.error_at((*buf).ptr, msg)
+    }
+
+    unsafe fn error_at(&self, p: *mut u8, msg: &'static str) -> DecodeError {
+        DecodeError {
+            msg,
+            offset: p.offset_from(self.start) as u32,
+        }
+    }
+
+    /// Allocates an uninitialized array on the heap
+    unsafe fn alloc_array<T>(&mut self, buf: *mut Buf, len: u32) -> Result<*mut T, DecodeError> {
+        let size = u64::from(len) * size_of::<T>() as u64 + align_of::<T>() as u64;
+        if size > u64::from(u32::MAX / 2) {
+            return Err(self.error(buf, "vector too long"));
+        }
+        let ptr = alloc(self.mem, Bytes(size as u32).to_words());
+        Ok(ptr.add(ptr.align_offset(align_of::<T>())) as *mut T)
+    }
+
+    unsafe fn alloc_value<'a>(
+        &mut self,
+        buf: *mut Buf,
+        value: Value<'a>,
+    ) -> Result<&'a Value<'a>, DecodeError> {
+        let ptr = self.alloc_array::<Value>(buf, 1)?;
+        *ptr = value;
+        Ok(&*ptr)
+    }
+
+    unsafe fn read_bytes<'a>(&self, buf: *mut Buf, n: u32) -> Result<&'a [u8], DecodeError> {
+        if ((*buf).end.offset_from((*buf).ptr) as u32) < n {
+            return Err(self.error(buf, "unexpected end of message"));
+        }
+        let bytes = core::slice::from_raw_parts((*buf).ptr, n as usize);
+        (*buf).ptr = (*buf).ptr.add(n as usize);
+        Ok(bytes)
+    }
+
+    /// Reads a little-endian number of `n` bytes
+    unsafe fn read_le(&self, buf: *mut Buf, n: u32) -> Result<u64, DecodeError> {
+        let bytes = self.read_bytes(buf, n)?;
+        Ok(bytes
+            .iter()
+            .rev()
+            .fold(0, |acc, byte| acc << 8 | u64::from(*byte)))
+    }
+
+    /// Reads a byte that has to be 0 or 1
+    unsafe fn read_tag(&self, buf: *mut Buf) -> Result<bool, DecodeError> {
+        let p = (*buf).ptr;
+        match self.read_bytes(buf, 1)? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(self.error_at(p, "byte tag not 0 or 1")),
+        }
+    }
+
+    /// Checks that the (S)LEB128 number at the current position ends before the end of the
+    /// message, so that it can be read without trapping
+    unsafe fn check_leb128(&self, buf: *mut Buf) -> Result<(), DecodeError> {
+        let mut p = (*buf).ptr;
+        while p < (*buf).end {
+            if *p & 0b1000_0000 == 0 {
+                return Ok(());
+            }
+            p = p.add(1);
+        }
+        Err(self.error(buf, "unexpected end of message"))
+    }
+
+    unsafe fn read_leb128(&self, buf: *mut Buf) -> Result<u32, DecodeError> {
+        self.check_leb128(buf)?;
+        let p = (*buf).ptr;
+        leb128_decode_checked(buf).ok_or_else(|| self.error_at(p, "leb128 overflow"))
+    }
+
+    unsafe fn read_blob<'a>(&self, buf: *mut Buf) -> Result<&'a [u8], DecodeError> {
+        let len = self.read_leb128(buf)?;
+        self.read_bytes(buf, len)
+    }
+
+    unsafe fn read_text<'a>(&self, buf: *mut Buf) -> Result<&'a str, DecodeError> {
+        let p = (*buf).ptr;
+        let bytes = self.read_blob(buf)?;
+        core::str::from_utf8(bytes).map_err(|_| self.error_at(p, "text is not valid UTF-8"))
+    }
+
+    /// Reads a reference to a service, which must not be opaque
+    unsafe fn read_ref<'a>(&self, buf: *mut Buf) -> Result<&'a [u8], DecodeError> {
+        let p = (*buf).ptr;
+        if !self.read_tag(buf)? {
+            return Err(self.error_at(p, "opaque references are not supported"));
+        }
+        self.read_blob(buf)
+    }
+
+    /// Decodes a value of type `t`, nested in `depth` other values
+    unsafe fn value<'a>(
+        &mut self,
+        buf: *mut Buf,
+        t: i32,
+        depth: u32,
+    ) -> Result<Value<'a>, DecodeError> {
+        if self.values_left == 0 {
+            return Err(self.error(buf, "too many values"));
+        }
+        self.values_left -= 1;
+        if depth >= self.max_depth {
+            return Err(self.error(buf, "values nested too deeply"));
+        }
+
+        if t < 0 {
+            return self.primitive(buf, t);
+        }
+
+        let start = (*buf).ptr;
+
+        // Type table entries are checked by `parse_idl_header`, so we can read them with the
+        // trapping functions
+        let mut tb = Buf {
+            ptr: *self.typtbl.entries.add(t as usize),
+            end: self.typtbl.end,
+        };
+        let value = match sleb128_decode(&mut tb) {
+            IDL_CON_opt => {
+                let it = sleb128_decode(&mut tb);
+                if self.read_tag(buf)? {
+                    let value = self.value(buf, it, depth + 1)?;
+                    Value::Opt(Some(self.alloc_value(buf, value)?))
+                } else {
+                    Value::Opt(None)
+                }
+            }
+            IDL_CON_vec => {
+                let it = sleb128_decode(&mut tb);
+                if it == IDL_PRIM_nat8 {
+                    Value::Blob(self.read_blob(buf)?)
+                } else {
+                    self.vec(buf, it, depth)?
+                }
+            }
+            IDL_CON_record => {
+                let n = leb128_decode(&mut tb);
+                let fields = self.alloc_array::<Field>(buf, n)?;
+                for i in 0..n as usize {
+                    let id = leb128_decode(&mut tb);
+                    let it = sleb128_decode(&mut tb);
+                    let value = self.value(buf, it, depth + 1)?;
+                    *fields.add(i) = Field { id, value };
+                }
+                Value::Record(core::slice::from_raw_parts(fields, n as usize))
+            }
+            IDL_CON_variant => {
+                let n = leb128_decode(&mut tb);
+                let i = self.read_leb128(buf)?;
+                if i >= n {
+                    return Err(self.error_at(start, "variant index out of range"));
+                }
+                for _ in 0..i {
+                    skip_leb128(&mut tb);
+                    skip_leb128(&mut tb);
+                }
+                let id = leb128_decode(&mut tb);
+                let it = sleb128_decode(&mut tb);
+                let value = self.value(buf, it, depth + 1)?;
+                let field = self.alloc_array::<Field>(buf, 1)?;
+                *field = Field { id, value };
+                Value::Variant(&*field)
+            }
+            IDL_CON_func => {
+                if !self.read_tag(buf)? {
+                    return Err(self.error_at(start, "opaque references are not supported"));
+                }
+                let service = self.read_ref(buf)?;
+                let method = self.read_text(buf)?;
+                Value::Func { service, method }
+            }
+            IDL_CON_service => Value::Service(self.read_ref(buf)?),
+            IDL_CON_alias => {
+                // See Note [mutable stable values] in codegen/compile.ml
+                let it = sleb128_decode(&mut tb);
+                if self.read_tag(buf)? {
+                    Value::AliasRef(self.read_le(buf, 4)? as i32)
+                } else {
+                    // Space for the pointer to the decoded value, then the contents
+                    self.read_bytes(buf, 8)?;
+                    let value = self.value(buf, it, depth + 1)?;
+                    Value::Alias(self.alloc_value(buf, value)?)
+                }
+            }
+            _ => {
+                // Future type
+                let n_data = self.read_leb128(buf)?;
+                let n_refs = self.read_leb128(buf)?;
+                let data = self.read_bytes(buf, n_data)?;
+                if n_refs > 0 {
+                    return Err(self.error_at(start, "opaque references are not supported"));
+                }
+                Value::Future(data)
+            }
+        };
+
+        Ok(value)
+    }
+
+    unsafe fn primitive<'a>(&mut self, buf: *mut Buf, t: i32) -> Result<Value<'a>, DecodeError> {
+        let value = match t {
+            IDL_PRIM_null => Value::Null,
+            IDL_PRIM_bool => Value::Bool(self.read_tag(buf)?),
+            IDL_PRIM_nat => {
+                self.check_leb128(buf)?;
+                Value::Nat(bigint_leb128_decode(buf))
+            }
+            IDL_PRIM_int => {
+                self.check_leb128(buf)?;
+                Value::Int(bigint_sleb128_decode(buf))
+            }
+            IDL_PRIM_nat8 => Value::Nat8(self.read_le(buf, 1)? as u8),
+            IDL_PRIM_nat16 => Value::Nat16(self.read_le(buf, 2)? as u16),
+            IDL_PRIM_nat32 => Value::Nat32(self.read_le(buf, 4)? as u32),
+            IDL_PRIM_nat64 => Value::Nat64(self.read_le(buf, 8)?),
+            IDL_PRIM_int8 => Value::Int8(self.read_le(buf, 1)? as i8),
+            IDL_PRIM_int16 => Value::Int16(self.read_le(buf, 2)? as i16),
+            IDL_PRIM_int32 => Value::Int32(self.read_le(buf, 4)? as i32),
+            IDL_PRIM_int64 => Value::Int64(self.read_le(buf, 8)? as i64),
+            IDL_PRIM_float32 => Value::Float32(f32::from_bits(self.read_le(buf, 4)? as u32)),
+            IDL_PRIM_float64 => Value::Float64(f64::from_bits(self.read_le(buf, 8)?)),
+            IDL_PRIM_text => Value::Text(self.read_text(buf)?),
+            IDL_PRIM_reserved => Value::Reserved,
+            IDL_PRIM_empty => return Err(self.error(buf, "value of type empty")),
+            IDL_REF_principal => {
+                if self.read_tag(buf)? {
+                    Value::Principal(Some(self.read_blob(buf)?))
+                } else {
+                    Value::Principal(None)
+                }
+            }
+            _ => return Err(self.error(buf, "unknown primitive type")),
+        };
+        Ok(value)
+    }
+
+    /// Decodes a vector with element type `t`, up to `max_vec_elems` elements
+    unsafe fn vec<'a>(
+        &mut self,
+        buf: *mut Buf,
+        t: i32,
+        depth: u32,
+    ) -> Result<Value<'a>, DecodeError> {
+        let start = (*buf).ptr;
+        let len = self.read_leb128(buf)?;
+        if len == 0 {
+            return Ok(Value::Vec { elems: &[], len });
+        }
+
+        // Check the length before allocating. Values of a type either take no space or at least
+        // one byte, the first element tells which.
+        let first_start = (*buf).ptr;
+        let first = self.value(buf, t, depth + 1)?;
+        if (*buf).ptr == first_start {
+            if len > self.max_zero_sized_vec_len {
+                return Err(self.error_at(start, "vector of zero-sized values too long"));
+            }
+        } else if len - 1 > (*buf).end.offset_from((*buf).ptr) as u32 {
+            return Err(self.error_at(start, "vector longer than the rest of the message"));
+        }
+
+        let n_elems = min(len, self.max_vec_elems);
+        let elems = self.alloc_array::<Value>(buf, n_elems)?;
+        if n_elems > 0 {
+            *elems = first;
+        }
+        for i in 1..n_elems as usize {
+            *elems.add(i) = self.value(buf, t, depth + 1)?;
+        }
+
+        // The other elements are not shown, skip them without allocating
+        for _ in n_elems.max(1)..len {
+            skip_any(self.mem, buf, self.typtbl.entries, t, 0);
+        }
+
+        Ok(Value::Vec {
+            elems: core::slice::from_raw_parts(elems, n_elems as usize),
+            len,
+        })
+    }
+}
@@ -0,0 +1,159 @@
+//! Candid field ids. Fields of records and variants are identified by numbers, which for named
+//! fields are hashes of the names. This module computes the hashes, and finds record fields in the
+//! type table by id.
+
+use super::*;
+use crate::print::WriteBuf;
+use crate::text::{blob_of_text, text_compare};
+use crate::types::SkewedPtr;
+
+use core::fmt::Write;
+
+/// Candid hash of a field name
+pub fn idl_hash(name: &[u8]) -> u32 {
+    name.iter()
+        .fold(0, |h, b| h.wrapping_mul(223).wrapping_add(u32::from(*b)))
+}
+
+/// Candid hash of a field name given as `Text`. Concatenations can be nested too deeply to walk
+/// recursively, so the text is flattened first.
+#[ic_mem_fn]
+pub unsafe fn idl_hash_text<M: Memory>(mem: &mut M, text: SkewedPtr) -> u32 {
+    let blob = blob_of_text(mem, text).as_blob();
+    idl_hash(core::slice::from_raw_parts(
+        blob.payload_addr(),
+        blob.len().as_usize(),
+    ))
+}
+
+/// Finds two different names with the same hash in an array of `Text`s, returns their indices.
+/// Names that occur more than once don't collide with themselves.
+pub unsafe fn find_hash_collision<M: Memory>(mem: &mut M, names: SkewedPtr) -> Option<(u32, u32)> {
+    let names = names.as_array();
+    let n = names.len();
+
+    // Hashes with the indices of the names, sorted
+    let hashes = alloc(mem, Words(2 * n)) as *mut (u32, u32);
+    let hashes = core::slice::from_raw_parts_mut(hashes, n as usize);
+    for i in 0..n {
+        hashes[i as usize] = (idl_hash_text(mem, names.get(i)), i);
+    }
+    hashes.sort_unstable();
+
+    // If the names with a hash are not all the same, two adjacent ones are different
+    for pair in hashes.windows(2) {
+        let ((hash1, i), (hash2, j)) = (pair[0], pair[1]);
+        if hash1 == hash2 && text_compare(names.get(i), names.get(j)) != 0 {
+            return Some((i, j));
+        }
+    }
+
+    None
+}
+
+/// Traps if two different names in an array of `Text`s have the same hash, and so cannot be the
+/// names of fields of the same record or variant
+#[ic_mem_fn]
+pub unsafe fn idl_check_field_hashes<M: Memory>(mem: &mut M, names: SkewedPtr) {
+    if let Some((i, j)) = find_hash_collision(mem, names) {
+        let name1 = blob_of_text(mem, names.as_array().get(i));
+        let name2 = blob_of_text(mem, names.as_array().get(j));
+
+        let mut buf = [0u8; 300];
+        let mut msg = WriteBuf::new(&mut buf);
+        let _ = write!(
+            &mut msg,
+            "field names \"{}\" and \"{}\" have the same hash",
+            prefix(name1, 100),
+            prefix(name2, 100)
+        );
+        idl_trap_with(msg.as_str());
+    }
+}
+
+/// The first `max_len` bytes of a text blob, rounded down to a character boundary
+unsafe fn prefix<'a>(blob: SkewedPtr, max_len: usize) -> &'a str {
+    let blob = blob.as_blob();
+    let bytes = core::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    let str = core::str::from_utf8_unchecked(bytes);
+    let mut len = min(str.len(), max_len);
+    while !str.is_char_boundary(len) {
+        len -= 1;
+    }
+    &str[..len]
+}
+
+/// Addresses of the ids of all record fields in a type table. Records are parsed in order and
+/// their fields are sorted by id, so a field of a record is found by binary search for its
+/// address, and the following fields of the record by binary search for their ids.
+///
+/// `parse_idl_header` stores the index right before the type table entries.
+#[repr(C)]
+pub(crate) struct FieldIndex {
+    fields: *mut *mut u8,
+    len: u32,
+    end: *mut u8,
+}
+
+impl FieldIndex {
+    /// Index of the type table returned by `parse_idl_header`
+    pub(crate) unsafe fn of<'a>(typtbl: *mut *mut u8) -> &'a mut FieldIndex {
+        &mut *(typtbl as *mut FieldIndex).sub(1)
+    }
+
+    /// Collects the fields of the records in the type table, which has `n_fields` record fields
+    /// in total
+    pub(crate) unsafe fn build<M: Memory>(&mut self, mem: &mut M, tt: TypeTable, n_fields: u32) {
+        self.fields = alloc(mem, Words(n_fields)) as *mut *mut u8;
+        self.len = n_fields;
+        self.end = tt.end;
+
+        let mut next = 0;
+        for i in 0..tt.size {
+            let mut tb = Buf {
+                ptr: *tt.entries.add(i as usize),
+                end: tt.end,
+            };
+            if sleb128_decode(&mut tb) != IDL_CON_record {
+                continue;
+            }
+            for _ in 0..leb128_decode(&mut tb) {
+                *self.fields.add(next) = tb.ptr;
+                next += 1;
+                skip_leb128(&mut tb);
+                skip_leb128(&mut tb);
+            }
+        }
+        debug_assert_eq!(next, n_fields as usize);
+    }
+
+    unsafe fn fields<'a>(&self) -> &'a [*mut u8] {
+        core::slice::from_raw_parts(self.fields, self.len as usize)
+    }
+
+    /// Finds a field among the `n` fields of a record starting at address `first`. Returns the
+    /// position of the field with the id, or of the first field with a larger id if there is no
+    /// such field, in the `Err` case, like `binary_search`.
+    pub(crate) unsafe fn lookup(&self, first: *mut u8, n: u32, id: u32) -> Result<u32, u32> {
+        let fields = self.fields();
+        let start = match fields.binary_search(&first) {
+            Ok(start) => start,
+            Err(_) => idl_trap_with("find_field: not at a record field"),
+        };
+        let fields = &fields[start..min(start + n as usize, fields.len())];
+
+        match fields.binary_search_by(|field| self.field_id(*field).cmp(&id)) {
+            Ok(i) => Ok(i as u32),
+            Err(i) => Err(i as u32),
+        }
+    }
+
+    /// Id of the record field at the given address
+    unsafe fn field_id(&self, field: *mut u8) -> u32 {
+        let mut tb = Buf {
+            ptr: field,
+            end: self.end,
+        };
+        leb128_decode(&mut tb)
+    }
+}
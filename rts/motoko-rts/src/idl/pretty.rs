@@ -0,0 +1,472 @@
+//! Renders Candid messages in the textual Candid syntax, for trap and log messages. For example,
+//! a message with a list of two numbers and a `nat8` renders as
+//!
+//! ```text
+//! type t0 = opt t1; type t1 = record { nat; t0 }; (opt record { 1; opt record { 2; null } } : t0, 42 : nat8)
+//! ```
+//!
+//! Every type table entry is shown as a type definition. Field names are not in the message, so
+//! fields are shown with their ids (the hashes of the names), except in tuples. Long vectors,
+//! blobs and texts are cut short, and the output stops at a maximum length.
+
+use super::decode::{decode_value, Field, Value};
+use super::*;
+use crate::bigint::bigint_to_text;
+use crate::float::{float32_fmt_shortest, float_fmt, FMT_SHORTEST};
+use crate::mem_utils::memcpy_bytes;
+use crate::principal_id::principal_of_blob;
+use crate::text::blob_of_text;
+use crate::text_builder::{text_builder_freeze, text_builder_new, TextBuilderWriter};
+use crate::types::{Bytes, SkewedPtr};
+
+use core::fmt::{self, Write};
+
+/// Limits on the size of the rendered message
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextLimits {
+    /// Number of elements shown of a vector
+    pub max_vec_elems: u32,
+    /// Number of bytes shown of a blob
+    pub max_blob_bytes: u32,
+    /// Number of bytes shown of a text, rounded down to a character boundary
+    pub max_text_bytes: u32,
+    /// Length of the output in bytes. Longer outputs are cut off and end with `…`, which is not
+    /// included in the limit.
+    pub max_len: u32,
+}
+
+impl TextLimits {
+    pub const DEFAULT: TextLimits = TextLimits {
+        max_vec_elems: 20,
+        max_blob_bytes: 64,
+        max_text_bytes: 200,
+        max_len: 10_000,
+    };
+}
+
+const ELLIPSIS: &str = "…";
+
+/// Renders the Candid message in a blob as `Text`, with the default limits. Traps if the header of
+/// the message, or a vector element that is not shown, is malformed.
+#[ic_mem_fn]
+pub unsafe fn idl_to_text<M: Memory>(mem: &mut M, blob: SkewedPtr) -> SkewedPtr {
+    let builder = text_builder_new(mem);
+    let mut out = TextBuilderWriter { mem, builder };
+
+    let blob = blob.as_blob();
+    let mut buf = Buf {
+        ptr: blob.payload_addr(),
+        end: blob.payload_addr().add(blob.len().as_usize()),
+    };
+    write_message(mem, &mut out, &mut buf, false, &TextLimits::DEFAULT);
+
+    text_builder_freeze(mem, builder)
+}
+
+/// Renders the message in `buf` to `out`. Traps if the header of the message is malformed.
+/// Malformed values are rendered as a comment with the decoding error, except for vector elements
+/// that are not shown, which are skipped with `skip_any` and trap if malformed.
+pub unsafe fn write_message<M: Memory, W: Write>(
+    mem: &mut M,
+    out: &mut W,
+    buf: *mut Buf,
+    extended: bool,
+    limits: &TextLimits,
+) {
+    let start = (*buf).ptr;
+
+    let mut entries = core::ptr::null_mut();
+    let mut size = 0;
+    let mut main_types = core::ptr::null_mut();
+    parse_idl_header(mem, extended, buf, &mut entries, &mut size, &mut main_types);
+    let typtbl = TypeTable {
+        entries,
+        end: (*buf).end,
+        size,
+    };
+
+    let mut printer = Printer {
+        mem,
+        out,
+        bytes_left: limits.max_len,
+        limits,
+        typtbl,
+    };
+    let main_types = Buf {
+        ptr: main_types,
+        end: (*buf).end,
+    };
+    if printer.message(buf, start, main_types).is_err() {
+        let _ = printer.out.write_str(ELLIPSIS);
+    }
+}
+
+struct Printer<'a, M: Memory, W: Write> {
+    mem: &'a mut M,
+    out: &'a mut W,
+    /// Number of bytes that can still be written
+    bytes_left: u32,
+    limits: &'a TextLimits,
+    typtbl: TypeTable,
+}
+
+/// Writes to the output until `max_len` is reached, then fails, which stops the rendering
+impl<'a, M: Memory, W: Write> Write for Printer<'a, M, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.len() <= self.bytes_left as usize {
+            self.bytes_left -= s.len() as u32;
+            return self.out.write_str(s);
+        }
+
+        let mut len = self.bytes_left as usize;
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        self.bytes_left = 0;
+        self.out.write_str(&s[..len])?;
+        Err(fmt::Error)
+    }
+}
+
+impl<'a, M: Memory, W: Write> Printer<'a, M, W> {
+    unsafe fn message(
+        &mut self,
+        buf: *mut Buf,
+        start: *mut u8,
+        mut main_types: Buf,
+    ) -> fmt::Result {
+        for t in 0..self.typtbl.size {
+            write!(self, "type t{} = ", t)?;
+            self.type_entry(t as i32)?;
+            self.write_str("; ")?;
+        }
+
+        self.write_str("(")?;
+        for i in 0..leb128_decode(&mut main_types) {
+            if i > 0 {
+                self.write_str(", ")?;
+            }
+            let t = sleb128_decode(&mut main_types);
+            let value_start = (*buf).ptr;
+            match decode_value(self.mem, buf, self.typtbl, t, self.limits.max_vec_elems) {
+                Ok(value) => {
+                    self.value(&value)?;
+                    self.write_str(" : ")?;
+                    self.type_name(t)?;
+                }
+                Err(err) => {
+                    let offset = value_start.offset_from(start) as u32 + err.offset;
+                    return write!(self, "/* {} at byte {} */)", err.msg, offset);
+                }
+            }
+        }
+        self.write_str(")")?;
+
+        let bytes_left = (*buf).end.offset_from((*buf).ptr);
+        if bytes_left > 0 {
+            write!(self, " /* and {} bytes after the arguments */", bytes_left)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a primitive type, or the name of the definition of a type table entry
+    unsafe fn type_name(&mut self, t: i32) -> fmt::Result {
+        let name = match t {
+            IDL_PRIM_null => "null",
+            IDL_PRIM_bool => "bool",
+            IDL_PRIM_nat => "nat",
+            IDL_PRIM_int => "int",
+            IDL_PRIM_nat8 => "nat8",
+            IDL_PRIM_nat16 => "nat16",
+            IDL_PRIM_nat32 => "nat32",
+            IDL_PRIM_nat64 => "nat64",
+            IDL_PRIM_int8 => "int8",
+            IDL_PRIM_int16 => "int16",
+            IDL_PRIM_int32 => "int32",
+            IDL_PRIM_int64 => "int64",
+            IDL_PRIM_float32 => "float32",
+            IDL_PRIM_float64 => "float64",
+            IDL_PRIM_text => "text",
+            IDL_PRIM_reserved => "reserved",
+            IDL_PRIM_empty => "empty",
+            IDL_REF_principal => "principal",
+            _ => return write!(self, "t{}", t),
+        };
+        self.write_str(name)
+    }
+
+    /// Writes the type of a type table entry. Entries are checked by `parse_idl_header`.
+    unsafe fn type_entry(&mut self, t: i32) -> fmt::Result {
+        let mut tb = Buf {
+            ptr: *self.typtbl.entries.add(t as usize),
+            end: self.typtbl.end,
+        };
+        match sleb128_decode(&mut tb) {
+            IDL_CON_opt => {
+                self.write_str("opt ")?;
+                self.type_name(sleb128_decode(&mut tb))
+            }
+            IDL_CON_vec => match sleb128_decode(&mut tb) {
+                IDL_PRIM_nat8 => self.write_str("blob"),
+                it => {
+                    self.write_str("vec ")?;
+                    self.type_name(it)
+                }
+            },
+            IDL_CON_record => {
+                let tuple = is_tuple(&tb);
+                self.write_str("record {")?;
+                let n = leb128_decode(&mut tb);
+                for i in 0..n {
+                    self.write_str(if i == 0 { " " } else { "; " })?;
+                    let id = leb128_decode(&mut tb);
+                    if !tuple {
+                        write!(self, "{} : ", id)?;
+                    }
+                    self.type_name(sleb128_decode(&mut tb))?;
+                }
+                self.write_str(if n == 0 { "}" } else { " }" })
+            }
+            IDL_CON_variant => {
+                self.write_str("variant {")?;
+                let n = leb128_decode(&mut tb);
+                for i in 0..n {
+                    self.write_str(if i == 0 { " " } else { "; " })?;
+                    write!(self, "{} : ", leb128_decode(&mut tb))?;
+                    self.type_name(sleb128_decode(&mut tb))?;
+                }
+                self.write_str(if n == 0 { "}" } else { " }" })
+            }
+            IDL_CON_func => {
+                self.write_str("func ")?;
+                self.types(&mut tb)?;
+                self.write_str(" -> ")?;
+                self.types(&mut tb)?;
+                for _ in 0..leb128_decode(&mut tb) {
+                    // Annotations are within 1..3, checked by `parse_idl_header`
+                    self.write_str(match read_byte(&mut tb) {
+                        IDL_FUNC_query => " query",
+                        IDL_FUNC_oneway => " oneway",
+                        _ => " composite_query",
+                    })?;
+                }
+                Ok(())
+            }
+            IDL_CON_service => {
+                self.write_str("service {")?;
+                let n = leb128_decode(&mut tb);
+                for i in 0..n {
+                    self.write_str(if i == 0 { " " } else { "; " })?;
+                    let len = leb128_decode(&mut tb);
+                    let name = core::slice::from_raw_parts(tb.ptr, len as usize);
+                    Buf::advance(&mut tb, len);
+                    self.name(core::str::from_utf8_unchecked(name))?;
+                    self.write_str(" : ")?;
+                    self.type_name(sleb128_decode(&mut tb))?;
+                }
+                self.write_str(if n == 0 { "}" } else { " }" })
+            }
+            IDL_CON_alias => {
+                self.write_str("alias ")?;
+                self.type_name(sleb128_decode(&mut tb))
+            }
+            con => write!(self, "reserved /* future type {} */", con),
+        }
+    }
+
+    /// Writes a sequence of types, e.g. the arguments of a function type
+    unsafe fn types(&mut self, tb: &mut Buf) -> fmt::Result {
+        self.write_str("(")?;
+        for i in 0..leb128_decode(tb) {
+            if i > 0 {
+                self.write_str(", ")?;
+            }
+            self.type_name(sleb128_decode(tb))?;
+        }
+        self.write_str(")")
+    }
+
+    /// Writes a method name, quoted unless it's an identifier
+    unsafe fn name(&mut self, name: &str) -> fmt::Result {
+        let is_ident = name
+            .bytes()
+            .enumerate()
+            .all(|(i, b)| b == b'_' || b.is_ascii_alphabetic() || (i > 0 && b.is_ascii_digit()));
+        if is_ident && !name.is_empty() {
+            self.write_str(name)
+        } else {
+            self.text(name)
+        }
+    }
+
+    unsafe fn value(&mut self, value: &Value) -> fmt::Result {
+        match *value {
+            Value::Null | Value::Reserved | Value::Opt(None) => self.write_str("null"),
+            Value::Bool(b) => write!(self, "{}", b),
+            Value::Nat(n) | Value::Int(n) => {
+                let text = bigint_to_text(self.mem, n, 10);
+                let blob = blob_of_text(self.mem, text);
+                self.write_str(blob_str(blob))
+            }
+            Value::Nat8(n) => write!(self, "{}", n),
+            Value::Nat16(n) => write!(self, "{}", n),
+            Value::Nat32(n) => write!(self, "{}", n),
+            Value::Nat64(n) => write!(self, "{}", n),
+            Value::Int8(n) => write!(self, "{}", n),
+            Value::Int16(n) => write!(self, "{}", n),
+            Value::Int32(n) => write!(self, "{}", n),
+            Value::Int64(n) => write!(self, "{}", n),
+            Value::Float32(f) => {
+                let blob = float32_fmt_shortest(self.mem, f);
+                self.write_str(blob_str(blob))
+            }
+            Value::Float64(f) => self.float(f),
+            Value::Text(text) => self.text(text),
+            Value::Principal(Some(bytes)) => {
+                self.write_str("principal ")?;
+                self.principal(bytes)
+            }
+            Value::Principal(None) => self.write_str("principal /* opaque */"),
+            Value::Opt(Some(value)) => {
+                self.write_str("opt ")?;
+                self.value(value)
+            }
+            Value::Blob(bytes) => self.blob(bytes),
+            Value::Vec { elems, len } => {
+                // Only the shown elements are decoded
+                self.write_str("vec {")?;
+                for (i, elem) in elems.iter().enumerate() {
+                    self.write_str(if i == 0 { " " } else { "; " })?;
+                    self.value(elem)?;
+                }
+                if elems.is_empty() && len > 0 {
+                    write!(self, " /* {} elements */", len)?;
+                } else if (elems.len() as u32) < len {
+                    write!(self, "; /* and {} more */", len - elems.len() as u32)?;
+                }
+                self.write_str(if len == 0 { "}" } else { " }" })
+            }
+            Value::Record(fields) => {
+                let tuple = fields.iter().enumerate().all(|(i, f)| f.id == i as u32);
+                self.write_str("record {")?;
+                for (i, field) in fields.iter().enumerate() {
+                    self.write_str(if i == 0 { " " } else { "; " })?;
+                    if !tuple {
+                        write!(self, "{} = ", field.id)?;
+                    }
+                    self.value(&field.value)?;
+                }
+                self.write_str(if fields.is_empty() { "}" } else { " }" })
+            }
+            Value::Variant(Field {
+                id,
+                value: Value::Null,
+            }) => write!(self, "variant {{ {} }}", id),
+            Value::Variant(Field { id, value }) => {
+                write!(self, "variant {{ {} = ", id)?;
+                self.value(value)?;
+                self.write_str(" }")
+            }
+            Value::Func { service, method } => {
+                self.write_str("func ")?;
+                self.principal(service)?;
+                self.write_str(".")?;
+                self.name(method)
+            }
+            Value::Service(service) => {
+                self.write_str("service ")?;
+                self.principal(service)
+            }
+            Value::Alias(value) => self.value(value),
+            Value::AliasRef(_) => self.write_str("/* alias of an earlier value */"),
+            Value::Future(data) => write!(self, "/* future value of {} bytes */", data.len()),
+        }
+    }
+
+    unsafe fn float(&mut self, f: f64) -> fmt::Result {
+        // `float_fmt` takes the mode in the upper byte
+        let blob = float_fmt(self.mem, f, 0, FMT_SHORTEST << 24);
+        self.write_str(blob_str(blob))
+    }
+
+    /// Writes a principal in its textual form, quoted
+    unsafe fn principal(&mut self, bytes: &[u8]) -> fmt::Result {
+        let blob = alloc_blob(self.mem, Bytes(bytes.len() as u32));
+        memcpy_bytes(
+            blob.as_blob().payload_addr() as usize,
+            bytes.as_ptr() as usize,
+            Bytes(bytes.len() as u32),
+        );
+        let text = principal_of_blob(self.mem, blob);
+        write!(self, "\"{}\"", blob_str(text))
+    }
+
+    /// Writes a quoted text, cut short after `max_text_bytes`
+    unsafe fn text(&mut self, text: &str) -> fmt::Result {
+        let mut shown = min(text.len(), self.limits.max_text_bytes as usize);
+        while !text.is_char_boundary(shown) {
+            shown -= 1;
+        }
+
+        self.write_str("\"")?;
+        for c in text[..shown].chars() {
+            match c {
+                '"' => self.write_str("\\\"")?,
+                '\\' => self.write_str("\\\\")?,
+                '\n' => self.write_str("\\n")?,
+                '\r' => self.write_str("\\r")?,
+                '\t' => self.write_str("\\t")?,
+                c if c.is_control() => write!(self, "\\u{{{:x}}}", c as u32)?,
+                c => self.write_char(c)?,
+            }
+        }
+        self.write_str("\"")?;
+
+        if shown < text.len() {
+            write!(self, " /* and {} more bytes */", text.len() - shown)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a blob literal, cut short after `max_blob_bytes`
+    unsafe fn blob(&mut self, bytes: &[u8]) -> fmt::Result {
+        let shown = min(bytes.len(), self.limits.max_blob_bytes as usize);
+
+        self.write_str("blob \"")?;
+        for b in &bytes[..shown] {
+            if b.is_ascii_graphic() && *b != b'"' && *b != b'\\' || *b == b' ' {
+                self.write_char(*b as char)?;
+            } else {
+                write!(self, "\\{:02x}", b)?;
+            }
+        }
+        self.write_str("\"")?;
+
+        if shown < bytes.len() {
+            write!(self, " /* and {} more bytes */", bytes.len() - shown)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the fields of the record type in `tb` are numbered `0`, `1`, ...
+unsafe fn is_tuple(tb: &Buf) -> bool {
+    let mut tb = Buf {
+        ptr: tb.ptr,
+        end: tb.end,
+    };
+    for i in 0..leb128_decode(&mut tb) {
+        if leb128_decode(&mut tb) != i {
+            return false;
+        }
+        skip_leb128(&mut tb);
+    }
+    true
+}
+
+/// Contents of a blob with ASCII text
+unsafe fn blob_str<'b>(blob: SkewedPtr) -> &'b str {
+    let blob = blob.as_blob();
+    let bytes = core::slice::from_raw_parts(blob.payload_addr(), blob.len().as_usize());
+    core::str::from_utf8_unchecked(bytes)
+}
@@ -0,0 +1,362 @@
+//! Candid subtyping between types of two type tables, e.g. the type table of a message and the
+//! type table of the expected arguments, or the stable variable types before and after an upgrade.
+//!
+//! Follows the current Candid spec, in particular the rules for `opt`: any type is a subtype of
+//! `opt t`, as the decoder coerces values that do not fit `t` to `null`, and record fields of
+//! type `null`, `opt t` or `reserved` can be missing.
+//!
+//! Types are compared structurally. Recursive types are handled by assuming that two types are
+//! related while comparing them. As all rules are conjunctions, a failed comparison makes the
+//! whole check fail, so these assumptions never need to be undone.
+
+use super::*;
+use crate::mem_utils::memzero;
+use crate::types::Words;
+
+/// Maximum nesting depth of the types being compared
+const MAX_DEPTH: u32 = 1_000;
+
+/// Checks whether type `t1` of type table 1 is a subtype of `t2` of type table 2. The type tables
+/// are given as returned by `parse_idl_header`, with the end of the message they point into.
+#[ic_mem_fn]
+pub unsafe fn idl_sub<M: Memory>(
+    mem: &mut M,
+    typtbl1: *mut *mut u8,
+    typtbl_end1: *mut u8,
+    typtbl_size1: u32,
+    typtbl2: *mut *mut u8,
+    typtbl_end2: *mut u8,
+    typtbl_size2: u32,
+    t1: i32,
+    t2: i32,
+) -> bool {
+    let tt1 = TypeTable {
+        entries: typtbl1,
+        end: typtbl_end1,
+        size: typtbl_size1,
+    };
+    let tt2 = TypeTable {
+        entries: typtbl2,
+        end: typtbl_end2,
+        size: typtbl_size2,
+    };
+    is_subtype(mem, tt1, tt2, t1, t2)
+}
+
+/// Checks whether type `t1` of `tt1` is a subtype of `t2` of `tt2`
+pub unsafe fn is_subtype<M: Memory>(
+    mem: &mut M,
+    tt1: TypeTable,
+    tt2: TypeTable,
+    t1: i32,
+    t2: i32,
+) -> bool {
+    let mut rel = Relation::new(mem, tt1.size, tt2.size);
+    sub(&mut rel, Polarity::Pos, tt1, tt2, t1, t2, 0)
+}
+
+/// Whether a comparison is between types of table 1 and table 2 (`Pos`) or the other way around,
+/// in contravariant positions (`Neg`)
+#[derive(Clone, Copy)]
+enum Polarity {
+    Pos,
+    Neg,
+}
+
+impl Polarity {
+    fn flip(self) -> Polarity {
+        match self {
+            Polarity::Pos => Polarity::Neg,
+            Polarity::Neg => Polarity::Pos,
+        }
+    }
+}
+
+/// Pairs of type table indices that are related, or assumed to be related while we compare them,
+/// for both polarities
+struct Relation {
+    bits: *mut u8,
+    size1: u32,
+    size2: u32,
+}
+
+impl Relation {
+    unsafe fn new<M: Memory>(mem: &mut M, size1: u32, size2: u32) -> Relation {
+        let n_bits = 2 * u64::from(size1) * u64::from(size2);
+        let n_words = (n_bits + 31) / 32;
+        if n_words > u64::from(u32::MAX / 4) {
+            idl_trap_with("idl_sub: type tables too large");
+        }
+        let bits = alloc(mem, Words(n_words as u32));
+        memzero(bits as usize, Words(n_words as u32));
+        Relation { bits, size1, size2 }
+    }
+
+    fn bit(&self, p: Polarity, t1: i32, t2: i32) -> usize {
+        let (t1, t2) = (t1 as usize, t2 as usize);
+        let (size1, size2) = (self.size1 as usize, self.size2 as usize);
+        match p {
+            Polarity::Pos => t1 * size2 + t2,
+            Polarity::Neg => size1 * size2 + t1 * size1 + t2,
+        }
+    }
+
+    /// Marks the types as related, returns whether they were marked before
+    unsafe fn mark(&mut self, p: Polarity, t1: i32, t2: i32) -> bool {
+        let bit = self.bit(p, t1, t2);
+        let byte = self.bits.add(bit / 8);
+        let mask = 1 << (bit % 8);
+        let marked = *byte & mask != 0;
+        *byte |= mask;
+        marked
+    }
+}
+
+/// Returns the type constructor of `t`, and a buffer for the rest of its type table entry
+unsafe fn type_con(tt: TypeTable, t: i32) -> (i32, Buf) {
+    if t < 0 {
+        let empty = Buf {
+            ptr: tt.end,
+            end: tt.end,
+        };
+        return (t, empty);
+    }
+
+    let mut buf = Buf {
+        ptr: *tt.entries.add(t as usize),
+        end: tt.end,
+    };
+    let con = sleb128_decode(&mut buf);
+    (con, buf)
+}
+
+/// Whether `null` is a subtype of `t`, which is the case for the types of record fields that can
+/// be missing
+unsafe fn is_optional(tt: TypeTable, t: i32) -> bool {
+    let (con, _) = type_con(tt, t);
+    con == IDL_PRIM_null || con == IDL_CON_opt || con == IDL_PRIM_reserved
+}
+
+/// Checks `t1 <: t2`, where `t1` is a type of `tt1` and `t2` a type of `tt2`. With negative
+/// polarity, `tt1` is the second type table of the check started by `is_subtype`.
+unsafe fn sub(
+    rel: &mut Relation,
+    p: Polarity,
+    tt1: TypeTable,
+    tt2: TypeTable,
+    t1: i32,
+    t2: i32,
+    depth: u32,
+) -> bool {
+    if depth > MAX_DEPTH {
+        idl_trap_with("idl_sub: too deeply nested types");
+    }
+
+    // Assume that the types are related while we compare them, for recursive types
+    if t1 >= 0 && t2 >= 0 && rel.mark(p, t1, t2) {
+        return true;
+    }
+
+    let (con1, mut tb1) = type_con(tt1, t1);
+    let (con2, mut tb2) = type_con(tt2, t2);
+
+    match (con1, con2) {
+        (_, IDL_PRIM_reserved) | (IDL_PRIM_empty, _) | (IDL_PRIM_nat, IDL_PRIM_int) => true,
+
+        // Values that are not of the option's type are decoded as `null`
+        (_, IDL_CON_opt) => true,
+
+        (IDL_CON_vec, IDL_CON_vec) => {
+            let it1 = sleb128_decode(&mut tb1);
+            let it2 = sleb128_decode(&mut tb2);
+            sub(rel, p, tt1, tt2, it1, it2, depth + 1)
+        }
+
+        (IDL_CON_record, IDL_CON_record) => {
+            // Every field of t2 needs to be in t1, unless it's optional
+            let mut n1 = leb128_decode(&mut tb1);
+            for _ in 0..leb128_decode(&mut tb2) {
+                let tag2 = leb128_decode(&mut tb2);
+                let it2 = sleb128_decode(&mut tb2);
+
+                let mut found = None;
+                while n1 > 0 {
+                    let last_p = tb1.ptr;
+                    let tag1 = leb128_decode(&mut tb1);
+                    let it1 = sleb128_decode(&mut tb1);
+                    if tag1 < tag2 {
+                        n1 -= 1;
+                        continue;
+                    }
+                    if tag1 == tag2 {
+                        n1 -= 1;
+                        found = Some(it1);
+                    } else {
+                        // Rewind, this field may be one of the next fields of t2
+                        tb1.ptr = last_p;
+                    }
+                    break;
+                }
+
+                let field_ok = match found {
+                    Some(it1) => sub(rel, p, tt1, tt2, it1, it2, depth + 1),
+                    None => is_optional(tt2, it2),
+                };
+                if !field_ok {
+                    return false;
+                }
+            }
+            true
+        }
+
+        (IDL_CON_variant, IDL_CON_variant) => {
+            // Every field of t1 needs to be in t2
+            let mut n2 = leb128_decode(&mut tb2);
+            for _ in 0..leb128_decode(&mut tb1) {
+                let tag1 = leb128_decode(&mut tb1);
+                let it1 = sleb128_decode(&mut tb1);
+
+                let mut found = None;
+                while n2 > 0 {
+                    let tag2 = leb128_decode(&mut tb2);
+                    let it2 = sleb128_decode(&mut tb2);
+                    n2 -= 1;
+                    if tag2 >= tag1 {
+                        if tag2 == tag1 {
+                            found = Some(it2);
+                        }
+                        break;
+                    }
+                }
+
+                match found {
+                    Some(it2) if sub(rel, p, tt1, tt2, it1, it2, depth + 1) => {}
+                    _ => return false,
+                }
+            }
+            true
+        }
+
+        (IDL_CON_func, IDL_CON_func) => {
+            // Skip to the annotations first, they need to be the same
+            let args1 = tb1.ptr;
+            let args2 = tb2.ptr;
+            skip_types(&mut tb1);
+            skip_types(&mut tb2);
+            let rets1 = tb1.ptr;
+            let rets2 = tb2.ptr;
+            skip_types(&mut tb1);
+            skip_types(&mut tb2);
+            if func_annotations(&mut tb1) != func_annotations(&mut tb2) {
+                return false;
+            }
+
+            // Arguments are contravariant, results are covariant
+            tb1.ptr = args1;
+            tb2.ptr = args2;
+            if !sub_tuple(rel, p.flip(), tt2, tt1, &mut tb2, &mut tb1, depth + 1) {
+                return false;
+            }
+            tb1.ptr = rets1;
+            tb2.ptr = rets2;
+            sub_tuple(rel, p, tt1, tt2, &mut tb1, &mut tb2, depth + 1)
+        }
+
+        (IDL_CON_service, IDL_CON_service) => {
+            // Every method of t2 needs to be in t1
+            let mut n1 = leb128_decode(&mut tb1);
+            for _ in 0..leb128_decode(&mut tb2) {
+                let (name2, it2) = read_method(&mut tb2);
+
+                let mut found = None;
+                while n1 > 0 {
+                    let last_p = tb1.ptr;
+                    let (name1, it1) = read_method(&mut tb1);
+                    if name1 < name2 {
+                        n1 -= 1;
+                        continue;
+                    }
+                    if name1 == name2 {
+                        n1 -= 1;
+                        found = Some(it1);
+                    } else {
+                        tb1.ptr = last_p;
+                    }
+                    break;
+                }
+
+                match found {
+                    Some(it1) if sub(rel, p, tt1, tt2, it1, it2, depth + 1) => {}
+                    _ => return false,
+                }
+            }
+            true
+        }
+
+        (IDL_CON_alias, IDL_CON_alias) => {
+            // Mutable contents, see Note [mutable stable values] in codegen/compile.ml. Values are
+            // written and read, so the types need to be related both ways.
+            let it1 = sleb128_decode(&mut tb1);
+            let it2 = sleb128_decode(&mut tb2);
+            sub(rel, p, tt1, tt2, it1, it2, depth + 1)
+                && sub(rel, p.flip(), tt2, tt1, it2, it1, depth + 1)
+        }
+
+        // Primitive types are only related to themselves (other than the cases above). Future
+        // types are not related to anything but `reserved`.
+        (con1, con2) => con1 == con2 && t1 < 0,
+    }
+}
+
+/// Checks the sequence of types in `tb1` against the one in `tb2`, like records with fields
+/// `0`, `1`, ...
+unsafe fn sub_tuple(
+    rel: &mut Relation,
+    p: Polarity,
+    tt1: TypeTable,
+    tt2: TypeTable,
+    tb1: &mut Buf,
+    tb2: &mut Buf,
+    depth: u32,
+) -> bool {
+    let n1 = leb128_decode(tb1);
+    let n2 = leb128_decode(tb2);
+    for i in 0..n2 {
+        let it2 = sleb128_decode(tb2);
+        let ok = if i < n1 {
+            let it1 = sleb128_decode(tb1);
+            sub(rel, p, tt1, tt2, it1, it2, depth)
+        } else {
+            is_optional(tt2, it2)
+        };
+        if !ok {
+            return false;
+        }
+    }
+    true
+}
+
+/// Skips a sequence of types, e.g. the argument types of a function
+unsafe fn skip_types(tb: &mut Buf) {
+    for _ in 0..leb128_decode(tb) {
+        skip_leb128(tb);
+    }
+}
+
+/// Returns the set of annotations of a function type, as a bit set
+unsafe fn func_annotations(tb: &mut Buf) -> u8 {
+    let mut annotations = 0;
+    for _ in 0..leb128_decode(tb) {
+        annotations |= 1 << read_byte(tb);
+    }
+    annotations
+}
+
+/// Reads the name and type of a service method
+unsafe fn read_method<'a>(tb: &mut Buf) -> (&'a [u8], i32) {
+    let len = leb128_decode(tb);
+    let name = core::slice::from_raw_parts(tb.ptr, len as usize);
+    Buf::advance(tb, len);
+    let t = sleb128_decode(tb);
+    (name, t)
+}
@@ -1,6 +1,7 @@
 //! LEB1128 encoding. Reference: https://en.wikipedia.org/wiki/LEB128
 
 use crate::buf::{read_byte, Buf};
+use crate::idl_trap_with;
 
 #[no_mangle]
 pub unsafe extern "C" fn leb128_encode(mut val: u32, mut buf: *mut u8) {
@@ -35,7 +36,10 @@ pub unsafe extern "C" fn sleb128_encode(mut val: i32, mut buf: *mut u8) {
 
 #[no_mangle]
 pub unsafe extern "C" fn leb128_decode(buf: *mut Buf) -> u32 {
-    leb128_decode_checked(buf).expect("leb128_decode: overflow")
+    match leb128_decode_checked(buf) {
+        Some(val) => val,
+        None => idl_trap_with("leb128_decode: overflow"),
+    }
 }
 
 /// Returns `None` on overflow
@@ -66,7 +70,10 @@ pub unsafe fn leb128_decode_checked(buf: *mut Buf) -> Option<u32> {
 
 #[no_mangle]
 pub unsafe extern "C" fn sleb128_decode(buf: *mut Buf) -> i32 {
-    sleb128_decode_checked(buf).expect("sleb128_decode: overflow")
+    match sleb128_decode_checked(buf) {
+        Some(val) => val,
+        None => idl_trap_with("sleb128_decode: overflow"),
+    }
 }
 
 /// Returns `None` on overflow
@@ -81,7 +88,10 @@ pub unsafe fn sleb128_decode_checked(buf: *mut Buf) -> Option<i32> {
 
         // Overflow check ported from Wasm reference implementation:
         // https://github.com/WebAssembly/spec/blob/f9770eb75117cac0c878feaa5eaf4a4d9dda61f5/interpreter/binary/decode.ml#L89-L98
-        if shift == 28 && (byte & 0b0111_1000 != 0 && byte & 0b0111_1000 != 0b0111_1000) {
+        if shift == 28
+            && (byte & 0b1000_0000 != 0
+                || (byte & 0b0111_1000 != 0 && byte & 0b0111_1000 != 0b0111_1000))
+        {
             return None;
         }
 
@@ -19,27 +19,28 @@ pub mod debug;
 pub mod bigint;
 mod blob_iter;
 pub mod buf;
-mod char;
+pub mod char;
 pub mod closure_table;
 pub mod constants;
+pub mod encoding;
+pub mod float;
 pub mod gc;
+pub mod idl;
 pub mod leb128;
+pub mod math;
 mod mem_utils;
 pub mod memory;
+pub mod normalization;
 pub mod principal_id;
 pub mod text;
+pub mod text_builder;
+pub mod text_index;
 pub mod text_iter;
 mod tommath_bindings;
 pub mod types;
 pub mod utf8;
 mod visitor;
 
-#[cfg(feature = "ic")]
-mod idl;
-
-#[cfg(feature = "ic")]
-mod float;
-
 use types::Bytes;
 
 use motoko_rts_macros::ic_mem_fn;
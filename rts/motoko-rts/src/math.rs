@@ -0,0 +1,1644 @@
+//! Elementary functions on `f64`, exported under the C library names when building for the IC
+//! (natively they would override the C library's functions)
+//!
+//! These are ports of the FreeBSD/musl (originally Sun fdlibm) implementations, using only
+//! arithmetic on doubles and integers, so results are the same on every platform. Errors are
+//! below 1 ULP, except `atan2`, `sinh`, `cosh` and `tanh`, which are below 2 ULP.
+//!
+//! Status flags (inexact, underflow etc.) are not raised, as Wasm doesn't have them.
+
+// Many of the constants below have more digits than needed, they are copied from fdlibm along
+// with their bit patterns. Some are split or rounded versions of `core::f64::consts`, and `x - x`
+// is used to get NaN from an infinite or NaN `x`.
+#![allow(
+    clippy::approx_constant,
+    clippy::eq_op,
+    clippy::excessive_precision,
+    clippy::unreadable_literal
+)]
+
+//
+// Helpers
+//
+
+fn high_word(x: f64) -> u32 {
+    (x.to_bits() >> 32) as u32
+}
+
+fn low_word(x: f64) -> u32 {
+    x.to_bits() as u32
+}
+
+fn from_words(high: u32, low: u32) -> f64 {
+    f64::from_bits((u64::from(high) << 32) | u64::from(low))
+}
+
+fn with_high_word(x: f64, high: u32) -> f64 {
+    from_words(high, low_word(x))
+}
+
+fn with_low_word(x: f64, low: u32) -> f64 {
+    from_words(high_word(x), low)
+}
+
+fn fabs(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & (u64::MAX >> 1))
+}
+
+fn sqrt(x: f64) -> f64 {
+    // Compiles to `f64.sqrt`, which is correctly rounded
+    unsafe { core::intrinsics::sqrtf64(x) }
+}
+
+fn floor(x: f64) -> f64 {
+    unsafe { core::intrinsics::floorf64(x) }
+}
+
+/// `x * 2 ** n`, rounded once
+fn scalbn(x: f64, mut n: i32) -> f64 {
+    let mut y = x;
+    if n > 1023 {
+        y *= 8.98846567431157953865e+307; // 0x1p1023
+        n -= 1023;
+        if n > 1023 {
+            y *= 8.98846567431157953865e+307;
+            n -= 1023;
+            if n > 1023 {
+                n = 1023;
+            }
+        }
+    } else if n < -1022 {
+        // Scale by 2 ** (-1022 + 53), so that the final step doesn't round twice in the subnormal
+        // range
+        y *= 2.00416836000897277800e-292; // 0x1p-969
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= 2.00416836000897277800e-292;
+            n += 1022 - 53;
+            if n < -1022 {
+                n = -1022;
+            }
+        }
+    }
+    y * f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+//
+// Argument reduction for trigonometric functions
+//
+
+const TOINT: f64 = 6755399441055744.0; // 1.5 / f64::EPSILON
+const PIO4: f64 = 7.85398163397448278999e-01; // 0x3FE921FB, 0x54442D18
+const INVPIO2: f64 = 6.36619772367581382433e-01; // 0x3FE45F30, 0x6DC9C883
+const PIO2_1: f64 = 1.57079632673412561417e+00; // 0x3FF921FB, 0x54400000
+const PIO2_1T: f64 = 6.07710050650619224932e-11; // 0x3DD0B461, 0x1A626331
+const PIO2_2: f64 = 6.07710050630396597660e-11; // 0x3DD0B461, 0x1A600000
+const PIO2_2T: f64 = 2.02226624879595063154e-21; // 0x3BA3198A, 0x2E037073
+const PIO2_3: f64 = 2.02226624871116645580e-21; // 0x3BA3198A, 0x2E000000
+const PIO2_3T: f64 = 8.47842766036889956997e-32; // 0x397B839A, 0x252049C1
+
+/// Reduces `x` to `y0 + y1` in [-pi/4, pi/4], returns `n` such that `x = y0 + y1 + n * pi/2`
+/// (`n` only modulo 8 for large `x`)
+fn rem_pio2(x: f64) -> (i32, f64, f64) {
+    let sign = x.to_bits() >> 63 != 0;
+    let ix = high_word(x) & 0x7fffffff;
+
+    if ix <= 0x400f6a7a {
+        // |x| ~<= 5pi/4
+        if (ix & 0xfffff) != 0x921fb {
+            // Not close to pi/2 or pi, where there is cancellation
+            let k = if ix <= 0x4002d97c { 1.0 } else { 2.0 };
+            return reduce_small(x, if sign { -k } else { k });
+        }
+    } else if ix <= 0x401c463b {
+        // |x| ~<= 9pi/4
+        if ix <= 0x4015fdbc {
+            // |x| ~<= 7pi/4
+            if ix != 0x4012d97c {
+                // Not close to 3pi/2
+                return reduce_small(x, if sign { -3.0 } else { 3.0 });
+            }
+        } else if ix != 0x401921fb {
+            // Not close to 2pi
+            return reduce_small(x, if sign { -4.0 } else { 4.0 });
+        }
+    } else if ix >= 0x413921fb {
+        // |x| ~>= 2 ** 20 * pi/2
+        if ix >= 0x7ff00000 {
+            // Inf or NaN
+            let nan = x - x;
+            return (0, nan, nan);
+        }
+        return reduce_large(x);
+    }
+
+    reduce_medium(x, ix)
+}
+
+/// Subtracts `k * pi/2` with 85 bits of pi/2, for `k` in -4..4, not close to a multiple of pi/2
+fn reduce_small(x: f64, k: f64) -> (i32, f64, f64) {
+    let z = x - k * PIO2_1;
+    let y0 = z - k * PIO2_1T;
+    let y1 = (z - y0) - k * PIO2_1T;
+    (k as i32, y0, y1)
+}
+
+/// Reduction for |x| ~< 2 ** 20 * pi/2, with up to 151 bits of pi/2 depending on cancellation
+fn reduce_medium(x: f64, ix: u32) -> (i32, f64, f64) {
+    // rint(x / (pi/2))
+    let mut f_n = x * INVPIO2 + TOINT - TOINT;
+    let mut n = f_n as i32;
+    let mut r = x - f_n * PIO2_1;
+    let mut w = f_n * PIO2_1T; // First round, good to 85 bits
+
+    // Matters with directed rounding
+    if r - w < -PIO4 {
+        n -= 1;
+        f_n -= 1.0;
+        r = x - f_n * PIO2_1;
+        w = f_n * PIO2_1T;
+    } else if r - w > PIO4 {
+        n += 1;
+        f_n += 1.0;
+        r = x - f_n * PIO2_1;
+        w = f_n * PIO2_1T;
+    }
+
+    let mut y0 = r - w;
+    let ex = (ix >> 20) as i32;
+    let ey = ((y0.to_bits() >> 52) & 0x7ff) as i32;
+    if ex - ey > 16 {
+        // Second round, good to 118 bits
+        let t = r;
+        w = f_n * PIO2_2;
+        r = t - w;
+        w = f_n * PIO2_2T - ((t - r) - w);
+        y0 = r - w;
+        let ey = ((y0.to_bits() >> 52) & 0x7ff) as i32;
+        if ex - ey > 49 {
+            // Third round, good to 151 bits, covers all cases
+            let t = r;
+            w = f_n * PIO2_3;
+            r = t - w;
+            w = f_n * PIO2_3T - ((t - r) - w);
+            y0 = r - w;
+        }
+    }
+    let y1 = (r - y0) - w;
+    (n, y0, y1)
+}
+
+/// Reduction for large finite `x`, with as many bits of 2/pi as needed
+fn reduce_large(x: f64) -> (i32, f64, f64) {
+    let sign = x.to_bits() >> 63 != 0;
+    let ix = high_word(x) & 0x7fffffff;
+
+    // Split |x| * 2 ** (23 - ilogb(x)) into three 24-bit chunks
+    let mut z = f64::from_bits((x.to_bits() & (u64::MAX >> 12)) | ((0x3ff + 23) << 52));
+    let mut tx = [0.0; 3];
+    for chunk in tx.iter_mut().take(2) {
+        *chunk = z as i32 as f64;
+        z = (z - *chunk) * 16777216.0; // 0x1p24
+    }
+    tx[2] = z;
+
+    // Skip zero chunks, the first one is not zero
+    let mut nx = 3;
+    while tx[nx - 1] == 0.0 {
+        nx -= 1;
+    }
+
+    let (n, y0, y1) = rem_pio2_large(&tx[..nx], (ix >> 20) as i32 - (0x3ff + 23));
+    if sign {
+        (-n, -y0, -y1)
+    } else {
+        (n, y0, y1)
+    }
+}
+
+/// 2/pi in 24-bit chunks, enough for the largest doubles
+const IPIO2: [i32; 66] = [
+    0xA2F983, 0x6E4E44, 0x1529FC, 0x2757D1, 0xF534DD, 0xC0DB62, 0x95993C, 0x439041, 0xFE5163,
+    0xABDEBB, 0xC561B7, 0x246E3A, 0x424DD2, 0xE00649, 0x2EEA09, 0xD1921C, 0xFE1DEB, 0x1CB129,
+    0xA73EE8, 0x8235F5, 0x2EBB44, 0x84E99C, 0x7026B4, 0x5F7E41, 0x3991D6, 0x398353, 0x39F49C,
+    0x845F8B, 0xBDF928, 0x3B1FF8, 0x97FFDE, 0x05980F, 0xEF2F11, 0x8B5A0A, 0x6D1F6D, 0x367ECF,
+    0x27CB09, 0xB74F46, 0x3F669E, 0x5FEA2D, 0x7527BA, 0xC7EBE5, 0xF17B3D, 0x0739F7, 0x8A5292,
+    0xEA6BFB, 0x5FB11F, 0x8D5D08, 0x560330, 0x46FC7B, 0x6BABF0, 0xCFBC20, 0x9AF436, 0x1DA9E3,
+    0x91615E, 0xE61B08, 0x659985, 0x5F14A0, 0x68408D, 0xFFD880, 0x4D7327, 0x310606, 0x1556CA,
+    0x73A8C9, 0x60E27B, 0xC08C6B,
+];
+
+/// pi/2 in 24-bit chunks
+const PIO2: [f64; 8] = [
+    1.57079625129699707031e+00, // 0x3FF921FB, 0x40000000
+    7.54978941586159635335e-08, // 0x3E74442D, 0x00000000
+    5.39030252995776476554e-15, // 0x3CF84698, 0x80000000
+    3.28200341580791294123e-22, // 0x3B78CC51, 0x60000000
+    1.27065575308067607349e-29, // 0x39F01B83, 0x80000000
+    1.22933308981111328932e-36, // 0x387A2520, 0x40000000
+    2.73370053816464559624e-44, // 0x36E38222, 0x80000000
+    2.16741683877804819444e-51, // 0x3569F31D, 0x00000000
+];
+
+/// Reduces `x * 2 ** e0`, where `x` are 24-bit chunks, modulo pi/2. Returns `n` modulo 8 and
+/// the remainder in two doubles. See fdlibm's `__kernel_rem_pio2` for details.
+fn rem_pio2_large(x: &[f64], e0: i32) -> (i32, f64, f64) {
+    // Number of terms of 2/pi to start with, for double precision
+    const JK: usize = 4;
+    const JP: usize = JK;
+
+    let mut iq = [0i32; 20];
+    let mut f = [0.0; 20];
+    let mut fq = [0.0; 20];
+    let mut q = [0.0; 20];
+
+    let jx = x.len() - 1;
+    let jv = core::cmp::max((e0 - 3) / 24, 0) as usize;
+    let mut q0 = e0 - 24 * (jv as i32 + 1);
+
+    // f[0] to f[jx + JK], where f[jx + JK] = IPIO2[jv + JK]
+    for (i, fi) in f.iter_mut().take(jx + JK + 1).enumerate() {
+        *fi = if i + jv < jx {
+            0.0
+        } else {
+            IPIO2[i + jv - jx] as f64
+        };
+    }
+
+    // q[0] to q[JK]
+    for i in 0..=JK {
+        let mut fw = 0.0;
+        for j in 0..=jx {
+            fw += x[j] * f[jx + i - j];
+        }
+        q[i] = fw;
+    }
+
+    let mut jz = JK;
+    let mut z;
+    let mut n;
+    let mut ih;
+
+    loop {
+        // Distill q[] into iq[] in reverse order
+        z = q[jz];
+        for (i, j) in (1..=jz).rev().enumerate() {
+            let fw = (5.9604644775390625e-08 * z) as i32 as f64; // 0x1p-24
+            iq[i] = (z - 16777216.0 * fw) as i32;
+            z = q[j - 1] + fw;
+        }
+
+        // Compute n
+        z = scalbn(z, q0); // Actual value of z
+        z -= 8.0 * floor(z * 0.125); // Trim off integer >= 8
+        n = z as i32;
+        z -= n as f64;
+        ih = 0;
+        if q0 > 0 {
+            // Need iq[jz - 1] to determine n
+            let i = iq[jz - 1] >> (24 - q0);
+            n += i;
+            iq[jz - 1] -= i << (24 - q0);
+            ih = iq[jz - 1] >> (23 - q0);
+        } else if q0 == 0 {
+            ih = iq[jz - 1] >> 23;
+        } else if z >= 0.5 {
+            ih = 2;
+        }
+
+        if ih > 0 {
+            // q > 0.5
+            n += 1;
+            let mut carry = 0;
+            for iqi in iq.iter_mut().take(jz) {
+                // Compute 1 - q
+                let j = *iqi;
+                if carry == 0 {
+                    if j != 0 {
+                        carry = 1;
+                        *iqi = 0x1000000 - j;
+                    }
+                } else {
+                    *iqi = 0xffffff - j;
+                }
+            }
+            if q0 > 0 {
+                // Rare case: chance is 1 in 12
+                match q0 {
+                    1 => iq[jz - 1] &= 0x7fffff,
+                    2 => iq[jz - 1] &= 0x3fffff,
+                    _ => {}
+                }
+            }
+            if ih == 2 {
+                z = 1.0 - z;
+                if carry != 0 {
+                    z -= scalbn(1.0, q0);
+                }
+            }
+        }
+
+        // Check if recomputation is needed
+        if z == 0.0 {
+            let mut j = 0;
+            for i in (JK..jz).rev() {
+                j |= iq[i];
+            }
+            if j == 0 {
+                // Number of additional terms needed
+                let mut k = 1;
+                while iq[JK - k] == 0 {
+                    k += 1;
+                }
+
+                // Add q[jz + 1] to q[jz + k]
+                for i in jz + 1..=jz + k {
+                    f[jx + i] = IPIO2[jv + i] as f64;
+                    let mut fw = 0.0;
+                    for j in 0..=jx {
+                        fw += x[j] * f[jx + i - j];
+                    }
+                    q[i] = fw;
+                }
+                jz += k;
+                continue;
+            }
+        }
+
+        break;
+    }
+
+    // Chop off zero terms
+    if z == 0.0 {
+        jz -= 1;
+        q0 -= 24;
+        while iq[jz] == 0 {
+            jz -= 1;
+            q0 -= 24;
+        }
+    } else {
+        // Break z into 24-bit chunks if necessary
+        z = scalbn(z, -q0);
+        if z >= 16777216.0 {
+            let fw = (5.9604644775390625e-08 * z) as i32 as f64;
+            iq[jz] = (z - 16777216.0 * fw) as i32;
+            jz += 1;
+            q0 += 24;
+            iq[jz] = fw as i32;
+        } else {
+            iq[jz] = z as i32;
+        }
+    }
+
+    // Convert integer chunks to floating point
+    let mut fw = scalbn(1.0, q0);
+    for i in (0..=jz).rev() {
+        q[i] = fw * iq[i] as f64;
+        fw *= 5.9604644775390625e-08;
+    }
+
+    // PIO2[0..=JP] * q[jz..=0]
+    for i in (0..=jz).rev() {
+        let mut fw = 0.0;
+        let mut k = 0;
+        while k <= JP && k <= jz - i {
+            fw += PIO2[k] * q[i + k];
+            k += 1;
+        }
+        fq[jz - i] = fw;
+    }
+
+    // Compress fq[] into two doubles
+    let mut fw = 0.0;
+    for i in (0..=jz).rev() {
+        fw += fq[i];
+    }
+    let y0 = if ih == 0 { fw } else { -fw };
+    let mut fw = fq[0] - fw;
+    for fqi in fq.iter().take(jz + 1).skip(1) {
+        fw += fqi;
+    }
+    let y1 = if ih == 0 { fw } else { -fw };
+
+    (n & 7, y0, y1)
+}
+
+//
+// Trigonometric kernels, for arguments in [-pi/4, pi/4] given as `x + y`
+//
+
+const S1: f64 = -1.66666666666666324348e-01; // 0xBFC55555, 0x55555549
+const S2: f64 = 8.33333333332248946124e-03; // 0x3F811111, 0x1110F8A6
+const S3: f64 = -1.98412698298579493134e-04; // 0xBF2A01A0, 0x19C161D5
+const S4: f64 = 2.75573137070700676789e-06; // 0x3EC71DE3, 0x57B1FE7D
+const S5: f64 = -2.50507602534068634195e-08; // 0xBE5AE5E6, 0x8A2B9CEB
+const S6: f64 = 1.58969099521155010221e-10; // 0x3DE5D93A, 0x5ACFD57C
+
+/// sin(x + y), where `y` is ignored when `!with_tail`
+fn kernel_sin(x: f64, y: f64, with_tail: bool) -> f64 {
+    let z = x * x;
+    let w = z * z;
+    let r = S2 + z * (S3 + z * S4) + z * w * (S5 + z * S6);
+    let v = z * x;
+    if !with_tail {
+        x + v * (S1 + z * r)
+    } else {
+        x - ((z * (0.5 * y - v * r) - y) - v * S1)
+    }
+}
+
+const C1: f64 = 4.16666666666666019037e-02; // 0x3FA55555, 0x5555554C
+const C2: f64 = -1.38888888888741095749e-03; // 0xBF56C16C, 0x16C15177
+const C3: f64 = 2.48015872894767294178e-05; // 0x3EFA01A0, 0x19CB1590
+const C4: f64 = -2.75573143513906633035e-07; // 0xBE927E4F, 0x809C52AD
+const C5: f64 = 2.08757232129817482790e-09; // 0x3E21EE9E, 0xBDB4B1C4
+const C6: f64 = -1.13596475577881948265e-11; // 0xBDA8FAE9, 0xBE8838D4
+
+/// cos(x + y)
+fn kernel_cos(x: f64, y: f64) -> f64 {
+    let z = x * x;
+    let w = z * z;
+    let r = z * (C1 + z * (C2 + z * C3)) + w * w * (C4 + z * (C5 + z * C6));
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+    w + (((1.0 - w) - hz) + (z * r - x * y))
+}
+
+const T: [f64; 13] = [
+    3.33333333333334091986e-01,  // 0x3FD55555, 0x55555563
+    1.33333333333201242699e-01,  // 0x3FC11111, 0x1110FE7A
+    5.39682539762260521377e-02,  // 0x3FABA1BA, 0x1BB341FE
+    2.18694882948595424599e-02,  // 0x3F9664F4, 0x8406D637
+    8.86323982359930005737e-03,  // 0x3F8226E3, 0xE96E8493
+    3.59207910759131235356e-03,  // 0x3F6D6D22, 0xC9560328
+    1.45620945432529025516e-03,  // 0x3F57DBC8, 0xFEE08315
+    5.88041240820264096874e-04,  // 0x3F4344D8, 0xF2F26501
+    2.46463134818469906812e-04,  // 0x3F3026F7, 0x1A8D1068
+    7.81794442939557092300e-05,  // 0x3F147E88, 0xA03792A6
+    7.14072491382608190305e-05,  // 0x3F12B80F, 0x32F0A7E9
+    -1.85586374855275456654e-05, // 0xBEF375CB, 0xDB605373
+    2.59073051863633712884e-05,  // 0x3EFB2A70, 0x74BF7AD4
+];
+const PIO4_LO: f64 = 3.06161699786838301793e-17; // 0x3C81A626, 0x33145C07
+
+/// tan(x + y) when `!odd`, -1/tan(x + y) when `odd`
+fn kernel_tan(x: f64, y: f64, odd: bool) -> f64 {
+    let hx = high_word(x);
+    let big = (hx & 0x7fffffff) >= 0x3FE59428; // |x| >= 0.6744
+    let negative = hx >> 31 != 0;
+    let (mut x, mut y) = (x, y);
+    if big {
+        if negative {
+            x = -x;
+            y = -y;
+        }
+        x = (PIO4 - x) + (PIO4_LO - y);
+        y = 0.0;
+    }
+
+    let z = x * x;
+    let w = z * z;
+    // Break the polynomial into odd and even terms
+    let r = T[1] + w * (T[3] + w * (T[5] + w * (T[7] + w * (T[9] + w * T[11]))));
+    let v = z * (T[2] + w * (T[4] + w * (T[6] + w * (T[8] + w * (T[10] + w * T[12])))));
+    let s = z * x;
+    let r = y + z * (s * (r + v) + y) + s * T[0];
+    let w = x + r;
+
+    if big {
+        let s = if odd { -1.0 } else { 1.0 };
+        let v = s - 2.0 * (x + (r - w * w / (w + s)));
+        return if negative { -v } else { v };
+    }
+
+    if !odd {
+        return w;
+    }
+
+    // -1.0 / (x + r) has up to 2 ULP error, so compute it accurately
+    let w0 = with_low_word(w, 0);
+    let v = r - (w0 - x); // w0 + v = r + x
+    let a = -1.0 / w;
+    let a0 = with_low_word(a, 0);
+    a0 + a * (1.0 + a0 * w0 + a0 * v)
+}
+
+//
+// Trigonometric functions
+//
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn sin(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+
+    if ix <= 0x3fe921fb {
+        // |x| ~< pi/4
+        if ix < 0x3e500000 {
+            // |x| < 2 ** -26
+            return x;
+        }
+        return kernel_sin(x, 0.0, false);
+    }
+
+    if ix >= 0x7ff00000 {
+        // Inf or NaN
+        return x - x;
+    }
+
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => kernel_sin(y0, y1, true),
+        1 => kernel_cos(y0, y1),
+        2 => -kernel_sin(y0, y1, true),
+        _ => -kernel_cos(y0, y1),
+    }
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn cos(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+
+    if ix <= 0x3fe921fb {
+        // |x| ~< pi/4
+        if ix < 0x3e46a09e {
+            // |x| < 2 ** -27 * sqrt(2)
+            return 1.0;
+        }
+        return kernel_cos(x, 0.0);
+    }
+
+    if ix >= 0x7ff00000 {
+        // Inf or NaN
+        return x - x;
+    }
+
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => kernel_cos(y0, y1),
+        1 => -kernel_sin(y0, y1, true),
+        2 => -kernel_cos(y0, y1),
+        _ => kernel_sin(y0, y1, true),
+    }
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn tan(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+
+    if ix <= 0x3fe921fb {
+        // |x| ~< pi/4
+        if ix < 0x3e400000 {
+            // |x| < 2 ** -27
+            return x;
+        }
+        return kernel_tan(x, 0.0, false);
+    }
+
+    if ix >= 0x7ff00000 {
+        // Inf or NaN
+        return x - x;
+    }
+
+    let (n, y0, y1) = rem_pio2(x);
+    kernel_tan(y0, y1, n & 1 != 0)
+}
+
+//
+// Inverse trigonometric functions
+//
+
+const PIO2_HI: f64 = 1.57079632679489655800e+00; // 0x3FF921FB, 0x54442D18
+const PIO2_LO: f64 = 6.12323399573676603587e-17; // 0x3C91A626, 0x33145C07
+
+const PS0: f64 = 1.66666666666666657415e-01; // 0x3FC55555, 0x55555555
+const PS1: f64 = -3.25565818622400915405e-01; // 0xBFD4D612, 0x03EB6F7D
+const PS2: f64 = 2.01212532134862925881e-01; // 0x3FC9C155, 0x0E884455
+const PS3: f64 = -4.00555345006794114027e-02; // 0xBFA48228, 0xB5688F3B
+const PS4: f64 = 7.91534994289814532176e-04; // 0x3F49EFE0, 0x7501B288
+const PS5: f64 = 3.47933107596021167570e-05; // 0x3F023DE1, 0x0DFDF709
+const QS1: f64 = -2.40339491173441421878e+00; // 0xC0033A27, 0x1C8A2D4B
+const QS2: f64 = 2.02094576023350569471e+00; // 0x40002AE5, 0x9C598AC8
+const QS3: f64 = -6.88283971605453293030e-01; // 0xBFE6066C, 0x1B8D0159
+const QS4: f64 = 7.70381505559019352791e-02; // 0x3FB3B8C5, 0xB12E9282
+
+/// Rational approximation of `(asin(x) - x) / x ** 3` in terms of `z = x ** 2`
+fn asin_r(z: f64) -> f64 {
+    let p = z * (PS0 + z * (PS1 + z * (PS2 + z * (PS3 + z * (PS4 + z * PS5)))));
+    let q = 1.0 + z * (QS1 + z * (QS2 + z * (QS3 + z * QS4)));
+    p / q
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn asin(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fffffff;
+
+    if ix >= 0x3ff00000 {
+        // |x| >= 1 or NaN
+        if ((ix - 0x3ff00000) | low_word(x)) == 0 {
+            // asin(+-1) = +-pi/2
+            return x * PIO2_HI;
+        }
+        return 0.0 / (x - x);
+    }
+
+    if ix < 0x3fe00000 {
+        // |x| < 0.5
+        if ix < 0x3e500000 {
+            // |x| < 2 ** -26
+            return x;
+        }
+        return x + x * asin_r(x * x);
+    }
+
+    // 0.5 <= |x| < 1
+    let z = (1.0 - fabs(x)) * 0.5;
+    let s = sqrt(z);
+    let r = asin_r(z);
+    let y = if ix >= 0x3fef3333 {
+        // |x| > 0.975
+        PIO2_HI - (2.0 * (s + s * r) - PIO2_LO)
+    } else {
+        // f + c = sqrt(z)
+        let f = with_low_word(s, 0);
+        let c = (z - f * f) / (s + f);
+        0.5 * PIO2_HI - (2.0 * s * r - (PIO2_LO - 2.0 * c) - (0.5 * PIO2_HI - 2.0 * f))
+    };
+
+    if hx >> 31 != 0 {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn acos(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fffffff;
+
+    if ix >= 0x3ff00000 {
+        // |x| >= 1 or NaN
+        if ((ix - 0x3ff00000) | low_word(x)) == 0 {
+            // acos(1) = 0, acos(-1) = pi
+            if hx >> 31 != 0 {
+                return 2.0 * PIO2_HI;
+            }
+            return 0.0;
+        }
+        return 0.0 / (x - x);
+    }
+
+    if ix < 0x3fe00000 {
+        // |x| < 0.5
+        if ix <= 0x3c600000 {
+            // |x| < 2 ** -57
+            return PIO2_HI;
+        }
+        return PIO2_HI - (x - (PIO2_LO - x * asin_r(x * x)));
+    }
+
+    if hx >> 31 != 0 {
+        // x < -0.5
+        let z = (1.0 + x) * 0.5;
+        let s = sqrt(z);
+        let w = asin_r(z) * s - PIO2_LO;
+        return 2.0 * (PIO2_HI - (s + w));
+    }
+
+    // x > 0.5
+    let z = (1.0 - x) * 0.5;
+    let s = sqrt(z);
+    let df = with_low_word(s, 0);
+    let c = (z - df * df) / (s + df);
+    let w = asin_r(z) * s + c;
+    2.0 * (df + w)
+}
+
+const ATAN_HI: [f64; 4] = [
+    4.63647609000806093515e-01, // atan(0.5) hi, 0x3FDDAC67, 0x0561BB4F
+    7.85398163397448278999e-01, // atan(1.0) hi, 0x3FE921FB, 0x54442D18
+    9.82793723247329054082e-01, // atan(1.5) hi, 0x3FEF730B, 0xD281F69B
+    1.57079632679489655800e+00, // atan(inf) hi, 0x3FF921FB, 0x54442D18
+];
+
+const ATAN_LO: [f64; 4] = [
+    2.26987774529616870924e-17, // atan(0.5) lo, 0x3C7A2B7F, 0x222F65E2
+    3.06161699786838301793e-17, // atan(1.0) lo, 0x3C81A626, 0x33145C07
+    1.39033110312309984516e-17, // atan(1.5) lo, 0x3C700788, 0x7AF0CBBD
+    6.12323399573676603587e-17, // atan(inf) lo, 0x3C91A626, 0x33145C07
+];
+
+const AT: [f64; 11] = [
+    3.33333333333329318027e-01,  // 0x3FD55555, 0x5555550D
+    -1.99999999998764832476e-01, // 0xBFC99999, 0x9998EBC4
+    1.42857142725034663711e-01,  // 0x3FC24924, 0x920083FF
+    -1.11111104054623557880e-01, // 0xBFBC71C6, 0xFE231671
+    9.09088713343650656196e-02,  // 0x3FB745CD, 0xC54C206E
+    -7.69187620504482999495e-02, // 0xBFB3B0F2, 0xAF749A6D
+    6.66107313738753120669e-02,  // 0x3FB10D66, 0xA0D03D51
+    -5.83357013379057348645e-02, // 0xBFADDE2D, 0x52DEFD9A
+    4.97687799461593236017e-02,  // 0x3FA97B4B, 0x24760DEB
+    -3.65315727442169155270e-02, // 0xBFA2B444, 0x2C6A6C2F
+    1.62858201153657823623e-02,  // 0x3F90AD3A, 0xE322DA11
+];
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn atan(x: f64) -> f64 {
+    let hx = high_word(x);
+    let negative = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+
+    if ix >= 0x44100000 {
+        // |x| >= 2 ** 66
+        if x.is_nan() {
+            return x;
+        }
+        return if negative { -ATAN_HI[3] } else { ATAN_HI[3] };
+    }
+
+    // Reduce to |x| < 0.4375, with atan(x) = ATAN_HI[id] + ATAN_LO[id] + atan(reduced)
+    let (id, x) = if ix < 0x3fdc0000 {
+        // |x| < 0.4375
+        if ix < 0x3e400000 {
+            // |x| < 2 ** -27
+            return x;
+        }
+        (None, x)
+    } else {
+        let x = fabs(x);
+        if ix < 0x3ff30000 {
+            // |x| < 1.1875
+            if ix < 0x3fe60000 {
+                // 7/16 <= |x| < 11/16
+                (Some(0), (2.0 * x - 1.0) / (2.0 + x))
+            } else {
+                // 11/16 <= |x| < 19/16
+                (Some(1), (x - 1.0) / (x + 1.0))
+            }
+        } else if ix < 0x40038000 {
+            // |x| < 2.4375
+            (Some(2), (x - 1.5) / (1.0 + 1.5 * x))
+        } else {
+            // 2.4375 <= |x| < 2 ** 66
+            (Some(3), -1.0 / x)
+        }
+    };
+
+    let z = x * x;
+    let w = z * z;
+    // Break the sum of AT[i] * z ** (i + 1) into odd and even polynomials
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+
+    match id {
+        None => x - x * (s1 + s2),
+        Some(id) => {
+            let z = ATAN_HI[id] - (x * (s1 + s2) - ATAN_LO[id] - x);
+            if negative {
+                -z
+            } else {
+                z
+            }
+        }
+    }
+}
+
+const PI: f64 = 3.1415926535897931160E+00; // 0x400921FB, 0x54442D18
+const PI_LO: f64 = 1.2246467991473531772E-16; // 0x3CA1A626, 0x33145C07
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn atan2(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    let (ix, lx) = (high_word(x), low_word(x));
+    let (iy, ly) = (high_word(y), low_word(y));
+
+    if (ix.wrapping_sub(0x3ff00000) | lx) == 0 {
+        // x = 1.0
+        return atan(y);
+    }
+
+    // 2 * sign(x) + sign(y)
+    let m = ((iy >> 31) & 1) | ((ix >> 30) & 2);
+    let ix = ix & 0x7fffffff;
+    let iy = iy & 0x7fffffff;
+
+    if (iy | ly) == 0 {
+        // y = 0
+        return match m {
+            0 | 1 => y, // atan(+-0, +anything) = +-0
+            2 => PI,    // atan(+0, -anything) = pi
+            _ => -PI,   // atan(-0, -anything) = -pi
+        };
+    }
+
+    if (ix | lx) == 0 {
+        // x = 0
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 };
+    }
+
+    if ix == 0x7ff00000 {
+        // x is infinite
+        return if iy == 0x7ff00000 {
+            match m {
+                0 => PI / 4.0,
+                1 => -PI / 4.0,
+                2 => 3.0 * PI / 4.0,
+                _ => -3.0 * PI / 4.0,
+            }
+        } else {
+            match m {
+                0 => 0.0,
+                1 => -0.0,
+                2 => PI,
+                _ => -PI,
+            }
+        };
+    }
+
+    if ix + (64 << 20) < iy || iy == 0x7ff00000 {
+        // |y / x| > 2 ** 64
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 };
+    }
+
+    // atan(|y / x|) without spurious underflow
+    let z = if (m & 2) != 0 && iy + (64 << 20) < ix {
+        // |y / x| < 2 ** -64, x < 0
+        0.0
+    } else {
+        atan(fabs(y / x))
+    };
+
+    match m {
+        0 => z,                // atan(+, +)
+        1 => -z,               // atan(-, +)
+        2 => PI - (z - PI_LO), // atan(+, -)
+        _ => (z - PI_LO) - PI, // atan(-, -)
+    }
+}
+
+//
+// Exponential functions
+//
+
+const LN2_HI: f64 = 6.93147180369123816490e-01; // 0x3FE62E42, 0xFEE00000
+const LN2_LO: f64 = 1.90821492927058770002e-10; // 0x3DEA39EF, 0x35793C76
+const INVLN2: f64 = 1.44269504088896338700e+00; // 0x3FF71547, 0x652B82FE
+
+const P1: f64 = 1.66666666666666019037e-01; // 0x3FC55555, 0x5555553E
+const P2: f64 = -2.77777777770155933842e-03; // 0xBF66C16C, 0x16BEBD93
+const P3: f64 = 6.61375632143793436117e-05; // 0x3F11566A, 0xAF25DE2C
+const P4: f64 = -1.65339022054652515390e-06; // 0xBEBBBD41, 0xC5D26BF1
+const P5: f64 = 4.13813679705723846039e-08; // 0x3E663769, 0x72BEA4D0
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn exp(x: f64) -> f64 {
+    let hx = high_word(x);
+    let negative = hx >> 31 != 0;
+    let hx = hx & 0x7fffffff; // High word of |x|
+
+    if hx >= 0x4086232b {
+        // |x| >= 708.39... or NaN
+        if x.is_nan() {
+            return x;
+        }
+        if x > 709.782712893383973096 {
+            // Overflow
+            return f64::INFINITY;
+        }
+        if x < -745.13321910194110842 {
+            // Underflow
+            return 0.0;
+        }
+    }
+
+    // Reduce to x = k * ln2 + (hi - lo), with |hi - lo| <= 0.5 * ln2
+    let (k, hi, lo) = if hx > 0x3fd62e42 {
+        // |x| > 0.5 * ln2
+        let k = if hx >= 0x3ff0a2b2 {
+            // |x| >= 1.5 * ln2
+            (INVLN2 * x + if negative { -0.5 } else { 0.5 }) as i32
+        } else if negative {
+            -1
+        } else {
+            1
+        };
+        // k * LN2_HI is exact here
+        (k, x - k as f64 * LN2_HI, k as f64 * LN2_LO)
+    } else if hx > 0x3e300000 {
+        // |x| > 2 ** -28
+        (0, x, 0.0)
+    } else {
+        return 1.0 + x;
+    };
+    let x = hi - lo;
+
+    // x is now in the primary range
+    let xx = x * x;
+    let c = x - xx * (P1 + xx * (P2 + xx * (P3 + xx * (P4 + xx * P5))));
+    let y = 1.0 + (x * c / (2.0 - c) - lo + hi);
+    if k == 0 {
+        y
+    } else {
+        scalbn(y, k)
+    }
+}
+
+const O_THRESHOLD: f64 = 7.09782712893383973096e+02; // 0x40862E42, 0xFEFA39EF
+
+const Q1: f64 = -3.33333333333331316428e-02; // 0xBFA11111, 0x111110F4
+const Q2: f64 = 1.58730158725481460165e-03; // 0x3F5A01A0, 0x19FE5585
+const Q3: f64 = -7.93650757867487942473e-05; // 0xBF14CE19, 0x9EAADBB7
+const Q4: f64 = 4.00821782732936239552e-06; // 0x3ED0CFCA, 0x86E65239
+const Q5: f64 = -2.01099218183624371326e-07; // 0xBE8AFDB7, 0x6E09C32D
+
+/// exp(x) - 1, accurate for small x
+fn expm1(x: f64) -> f64 {
+    let hx = high_word(x) & 0x7fffffff;
+    let negative = x.to_bits() >> 63 != 0;
+
+    // Filter out huge and non-finite arguments
+    if hx >= 0x4043687A {
+        // |x| >= 56 * ln2
+        if x.is_nan() {
+            return x;
+        }
+        if negative {
+            return -1.0;
+        }
+        if x > O_THRESHOLD {
+            return f64::INFINITY;
+        }
+    }
+
+    // Argument reduction
+    let (k, x, c) = if hx > 0x3fd62e42 {
+        // |x| > 0.5 * ln2
+        let (k, hi, lo) = if hx < 0x3FF0A2B2 {
+            // |x| < 1.5 * ln2
+            if negative {
+                (-1, x + LN2_HI, -LN2_LO)
+            } else {
+                (1, x - LN2_HI, LN2_LO)
+            }
+        } else {
+            let k = (INVLN2 * x + if negative { -0.5 } else { 0.5 }) as i32;
+            let t = k as f64;
+            // t * LN2_HI is exact here
+            (k, x - t * LN2_HI, t * LN2_LO)
+        };
+        let x = hi - lo;
+        (k, x, (hi - x) - lo)
+    } else if hx < 0x3c900000 {
+        // |x| < 2 ** -54
+        return x;
+    } else {
+        (0, x, 0.0)
+    };
+
+    // x is now in the primary range
+    let hfx = 0.5 * x;
+    let hxs = x * hfx;
+    let r1 = 1.0 + hxs * (Q1 + hxs * (Q2 + hxs * (Q3 + hxs * (Q4 + hxs * Q5))));
+    let t = 3.0 - r1 * hfx;
+    let mut e = hxs * ((r1 - t) / (6.0 - x * t));
+    if k == 0 {
+        // c is 0
+        return x - (x * e - hxs);
+    }
+    e = x * (e - c) - c;
+    e -= hxs;
+
+    // exp(x) ~ 2 ** k * (x_reduced - e + 1)
+    if k == -1 {
+        return 0.5 * (x - e) - 0.5;
+    }
+    if k == 1 {
+        if x < -0.25 {
+            return -2.0 * (e - (x + 0.5));
+        }
+        return 1.0 + 2.0 * (x - e);
+    }
+
+    let twopk = f64::from_bits(((0x3ff + k) as u64) << 52);
+    if !(0..=56).contains(&k) {
+        // Suffices to return exp(x) - 1
+        let y = x - e + 1.0;
+        let y = if k == 1024 {
+            y * 2.0 * 8.98846567431157953865e+307 // 0x1p1023
+        } else {
+            y * twopk
+        };
+        return y - 1.0;
+    }
+
+    let twomk = f64::from_bits(((0x3ff - k) as u64) << 52);
+    if k < 20 {
+        (x - e + (1.0 - twomk)) * twopk
+    } else {
+        (x - (e + twomk) + 1.0) * twopk
+    }
+}
+
+/// exp(x) / 2 for x >= log(f64::MAX), avoiding overflow in the intermediate results
+fn expo2(x: f64) -> f64 {
+    // K is odd, and K * ln2 has minimal relative error, and x - K * ln2 > log(f64::MIN_POSITIVE)
+    const K: u32 = 2043;
+    const KLN2: f64 = 1.41609968988396832315e+03; // 0x40962066, 0x151ADD8B
+    let scale = from_words((0x3ff + K / 2) << 20, 0);
+    // exp(x - K * ln2) * 2 ** (K - 1)
+    exp(x - KLN2) * scale * scale
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn sinh(x: f64) -> f64 {
+    let h = if x.to_bits() >> 63 != 0 { -0.5 } else { 0.5 };
+    let absx = fabs(x);
+    let w = high_word(absx);
+
+    if w < 0x40862e42 {
+        // |x| < log(f64::MAX)
+        let t = expm1(absx);
+        if w < 0x3ff00000 {
+            if w < 0x3ff00000 - (26 << 20) {
+                // |x| < 2 ** -26
+                return x;
+            }
+            return h * (2.0 * t - t * t / (t + 1.0));
+        }
+        return h * (t + t / (t + 1.0));
+    }
+
+    // |x| >= log(f64::MAX) or NaN
+    2.0 * h * expo2(absx)
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn cosh(x: f64) -> f64 {
+    let x = fabs(x);
+    let w = high_word(x);
+
+    if w < 0x3fe62e42 {
+        // |x| < log(2)
+        if w < 0x3ff00000 - (26 << 20) {
+            // |x| < 2 ** -26
+            return 1.0;
+        }
+        let t = expm1(x);
+        return 1.0 + t * t / (2.0 * (1.0 + t));
+    }
+
+    if w < 0x40862e42 {
+        // |x| < log(f64::MAX)
+        let t = exp(x);
+        return 0.5 * (t + 1.0 / t);
+    }
+
+    // |x| >= log(f64::MAX) or NaN
+    expo2(x)
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn tanh(x: f64) -> f64 {
+    let negative = x.to_bits() >> 63 != 0;
+    let x = fabs(x);
+    let w = high_word(x);
+
+    let t = if w > 0x3fe193ea {
+        // |x| > log(3) / 2 ~= 0.5493, or NaN
+        if w > 0x40340000 {
+            // |x| > 20 or NaN
+            1.0 - 0.0 / x
+        } else {
+            let t = expm1(2.0 * x);
+            1.0 - 2.0 / (t + 2.0)
+        }
+    } else if w > 0x3fd058ae {
+        // |x| > log(5/3) / 2 ~= 0.2554
+        let t = expm1(2.0 * x);
+        t / (t + 2.0)
+    } else if w >= 0x00100000 {
+        // |x| >= 2 ** -1022
+        let t = expm1(-2.0 * x);
+        -t / (t + 2.0)
+    } else {
+        // |x| is subnormal
+        x
+    };
+
+    if negative {
+        -t
+    } else {
+        t
+    }
+}
+
+//
+// Logarithms
+//
+
+const LG1: f64 = 6.666666666666735130e-01; // 0x3FE55555, 0x55555593
+const LG2: f64 = 3.999999999940941908e-01; // 0x3FD99999, 0x9997FA04
+const LG3: f64 = 2.857142874366239149e-01; // 0x3FD24924, 0x94229359
+const LG4: f64 = 2.222219843214978396e-01; // 0x3FCC71C5, 0x1D8E78AF
+const LG5: f64 = 1.818357216161805012e-01; // 0x3FC74664, 0x96CB03DE
+const LG6: f64 = 1.531383769920937332e-01; // 0x3FC39A09, 0xD078C69F
+const LG7: f64 = 1.479819860511658591e-01; // 0x3FC2F112, 0xDF3E5244
+
+/// Result of `log_reduce`: `x = 2 ** k * (1 + f)` with `1 + f` in [sqrt(2)/2, sqrt(2)], and the
+/// terms of `log(1 + f) = f - hfsq + s * (hfsq + r)`
+struct LogParts {
+    k: i32,
+    f: f64,
+    hfsq: f64,
+    s: f64,
+    r: f64,
+}
+
+/// Special cases of logarithms, or the reduced argument
+fn log_reduce(x: f64) -> Result<LogParts, f64> {
+    let mut x = x;
+    let mut hx = high_word(x);
+    let mut k = 0;
+
+    if hx < 0x00100000 || hx >> 31 != 0 {
+        if x.to_bits() << 1 == 0 {
+            // log(+-0) = -inf
+            return Err(-1.0 / (x * x));
+        }
+        if hx >> 31 != 0 {
+            // log(-#) = NaN
+            return Err((x - x) / 0.0);
+        }
+        // Subnormal, scale up x
+        k -= 54;
+        x *= 18014398509481984.0; // 0x1p54
+        hx = high_word(x);
+    } else if hx >= 0x7ff00000 {
+        // Inf or NaN
+        return Err(x);
+    } else if hx == 0x3ff00000 && low_word(x) == 0 {
+        // log(1) = 0
+        return Err(0.0);
+    }
+
+    // Reduce x into [sqrt(2)/2, sqrt(2)]
+    hx += 0x3ff00000 - 0x3fe6a09e;
+    k += (hx >> 20) as i32 - 0x3ff;
+    hx = (hx & 0x000fffff) + 0x3fe6a09e;
+    let x = with_high_word(x, hx);
+
+    let f = x - 1.0;
+    let hfsq = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+
+    Ok(LogParts {
+        k,
+        f,
+        hfsq,
+        s,
+        r: t2 + t1,
+    })
+}
+
+/// Splits `log(1 + f)` into `hi + lo`, where `hi` has 21 significant bits
+fn log_hi_lo(p: &LogParts) -> (f64, f64) {
+    let hi = with_low_word(p.f - p.hfsq, 0);
+    let lo = p.f - hi - p.hfsq + p.s * (p.hfsq + p.r);
+    (hi, lo)
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn log(x: f64) -> f64 {
+    let p = match log_reduce(x) {
+        Ok(p) => p,
+        Err(y) => return y,
+    };
+    let dk = p.k as f64;
+    p.s * (p.hfsq + p.r) + dk * LN2_LO - p.hfsq + p.f + dk * LN2_HI
+}
+
+const IVLN2HI: f64 = 1.44269504072144627571e+00; // 0x3FF71547, 0x65200000
+const IVLN2LO: f64 = 1.67517131648865118353e-10; // 0x3DE705FC, 0x2EEFA200
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn log2(x: f64) -> f64 {
+    let p = match log_reduce(x) {
+        Ok(p) => p,
+        Err(y) => return y,
+    };
+    let (hi, lo) = log_hi_lo(&p);
+
+    let val_hi = hi * IVLN2HI;
+    let val_lo = (lo + hi) * IVLN2LO + lo * IVLN2HI;
+
+    // Add k with extra precision
+    let y = p.k as f64;
+    let w = y + val_hi;
+    let val_lo = val_lo + (y - w) + val_hi;
+    val_lo + w
+}
+
+const IVLN10HI: f64 = 4.34294481878168880939e-01; // 0x3FDBCB7B, 0x15200000
+const IVLN10LO: f64 = 2.50829467116452752298e-11; // 0x3DBB9438, 0xCA9AADD5
+const LOG10_2HI: f64 = 3.01029995663611771306e-01; // 0x3FD34413, 0x509F6000
+const LOG10_2LO: f64 = 3.69423907715893078616e-13; // 0x3D59FEF3, 0x11F12B36
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn log10(x: f64) -> f64 {
+    let p = match log_reduce(x) {
+        Ok(p) => p,
+        Err(y) => return y,
+    };
+    let (hi, lo) = log_hi_lo(&p);
+
+    // val_hi + val_lo ~ log10(1 + f) + k * log10(2)
+    let val_hi = hi * IVLN10HI;
+    let dk = p.k as f64;
+    let y = dk * LOG10_2HI;
+    let val_lo = dk * LOG10_2LO + (lo + hi) * IVLN10LO + lo * IVLN10HI;
+
+    // Add y with extra precision
+    let w = y + val_hi;
+    let val_lo = val_lo + (y - w) + val_hi;
+    val_lo + w
+}
+
+//
+// Power
+//
+
+const BP: [f64; 2] = [1.0, 1.5];
+const DP_H: [f64; 2] = [0.0, 5.84962487220764160156e-01]; // 0x3FE2B803, 0x40000000
+const DP_L: [f64; 2] = [0.0, 1.35003920212974897128e-08]; // 0x3E4CFDEB, 0x43CFD006
+const TWO53: f64 = 9007199254740992.0; // 0x43400000, 0x00000000
+const HUGE: f64 = 1.0e300;
+const TINY: f64 = 1.0e-300;
+
+// Polynomial for (3/2) * (log(x) - 2s - 2/3 * s ** 3)
+const L1: f64 = 5.99999999999994648725e-01; // 0x3FE33333, 0x33333303
+const L2: f64 = 4.28571428578550184252e-01; // 0x3FDB6DB6, 0xDB6FABFF
+const L3: f64 = 3.33333329818377432918e-01; // 0x3FD55555, 0x518F264D
+const L4: f64 = 2.72728123808534006489e-01; // 0x3FD17460, 0xA91D4101
+const L5: f64 = 2.30660745775561754067e-01; // 0x3FCD864A, 0x93C9DB65
+const L6: f64 = 2.06975017800338417784e-01; // 0x3FCA7E28, 0x4A454EEF
+
+const LG2_FULL: f64 = 6.93147180559945286227e-01; // 0x3FE62E42, 0xFEFA39EF
+const LG2_H: f64 = 6.93147182464599609375e-01; // 0x3FE62E43, 0x00000000
+const LG2_L: f64 = -1.90465429995776804525e-09; // 0xBE205C61, 0x0CA86C39
+const OVT: f64 = 8.0085662595372944372e-17; // -(1024 - log2(ovfl + .5ulp))
+const CP: f64 = 9.61796693925975554329e-01; // 0x3FEEC709, 0xDC3A03FD, 2 / (3 * ln2)
+const CP_H: f64 = 9.61796700954437255859e-01; // 0x3FEEC709, 0xE0000000, (float)cp
+const CP_L: f64 = -7.02846165095275826516e-09; // 0xBE3E2FE0, 0x145B01F5, tail of CP_H
+const IVLN2: f64 = 1.44269504088896338700e+00; // 0x3FF71547, 0x652B82FE, 1 / ln2
+const IVLN2_H: f64 = 1.44269502162933349609e+00; // 0x3FF71547, 0x60000000, 24 bits of 1 / ln2
+const IVLN2_L: f64 = 1.92596299112661746887e-08; // 0x3E54AE0B, 0xF85DDF44, 1 / ln2 tail
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn pow(x: f64, y: f64) -> f64 {
+    let (hx, lx) = (high_word(x) as i32, low_word(x));
+    let (hy, ly) = (high_word(y) as i32, low_word(y));
+    let mut ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    // x ** 0 = 1, even if x is NaN
+    if (iy as u32 | ly) == 0 {
+        return 1.0;
+    }
+
+    // 1 ** y = 1, even if y is NaN
+    if hx == 0x3ff00000 && lx == 0 {
+        return 1.0;
+    }
+
+    // NaN if either argument is NaN
+    if ix > 0x7ff00000
+        || (ix == 0x7ff00000 && lx != 0)
+        || iy > 0x7ff00000
+        || (iy == 0x7ff00000 && ly != 0)
+    {
+        return x + y;
+    }
+
+    // When x < 0, determine if y is an odd integer:
+    // 0: y is not an integer, 1: y is an odd integer, 2: y is an even integer
+    let mut yisint = 0;
+    if hx < 0 {
+        if iy >= 0x43400000 {
+            // |y| >= 2 ** 53, an even integer
+            yisint = 2;
+        } else if iy >= 0x3ff00000 {
+            let k = (iy >> 20) - 0x3ff; // Exponent
+            if k > 20 {
+                let j = ly >> (52 - k);
+                if (j << (52 - k)) == ly {
+                    yisint = 2 - (j & 1) as i32;
+                }
+            } else if ly == 0 {
+                let j = iy >> (20 - k);
+                if (j << (20 - k)) == iy {
+                    yisint = 2 - (j & 1);
+                }
+            }
+        }
+    }
+
+    // Special values of y
+    if ly == 0 {
+        if iy == 0x7ff00000 {
+            // y is +-inf
+            return if ((ix - 0x3ff00000) as u32 | lx) == 0 {
+                // (-1) ** +-inf = 1
+                1.0
+            } else if ix >= 0x3ff00000 {
+                // (|x| > 1) ** +-inf = inf, 0
+                if hy >= 0 {
+                    y
+                } else {
+                    0.0
+                }
+            } else if hy >= 0 {
+                // (|x| < 1) ** +-inf = 0, inf
+                0.0
+            } else {
+                -y
+            };
+        }
+        if iy == 0x3ff00000 {
+            // y is +-1
+            return if hy >= 0 { x } else { 1.0 / x };
+        }
+        if hy == 0x40000000 {
+            // y is 2
+            return x * x;
+        }
+        if hy == 0x3fe00000 && hx >= 0 {
+            // y is 0.5, x >= +0
+            return sqrt(x);
+        }
+    }
+
+    let mut ax = fabs(x);
+
+    // Special values of x
+    if lx == 0 && (ix == 0x7ff00000 || ix == 0 || ix == 0x3ff00000) {
+        // x is +-0, +-inf, +-1
+        let mut z = ax;
+        if hy < 0 {
+            z = 1.0 / z;
+        }
+        if hx < 0 {
+            if ((ix - 0x3ff00000) | yisint) == 0 {
+                // (-1) ** non-integer is NaN
+                z = (z - z) / (z - z);
+            } else if yisint == 1 {
+                // (x < 0) ** odd = -(|x| ** odd)
+                z = -z;
+            }
+        }
+        return z;
+    }
+
+    // Sign of the result
+    let mut s = 1.0;
+    if hx < 0 {
+        if yisint == 0 {
+            // (x < 0) ** non-integer is NaN
+            return (x - x) / (x - x);
+        }
+        if yisint == 1 {
+            // (x < 0) ** odd integer
+            s = -1.0;
+        }
+    }
+
+    // log2(ax) = t1 + t2
+    let (t1, t2) = if iy > 0x41e00000 {
+        // |y| > 2 ** 31
+        if iy > 0x43f00000 {
+            // |y| > 2 ** 64, must overflow or underflow
+            if ix <= 0x3fefffff {
+                return if hy < 0 { HUGE * HUGE } else { TINY * TINY };
+            }
+            if ix >= 0x3ff00000 {
+                return if hy > 0 { HUGE * HUGE } else { TINY * TINY };
+            }
+        }
+        // Overflow or underflow if x is not close to one
+        if ix < 0x3fefffff {
+            return if hy < 0 {
+                s * HUGE * HUGE
+            } else {
+                s * TINY * TINY
+            };
+        }
+        if ix > 0x3ff00000 {
+            return if hy > 0 {
+                s * HUGE * HUGE
+            } else {
+                s * TINY * TINY
+            };
+        }
+
+        // Now |1 - x| is tiny <= 2 ** -20, so log(x) ~ x - x ** 2 / 2 + x ** 3 / 3 - x ** 4 / 4
+        let t = ax - 1.0; // Exact, up to 33 significant bits
+        let w = (t * t) * (0.5 - t * (0.3333333333333333333333 - t * 0.25));
+        // IVLN2_H has 24 significant bits, so multiply it with the high 21 bits of t to get an
+        // exact product. fdlibm uses all of t here, which loses up to 300 ULP in the result.
+        let t_h = with_low_word(t, 0);
+        let u = IVLN2_H * t_h;
+        let v = (t - t_h) * IVLN2_H + t * IVLN2_L - w * IVLN2;
+        let t1 = with_low_word(u + v, 0);
+        let t2 = v - (t1 - u);
+        (t1, t2)
+    } else {
+        let mut n = 0;
+
+        // Subnormal x
+        if ix < 0x00100000 {
+            ax *= TWO53;
+            n -= 53;
+            ix = high_word(ax) as i32;
+        }
+        n += (ix >> 20) - 0x3ff;
+        let j = ix & 0x000fffff;
+
+        // Determine the interval
+        ix = j | 0x3ff00000; // Normalize ix
+        let k = if j <= 0x3988E {
+            // |x| < sqrt(3/2)
+            0
+        } else if j < 0xBB67A {
+            // |x| < sqrt(3)
+            1
+        } else {
+            n += 1;
+            ix -= 0x00100000;
+            0
+        };
+        ax = with_high_word(ax, ix as u32);
+
+        // ss = s_h + s_l = (x - 1) / (x + 1) or (x - 1.5) / (x + 1.5)
+        let u = ax - BP[k];
+        let v = 1.0 / (ax + BP[k]);
+        let ss = u * v;
+        let s_h = with_low_word(ss, 0);
+        // t_h = ax + BP[k], high part
+        let t_h = from_words(
+            (((ix >> 1) | 0x20000000) + 0x00080000 + ((k as i32) << 18)) as u32,
+            0,
+        );
+        let t_l = ax - (t_h - BP[k]);
+        let s_l = v * ((u - s_h * t_h) - s_h * t_l);
+
+        // log(ax)
+        let s2 = ss * ss;
+        let mut r = s2 * s2 * (L1 + s2 * (L2 + s2 * (L3 + s2 * (L4 + s2 * (L5 + s2 * L6)))));
+        r += s_l * (s_h + ss);
+        let s2 = s_h * s_h;
+        let t_h = with_low_word(3.0 + s2 + r, 0);
+        let t_l = r - ((t_h - 3.0) - s2);
+
+        // u + v = ss * (1 + ...)
+        let u = s_h * t_h;
+        let v = s_l * t_h + t_l * ss;
+
+        // 2 / (3 * log2) * (ss + ...)
+        let p_h = with_low_word(u + v, 0);
+        let p_l = v - (p_h - u);
+        let z_h = CP_H * p_h; // CP_H + CP_L = 2 / (3 * log2)
+        let z_l = CP_L * p_h + p_l * CP + DP_L[k];
+
+        // log2(ax) = (ss + ...) * 2 / (3 * log2) = n + DP_H + z_h + z_l
+        let t = n as f64;
+        let t1 = with_low_word(((z_h + z_l) + DP_H[k]) + t, 0);
+        let t2 = z_l - (((t1 - t) - DP_H[k]) - z_h);
+        (t1, t2)
+    };
+
+    // Split y into y1 + y2 and compute (y1 + y2) * (t1 + t2)
+    let y1 = with_low_word(y, 0);
+    let p_l = (y - y1) * t1 + y * t2;
+    let mut p_h = y1 * t1;
+    let z = p_l + p_h;
+    let j = high_word(z) as i32;
+    let i = low_word(z) as i32;
+    if j >= 0x40900000 {
+        // z >= 1024
+        if ((j - 0x40900000) | i) != 0 {
+            // z > 1024
+            return s * HUGE * HUGE;
+        }
+        if p_l + OVT > z - p_h {
+            return s * HUGE * HUGE;
+        }
+    } else if (j & 0x7fffffff) >= 0x4090cc00 {
+        // z <= -1075
+        if ((j as u32).wrapping_sub(0xc090cc00) | i as u32) != 0 {
+            // z < -1075
+            return s * TINY * TINY;
+        }
+        if p_l <= z - p_h {
+            return s * TINY * TINY;
+        }
+    }
+
+    // 2 ** (p_h + p_l)
+    let i = j & 0x7fffffff;
+    let mut k = (i >> 20) - 0x3ff;
+    let mut n = 0;
+    if i > 0x3fe00000 {
+        // |z| > 0.5, set n = [z + 0.5]
+        n = j + (0x00100000 >> (k + 1));
+        k = ((n & 0x7fffffff) >> 20) - 0x3ff; // New k for n
+        let t = from_words((n & !(0x000fffff >> k)) as u32, 0);
+        n = ((n & 0x000fffff) | 0x00100000) >> (20 - k);
+        if j < 0 {
+            n = -n;
+        }
+        p_h -= t;
+    }
+    let t = with_low_word(p_l + p_h, 0);
+    let u = t * LG2_H;
+    let v = (p_l - (t - p_h)) * LG2_FULL + t * LG2_L;
+    let z = u + v;
+    let w = v - (z - u);
+    let t = z * z;
+    let t1 = z - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    let r = (z * t1) / (t1 - 2.0) - (w + z * w);
+    let z = 1.0 - (r - z);
+    let j = (high_word(z) as i32).wrapping_add(n << 20);
+    let z = if (j >> 20) <= 0 {
+        // Subnormal result
+        scalbn(z, n)
+    } else {
+        with_high_word(z, j as u32)
+    };
+    s * z
+}
+
+//
+// Hypotenuse
+//
+
+/// `x * x` as `hi + lo`, exactly
+fn sq(x: f64) -> (f64, f64) {
+    const SPLIT: f64 = 134217729.0; // 0x1p27 + 1
+    let xc = x * SPLIT;
+    let xh = x - xc + xc;
+    let xl = x - xh;
+    let hi = x * x;
+    let lo = xh * xh - hi + 2.0 * xh * xl + xl * xl;
+    (hi, lo)
+}
+
+#[cfg_attr(feature = "ic", no_mangle)]
+pub extern "C" fn hypot(x: f64, y: f64) -> f64 {
+    // Arrange |x| >= |y|
+    let mut ux = x.to_bits() & (u64::MAX >> 1);
+    let mut uy = y.to_bits() & (u64::MAX >> 1);
+    if ux < uy {
+        core::mem::swap(&mut ux, &mut uy);
+    }
+
+    let ex = (ux >> 52) as i32;
+    let ey = (uy >> 52) as i32;
+    let mut x = f64::from_bits(ux);
+    let mut y = f64::from_bits(uy);
+
+    // hypot(inf, nan) = inf
+    if ey == 0x7ff {
+        return y;
+    }
+    if ex == 0x7ff || uy == 0 {
+        return x;
+    }
+
+    // hypot(x, y) ~= x + y * y / x / 2 for small y / x
+    if ex - ey > 64 {
+        return x + y;
+    }
+
+    // Scale to avoid overflow of xh * xh and underflow of xl * xl in sq
+    let mut z = 1.0;
+    if ex > 0x3ff + 510 {
+        z = 5.26013590154837350724e+210; // 0x1p700
+        x *= 1.90109156629515982352e-211; // 0x1p-700
+        y *= 1.90109156629515982352e-211;
+    } else if ey < 0x3ff - 450 {
+        z = 1.90109156629515982352e-211;
+        x *= 5.26013590154837350724e+210;
+        y *= 5.26013590154837350724e+210;
+    }
+
+    let (hx, lx) = sq(x);
+    let (hy, ly) = sq(y);
+    z * sqrt(ly + lx + hy + hx)
+}
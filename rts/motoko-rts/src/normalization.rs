@@ -0,0 +1,487 @@
+//! Unicode normalization of Text values (Unicode Standard Annex #15)
+//!
+//! The four normalization forms are implemented as usual: canonical or compatibility decomposition
+//! (with algorithmic decomposition of Hangul syllables), canonical ordering of combining
+//! characters, and (for NFC and NFKC) canonical composition.
+//!
+//! The decomposed text is written to a scratch buffer allocated in the heap, with one word per
+//! character. The buffer does not need to survive GC. The tables are generated by
+//! `rts/gen-unicode-tables.py`.
+
+mod tables;
+
+use crate::memory::{alloc_blob, Memory};
+use crate::rts_trap_with;
+use crate::text::{alloc_text_blob, blob_of_text, text_compare};
+use crate::types::{Bytes, SkewedPtr};
+
+use tables::*;
+
+pub use tables::UNICODE_VERSION;
+
+use core::{slice, str};
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Normalization Form C: canonical decomposition followed by canonical composition
+pub const NFC: u32 = 0;
+
+/// Normalization Form D: canonical decomposition
+pub const NFD: u32 = 1;
+
+/// Normalization Form KC: compatibility decomposition followed by canonical composition
+pub const NFKC: u32 = 2;
+
+/// Normalization Form KD: compatibility decomposition
+pub const NFKD: u32 = 3;
+
+unsafe fn check_form(form: u32) {
+    if form > NFKD {
+        rts_trap_with("text_normalize: unknown normalization form");
+    }
+}
+
+fn is_compatibility_form(form: u32) -> bool {
+    form == NFKC || form == NFKD
+}
+
+fn is_composed_form(form: u32) -> bool {
+    form == NFC || form == NFKC
+}
+
+//
+// Hangul syllables are decomposed and composed algorithmically, see section 3.12 of the Unicode
+// Standard
+//
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+fn is_hangul_syllable(c: u32) -> bool {
+    c >= HANGUL_S_BASE && c < HANGUL_S_BASE + HANGUL_S_COUNT
+}
+
+/// Canonical combining class of a character
+pub fn combining_class(c: u32) -> u8 {
+    // Fast path for the characters below the first combining mark
+    if c < 0x300 {
+        return 0;
+    }
+
+    match COMBINING_CLASSES.binary_search_by(|&(first, last, _)| {
+        if last < c {
+            core::cmp::Ordering::Less
+        } else if first > c {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(idx) => COMBINING_CLASSES[idx].2,
+        Err(_) => 0,
+    }
+}
+
+/// Returns the (non-recursive) decomposition mapping of a character, and whether it is a
+/// compatibility mapping. Does not handle Hangul syllables.
+fn decomposition_mapping(c: u32) -> Option<(&'static [u32], bool)> {
+    if c < 0xA0 {
+        return None;
+    }
+
+    let idx = DECOMPOSITIONS.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+    let data = DECOMPOSITIONS[idx].1;
+    let offset = (data & 0xFFFF) as usize;
+    let len = ((data >> 16) & 0b1_1111) as usize;
+    let compat = data & (1 << 31) != 0;
+    Some((&DECOMPOSITION_CHARS[offset..offset + len], compat))
+}
+
+/// Calls `f` with the full canonical (or, if `compat` is set, compatibility) decomposition of a
+/// character
+fn decompose<F: FnMut(u32)>(c: u32, compat: bool, f: &mut F) {
+    if is_hangul_syllable(c) {
+        let s_index = c - HANGUL_S_BASE;
+        f(HANGUL_L_BASE + s_index / HANGUL_N_COUNT);
+        f(HANGUL_V_BASE + (s_index % HANGUL_N_COUNT) / HANGUL_T_COUNT);
+        let t_index = s_index % HANGUL_T_COUNT;
+        if t_index != 0 {
+            f(HANGUL_T_BASE + t_index);
+        }
+        return;
+    }
+
+    match decomposition_mapping(c) {
+        Some((mapping, is_compat)) if compat || !is_compat => {
+            // Mappings are at most four levels deep, so recursion is fine here
+            for &c in mapping {
+                decompose(c, compat, f);
+            }
+        }
+        _ => f(c),
+    }
+}
+
+/// Returns the primary composite of two characters, if there is one
+fn compose_pair(first: u32, second: u32) -> Option<u32> {
+    // <L, V> to <LV>
+    if first >= HANGUL_L_BASE
+        && first < HANGUL_L_BASE + HANGUL_L_COUNT
+        && second >= HANGUL_V_BASE
+        && second < HANGUL_V_BASE + HANGUL_V_COUNT
+    {
+        let l_index = first - HANGUL_L_BASE;
+        let v_index = second - HANGUL_V_BASE;
+        return Some(HANGUL_S_BASE + (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT);
+    }
+
+    // <LV, T> to <LVT>
+    if is_hangul_syllable(first)
+        && (first - HANGUL_S_BASE) % HANGUL_T_COUNT == 0
+        && second > HANGUL_T_BASE
+        && second < HANGUL_T_BASE + HANGUL_T_COUNT
+    {
+        return Some(first + (second - HANGUL_T_BASE));
+    }
+
+    COMPOSITIONS
+        .binary_search_by_key(&(first, second), |&(first, second, _)| (first, second))
+        .ok()
+        .map(|idx| COMPOSITIONS[idx].2)
+}
+
+/// Whether the character occurs as the second character of a primary composite
+fn is_composition_second(c: u32) -> bool {
+    COMPOSITION_SECONDS
+        .binary_search_by(|&(first, last)| {
+            if last < c {
+                core::cmp::Ordering::Less
+            } else if first > c {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Whether the full decomposition of a character involves a compatibility mapping
+fn has_compatibility_decomposition(c: u32) -> bool {
+    match decomposition_mapping(c) {
+        None => false,
+        Some((_, true)) => true,
+        Some((mapping, false)) => mapping.iter().any(|&c| has_compatibility_decomposition(c)),
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum QuickCheck {
+    Yes,
+    No,
+    Maybe,
+}
+
+/// The `NF*_Quick_Check` property of a character
+fn quick_check_char(c: u32, form: u32) -> QuickCheck {
+    if is_hangul_syllable(c) {
+        return if is_composed_form(form) {
+            QuickCheck::Yes
+        } else {
+            QuickCheck::No
+        };
+    }
+
+    if is_compatibility_form(form) && has_compatibility_decomposition(c) {
+        return QuickCheck::No;
+    }
+
+    match decomposition_mapping(c) {
+        Some((mapping, false)) => {
+            if !is_composed_form(form) {
+                return QuickCheck::No;
+            }
+            // Characters that are not primary composites (singletons, non-starter decompositions
+            // and composition exclusions) never occur in composed forms
+            if mapping.len() != 2 || compose_pair(mapping[0], mapping[1]) != Some(c) {
+                return QuickCheck::No;
+            }
+        }
+        _ => {}
+    }
+
+    if is_composed_form(form) && is_composition_second(c) {
+        QuickCheck::Maybe
+    } else {
+        QuickCheck::Yes
+    }
+}
+
+/// Quick check algorithm of UAX #15. Returns `Maybe` when the text has to be normalized to know
+/// whether it is already normalized.
+fn quick_check(s: &str, form: u32) -> QuickCheck {
+    let mut result = QuickCheck::Yes;
+    let mut last_ccc = 0;
+
+    for c in s.chars() {
+        let c = c as u32;
+
+        // ASCII is unchanged by all normalization forms, and does not combine with anything that
+        // precedes it
+        if c < 0x80 {
+            last_ccc = 0;
+            continue;
+        }
+
+        let ccc = combining_class(c);
+        if last_ccc > ccc && ccc != 0 {
+            return QuickCheck::No;
+        }
+
+        match quick_check_char(c, form) {
+            QuickCheck::No => return QuickCheck::No,
+            QuickCheck::Maybe => result = QuickCheck::Maybe,
+            QuickCheck::Yes => {}
+        }
+
+        last_ccc = ccc;
+    }
+
+    result
+}
+
+/// Sorts a sequence of non-starters by their canonical combining classes, keeping the relative
+/// order of characters with the same class. `tmp` is used as scratch space, and needs to be at
+/// least as long as `chars`.
+fn canonical_sort(chars: &mut [u32], tmp: &mut [u32]) {
+    // Sequences of combining characters are short in practice, use insertion sort for those
+    if chars.len() <= 16 {
+        for i in 1..chars.len() {
+            let c = chars[i];
+            let ccc = combining_class(c);
+            let mut j = i;
+            while j > 0 && combining_class(chars[j - 1]) > ccc {
+                chars[j] = chars[j - 1];
+                j -= 1;
+            }
+            chars[j] = c;
+        }
+        return;
+    }
+
+    // Counting sort for long sequences, to avoid quadratic behavior with malicious input
+    let mut counts = [0u32; 256];
+    for &c in chars.iter() {
+        counts[combining_class(c) as usize] += 1;
+    }
+
+    let mut offset = 0;
+    for count in counts.iter_mut() {
+        let n = *count;
+        *count = offset;
+        offset += n;
+    }
+
+    for &c in chars.iter() {
+        let ccc = combining_class(c) as usize;
+        tmp[counts[ccc] as usize] = c;
+        counts[ccc] += 1;
+    }
+
+    chars.copy_from_slice(&tmp[..chars.len()]);
+}
+
+/// Canonical composition algorithm of UAX #15, in place. Returns the new length.
+fn canonical_compose(chars: &mut [u32]) -> usize {
+    if chars.is_empty() {
+        return 0;
+    }
+
+    // Position of the last starter in the output, if it can still be combined with
+    let mut starter_idx: Option<usize> = if combining_class(chars[0]) == 0 {
+        Some(0)
+    } else {
+        None
+    };
+    // Combining class of the last character written to the output after the starter
+    let mut last_ccc: Option<u8> = None;
+    let mut out = 1;
+
+    for i in 1..chars.len() {
+        let c = chars[i];
+        let ccc = combining_class(c);
+
+        if let Some(starter_idx) = starter_idx {
+            // The character can combine with the starter if it's not blocked
+            let blocked = match last_ccc {
+                None => false,
+                Some(last_ccc) => last_ccc >= ccc,
+            };
+
+            if !blocked {
+                if let Some(composite) = compose_pair(chars[starter_idx], c) {
+                    chars[starter_idx] = composite;
+                    continue;
+                }
+            }
+        }
+
+        if ccc == 0 {
+            starter_idx = Some(out);
+            last_ccc = None;
+        } else {
+            last_ccc = Some(ccc);
+        }
+
+        chars[out] = c;
+        out += 1;
+    }
+
+    out
+}
+
+fn utf8_len(c: u32) -> u32 {
+    if c < 0x80 {
+        1
+    } else if c < 0x800 {
+        2
+    } else if c < 0x10000 {
+        3
+    } else {
+        4
+    }
+}
+
+/// Normalizes the string, returning a new text
+unsafe fn normalize_str<M: Memory>(mem: &mut M, s: &str, form: u32) -> SkewedPtr {
+    let compat = is_compatibility_form(form);
+
+    // Compute the length of the decomposition to allocate the scratch buffer
+    let mut n_chars: u32 = 0;
+    for c in s.chars() {
+        decompose(c as u32, compat, &mut |_| n_chars += 1);
+    }
+
+    // Decomposition expands a character to at most 18 characters, so this can only happen with
+    // very large texts
+    if n_chars > 1 << 27 {
+        rts_trap_with("text_normalize: Text too large");
+    }
+
+    // The second half of the buffer is used when sorting long sequences of combining characters
+    let buf_blob = alloc_blob(mem, Bytes(n_chars * 2 * 4));
+    let buf_payload = buf_blob.as_blob().payload_addr() as *mut u32;
+    let chars = slice::from_raw_parts_mut(buf_payload, n_chars as usize);
+    let tmp = slice::from_raw_parts_mut(buf_payload.add(n_chars as usize), n_chars as usize);
+
+    // Decompose
+    let mut len = 0;
+    for c in s.chars() {
+        decompose(c as u32, compat, &mut |c| {
+            chars[len] = c;
+            len += 1;
+        });
+    }
+
+    // Reorder sequences of non-starters
+    let mut i = 0;
+    while i < len {
+        if combining_class(chars[i]) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < len && combining_class(chars[i]) != 0 {
+            i += 1;
+        }
+        canonical_sort(&mut chars[start..i], tmp);
+    }
+
+    // Compose
+    if is_composed_form(form) {
+        len = canonical_compose(&mut chars[..len]);
+    }
+
+    // Encode the result
+    let n_bytes = chars[..len].iter().map(|&c| utf8_len(c)).sum();
+    let text = alloc_text_blob(mem, Bytes(n_bytes));
+    let mut dst = text.as_blob().payload_addr();
+    for &c in chars[..len].iter() {
+        let encoded = char::from_u32_unchecked(c).encode_utf8(slice::from_raw_parts_mut(dst, 4));
+        dst = dst.add(encoded.len());
+    }
+
+    text
+}
+
+unsafe fn text_as_str<'a>(blob: SkewedPtr) -> &'a str {
+    let blob = blob.as_blob();
+    str::from_utf8_unchecked(slice::from_raw_parts(
+        blob.payload_addr(),
+        blob.len().as_usize(),
+    ))
+}
+
+/// Returns the text in the given normalization form. The text itself is returned when it's
+/// already normalized.
+#[ic_mem_fn]
+pub unsafe fn text_normalize<M: Memory>(mem: &mut M, text: SkewedPtr, form: u32) -> SkewedPtr {
+    check_form(form);
+
+    let blob = blob_of_text(mem, text);
+    let s = text_as_str(blob);
+
+    if quick_check(s, form) == QuickCheck::Yes {
+        return text;
+    }
+
+    normalize_str(mem, s, form)
+}
+
+/// Returns whether the text is in the given normalization form. Only allocates when the text is a
+/// concatenation, or when the quick check is inconclusive.
+#[ic_mem_fn]
+pub unsafe fn text_is_normalized<M: Memory>(mem: &mut M, text: SkewedPtr, form: u32) -> bool {
+    check_form(form);
+
+    let blob = blob_of_text(mem, text);
+    let s = text_as_str(blob);
+
+    match quick_check(s, form) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => text_compare(blob, normalize_str(mem, s, form)) == 0,
+    }
+}
+
+/// Compares two texts after normalizing them to the given form. Returns the same values as
+/// `text_compare`.
+#[ic_mem_fn]
+pub unsafe fn text_compare_normalized<M: Memory>(
+    mem: &mut M,
+    s1: SkewedPtr,
+    s2: SkewedPtr,
+    form: u32,
+) -> i32 {
+    let s1 = text_normalize(mem, s1, form);
+    let s2 = text_normalize(mem, s2, form);
+    text_compare(s1, s2)
+}
+
+/// Whether two texts are equal after normalizing them to the given form, e.g. `"é"` (U+00E9) and
+/// `"é"` (U+0065 U+0301) are equal in all normalization forms
+#[ic_mem_fn]
+pub unsafe fn text_eq_normalized<M: Memory>(
+    mem: &mut M,
+    s1: SkewedPtr,
+    s2: SkewedPtr,
+    form: u32,
+) -> bool {
+    text_compare_normalized(mem, s1, s2, form) == 0
+}
@@ -0,0 +1,2764 @@
+// Generated by rts/gen-unicode-tables.py from the Unicode Character Database, version
+// 14.0.0. Do not edit.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+pub const UNICODE_VERSION: (u8, u8, u8) = (14, 0, 0);
+
+/// Non-zero canonical combining classes, as `(first, last, class)` ranges
+pub(super) static COMBINING_CLASSES: [(u32, u32, u8); 382] = [
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216), (0x31C, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230), (0x34D, 0x34E, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36F, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230), (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18), (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65B, 230), (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230), (0x6ED, 0x6ED, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230), (0x73E, 0x73E, 220), (0x73F, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230), (0x7FD, 0x7FD, 220), (0x816, 0x819, 230),
+    (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x898, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230), (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220), (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7), (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91),
+    (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9), (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9),
+    (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220),
+    (0xF35, 0xF35, 220), (0xF37, 0xF37, 220), (0xF39, 0xF39, 216), (0xF71, 0xF71, 129),
+    (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ACE, 230), (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230), (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9), (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9), (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6), (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+/// Decomposition mappings (excluding Hangul syllables), as `(code point, data)`, sorted by code
+/// point. `data` holds the offset of the mapping in `DECOMPOSITION_CHARS` in bits 0-15, the length of
+/// the mapping in bits 16-20, and whether the mapping is a compatibility mapping in bit 31.
+///
+/// The mappings are not applied recursively.
+pub(super) static DECOMPOSITIONS: [(u32, u32); 5795] = [
+    (0xA0, 0x80010000), (0xA8, 0x80020001), (0xAA, 0x80010003), (0xAF, 0x80020004),
+    (0xB2, 0x80010006), (0xB3, 0x80010007), (0xB4, 0x80020008), (0xB5, 0x8001000A),
+    (0xB8, 0x8002000B), (0xB9, 0x8001000D), (0xBA, 0x8001000E), (0xBC, 0x8003000F),
+    (0xBD, 0x80030012), (0xBE, 0x80030015), (0xC0, 0x20018), (0xC1, 0x2001A),
+    (0xC2, 0x2001C), (0xC3, 0x2001E), (0xC4, 0x20020), (0xC5, 0x20022),
+    (0xC7, 0x20024), (0xC8, 0x20026), (0xC9, 0x20028), (0xCA, 0x2002A),
+    (0xCB, 0x2002C), (0xCC, 0x2002E), (0xCD, 0x20030), (0xCE, 0x20032),
+    (0xCF, 0x20034), (0xD1, 0x20036), (0xD2, 0x20038), (0xD3, 0x2003A),
+    (0xD4, 0x2003C), (0xD5, 0x2003E), (0xD6, 0x20040), (0xD9, 0x20042),
+    (0xDA, 0x20044), (0xDB, 0x20046), (0xDC, 0x20048), (0xDD, 0x2004A),
+    (0xE0, 0x2004C), (0xE1, 0x2004E), (0xE2, 0x20050), (0xE3, 0x20052),
+    (0xE4, 0x20054), (0xE5, 0x20056), (0xE7, 0x20058), (0xE8, 0x2005A),
+    (0xE9, 0x2005C), (0xEA, 0x2005E), (0xEB, 0x20060), (0xEC, 0x20062),
+    (0xED, 0x20064), (0xEE, 0x20066), (0xEF, 0x20068), (0xF1, 0x2006A),
+    (0xF2, 0x2006C), (0xF3, 0x2006E), (0xF4, 0x20070), (0xF5, 0x20072),
+    (0xF6, 0x20074), (0xF9, 0x20076), (0xFA, 0x20078), (0xFB, 0x2007A),
+    (0xFC, 0x2007C), (0xFD, 0x2007E), (0xFF, 0x20080), (0x100, 0x20082),
+    (0x101, 0x20084), (0x102, 0x20086), (0x103, 0x20088), (0x104, 0x2008A),
+    (0x105, 0x2008C), (0x106, 0x2008E), (0x107, 0x20090), (0x108, 0x20092),
+    (0x109, 0x20094), (0x10A, 0x20096), (0x10B, 0x20098), (0x10C, 0x2009A),
+    (0x10D, 0x2009C), (0x10E, 0x2009E), (0x10F, 0x200A0), (0x112, 0x200A2),
+    (0x113, 0x200A4), (0x114, 0x200A6), (0x115, 0x200A8), (0x116, 0x200AA),
+    (0x117, 0x200AC), (0x118, 0x200AE), (0x119, 0x200B0), (0x11A, 0x200B2),
+    (0x11B, 0x200B4), (0x11C, 0x200B6), (0x11D, 0x200B8), (0x11E, 0x200BA),
+    (0x11F, 0x200BC), (0x120, 0x200BE), (0x121, 0x200C0), (0x122, 0x200C2),
+    (0x123, 0x200C4), (0x124, 0x200C6), (0x125, 0x200C8), (0x128, 0x200CA),
+    (0x129, 0x200CC), (0x12A, 0x200CE), (0x12B, 0x200D0), (0x12C, 0x200D2),
+    (0x12D, 0x200D4), (0x12E, 0x200D6), (0x12F, 0x200D8), (0x130, 0x200DA),
+    (0x132, 0x800200DC), (0x133, 0x800200DE), (0x134, 0x200E0), (0x135, 0x200E2),
+    (0x136, 0x200E4), (0x137, 0x200E6), (0x139, 0x200E8), (0x13A, 0x200EA),
+    (0x13B, 0x200EC), (0x13C, 0x200EE), (0x13D, 0x200F0), (0x13E, 0x200F2),
+    (0x13F, 0x800200F4), (0x140, 0x800200F6), (0x143, 0x200F8), (0x144, 0x200FA),
+    (0x145, 0x200FC), (0x146, 0x200FE), (0x147, 0x20100), (0x148, 0x20102),
+    (0x149, 0x80020104), (0x14C, 0x20106), (0x14D, 0x20108), (0x14E, 0x2010A),
+    (0x14F, 0x2010C), (0x150, 0x2010E), (0x151, 0x20110), (0x154, 0x20112),
+    (0x155, 0x20114), (0x156, 0x20116), (0x157, 0x20118), (0x158, 0x2011A),
+    (0x159, 0x2011C), (0x15A, 0x2011E), (0x15B, 0x20120), (0x15C, 0x20122),
+    (0x15D, 0x20124), (0x15E, 0x20126), (0x15F, 0x20128), (0x160, 0x2012A),
+    (0x161, 0x2012C), (0x162, 0x2012E), (0x163, 0x20130), (0x164, 0x20132),
+    (0x165, 0x20134), (0x168, 0x20136), (0x169, 0x20138), (0x16A, 0x2013A),
+    (0x16B, 0x2013C), (0x16C, 0x2013E), (0x16D, 0x20140), (0x16E, 0x20142),
+    (0x16F, 0x20144), (0x170, 0x20146), (0x171, 0x20148), (0x172, 0x2014A),
+    (0x173, 0x2014C), (0x174, 0x2014E), (0x175, 0x20150), (0x176, 0x20152),
+    (0x177, 0x20154), (0x178, 0x20156), (0x179, 0x20158), (0x17A, 0x2015A),
+    (0x17B, 0x2015C), (0x17C, 0x2015E), (0x17D, 0x20160), (0x17E, 0x20162),
+    (0x17F, 0x80010164), (0x1A0, 0x20165), (0x1A1, 0x20167), (0x1AF, 0x20169),
+    (0x1B0, 0x2016B), (0x1C4, 0x8002016D), (0x1C5, 0x8002016F), (0x1C6, 0x80020171),
+    (0x1C7, 0x80020173), (0x1C8, 0x80020175), (0x1C9, 0x80020177), (0x1CA, 0x80020179),
+    (0x1CB, 0x8002017B), (0x1CC, 0x8002017D), (0x1CD, 0x2017F), (0x1CE, 0x20181),
+    (0x1CF, 0x20183), (0x1D0, 0x20185), (0x1D1, 0x20187), (0x1D2, 0x20189),
+    (0x1D3, 0x2018B), (0x1D4, 0x2018D), (0x1D5, 0x2018F), (0x1D6, 0x20191),
+    (0x1D7, 0x20193), (0x1D8, 0x20195), (0x1D9, 0x20197), (0x1DA, 0x20199),
+    (0x1DB, 0x2019B), (0x1DC, 0x2019D), (0x1DE, 0x2019F), (0x1DF, 0x201A1),
+    (0x1E0, 0x201A3), (0x1E1, 0x201A5), (0x1E2, 0x201A7), (0x1E3, 0x201A9),
+    (0x1E6, 0x201AB), (0x1E7, 0x201AD), (0x1E8, 0x201AF), (0x1E9, 0x201B1),
+    (0x1EA, 0x201B3), (0x1EB, 0x201B5), (0x1EC, 0x201B7), (0x1ED, 0x201B9),
+    (0x1EE, 0x201BB), (0x1EF, 0x201BD), (0x1F0, 0x201BF), (0x1F1, 0x800201C1),
+    (0x1F2, 0x800201C3), (0x1F3, 0x800201C5), (0x1F4, 0x201C7), (0x1F5, 0x201C9),
+    (0x1F8, 0x201CB), (0x1F9, 0x201CD), (0x1FA, 0x201CF), (0x1FB, 0x201D1),
+    (0x1FC, 0x201D3), (0x1FD, 0x201D5), (0x1FE, 0x201D7), (0x1FF, 0x201D9),
+    (0x200, 0x201DB), (0x201, 0x201DD), (0x202, 0x201DF), (0x203, 0x201E1),
+    (0x204, 0x201E3), (0x205, 0x201E5), (0x206, 0x201E7), (0x207, 0x201E9),
+    (0x208, 0x201EB), (0x209, 0x201ED), (0x20A, 0x201EF), (0x20B, 0x201F1),
+    (0x20C, 0x201F3), (0x20D, 0x201F5), (0x20E, 0x201F7), (0x20F, 0x201F9),
+    (0x210, 0x201FB), (0x211, 0x201FD), (0x212, 0x201FF), (0x213, 0x20201),
+    (0x214, 0x20203), (0x215, 0x20205), (0x216, 0x20207), (0x217, 0x20209),
+    (0x218, 0x2020B), (0x219, 0x2020D), (0x21A, 0x2020F), (0x21B, 0x20211),
+    (0x21E, 0x20213), (0x21F, 0x20215), (0x226, 0x20217), (0x227, 0x20219),
+    (0x228, 0x2021B), (0x229, 0x2021D), (0x22A, 0x2021F), (0x22B, 0x20221),
+    (0x22C, 0x20223), (0x22D, 0x20225), (0x22E, 0x20227), (0x22F, 0x20229),
+    (0x230, 0x2022B), (0x231, 0x2022D), (0x232, 0x2022F), (0x233, 0x20231),
+    (0x2B0, 0x80010233), (0x2B1, 0x80010234), (0x2B2, 0x80010235), (0x2B3, 0x80010236),
+    (0x2B4, 0x80010237), (0x2B5, 0x80010238), (0x2B6, 0x80010239), (0x2B7, 0x8001023A),
+    (0x2B8, 0x8001023B), (0x2D8, 0x8002023C), (0x2D9, 0x8002023E), (0x2DA, 0x80020240),
+    (0x2DB, 0x80020242), (0x2DC, 0x80020244), (0x2DD, 0x80020246), (0x2E0, 0x80010248),
+    (0x2E1, 0x80010249), (0x2E2, 0x8001024A), (0x2E3, 0x8001024B), (0x2E4, 0x8001024C),
+    (0x340, 0x1024D), (0x341, 0x1024E), (0x343, 0x1024F), (0x344, 0x20250),
+    (0x374, 0x10252), (0x37A, 0x80020253), (0x37E, 0x10255), (0x384, 0x80020256),
+    (0x385, 0x20258), (0x386, 0x2025A), (0x387, 0x1025C), (0x388, 0x2025D),
+    (0x389, 0x2025F), (0x38A, 0x20261), (0x38C, 0x20263), (0x38E, 0x20265),
+    (0x38F, 0x20267), (0x390, 0x20269), (0x3AA, 0x2026B), (0x3AB, 0x2026D),
+    (0x3AC, 0x2026F), (0x3AD, 0x20271), (0x3AE, 0x20273), (0x3AF, 0x20275),
+    (0x3B0, 0x20277), (0x3CA, 0x20279), (0x3CB, 0x2027B), (0x3CC, 0x2027D),
+    (0x3CD, 0x2027F), (0x3CE, 0x20281), (0x3D0, 0x80010283), (0x3D1, 0x80010284),
+    (0x3D2, 0x80010285), (0x3D3, 0x20286), (0x3D4, 0x20288), (0x3D5, 0x8001028A),
+    (0x3D6, 0x8001028B), (0x3F0, 0x8001028C), (0x3F1, 0x8001028D), (0x3F2, 0x8001028E),
+    (0x3F4, 0x8001028F), (0x3F5, 0x80010290), (0x3F9, 0x80010291), (0x400, 0x20292),
+    (0x401, 0x20294), (0x403, 0x20296), (0x407, 0x20298), (0x40C, 0x2029A),
+    (0x40D, 0x2029C), (0x40E, 0x2029E), (0x419, 0x202A0), (0x439, 0x202A2),
+    (0x450, 0x202A4), (0x451, 0x202A6), (0x453, 0x202A8), (0x457, 0x202AA),
+    (0x45C, 0x202AC), (0x45D, 0x202AE), (0x45E, 0x202B0), (0x476, 0x202B2),
+    (0x477, 0x202B4), (0x4C1, 0x202B6), (0x4C2, 0x202B8), (0x4D0, 0x202BA),
+    (0x4D1, 0x202BC), (0x4D2, 0x202BE), (0x4D3, 0x202C0), (0x4D6, 0x202C2),
+    (0x4D7, 0x202C4), (0x4DA, 0x202C6), (0x4DB, 0x202C8), (0x4DC, 0x202CA),
+    (0x4DD, 0x202CC), (0x4DE, 0x202CE), (0x4DF, 0x202D0), (0x4E2, 0x202D2),
+    (0x4E3, 0x202D4), (0x4E4, 0x202D6), (0x4E5, 0x202D8), (0x4E6, 0x202DA),
+    (0x4E7, 0x202DC), (0x4EA, 0x202DE), (0x4EB, 0x202E0), (0x4EC, 0x202E2),
+    (0x4ED, 0x202E4), (0x4EE, 0x202E6), (0x4EF, 0x202E8), (0x4F0, 0x202EA),
+    (0x4F1, 0x202EC), (0x4F2, 0x202EE), (0x4F3, 0x202F0), (0x4F4, 0x202F2),
+    (0x4F5, 0x202F4), (0x4F8, 0x202F6), (0x4F9, 0x202F8), (0x587, 0x800202FA),
+    (0x622, 0x202FC), (0x623, 0x202FE), (0x624, 0x20300), (0x625, 0x20302),
+    (0x626, 0x20304), (0x675, 0x80020306), (0x676, 0x80020308), (0x677, 0x8002030A),
+    (0x678, 0x8002030C), (0x6C0, 0x2030E), (0x6C2, 0x20310), (0x6D3, 0x20312),
+    (0x929, 0x20314), (0x931, 0x20316), (0x934, 0x20318), (0x958, 0x2031A),
+    (0x959, 0x2031C), (0x95A, 0x2031E), (0x95B, 0x20320), (0x95C, 0x20322),
+    (0x95D, 0x20324), (0x95E, 0x20326), (0x95F, 0x20328), (0x9CB, 0x2032A),
+    (0x9CC, 0x2032C), (0x9DC, 0x2032E), (0x9DD, 0x20330), (0x9DF, 0x20332),
+    (0xA33, 0x20334), (0xA36, 0x20336), (0xA59, 0x20338), (0xA5A, 0x2033A),
+    (0xA5B, 0x2033C), (0xA5E, 0x2033E), (0xB48, 0x20340), (0xB4B, 0x20342),
+    (0xB4C, 0x20344), (0xB5C, 0x20346), (0xB5D, 0x20348), (0xB94, 0x2034A),
+    (0xBCA, 0x2034C), (0xBCB, 0x2034E), (0xBCC, 0x20350), (0xC48, 0x20352),
+    (0xCC0, 0x20354), (0xCC7, 0x20356), (0xCC8, 0x20358), (0xCCA, 0x2035A),
+    (0xCCB, 0x2035C), (0xD4A, 0x2035E), (0xD4B, 0x20360), (0xD4C, 0x20362),
+    (0xDDA, 0x20364), (0xDDC, 0x20366), (0xDDD, 0x20368), (0xDDE, 0x2036A),
+    (0xE33, 0x8002036C), (0xEB3, 0x8002036E), (0xEDC, 0x80020370), (0xEDD, 0x80020372),
+    (0xF0C, 0x80010374), (0xF43, 0x20375), (0xF4D, 0x20377), (0xF52, 0x20379),
+    (0xF57, 0x2037B), (0xF5C, 0x2037D), (0xF69, 0x2037F), (0xF73, 0x20381),
+    (0xF75, 0x20383), (0xF76, 0x20385), (0xF77, 0x80020387), (0xF78, 0x20389),
+    (0xF79, 0x8002038B), (0xF81, 0x2038D), (0xF93, 0x2038F), (0xF9D, 0x20391),
+    (0xFA2, 0x20393), (0xFA7, 0x20395), (0xFAC, 0x20397), (0xFB9, 0x20399),
+    (0x1026, 0x2039B), (0x10FC, 0x8001039D), (0x1B06, 0x2039E), (0x1B08, 0x203A0),
+    (0x1B0A, 0x203A2), (0x1B0C, 0x203A4), (0x1B0E, 0x203A6), (0x1B12, 0x203A8),
+    (0x1B3B, 0x203AA), (0x1B3D, 0x203AC), (0x1B40, 0x203AE), (0x1B41, 0x203B0),
+    (0x1B43, 0x203B2), (0x1D2C, 0x800103B4), (0x1D2D, 0x800103B5), (0x1D2E, 0x800103B6),
+    (0x1D30, 0x800103B7), (0x1D31, 0x800103B8), (0x1D32, 0x800103B9), (0x1D33, 0x800103BA),
+    (0x1D34, 0x800103BB), (0x1D35, 0x800103BC), (0x1D36, 0x800103BD), (0x1D37, 0x800103BE),
+    (0x1D38, 0x800103BF), (0x1D39, 0x800103C0), (0x1D3A, 0x800103C1), (0x1D3C, 0x800103C2),
+    (0x1D3D, 0x800103C3), (0x1D3E, 0x800103C4), (0x1D3F, 0x800103C5), (0x1D40, 0x800103C6),
+    (0x1D41, 0x800103C7), (0x1D42, 0x800103C8), (0x1D43, 0x800103C9), (0x1D44, 0x800103CA),
+    (0x1D45, 0x800103CB), (0x1D46, 0x800103CC), (0x1D47, 0x800103CD), (0x1D48, 0x800103CE),
+    (0x1D49, 0x800103CF), (0x1D4A, 0x800103D0), (0x1D4B, 0x800103D1), (0x1D4C, 0x800103D2),
+    (0x1D4D, 0x800103D3), (0x1D4F, 0x800103D4), (0x1D50, 0x800103D5), (0x1D51, 0x800103D6),
+    (0x1D52, 0x800103D7), (0x1D53, 0x800103D8), (0x1D54, 0x800103D9), (0x1D55, 0x800103DA),
+    (0x1D56, 0x800103DB), (0x1D57, 0x800103DC), (0x1D58, 0x800103DD), (0x1D59, 0x800103DE),
+    (0x1D5A, 0x800103DF), (0x1D5B, 0x800103E0), (0x1D5C, 0x800103E1), (0x1D5D, 0x800103E2),
+    (0x1D5E, 0x800103E3), (0x1D5F, 0x800103E4), (0x1D60, 0x800103E5), (0x1D61, 0x800103E6),
+    (0x1D62, 0x800103E7), (0x1D63, 0x800103E8), (0x1D64, 0x800103E9), (0x1D65, 0x800103EA),
+    (0x1D66, 0x800103EB), (0x1D67, 0x800103EC), (0x1D68, 0x800103ED), (0x1D69, 0x800103EE),
+    (0x1D6A, 0x800103EF), (0x1D78, 0x800103F0), (0x1D9B, 0x800103F1), (0x1D9C, 0x800103F2),
+    (0x1D9D, 0x800103F3), (0x1D9E, 0x800103F4), (0x1D9F, 0x800103F5), (0x1DA0, 0x800103F6),
+    (0x1DA1, 0x800103F7), (0x1DA2, 0x800103F8), (0x1DA3, 0x800103F9), (0x1DA4, 0x800103FA),
+    (0x1DA5, 0x800103FB), (0x1DA6, 0x800103FC), (0x1DA7, 0x800103FD), (0x1DA8, 0x800103FE),
+    (0x1DA9, 0x800103FF), (0x1DAA, 0x80010400), (0x1DAB, 0x80010401), (0x1DAC, 0x80010402),
+    (0x1DAD, 0x80010403), (0x1DAE, 0x80010404), (0x1DAF, 0x80010405), (0x1DB0, 0x80010406),
+    (0x1DB1, 0x80010407), (0x1DB2, 0x80010408), (0x1DB3, 0x80010409), (0x1DB4, 0x8001040A),
+    (0x1DB5, 0x8001040B), (0x1DB6, 0x8001040C), (0x1DB7, 0x8001040D), (0x1DB8, 0x8001040E),
+    (0x1DB9, 0x8001040F), (0x1DBA, 0x80010410), (0x1DBB, 0x80010411), (0x1DBC, 0x80010412),
+    (0x1DBD, 0x80010413), (0x1DBE, 0x80010414), (0x1DBF, 0x80010415), (0x1E00, 0x20416),
+    (0x1E01, 0x20418), (0x1E02, 0x2041A), (0x1E03, 0x2041C), (0x1E04, 0x2041E),
+    (0x1E05, 0x20420), (0x1E06, 0x20422), (0x1E07, 0x20424), (0x1E08, 0x20426),
+    (0x1E09, 0x20428), (0x1E0A, 0x2042A), (0x1E0B, 0x2042C), (0x1E0C, 0x2042E),
+    (0x1E0D, 0x20430), (0x1E0E, 0x20432), (0x1E0F, 0x20434), (0x1E10, 0x20436),
+    (0x1E11, 0x20438), (0x1E12, 0x2043A), (0x1E13, 0x2043C), (0x1E14, 0x2043E),
+    (0x1E15, 0x20440), (0x1E16, 0x20442), (0x1E17, 0x20444), (0x1E18, 0x20446),
+    (0x1E19, 0x20448), (0x1E1A, 0x2044A), (0x1E1B, 0x2044C), (0x1E1C, 0x2044E),
+    (0x1E1D, 0x20450), (0x1E1E, 0x20452), (0x1E1F, 0x20454), (0x1E20, 0x20456),
+    (0x1E21, 0x20458), (0x1E22, 0x2045A), (0x1E23, 0x2045C), (0x1E24, 0x2045E),
+    (0x1E25, 0x20460), (0x1E26, 0x20462), (0x1E27, 0x20464), (0x1E28, 0x20466),
+    (0x1E29, 0x20468), (0x1E2A, 0x2046A), (0x1E2B, 0x2046C), (0x1E2C, 0x2046E),
+    (0x1E2D, 0x20470), (0x1E2E, 0x20472), (0x1E2F, 0x20474), (0x1E30, 0x20476),
+    (0x1E31, 0x20478), (0x1E32, 0x2047A), (0x1E33, 0x2047C), (0x1E34, 0x2047E),
+    (0x1E35, 0x20480), (0x1E36, 0x20482), (0x1E37, 0x20484), (0x1E38, 0x20486),
+    (0x1E39, 0x20488), (0x1E3A, 0x2048A), (0x1E3B, 0x2048C), (0x1E3C, 0x2048E),
+    (0x1E3D, 0x20490), (0x1E3E, 0x20492), (0x1E3F, 0x20494), (0x1E40, 0x20496),
+    (0x1E41, 0x20498), (0x1E42, 0x2049A), (0x1E43, 0x2049C), (0x1E44, 0x2049E),
+    (0x1E45, 0x204A0), (0x1E46, 0x204A2), (0x1E47, 0x204A4), (0x1E48, 0x204A6),
+    (0x1E49, 0x204A8), (0x1E4A, 0x204AA), (0x1E4B, 0x204AC), (0x1E4C, 0x204AE),
+    (0x1E4D, 0x204B0), (0x1E4E, 0x204B2), (0x1E4F, 0x204B4), (0x1E50, 0x204B6),
+    (0x1E51, 0x204B8), (0x1E52, 0x204BA), (0x1E53, 0x204BC), (0x1E54, 0x204BE),
+    (0x1E55, 0x204C0), (0x1E56, 0x204C2), (0x1E57, 0x204C4), (0x1E58, 0x204C6),
+    (0x1E59, 0x204C8), (0x1E5A, 0x204CA), (0x1E5B, 0x204CC), (0x1E5C, 0x204CE),
+    (0x1E5D, 0x204D0), (0x1E5E, 0x204D2), (0x1E5F, 0x204D4), (0x1E60, 0x204D6),
+    (0x1E61, 0x204D8), (0x1E62, 0x204DA), (0x1E63, 0x204DC), (0x1E64, 0x204DE),
+    (0x1E65, 0x204E0), (0x1E66, 0x204E2), (0x1E67, 0x204E4), (0x1E68, 0x204E6),
+    (0x1E69, 0x204E8), (0x1E6A, 0x204EA), (0x1E6B, 0x204EC), (0x1E6C, 0x204EE),
+    (0x1E6D, 0x204F0), (0x1E6E, 0x204F2), (0x1E6F, 0x204F4), (0x1E70, 0x204F6),
+    (0x1E71, 0x204F8), (0x1E72, 0x204FA), (0x1E73, 0x204FC), (0x1E74, 0x204FE),
+    (0x1E75, 0x20500), (0x1E76, 0x20502), (0x1E77, 0x20504), (0x1E78, 0x20506),
+    (0x1E79, 0x20508), (0x1E7A, 0x2050A), (0x1E7B, 0x2050C), (0x1E7C, 0x2050E),
+    (0x1E7D, 0x20510), (0x1E7E, 0x20512), (0x1E7F, 0x20514), (0x1E80, 0x20516),
+    (0x1E81, 0x20518), (0x1E82, 0x2051A), (0x1E83, 0x2051C), (0x1E84, 0x2051E),
+    (0x1E85, 0x20520), (0x1E86, 0x20522), (0x1E87, 0x20524), (0x1E88, 0x20526),
+    (0x1E89, 0x20528), (0x1E8A, 0x2052A), (0x1E8B, 0x2052C), (0x1E8C, 0x2052E),
+    (0x1E8D, 0x20530), (0x1E8E, 0x20532), (0x1E8F, 0x20534), (0x1E90, 0x20536),
+    (0x1E91, 0x20538), (0x1E92, 0x2053A), (0x1E93, 0x2053C), (0x1E94, 0x2053E),
+    (0x1E95, 0x20540), (0x1E96, 0x20542), (0x1E97, 0x20544), (0x1E98, 0x20546),
+    (0x1E99, 0x20548), (0x1E9A, 0x8002054A), (0x1E9B, 0x2054C), (0x1EA0, 0x2054E),
+    (0x1EA1, 0x20550), (0x1EA2, 0x20552), (0x1EA3, 0x20554), (0x1EA4, 0x20556),
+    (0x1EA5, 0x20558), (0x1EA6, 0x2055A), (0x1EA7, 0x2055C), (0x1EA8, 0x2055E),
+    (0x1EA9, 0x20560), (0x1EAA, 0x20562), (0x1EAB, 0x20564), (0x1EAC, 0x20566),
+    (0x1EAD, 0x20568), (0x1EAE, 0x2056A), (0x1EAF, 0x2056C), (0x1EB0, 0x2056E),
+    (0x1EB1, 0x20570), (0x1EB2, 0x20572), (0x1EB3, 0x20574), (0x1EB4, 0x20576),
+    (0x1EB5, 0x20578), (0x1EB6, 0x2057A), (0x1EB7, 0x2057C), (0x1EB8, 0x2057E),
+    (0x1EB9, 0x20580), (0x1EBA, 0x20582), (0x1EBB, 0x20584), (0x1EBC, 0x20586),
+    (0x1EBD, 0x20588), (0x1EBE, 0x2058A), (0x1EBF, 0x2058C), (0x1EC0, 0x2058E),
+    (0x1EC1, 0x20590), (0x1EC2, 0x20592), (0x1EC3, 0x20594), (0x1EC4, 0x20596),
+    (0x1EC5, 0x20598), (0x1EC6, 0x2059A), (0x1EC7, 0x2059C), (0x1EC8, 0x2059E),
+    (0x1EC9, 0x205A0), (0x1ECA, 0x205A2), (0x1ECB, 0x205A4), (0x1ECC, 0x205A6),
+    (0x1ECD, 0x205A8), (0x1ECE, 0x205AA), (0x1ECF, 0x205AC), (0x1ED0, 0x205AE),
+    (0x1ED1, 0x205B0), (0x1ED2, 0x205B2), (0x1ED3, 0x205B4), (0x1ED4, 0x205B6),
+    (0x1ED5, 0x205B8), (0x1ED6, 0x205BA), (0x1ED7, 0x205BC), (0x1ED8, 0x205BE),
+    (0x1ED9, 0x205C0), (0x1EDA, 0x205C2), (0x1EDB, 0x205C4), (0x1EDC, 0x205C6),
+    (0x1EDD, 0x205C8), (0x1EDE, 0x205CA), (0x1EDF, 0x205CC), (0x1EE0, 0x205CE),
+    (0x1EE1, 0x205D0), (0x1EE2, 0x205D2), (0x1EE3, 0x205D4), (0x1EE4, 0x205D6),
+    (0x1EE5, 0x205D8), (0x1EE6, 0x205DA), (0x1EE7, 0x205DC), (0x1EE8, 0x205DE),
+    (0x1EE9, 0x205E0), (0x1EEA, 0x205E2), (0x1EEB, 0x205E4), (0x1EEC, 0x205E6),
+    (0x1EED, 0x205E8), (0x1EEE, 0x205EA), (0x1EEF, 0x205EC), (0x1EF0, 0x205EE),
+    (0x1EF1, 0x205F0), (0x1EF2, 0x205F2), (0x1EF3, 0x205F4), (0x1EF4, 0x205F6),
+    (0x1EF5, 0x205F8), (0x1EF6, 0x205FA), (0x1EF7, 0x205FC), (0x1EF8, 0x205FE),
+    (0x1EF9, 0x20600), (0x1F00, 0x20602), (0x1F01, 0x20604), (0x1F02, 0x20606),
+    (0x1F03, 0x20608), (0x1F04, 0x2060A), (0x1F05, 0x2060C), (0x1F06, 0x2060E),
+    (0x1F07, 0x20610), (0x1F08, 0x20612), (0x1F09, 0x20614), (0x1F0A, 0x20616),
+    (0x1F0B, 0x20618), (0x1F0C, 0x2061A), (0x1F0D, 0x2061C), (0x1F0E, 0x2061E),
+    (0x1F0F, 0x20620), (0x1F10, 0x20622), (0x1F11, 0x20624), (0x1F12, 0x20626),
+    (0x1F13, 0x20628), (0x1F14, 0x2062A), (0x1F15, 0x2062C), (0x1F18, 0x2062E),
+    (0x1F19, 0x20630), (0x1F1A, 0x20632), (0x1F1B, 0x20634), (0x1F1C, 0x20636),
+    (0x1F1D, 0x20638), (0x1F20, 0x2063A), (0x1F21, 0x2063C), (0x1F22, 0x2063E),
+    (0x1F23, 0x20640), (0x1F24, 0x20642), (0x1F25, 0x20644), (0x1F26, 0x20646),
+    (0x1F27, 0x20648), (0x1F28, 0x2064A), (0x1F29, 0x2064C), (0x1F2A, 0x2064E),
+    (0x1F2B, 0x20650), (0x1F2C, 0x20652), (0x1F2D, 0x20654), (0x1F2E, 0x20656),
+    (0x1F2F, 0x20658), (0x1F30, 0x2065A), (0x1F31, 0x2065C), (0x1F32, 0x2065E),
+    (0x1F33, 0x20660), (0x1F34, 0x20662), (0x1F35, 0x20664), (0x1F36, 0x20666),
+    (0x1F37, 0x20668), (0x1F38, 0x2066A), (0x1F39, 0x2066C), (0x1F3A, 0x2066E),
+    (0x1F3B, 0x20670), (0x1F3C, 0x20672), (0x1F3D, 0x20674), (0x1F3E, 0x20676),
+    (0x1F3F, 0x20678), (0x1F40, 0x2067A), (0x1F41, 0x2067C), (0x1F42, 0x2067E),
+    (0x1F43, 0x20680), (0x1F44, 0x20682), (0x1F45, 0x20684), (0x1F48, 0x20686),
+    (0x1F49, 0x20688), (0x1F4A, 0x2068A), (0x1F4B, 0x2068C), (0x1F4C, 0x2068E),
+    (0x1F4D, 0x20690), (0x1F50, 0x20692), (0x1F51, 0x20694), (0x1F52, 0x20696),
+    (0x1F53, 0x20698), (0x1F54, 0x2069A), (0x1F55, 0x2069C), (0x1F56, 0x2069E),
+    (0x1F57, 0x206A0), (0x1F59, 0x206A2), (0x1F5B, 0x206A4), (0x1F5D, 0x206A6),
+    (0x1F5F, 0x206A8), (0x1F60, 0x206AA), (0x1F61, 0x206AC), (0x1F62, 0x206AE),
+    (0x1F63, 0x206B0), (0x1F64, 0x206B2), (0x1F65, 0x206B4), (0x1F66, 0x206B6),
+    (0x1F67, 0x206B8), (0x1F68, 0x206BA), (0x1F69, 0x206BC), (0x1F6A, 0x206BE),
+    (0x1F6B, 0x206C0), (0x1F6C, 0x206C2), (0x1F6D, 0x206C4), (0x1F6E, 0x206C6),
+    (0x1F6F, 0x206C8), (0x1F70, 0x206CA), (0x1F71, 0x106CC), (0x1F72, 0x206CD),
+    (0x1F73, 0x106CF), (0x1F74, 0x206D0), (0x1F75, 0x106D2), (0x1F76, 0x206D3),
+    (0x1F77, 0x106D5), (0x1F78, 0x206D6), (0x1F79, 0x106D8), (0x1F7A, 0x206D9),
+    (0x1F7B, 0x106DB), (0x1F7C, 0x206DC), (0x1F7D, 0x106DE), (0x1F80, 0x206DF),
+    (0x1F81, 0x206E1), (0x1F82, 0x206E3), (0x1F83, 0x206E5), (0x1F84, 0x206E7),
+    (0x1F85, 0x206E9), (0x1F86, 0x206EB), (0x1F87, 0x206ED), (0x1F88, 0x206EF),
+    (0x1F89, 0x206F1), (0x1F8A, 0x206F3), (0x1F8B, 0x206F5), (0x1F8C, 0x206F7),
+    (0x1F8D, 0x206F9), (0x1F8E, 0x206FB), (0x1F8F, 0x206FD), (0x1F90, 0x206FF),
+    (0x1F91, 0x20701), (0x1F92, 0x20703), (0x1F93, 0x20705), (0x1F94, 0x20707),
+    (0x1F95, 0x20709), (0x1F96, 0x2070B), (0x1F97, 0x2070D), (0x1F98, 0x2070F),
+    (0x1F99, 0x20711), (0x1F9A, 0x20713), (0x1F9B, 0x20715), (0x1F9C, 0x20717),
+    (0x1F9D, 0x20719), (0x1F9E, 0x2071B), (0x1F9F, 0x2071D), (0x1FA0, 0x2071F),
+    (0x1FA1, 0x20721), (0x1FA2, 0x20723), (0x1FA3, 0x20725), (0x1FA4, 0x20727),
+    (0x1FA5, 0x20729), (0x1FA6, 0x2072B), (0x1FA7, 0x2072D), (0x1FA8, 0x2072F),
+    (0x1FA9, 0x20731), (0x1FAA, 0x20733), (0x1FAB, 0x20735), (0x1FAC, 0x20737),
+    (0x1FAD, 0x20739), (0x1FAE, 0x2073B), (0x1FAF, 0x2073D), (0x1FB0, 0x2073F),
+    (0x1FB1, 0x20741), (0x1FB2, 0x20743), (0x1FB3, 0x20745), (0x1FB4, 0x20747),
+    (0x1FB6, 0x20749), (0x1FB7, 0x2074B), (0x1FB8, 0x2074D), (0x1FB9, 0x2074F),
+    (0x1FBA, 0x20751), (0x1FBB, 0x10753), (0x1FBC, 0x20754), (0x1FBD, 0x80020756),
+    (0x1FBE, 0x10758), (0x1FBF, 0x80020759), (0x1FC0, 0x8002075B), (0x1FC1, 0x2075D),
+    (0x1FC2, 0x2075F), (0x1FC3, 0x20761), (0x1FC4, 0x20763), (0x1FC6, 0x20765),
+    (0x1FC7, 0x20767), (0x1FC8, 0x20769), (0x1FC9, 0x1076B), (0x1FCA, 0x2076C),
+    (0x1FCB, 0x1076E), (0x1FCC, 0x2076F), (0x1FCD, 0x20771), (0x1FCE, 0x20773),
+    (0x1FCF, 0x20775), (0x1FD0, 0x20777), (0x1FD1, 0x20779), (0x1FD2, 0x2077B),
+    (0x1FD3, 0x1077D), (0x1FD6, 0x2077E), (0x1FD7, 0x20780), (0x1FD8, 0x20782),
+    (0x1FD9, 0x20784), (0x1FDA, 0x20786), (0x1FDB, 0x10788), (0x1FDD, 0x20789),
+    (0x1FDE, 0x2078B), (0x1FDF, 0x2078D), (0x1FE0, 0x2078F), (0x1FE1, 0x20791),
+    (0x1FE2, 0x20793), (0x1FE3, 0x10795), (0x1FE4, 0x20796), (0x1FE5, 0x20798),
+    (0x1FE6, 0x2079A), (0x1FE7, 0x2079C), (0x1FE8, 0x2079E), (0x1FE9, 0x207A0),
+    (0x1FEA, 0x207A2), (0x1FEB, 0x107A4), (0x1FEC, 0x207A5), (0x1FED, 0x207A7),
+    (0x1FEE, 0x107A9), (0x1FEF, 0x107AA), (0x1FF2, 0x207AB), (0x1FF3, 0x207AD),
+    (0x1FF4, 0x207AF), (0x1FF6, 0x207B1), (0x1FF7, 0x207B3), (0x1FF8, 0x207B5),
+    (0x1FF9, 0x107B7), (0x1FFA, 0x207B8), (0x1FFB, 0x107BA), (0x1FFC, 0x207BB),
+    (0x1FFD, 0x107BD), (0x1FFE, 0x800207BE), (0x2000, 0x107C0), (0x2001, 0x107C1),
+    (0x2002, 0x800107C2), (0x2003, 0x800107C3), (0x2004, 0x800107C4), (0x2005, 0x800107C5),
+    (0x2006, 0x800107C6), (0x2007, 0x800107C7), (0x2008, 0x800107C8), (0x2009, 0x800107C9),
+    (0x200A, 0x800107CA), (0x2011, 0x800107CB), (0x2017, 0x800207CC), (0x2024, 0x800107CE),
+    (0x2025, 0x800207CF), (0x2026, 0x800307D1), (0x202F, 0x800107D4), (0x2033, 0x800207D5),
+    (0x2034, 0x800307D7), (0x2036, 0x800207DA), (0x2037, 0x800307DC), (0x203C, 0x800207DF),
+    (0x203E, 0x800207E1), (0x2047, 0x800207E3), (0x2048, 0x800207E5), (0x2049, 0x800207E7),
+    (0x2057, 0x800407E9), (0x205F, 0x800107ED), (0x2070, 0x800107EE), (0x2071, 0x800107EF),
+    (0x2074, 0x800107F0), (0x2075, 0x800107F1), (0x2076, 0x800107F2), (0x2077, 0x800107F3),
+    (0x2078, 0x800107F4), (0x2079, 0x800107F5), (0x207A, 0x800107F6), (0x207B, 0x800107F7),
+    (0x207C, 0x800107F8), (0x207D, 0x800107F9), (0x207E, 0x800107FA), (0x207F, 0x800107FB),
+    (0x2080, 0x800107FC), (0x2081, 0x800107FD), (0x2082, 0x800107FE), (0x2083, 0x800107FF),
+    (0x2084, 0x80010800), (0x2085, 0x80010801), (0x2086, 0x80010802), (0x2087, 0x80010803),
+    (0x2088, 0x80010804), (0x2089, 0x80010805), (0x208A, 0x80010806), (0x208B, 0x80010807),
+    (0x208C, 0x80010808), (0x208D, 0x80010809), (0x208E, 0x8001080A), (0x2090, 0x8001080B),
+    (0x2091, 0x8001080C), (0x2092, 0x8001080D), (0x2093, 0x8001080E), (0x2094, 0x8001080F),
+    (0x2095, 0x80010810), (0x2096, 0x80010811), (0x2097, 0x80010812), (0x2098, 0x80010813),
+    (0x2099, 0x80010814), (0x209A, 0x80010815), (0x209B, 0x80010816), (0x209C, 0x80010817),
+    (0x20A8, 0x80020818), (0x2100, 0x8003081A), (0x2101, 0x8003081D), (0x2102, 0x80010820),
+    (0x2103, 0x80020821), (0x2105, 0x80030823), (0x2106, 0x80030826), (0x2107, 0x80010829),
+    (0x2109, 0x8002082A), (0x210A, 0x8001082C), (0x210B, 0x8001082D), (0x210C, 0x8001082E),
+    (0x210D, 0x8001082F), (0x210E, 0x80010830), (0x210F, 0x80010831), (0x2110, 0x80010832),
+    (0x2111, 0x80010833), (0x2112, 0x80010834), (0x2113, 0x80010835), (0x2115, 0x80010836),
+    (0x2116, 0x80020837), (0x2119, 0x80010839), (0x211A, 0x8001083A), (0x211B, 0x8001083B),
+    (0x211C, 0x8001083C), (0x211D, 0x8001083D), (0x2120, 0x8002083E), (0x2121, 0x80030840),
+    (0x2122, 0x80020843), (0x2124, 0x80010845), (0x2126, 0x10846), (0x2128, 0x80010847),
+    (0x212A, 0x10848), (0x212B, 0x10849), (0x212C, 0x8001084A), (0x212D, 0x8001084B),
+    (0x212F, 0x8001084C), (0x2130, 0x8001084D), (0x2131, 0x8001084E), (0x2133, 0x8001084F),
+    (0x2134, 0x80010850), (0x2135, 0x80010851), (0x2136, 0x80010852), (0x2137, 0x80010853),
+    (0x2138, 0x80010854), (0x2139, 0x80010855), (0x213B, 0x80030856), (0x213C, 0x80010859),
+    (0x213D, 0x8001085A), (0x213E, 0x8001085B), (0x213F, 0x8001085C), (0x2140, 0x8001085D),
+    (0x2145, 0x8001085E), (0x2146, 0x8001085F), (0x2147, 0x80010860), (0x2148, 0x80010861),
+    (0x2149, 0x80010862), (0x2150, 0x80030863), (0x2151, 0x80030866), (0x2152, 0x80040869),
+    (0x2153, 0x8003086D), (0x2154, 0x80030870), (0x2155, 0x80030873), (0x2156, 0x80030876),
+    (0x2157, 0x80030879), (0x2158, 0x8003087C), (0x2159, 0x8003087F), (0x215A, 0x80030882),
+    (0x215B, 0x80030885), (0x215C, 0x80030888), (0x215D, 0x8003088B), (0x215E, 0x8003088E),
+    (0x215F, 0x80020891), (0x2160, 0x80010893), (0x2161, 0x80020894), (0x2162, 0x80030896),
+    (0x2163, 0x80020899), (0x2164, 0x8001089B), (0x2165, 0x8002089C), (0x2166, 0x8003089E),
+    (0x2167, 0x800408A1), (0x2168, 0x800208A5), (0x2169, 0x800108A7), (0x216A, 0x800208A8),
+    (0x216B, 0x800308AA), (0x216C, 0x800108AD), (0x216D, 0x800108AE), (0x216E, 0x800108AF),
+    (0x216F, 0x800108B0), (0x2170, 0x800108B1), (0x2171, 0x800208B2), (0x2172, 0x800308B4),
+    (0x2173, 0x800208B7), (0x2174, 0x800108B9), (0x2175, 0x800208BA), (0x2176, 0x800308BC),
+    (0x2177, 0x800408BF), (0x2178, 0x800208C3), (0x2179, 0x800108C5), (0x217A, 0x800208C6),
+    (0x217B, 0x800308C8), (0x217C, 0x800108CB), (0x217D, 0x800108CC), (0x217E, 0x800108CD),
+    (0x217F, 0x800108CE), (0x2189, 0x800308CF), (0x219A, 0x208D2), (0x219B, 0x208D4),
+    (0x21AE, 0x208D6), (0x21CD, 0x208D8), (0x21CE, 0x208DA), (0x21CF, 0x208DC),
+    (0x2204, 0x208DE), (0x2209, 0x208E0), (0x220C, 0x208E2), (0x2224, 0x208E4),
+    (0x2226, 0x208E6), (0x222C, 0x800208E8), (0x222D, 0x800308EA), (0x222F, 0x800208ED),
+    (0x2230, 0x800308EF), (0x2241, 0x208F2), (0x2244, 0x208F4), (0x2247, 0x208F6),
+    (0x2249, 0x208F8), (0x2260, 0x208FA), (0x2262, 0x208FC), (0x226D, 0x208FE),
+    (0x226E, 0x20900), (0x226F, 0x20902), (0x2270, 0x20904), (0x2271, 0x20906),
+    (0x2274, 0x20908), (0x2275, 0x2090A), (0x2278, 0x2090C), (0x2279, 0x2090E),
+    (0x2280, 0x20910), (0x2281, 0x20912), (0x2284, 0x20914), (0x2285, 0x20916),
+    (0x2288, 0x20918), (0x2289, 0x2091A), (0x22AC, 0x2091C), (0x22AD, 0x2091E),
+    (0x22AE, 0x20920), (0x22AF, 0x20922), (0x22E0, 0x20924), (0x22E1, 0x20926),
+    (0x22E2, 0x20928), (0x22E3, 0x2092A), (0x22EA, 0x2092C), (0x22EB, 0x2092E),
+    (0x22EC, 0x20930), (0x22ED, 0x20932), (0x2329, 0x10934), (0x232A, 0x10935),
+    (0x2460, 0x80010936), (0x2461, 0x80010937), (0x2462, 0x80010938), (0x2463, 0x80010939),
+    (0x2464, 0x8001093A), (0x2465, 0x8001093B), (0x2466, 0x8001093C), (0x2467, 0x8001093D),
+    (0x2468, 0x8001093E), (0x2469, 0x8002093F), (0x246A, 0x80020941), (0x246B, 0x80020943),
+    (0x246C, 0x80020945), (0x246D, 0x80020947), (0x246E, 0x80020949), (0x246F, 0x8002094B),
+    (0x2470, 0x8002094D), (0x2471, 0x8002094F), (0x2472, 0x80020951), (0x2473, 0x80020953),
+    (0x2474, 0x80030955), (0x2475, 0x80030958), (0x2476, 0x8003095B), (0x2477, 0x8003095E),
+    (0x2478, 0x80030961), (0x2479, 0x80030964), (0x247A, 0x80030967), (0x247B, 0x8003096A),
+    (0x247C, 0x8003096D), (0x247D, 0x80040970), (0x247E, 0x80040974), (0x247F, 0x80040978),
+    (0x2480, 0x8004097C), (0x2481, 0x80040980), (0x2482, 0x80040984), (0x2483, 0x80040988),
+    (0x2484, 0x8004098C), (0x2485, 0x80040990), (0x2486, 0x80040994), (0x2487, 0x80040998),
+    (0x2488, 0x8002099C), (0x2489, 0x8002099E), (0x248A, 0x800209A0), (0x248B, 0x800209A2),
+    (0x248C, 0x800209A4), (0x248D, 0x800209A6), (0x248E, 0x800209A8), (0x248F, 0x800209AA),
+    (0x2490, 0x800209AC), (0x2491, 0x800309AE), (0x2492, 0x800309B1), (0x2493, 0x800309B4),
+    (0x2494, 0x800309B7), (0x2495, 0x800309BA), (0x2496, 0x800309BD), (0x2497, 0x800309C0),
+    (0x2498, 0x800309C3), (0x2499, 0x800309C6), (0x249A, 0x800309C9), (0x249B, 0x800309CC),
+    (0x249C, 0x800309CF), (0x249D, 0x800309D2), (0x249E, 0x800309D5), (0x249F, 0x800309D8),
+    (0x24A0, 0x800309DB), (0x24A1, 0x800309DE), (0x24A2, 0x800309E1), (0x24A3, 0x800309E4),
+    (0x24A4, 0x800309E7), (0x24A5, 0x800309EA), (0x24A6, 0x800309ED), (0x24A7, 0x800309F0),
+    (0x24A8, 0x800309F3), (0x24A9, 0x800309F6), (0x24AA, 0x800309F9), (0x24AB, 0x800309FC),
+    (0x24AC, 0x800309FF), (0x24AD, 0x80030A02), (0x24AE, 0x80030A05), (0x24AF, 0x80030A08),
+    (0x24B0, 0x80030A0B), (0x24B1, 0x80030A0E), (0x24B2, 0x80030A11), (0x24B3, 0x80030A14),
+    (0x24B4, 0x80030A17), (0x24B5, 0x80030A1A), (0x24B6, 0x80010A1D), (0x24B7, 0x80010A1E),
+    (0x24B8, 0x80010A1F), (0x24B9, 0x80010A20), (0x24BA, 0x80010A21), (0x24BB, 0x80010A22),
+    (0x24BC, 0x80010A23), (0x24BD, 0x80010A24), (0x24BE, 0x80010A25), (0x24BF, 0x80010A26),
+    (0x24C0, 0x80010A27), (0x24C1, 0x80010A28), (0x24C2, 0x80010A29), (0x24C3, 0x80010A2A),
+    (0x24C4, 0x80010A2B), (0x24C5, 0x80010A2C), (0x24C6, 0x80010A2D), (0x24C7, 0x80010A2E),
+    (0x24C8, 0x80010A2F), (0x24C9, 0x80010A30), (0x24CA, 0x80010A31), (0x24CB, 0x80010A32),
+    (0x24CC, 0x80010A33), (0x24CD, 0x80010A34), (0x24CE, 0x80010A35), (0x24CF, 0x80010A36),
+    (0x24D0, 0x80010A37), (0x24D1, 0x80010A38), (0x24D2, 0x80010A39), (0x24D3, 0x80010A3A),
+    (0x24D4, 0x80010A3B), (0x24D5, 0x80010A3C), (0x24D6, 0x80010A3D), (0x24D7, 0x80010A3E),
+    (0x24D8, 0x80010A3F), (0x24D9, 0x80010A40), (0x24DA, 0x80010A41), (0x24DB, 0x80010A42),
+    (0x24DC, 0x80010A43), (0x24DD, 0x80010A44), (0x24DE, 0x80010A45), (0x24DF, 0x80010A46),
+    (0x24E0, 0x80010A47), (0x24E1, 0x80010A48), (0x24E2, 0x80010A49), (0x24E3, 0x80010A4A),
+    (0x24E4, 0x80010A4B), (0x24E5, 0x80010A4C), (0x24E6, 0x80010A4D), (0x24E7, 0x80010A4E),
+    (0x24E8, 0x80010A4F), (0x24E9, 0x80010A50), (0x24EA, 0x80010A51), (0x2A0C, 0x80040A52),
+    (0x2A74, 0x80030A56), (0x2A75, 0x80020A59), (0x2A76, 0x80030A5B), (0x2ADC, 0x20A5E),
+    (0x2C7C, 0x80010A60), (0x2C7D, 0x80010A61), (0x2D6F, 0x80010A62), (0x2E9F, 0x80010A63),
+    (0x2EF3, 0x80010A64), (0x2F00, 0x80010A65), (0x2F01, 0x80010A66), (0x2F02, 0x80010A67),
+    (0x2F03, 0x80010A68), (0x2F04, 0x80010A69), (0x2F05, 0x80010A6A), (0x2F06, 0x80010A6B),
+    (0x2F07, 0x80010A6C), (0x2F08, 0x80010A6D), (0x2F09, 0x80010A6E), (0x2F0A, 0x80010A6F),
+    (0x2F0B, 0x80010A70), (0x2F0C, 0x80010A71), (0x2F0D, 0x80010A72), (0x2F0E, 0x80010A73),
+    (0x2F0F, 0x80010A74), (0x2F10, 0x80010A75), (0x2F11, 0x80010A76), (0x2F12, 0x80010A77),
+    (0x2F13, 0x80010A78), (0x2F14, 0x80010A79), (0x2F15, 0x80010A7A), (0x2F16, 0x80010A7B),
+    (0x2F17, 0x80010A7C), (0x2F18, 0x80010A7D), (0x2F19, 0x80010A7E), (0x2F1A, 0x80010A7F),
+    (0x2F1B, 0x80010A80), (0x2F1C, 0x80010A81), (0x2F1D, 0x80010A82), (0x2F1E, 0x80010A83),
+    (0x2F1F, 0x80010A84), (0x2F20, 0x80010A85), (0x2F21, 0x80010A86), (0x2F22, 0x80010A87),
+    (0x2F23, 0x80010A88), (0x2F24, 0x80010A89), (0x2F25, 0x80010A8A), (0x2F26, 0x80010A8B),
+    (0x2F27, 0x80010A8C), (0x2F28, 0x80010A8D), (0x2F29, 0x80010A8E), (0x2F2A, 0x80010A8F),
+    (0x2F2B, 0x80010A90), (0x2F2C, 0x80010A91), (0x2F2D, 0x80010A92), (0x2F2E, 0x80010A93),
+    (0x2F2F, 0x80010A94), (0x2F30, 0x80010A95), (0x2F31, 0x80010A96), (0x2F32, 0x80010A97),
+    (0x2F33, 0x80010A98), (0x2F34, 0x80010A99), (0x2F35, 0x80010A9A), (0x2F36, 0x80010A9B),
+    (0x2F37, 0x80010A9C), (0x2F38, 0x80010A9D), (0x2F39, 0x80010A9E), (0x2F3A, 0x80010A9F),
+    (0x2F3B, 0x80010AA0), (0x2F3C, 0x80010AA1), (0x2F3D, 0x80010AA2), (0x2F3E, 0x80010AA3),
+    (0x2F3F, 0x80010AA4), (0x2F40, 0x80010AA5), (0x2F41, 0x80010AA6), (0x2F42, 0x80010AA7),
+    (0x2F43, 0x80010AA8), (0x2F44, 0x80010AA9), (0x2F45, 0x80010AAA), (0x2F46, 0x80010AAB),
+    (0x2F47, 0x80010AAC), (0x2F48, 0x80010AAD), (0x2F49, 0x80010AAE), (0x2F4A, 0x80010AAF),
+    (0x2F4B, 0x80010AB0), (0x2F4C, 0x80010AB1), (0x2F4D, 0x80010AB2), (0x2F4E, 0x80010AB3),
+    (0x2F4F, 0x80010AB4), (0x2F50, 0x80010AB5), (0x2F51, 0x80010AB6), (0x2F52, 0x80010AB7),
+    (0x2F53, 0x80010AB8), (0x2F54, 0x80010AB9), (0x2F55, 0x80010ABA), (0x2F56, 0x80010ABB),
+    (0x2F57, 0x80010ABC), (0x2F58, 0x80010ABD), (0x2F59, 0x80010ABE), (0x2F5A, 0x80010ABF),
+    (0x2F5B, 0x80010AC0), (0x2F5C, 0x80010AC1), (0x2F5D, 0x80010AC2), (0x2F5E, 0x80010AC3),
+    (0x2F5F, 0x80010AC4), (0x2F60, 0x80010AC5), (0x2F61, 0x80010AC6), (0x2F62, 0x80010AC7),
+    (0x2F63, 0x80010AC8), (0x2F64, 0x80010AC9), (0x2F65, 0x80010ACA), (0x2F66, 0x80010ACB),
+    (0x2F67, 0x80010ACC), (0x2F68, 0x80010ACD), (0x2F69, 0x80010ACE), (0x2F6A, 0x80010ACF),
+    (0x2F6B, 0x80010AD0), (0x2F6C, 0x80010AD1), (0x2F6D, 0x80010AD2), (0x2F6E, 0x80010AD3),
+    (0x2F6F, 0x80010AD4), (0x2F70, 0x80010AD5), (0x2F71, 0x80010AD6), (0x2F72, 0x80010AD7),
+    (0x2F73, 0x80010AD8), (0x2F74, 0x80010AD9), (0x2F75, 0x80010ADA), (0x2F76, 0x80010ADB),
+    (0x2F77, 0x80010ADC), (0x2F78, 0x80010ADD), (0x2F79, 0x80010ADE), (0x2F7A, 0x80010ADF),
+    (0x2F7B, 0x80010AE0), (0x2F7C, 0x80010AE1), (0x2F7D, 0x80010AE2), (0x2F7E, 0x80010AE3),
+    (0x2F7F, 0x80010AE4), (0x2F80, 0x80010AE5), (0x2F81, 0x80010AE6), (0x2F82, 0x80010AE7),
+    (0x2F83, 0x80010AE8), (0x2F84, 0x80010AE9), (0x2F85, 0x80010AEA), (0x2F86, 0x80010AEB),
+    (0x2F87, 0x80010AEC), (0x2F88, 0x80010AED), (0x2F89, 0x80010AEE), (0x2F8A, 0x80010AEF),
+    (0x2F8B, 0x80010AF0), (0x2F8C, 0x80010AF1), (0x2F8D, 0x80010AF2), (0x2F8E, 0x80010AF3),
+    (0x2F8F, 0x80010AF4), (0x2F90, 0x80010AF5), (0x2F91, 0x80010AF6), (0x2F92, 0x80010AF7),
+    (0x2F93, 0x80010AF8), (0x2F94, 0x80010AF9), (0x2F95, 0x80010AFA), (0x2F96, 0x80010AFB),
+    (0x2F97, 0x80010AFC), (0x2F98, 0x80010AFD), (0x2F99, 0x80010AFE), (0x2F9A, 0x80010AFF),
+    (0x2F9B, 0x80010B00), (0x2F9C, 0x80010B01), (0x2F9D, 0x80010B02), (0x2F9E, 0x80010B03),
+    (0x2F9F, 0x80010B04), (0x2FA0, 0x80010B05), (0x2FA1, 0x80010B06), (0x2FA2, 0x80010B07),
+    (0x2FA3, 0x80010B08), (0x2FA4, 0x80010B09), (0x2FA5, 0x80010B0A), (0x2FA6, 0x80010B0B),
+    (0x2FA7, 0x80010B0C), (0x2FA8, 0x80010B0D), (0x2FA9, 0x80010B0E), (0x2FAA, 0x80010B0F),
+    (0x2FAB, 0x80010B10), (0x2FAC, 0x80010B11), (0x2FAD, 0x80010B12), (0x2FAE, 0x80010B13),
+    (0x2FAF, 0x80010B14), (0x2FB0, 0x80010B15), (0x2FB1, 0x80010B16), (0x2FB2, 0x80010B17),
+    (0x2FB3, 0x80010B18), (0x2FB4, 0x80010B19), (0x2FB5, 0x80010B1A), (0x2FB6, 0x80010B1B),
+    (0x2FB7, 0x80010B1C), (0x2FB8, 0x80010B1D), (0x2FB9, 0x80010B1E), (0x2FBA, 0x80010B1F),
+    (0x2FBB, 0x80010B20), (0x2FBC, 0x80010B21), (0x2FBD, 0x80010B22), (0x2FBE, 0x80010B23),
+    (0x2FBF, 0x80010B24), (0x2FC0, 0x80010B25), (0x2FC1, 0x80010B26), (0x2FC2, 0x80010B27),
+    (0x2FC3, 0x80010B28), (0x2FC4, 0x80010B29), (0x2FC5, 0x80010B2A), (0x2FC6, 0x80010B2B),
+    (0x2FC7, 0x80010B2C), (0x2FC8, 0x80010B2D), (0x2FC9, 0x80010B2E), (0x2FCA, 0x80010B2F),
+    (0x2FCB, 0x80010B30), (0x2FCC, 0x80010B31), (0x2FCD, 0x80010B32), (0x2FCE, 0x80010B33),
+    (0x2FCF, 0x80010B34), (0x2FD0, 0x80010B35), (0x2FD1, 0x80010B36), (0x2FD2, 0x80010B37),
+    (0x2FD3, 0x80010B38), (0x2FD4, 0x80010B39), (0x2FD5, 0x80010B3A), (0x3000, 0x80010B3B),
+    (0x3036, 0x80010B3C), (0x3038, 0x80010B3D), (0x3039, 0x80010B3E), (0x303A, 0x80010B3F),
+    (0x304C, 0x20B40), (0x304E, 0x20B42), (0x3050, 0x20B44), (0x3052, 0x20B46),
+    (0x3054, 0x20B48), (0x3056, 0x20B4A), (0x3058, 0x20B4C), (0x305A, 0x20B4E),
+    (0x305C, 0x20B50), (0x305E, 0x20B52), (0x3060, 0x20B54), (0x3062, 0x20B56),
+    (0x3065, 0x20B58), (0x3067, 0x20B5A), (0x3069, 0x20B5C), (0x3070, 0x20B5E),
+    (0x3071, 0x20B60), (0x3073, 0x20B62), (0x3074, 0x20B64), (0x3076, 0x20B66),
+    (0x3077, 0x20B68), (0x3079, 0x20B6A), (0x307A, 0x20B6C), (0x307C, 0x20B6E),
+    (0x307D, 0x20B70), (0x3094, 0x20B72), (0x309B, 0x80020B74), (0x309C, 0x80020B76),
+    (0x309E, 0x20B78), (0x309F, 0x80020B7A), (0x30AC, 0x20B7C), (0x30AE, 0x20B7E),
+    (0x30B0, 0x20B80), (0x30B2, 0x20B82), (0x30B4, 0x20B84), (0x30B6, 0x20B86),
+    (0x30B8, 0x20B88), (0x30BA, 0x20B8A), (0x30BC, 0x20B8C), (0x30BE, 0x20B8E),
+    (0x30C0, 0x20B90), (0x30C2, 0x20B92), (0x30C5, 0x20B94), (0x30C7, 0x20B96),
+    (0x30C9, 0x20B98), (0x30D0, 0x20B9A), (0x30D1, 0x20B9C), (0x30D3, 0x20B9E),
+    (0x30D4, 0x20BA0), (0x30D6, 0x20BA2), (0x30D7, 0x20BA4), (0x30D9, 0x20BA6),
+    (0x30DA, 0x20BA8), (0x30DC, 0x20BAA), (0x30DD, 0x20BAC), (0x30F4, 0x20BAE),
+    (0x30F7, 0x20BB0), (0x30F8, 0x20BB2), (0x30F9, 0x20BB4), (0x30FA, 0x20BB6),
+    (0x30FE, 0x20BB8), (0x30FF, 0x80020BBA), (0x3131, 0x80010BBC), (0x3132, 0x80010BBD),
+    (0x3133, 0x80010BBE), (0x3134, 0x80010BBF), (0x3135, 0x80010BC0), (0x3136, 0x80010BC1),
+    (0x3137, 0x80010BC2), (0x3138, 0x80010BC3), (0x3139, 0x80010BC4), (0x313A, 0x80010BC5),
+    (0x313B, 0x80010BC6), (0x313C, 0x80010BC7), (0x313D, 0x80010BC8), (0x313E, 0x80010BC9),
+    (0x313F, 0x80010BCA), (0x3140, 0x80010BCB), (0x3141, 0x80010BCC), (0x3142, 0x80010BCD),
+    (0x3143, 0x80010BCE), (0x3144, 0x80010BCF), (0x3145, 0x80010BD0), (0x3146, 0x80010BD1),
+    (0x3147, 0x80010BD2), (0x3148, 0x80010BD3), (0x3149, 0x80010BD4), (0x314A, 0x80010BD5),
+    (0x314B, 0x80010BD6), (0x314C, 0x80010BD7), (0x314D, 0x80010BD8), (0x314E, 0x80010BD9),
+    (0x314F, 0x80010BDA), (0x3150, 0x80010BDB), (0x3151, 0x80010BDC), (0x3152, 0x80010BDD),
+    (0x3153, 0x80010BDE), (0x3154, 0x80010BDF), (0x3155, 0x80010BE0), (0x3156, 0x80010BE1),
+    (0x3157, 0x80010BE2), (0x3158, 0x80010BE3), (0x3159, 0x80010BE4), (0x315A, 0x80010BE5),
+    (0x315B, 0x80010BE6), (0x315C, 0x80010BE7), (0x315D, 0x80010BE8), (0x315E, 0x80010BE9),
+    (0x315F, 0x80010BEA), (0x3160, 0x80010BEB), (0x3161, 0x80010BEC), (0x3162, 0x80010BED),
+    (0x3163, 0x80010BEE), (0x3164, 0x80010BEF), (0x3165, 0x80010BF0), (0x3166, 0x80010BF1),
+    (0x3167, 0x80010BF2), (0x3168, 0x80010BF3), (0x3169, 0x80010BF4), (0x316A, 0x80010BF5),
+    (0x316B, 0x80010BF6), (0x316C, 0x80010BF7), (0x316D, 0x80010BF8), (0x316E, 0x80010BF9),
+    (0x316F, 0x80010BFA), (0x3170, 0x80010BFB), (0x3171, 0x80010BFC), (0x3172, 0x80010BFD),
+    (0x3173, 0x80010BFE), (0x3174, 0x80010BFF), (0x3175, 0x80010C00), (0x3176, 0x80010C01),
+    (0x3177, 0x80010C02), (0x3178, 0x80010C03), (0x3179, 0x80010C04), (0x317A, 0x80010C05),
+    (0x317B, 0x80010C06), (0x317C, 0x80010C07), (0x317D, 0x80010C08), (0x317E, 0x80010C09),
+    (0x317F, 0x80010C0A), (0x3180, 0x80010C0B), (0x3181, 0x80010C0C), (0x3182, 0x80010C0D),
+    (0x3183, 0x80010C0E), (0x3184, 0x80010C0F), (0x3185, 0x80010C10), (0x3186, 0x80010C11),
+    (0x3187, 0x80010C12), (0x3188, 0x80010C13), (0x3189, 0x80010C14), (0x318A, 0x80010C15),
+    (0x318B, 0x80010C16), (0x318C, 0x80010C17), (0x318D, 0x80010C18), (0x318E, 0x80010C19),
+    (0x3192, 0x80010C1A), (0x3193, 0x80010C1B), (0x3194, 0x80010C1C), (0x3195, 0x80010C1D),
+    (0x3196, 0x80010C1E), (0x3197, 0x80010C1F), (0x3198, 0x80010C20), (0x3199, 0x80010C21),
+    (0x319A, 0x80010C22), (0x319B, 0x80010C23), (0x319C, 0x80010C24), (0x319D, 0x80010C25),
+    (0x319E, 0x80010C26), (0x319F, 0x80010C27), (0x3200, 0x80030C28), (0x3201, 0x80030C2B),
+    (0x3202, 0x80030C2E), (0x3203, 0x80030C31), (0x3204, 0x80030C34), (0x3205, 0x80030C37),
+    (0x3206, 0x80030C3A), (0x3207, 0x80030C3D), (0x3208, 0x80030C40), (0x3209, 0x80030C43),
+    (0x320A, 0x80030C46), (0x320B, 0x80030C49), (0x320C, 0x80030C4C), (0x320D, 0x80030C4F),
+    (0x320E, 0x80040C52), (0x320F, 0x80040C56), (0x3210, 0x80040C5A), (0x3211, 0x80040C5E),
+    (0x3212, 0x80040C62), (0x3213, 0x80040C66), (0x3214, 0x80040C6A), (0x3215, 0x80040C6E),
+    (0x3216, 0x80040C72), (0x3217, 0x80040C76), (0x3218, 0x80040C7A), (0x3219, 0x80040C7E),
+    (0x321A, 0x80040C82), (0x321B, 0x80040C86), (0x321C, 0x80040C8A), (0x321D, 0x80070C8E),
+    (0x321E, 0x80060C95), (0x3220, 0x80030C9B), (0x3221, 0x80030C9E), (0x3222, 0x80030CA1),
+    (0x3223, 0x80030CA4), (0x3224, 0x80030CA7), (0x3225, 0x80030CAA), (0x3226, 0x80030CAD),
+    (0x3227, 0x80030CB0), (0x3228, 0x80030CB3), (0x3229, 0x80030CB6), (0x322A, 0x80030CB9),
+    (0x322B, 0x80030CBC), (0x322C, 0x80030CBF), (0x322D, 0x80030CC2), (0x322E, 0x80030CC5),
+    (0x322F, 0x80030CC8), (0x3230, 0x80030CCB), (0x3231, 0x80030CCE), (0x3232, 0x80030CD1),
+    (0x3233, 0x80030CD4), (0x3234, 0x80030CD7), (0x3235, 0x80030CDA), (0x3236, 0x80030CDD),
+    (0x3237, 0x80030CE0), (0x3238, 0x80030CE3), (0x3239, 0x80030CE6), (0x323A, 0x80030CE9),
+    (0x323B, 0x80030CEC), (0x323C, 0x80030CEF), (0x323D, 0x80030CF2), (0x323E, 0x80030CF5),
+    (0x323F, 0x80030CF8), (0x3240, 0x80030CFB), (0x3241, 0x80030CFE), (0x3242, 0x80030D01),
+    (0x3243, 0x80030D04), (0x3244, 0x80010D07), (0x3245, 0x80010D08), (0x3246, 0x80010D09),
+    (0x3247, 0x80010D0A), (0x3250, 0x80030D0B), (0x3251, 0x80020D0E), (0x3252, 0x80020D10),
+    (0x3253, 0x80020D12), (0x3254, 0x80020D14), (0x3255, 0x80020D16), (0x3256, 0x80020D18),
+    (0x3257, 0x80020D1A), (0x3258, 0x80020D1C), (0x3259, 0x80020D1E), (0x325A, 0x80020D20),
+    (0x325B, 0x80020D22), (0x325C, 0x80020D24), (0x325D, 0x80020D26), (0x325E, 0x80020D28),
+    (0x325F, 0x80020D2A), (0x3260, 0x80010D2C), (0x3261, 0x80010D2D), (0x3262, 0x80010D2E),
+    (0x3263, 0x80010D2F), (0x3264, 0x80010D30), (0x3265, 0x80010D31), (0x3266, 0x80010D32),
+    (0x3267, 0x80010D33), (0x3268, 0x80010D34), (0x3269, 0x80010D35), (0x326A, 0x80010D36),
+    (0x326B, 0x80010D37), (0x326C, 0x80010D38), (0x326D, 0x80010D39), (0x326E, 0x80020D3A),
+    (0x326F, 0x80020D3C), (0x3270, 0x80020D3E), (0x3271, 0x80020D40), (0x3272, 0x80020D42),
+    (0x3273, 0x80020D44), (0x3274, 0x80020D46), (0x3275, 0x80020D48), (0x3276, 0x80020D4A),
+    (0x3277, 0x80020D4C), (0x3278, 0x80020D4E), (0x3279, 0x80020D50), (0x327A, 0x80020D52),
+    (0x327B, 0x80020D54), (0x327C, 0x80050D56), (0x327D, 0x80040D5B), (0x327E, 0x80020D5F),
+    (0x3280, 0x80010D61), (0x3281, 0x80010D62), (0x3282, 0x80010D63), (0x3283, 0x80010D64),
+    (0x3284, 0x80010D65), (0x3285, 0x80010D66), (0x3286, 0x80010D67), (0x3287, 0x80010D68),
+    (0x3288, 0x80010D69), (0x3289, 0x80010D6A), (0x328A, 0x80010D6B), (0x328B, 0x80010D6C),
+    (0x328C, 0x80010D6D), (0x328D, 0x80010D6E), (0x328E, 0x80010D6F), (0x328F, 0x80010D70),
+    (0x3290, 0x80010D71), (0x3291, 0x80010D72), (0x3292, 0x80010D73), (0x3293, 0x80010D74),
+    (0x3294, 0x80010D75), (0x3295, 0x80010D76), (0x3296, 0x80010D77), (0x3297, 0x80010D78),
+    (0x3298, 0x80010D79), (0x3299, 0x80010D7A), (0x329A, 0x80010D7B), (0x329B, 0x80010D7C),
+    (0x329C, 0x80010D7D), (0x329D, 0x80010D7E), (0x329E, 0x80010D7F), (0x329F, 0x80010D80),
+    (0x32A0, 0x80010D81), (0x32A1, 0x80010D82), (0x32A2, 0x80010D83), (0x32A3, 0x80010D84),
+    (0x32A4, 0x80010D85), (0x32A5, 0x80010D86), (0x32A6, 0x80010D87), (0x32A7, 0x80010D88),
+    (0x32A8, 0x80010D89), (0x32A9, 0x80010D8A), (0x32AA, 0x80010D8B), (0x32AB, 0x80010D8C),
+    (0x32AC, 0x80010D8D), (0x32AD, 0x80010D8E), (0x32AE, 0x80010D8F), (0x32AF, 0x80010D90),
+    (0x32B0, 0x80010D91), (0x32B1, 0x80020D92), (0x32B2, 0x80020D94), (0x32B3, 0x80020D96),
+    (0x32B4, 0x80020D98), (0x32B5, 0x80020D9A), (0x32B6, 0x80020D9C), (0x32B7, 0x80020D9E),
+    (0x32B8, 0x80020DA0), (0x32B9, 0x80020DA2), (0x32BA, 0x80020DA4), (0x32BB, 0x80020DA6),
+    (0x32BC, 0x80020DA8), (0x32BD, 0x80020DAA), (0x32BE, 0x80020DAC), (0x32BF, 0x80020DAE),
+    (0x32C0, 0x80020DB0), (0x32C1, 0x80020DB2), (0x32C2, 0x80020DB4), (0x32C3, 0x80020DB6),
+    (0x32C4, 0x80020DB8), (0x32C5, 0x80020DBA), (0x32C6, 0x80020DBC), (0x32C7, 0x80020DBE),
+    (0x32C8, 0x80020DC0), (0x32C9, 0x80030DC2), (0x32CA, 0x80030DC5), (0x32CB, 0x80030DC8),
+    (0x32CC, 0x80020DCB), (0x32CD, 0x80030DCD), (0x32CE, 0x80020DD0), (0x32CF, 0x80030DD2),
+    (0x32D0, 0x80010DD5), (0x32D1, 0x80010DD6), (0x32D2, 0x80010DD7), (0x32D3, 0x80010DD8),
+    (0x32D4, 0x80010DD9), (0x32D5, 0x80010DDA), (0x32D6, 0x80010DDB), (0x32D7, 0x80010DDC),
+    (0x32D8, 0x80010DDD), (0x32D9, 0x80010DDE), (0x32DA, 0x80010DDF), (0x32DB, 0x80010DE0),
+    (0x32DC, 0x80010DE1), (0x32DD, 0x80010DE2), (0x32DE, 0x80010DE3), (0x32DF, 0x80010DE4),
+    (0x32E0, 0x80010DE5), (0x32E1, 0x80010DE6), (0x32E2, 0x80010DE7), (0x32E3, 0x80010DE8),
+    (0x32E4, 0x80010DE9), (0x32E5, 0x80010DEA), (0x32E6, 0x80010DEB), (0x32E7, 0x80010DEC),
+    (0x32E8, 0x80010DED), (0x32E9, 0x80010DEE), (0x32EA, 0x80010DEF), (0x32EB, 0x80010DF0),
+    (0x32EC, 0x80010DF1), (0x32ED, 0x80010DF2), (0x32EE, 0x80010DF3), (0x32EF, 0x80010DF4),
+    (0x32F0, 0x80010DF5), (0x32F1, 0x80010DF6), (0x32F2, 0x80010DF7), (0x32F3, 0x80010DF8),
+    (0x32F4, 0x80010DF9), (0x32F5, 0x80010DFA), (0x32F6, 0x80010DFB), (0x32F7, 0x80010DFC),
+    (0x32F8, 0x80010DFD), (0x32F9, 0x80010DFE), (0x32FA, 0x80010DFF), (0x32FB, 0x80010E00),
+    (0x32FC, 0x80010E01), (0x32FD, 0x80010E02), (0x32FE, 0x80010E03), (0x32FF, 0x80020E04),
+    (0x3300, 0x80040E06), (0x3301, 0x80040E0A), (0x3302, 0x80040E0E), (0x3303, 0x80030E12),
+    (0x3304, 0x80040E15), (0x3305, 0x80030E19), (0x3306, 0x80030E1C), (0x3307, 0x80050E1F),
+    (0x3308, 0x80040E24), (0x3309, 0x80030E28), (0x330A, 0x80030E2B), (0x330B, 0x80030E2E),
+    (0x330C, 0x80040E31), (0x330D, 0x80040E35), (0x330E, 0x80030E39), (0x330F, 0x80030E3C),
+    (0x3310, 0x80020E3F), (0x3311, 0x80030E41), (0x3312, 0x80040E44), (0x3313, 0x80040E48),
+    (0x3314, 0x80020E4C), (0x3315, 0x80050E4E), (0x3316, 0x80060E53), (0x3317, 0x80050E59),
+    (0x3318, 0x80030E5E), (0x3319, 0x80050E61), (0x331A, 0x80050E66), (0x331B, 0x80040E6B),
+    (0x331C, 0x80030E6F), (0x331D, 0x80030E72), (0x331E, 0x80030E75), (0x331F, 0x80040E78),
+    (0x3320, 0x80050E7C), (0x3321, 0x80040E81), (0x3322, 0x80030E85), (0x3323, 0x80030E88),
+    (0x3324, 0x80030E8B), (0x3325, 0x80020E8E), (0x3326, 0x80020E90), (0x3327, 0x80020E92),
+    (0x3328, 0x80020E94), (0x3329, 0x80030E96), (0x332A, 0x80030E99), (0x332B, 0x80050E9C),
+    (0x332C, 0x80030EA1), (0x332D, 0x80040EA4), (0x332E, 0x80050EA8), (0x332F, 0x80030EAD),
+    (0x3330, 0x80020EB0), (0x3331, 0x80020EB2), (0x3332, 0x80050EB4), (0x3333, 0x80040EB9),
+    (0x3334, 0x80050EBD), (0x3335, 0x80030EC2), (0x3336, 0x80050EC5), (0x3337, 0x80020ECA),
+    (0x3338, 0x80030ECC), (0x3339, 0x80030ECF), (0x333A, 0x80030ED2), (0x333B, 0x80030ED5),
+    (0x333C, 0x80030ED8), (0x333D, 0x80040EDB), (0x333E, 0x80030EDF), (0x333F, 0x80020EE2),
+    (0x3340, 0x80030EE4), (0x3341, 0x80030EE7), (0x3342, 0x80030EEA), (0x3343, 0x80040EED),
+    (0x3344, 0x80030EF1), (0x3345, 0x80030EF4), (0x3346, 0x80030EF7), (0x3347, 0x80050EFA),
+    (0x3348, 0x80040EFF), (0x3349, 0x80020F03), (0x334A, 0x80050F05), (0x334B, 0x80020F0A),
+    (0x334C, 0x80040F0C), (0x334D, 0x80040F10), (0x334E, 0x80030F14), (0x334F, 0x80030F17),
+    (0x3350, 0x80030F1A), (0x3351, 0x80040F1D), (0x3352, 0x80020F21), (0x3353, 0x80030F23),
+    (0x3354, 0x80040F26), (0x3355, 0x80020F2A), (0x3356, 0x80050F2C), (0x3357, 0x80030F31),
+    (0x3358, 0x80020F34), (0x3359, 0x80020F36), (0x335A, 0x80020F38), (0x335B, 0x80020F3A),
+    (0x335C, 0x80020F3C), (0x335D, 0x80020F3E), (0x335E, 0x80020F40), (0x335F, 0x80020F42),
+    (0x3360, 0x80020F44), (0x3361, 0x80020F46), (0x3362, 0x80030F48), (0x3363, 0x80030F4B),
+    (0x3364, 0x80030F4E), (0x3365, 0x80030F51), (0x3366, 0x80030F54), (0x3367, 0x80030F57),
+    (0x3368, 0x80030F5A), (0x3369, 0x80030F5D), (0x336A, 0x80030F60), (0x336B, 0x80030F63),
+    (0x336C, 0x80030F66), (0x336D, 0x80030F69), (0x336E, 0x80030F6C), (0x336F, 0x80030F6F),
+    (0x3370, 0x80030F72), (0x3371, 0x80030F75), (0x3372, 0x80020F78), (0x3373, 0x80020F7A),
+    (0x3374, 0x80030F7C), (0x3375, 0x80020F7F), (0x3376, 0x80020F81), (0x3377, 0x80020F83),
+    (0x3378, 0x80030F85), (0x3379, 0x80030F88), (0x337A, 0x80020F8B), (0x337B, 0x80020F8D),
+    (0x337C, 0x80020F8F), (0x337D, 0x80020F91), (0x337E, 0x80020F93), (0x337F, 0x80040F95),
+    (0x3380, 0x80020F99), (0x3381, 0x80020F9B), (0x3382, 0x80020F9D), (0x3383, 0x80020F9F),
+    (0x3384, 0x80020FA1), (0x3385, 0x80020FA3), (0x3386, 0x80020FA5), (0x3387, 0x80020FA7),
+    (0x3388, 0x80030FA9), (0x3389, 0x80040FAC), (0x338A, 0x80020FB0), (0x338B, 0x80020FB2),
+    (0x338C, 0x80020FB4), (0x338D, 0x80020FB6), (0x338E, 0x80020FB8), (0x338F, 0x80020FBA),
+    (0x3390, 0x80020FBC), (0x3391, 0x80030FBE), (0x3392, 0x80030FC1), (0x3393, 0x80030FC4),
+    (0x3394, 0x80030FC7), (0x3395, 0x80020FCA), (0x3396, 0x80020FCC), (0x3397, 0x80020FCE),
+    (0x3398, 0x80020FD0), (0x3399, 0x80020FD2), (0x339A, 0x80020FD4), (0x339B, 0x80020FD6),
+    (0x339C, 0x80020FD8), (0x339D, 0x80020FDA), (0x339E, 0x80020FDC), (0x339F, 0x80030FDE),
+    (0x33A0, 0x80030FE1), (0x33A1, 0x80020FE4), (0x33A2, 0x80030FE6), (0x33A3, 0x80030FE9),
+    (0x33A4, 0x80030FEC), (0x33A5, 0x80020FEF), (0x33A6, 0x80030FF1), (0x33A7, 0x80030FF4),
+    (0x33A8, 0x80040FF7), (0x33A9, 0x80020FFB), (0x33AA, 0x80030FFD), (0x33AB, 0x80031000),
+    (0x33AC, 0x80031003), (0x33AD, 0x80031006), (0x33AE, 0x80051009), (0x33AF, 0x8006100E),
+    (0x33B0, 0x80021014), (0x33B1, 0x80021016), (0x33B2, 0x80021018), (0x33B3, 0x8002101A),
+    (0x33B4, 0x8002101C), (0x33B5, 0x8002101E), (0x33B6, 0x80021020), (0x33B7, 0x80021022),
+    (0x33B8, 0x80021024), (0x33B9, 0x80021026), (0x33BA, 0x80021028), (0x33BB, 0x8002102A),
+    (0x33BC, 0x8002102C), (0x33BD, 0x8002102E), (0x33BE, 0x80021030), (0x33BF, 0x80021032),
+    (0x33C0, 0x80021034), (0x33C1, 0x80021036), (0x33C2, 0x80041038), (0x33C3, 0x8002103C),
+    (0x33C4, 0x8002103E), (0x33C5, 0x80021040), (0x33C6, 0x80041042), (0x33C7, 0x80031046),
+    (0x33C8, 0x80021049), (0x33C9, 0x8002104B), (0x33CA, 0x8002104D), (0x33CB, 0x8002104F),
+    (0x33CC, 0x80021051), (0x33CD, 0x80021053), (0x33CE, 0x80021055), (0x33CF, 0x80021057),
+    (0x33D0, 0x80021059), (0x33D1, 0x8002105B), (0x33D2, 0x8003105D), (0x33D3, 0x80021060),
+    (0x33D4, 0x80021062), (0x33D5, 0x80031064), (0x33D6, 0x80031067), (0x33D7, 0x8002106A),
+    (0x33D8, 0x8004106C), (0x33D9, 0x80031070), (0x33DA, 0x80021073), (0x33DB, 0x80021075),
+    (0x33DC, 0x80021077), (0x33DD, 0x80021079), (0x33DE, 0x8003107B), (0x33DF, 0x8003107E),
+    (0x33E0, 0x80021081), (0x33E1, 0x80021083), (0x33E2, 0x80021085), (0x33E3, 0x80021087),
+    (0x33E4, 0x80021089), (0x33E5, 0x8002108B), (0x33E6, 0x8002108D), (0x33E7, 0x8002108F),
+    (0x33E8, 0x80021091), (0x33E9, 0x80031093), (0x33EA, 0x80031096), (0x33EB, 0x80031099),
+    (0x33EC, 0x8003109C), (0x33ED, 0x8003109F), (0x33EE, 0x800310A2), (0x33EF, 0x800310A5),
+    (0x33F0, 0x800310A8), (0x33F1, 0x800310AB), (0x33F2, 0x800310AE), (0x33F3, 0x800310B1),
+    (0x33F4, 0x800310B4), (0x33F5, 0x800310B7), (0x33F6, 0x800310BA), (0x33F7, 0x800310BD),
+    (0x33F8, 0x800310C0), (0x33F9, 0x800310C3), (0x33FA, 0x800310C6), (0x33FB, 0x800310C9),
+    (0x33FC, 0x800310CC), (0x33FD, 0x800310CF), (0x33FE, 0x800310D2), (0x33FF, 0x800310D5),
+    (0xA69C, 0x800110D8), (0xA69D, 0x800110D9), (0xA770, 0x800110DA), (0xA7F2, 0x800110DB),
+    (0xA7F3, 0x800110DC), (0xA7F4, 0x800110DD), (0xA7F8, 0x800110DE), (0xA7F9, 0x800110DF),
+    (0xAB5C, 0x800110E0), (0xAB5D, 0x800110E1), (0xAB5E, 0x800110E2), (0xAB5F, 0x800110E3),
+    (0xAB69, 0x800110E4), (0xF900, 0x110E5), (0xF901, 0x110E6), (0xF902, 0x110E7),
+    (0xF903, 0x110E8), (0xF904, 0x110E9), (0xF905, 0x110EA), (0xF906, 0x110EB),
+    (0xF907, 0x110EC), (0xF908, 0x110ED), (0xF909, 0x110EE), (0xF90A, 0x110EF),
+    (0xF90B, 0x110F0), (0xF90C, 0x110F1), (0xF90D, 0x110F2), (0xF90E, 0x110F3),
+    (0xF90F, 0x110F4), (0xF910, 0x110F5), (0xF911, 0x110F6), (0xF912, 0x110F7),
+    (0xF913, 0x110F8), (0xF914, 0x110F9), (0xF915, 0x110FA), (0xF916, 0x110FB),
+    (0xF917, 0x110FC), (0xF918, 0x110FD), (0xF919, 0x110FE), (0xF91A, 0x110FF),
+    (0xF91B, 0x11100), (0xF91C, 0x11101), (0xF91D, 0x11102), (0xF91E, 0x11103),
+    (0xF91F, 0x11104), (0xF920, 0x11105), (0xF921, 0x11106), (0xF922, 0x11107),
+    (0xF923, 0x11108), (0xF924, 0x11109), (0xF925, 0x1110A), (0xF926, 0x1110B),
+    (0xF927, 0x1110C), (0xF928, 0x1110D), (0xF929, 0x1110E), (0xF92A, 0x1110F),
+    (0xF92B, 0x11110), (0xF92C, 0x11111), (0xF92D, 0x11112), (0xF92E, 0x11113),
+    (0xF92F, 0x11114), (0xF930, 0x11115), (0xF931, 0x11116), (0xF932, 0x11117),
+    (0xF933, 0x11118), (0xF934, 0x11119), (0xF935, 0x1111A), (0xF936, 0x1111B),
+    (0xF937, 0x1111C), (0xF938, 0x1111D), (0xF939, 0x1111E), (0xF93A, 0x1111F),
+    (0xF93B, 0x11120), (0xF93C, 0x11121), (0xF93D, 0x11122), (0xF93E, 0x11123),
+    (0xF93F, 0x11124), (0xF940, 0x11125), (0xF941, 0x11126), (0xF942, 0x11127),
+    (0xF943, 0x11128), (0xF944, 0x11129), (0xF945, 0x1112A), (0xF946, 0x1112B),
+    (0xF947, 0x1112C), (0xF948, 0x1112D), (0xF949, 0x1112E), (0xF94A, 0x1112F),
+    (0xF94B, 0x11130), (0xF94C, 0x11131), (0xF94D, 0x11132), (0xF94E, 0x11133),
+    (0xF94F, 0x11134), (0xF950, 0x11135), (0xF951, 0x11136), (0xF952, 0x11137),
+    (0xF953, 0x11138), (0xF954, 0x11139), (0xF955, 0x1113A), (0xF956, 0x1113B),
+    (0xF957, 0x1113C), (0xF958, 0x1113D), (0xF959, 0x1113E), (0xF95A, 0x1113F),
+    (0xF95B, 0x11140), (0xF95C, 0x11141), (0xF95D, 0x11142), (0xF95E, 0x11143),
+    (0xF95F, 0x11144), (0xF960, 0x11145), (0xF961, 0x11146), (0xF962, 0x11147),
+    (0xF963, 0x11148), (0xF964, 0x11149), (0xF965, 0x1114A), (0xF966, 0x1114B),
+    (0xF967, 0x1114C), (0xF968, 0x1114D), (0xF969, 0x1114E), (0xF96A, 0x1114F),
+    (0xF96B, 0x11150), (0xF96C, 0x11151), (0xF96D, 0x11152), (0xF96E, 0x11153),
+    (0xF96F, 0x11154), (0xF970, 0x11155), (0xF971, 0x11156), (0xF972, 0x11157),
+    (0xF973, 0x11158), (0xF974, 0x11159), (0xF975, 0x1115A), (0xF976, 0x1115B),
+    (0xF977, 0x1115C), (0xF978, 0x1115D), (0xF979, 0x1115E), (0xF97A, 0x1115F),
+    (0xF97B, 0x11160), (0xF97C, 0x11161), (0xF97D, 0x11162), (0xF97E, 0x11163),
+    (0xF97F, 0x11164), (0xF980, 0x11165), (0xF981, 0x11166), (0xF982, 0x11167),
+    (0xF983, 0x11168), (0xF984, 0x11169), (0xF985, 0x1116A), (0xF986, 0x1116B),
+    (0xF987, 0x1116C), (0xF988, 0x1116D), (0xF989, 0x1116E), (0xF98A, 0x1116F),
+    (0xF98B, 0x11170), (0xF98C, 0x11171), (0xF98D, 0x11172), (0xF98E, 0x11173),
+    (0xF98F, 0x11174), (0xF990, 0x11175), (0xF991, 0x11176), (0xF992, 0x11177),
+    (0xF993, 0x11178), (0xF994, 0x11179), (0xF995, 0x1117A), (0xF996, 0x1117B),
+    (0xF997, 0x1117C), (0xF998, 0x1117D), (0xF999, 0x1117E), (0xF99A, 0x1117F),
+    (0xF99B, 0x11180), (0xF99C, 0x11181), (0xF99D, 0x11182), (0xF99E, 0x11183),
+    (0xF99F, 0x11184), (0xF9A0, 0x11185), (0xF9A1, 0x11186), (0xF9A2, 0x11187),
+    (0xF9A3, 0x11188), (0xF9A4, 0x11189), (0xF9A5, 0x1118A), (0xF9A6, 0x1118B),
+    (0xF9A7, 0x1118C), (0xF9A8, 0x1118D), (0xF9A9, 0x1118E), (0xF9AA, 0x1118F),
+    (0xF9AB, 0x11190), (0xF9AC, 0x11191), (0xF9AD, 0x11192), (0xF9AE, 0x11193),
+    (0xF9AF, 0x11194), (0xF9B0, 0x11195), (0xF9B1, 0x11196), (0xF9B2, 0x11197),
+    (0xF9B3, 0x11198), (0xF9B4, 0x11199), (0xF9B5, 0x1119A), (0xF9B6, 0x1119B),
+    (0xF9B7, 0x1119C), (0xF9B8, 0x1119D), (0xF9B9, 0x1119E), (0xF9BA, 0x1119F),
+    (0xF9BB, 0x111A0), (0xF9BC, 0x111A1), (0xF9BD, 0x111A2), (0xF9BE, 0x111A3),
+    (0xF9BF, 0x111A4), (0xF9C0, 0x111A5), (0xF9C1, 0x111A6), (0xF9C2, 0x111A7),
+    (0xF9C3, 0x111A8), (0xF9C4, 0x111A9), (0xF9C5, 0x111AA), (0xF9C6, 0x111AB),
+    (0xF9C7, 0x111AC), (0xF9C8, 0x111AD), (0xF9C9, 0x111AE), (0xF9CA, 0x111AF),
+    (0xF9CB, 0x111B0), (0xF9CC, 0x111B1), (0xF9CD, 0x111B2), (0xF9CE, 0x111B3),
+    (0xF9CF, 0x111B4), (0xF9D0, 0x111B5), (0xF9D1, 0x111B6), (0xF9D2, 0x111B7),
+    (0xF9D3, 0x111B8), (0xF9D4, 0x111B9), (0xF9D5, 0x111BA), (0xF9D6, 0x111BB),
+    (0xF9D7, 0x111BC), (0xF9D8, 0x111BD), (0xF9D9, 0x111BE), (0xF9DA, 0x111BF),
+    (0xF9DB, 0x111C0), (0xF9DC, 0x111C1), (0xF9DD, 0x111C2), (0xF9DE, 0x111C3),
+    (0xF9DF, 0x111C4), (0xF9E0, 0x111C5), (0xF9E1, 0x111C6), (0xF9E2, 0x111C7),
+    (0xF9E3, 0x111C8), (0xF9E4, 0x111C9), (0xF9E5, 0x111CA), (0xF9E6, 0x111CB),
+    (0xF9E7, 0x111CC), (0xF9E8, 0x111CD), (0xF9E9, 0x111CE), (0xF9EA, 0x111CF),
+    (0xF9EB, 0x111D0), (0xF9EC, 0x111D1), (0xF9ED, 0x111D2), (0xF9EE, 0x111D3),
+    (0xF9EF, 0x111D4), (0xF9F0, 0x111D5), (0xF9F1, 0x111D6), (0xF9F2, 0x111D7),
+    (0xF9F3, 0x111D8), (0xF9F4, 0x111D9), (0xF9F5, 0x111DA), (0xF9F6, 0x111DB),
+    (0xF9F7, 0x111DC), (0xF9F8, 0x111DD), (0xF9F9, 0x111DE), (0xF9FA, 0x111DF),
+    (0xF9FB, 0x111E0), (0xF9FC, 0x111E1), (0xF9FD, 0x111E2), (0xF9FE, 0x111E3),
+    (0xF9FF, 0x111E4), (0xFA00, 0x111E5), (0xFA01, 0x111E6), (0xFA02, 0x111E7),
+    (0xFA03, 0x111E8), (0xFA04, 0x111E9), (0xFA05, 0x111EA), (0xFA06, 0x111EB),
+    (0xFA07, 0x111EC), (0xFA08, 0x111ED), (0xFA09, 0x111EE), (0xFA0A, 0x111EF),
+    (0xFA0B, 0x111F0), (0xFA0C, 0x111F1), (0xFA0D, 0x111F2), (0xFA10, 0x111F3),
+    (0xFA12, 0x111F4), (0xFA15, 0x111F5), (0xFA16, 0x111F6), (0xFA17, 0x111F7),
+    (0xFA18, 0x111F8), (0xFA19, 0x111F9), (0xFA1A, 0x111FA), (0xFA1B, 0x111FB),
+    (0xFA1C, 0x111FC), (0xFA1D, 0x111FD), (0xFA1E, 0x111FE), (0xFA20, 0x111FF),
+    (0xFA22, 0x11200), (0xFA25, 0x11201), (0xFA26, 0x11202), (0xFA2A, 0x11203),
+    (0xFA2B, 0x11204), (0xFA2C, 0x11205), (0xFA2D, 0x11206), (0xFA2E, 0x11207),
+    (0xFA2F, 0x11208), (0xFA30, 0x11209), (0xFA31, 0x1120A), (0xFA32, 0x1120B),
+    (0xFA33, 0x1120C), (0xFA34, 0x1120D), (0xFA35, 0x1120E), (0xFA36, 0x1120F),
+    (0xFA37, 0x11210), (0xFA38, 0x11211), (0xFA39, 0x11212), (0xFA3A, 0x11213),
+    (0xFA3B, 0x11214), (0xFA3C, 0x11215), (0xFA3D, 0x11216), (0xFA3E, 0x11217),
+    (0xFA3F, 0x11218), (0xFA40, 0x11219), (0xFA41, 0x1121A), (0xFA42, 0x1121B),
+    (0xFA43, 0x1121C), (0xFA44, 0x1121D), (0xFA45, 0x1121E), (0xFA46, 0x1121F),
+    (0xFA47, 0x11220), (0xFA48, 0x11221), (0xFA49, 0x11222), (0xFA4A, 0x11223),
+    (0xFA4B, 0x11224), (0xFA4C, 0x11225), (0xFA4D, 0x11226), (0xFA4E, 0x11227),
+    (0xFA4F, 0x11228), (0xFA50, 0x11229), (0xFA51, 0x1122A), (0xFA52, 0x1122B),
+    (0xFA53, 0x1122C), (0xFA54, 0x1122D), (0xFA55, 0x1122E), (0xFA56, 0x1122F),
+    (0xFA57, 0x11230), (0xFA58, 0x11231), (0xFA59, 0x11232), (0xFA5A, 0x11233),
+    (0xFA5B, 0x11234), (0xFA5C, 0x11235), (0xFA5D, 0x11236), (0xFA5E, 0x11237),
+    (0xFA5F, 0x11238), (0xFA60, 0x11239), (0xFA61, 0x1123A), (0xFA62, 0x1123B),
+    (0xFA63, 0x1123C), (0xFA64, 0x1123D), (0xFA65, 0x1123E), (0xFA66, 0x1123F),
+    (0xFA67, 0x11240), (0xFA68, 0x11241), (0xFA69, 0x11242), (0xFA6A, 0x11243),
+    (0xFA6B, 0x11244), (0xFA6C, 0x11245), (0xFA6D, 0x11246), (0xFA70, 0x11247),
+    (0xFA71, 0x11248), (0xFA72, 0x11249), (0xFA73, 0x1124A), (0xFA74, 0x1124B),
+    (0xFA75, 0x1124C), (0xFA76, 0x1124D), (0xFA77, 0x1124E), (0xFA78, 0x1124F),
+    (0xFA79, 0x11250), (0xFA7A, 0x11251), (0xFA7B, 0x11252), (0xFA7C, 0x11253),
+    (0xFA7D, 0x11254), (0xFA7E, 0x11255), (0xFA7F, 0x11256), (0xFA80, 0x11257),
+    (0xFA81, 0x11258), (0xFA82, 0x11259), (0xFA83, 0x1125A), (0xFA84, 0x1125B),
+    (0xFA85, 0x1125C), (0xFA86, 0x1125D), (0xFA87, 0x1125E), (0xFA88, 0x1125F),
+    (0xFA89, 0x11260), (0xFA8A, 0x11261), (0xFA8B, 0x11262), (0xFA8C, 0x11263),
+    (0xFA8D, 0x11264), (0xFA8E, 0x11265), (0xFA8F, 0x11266), (0xFA90, 0x11267),
+    (0xFA91, 0x11268), (0xFA92, 0x11269), (0xFA93, 0x1126A), (0xFA94, 0x1126B),
+    (0xFA95, 0x1126C), (0xFA96, 0x1126D), (0xFA97, 0x1126E), (0xFA98, 0x1126F),
+    (0xFA99, 0x11270), (0xFA9A, 0x11271), (0xFA9B, 0x11272), (0xFA9C, 0x11273),
+    (0xFA9D, 0x11274), (0xFA9E, 0x11275), (0xFA9F, 0x11276), (0xFAA0, 0x11277),
+    (0xFAA1, 0x11278), (0xFAA2, 0x11279), (0xFAA3, 0x1127A), (0xFAA4, 0x1127B),
+    (0xFAA5, 0x1127C), (0xFAA6, 0x1127D), (0xFAA7, 0x1127E), (0xFAA8, 0x1127F),
+    (0xFAA9, 0x11280), (0xFAAA, 0x11281), (0xFAAB, 0x11282), (0xFAAC, 0x11283),
+    (0xFAAD, 0x11284), (0xFAAE, 0x11285), (0xFAAF, 0x11286), (0xFAB0, 0x11287),
+    (0xFAB1, 0x11288), (0xFAB2, 0x11289), (0xFAB3, 0x1128A), (0xFAB4, 0x1128B),
+    (0xFAB5, 0x1128C), (0xFAB6, 0x1128D), (0xFAB7, 0x1128E), (0xFAB8, 0x1128F),
+    (0xFAB9, 0x11290), (0xFABA, 0x11291), (0xFABB, 0x11292), (0xFABC, 0x11293),
+    (0xFABD, 0x11294), (0xFABE, 0x11295), (0xFABF, 0x11296), (0xFAC0, 0x11297),
+    (0xFAC1, 0x11298), (0xFAC2, 0x11299), (0xFAC3, 0x1129A), (0xFAC4, 0x1129B),
+    (0xFAC5, 0x1129C), (0xFAC6, 0x1129D), (0xFAC7, 0x1129E), (0xFAC8, 0x1129F),
+    (0xFAC9, 0x112A0), (0xFACA, 0x112A1), (0xFACB, 0x112A2), (0xFACC, 0x112A3),
+    (0xFACD, 0x112A4), (0xFACE, 0x112A5), (0xFACF, 0x112A6), (0xFAD0, 0x112A7),
+    (0xFAD1, 0x112A8), (0xFAD2, 0x112A9), (0xFAD3, 0x112AA), (0xFAD4, 0x112AB),
+    (0xFAD5, 0x112AC), (0xFAD6, 0x112AD), (0xFAD7, 0x112AE), (0xFAD8, 0x112AF),
+    (0xFAD9, 0x112B0), (0xFB00, 0x800212B1), (0xFB01, 0x800212B3), (0xFB02, 0x800212B5),
+    (0xFB03, 0x800312B7), (0xFB04, 0x800312BA), (0xFB05, 0x800212BD), (0xFB06, 0x800212BF),
+    (0xFB13, 0x800212C1), (0xFB14, 0x800212C3), (0xFB15, 0x800212C5), (0xFB16, 0x800212C7),
+    (0xFB17, 0x800212C9), (0xFB1D, 0x212CB), (0xFB1F, 0x212CD), (0xFB20, 0x800112CF),
+    (0xFB21, 0x800112D0), (0xFB22, 0x800112D1), (0xFB23, 0x800112D2), (0xFB24, 0x800112D3),
+    (0xFB25, 0x800112D4), (0xFB26, 0x800112D5), (0xFB27, 0x800112D6), (0xFB28, 0x800112D7),
+    (0xFB29, 0x800112D8), (0xFB2A, 0x212D9), (0xFB2B, 0x212DB), (0xFB2C, 0x212DD),
+    (0xFB2D, 0x212DF), (0xFB2E, 0x212E1), (0xFB2F, 0x212E3), (0xFB30, 0x212E5),
+    (0xFB31, 0x212E7), (0xFB32, 0x212E9), (0xFB33, 0x212EB), (0xFB34, 0x212ED),
+    (0xFB35, 0x212EF), (0xFB36, 0x212F1), (0xFB38, 0x212F3), (0xFB39, 0x212F5),
+    (0xFB3A, 0x212F7), (0xFB3B, 0x212F9), (0xFB3C, 0x212FB), (0xFB3E, 0x212FD),
+    (0xFB40, 0x212FF), (0xFB41, 0x21301), (0xFB43, 0x21303), (0xFB44, 0x21305),
+    (0xFB46, 0x21307), (0xFB47, 0x21309), (0xFB48, 0x2130B), (0xFB49, 0x2130D),
+    (0xFB4A, 0x2130F), (0xFB4B, 0x21311), (0xFB4C, 0x21313), (0xFB4D, 0x21315),
+    (0xFB4E, 0x21317), (0xFB4F, 0x80021319), (0xFB50, 0x8001131B), (0xFB51, 0x8001131C),
+    (0xFB52, 0x8001131D), (0xFB53, 0x8001131E), (0xFB54, 0x8001131F), (0xFB55, 0x80011320),
+    (0xFB56, 0x80011321), (0xFB57, 0x80011322), (0xFB58, 0x80011323), (0xFB59, 0x80011324),
+    (0xFB5A, 0x80011325), (0xFB5B, 0x80011326), (0xFB5C, 0x80011327), (0xFB5D, 0x80011328),
+    (0xFB5E, 0x80011329), (0xFB5F, 0x8001132A), (0xFB60, 0x8001132B), (0xFB61, 0x8001132C),
+    (0xFB62, 0x8001132D), (0xFB63, 0x8001132E), (0xFB64, 0x8001132F), (0xFB65, 0x80011330),
+    (0xFB66, 0x80011331), (0xFB67, 0x80011332), (0xFB68, 0x80011333), (0xFB69, 0x80011334),
+    (0xFB6A, 0x80011335), (0xFB6B, 0x80011336), (0xFB6C, 0x80011337), (0xFB6D, 0x80011338),
+    (0xFB6E, 0x80011339), (0xFB6F, 0x8001133A), (0xFB70, 0x8001133B), (0xFB71, 0x8001133C),
+    (0xFB72, 0x8001133D), (0xFB73, 0x8001133E), (0xFB74, 0x8001133F), (0xFB75, 0x80011340),
+    (0xFB76, 0x80011341), (0xFB77, 0x80011342), (0xFB78, 0x80011343), (0xFB79, 0x80011344),
+    (0xFB7A, 0x80011345), (0xFB7B, 0x80011346), (0xFB7C, 0x80011347), (0xFB7D, 0x80011348),
+    (0xFB7E, 0x80011349), (0xFB7F, 0x8001134A), (0xFB80, 0x8001134B), (0xFB81, 0x8001134C),
+    (0xFB82, 0x8001134D), (0xFB83, 0x8001134E), (0xFB84, 0x8001134F), (0xFB85, 0x80011350),
+    (0xFB86, 0x80011351), (0xFB87, 0x80011352), (0xFB88, 0x80011353), (0xFB89, 0x80011354),
+    (0xFB8A, 0x80011355), (0xFB8B, 0x80011356), (0xFB8C, 0x80011357), (0xFB8D, 0x80011358),
+    (0xFB8E, 0x80011359), (0xFB8F, 0x8001135A), (0xFB90, 0x8001135B), (0xFB91, 0x8001135C),
+    (0xFB92, 0x8001135D), (0xFB93, 0x8001135E), (0xFB94, 0x8001135F), (0xFB95, 0x80011360),
+    (0xFB96, 0x80011361), (0xFB97, 0x80011362), (0xFB98, 0x80011363), (0xFB99, 0x80011364),
+    (0xFB9A, 0x80011365), (0xFB9B, 0x80011366), (0xFB9C, 0x80011367), (0xFB9D, 0x80011368),
+    (0xFB9E, 0x80011369), (0xFB9F, 0x8001136A), (0xFBA0, 0x8001136B), (0xFBA1, 0x8001136C),
+    (0xFBA2, 0x8001136D), (0xFBA3, 0x8001136E), (0xFBA4, 0x8001136F), (0xFBA5, 0x80011370),
+    (0xFBA6, 0x80011371), (0xFBA7, 0x80011372), (0xFBA8, 0x80011373), (0xFBA9, 0x80011374),
+    (0xFBAA, 0x80011375), (0xFBAB, 0x80011376), (0xFBAC, 0x80011377), (0xFBAD, 0x80011378),
+    (0xFBAE, 0x80011379), (0xFBAF, 0x8001137A), (0xFBB0, 0x8001137B), (0xFBB1, 0x8001137C),
+    (0xFBD3, 0x8001137D), (0xFBD4, 0x8001137E), (0xFBD5, 0x8001137F), (0xFBD6, 0x80011380),
+    (0xFBD7, 0x80011381), (0xFBD8, 0x80011382), (0xFBD9, 0x80011383), (0xFBDA, 0x80011384),
+    (0xFBDB, 0x80011385), (0xFBDC, 0x80011386), (0xFBDD, 0x80011387), (0xFBDE, 0x80011388),
+    (0xFBDF, 0x80011389), (0xFBE0, 0x8001138A), (0xFBE1, 0x8001138B), (0xFBE2, 0x8001138C),
+    (0xFBE3, 0x8001138D), (0xFBE4, 0x8001138E), (0xFBE5, 0x8001138F), (0xFBE6, 0x80011390),
+    (0xFBE7, 0x80011391), (0xFBE8, 0x80011392), (0xFBE9, 0x80011393), (0xFBEA, 0x80021394),
+    (0xFBEB, 0x80021396), (0xFBEC, 0x80021398), (0xFBED, 0x8002139A), (0xFBEE, 0x8002139C),
+    (0xFBEF, 0x8002139E), (0xFBF0, 0x800213A0), (0xFBF1, 0x800213A2), (0xFBF2, 0x800213A4),
+    (0xFBF3, 0x800213A6), (0xFBF4, 0x800213A8), (0xFBF5, 0x800213AA), (0xFBF6, 0x800213AC),
+    (0xFBF7, 0x800213AE), (0xFBF8, 0x800213B0), (0xFBF9, 0x800213B2), (0xFBFA, 0x800213B4),
+    (0xFBFB, 0x800213B6), (0xFBFC, 0x800113B8), (0xFBFD, 0x800113B9), (0xFBFE, 0x800113BA),
+    (0xFBFF, 0x800113BB), (0xFC00, 0x800213BC), (0xFC01, 0x800213BE), (0xFC02, 0x800213C0),
+    (0xFC03, 0x800213C2), (0xFC04, 0x800213C4), (0xFC05, 0x800213C6), (0xFC06, 0x800213C8),
+    (0xFC07, 0x800213CA), (0xFC08, 0x800213CC), (0xFC09, 0x800213CE), (0xFC0A, 0x800213D0),
+    (0xFC0B, 0x800213D2), (0xFC0C, 0x800213D4), (0xFC0D, 0x800213D6), (0xFC0E, 0x800213D8),
+    (0xFC0F, 0x800213DA), (0xFC10, 0x800213DC), (0xFC11, 0x800213DE), (0xFC12, 0x800213E0),
+    (0xFC13, 0x800213E2), (0xFC14, 0x800213E4), (0xFC15, 0x800213E6), (0xFC16, 0x800213E8),
+    (0xFC17, 0x800213EA), (0xFC18, 0x800213EC), (0xFC19, 0x800213EE), (0xFC1A, 0x800213F0),
+    (0xFC1B, 0x800213F2), (0xFC1C, 0x800213F4), (0xFC1D, 0x800213F6), (0xFC1E, 0x800213F8),
+    (0xFC1F, 0x800213FA), (0xFC20, 0x800213FC), (0xFC21, 0x800213FE), (0xFC22, 0x80021400),
+    (0xFC23, 0x80021402), (0xFC24, 0x80021404), (0xFC25, 0x80021406), (0xFC26, 0x80021408),
+    (0xFC27, 0x8002140A), (0xFC28, 0x8002140C), (0xFC29, 0x8002140E), (0xFC2A, 0x80021410),
+    (0xFC2B, 0x80021412), (0xFC2C, 0x80021414), (0xFC2D, 0x80021416), (0xFC2E, 0x80021418),
+    (0xFC2F, 0x8002141A), (0xFC30, 0x8002141C), (0xFC31, 0x8002141E), (0xFC32, 0x80021420),
+    (0xFC33, 0x80021422), (0xFC34, 0x80021424), (0xFC35, 0x80021426), (0xFC36, 0x80021428),
+    (0xFC37, 0x8002142A), (0xFC38, 0x8002142C), (0xFC39, 0x8002142E), (0xFC3A, 0x80021430),
+    (0xFC3B, 0x80021432), (0xFC3C, 0x80021434), (0xFC3D, 0x80021436), (0xFC3E, 0x80021438),
+    (0xFC3F, 0x8002143A), (0xFC40, 0x8002143C), (0xFC41, 0x8002143E), (0xFC42, 0x80021440),
+    (0xFC43, 0x80021442), (0xFC44, 0x80021444), (0xFC45, 0x80021446), (0xFC46, 0x80021448),
+    (0xFC47, 0x8002144A), (0xFC48, 0x8002144C), (0xFC49, 0x8002144E), (0xFC4A, 0x80021450),
+    (0xFC4B, 0x80021452), (0xFC4C, 0x80021454), (0xFC4D, 0x80021456), (0xFC4E, 0x80021458),
+    (0xFC4F, 0x8002145A), (0xFC50, 0x8002145C), (0xFC51, 0x8002145E), (0xFC52, 0x80021460),
+    (0xFC53, 0x80021462), (0xFC54, 0x80021464), (0xFC55, 0x80021466), (0xFC56, 0x80021468),
+    (0xFC57, 0x8002146A), (0xFC58, 0x8002146C), (0xFC59, 0x8002146E), (0xFC5A, 0x80021470),
+    (0xFC5B, 0x80021472), (0xFC5C, 0x80021474), (0xFC5D, 0x80021476), (0xFC5E, 0x80031478),
+    (0xFC5F, 0x8003147B), (0xFC60, 0x8003147E), (0xFC61, 0x80031481), (0xFC62, 0x80031484),
+    (0xFC63, 0x80031487), (0xFC64, 0x8002148A), (0xFC65, 0x8002148C), (0xFC66, 0x8002148E),
+    (0xFC67, 0x80021490), (0xFC68, 0x80021492), (0xFC69, 0x80021494), (0xFC6A, 0x80021496),
+    (0xFC6B, 0x80021498), (0xFC6C, 0x8002149A), (0xFC6D, 0x8002149C), (0xFC6E, 0x8002149E),
+    (0xFC6F, 0x800214A0), (0xFC70, 0x800214A2), (0xFC71, 0x800214A4), (0xFC72, 0x800214A6),
+    (0xFC73, 0x800214A8), (0xFC74, 0x800214AA), (0xFC75, 0x800214AC), (0xFC76, 0x800214AE),
+    (0xFC77, 0x800214B0), (0xFC78, 0x800214B2), (0xFC79, 0x800214B4), (0xFC7A, 0x800214B6),
+    (0xFC7B, 0x800214B8), (0xFC7C, 0x800214BA), (0xFC7D, 0x800214BC), (0xFC7E, 0x800214BE),
+    (0xFC7F, 0x800214C0), (0xFC80, 0x800214C2), (0xFC81, 0x800214C4), (0xFC82, 0x800214C6),
+    (0xFC83, 0x800214C8), (0xFC84, 0x800214CA), (0xFC85, 0x800214CC), (0xFC86, 0x800214CE),
+    (0xFC87, 0x800214D0), (0xFC88, 0x800214D2), (0xFC89, 0x800214D4), (0xFC8A, 0x800214D6),
+    (0xFC8B, 0x800214D8), (0xFC8C, 0x800214DA), (0xFC8D, 0x800214DC), (0xFC8E, 0x800214DE),
+    (0xFC8F, 0x800214E0), (0xFC90, 0x800214E2), (0xFC91, 0x800214E4), (0xFC92, 0x800214E6),
+    (0xFC93, 0x800214E8), (0xFC94, 0x800214EA), (0xFC95, 0x800214EC), (0xFC96, 0x800214EE),
+    (0xFC97, 0x800214F0), (0xFC98, 0x800214F2), (0xFC99, 0x800214F4), (0xFC9A, 0x800214F6),
+    (0xFC9B, 0x800214F8), (0xFC9C, 0x800214FA), (0xFC9D, 0x800214FC), (0xFC9E, 0x800214FE),
+    (0xFC9F, 0x80021500), (0xFCA0, 0x80021502), (0xFCA1, 0x80021504), (0xFCA2, 0x80021506),
+    (0xFCA3, 0x80021508), (0xFCA4, 0x8002150A), (0xFCA5, 0x8002150C), (0xFCA6, 0x8002150E),
+    (0xFCA7, 0x80021510), (0xFCA8, 0x80021512), (0xFCA9, 0x80021514), (0xFCAA, 0x80021516),
+    (0xFCAB, 0x80021518), (0xFCAC, 0x8002151A), (0xFCAD, 0x8002151C), (0xFCAE, 0x8002151E),
+    (0xFCAF, 0x80021520), (0xFCB0, 0x80021522), (0xFCB1, 0x80021524), (0xFCB2, 0x80021526),
+    (0xFCB3, 0x80021528), (0xFCB4, 0x8002152A), (0xFCB5, 0x8002152C), (0xFCB6, 0x8002152E),
+    (0xFCB7, 0x80021530), (0xFCB8, 0x80021532), (0xFCB9, 0x80021534), (0xFCBA, 0x80021536),
+    (0xFCBB, 0x80021538), (0xFCBC, 0x8002153A), (0xFCBD, 0x8002153C), (0xFCBE, 0x8002153E),
+    (0xFCBF, 0x80021540), (0xFCC0, 0x80021542), (0xFCC1, 0x80021544), (0xFCC2, 0x80021546),
+    (0xFCC3, 0x80021548), (0xFCC4, 0x8002154A), (0xFCC5, 0x8002154C), (0xFCC6, 0x8002154E),
+    (0xFCC7, 0x80021550), (0xFCC8, 0x80021552), (0xFCC9, 0x80021554), (0xFCCA, 0x80021556),
+    (0xFCCB, 0x80021558), (0xFCCC, 0x8002155A), (0xFCCD, 0x8002155C), (0xFCCE, 0x8002155E),
+    (0xFCCF, 0x80021560), (0xFCD0, 0x80021562), (0xFCD1, 0x80021564), (0xFCD2, 0x80021566),
+    (0xFCD3, 0x80021568), (0xFCD4, 0x8002156A), (0xFCD5, 0x8002156C), (0xFCD6, 0x8002156E),
+    (0xFCD7, 0x80021570), (0xFCD8, 0x80021572), (0xFCD9, 0x80021574), (0xFCDA, 0x80021576),
+    (0xFCDB, 0x80021578), (0xFCDC, 0x8002157A), (0xFCDD, 0x8002157C), (0xFCDE, 0x8002157E),
+    (0xFCDF, 0x80021580), (0xFCE0, 0x80021582), (0xFCE1, 0x80021584), (0xFCE2, 0x80021586),
+    (0xFCE3, 0x80021588), (0xFCE4, 0x8002158A), (0xFCE5, 0x8002158C), (0xFCE6, 0x8002158E),
+    (0xFCE7, 0x80021590), (0xFCE8, 0x80021592), (0xFCE9, 0x80021594), (0xFCEA, 0x80021596),
+    (0xFCEB, 0x80021598), (0xFCEC, 0x8002159A), (0xFCED, 0x8002159C), (0xFCEE, 0x8002159E),
+    (0xFCEF, 0x800215A0), (0xFCF0, 0x800215A2), (0xFCF1, 0x800215A4), (0xFCF2, 0x800315A6),
+    (0xFCF3, 0x800315A9), (0xFCF4, 0x800315AC), (0xFCF5, 0x800215AF), (0xFCF6, 0x800215B1),
+    (0xFCF7, 0x800215B3), (0xFCF8, 0x800215B5), (0xFCF9, 0x800215B7), (0xFCFA, 0x800215B9),
+    (0xFCFB, 0x800215BB), (0xFCFC, 0x800215BD), (0xFCFD, 0x800215BF), (0xFCFE, 0x800215C1),
+    (0xFCFF, 0x800215C3), (0xFD00, 0x800215C5), (0xFD01, 0x800215C7), (0xFD02, 0x800215C9),
+    (0xFD03, 0x800215CB), (0xFD04, 0x800215CD), (0xFD05, 0x800215CF), (0xFD06, 0x800215D1),
+    (0xFD07, 0x800215D3), (0xFD08, 0x800215D5), (0xFD09, 0x800215D7), (0xFD0A, 0x800215D9),
+    (0xFD0B, 0x800215DB), (0xFD0C, 0x800215DD), (0xFD0D, 0x800215DF), (0xFD0E, 0x800215E1),
+    (0xFD0F, 0x800215E3), (0xFD10, 0x800215E5), (0xFD11, 0x800215E7), (0xFD12, 0x800215E9),
+    (0xFD13, 0x800215EB), (0xFD14, 0x800215ED), (0xFD15, 0x800215EF), (0xFD16, 0x800215F1),
+    (0xFD17, 0x800215F3), (0xFD18, 0x800215F5), (0xFD19, 0x800215F7), (0xFD1A, 0x800215F9),
+    (0xFD1B, 0x800215FB), (0xFD1C, 0x800215FD), (0xFD1D, 0x800215FF), (0xFD1E, 0x80021601),
+    (0xFD1F, 0x80021603), (0xFD20, 0x80021605), (0xFD21, 0x80021607), (0xFD22, 0x80021609),
+    (0xFD23, 0x8002160B), (0xFD24, 0x8002160D), (0xFD25, 0x8002160F), (0xFD26, 0x80021611),
+    (0xFD27, 0x80021613), (0xFD28, 0x80021615), (0xFD29, 0x80021617), (0xFD2A, 0x80021619),
+    (0xFD2B, 0x8002161B), (0xFD2C, 0x8002161D), (0xFD2D, 0x8002161F), (0xFD2E, 0x80021621),
+    (0xFD2F, 0x80021623), (0xFD30, 0x80021625), (0xFD31, 0x80021627), (0xFD32, 0x80021629),
+    (0xFD33, 0x8002162B), (0xFD34, 0x8002162D), (0xFD35, 0x8002162F), (0xFD36, 0x80021631),
+    (0xFD37, 0x80021633), (0xFD38, 0x80021635), (0xFD39, 0x80021637), (0xFD3A, 0x80021639),
+    (0xFD3B, 0x8002163B), (0xFD3C, 0x8002163D), (0xFD3D, 0x8002163F), (0xFD50, 0x80031641),
+    (0xFD51, 0x80031644), (0xFD52, 0x80031647), (0xFD53, 0x8003164A), (0xFD54, 0x8003164D),
+    (0xFD55, 0x80031650), (0xFD56, 0x80031653), (0xFD57, 0x80031656), (0xFD58, 0x80031659),
+    (0xFD59, 0x8003165C), (0xFD5A, 0x8003165F), (0xFD5B, 0x80031662), (0xFD5C, 0x80031665),
+    (0xFD5D, 0x80031668), (0xFD5E, 0x8003166B), (0xFD5F, 0x8003166E), (0xFD60, 0x80031671),
+    (0xFD61, 0x80031674), (0xFD62, 0x80031677), (0xFD63, 0x8003167A), (0xFD64, 0x8003167D),
+    (0xFD65, 0x80031680), (0xFD66, 0x80031683), (0xFD67, 0x80031686), (0xFD68, 0x80031689),
+    (0xFD69, 0x8003168C), (0xFD6A, 0x8003168F), (0xFD6B, 0x80031692), (0xFD6C, 0x80031695),
+    (0xFD6D, 0x80031698), (0xFD6E, 0x8003169B), (0xFD6F, 0x8003169E), (0xFD70, 0x800316A1),
+    (0xFD71, 0x800316A4), (0xFD72, 0x800316A7), (0xFD73, 0x800316AA), (0xFD74, 0x800316AD),
+    (0xFD75, 0x800316B0), (0xFD76, 0x800316B3), (0xFD77, 0x800316B6), (0xFD78, 0x800316B9),
+    (0xFD79, 0x800316BC), (0xFD7A, 0x800316BF), (0xFD7B, 0x800316C2), (0xFD7C, 0x800316C5),
+    (0xFD7D, 0x800316C8), (0xFD7E, 0x800316CB), (0xFD7F, 0x800316CE), (0xFD80, 0x800316D1),
+    (0xFD81, 0x800316D4), (0xFD82, 0x800316D7), (0xFD83, 0x800316DA), (0xFD84, 0x800316DD),
+    (0xFD85, 0x800316E0), (0xFD86, 0x800316E3), (0xFD87, 0x800316E6), (0xFD88, 0x800316E9),
+    (0xFD89, 0x800316EC), (0xFD8A, 0x800316EF), (0xFD8B, 0x800316F2), (0xFD8C, 0x800316F5),
+    (0xFD8D, 0x800316F8), (0xFD8E, 0x800316FB), (0xFD8F, 0x800316FE), (0xFD92, 0x80031701),
+    (0xFD93, 0x80031704), (0xFD94, 0x80031707), (0xFD95, 0x8003170A), (0xFD96, 0x8003170D),
+    (0xFD97, 0x80031710), (0xFD98, 0x80031713), (0xFD99, 0x80031716), (0xFD9A, 0x80031719),
+    (0xFD9B, 0x8003171C), (0xFD9C, 0x8003171F), (0xFD9D, 0x80031722), (0xFD9E, 0x80031725),
+    (0xFD9F, 0x80031728), (0xFDA0, 0x8003172B), (0xFDA1, 0x8003172E), (0xFDA2, 0x80031731),
+    (0xFDA3, 0x80031734), (0xFDA4, 0x80031737), (0xFDA5, 0x8003173A), (0xFDA6, 0x8003173D),
+    (0xFDA7, 0x80031740), (0xFDA8, 0x80031743), (0xFDA9, 0x80031746), (0xFDAA, 0x80031749),
+    (0xFDAB, 0x8003174C), (0xFDAC, 0x8003174F), (0xFDAD, 0x80031752), (0xFDAE, 0x80031755),
+    (0xFDAF, 0x80031758), (0xFDB0, 0x8003175B), (0xFDB1, 0x8003175E), (0xFDB2, 0x80031761),
+    (0xFDB3, 0x80031764), (0xFDB4, 0x80031767), (0xFDB5, 0x8003176A), (0xFDB6, 0x8003176D),
+    (0xFDB7, 0x80031770), (0xFDB8, 0x80031773), (0xFDB9, 0x80031776), (0xFDBA, 0x80031779),
+    (0xFDBB, 0x8003177C), (0xFDBC, 0x8003177F), (0xFDBD, 0x80031782), (0xFDBE, 0x80031785),
+    (0xFDBF, 0x80031788), (0xFDC0, 0x8003178B), (0xFDC1, 0x8003178E), (0xFDC2, 0x80031791),
+    (0xFDC3, 0x80031794), (0xFDC4, 0x80031797), (0xFDC5, 0x8003179A), (0xFDC6, 0x8003179D),
+    (0xFDC7, 0x800317A0), (0xFDF0, 0x800317A3), (0xFDF1, 0x800317A6), (0xFDF2, 0x800417A9),
+    (0xFDF3, 0x800417AD), (0xFDF4, 0x800417B1), (0xFDF5, 0x800417B5), (0xFDF6, 0x800417B9),
+    (0xFDF7, 0x800417BD), (0xFDF8, 0x800417C1), (0xFDF9, 0x800317C5), (0xFDFA, 0x801217C8),
+    (0xFDFB, 0x800817DA), (0xFDFC, 0x800417E2), (0xFE10, 0x800117E6), (0xFE11, 0x800117E7),
+    (0xFE12, 0x800117E8), (0xFE13, 0x800117E9), (0xFE14, 0x800117EA), (0xFE15, 0x800117EB),
+    (0xFE16, 0x800117EC), (0xFE17, 0x800117ED), (0xFE18, 0x800117EE), (0xFE19, 0x800117EF),
+    (0xFE30, 0x800117F0), (0xFE31, 0x800117F1), (0xFE32, 0x800117F2), (0xFE33, 0x800117F3),
+    (0xFE34, 0x800117F4), (0xFE35, 0x800117F5), (0xFE36, 0x800117F6), (0xFE37, 0x800117F7),
+    (0xFE38, 0x800117F8), (0xFE39, 0x800117F9), (0xFE3A, 0x800117FA), (0xFE3B, 0x800117FB),
+    (0xFE3C, 0x800117FC), (0xFE3D, 0x800117FD), (0xFE3E, 0x800117FE), (0xFE3F, 0x800117FF),
+    (0xFE40, 0x80011800), (0xFE41, 0x80011801), (0xFE42, 0x80011802), (0xFE43, 0x80011803),
+    (0xFE44, 0x80011804), (0xFE47, 0x80011805), (0xFE48, 0x80011806), (0xFE49, 0x80011807),
+    (0xFE4A, 0x80011808), (0xFE4B, 0x80011809), (0xFE4C, 0x8001180A), (0xFE4D, 0x8001180B),
+    (0xFE4E, 0x8001180C), (0xFE4F, 0x8001180D), (0xFE50, 0x8001180E), (0xFE51, 0x8001180F),
+    (0xFE52, 0x80011810), (0xFE54, 0x80011811), (0xFE55, 0x80011812), (0xFE56, 0x80011813),
+    (0xFE57, 0x80011814), (0xFE58, 0x80011815), (0xFE59, 0x80011816), (0xFE5A, 0x80011817),
+    (0xFE5B, 0x80011818), (0xFE5C, 0x80011819), (0xFE5D, 0x8001181A), (0xFE5E, 0x8001181B),
+    (0xFE5F, 0x8001181C), (0xFE60, 0x8001181D), (0xFE61, 0x8001181E), (0xFE62, 0x8001181F),
+    (0xFE63, 0x80011820), (0xFE64, 0x80011821), (0xFE65, 0x80011822), (0xFE66, 0x80011823),
+    (0xFE68, 0x80011824), (0xFE69, 0x80011825), (0xFE6A, 0x80011826), (0xFE6B, 0x80011827),
+    (0xFE70, 0x80021828), (0xFE71, 0x8002182A), (0xFE72, 0x8002182C), (0xFE74, 0x8002182E),
+    (0xFE76, 0x80021830), (0xFE77, 0x80021832), (0xFE78, 0x80021834), (0xFE79, 0x80021836),
+    (0xFE7A, 0x80021838), (0xFE7B, 0x8002183A), (0xFE7C, 0x8002183C), (0xFE7D, 0x8002183E),
+    (0xFE7E, 0x80021840), (0xFE7F, 0x80021842), (0xFE80, 0x80011844), (0xFE81, 0x80011845),
+    (0xFE82, 0x80011846), (0xFE83, 0x80011847), (0xFE84, 0x80011848), (0xFE85, 0x80011849),
+    (0xFE86, 0x8001184A), (0xFE87, 0x8001184B), (0xFE88, 0x8001184C), (0xFE89, 0x8001184D),
+    (0xFE8A, 0x8001184E), (0xFE8B, 0x8001184F), (0xFE8C, 0x80011850), (0xFE8D, 0x80011851),
+    (0xFE8E, 0x80011852), (0xFE8F, 0x80011853), (0xFE90, 0x80011854), (0xFE91, 0x80011855),
+    (0xFE92, 0x80011856), (0xFE93, 0x80011857), (0xFE94, 0x80011858), (0xFE95, 0x80011859),
+    (0xFE96, 0x8001185A), (0xFE97, 0x8001185B), (0xFE98, 0x8001185C), (0xFE99, 0x8001185D),
+    (0xFE9A, 0x8001185E), (0xFE9B, 0x8001185F), (0xFE9C, 0x80011860), (0xFE9D, 0x80011861),
+    (0xFE9E, 0x80011862), (0xFE9F, 0x80011863), (0xFEA0, 0x80011864), (0xFEA1, 0x80011865),
+    (0xFEA2, 0x80011866), (0xFEA3, 0x80011867), (0xFEA4, 0x80011868), (0xFEA5, 0x80011869),
+    (0xFEA6, 0x8001186A), (0xFEA7, 0x8001186B), (0xFEA8, 0x8001186C), (0xFEA9, 0x8001186D),
+    (0xFEAA, 0x8001186E), (0xFEAB, 0x8001186F), (0xFEAC, 0x80011870), (0xFEAD, 0x80011871),
+    (0xFEAE, 0x80011872), (0xFEAF, 0x80011873), (0xFEB0, 0x80011874), (0xFEB1, 0x80011875),
+    (0xFEB2, 0x80011876), (0xFEB3, 0x80011877), (0xFEB4, 0x80011878), (0xFEB5, 0x80011879),
+    (0xFEB6, 0x8001187A), (0xFEB7, 0x8001187B), (0xFEB8, 0x8001187C), (0xFEB9, 0x8001187D),
+    (0xFEBA, 0x8001187E), (0xFEBB, 0x8001187F), (0xFEBC, 0x80011880), (0xFEBD, 0x80011881),
+    (0xFEBE, 0x80011882), (0xFEBF, 0x80011883), (0xFEC0, 0x80011884), (0xFEC1, 0x80011885),
+    (0xFEC2, 0x80011886), (0xFEC3, 0x80011887), (0xFEC4, 0x80011888), (0xFEC5, 0x80011889),
+    (0xFEC6, 0x8001188A), (0xFEC7, 0x8001188B), (0xFEC8, 0x8001188C), (0xFEC9, 0x8001188D),
+    (0xFECA, 0x8001188E), (0xFECB, 0x8001188F), (0xFECC, 0x80011890), (0xFECD, 0x80011891),
+    (0xFECE, 0x80011892), (0xFECF, 0x80011893), (0xFED0, 0x80011894), (0xFED1, 0x80011895),
+    (0xFED2, 0x80011896), (0xFED3, 0x80011897), (0xFED4, 0x80011898), (0xFED5, 0x80011899),
+    (0xFED6, 0x8001189A), (0xFED7, 0x8001189B), (0xFED8, 0x8001189C), (0xFED9, 0x8001189D),
+    (0xFEDA, 0x8001189E), (0xFEDB, 0x8001189F), (0xFEDC, 0x800118A0), (0xFEDD, 0x800118A1),
+    (0xFEDE, 0x800118A2), (0xFEDF, 0x800118A3), (0xFEE0, 0x800118A4), (0xFEE1, 0x800118A5),
+    (0xFEE2, 0x800118A6), (0xFEE3, 0x800118A7), (0xFEE4, 0x800118A8), (0xFEE5, 0x800118A9),
+    (0xFEE6, 0x800118AA), (0xFEE7, 0x800118AB), (0xFEE8, 0x800118AC), (0xFEE9, 0x800118AD),
+    (0xFEEA, 0x800118AE), (0xFEEB, 0x800118AF), (0xFEEC, 0x800118B0), (0xFEED, 0x800118B1),
+    (0xFEEE, 0x800118B2), (0xFEEF, 0x800118B3), (0xFEF0, 0x800118B4), (0xFEF1, 0x800118B5),
+    (0xFEF2, 0x800118B6), (0xFEF3, 0x800118B7), (0xFEF4, 0x800118B8), (0xFEF5, 0x800218B9),
+    (0xFEF6, 0x800218BB), (0xFEF7, 0x800218BD), (0xFEF8, 0x800218BF), (0xFEF9, 0x800218C1),
+    (0xFEFA, 0x800218C3), (0xFEFB, 0x800218C5), (0xFEFC, 0x800218C7), (0xFF01, 0x800118C9),
+    (0xFF02, 0x800118CA), (0xFF03, 0x800118CB), (0xFF04, 0x800118CC), (0xFF05, 0x800118CD),
+    (0xFF06, 0x800118CE), (0xFF07, 0x800118CF), (0xFF08, 0x800118D0), (0xFF09, 0x800118D1),
+    (0xFF0A, 0x800118D2), (0xFF0B, 0x800118D3), (0xFF0C, 0x800118D4), (0xFF0D, 0x800118D5),
+    (0xFF0E, 0x800118D6), (0xFF0F, 0x800118D7), (0xFF10, 0x800118D8), (0xFF11, 0x800118D9),
+    (0xFF12, 0x800118DA), (0xFF13, 0x800118DB), (0xFF14, 0x800118DC), (0xFF15, 0x800118DD),
+    (0xFF16, 0x800118DE), (0xFF17, 0x800118DF), (0xFF18, 0x800118E0), (0xFF19, 0x800118E1),
+    (0xFF1A, 0x800118E2), (0xFF1B, 0x800118E3), (0xFF1C, 0x800118E4), (0xFF1D, 0x800118E5),
+    (0xFF1E, 0x800118E6), (0xFF1F, 0x800118E7), (0xFF20, 0x800118E8), (0xFF21, 0x800118E9),
+    (0xFF22, 0x800118EA), (0xFF23, 0x800118EB), (0xFF24, 0x800118EC), (0xFF25, 0x800118ED),
+    (0xFF26, 0x800118EE), (0xFF27, 0x800118EF), (0xFF28, 0x800118F0), (0xFF29, 0x800118F1),
+    (0xFF2A, 0x800118F2), (0xFF2B, 0x800118F3), (0xFF2C, 0x800118F4), (0xFF2D, 0x800118F5),
+    (0xFF2E, 0x800118F6), (0xFF2F, 0x800118F7), (0xFF30, 0x800118F8), (0xFF31, 0x800118F9),
+    (0xFF32, 0x800118FA), (0xFF33, 0x800118FB), (0xFF34, 0x800118FC), (0xFF35, 0x800118FD),
+    (0xFF36, 0x800118FE), (0xFF37, 0x800118FF), (0xFF38, 0x80011900), (0xFF39, 0x80011901),
+    (0xFF3A, 0x80011902), (0xFF3B, 0x80011903), (0xFF3C, 0x80011904), (0xFF3D, 0x80011905),
+    (0xFF3E, 0x80011906), (0xFF3F, 0x80011907), (0xFF40, 0x80011908), (0xFF41, 0x80011909),
+    (0xFF42, 0x8001190A), (0xFF43, 0x8001190B), (0xFF44, 0x8001190C), (0xFF45, 0x8001190D),
+    (0xFF46, 0x8001190E), (0xFF47, 0x8001190F), (0xFF48, 0x80011910), (0xFF49, 0x80011911),
+    (0xFF4A, 0x80011912), (0xFF4B, 0x80011913), (0xFF4C, 0x80011914), (0xFF4D, 0x80011915),
+    (0xFF4E, 0x80011916), (0xFF4F, 0x80011917), (0xFF50, 0x80011918), (0xFF51, 0x80011919),
+    (0xFF52, 0x8001191A), (0xFF53, 0x8001191B), (0xFF54, 0x8001191C), (0xFF55, 0x8001191D),
+    (0xFF56, 0x8001191E), (0xFF57, 0x8001191F), (0xFF58, 0x80011920), (0xFF59, 0x80011921),
+    (0xFF5A, 0x80011922), (0xFF5B, 0x80011923), (0xFF5C, 0x80011924), (0xFF5D, 0x80011925),
+    (0xFF5E, 0x80011926), (0xFF5F, 0x80011927), (0xFF60, 0x80011928), (0xFF61, 0x80011929),
+    (0xFF62, 0x8001192A), (0xFF63, 0x8001192B), (0xFF64, 0x8001192C), (0xFF65, 0x8001192D),
+    (0xFF66, 0x8001192E), (0xFF67, 0x8001192F), (0xFF68, 0x80011930), (0xFF69, 0x80011931),
+    (0xFF6A, 0x80011932), (0xFF6B, 0x80011933), (0xFF6C, 0x80011934), (0xFF6D, 0x80011935),
+    (0xFF6E, 0x80011936), (0xFF6F, 0x80011937), (0xFF70, 0x80011938), (0xFF71, 0x80011939),
+    (0xFF72, 0x8001193A), (0xFF73, 0x8001193B), (0xFF74, 0x8001193C), (0xFF75, 0x8001193D),
+    (0xFF76, 0x8001193E), (0xFF77, 0x8001193F), (0xFF78, 0x80011940), (0xFF79, 0x80011941),
+    (0xFF7A, 0x80011942), (0xFF7B, 0x80011943), (0xFF7C, 0x80011944), (0xFF7D, 0x80011945),
+    (0xFF7E, 0x80011946), (0xFF7F, 0x80011947), (0xFF80, 0x80011948), (0xFF81, 0x80011949),
+    (0xFF82, 0x8001194A), (0xFF83, 0x8001194B), (0xFF84, 0x8001194C), (0xFF85, 0x8001194D),
+    (0xFF86, 0x8001194E), (0xFF87, 0x8001194F), (0xFF88, 0x80011950), (0xFF89, 0x80011951),
+    (0xFF8A, 0x80011952), (0xFF8B, 0x80011953), (0xFF8C, 0x80011954), (0xFF8D, 0x80011955),
+    (0xFF8E, 0x80011956), (0xFF8F, 0x80011957), (0xFF90, 0x80011958), (0xFF91, 0x80011959),
+    (0xFF92, 0x8001195A), (0xFF93, 0x8001195B), (0xFF94, 0x8001195C), (0xFF95, 0x8001195D),
+    (0xFF96, 0x8001195E), (0xFF97, 0x8001195F), (0xFF98, 0x80011960), (0xFF99, 0x80011961),
+    (0xFF9A, 0x80011962), (0xFF9B, 0x80011963), (0xFF9C, 0x80011964), (0xFF9D, 0x80011965),
+    (0xFF9E, 0x80011966), (0xFF9F, 0x80011967), (0xFFA0, 0x80011968), (0xFFA1, 0x80011969),
+    (0xFFA2, 0x8001196A), (0xFFA3, 0x8001196B), (0xFFA4, 0x8001196C), (0xFFA5, 0x8001196D),
+    (0xFFA6, 0x8001196E), (0xFFA7, 0x8001196F), (0xFFA8, 0x80011970), (0xFFA9, 0x80011971),
+    (0xFFAA, 0x80011972), (0xFFAB, 0x80011973), (0xFFAC, 0x80011974), (0xFFAD, 0x80011975),
+    (0xFFAE, 0x80011976), (0xFFAF, 0x80011977), (0xFFB0, 0x80011978), (0xFFB1, 0x80011979),
+    (0xFFB2, 0x8001197A), (0xFFB3, 0x8001197B), (0xFFB4, 0x8001197C), (0xFFB5, 0x8001197D),
+    (0xFFB6, 0x8001197E), (0xFFB7, 0x8001197F), (0xFFB8, 0x80011980), (0xFFB9, 0x80011981),
+    (0xFFBA, 0x80011982), (0xFFBB, 0x80011983), (0xFFBC, 0x80011984), (0xFFBD, 0x80011985),
+    (0xFFBE, 0x80011986), (0xFFC2, 0x80011987), (0xFFC3, 0x80011988), (0xFFC4, 0x80011989),
+    (0xFFC5, 0x8001198A), (0xFFC6, 0x8001198B), (0xFFC7, 0x8001198C), (0xFFCA, 0x8001198D),
+    (0xFFCB, 0x8001198E), (0xFFCC, 0x8001198F), (0xFFCD, 0x80011990), (0xFFCE, 0x80011991),
+    (0xFFCF, 0x80011992), (0xFFD2, 0x80011993), (0xFFD3, 0x80011994), (0xFFD4, 0x80011995),
+    (0xFFD5, 0x80011996), (0xFFD6, 0x80011997), (0xFFD7, 0x80011998), (0xFFDA, 0x80011999),
+    (0xFFDB, 0x8001199A), (0xFFDC, 0x8001199B), (0xFFE0, 0x8001199C), (0xFFE1, 0x8001199D),
+    (0xFFE2, 0x8001199E), (0xFFE3, 0x8001199F), (0xFFE4, 0x800119A0), (0xFFE5, 0x800119A1),
+    (0xFFE6, 0x800119A2), (0xFFE8, 0x800119A3), (0xFFE9, 0x800119A4), (0xFFEA, 0x800119A5),
+    (0xFFEB, 0x800119A6), (0xFFEC, 0x800119A7), (0xFFED, 0x800119A8), (0xFFEE, 0x800119A9),
+    (0x10781, 0x800119AA), (0x10782, 0x800119AB), (0x10783, 0x800119AC), (0x10784, 0x800119AD),
+    (0x10785, 0x800119AE), (0x10787, 0x800119AF), (0x10788, 0x800119B0), (0x10789, 0x800119B1),
+    (0x1078A, 0x800119B2), (0x1078B, 0x800119B3), (0x1078C, 0x800119B4), (0x1078D, 0x800119B5),
+    (0x1078E, 0x800119B6), (0x1078F, 0x800119B7), (0x10790, 0x800119B8), (0x10791, 0x800119B9),
+    (0x10792, 0x800119BA), (0x10793, 0x800119BB), (0x10794, 0x800119BC), (0x10795, 0x800119BD),
+    (0x10796, 0x800119BE), (0x10797, 0x800119BF), (0x10798, 0x800119C0), (0x10799, 0x800119C1),
+    (0x1079A, 0x800119C2), (0x1079B, 0x800119C3), (0x1079C, 0x800119C4), (0x1079D, 0x800119C5),
+    (0x1079E, 0x800119C6), (0x1079F, 0x800119C7), (0x107A0, 0x800119C8), (0x107A1, 0x800119C9),
+    (0x107A2, 0x800119CA), (0x107A3, 0x800119CB), (0x107A4, 0x800119CC), (0x107A5, 0x800119CD),
+    (0x107A6, 0x800119CE), (0x107A7, 0x800119CF), (0x107A8, 0x800119D0), (0x107A9, 0x800119D1),
+    (0x107AA, 0x800119D2), (0x107AB, 0x800119D3), (0x107AC, 0x800119D4), (0x107AD, 0x800119D5),
+    (0x107AE, 0x800119D6), (0x107AF, 0x800119D7), (0x107B0, 0x800119D8), (0x107B2, 0x800119D9),
+    (0x107B3, 0x800119DA), (0x107B4, 0x800119DB), (0x107B5, 0x800119DC), (0x107B6, 0x800119DD),
+    (0x107B7, 0x800119DE), (0x107B8, 0x800119DF), (0x107B9, 0x800119E0), (0x107BA, 0x800119E1),
+    (0x1109A, 0x219E2), (0x1109C, 0x219E4), (0x110AB, 0x219E6), (0x1112E, 0x219E8),
+    (0x1112F, 0x219EA), (0x1134B, 0x219EC), (0x1134C, 0x219EE), (0x114BB, 0x219F0),
+    (0x114BC, 0x219F2), (0x114BE, 0x219F4), (0x115BA, 0x219F6), (0x115BB, 0x219F8),
+    (0x11938, 0x219FA), (0x1D15E, 0x219FC), (0x1D15F, 0x219FE), (0x1D160, 0x21A00),
+    (0x1D161, 0x21A02), (0x1D162, 0x21A04), (0x1D163, 0x21A06), (0x1D164, 0x21A08),
+    (0x1D1BB, 0x21A0A), (0x1D1BC, 0x21A0C), (0x1D1BD, 0x21A0E), (0x1D1BE, 0x21A10),
+    (0x1D1BF, 0x21A12), (0x1D1C0, 0x21A14), (0x1D400, 0x80011A16), (0x1D401, 0x80011A17),
+    (0x1D402, 0x80011A18), (0x1D403, 0x80011A19), (0x1D404, 0x80011A1A), (0x1D405, 0x80011A1B),
+    (0x1D406, 0x80011A1C), (0x1D407, 0x80011A1D), (0x1D408, 0x80011A1E), (0x1D409, 0x80011A1F),
+    (0x1D40A, 0x80011A20), (0x1D40B, 0x80011A21), (0x1D40C, 0x80011A22), (0x1D40D, 0x80011A23),
+    (0x1D40E, 0x80011A24), (0x1D40F, 0x80011A25), (0x1D410, 0x80011A26), (0x1D411, 0x80011A27),
+    (0x1D412, 0x80011A28), (0x1D413, 0x80011A29), (0x1D414, 0x80011A2A), (0x1D415, 0x80011A2B),
+    (0x1D416, 0x80011A2C), (0x1D417, 0x80011A2D), (0x1D418, 0x80011A2E), (0x1D419, 0x80011A2F),
+    (0x1D41A, 0x80011A30), (0x1D41B, 0x80011A31), (0x1D41C, 0x80011A32), (0x1D41D, 0x80011A33),
+    (0x1D41E, 0x80011A34), (0x1D41F, 0x80011A35), (0x1D420, 0x80011A36), (0x1D421, 0x80011A37),
+    (0x1D422, 0x80011A38), (0x1D423, 0x80011A39), (0x1D424, 0x80011A3A), (0x1D425, 0x80011A3B),
+    (0x1D426, 0x80011A3C), (0x1D427, 0x80011A3D), (0x1D428, 0x80011A3E), (0x1D429, 0x80011A3F),
+    (0x1D42A, 0x80011A40), (0x1D42B, 0x80011A41), (0x1D42C, 0x80011A42), (0x1D42D, 0x80011A43),
+    (0x1D42E, 0x80011A44), (0x1D42F, 0x80011A45), (0x1D430, 0x80011A46), (0x1D431, 0x80011A47),
+    (0x1D432, 0x80011A48), (0x1D433, 0x80011A49), (0x1D434, 0x80011A4A), (0x1D435, 0x80011A4B),
+    (0x1D436, 0x80011A4C), (0x1D437, 0x80011A4D), (0x1D438, 0x80011A4E), (0x1D439, 0x80011A4F),
+    (0x1D43A, 0x80011A50), (0x1D43B, 0x80011A51), (0x1D43C, 0x80011A52), (0x1D43D, 0x80011A53),
+    (0x1D43E, 0x80011A54), (0x1D43F, 0x80011A55), (0x1D440, 0x80011A56), (0x1D441, 0x80011A57),
+    (0x1D442, 0x80011A58), (0x1D443, 0x80011A59), (0x1D444, 0x80011A5A), (0x1D445, 0x80011A5B),
+    (0x1D446, 0x80011A5C), (0x1D447, 0x80011A5D), (0x1D448, 0x80011A5E), (0x1D449, 0x80011A5F),
+    (0x1D44A, 0x80011A60), (0x1D44B, 0x80011A61), (0x1D44C, 0x80011A62), (0x1D44D, 0x80011A63),
+    (0x1D44E, 0x80011A64), (0x1D44F, 0x80011A65), (0x1D450, 0x80011A66), (0x1D451, 0x80011A67),
+    (0x1D452, 0x80011A68), (0x1D453, 0x80011A69), (0x1D454, 0x80011A6A), (0x1D456, 0x80011A6B),
+    (0x1D457, 0x80011A6C), (0x1D458, 0x80011A6D), (0x1D459, 0x80011A6E), (0x1D45A, 0x80011A6F),
+    (0x1D45B, 0x80011A70), (0x1D45C, 0x80011A71), (0x1D45D, 0x80011A72), (0x1D45E, 0x80011A73),
+    (0x1D45F, 0x80011A74), (0x1D460, 0x80011A75), (0x1D461, 0x80011A76), (0x1D462, 0x80011A77),
+    (0x1D463, 0x80011A78), (0x1D464, 0x80011A79), (0x1D465, 0x80011A7A), (0x1D466, 0x80011A7B),
+    (0x1D467, 0x80011A7C), (0x1D468, 0x80011A7D), (0x1D469, 0x80011A7E), (0x1D46A, 0x80011A7F),
+    (0x1D46B, 0x80011A80), (0x1D46C, 0x80011A81), (0x1D46D, 0x80011A82), (0x1D46E, 0x80011A83),
+    (0x1D46F, 0x80011A84), (0x1D470, 0x80011A85), (0x1D471, 0x80011A86), (0x1D472, 0x80011A87),
+    (0x1D473, 0x80011A88), (0x1D474, 0x80011A89), (0x1D475, 0x80011A8A), (0x1D476, 0x80011A8B),
+    (0x1D477, 0x80011A8C), (0x1D478, 0x80011A8D), (0x1D479, 0x80011A8E), (0x1D47A, 0x80011A8F),
+    (0x1D47B, 0x80011A90), (0x1D47C, 0x80011A91), (0x1D47D, 0x80011A92), (0x1D47E, 0x80011A93),
+    (0x1D47F, 0x80011A94), (0x1D480, 0x80011A95), (0x1D481, 0x80011A96), (0x1D482, 0x80011A97),
+    (0x1D483, 0x80011A98), (0x1D484, 0x80011A99), (0x1D485, 0x80011A9A), (0x1D486, 0x80011A9B),
+    (0x1D487, 0x80011A9C), (0x1D488, 0x80011A9D), (0x1D489, 0x80011A9E), (0x1D48A, 0x80011A9F),
+    (0x1D48B, 0x80011AA0), (0x1D48C, 0x80011AA1), (0x1D48D, 0x80011AA2), (0x1D48E, 0x80011AA3),
+    (0x1D48F, 0x80011AA4), (0x1D490, 0x80011AA5), (0x1D491, 0x80011AA6), (0x1D492, 0x80011AA7),
+    (0x1D493, 0x80011AA8), (0x1D494, 0x80011AA9), (0x1D495, 0x80011AAA), (0x1D496, 0x80011AAB),
+    (0x1D497, 0x80011AAC), (0x1D498, 0x80011AAD), (0x1D499, 0x80011AAE), (0x1D49A, 0x80011AAF),
+    (0x1D49B, 0x80011AB0), (0x1D49C, 0x80011AB1), (0x1D49E, 0x80011AB2), (0x1D49F, 0x80011AB3),
+    (0x1D4A2, 0x80011AB4), (0x1D4A5, 0x80011AB5), (0x1D4A6, 0x80011AB6), (0x1D4A9, 0x80011AB7),
+    (0x1D4AA, 0x80011AB8), (0x1D4AB, 0x80011AB9), (0x1D4AC, 0x80011ABA), (0x1D4AE, 0x80011ABB),
+    (0x1D4AF, 0x80011ABC), (0x1D4B0, 0x80011ABD), (0x1D4B1, 0x80011ABE), (0x1D4B2, 0x80011ABF),
+    (0x1D4B3, 0x80011AC0), (0x1D4B4, 0x80011AC1), (0x1D4B5, 0x80011AC2), (0x1D4B6, 0x80011AC3),
+    (0x1D4B7, 0x80011AC4), (0x1D4B8, 0x80011AC5), (0x1D4B9, 0x80011AC6), (0x1D4BB, 0x80011AC7),
+    (0x1D4BD, 0x80011AC8), (0x1D4BE, 0x80011AC9), (0x1D4BF, 0x80011ACA), (0x1D4C0, 0x80011ACB),
+    (0x1D4C1, 0x80011ACC), (0x1D4C2, 0x80011ACD), (0x1D4C3, 0x80011ACE), (0x1D4C5, 0x80011ACF),
+    (0x1D4C6, 0x80011AD0), (0x1D4C7, 0x80011AD1), (0x1D4C8, 0x80011AD2), (0x1D4C9, 0x80011AD3),
+    (0x1D4CA, 0x80011AD4), (0x1D4CB, 0x80011AD5), (0x1D4CC, 0x80011AD6), (0x1D4CD, 0x80011AD7),
+    (0x1D4CE, 0x80011AD8), (0x1D4CF, 0x80011AD9), (0x1D4D0, 0x80011ADA), (0x1D4D1, 0x80011ADB),
+    (0x1D4D2, 0x80011ADC), (0x1D4D3, 0x80011ADD), (0x1D4D4, 0x80011ADE), (0x1D4D5, 0x80011ADF),
+    (0x1D4D6, 0x80011AE0), (0x1D4D7, 0x80011AE1), (0x1D4D8, 0x80011AE2), (0x1D4D9, 0x80011AE3),
+    (0x1D4DA, 0x80011AE4), (0x1D4DB, 0x80011AE5), (0x1D4DC, 0x80011AE6), (0x1D4DD, 0x80011AE7),
+    (0x1D4DE, 0x80011AE8), (0x1D4DF, 0x80011AE9), (0x1D4E0, 0x80011AEA), (0x1D4E1, 0x80011AEB),
+    (0x1D4E2, 0x80011AEC), (0x1D4E3, 0x80011AED), (0x1D4E4, 0x80011AEE), (0x1D4E5, 0x80011AEF),
+    (0x1D4E6, 0x80011AF0), (0x1D4E7, 0x80011AF1), (0x1D4E8, 0x80011AF2), (0x1D4E9, 0x80011AF3),
+    (0x1D4EA, 0x80011AF4), (0x1D4EB, 0x80011AF5), (0x1D4EC, 0x80011AF6), (0x1D4ED, 0x80011AF7),
+    (0x1D4EE, 0x80011AF8), (0x1D4EF, 0x80011AF9), (0x1D4F0, 0x80011AFA), (0x1D4F1, 0x80011AFB),
+    (0x1D4F2, 0x80011AFC), (0x1D4F3, 0x80011AFD), (0x1D4F4, 0x80011AFE), (0x1D4F5, 0x80011AFF),
+    (0x1D4F6, 0x80011B00), (0x1D4F7, 0x80011B01), (0x1D4F8, 0x80011B02), (0x1D4F9, 0x80011B03),
+    (0x1D4FA, 0x80011B04), (0x1D4FB, 0x80011B05), (0x1D4FC, 0x80011B06), (0x1D4FD, 0x80011B07),
+    (0x1D4FE, 0x80011B08), (0x1D4FF, 0x80011B09), (0x1D500, 0x80011B0A), (0x1D501, 0x80011B0B),
+    (0x1D502, 0x80011B0C), (0x1D503, 0x80011B0D), (0x1D504, 0x80011B0E), (0x1D505, 0x80011B0F),
+    (0x1D507, 0x80011B10), (0x1D508, 0x80011B11), (0x1D509, 0x80011B12), (0x1D50A, 0x80011B13),
+    (0x1D50D, 0x80011B14), (0x1D50E, 0x80011B15), (0x1D50F, 0x80011B16), (0x1D510, 0x80011B17),
+    (0x1D511, 0x80011B18), (0x1D512, 0x80011B19), (0x1D513, 0x80011B1A), (0x1D514, 0x80011B1B),
+    (0x1D516, 0x80011B1C), (0x1D517, 0x80011B1D), (0x1D518, 0x80011B1E), (0x1D519, 0x80011B1F),
+    (0x1D51A, 0x80011B20), (0x1D51B, 0x80011B21), (0x1D51C, 0x80011B22), (0x1D51E, 0x80011B23),
+    (0x1D51F, 0x80011B24), (0x1D520, 0x80011B25), (0x1D521, 0x80011B26), (0x1D522, 0x80011B27),
+    (0x1D523, 0x80011B28), (0x1D524, 0x80011B29), (0x1D525, 0x80011B2A), (0x1D526, 0x80011B2B),
+    (0x1D527, 0x80011B2C), (0x1D528, 0x80011B2D), (0x1D529, 0x80011B2E), (0x1D52A, 0x80011B2F),
+    (0x1D52B, 0x80011B30), (0x1D52C, 0x80011B31), (0x1D52D, 0x80011B32), (0x1D52E, 0x80011B33),
+    (0x1D52F, 0x80011B34), (0x1D530, 0x80011B35), (0x1D531, 0x80011B36), (0x1D532, 0x80011B37),
+    (0x1D533, 0x80011B38), (0x1D534, 0x80011B39), (0x1D535, 0x80011B3A), (0x1D536, 0x80011B3B),
+    (0x1D537, 0x80011B3C), (0x1D538, 0x80011B3D), (0x1D539, 0x80011B3E), (0x1D53B, 0x80011B3F),
+    (0x1D53C, 0x80011B40), (0x1D53D, 0x80011B41), (0x1D53E, 0x80011B42), (0x1D540, 0x80011B43),
+    (0x1D541, 0x80011B44), (0x1D542, 0x80011B45), (0x1D543, 0x80011B46), (0x1D544, 0x80011B47),
+    (0x1D546, 0x80011B48), (0x1D54A, 0x80011B49), (0x1D54B, 0x80011B4A), (0x1D54C, 0x80011B4B),
+    (0x1D54D, 0x80011B4C), (0x1D54E, 0x80011B4D), (0x1D54F, 0x80011B4E), (0x1D550, 0x80011B4F),
+    (0x1D552, 0x80011B50), (0x1D553, 0x80011B51), (0x1D554, 0x80011B52), (0x1D555, 0x80011B53),
+    (0x1D556, 0x80011B54), (0x1D557, 0x80011B55), (0x1D558, 0x80011B56), (0x1D559, 0x80011B57),
+    (0x1D55A, 0x80011B58), (0x1D55B, 0x80011B59), (0x1D55C, 0x80011B5A), (0x1D55D, 0x80011B5B),
+    (0x1D55E, 0x80011B5C), (0x1D55F, 0x80011B5D), (0x1D560, 0x80011B5E), (0x1D561, 0x80011B5F),
+    (0x1D562, 0x80011B60), (0x1D563, 0x80011B61), (0x1D564, 0x80011B62), (0x1D565, 0x80011B63),
+    (0x1D566, 0x80011B64), (0x1D567, 0x80011B65), (0x1D568, 0x80011B66), (0x1D569, 0x80011B67),
+    (0x1D56A, 0x80011B68), (0x1D56B, 0x80011B69), (0x1D56C, 0x80011B6A), (0x1D56D, 0x80011B6B),
+    (0x1D56E, 0x80011B6C), (0x1D56F, 0x80011B6D), (0x1D570, 0x80011B6E), (0x1D571, 0x80011B6F),
+    (0x1D572, 0x80011B70), (0x1D573, 0x80011B71), (0x1D574, 0x80011B72), (0x1D575, 0x80011B73),
+    (0x1D576, 0x80011B74), (0x1D577, 0x80011B75), (0x1D578, 0x80011B76), (0x1D579, 0x80011B77),
+    (0x1D57A, 0x80011B78), (0x1D57B, 0x80011B79), (0x1D57C, 0x80011B7A), (0x1D57D, 0x80011B7B),
+    (0x1D57E, 0x80011B7C), (0x1D57F, 0x80011B7D), (0x1D580, 0x80011B7E), (0x1D581, 0x80011B7F),
+    (0x1D582, 0x80011B80), (0x1D583, 0x80011B81), (0x1D584, 0x80011B82), (0x1D585, 0x80011B83),
+    (0x1D586, 0x80011B84), (0x1D587, 0x80011B85), (0x1D588, 0x80011B86), (0x1D589, 0x80011B87),
+    (0x1D58A, 0x80011B88), (0x1D58B, 0x80011B89), (0x1D58C, 0x80011B8A), (0x1D58D, 0x80011B8B),
+    (0x1D58E, 0x80011B8C), (0x1D58F, 0x80011B8D), (0x1D590, 0x80011B8E), (0x1D591, 0x80011B8F),
+    (0x1D592, 0x80011B90), (0x1D593, 0x80011B91), (0x1D594, 0x80011B92), (0x1D595, 0x80011B93),
+    (0x1D596, 0x80011B94), (0x1D597, 0x80011B95), (0x1D598, 0x80011B96), (0x1D599, 0x80011B97),
+    (0x1D59A, 0x80011B98), (0x1D59B, 0x80011B99), (0x1D59C, 0x80011B9A), (0x1D59D, 0x80011B9B),
+    (0x1D59E, 0x80011B9C), (0x1D59F, 0x80011B9D), (0x1D5A0, 0x80011B9E), (0x1D5A1, 0x80011B9F),
+    (0x1D5A2, 0x80011BA0), (0x1D5A3, 0x80011BA1), (0x1D5A4, 0x80011BA2), (0x1D5A5, 0x80011BA3),
+    (0x1D5A6, 0x80011BA4), (0x1D5A7, 0x80011BA5), (0x1D5A8, 0x80011BA6), (0x1D5A9, 0x80011BA7),
+    (0x1D5AA, 0x80011BA8), (0x1D5AB, 0x80011BA9), (0x1D5AC, 0x80011BAA), (0x1D5AD, 0x80011BAB),
+    (0x1D5AE, 0x80011BAC), (0x1D5AF, 0x80011BAD), (0x1D5B0, 0x80011BAE), (0x1D5B1, 0x80011BAF),
+    (0x1D5B2, 0x80011BB0), (0x1D5B3, 0x80011BB1), (0x1D5B4, 0x80011BB2), (0x1D5B5, 0x80011BB3),
+    (0x1D5B6, 0x80011BB4), (0x1D5B7, 0x80011BB5), (0x1D5B8, 0x80011BB6), (0x1D5B9, 0x80011BB7),
+    (0x1D5BA, 0x80011BB8), (0x1D5BB, 0x80011BB9), (0x1D5BC, 0x80011BBA), (0x1D5BD, 0x80011BBB),
+    (0x1D5BE, 0x80011BBC), (0x1D5BF, 0x80011BBD), (0x1D5C0, 0x80011BBE), (0x1D5C1, 0x80011BBF),
+    (0x1D5C2, 0x80011BC0), (0x1D5C3, 0x80011BC1), (0x1D5C4, 0x80011BC2), (0x1D5C5, 0x80011BC3),
+    (0x1D5C6, 0x80011BC4), (0x1D5C7, 0x80011BC5), (0x1D5C8, 0x80011BC6), (0x1D5C9, 0x80011BC7),
+    (0x1D5CA, 0x80011BC8), (0x1D5CB, 0x80011BC9), (0x1D5CC, 0x80011BCA), (0x1D5CD, 0x80011BCB),
+    (0x1D5CE, 0x80011BCC), (0x1D5CF, 0x80011BCD), (0x1D5D0, 0x80011BCE), (0x1D5D1, 0x80011BCF),
+    (0x1D5D2, 0x80011BD0), (0x1D5D3, 0x80011BD1), (0x1D5D4, 0x80011BD2), (0x1D5D5, 0x80011BD3),
+    (0x1D5D6, 0x80011BD4), (0x1D5D7, 0x80011BD5), (0x1D5D8, 0x80011BD6), (0x1D5D9, 0x80011BD7),
+    (0x1D5DA, 0x80011BD8), (0x1D5DB, 0x80011BD9), (0x1D5DC, 0x80011BDA), (0x1D5DD, 0x80011BDB),
+    (0x1D5DE, 0x80011BDC), (0x1D5DF, 0x80011BDD), (0x1D5E0, 0x80011BDE), (0x1D5E1, 0x80011BDF),
+    (0x1D5E2, 0x80011BE0), (0x1D5E3, 0x80011BE1), (0x1D5E4, 0x80011BE2), (0x1D5E5, 0x80011BE3),
+    (0x1D5E6, 0x80011BE4), (0x1D5E7, 0x80011BE5), (0x1D5E8, 0x80011BE6), (0x1D5E9, 0x80011BE7),
+    (0x1D5EA, 0x80011BE8), (0x1D5EB, 0x80011BE9), (0x1D5EC, 0x80011BEA), (0x1D5ED, 0x80011BEB),
+    (0x1D5EE, 0x80011BEC), (0x1D5EF, 0x80011BED), (0x1D5F0, 0x80011BEE), (0x1D5F1, 0x80011BEF),
+    (0x1D5F2, 0x80011BF0), (0x1D5F3, 0x80011BF1), (0x1D5F4, 0x80011BF2), (0x1D5F5, 0x80011BF3),
+    (0x1D5F6, 0x80011BF4), (0x1D5F7, 0x80011BF5), (0x1D5F8, 0x80011BF6), (0x1D5F9, 0x80011BF7),
+    (0x1D5FA, 0x80011BF8), (0x1D5FB, 0x80011BF9), (0x1D5FC, 0x80011BFA), (0x1D5FD, 0x80011BFB),
+    (0x1D5FE, 0x80011BFC), (0x1D5FF, 0x80011BFD), (0x1D600, 0x80011BFE), (0x1D601, 0x80011BFF),
+    (0x1D602, 0x80011C00), (0x1D603, 0x80011C01), (0x1D604, 0x80011C02), (0x1D605, 0x80011C03),
+    (0x1D606, 0x80011C04), (0x1D607, 0x80011C05), (0x1D608, 0x80011C06), (0x1D609, 0x80011C07),
+    (0x1D60A, 0x80011C08), (0x1D60B, 0x80011C09), (0x1D60C, 0x80011C0A), (0x1D60D, 0x80011C0B),
+    (0x1D60E, 0x80011C0C), (0x1D60F, 0x80011C0D), (0x1D610, 0x80011C0E), (0x1D611, 0x80011C0F),
+    (0x1D612, 0x80011C10), (0x1D613, 0x80011C11), (0x1D614, 0x80011C12), (0x1D615, 0x80011C13),
+    (0x1D616, 0x80011C14), (0x1D617, 0x80011C15), (0x1D618, 0x80011C16), (0x1D619, 0x80011C17),
+    (0x1D61A, 0x80011C18), (0x1D61B, 0x80011C19), (0x1D61C, 0x80011C1A), (0x1D61D, 0x80011C1B),
+    (0x1D61E, 0x80011C1C), (0x1D61F, 0x80011C1D), (0x1D620, 0x80011C1E), (0x1D621, 0x80011C1F),
+    (0x1D622, 0x80011C20), (0x1D623, 0x80011C21), (0x1D624, 0x80011C22), (0x1D625, 0x80011C23),
+    (0x1D626, 0x80011C24), (0x1D627, 0x80011C25), (0x1D628, 0x80011C26), (0x1D629, 0x80011C27),
+    (0x1D62A, 0x80011C28), (0x1D62B, 0x80011C29), (0x1D62C, 0x80011C2A), (0x1D62D, 0x80011C2B),
+    (0x1D62E, 0x80011C2C), (0x1D62F, 0x80011C2D), (0x1D630, 0x80011C2E), (0x1D631, 0x80011C2F),
+    (0x1D632, 0x80011C30), (0x1D633, 0x80011C31), (0x1D634, 0x80011C32), (0x1D635, 0x80011C33),
+    (0x1D636, 0x80011C34), (0x1D637, 0x80011C35), (0x1D638, 0x80011C36), (0x1D639, 0x80011C37),
+    (0x1D63A, 0x80011C38), (0x1D63B, 0x80011C39), (0x1D63C, 0x80011C3A), (0x1D63D, 0x80011C3B),
+    (0x1D63E, 0x80011C3C), (0x1D63F, 0x80011C3D), (0x1D640, 0x80011C3E), (0x1D641, 0x80011C3F),
+    (0x1D642, 0x80011C40), (0x1D643, 0x80011C41), (0x1D644, 0x80011C42), (0x1D645, 0x80011C43),
+    (0x1D646, 0x80011C44), (0x1D647, 0x80011C45), (0x1D648, 0x80011C46), (0x1D649, 0x80011C47),
+    (0x1D64A, 0x80011C48), (0x1D64B, 0x80011C49), (0x1D64C, 0x80011C4A), (0x1D64D, 0x80011C4B),
+    (0x1D64E, 0x80011C4C), (0x1D64F, 0x80011C4D), (0x1D650, 0x80011C4E), (0x1D651, 0x80011C4F),
+    (0x1D652, 0x80011C50), (0x1D653, 0x80011C51), (0x1D654, 0x80011C52), (0x1D655, 0x80011C53),
+    (0x1D656, 0x80011C54), (0x1D657, 0x80011C55), (0x1D658, 0x80011C56), (0x1D659, 0x80011C57),
+    (0x1D65A, 0x80011C58), (0x1D65B, 0x80011C59), (0x1D65C, 0x80011C5A), (0x1D65D, 0x80011C5B),
+    (0x1D65E, 0x80011C5C), (0x1D65F, 0x80011C5D), (0x1D660, 0x80011C5E), (0x1D661, 0x80011C5F),
+    (0x1D662, 0x80011C60), (0x1D663, 0x80011C61), (0x1D664, 0x80011C62), (0x1D665, 0x80011C63),
+    (0x1D666, 0x80011C64), (0x1D667, 0x80011C65), (0x1D668, 0x80011C66), (0x1D669, 0x80011C67),
+    (0x1D66A, 0x80011C68), (0x1D66B, 0x80011C69), (0x1D66C, 0x80011C6A), (0x1D66D, 0x80011C6B),
+    (0x1D66E, 0x80011C6C), (0x1D66F, 0x80011C6D), (0x1D670, 0x80011C6E), (0x1D671, 0x80011C6F),
+    (0x1D672, 0x80011C70), (0x1D673, 0x80011C71), (0x1D674, 0x80011C72), (0x1D675, 0x80011C73),
+    (0x1D676, 0x80011C74), (0x1D677, 0x80011C75), (0x1D678, 0x80011C76), (0x1D679, 0x80011C77),
+    (0x1D67A, 0x80011C78), (0x1D67B, 0x80011C79), (0x1D67C, 0x80011C7A), (0x1D67D, 0x80011C7B),
+    (0x1D67E, 0x80011C7C), (0x1D67F, 0x80011C7D), (0x1D680, 0x80011C7E), (0x1D681, 0x80011C7F),
+    (0x1D682, 0x80011C80), (0x1D683, 0x80011C81), (0x1D684, 0x80011C82), (0x1D685, 0x80011C83),
+    (0x1D686, 0x80011C84), (0x1D687, 0x80011C85), (0x1D688, 0x80011C86), (0x1D689, 0x80011C87),
+    (0x1D68A, 0x80011C88), (0x1D68B, 0x80011C89), (0x1D68C, 0x80011C8A), (0x1D68D, 0x80011C8B),
+    (0x1D68E, 0x80011C8C), (0x1D68F, 0x80011C8D), (0x1D690, 0x80011C8E), (0x1D691, 0x80011C8F),
+    (0x1D692, 0x80011C90), (0x1D693, 0x80011C91), (0x1D694, 0x80011C92), (0x1D695, 0x80011C93),
+    (0x1D696, 0x80011C94), (0x1D697, 0x80011C95), (0x1D698, 0x80011C96), (0x1D699, 0x80011C97),
+    (0x1D69A, 0x80011C98), (0x1D69B, 0x80011C99), (0x1D69C, 0x80011C9A), (0x1D69D, 0x80011C9B),
+    (0x1D69E, 0x80011C9C), (0x1D69F, 0x80011C9D), (0x1D6A0, 0x80011C9E), (0x1D6A1, 0x80011C9F),
+    (0x1D6A2, 0x80011CA0), (0x1D6A3, 0x80011CA1), (0x1D6A4, 0x80011CA2), (0x1D6A5, 0x80011CA3),
+    (0x1D6A8, 0x80011CA4), (0x1D6A9, 0x80011CA5), (0x1D6AA, 0x80011CA6), (0x1D6AB, 0x80011CA7),
+    (0x1D6AC, 0x80011CA8), (0x1D6AD, 0x80011CA9), (0x1D6AE, 0x80011CAA), (0x1D6AF, 0x80011CAB),
+    (0x1D6B0, 0x80011CAC), (0x1D6B1, 0x80011CAD), (0x1D6B2, 0x80011CAE), (0x1D6B3, 0x80011CAF),
+    (0x1D6B4, 0x80011CB0), (0x1D6B5, 0x80011CB1), (0x1D6B6, 0x80011CB2), (0x1D6B7, 0x80011CB3),
+    (0x1D6B8, 0x80011CB4), (0x1D6B9, 0x80011CB5), (0x1D6BA, 0x80011CB6), (0x1D6BB, 0x80011CB7),
+    (0x1D6BC, 0x80011CB8), (0x1D6BD, 0x80011CB9), (0x1D6BE, 0x80011CBA), (0x1D6BF, 0x80011CBB),
+    (0x1D6C0, 0x80011CBC), (0x1D6C1, 0x80011CBD), (0x1D6C2, 0x80011CBE), (0x1D6C3, 0x80011CBF),
+    (0x1D6C4, 0x80011CC0), (0x1D6C5, 0x80011CC1), (0x1D6C6, 0x80011CC2), (0x1D6C7, 0x80011CC3),
+    (0x1D6C8, 0x80011CC4), (0x1D6C9, 0x80011CC5), (0x1D6CA, 0x80011CC6), (0x1D6CB, 0x80011CC7),
+    (0x1D6CC, 0x80011CC8), (0x1D6CD, 0x80011CC9), (0x1D6CE, 0x80011CCA), (0x1D6CF, 0x80011CCB),
+    (0x1D6D0, 0x80011CCC), (0x1D6D1, 0x80011CCD), (0x1D6D2, 0x80011CCE), (0x1D6D3, 0x80011CCF),
+    (0x1D6D4, 0x80011CD0), (0x1D6D5, 0x80011CD1), (0x1D6D6, 0x80011CD2), (0x1D6D7, 0x80011CD3),
+    (0x1D6D8, 0x80011CD4), (0x1D6D9, 0x80011CD5), (0x1D6DA, 0x80011CD6), (0x1D6DB, 0x80011CD7),
+    (0x1D6DC, 0x80011CD8), (0x1D6DD, 0x80011CD9), (0x1D6DE, 0x80011CDA), (0x1D6DF, 0x80011CDB),
+    (0x1D6E0, 0x80011CDC), (0x1D6E1, 0x80011CDD), (0x1D6E2, 0x80011CDE), (0x1D6E3, 0x80011CDF),
+    (0x1D6E4, 0x80011CE0), (0x1D6E5, 0x80011CE1), (0x1D6E6, 0x80011CE2), (0x1D6E7, 0x80011CE3),
+    (0x1D6E8, 0x80011CE4), (0x1D6E9, 0x80011CE5), (0x1D6EA, 0x80011CE6), (0x1D6EB, 0x80011CE7),
+    (0x1D6EC, 0x80011CE8), (0x1D6ED, 0x80011CE9), (0x1D6EE, 0x80011CEA), (0x1D6EF, 0x80011CEB),
+    (0x1D6F0, 0x80011CEC), (0x1D6F1, 0x80011CED), (0x1D6F2, 0x80011CEE), (0x1D6F3, 0x80011CEF),
+    (0x1D6F4, 0x80011CF0), (0x1D6F5, 0x80011CF1), (0x1D6F6, 0x80011CF2), (0x1D6F7, 0x80011CF3),
+    (0x1D6F8, 0x80011CF4), (0x1D6F9, 0x80011CF5), (0x1D6FA, 0x80011CF6), (0x1D6FB, 0x80011CF7),
+    (0x1D6FC, 0x80011CF8), (0x1D6FD, 0x80011CF9), (0x1D6FE, 0x80011CFA), (0x1D6FF, 0x80011CFB),
+    (0x1D700, 0x80011CFC), (0x1D701, 0x80011CFD), (0x1D702, 0x80011CFE), (0x1D703, 0x80011CFF),
+    (0x1D704, 0x80011D00), (0x1D705, 0x80011D01), (0x1D706, 0x80011D02), (0x1D707, 0x80011D03),
+    (0x1D708, 0x80011D04), (0x1D709, 0x80011D05), (0x1D70A, 0x80011D06), (0x1D70B, 0x80011D07),
+    (0x1D70C, 0x80011D08), (0x1D70D, 0x80011D09), (0x1D70E, 0x80011D0A), (0x1D70F, 0x80011D0B),
+    (0x1D710, 0x80011D0C), (0x1D711, 0x80011D0D), (0x1D712, 0x80011D0E), (0x1D713, 0x80011D0F),
+    (0x1D714, 0x80011D10), (0x1D715, 0x80011D11), (0x1D716, 0x80011D12), (0x1D717, 0x80011D13),
+    (0x1D718, 0x80011D14), (0x1D719, 0x80011D15), (0x1D71A, 0x80011D16), (0x1D71B, 0x80011D17),
+    (0x1D71C, 0x80011D18), (0x1D71D, 0x80011D19), (0x1D71E, 0x80011D1A), (0x1D71F, 0x80011D1B),
+    (0x1D720, 0x80011D1C), (0x1D721, 0x80011D1D), (0x1D722, 0x80011D1E), (0x1D723, 0x80011D1F),
+    (0x1D724, 0x80011D20), (0x1D725, 0x80011D21), (0x1D726, 0x80011D22), (0x1D727, 0x80011D23),
+    (0x1D728, 0x80011D24), (0x1D729, 0x80011D25), (0x1D72A, 0x80011D26), (0x1D72B, 0x80011D27),
+    (0x1D72C, 0x80011D28), (0x1D72D, 0x80011D29), (0x1D72E, 0x80011D2A), (0x1D72F, 0x80011D2B),
+    (0x1D730, 0x80011D2C), (0x1D731, 0x80011D2D), (0x1D732, 0x80011D2E), (0x1D733, 0x80011D2F),
+    (0x1D734, 0x80011D30), (0x1D735, 0x80011D31), (0x1D736, 0x80011D32), (0x1D737, 0x80011D33),
+    (0x1D738, 0x80011D34), (0x1D739, 0x80011D35), (0x1D73A, 0x80011D36), (0x1D73B, 0x80011D37),
+    (0x1D73C, 0x80011D38), (0x1D73D, 0x80011D39), (0x1D73E, 0x80011D3A), (0x1D73F, 0x80011D3B),
+    (0x1D740, 0x80011D3C), (0x1D741, 0x80011D3D), (0x1D742, 0x80011D3E), (0x1D743, 0x80011D3F),
+    (0x1D744, 0x80011D40), (0x1D745, 0x80011D41), (0x1D746, 0x80011D42), (0x1D747, 0x80011D43),
+    (0x1D748, 0x80011D44), (0x1D749, 0x80011D45), (0x1D74A, 0x80011D46), (0x1D74B, 0x80011D47),
+    (0x1D74C, 0x80011D48), (0x1D74D, 0x80011D49), (0x1D74E, 0x80011D4A), (0x1D74F, 0x80011D4B),
+    (0x1D750, 0x80011D4C), (0x1D751, 0x80011D4D), (0x1D752, 0x80011D4E), (0x1D753, 0x80011D4F),
+    (0x1D754, 0x80011D50), (0x1D755, 0x80011D51), (0x1D756, 0x80011D52), (0x1D757, 0x80011D53),
+    (0x1D758, 0x80011D54), (0x1D759, 0x80011D55), (0x1D75A, 0x80011D56), (0x1D75B, 0x80011D57),
+    (0x1D75C, 0x80011D58), (0x1D75D, 0x80011D59), (0x1D75E, 0x80011D5A), (0x1D75F, 0x80011D5B),
+    (0x1D760, 0x80011D5C), (0x1D761, 0x80011D5D), (0x1D762, 0x80011D5E), (0x1D763, 0x80011D5F),
+    (0x1D764, 0x80011D60), (0x1D765, 0x80011D61), (0x1D766, 0x80011D62), (0x1D767, 0x80011D63),
+    (0x1D768, 0x80011D64), (0x1D769, 0x80011D65), (0x1D76A, 0x80011D66), (0x1D76B, 0x80011D67),
+    (0x1D76C, 0x80011D68), (0x1D76D, 0x80011D69), (0x1D76E, 0x80011D6A), (0x1D76F, 0x80011D6B),
+    (0x1D770, 0x80011D6C), (0x1D771, 0x80011D6D), (0x1D772, 0x80011D6E), (0x1D773, 0x80011D6F),
+    (0x1D774, 0x80011D70), (0x1D775, 0x80011D71), (0x1D776, 0x80011D72), (0x1D777, 0x80011D73),
+    (0x1D778, 0x80011D74), (0x1D779, 0x80011D75), (0x1D77A, 0x80011D76), (0x1D77B, 0x80011D77),
+    (0x1D77C, 0x80011D78), (0x1D77D, 0x80011D79), (0x1D77E, 0x80011D7A), (0x1D77F, 0x80011D7B),
+    (0x1D780, 0x80011D7C), (0x1D781, 0x80011D7D), (0x1D782, 0x80011D7E), (0x1D783, 0x80011D7F),
+    (0x1D784, 0x80011D80), (0x1D785, 0x80011D81), (0x1D786, 0x80011D82), (0x1D787, 0x80011D83),
+    (0x1D788, 0x80011D84), (0x1D789, 0x80011D85), (0x1D78A, 0x80011D86), (0x1D78B, 0x80011D87),
+    (0x1D78C, 0x80011D88), (0x1D78D, 0x80011D89), (0x1D78E, 0x80011D8A), (0x1D78F, 0x80011D8B),
+    (0x1D790, 0x80011D8C), (0x1D791, 0x80011D8D), (0x1D792, 0x80011D8E), (0x1D793, 0x80011D8F),
+    (0x1D794, 0x80011D90), (0x1D795, 0x80011D91), (0x1D796, 0x80011D92), (0x1D797, 0x80011D93),
+    (0x1D798, 0x80011D94), (0x1D799, 0x80011D95), (0x1D79A, 0x80011D96), (0x1D79B, 0x80011D97),
+    (0x1D79C, 0x80011D98), (0x1D79D, 0x80011D99), (0x1D79E, 0x80011D9A), (0x1D79F, 0x80011D9B),
+    (0x1D7A0, 0x80011D9C), (0x1D7A1, 0x80011D9D), (0x1D7A2, 0x80011D9E), (0x1D7A3, 0x80011D9F),
+    (0x1D7A4, 0x80011DA0), (0x1D7A5, 0x80011DA1), (0x1D7A6, 0x80011DA2), (0x1D7A7, 0x80011DA3),
+    (0x1D7A8, 0x80011DA4), (0x1D7A9, 0x80011DA5), (0x1D7AA, 0x80011DA6), (0x1D7AB, 0x80011DA7),
+    (0x1D7AC, 0x80011DA8), (0x1D7AD, 0x80011DA9), (0x1D7AE, 0x80011DAA), (0x1D7AF, 0x80011DAB),
+    (0x1D7B0, 0x80011DAC), (0x1D7B1, 0x80011DAD), (0x1D7B2, 0x80011DAE), (0x1D7B3, 0x80011DAF),
+    (0x1D7B4, 0x80011DB0), (0x1D7B5, 0x80011DB1), (0x1D7B6, 0x80011DB2), (0x1D7B7, 0x80011DB3),
+    (0x1D7B8, 0x80011DB4), (0x1D7B9, 0x80011DB5), (0x1D7BA, 0x80011DB6), (0x1D7BB, 0x80011DB7),
+    (0x1D7BC, 0x80011DB8), (0x1D7BD, 0x80011DB9), (0x1D7BE, 0x80011DBA), (0x1D7BF, 0x80011DBB),
+    (0x1D7C0, 0x80011DBC), (0x1D7C1, 0x80011DBD), (0x1D7C2, 0x80011DBE), (0x1D7C3, 0x80011DBF),
+    (0x1D7C4, 0x80011DC0), (0x1D7C5, 0x80011DC1), (0x1D7C6, 0x80011DC2), (0x1D7C7, 0x80011DC3),
+    (0x1D7C8, 0x80011DC4), (0x1D7C9, 0x80011DC5), (0x1D7CA, 0x80011DC6), (0x1D7CB, 0x80011DC7),
+    (0x1D7CE, 0x80011DC8), (0x1D7CF, 0x80011DC9), (0x1D7D0, 0x80011DCA), (0x1D7D1, 0x80011DCB),
+    (0x1D7D2, 0x80011DCC), (0x1D7D3, 0x80011DCD), (0x1D7D4, 0x80011DCE), (0x1D7D5, 0x80011DCF),
+    (0x1D7D6, 0x80011DD0), (0x1D7D7, 0x80011DD1), (0x1D7D8, 0x80011DD2), (0x1D7D9, 0x80011DD3),
+    (0x1D7DA, 0x80011DD4), (0x1D7DB, 0x80011DD5), (0x1D7DC, 0x80011DD6), (0x1D7DD, 0x80011DD7),
+    (0x1D7DE, 0x80011DD8), (0x1D7DF, 0x80011DD9), (0x1D7E0, 0x80011DDA), (0x1D7E1, 0x80011DDB),
+    (0x1D7E2, 0x80011DDC), (0x1D7E3, 0x80011DDD), (0x1D7E4, 0x80011DDE), (0x1D7E5, 0x80011DDF),
+    (0x1D7E6, 0x80011DE0), (0x1D7E7, 0x80011DE1), (0x1D7E8, 0x80011DE2), (0x1D7E9, 0x80011DE3),
+    (0x1D7EA, 0x80011DE4), (0x1D7EB, 0x80011DE5), (0x1D7EC, 0x80011DE6), (0x1D7ED, 0x80011DE7),
+    (0x1D7EE, 0x80011DE8), (0x1D7EF, 0x80011DE9), (0x1D7F0, 0x80011DEA), (0x1D7F1, 0x80011DEB),
+    (0x1D7F2, 0x80011DEC), (0x1D7F3, 0x80011DED), (0x1D7F4, 0x80011DEE), (0x1D7F5, 0x80011DEF),
+    (0x1D7F6, 0x80011DF0), (0x1D7F7, 0x80011DF1), (0x1D7F8, 0x80011DF2), (0x1D7F9, 0x80011DF3),
+    (0x1D7FA, 0x80011DF4), (0x1D7FB, 0x80011DF5), (0x1D7FC, 0x80011DF6), (0x1D7FD, 0x80011DF7),
+    (0x1D7FE, 0x80011DF8), (0x1D7FF, 0x80011DF9), (0x1EE00, 0x80011DFA), (0x1EE01, 0x80011DFB),
+    (0x1EE02, 0x80011DFC), (0x1EE03, 0x80011DFD), (0x1EE05, 0x80011DFE), (0x1EE06, 0x80011DFF),
+    (0x1EE07, 0x80011E00), (0x1EE08, 0x80011E01), (0x1EE09, 0x80011E02), (0x1EE0A, 0x80011E03),
+    (0x1EE0B, 0x80011E04), (0x1EE0C, 0x80011E05), (0x1EE0D, 0x80011E06), (0x1EE0E, 0x80011E07),
+    (0x1EE0F, 0x80011E08), (0x1EE10, 0x80011E09), (0x1EE11, 0x80011E0A), (0x1EE12, 0x80011E0B),
+    (0x1EE13, 0x80011E0C), (0x1EE14, 0x80011E0D), (0x1EE15, 0x80011E0E), (0x1EE16, 0x80011E0F),
+    (0x1EE17, 0x80011E10), (0x1EE18, 0x80011E11), (0x1EE19, 0x80011E12), (0x1EE1A, 0x80011E13),
+    (0x1EE1B, 0x80011E14), (0x1EE1C, 0x80011E15), (0x1EE1D, 0x80011E16), (0x1EE1E, 0x80011E17),
+    (0x1EE1F, 0x80011E18), (0x1EE21, 0x80011E19), (0x1EE22, 0x80011E1A), (0x1EE24, 0x80011E1B),
+    (0x1EE27, 0x80011E1C), (0x1EE29, 0x80011E1D), (0x1EE2A, 0x80011E1E), (0x1EE2B, 0x80011E1F),
+    (0x1EE2C, 0x80011E20), (0x1EE2D, 0x80011E21), (0x1EE2E, 0x80011E22), (0x1EE2F, 0x80011E23),
+    (0x1EE30, 0x80011E24), (0x1EE31, 0x80011E25), (0x1EE32, 0x80011E26), (0x1EE34, 0x80011E27),
+    (0x1EE35, 0x80011E28), (0x1EE36, 0x80011E29), (0x1EE37, 0x80011E2A), (0x1EE39, 0x80011E2B),
+    (0x1EE3B, 0x80011E2C), (0x1EE42, 0x80011E2D), (0x1EE47, 0x80011E2E), (0x1EE49, 0x80011E2F),
+    (0x1EE4B, 0x80011E30), (0x1EE4D, 0x80011E31), (0x1EE4E, 0x80011E32), (0x1EE4F, 0x80011E33),
+    (0x1EE51, 0x80011E34), (0x1EE52, 0x80011E35), (0x1EE54, 0x80011E36), (0x1EE57, 0x80011E37),
+    (0x1EE59, 0x80011E38), (0x1EE5B, 0x80011E39), (0x1EE5D, 0x80011E3A), (0x1EE5F, 0x80011E3B),
+    (0x1EE61, 0x80011E3C), (0x1EE62, 0x80011E3D), (0x1EE64, 0x80011E3E), (0x1EE67, 0x80011E3F),
+    (0x1EE68, 0x80011E40), (0x1EE69, 0x80011E41), (0x1EE6A, 0x80011E42), (0x1EE6C, 0x80011E43),
+    (0x1EE6D, 0x80011E44), (0x1EE6E, 0x80011E45), (0x1EE6F, 0x80011E46), (0x1EE70, 0x80011E47),
+    (0x1EE71, 0x80011E48), (0x1EE72, 0x80011E49), (0x1EE74, 0x80011E4A), (0x1EE75, 0x80011E4B),
+    (0x1EE76, 0x80011E4C), (0x1EE77, 0x80011E4D), (0x1EE79, 0x80011E4E), (0x1EE7A, 0x80011E4F),
+    (0x1EE7B, 0x80011E50), (0x1EE7C, 0x80011E51), (0x1EE7E, 0x80011E52), (0x1EE80, 0x80011E53),
+    (0x1EE81, 0x80011E54), (0x1EE82, 0x80011E55), (0x1EE83, 0x80011E56), (0x1EE84, 0x80011E57),
+    (0x1EE85, 0x80011E58), (0x1EE86, 0x80011E59), (0x1EE87, 0x80011E5A), (0x1EE88, 0x80011E5B),
+    (0x1EE89, 0x80011E5C), (0x1EE8B, 0x80011E5D), (0x1EE8C, 0x80011E5E), (0x1EE8D, 0x80011E5F),
+    (0x1EE8E, 0x80011E60), (0x1EE8F, 0x80011E61), (0x1EE90, 0x80011E62), (0x1EE91, 0x80011E63),
+    (0x1EE92, 0x80011E64), (0x1EE93, 0x80011E65), (0x1EE94, 0x80011E66), (0x1EE95, 0x80011E67),
+    (0x1EE96, 0x80011E68), (0x1EE97, 0x80011E69), (0x1EE98, 0x80011E6A), (0x1EE99, 0x80011E6B),
+    (0x1EE9A, 0x80011E6C), (0x1EE9B, 0x80011E6D), (0x1EEA1, 0x80011E6E), (0x1EEA2, 0x80011E6F),
+    (0x1EEA3, 0x80011E70), (0x1EEA5, 0x80011E71), (0x1EEA6, 0x80011E72), (0x1EEA7, 0x80011E73),
+    (0x1EEA8, 0x80011E74), (0x1EEA9, 0x80011E75), (0x1EEAB, 0x80011E76), (0x1EEAC, 0x80011E77),
+    (0x1EEAD, 0x80011E78), (0x1EEAE, 0x80011E79), (0x1EEAF, 0x80011E7A), (0x1EEB0, 0x80011E7B),
+    (0x1EEB1, 0x80011E7C), (0x1EEB2, 0x80011E7D), (0x1EEB3, 0x80011E7E), (0x1EEB4, 0x80011E7F),
+    (0x1EEB5, 0x80011E80), (0x1EEB6, 0x80011E81), (0x1EEB7, 0x80011E82), (0x1EEB8, 0x80011E83),
+    (0x1EEB9, 0x80011E84), (0x1EEBA, 0x80011E85), (0x1EEBB, 0x80011E86), (0x1F100, 0x80021E87),
+    (0x1F101, 0x80021E89), (0x1F102, 0x80021E8B), (0x1F103, 0x80021E8D), (0x1F104, 0x80021E8F),
+    (0x1F105, 0x80021E91), (0x1F106, 0x80021E93), (0x1F107, 0x80021E95), (0x1F108, 0x80021E97),
+    (0x1F109, 0x80021E99), (0x1F10A, 0x80021E9B), (0x1F110, 0x80031E9D), (0x1F111, 0x80031EA0),
+    (0x1F112, 0x80031EA3), (0x1F113, 0x80031EA6), (0x1F114, 0x80031EA9), (0x1F115, 0x80031EAC),
+    (0x1F116, 0x80031EAF), (0x1F117, 0x80031EB2), (0x1F118, 0x80031EB5), (0x1F119, 0x80031EB8),
+    (0x1F11A, 0x80031EBB), (0x1F11B, 0x80031EBE), (0x1F11C, 0x80031EC1), (0x1F11D, 0x80031EC4),
+    (0x1F11E, 0x80031EC7), (0x1F11F, 0x80031ECA), (0x1F120, 0x80031ECD), (0x1F121, 0x80031ED0),
+    (0x1F122, 0x80031ED3), (0x1F123, 0x80031ED6), (0x1F124, 0x80031ED9), (0x1F125, 0x80031EDC),
+    (0x1F126, 0x80031EDF), (0x1F127, 0x80031EE2), (0x1F128, 0x80031EE5), (0x1F129, 0x80031EE8),
+    (0x1F12A, 0x80031EEB), (0x1F12B, 0x80011EEE), (0x1F12C, 0x80011EEF), (0x1F12D, 0x80021EF0),
+    (0x1F12E, 0x80021EF2), (0x1F130, 0x80011EF4), (0x1F131, 0x80011EF5), (0x1F132, 0x80011EF6),
+    (0x1F133, 0x80011EF7), (0x1F134, 0x80011EF8), (0x1F135, 0x80011EF9), (0x1F136, 0x80011EFA),
+    (0x1F137, 0x80011EFB), (0x1F138, 0x80011EFC), (0x1F139, 0x80011EFD), (0x1F13A, 0x80011EFE),
+    (0x1F13B, 0x80011EFF), (0x1F13C, 0x80011F00), (0x1F13D, 0x80011F01), (0x1F13E, 0x80011F02),
+    (0x1F13F, 0x80011F03), (0x1F140, 0x80011F04), (0x1F141, 0x80011F05), (0x1F142, 0x80011F06),
+    (0x1F143, 0x80011F07), (0x1F144, 0x80011F08), (0x1F145, 0x80011F09), (0x1F146, 0x80011F0A),
+    (0x1F147, 0x80011F0B), (0x1F148, 0x80011F0C), (0x1F149, 0x80011F0D), (0x1F14A, 0x80021F0E),
+    (0x1F14B, 0x80021F10), (0x1F14C, 0x80021F12), (0x1F14D, 0x80021F14), (0x1F14E, 0x80031F16),
+    (0x1F14F, 0x80021F19), (0x1F16A, 0x80021F1B), (0x1F16B, 0x80021F1D), (0x1F16C, 0x80021F1F),
+    (0x1F190, 0x80021F21), (0x1F200, 0x80021F23), (0x1F201, 0x80021F25), (0x1F202, 0x80011F27),
+    (0x1F210, 0x80011F28), (0x1F211, 0x80011F29), (0x1F212, 0x80011F2A), (0x1F213, 0x80011F2B),
+    (0x1F214, 0x80011F2C), (0x1F215, 0x80011F2D), (0x1F216, 0x80011F2E), (0x1F217, 0x80011F2F),
+    (0x1F218, 0x80011F30), (0x1F219, 0x80011F31), (0x1F21A, 0x80011F32), (0x1F21B, 0x80011F33),
+    (0x1F21C, 0x80011F34), (0x1F21D, 0x80011F35), (0x1F21E, 0x80011F36), (0x1F21F, 0x80011F37),
+    (0x1F220, 0x80011F38), (0x1F221, 0x80011F39), (0x1F222, 0x80011F3A), (0x1F223, 0x80011F3B),
+    (0x1F224, 0x80011F3C), (0x1F225, 0x80011F3D), (0x1F226, 0x80011F3E), (0x1F227, 0x80011F3F),
+    (0x1F228, 0x80011F40), (0x1F229, 0x80011F41), (0x1F22A, 0x80011F42), (0x1F22B, 0x80011F43),
+    (0x1F22C, 0x80011F44), (0x1F22D, 0x80011F45), (0x1F22E, 0x80011F46), (0x1F22F, 0x80011F47),
+    (0x1F230, 0x80011F48), (0x1F231, 0x80011F49), (0x1F232, 0x80011F4A), (0x1F233, 0x80011F4B),
+    (0x1F234, 0x80011F4C), (0x1F235, 0x80011F4D), (0x1F236, 0x80011F4E), (0x1F237, 0x80011F4F),
+    (0x1F238, 0x80011F50), (0x1F239, 0x80011F51), (0x1F23A, 0x80011F52), (0x1F23B, 0x80011F53),
+    (0x1F240, 0x80031F54), (0x1F241, 0x80031F57), (0x1F242, 0x80031F5A), (0x1F243, 0x80031F5D),
+    (0x1F244, 0x80031F60), (0x1F245, 0x80031F63), (0x1F246, 0x80031F66), (0x1F247, 0x80031F69),
+    (0x1F248, 0x80031F6C), (0x1F250, 0x80011F6F), (0x1F251, 0x80011F70), (0x1FBF0, 0x80011F71),
+    (0x1FBF1, 0x80011F72), (0x1FBF2, 0x80011F73), (0x1FBF3, 0x80011F74), (0x1FBF4, 0x80011F75),
+    (0x1FBF5, 0x80011F76), (0x1FBF6, 0x80011F77), (0x1FBF7, 0x80011F78), (0x1FBF8, 0x80011F79),
+    (0x1FBF9, 0x80011F7A), (0x2F800, 0x11F7B), (0x2F801, 0x11F7C), (0x2F802, 0x11F7D),
+    (0x2F803, 0x11F7E), (0x2F804, 0x11F7F), (0x2F805, 0x11F80), (0x2F806, 0x11F81),
+    (0x2F807, 0x11F82), (0x2F808, 0x11F83), (0x2F809, 0x11F84), (0x2F80A, 0x11F85),
+    (0x2F80B, 0x11F86), (0x2F80C, 0x11F87), (0x2F80D, 0x11F88), (0x2F80E, 0x11F89),
+    (0x2F80F, 0x11F8A), (0x2F810, 0x11F8B), (0x2F811, 0x11F8C), (0x2F812, 0x11F8D),
+    (0x2F813, 0x11F8E), (0x2F814, 0x11F8F), (0x2F815, 0x11F90), (0x2F816, 0x11F91),
+    (0x2F817, 0x11F92), (0x2F818, 0x11F93), (0x2F819, 0x11F94), (0x2F81A, 0x11F95),
+    (0x2F81B, 0x11F96), (0x2F81C, 0x11F97), (0x2F81D, 0x11F98), (0x2F81E, 0x11F99),
+    (0x2F81F, 0x11F9A), (0x2F820, 0x11F9B), (0x2F821, 0x11F9C), (0x2F822, 0x11F9D),
+    (0x2F823, 0x11F9E), (0x2F824, 0x11F9F), (0x2F825, 0x11FA0), (0x2F826, 0x11FA1),
+    (0x2F827, 0x11FA2), (0x2F828, 0x11FA3), (0x2F829, 0x11FA4), (0x2F82A, 0x11FA5),
+    (0x2F82B, 0x11FA6), (0x2F82C, 0x11FA7), (0x2F82D, 0x11FA8), (0x2F82E, 0x11FA9),
+    (0x2F82F, 0x11FAA), (0x2F830, 0x11FAB), (0x2F831, 0x11FAC), (0x2F832, 0x11FAD),
+    (0x2F833, 0x11FAE), (0x2F834, 0x11FAF), (0x2F835, 0x11FB0), (0x2F836, 0x11FB1),
+    (0x2F837, 0x11FB2), (0x2F838, 0x11FB3), (0x2F839, 0x11FB4), (0x2F83A, 0x11FB5),
+    (0x2F83B, 0x11FB6), (0x2F83C, 0x11FB7), (0x2F83D, 0x11FB8), (0x2F83E, 0x11FB9),
+    (0x2F83F, 0x11FBA), (0x2F840, 0x11FBB), (0x2F841, 0x11FBC), (0x2F842, 0x11FBD),
+    (0x2F843, 0x11FBE), (0x2F844, 0x11FBF), (0x2F845, 0x11FC0), (0x2F846, 0x11FC1),
+    (0x2F847, 0x11FC2), (0x2F848, 0x11FC3), (0x2F849, 0x11FC4), (0x2F84A, 0x11FC5),
+    (0x2F84B, 0x11FC6), (0x2F84C, 0x11FC7), (0x2F84D, 0x11FC8), (0x2F84E, 0x11FC9),
+    (0x2F84F, 0x11FCA), (0x2F850, 0x11FCB), (0x2F851, 0x11FCC), (0x2F852, 0x11FCD),
+    (0x2F853, 0x11FCE), (0x2F854, 0x11FCF), (0x2F855, 0x11FD0), (0x2F856, 0x11FD1),
+    (0x2F857, 0x11FD2), (0x2F858, 0x11FD3), (0x2F859, 0x11FD4), (0x2F85A, 0x11FD5),
+    (0x2F85B, 0x11FD6), (0x2F85C, 0x11FD7), (0x2F85D, 0x11FD8), (0x2F85E, 0x11FD9),
+    (0x2F85F, 0x11FDA), (0x2F860, 0x11FDB), (0x2F861, 0x11FDC), (0x2F862, 0x11FDD),
+    (0x2F863, 0x11FDE), (0x2F864, 0x11FDF), (0x2F865, 0x11FE0), (0x2F866, 0x11FE1),
+    (0x2F867, 0x11FE2), (0x2F868, 0x11FE3), (0x2F869, 0x11FE4), (0x2F86A, 0x11FE5),
+    (0x2F86B, 0x11FE6), (0x2F86C, 0x11FE7), (0x2F86D, 0x11FE8), (0x2F86E, 0x11FE9),
+    (0x2F86F, 0x11FEA), (0x2F870, 0x11FEB), (0x2F871, 0x11FEC), (0x2F872, 0x11FED),
+    (0x2F873, 0x11FEE), (0x2F874, 0x11FEF), (0x2F875, 0x11FF0), (0x2F876, 0x11FF1),
+    (0x2F877, 0x11FF2), (0x2F878, 0x11FF3), (0x2F879, 0x11FF4), (0x2F87A, 0x11FF5),
+    (0x2F87B, 0x11FF6), (0x2F87C, 0x11FF7), (0x2F87D, 0x11FF8), (0x2F87E, 0x11FF9),
+    (0x2F87F, 0x11FFA), (0x2F880, 0x11FFB), (0x2F881, 0x11FFC), (0x2F882, 0x11FFD),
+    (0x2F883, 0x11FFE), (0x2F884, 0x11FFF), (0x2F885, 0x12000), (0x2F886, 0x12001),
+    (0x2F887, 0x12002), (0x2F888, 0x12003), (0x2F889, 0x12004), (0x2F88A, 0x12005),
+    (0x2F88B, 0x12006), (0x2F88C, 0x12007), (0x2F88D, 0x12008), (0x2F88E, 0x12009),
+    (0x2F88F, 0x1200A), (0x2F890, 0x1200B), (0x2F891, 0x1200C), (0x2F892, 0x1200D),
+    (0x2F893, 0x1200E), (0x2F894, 0x1200F), (0x2F895, 0x12010), (0x2F896, 0x12011),
+    (0x2F897, 0x12012), (0x2F898, 0x12013), (0x2F899, 0x12014), (0x2F89A, 0x12015),
+    (0x2F89B, 0x12016), (0x2F89C, 0x12017), (0x2F89D, 0x12018), (0x2F89E, 0x12019),
+    (0x2F89F, 0x1201A), (0x2F8A0, 0x1201B), (0x2F8A1, 0x1201C), (0x2F8A2, 0x1201D),
+    (0x2F8A3, 0x1201E), (0x2F8A4, 0x1201F), (0x2F8A5, 0x12020), (0x2F8A6, 0x12021),
+    (0x2F8A7, 0x12022), (0x2F8A8, 0x12023), (0x2F8A9, 0x12024), (0x2F8AA, 0x12025),
+    (0x2F8AB, 0x12026), (0x2F8AC, 0x12027), (0x2F8AD, 0x12028), (0x2F8AE, 0x12029),
+    (0x2F8AF, 0x1202A), (0x2F8B0, 0x1202B), (0x2F8B1, 0x1202C), (0x2F8B2, 0x1202D),
+    (0x2F8B3, 0x1202E), (0x2F8B4, 0x1202F), (0x2F8B5, 0x12030), (0x2F8B6, 0x12031),
+    (0x2F8B7, 0x12032), (0x2F8B8, 0x12033), (0x2F8B9, 0x12034), (0x2F8BA, 0x12035),
+    (0x2F8BB, 0x12036), (0x2F8BC, 0x12037), (0x2F8BD, 0x12038), (0x2F8BE, 0x12039),
+    (0x2F8BF, 0x1203A), (0x2F8C0, 0x1203B), (0x2F8C1, 0x1203C), (0x2F8C2, 0x1203D),
+    (0x2F8C3, 0x1203E), (0x2F8C4, 0x1203F), (0x2F8C5, 0x12040), (0x2F8C6, 0x12041),
+    (0x2F8C7, 0x12042), (0x2F8C8, 0x12043), (0x2F8C9, 0x12044), (0x2F8CA, 0x12045),
+    (0x2F8CB, 0x12046), (0x2F8CC, 0x12047), (0x2F8CD, 0x12048), (0x2F8CE, 0x12049),
+    (0x2F8CF, 0x1204A), (0x2F8D0, 0x1204B), (0x2F8D1, 0x1204C), (0x2F8D2, 0x1204D),
+    (0x2F8D3, 0x1204E), (0x2F8D4, 0x1204F), (0x2F8D5, 0x12050), (0x2F8D6, 0x12051),
+    (0x2F8D7, 0x12052), (0x2F8D8, 0x12053), (0x2F8D9, 0x12054), (0x2F8DA, 0x12055),
+    (0x2F8DB, 0x12056), (0x2F8DC, 0x12057), (0x2F8DD, 0x12058), (0x2F8DE, 0x12059),
+    (0x2F8DF, 0x1205A), (0x2F8E0, 0x1205B), (0x2F8E1, 0x1205C), (0x2F8E2, 0x1205D),
+    (0x2F8E3, 0x1205E), (0x2F8E4, 0x1205F), (0x2F8E5, 0x12060), (0x2F8E6, 0x12061),
+    (0x2F8E7, 0x12062), (0x2F8E8, 0x12063), (0x2F8E9, 0x12064), (0x2F8EA, 0x12065),
+    (0x2F8EB, 0x12066), (0x2F8EC, 0x12067), (0x2F8ED, 0x12068), (0x2F8EE, 0x12069),
+    (0x2F8EF, 0x1206A), (0x2F8F0, 0x1206B), (0x2F8F1, 0x1206C), (0x2F8F2, 0x1206D),
+    (0x2F8F3, 0x1206E), (0x2F8F4, 0x1206F), (0x2F8F5, 0x12070), (0x2F8F6, 0x12071),
+    (0x2F8F7, 0x12072), (0x2F8F8, 0x12073), (0x2F8F9, 0x12074), (0x2F8FA, 0x12075),
+    (0x2F8FB, 0x12076), (0x2F8FC, 0x12077), (0x2F8FD, 0x12078), (0x2F8FE, 0x12079),
+    (0x2F8FF, 0x1207A), (0x2F900, 0x1207B), (0x2F901, 0x1207C), (0x2F902, 0x1207D),
+    (0x2F903, 0x1207E), (0x2F904, 0x1207F), (0x2F905, 0x12080), (0x2F906, 0x12081),
+    (0x2F907, 0x12082), (0x2F908, 0x12083), (0x2F909, 0x12084), (0x2F90A, 0x12085),
+    (0x2F90B, 0x12086), (0x2F90C, 0x12087), (0x2F90D, 0x12088), (0x2F90E, 0x12089),
+    (0x2F90F, 0x1208A), (0x2F910, 0x1208B), (0x2F911, 0x1208C), (0x2F912, 0x1208D),
+    (0x2F913, 0x1208E), (0x2F914, 0x1208F), (0x2F915, 0x12090), (0x2F916, 0x12091),
+    (0x2F917, 0x12092), (0x2F918, 0x12093), (0x2F919, 0x12094), (0x2F91A, 0x12095),
+    (0x2F91B, 0x12096), (0x2F91C, 0x12097), (0x2F91D, 0x12098), (0x2F91E, 0x12099),
+    (0x2F91F, 0x1209A), (0x2F920, 0x1209B), (0x2F921, 0x1209C), (0x2F922, 0x1209D),
+    (0x2F923, 0x1209E), (0x2F924, 0x1209F), (0x2F925, 0x120A0), (0x2F926, 0x120A1),
+    (0x2F927, 0x120A2), (0x2F928, 0x120A3), (0x2F929, 0x120A4), (0x2F92A, 0x120A5),
+    (0x2F92B, 0x120A6), (0x2F92C, 0x120A7), (0x2F92D, 0x120A8), (0x2F92E, 0x120A9),
+    (0x2F92F, 0x120AA), (0x2F930, 0x120AB), (0x2F931, 0x120AC), (0x2F932, 0x120AD),
+    (0x2F933, 0x120AE), (0x2F934, 0x120AF), (0x2F935, 0x120B0), (0x2F936, 0x120B1),
+    (0x2F937, 0x120B2), (0x2F938, 0x120B3), (0x2F939, 0x120B4), (0x2F93A, 0x120B5),
+    (0x2F93B, 0x120B6), (0x2F93C, 0x120B7), (0x2F93D, 0x120B8), (0x2F93E, 0x120B9),
+    (0x2F93F, 0x120BA), (0x2F940, 0x120BB), (0x2F941, 0x120BC), (0x2F942, 0x120BD),
+    (0x2F943, 0x120BE), (0x2F944, 0x120BF), (0x2F945, 0x120C0), (0x2F946, 0x120C1),
+    (0x2F947, 0x120C2), (0x2F948, 0x120C3), (0x2F949, 0x120C4), (0x2F94A, 0x120C5),
+    (0x2F94B, 0x120C6), (0x2F94C, 0x120C7), (0x2F94D, 0x120C8), (0x2F94E, 0x120C9),
+    (0x2F94F, 0x120CA), (0x2F950, 0x120CB), (0x2F951, 0x120CC), (0x2F952, 0x120CD),
+    (0x2F953, 0x120CE), (0x2F954, 0x120CF), (0x2F955, 0x120D0), (0x2F956, 0x120D1),
+    (0x2F957, 0x120D2), (0x2F958, 0x120D3), (0x2F959, 0x120D4), (0x2F95A, 0x120D5),
+    (0x2F95B, 0x120D6), (0x2F95C, 0x120D7), (0x2F95D, 0x120D8), (0x2F95E, 0x120D9),
+    (0x2F95F, 0x120DA), (0x2F960, 0x120DB), (0x2F961, 0x120DC), (0x2F962, 0x120DD),
+    (0x2F963, 0x120DE), (0x2F964, 0x120DF), (0x2F965, 0x120E0), (0x2F966, 0x120E1),
+    (0x2F967, 0x120E2), (0x2F968, 0x120E3), (0x2F969, 0x120E4), (0x2F96A, 0x120E5),
+    (0x2F96B, 0x120E6), (0x2F96C, 0x120E7), (0x2F96D, 0x120E8), (0x2F96E, 0x120E9),
+    (0x2F96F, 0x120EA), (0x2F970, 0x120EB), (0x2F971, 0x120EC), (0x2F972, 0x120ED),
+    (0x2F973, 0x120EE), (0x2F974, 0x120EF), (0x2F975, 0x120F0), (0x2F976, 0x120F1),
+    (0x2F977, 0x120F2), (0x2F978, 0x120F3), (0x2F979, 0x120F4), (0x2F97A, 0x120F5),
+    (0x2F97B, 0x120F6), (0x2F97C, 0x120F7), (0x2F97D, 0x120F8), (0x2F97E, 0x120F9),
+    (0x2F97F, 0x120FA), (0x2F980, 0x120FB), (0x2F981, 0x120FC), (0x2F982, 0x120FD),
+    (0x2F983, 0x120FE), (0x2F984, 0x120FF), (0x2F985, 0x12100), (0x2F986, 0x12101),
+    (0x2F987, 0x12102), (0x2F988, 0x12103), (0x2F989, 0x12104), (0x2F98A, 0x12105),
+    (0x2F98B, 0x12106), (0x2F98C, 0x12107), (0x2F98D, 0x12108), (0x2F98E, 0x12109),
+    (0x2F98F, 0x1210A), (0x2F990, 0x1210B), (0x2F991, 0x1210C), (0x2F992, 0x1210D),
+    (0x2F993, 0x1210E), (0x2F994, 0x1210F), (0x2F995, 0x12110), (0x2F996, 0x12111),
+    (0x2F997, 0x12112), (0x2F998, 0x12113), (0x2F999, 0x12114), (0x2F99A, 0x12115),
+    (0x2F99B, 0x12116), (0x2F99C, 0x12117), (0x2F99D, 0x12118), (0x2F99E, 0x12119),
+    (0x2F99F, 0x1211A), (0x2F9A0, 0x1211B), (0x2F9A1, 0x1211C), (0x2F9A2, 0x1211D),
+    (0x2F9A3, 0x1211E), (0x2F9A4, 0x1211F), (0x2F9A5, 0x12120), (0x2F9A6, 0x12121),
+    (0x2F9A7, 0x12122), (0x2F9A8, 0x12123), (0x2F9A9, 0x12124), (0x2F9AA, 0x12125),
+    (0x2F9AB, 0x12126), (0x2F9AC, 0x12127), (0x2F9AD, 0x12128), (0x2F9AE, 0x12129),
+    (0x2F9AF, 0x1212A), (0x2F9B0, 0x1212B), (0x2F9B1, 0x1212C), (0x2F9B2, 0x1212D),
+    (0x2F9B3, 0x1212E), (0x2F9B4, 0x1212F), (0x2F9B5, 0x12130), (0x2F9B6, 0x12131),
+    (0x2F9B7, 0x12132), (0x2F9B8, 0x12133), (0x2F9B9, 0x12134), (0x2F9BA, 0x12135),
+    (0x2F9BB, 0x12136), (0x2F9BC, 0x12137), (0x2F9BD, 0x12138), (0x2F9BE, 0x12139),
+    (0x2F9BF, 0x1213A), (0x2F9C0, 0x1213B), (0x2F9C1, 0x1213C), (0x2F9C2, 0x1213D),
+    (0x2F9C3, 0x1213E), (0x2F9C4, 0x1213F), (0x2F9C5, 0x12140), (0x2F9C6, 0x12141),
+    (0x2F9C7, 0x12142), (0x2F9C8, 0x12143), (0x2F9C9, 0x12144), (0x2F9CA, 0x12145),
+    (0x2F9CB, 0x12146), (0x2F9CC, 0x12147), (0x2F9CD, 0x12148), (0x2F9CE, 0x12149),
+    (0x2F9CF, 0x1214A), (0x2F9D0, 0x1214B), (0x2F9D1, 0x1214C), (0x2F9D2, 0x1214D),
+    (0x2F9D3, 0x1214E), (0x2F9D4, 0x1214F), (0x2F9D5, 0x12150), (0x2F9D6, 0x12151),
+    (0x2F9D7, 0x12152), (0x2F9D8, 0x12153), (0x2F9D9, 0x12154), (0x2F9DA, 0x12155),
+    (0x2F9DB, 0x12156), (0x2F9DC, 0x12157), (0x2F9DD, 0x12158), (0x2F9DE, 0x12159),
+    (0x2F9DF, 0x1215A), (0x2F9E0, 0x1215B), (0x2F9E1, 0x1215C), (0x2F9E2, 0x1215D),
+    (0x2F9E3, 0x1215E), (0x2F9E4, 0x1215F), (0x2F9E5, 0x12160), (0x2F9E6, 0x12161),
+    (0x2F9E7, 0x12162), (0x2F9E8, 0x12163), (0x2F9E9, 0x12164), (0x2F9EA, 0x12165),
+    (0x2F9EB, 0x12166), (0x2F9EC, 0x12167), (0x2F9ED, 0x12168), (0x2F9EE, 0x12169),
+    (0x2F9EF, 0x1216A), (0x2F9F0, 0x1216B), (0x2F9F1, 0x1216C), (0x2F9F2, 0x1216D),
+    (0x2F9F3, 0x1216E), (0x2F9F4, 0x1216F), (0x2F9F5, 0x12170), (0x2F9F6, 0x12171),
+    (0x2F9F7, 0x12172), (0x2F9F8, 0x12173), (0x2F9F9, 0x12174), (0x2F9FA, 0x12175),
+    (0x2F9FB, 0x12176), (0x2F9FC, 0x12177), (0x2F9FD, 0x12178), (0x2F9FE, 0x12179),
+    (0x2F9FF, 0x1217A), (0x2FA00, 0x1217B), (0x2FA01, 0x1217C), (0x2FA02, 0x1217D),
+    (0x2FA03, 0x1217E), (0x2FA04, 0x1217F), (0x2FA05, 0x12180), (0x2FA06, 0x12181),
+    (0x2FA07, 0x12182), (0x2FA08, 0x12183), (0x2FA09, 0x12184), (0x2FA0A, 0x12185),
+    (0x2FA0B, 0x12186), (0x2FA0C, 0x12187), (0x2FA0D, 0x12188), (0x2FA0E, 0x12189),
+    (0x2FA0F, 0x1218A), (0x2FA10, 0x1218B), (0x2FA11, 0x1218C), (0x2FA12, 0x1218D),
+    (0x2FA13, 0x1218E), (0x2FA14, 0x1218F), (0x2FA15, 0x12190), (0x2FA16, 0x12191),
+    (0x2FA17, 0x12192), (0x2FA18, 0x12193), (0x2FA19, 0x12194), (0x2FA1A, 0x12195),
+    (0x2FA1B, 0x12196), (0x2FA1C, 0x12197), (0x2FA1D, 0x12198),
+];
+
+/// Characters of the decomposition mappings in `DECOMPOSITIONS`
+pub(super) static DECOMPOSITION_CHARS: [u32; 8601] = [
+    0x20, 0x20, 0x308, 0x61, 0x20, 0x304, 0x32, 0x33, 0x20, 0x301,
+    0x3BC, 0x20, 0x327, 0x31, 0x6F, 0x31, 0x2044, 0x34, 0x31, 0x2044,
+    0x32, 0x33, 0x2044, 0x34, 0x41, 0x300, 0x41, 0x301, 0x41, 0x302,
+    0x41, 0x303, 0x41, 0x308, 0x41, 0x30A, 0x43, 0x327, 0x45, 0x300,
+    0x45, 0x301, 0x45, 0x302, 0x45, 0x308, 0x49, 0x300, 0x49, 0x301,
+    0x49, 0x302, 0x49, 0x308, 0x4E, 0x303, 0x4F, 0x300, 0x4F, 0x301,
+    0x4F, 0x302, 0x4F, 0x303, 0x4F, 0x308, 0x55, 0x300, 0x55, 0x301,
+    0x55, 0x302, 0x55, 0x308, 0x59, 0x301, 0x61, 0x300, 0x61, 0x301,
+    0x61, 0x302, 0x61, 0x303, 0x61, 0x308, 0x61, 0x30A, 0x63, 0x327,
+    0x65, 0x300, 0x65, 0x301, 0x65, 0x302, 0x65, 0x308, 0x69, 0x300,
+    0x69, 0x301, 0x69, 0x302, 0x69, 0x308, 0x6E, 0x303, 0x6F, 0x300,
+    0x6F, 0x301, 0x6F, 0x302, 0x6F, 0x303, 0x6F, 0x308, 0x75, 0x300,
+    0x75, 0x301, 0x75, 0x302, 0x75, 0x308, 0x79, 0x301, 0x79, 0x308,
+    0x41, 0x304, 0x61, 0x304, 0x41, 0x306, 0x61, 0x306, 0x41, 0x328,
+    0x61, 0x328, 0x43, 0x301, 0x63, 0x301, 0x43, 0x302, 0x63, 0x302,
+    0x43, 0x307, 0x63, 0x307, 0x43, 0x30C, 0x63, 0x30C, 0x44, 0x30C,
+    0x64, 0x30C, 0x45, 0x304, 0x65, 0x304, 0x45, 0x306, 0x65, 0x306,
+    0x45, 0x307, 0x65, 0x307, 0x45, 0x328, 0x65, 0x328, 0x45, 0x30C,
+    0x65, 0x30C, 0x47, 0x302, 0x67, 0x302, 0x47, 0x306, 0x67, 0x306,
+    0x47, 0x307, 0x67, 0x307, 0x47, 0x327, 0x67, 0x327, 0x48, 0x302,
+    0x68, 0x302, 0x49, 0x303, 0x69, 0x303, 0x49, 0x304, 0x69, 0x304,
+    0x49, 0x306, 0x69, 0x306, 0x49, 0x328, 0x69, 0x328, 0x49, 0x307,
+    0x49, 0x4A, 0x69, 0x6A, 0x4A, 0x302, 0x6A, 0x302, 0x4B, 0x327,
+    0x6B, 0x327, 0x4C, 0x301, 0x6C, 0x301, 0x4C, 0x327, 0x6C, 0x327,
+    0x4C, 0x30C, 0x6C, 0x30C, 0x4C, 0xB7, 0x6C, 0xB7, 0x4E, 0x301,
+    0x6E, 0x301, 0x4E, 0x327, 0x6E, 0x327, 0x4E, 0x30C, 0x6E, 0x30C,
+    0x2BC, 0x6E, 0x4F, 0x304, 0x6F, 0x304, 0x4F, 0x306, 0x6F, 0x306,
+    0x4F, 0x30B, 0x6F, 0x30B, 0x52, 0x301, 0x72, 0x301, 0x52, 0x327,
+    0x72, 0x327, 0x52, 0x30C, 0x72, 0x30C, 0x53, 0x301, 0x73, 0x301,
+    0x53, 0x302, 0x73, 0x302, 0x53, 0x327, 0x73, 0x327, 0x53, 0x30C,
+    0x73, 0x30C, 0x54, 0x327, 0x74, 0x327, 0x54, 0x30C, 0x74, 0x30C,
+    0x55, 0x303, 0x75, 0x303, 0x55, 0x304, 0x75, 0x304, 0x55, 0x306,
+    0x75, 0x306, 0x55, 0x30A, 0x75, 0x30A, 0x55, 0x30B, 0x75, 0x30B,
+    0x55, 0x328, 0x75, 0x328, 0x57, 0x302, 0x77, 0x302, 0x59, 0x302,
+    0x79, 0x302, 0x59, 0x308, 0x5A, 0x301, 0x7A, 0x301, 0x5A, 0x307,
+    0x7A, 0x307, 0x5A, 0x30C, 0x7A, 0x30C, 0x73, 0x4F, 0x31B, 0x6F,
+    0x31B, 0x55, 0x31B, 0x75, 0x31B, 0x44, 0x17D, 0x44, 0x17E, 0x64,
+    0x17E, 0x4C, 0x4A, 0x4C, 0x6A, 0x6C, 0x6A, 0x4E, 0x4A, 0x4E,
+    0x6A, 0x6E, 0x6A, 0x41, 0x30C, 0x61, 0x30C, 0x49, 0x30C, 0x69,
+    0x30C, 0x4F, 0x30C, 0x6F, 0x30C, 0x55, 0x30C, 0x75, 0x30C, 0xDC,
+    0x304, 0xFC, 0x304, 0xDC, 0x301, 0xFC, 0x301, 0xDC, 0x30C, 0xFC,
+    0x30C, 0xDC, 0x300, 0xFC, 0x300, 0xC4, 0x304, 0xE4, 0x304, 0x226,
+    0x304, 0x227, 0x304, 0xC6, 0x304, 0xE6, 0x304, 0x47, 0x30C, 0x67,
+    0x30C, 0x4B, 0x30C, 0x6B, 0x30C, 0x4F, 0x328, 0x6F, 0x328, 0x1EA,
+    0x304, 0x1EB, 0x304, 0x1B7, 0x30C, 0x292, 0x30C, 0x6A, 0x30C, 0x44,
+    0x5A, 0x44, 0x7A, 0x64, 0x7A, 0x47, 0x301, 0x67, 0x301, 0x4E,
+    0x300, 0x6E, 0x300, 0xC5, 0x301, 0xE5, 0x301, 0xC6, 0x301, 0xE6,
+    0x301, 0xD8, 0x301, 0xF8, 0x301, 0x41, 0x30F, 0x61, 0x30F, 0x41,
+    0x311, 0x61, 0x311, 0x45, 0x30F, 0x65, 0x30F, 0x45, 0x311, 0x65,
+    0x311, 0x49, 0x30F, 0x69, 0x30F, 0x49, 0x311, 0x69, 0x311, 0x4F,
+    0x30F, 0x6F, 0x30F, 0x4F, 0x311, 0x6F, 0x311, 0x52, 0x30F, 0x72,
+    0x30F, 0x52, 0x311, 0x72, 0x311, 0x55, 0x30F, 0x75, 0x30F, 0x55,
+    0x311, 0x75, 0x311, 0x53, 0x326, 0x73, 0x326, 0x54, 0x326, 0x74,
+    0x326, 0x48, 0x30C, 0x68, 0x30C, 0x41, 0x307, 0x61, 0x307, 0x45,
+    0x327, 0x65, 0x327, 0xD6, 0x304, 0xF6, 0x304, 0xD5, 0x304, 0xF5,
+    0x304, 0x4F, 0x307, 0x6F, 0x307, 0x22E, 0x304, 0x22F, 0x304, 0x59,
+    0x304, 0x79, 0x304, 0x68, 0x266, 0x6A, 0x72, 0x279, 0x27B, 0x281,
+    0x77, 0x79, 0x20, 0x306, 0x20, 0x307, 0x20, 0x30A, 0x20, 0x328,
+    0x20, 0x303, 0x20, 0x30B, 0x263, 0x6C, 0x73, 0x78, 0x295, 0x300,
+    0x301, 0x313, 0x308, 0x301, 0x2B9, 0x20, 0x345, 0x3B, 0x20, 0x301,
+    0xA8, 0x301, 0x391, 0x301, 0xB7, 0x395, 0x301, 0x397, 0x301, 0x399,
+    0x301, 0x39F, 0x301, 0x3A5, 0x301, 0x3A9, 0x301, 0x3CA, 0x301, 0x399,
+    0x308, 0x3A5, 0x308, 0x3B1, 0x301, 0x3B5, 0x301, 0x3B7, 0x301, 0x3B9,
+    0x301, 0x3CB, 0x301, 0x3B9, 0x308, 0x3C5, 0x308, 0x3BF, 0x301, 0x3C5,
+    0x301, 0x3C9, 0x301, 0x3B2, 0x3B8, 0x3A5, 0x3D2, 0x301, 0x3D2, 0x308,
+    0x3C6, 0x3C0, 0x3BA, 0x3C1, 0x3C2, 0x398, 0x3B5, 0x3A3, 0x415, 0x300,
+    0x415, 0x308, 0x413, 0x301, 0x406, 0x308, 0x41A, 0x301, 0x418, 0x300,
+    0x423, 0x306, 0x418, 0x306, 0x438, 0x306, 0x435, 0x300, 0x435, 0x308,
+    0x433, 0x301, 0x456, 0x308, 0x43A, 0x301, 0x438, 0x300, 0x443, 0x306,
+    0x474, 0x30F, 0x475, 0x30F, 0x416, 0x306, 0x436, 0x306, 0x410, 0x306,
+    0x430, 0x306, 0x410, 0x308, 0x430, 0x308, 0x415, 0x306, 0x435, 0x306,
+    0x4D8, 0x308, 0x4D9, 0x308, 0x416, 0x308, 0x436, 0x308, 0x417, 0x308,
+    0x437, 0x308, 0x418, 0x304, 0x438, 0x304, 0x418, 0x308, 0x438, 0x308,
+    0x41E, 0x308, 0x43E, 0x308, 0x4E8, 0x308, 0x4E9, 0x308, 0x42D, 0x308,
+    0x44D, 0x308, 0x423, 0x304, 0x443, 0x304, 0x423, 0x308, 0x443, 0x308,
+    0x423, 0x30B, 0x443, 0x30B, 0x427, 0x308, 0x447, 0x308, 0x42B, 0x308,
+    0x44B, 0x308, 0x565, 0x582, 0x627, 0x653, 0x627, 0x654, 0x648, 0x654,
+    0x627, 0x655, 0x64A, 0x654, 0x627, 0x674, 0x648, 0x674, 0x6C7, 0x674,
+    0x64A, 0x674, 0x6D5, 0x654, 0x6C1, 0x654, 0x6D2, 0x654, 0x928, 0x93C,
+    0x930, 0x93C, 0x933, 0x93C, 0x915, 0x93C, 0x916, 0x93C, 0x917, 0x93C,
+    0x91C, 0x93C, 0x921, 0x93C, 0x922, 0x93C, 0x92B, 0x93C, 0x92F, 0x93C,
+    0x9C7, 0x9BE, 0x9C7, 0x9D7, 0x9A1, 0x9BC, 0x9A2, 0x9BC, 0x9AF, 0x9BC,
+    0xA32, 0xA3C, 0xA38, 0xA3C, 0xA16, 0xA3C, 0xA17, 0xA3C, 0xA1C, 0xA3C,
+    0xA2B, 0xA3C, 0xB47, 0xB56, 0xB47, 0xB3E, 0xB47, 0xB57, 0xB21, 0xB3C,
+    0xB22, 0xB3C, 0xB92, 0xBD7, 0xBC6, 0xBBE, 0xBC7, 0xBBE, 0xBC6, 0xBD7,
+    0xC46, 0xC56, 0xCBF, 0xCD5, 0xCC6, 0xCD5, 0xCC6, 0xCD6, 0xCC6, 0xCC2,
+    0xCCA, 0xCD5, 0xD46, 0xD3E, 0xD47, 0xD3E, 0xD46, 0xD57, 0xDD9, 0xDCA,
+    0xDD9, 0xDCF, 0xDDC, 0xDCA, 0xDD9, 0xDDF, 0xE4D, 0xE32, 0xECD, 0xEB2,
+    0xEAB, 0xE99, 0xEAB, 0xEA1, 0xF0B, 0xF42, 0xFB7, 0xF4C, 0xFB7, 0xF51,
+    0xFB7, 0xF56, 0xFB7, 0xF5B, 0xFB7, 0xF40, 0xFB5, 0xF71, 0xF72, 0xF71,
+    0xF74, 0xFB2, 0xF80, 0xFB2, 0xF81, 0xFB3, 0xF80, 0xFB3, 0xF81, 0xF71,
+    0xF80, 0xF92, 0xFB7, 0xF9C, 0xFB7, 0xFA1, 0xFB7, 0xFA6, 0xFB7, 0xFAB,
+    0xFB7, 0xF90, 0xFB5, 0x1025, 0x102E, 0x10DC, 0x1B05, 0x1B35, 0x1B07, 0x1B35,
+    0x1B09, 0x1B35, 0x1B0B, 0x1B35, 0x1B0D, 0x1B35, 0x1B11, 0x1B35, 0x1B3A, 0x1B35,
+    0x1B3C, 0x1B35, 0x1B3E, 0x1B35, 0x1B3F, 0x1B35, 0x1B42, 0x1B35, 0x41, 0xC6,
+    0x42, 0x44, 0x45, 0x18E, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x222, 0x50, 0x52, 0x54, 0x55, 0x57, 0x61,
+    0x250, 0x251, 0x1D02, 0x62, 0x64, 0x65, 0x259, 0x25B, 0x25C, 0x67,
+    0x6B, 0x6D, 0x14B, 0x6F, 0x254, 0x1D16, 0x1D17, 0x70, 0x74, 0x75,
+    0x1D1D, 0x26F, 0x76, 0x1D25, 0x3B2, 0x3B3, 0x3B4, 0x3C6, 0x3C7, 0x69,
+    0x72, 0x75, 0x76, 0x3B2, 0x3B3, 0x3C1, 0x3C6, 0x3C7, 0x43D, 0x252,
+    0x63, 0x255, 0xF0, 0x25C, 0x66, 0x25F, 0x261, 0x265, 0x268, 0x269,
+    0x26A, 0x1D7B, 0x29D, 0x26D, 0x1D85, 0x29F, 0x271, 0x270, 0x272, 0x273,
+    0x274, 0x275, 0x278, 0x282, 0x283, 0x1AB, 0x289, 0x28A, 0x1D1C, 0x28B,
+    0x28C, 0x7A, 0x290, 0x291, 0x292, 0x3B8, 0x41, 0x325, 0x61, 0x325,
+    0x42, 0x307, 0x62, 0x307, 0x42, 0x323, 0x62, 0x323, 0x42, 0x331,
+    0x62, 0x331, 0xC7, 0x301, 0xE7, 0x301, 0x44, 0x307, 0x64, 0x307,
+    0x44, 0x323, 0x64, 0x323, 0x44, 0x331, 0x64, 0x331, 0x44, 0x327,
+    0x64, 0x327, 0x44, 0x32D, 0x64, 0x32D, 0x112, 0x300, 0x113, 0x300,
+    0x112, 0x301, 0x113, 0x301, 0x45, 0x32D, 0x65, 0x32D, 0x45, 0x330,
+    0x65, 0x330, 0x228, 0x306, 0x229, 0x306, 0x46, 0x307, 0x66, 0x307,
+    0x47, 0x304, 0x67, 0x304, 0x48, 0x307, 0x68, 0x307, 0x48, 0x323,
+    0x68, 0x323, 0x48, 0x308, 0x68, 0x308, 0x48, 0x327, 0x68, 0x327,
+    0x48, 0x32E, 0x68, 0x32E, 0x49, 0x330, 0x69, 0x330, 0xCF, 0x301,
+    0xEF, 0x301, 0x4B, 0x301, 0x6B, 0x301, 0x4B, 0x323, 0x6B, 0x323,
+    0x4B, 0x331, 0x6B, 0x331, 0x4C, 0x323, 0x6C, 0x323, 0x1E36, 0x304,
+    0x1E37, 0x304, 0x4C, 0x331, 0x6C, 0x331, 0x4C, 0x32D, 0x6C, 0x32D,
+    0x4D, 0x301, 0x6D, 0x301, 0x4D, 0x307, 0x6D, 0x307, 0x4D, 0x323,
+    0x6D, 0x323, 0x4E, 0x307, 0x6E, 0x307, 0x4E, 0x323, 0x6E, 0x323,
+    0x4E, 0x331, 0x6E, 0x331, 0x4E, 0x32D, 0x6E, 0x32D, 0xD5, 0x301,
+    0xF5, 0x301, 0xD5, 0x308, 0xF5, 0x308, 0x14C, 0x300, 0x14D, 0x300,
+    0x14C, 0x301, 0x14D, 0x301, 0x50, 0x301, 0x70, 0x301, 0x50, 0x307,
+    0x70, 0x307, 0x52, 0x307, 0x72, 0x307, 0x52, 0x323, 0x72, 0x323,
+    0x1E5A, 0x304, 0x1E5B, 0x304, 0x52, 0x331, 0x72, 0x331, 0x53, 0x307,
+    0x73, 0x307, 0x53, 0x323, 0x73, 0x323, 0x15A, 0x307, 0x15B, 0x307,
+    0x160, 0x307, 0x161, 0x307, 0x1E62, 0x307, 0x1E63, 0x307, 0x54, 0x307,
+    0x74, 0x307, 0x54, 0x323, 0x74, 0x323, 0x54, 0x331, 0x74, 0x331,
+    0x54, 0x32D, 0x74, 0x32D, 0x55, 0x324, 0x75, 0x324, 0x55, 0x330,
+    0x75, 0x330, 0x55, 0x32D, 0x75, 0x32D, 0x168, 0x301, 0x169, 0x301,
+    0x16A, 0x308, 0x16B, 0x308, 0x56, 0x303, 0x76, 0x303, 0x56, 0x323,
+    0x76, 0x323, 0x57, 0x300, 0x77, 0x300, 0x57, 0x301, 0x77, 0x301,
+    0x57, 0x308, 0x77, 0x308, 0x57, 0x307, 0x77, 0x307, 0x57, 0x323,
+    0x77, 0x323, 0x58, 0x307, 0x78, 0x307, 0x58, 0x308, 0x78, 0x308,
+    0x59, 0x307, 0x79, 0x307, 0x5A, 0x302, 0x7A, 0x302, 0x5A, 0x323,
+    0x7A, 0x323, 0x5A, 0x331, 0x7A, 0x331, 0x68, 0x331, 0x74, 0x308,
+    0x77, 0x30A, 0x79, 0x30A, 0x61, 0x2BE, 0x17F, 0x307, 0x41, 0x323,
+    0x61, 0x323, 0x41, 0x309, 0x61, 0x309, 0xC2, 0x301, 0xE2, 0x301,
+    0xC2, 0x300, 0xE2, 0x300, 0xC2, 0x309, 0xE2, 0x309, 0xC2, 0x303,
+    0xE2, 0x303, 0x1EA0, 0x302, 0x1EA1, 0x302, 0x102, 0x301, 0x103, 0x301,
+    0x102, 0x300, 0x103, 0x300, 0x102, 0x309, 0x103, 0x309, 0x102, 0x303,
+    0x103, 0x303, 0x1EA0, 0x306, 0x1EA1, 0x306, 0x45, 0x323, 0x65, 0x323,
+    0x45, 0x309, 0x65, 0x309, 0x45, 0x303, 0x65, 0x303, 0xCA, 0x301,
+    0xEA, 0x301, 0xCA, 0x300, 0xEA, 0x300, 0xCA, 0x309, 0xEA, 0x309,
+    0xCA, 0x303, 0xEA, 0x303, 0x1EB8, 0x302, 0x1EB9, 0x302, 0x49, 0x309,
+    0x69, 0x309, 0x49, 0x323, 0x69, 0x323, 0x4F, 0x323, 0x6F, 0x323,
+    0x4F, 0x309, 0x6F, 0x309, 0xD4, 0x301, 0xF4, 0x301, 0xD4, 0x300,
+    0xF4, 0x300, 0xD4, 0x309, 0xF4, 0x309, 0xD4, 0x303, 0xF4, 0x303,
+    0x1ECC, 0x302, 0x1ECD, 0x302, 0x1A0, 0x301, 0x1A1, 0x301, 0x1A0, 0x300,
+    0x1A1, 0x300, 0x1A0, 0x309, 0x1A1, 0x309, 0x1A0, 0x303, 0x1A1, 0x303,
+    0x1A0, 0x323, 0x1A1, 0x323, 0x55, 0x323, 0x75, 0x323, 0x55, 0x309,
+    0x75, 0x309, 0x1AF, 0x301, 0x1B0, 0x301, 0x1AF, 0x300, 0x1B0, 0x300,
+    0x1AF, 0x309, 0x1B0, 0x309, 0x1AF, 0x303, 0x1B0, 0x303, 0x1AF, 0x323,
+    0x1B0, 0x323, 0x59, 0x300, 0x79, 0x300, 0x59, 0x323, 0x79, 0x323,
+    0x59, 0x309, 0x79, 0x309, 0x59, 0x303, 0x79, 0x303, 0x3B1, 0x313,
+    0x3B1, 0x314, 0x1F00, 0x300, 0x1F01, 0x300, 0x1F00, 0x301, 0x1F01, 0x301,
+    0x1F00, 0x342, 0x1F01, 0x342, 0x391, 0x313, 0x391, 0x314, 0x1F08, 0x300,
+    0x1F09, 0x300, 0x1F08, 0x301, 0x1F09, 0x301, 0x1F08, 0x342, 0x1F09, 0x342,
+    0x3B5, 0x313, 0x3B5, 0x314, 0x1F10, 0x300, 0x1F11, 0x300, 0x1F10, 0x301,
+    0x1F11, 0x301, 0x395, 0x313, 0x395, 0x314, 0x1F18, 0x300, 0x1F19, 0x300,
+    0x1F18, 0x301, 0x1F19, 0x301, 0x3B7, 0x313, 0x3B7, 0x314, 0x1F20, 0x300,
+    0x1F21, 0x300, 0x1F20, 0x301, 0x1F21, 0x301, 0x1F20, 0x342, 0x1F21, 0x342,
+    0x397, 0x313, 0x397, 0x314, 0x1F28, 0x300, 0x1F29, 0x300, 0x1F28, 0x301,
+    0x1F29, 0x301, 0x1F28, 0x342, 0x1F29, 0x342, 0x3B9, 0x313, 0x3B9, 0x314,
+    0x1F30, 0x300, 0x1F31, 0x300, 0x1F30, 0x301, 0x1F31, 0x301, 0x1F30, 0x342,
+    0x1F31, 0x342, 0x399, 0x313, 0x399, 0x314, 0x1F38, 0x300, 0x1F39, 0x300,
+    0x1F38, 0x301, 0x1F39, 0x301, 0x1F38, 0x342, 0x1F39, 0x342, 0x3BF, 0x313,
+    0x3BF, 0x314, 0x1F40, 0x300, 0x1F41, 0x300, 0x1F40, 0x301, 0x1F41, 0x301,
+    0x39F, 0x313, 0x39F, 0x314, 0x1F48, 0x300, 0x1F49, 0x300, 0x1F48, 0x301,
+    0x1F49, 0x301, 0x3C5, 0x313, 0x3C5, 0x314, 0x1F50, 0x300, 0x1F51, 0x300,
+    0x1F50, 0x301, 0x1F51, 0x301, 0x1F50, 0x342, 0x1F51, 0x342, 0x3A5, 0x314,
+    0x1F59, 0x300, 0x1F59, 0x301, 0x1F59, 0x342, 0x3C9, 0x313, 0x3C9, 0x314,
+    0x1F60, 0x300, 0x1F61, 0x300, 0x1F60, 0x301, 0x1F61, 0x301, 0x1F60, 0x342,
+    0x1F61, 0x342, 0x3A9, 0x313, 0x3A9, 0x314, 0x1F68, 0x300, 0x1F69, 0x300,
+    0x1F68, 0x301, 0x1F69, 0x301, 0x1F68, 0x342, 0x1F69, 0x342, 0x3B1, 0x300,
+    0x3AC, 0x3B5, 0x300, 0x3AD, 0x3B7, 0x300, 0x3AE, 0x3B9, 0x300, 0x3AF,
+    0x3BF, 0x300, 0x3CC, 0x3C5, 0x300, 0x3CD, 0x3C9, 0x300, 0x3CE, 0x1F00,
+    0x345, 0x1F01, 0x345, 0x1F02, 0x345, 0x1F03, 0x345, 0x1F04, 0x345, 0x1F05,
+    0x345, 0x1F06, 0x345, 0x1F07, 0x345, 0x1F08, 0x345, 0x1F09, 0x345, 0x1F0A,
+    0x345, 0x1F0B, 0x345, 0x1F0C, 0x345, 0x1F0D, 0x345, 0x1F0E, 0x345, 0x1F0F,
+    0x345, 0x1F20, 0x345, 0x1F21, 0x345, 0x1F22, 0x345, 0x1F23, 0x345, 0x1F24,
+    0x345, 0x1F25, 0x345, 0x1F26, 0x345, 0x1F27, 0x345, 0x1F28, 0x345, 0x1F29,
+    0x345, 0x1F2A, 0x345, 0x1F2B, 0x345, 0x1F2C, 0x345, 0x1F2D, 0x345, 0x1F2E,
+    0x345, 0x1F2F, 0x345, 0x1F60, 0x345, 0x1F61, 0x345, 0x1F62, 0x345, 0x1F63,
+    0x345, 0x1F64, 0x345, 0x1F65, 0x345, 0x1F66, 0x345, 0x1F67, 0x345, 0x1F68,
+    0x345, 0x1F69, 0x345, 0x1F6A, 0x345, 0x1F6B, 0x345, 0x1F6C, 0x345, 0x1F6D,
+    0x345, 0x1F6E, 0x345, 0x1F6F, 0x345, 0x3B1, 0x306, 0x3B1, 0x304, 0x1F70,
+    0x345, 0x3B1, 0x345, 0x3AC, 0x345, 0x3B1, 0x342, 0x1FB6, 0x345, 0x391,
+    0x306, 0x391, 0x304, 0x391, 0x300, 0x386, 0x391, 0x345, 0x20, 0x313,
+    0x3B9, 0x20, 0x313, 0x20, 0x342, 0xA8, 0x342, 0x1F74, 0x345, 0x3B7,
+    0x345, 0x3AE, 0x345, 0x3B7, 0x342, 0x1FC6, 0x345, 0x395, 0x300, 0x388,
+    0x397, 0x300, 0x389, 0x397, 0x345, 0x1FBF, 0x300, 0x1FBF, 0x301, 0x1FBF,
+    0x342, 0x3B9, 0x306, 0x3B9, 0x304, 0x3CA, 0x300, 0x390, 0x3B9, 0x342,
+    0x3CA, 0x342, 0x399, 0x306, 0x399, 0x304, 0x399, 0x300, 0x38A, 0x1FFE,
+    0x300, 0x1FFE, 0x301, 0x1FFE, 0x342, 0x3C5, 0x306, 0x3C5, 0x304, 0x3CB,
+    0x300, 0x3B0, 0x3C1, 0x313, 0x3C1, 0x314, 0x3C5, 0x342, 0x3CB, 0x342,
+    0x3A5, 0x306, 0x3A5, 0x304, 0x3A5, 0x300, 0x38E, 0x3A1, 0x314, 0xA8,
+    0x300, 0x385, 0x60, 0x1F7C, 0x345, 0x3C9, 0x345, 0x3CE, 0x345, 0x3C9,
+    0x342, 0x1FF6, 0x345, 0x39F, 0x300, 0x38C, 0x3A9, 0x300, 0x38F, 0x3A9,
+    0x345, 0xB4, 0x20, 0x314, 0x2002, 0x2003, 0x20, 0x20, 0x20, 0x20,
+    0x20, 0x20, 0x20, 0x20, 0x20, 0x2010, 0x20, 0x333, 0x2E, 0x2E,
+    0x2E, 0x2E, 0x2E, 0x2E, 0x20, 0x2032, 0x2032, 0x2032, 0x2032, 0x2032,
+    0x2035, 0x2035, 0x2035, 0x2035, 0x2035, 0x21, 0x21, 0x20, 0x305, 0x3F,
+    0x3F, 0x3F, 0x21, 0x21, 0x3F, 0x2032, 0x2032, 0x2032, 0x2032, 0x20,
+    0x30, 0x69, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x2B, 0x2212,
+    0x3D, 0x28, 0x29, 0x6E, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x2B, 0x2212, 0x3D, 0x28, 0x29, 0x61,
+    0x65, 0x6F, 0x78, 0x259, 0x68, 0x6B, 0x6C, 0x6D, 0x6E, 0x70,
+    0x73, 0x74, 0x52, 0x73, 0x61, 0x2F, 0x63, 0x61, 0x2F, 0x73,
+    0x43, 0xB0, 0x43, 0x63, 0x2F, 0x6F, 0x63, 0x2F, 0x75, 0x190,
+    0xB0, 0x46, 0x67, 0x48, 0x48, 0x48, 0x68, 0x127, 0x49, 0x49,
+    0x4C, 0x6C, 0x4E, 0x4E, 0x6F, 0x50, 0x51, 0x52, 0x52, 0x52,
+    0x53, 0x4D, 0x54, 0x45, 0x4C, 0x54, 0x4D, 0x5A, 0x3A9, 0x5A,
+    0x4B, 0xC5, 0x42, 0x43, 0x65, 0x45, 0x46, 0x4D, 0x6F, 0x5D0,
+    0x5D1, 0x5D2, 0x5D3, 0x69, 0x46, 0x41, 0x58, 0x3C0, 0x3B3, 0x393,
+    0x3A0, 0x2211, 0x44, 0x64, 0x65, 0x69, 0x6A, 0x31, 0x2044, 0x37,
+    0x31, 0x2044, 0x39, 0x31, 0x2044, 0x31, 0x30, 0x31, 0x2044, 0x33,
+    0x32, 0x2044, 0x33, 0x31, 0x2044, 0x35, 0x32, 0x2044, 0x35, 0x33,
+    0x2044, 0x35, 0x34, 0x2044, 0x35, 0x31, 0x2044, 0x36, 0x35, 0x2044,
+    0x36, 0x31, 0x2044, 0x38, 0x33, 0x2044, 0x38, 0x35, 0x2044, 0x38,
+    0x37, 0x2044, 0x38, 0x31, 0x2044, 0x49, 0x49, 0x49, 0x49, 0x49,
+    0x49, 0x49, 0x56, 0x56, 0x56, 0x49, 0x56, 0x49, 0x49, 0x56,
+    0x49, 0x49, 0x49, 0x49, 0x58, 0x58, 0x58, 0x49, 0x58, 0x49,
+    0x49, 0x4C, 0x43, 0x44, 0x4D, 0x69, 0x69, 0x69, 0x69, 0x69,
+    0x69, 0x69, 0x76, 0x76, 0x76, 0x69, 0x76, 0x69, 0x69, 0x76,
+    0x69, 0x69, 0x69, 0x69, 0x78, 0x78, 0x78, 0x69, 0x78, 0x69,
+    0x69, 0x6C, 0x63, 0x64, 0x6D, 0x30, 0x2044, 0x33, 0x2190, 0x338,
+    0x2192, 0x338, 0x2194, 0x338, 0x21D0, 0x338, 0x21D4, 0x338, 0x21D2, 0x338,
+    0x2203, 0x338, 0x2208, 0x338, 0x220B, 0x338, 0x2223, 0x338, 0x2225, 0x338,
+    0x222B, 0x222B, 0x222B, 0x222B, 0x222B, 0x222E, 0x222E, 0x222E, 0x222E, 0x222E,
+    0x223C, 0x338, 0x2243, 0x338, 0x2245, 0x338, 0x2248, 0x338, 0x3D, 0x338,
+    0x2261, 0x338, 0x224D, 0x338, 0x3C, 0x338, 0x3E, 0x338, 0x2264, 0x338,
+    0x2265, 0x338, 0x2272, 0x338, 0x2273, 0x338, 0x2276, 0x338, 0x2277, 0x338,
+    0x227A, 0x338, 0x227B, 0x338, 0x2282, 0x338, 0x2283, 0x338, 0x2286, 0x338,
+    0x2287, 0x338, 0x22A2, 0x338, 0x22A8, 0x338, 0x22A9, 0x338, 0x22AB, 0x338,
+    0x227C, 0x338, 0x227D, 0x338, 0x2291, 0x338, 0x2292, 0x338, 0x22B2, 0x338,
+    0x22B3, 0x338, 0x22B4, 0x338, 0x22B5, 0x338, 0x3008, 0x3009, 0x31, 0x32,
+    0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x31, 0x30, 0x31,
+    0x31, 0x31, 0x32, 0x31, 0x33, 0x31, 0x34, 0x31, 0x35, 0x31,
+    0x36, 0x31, 0x37, 0x31, 0x38, 0x31, 0x39, 0x32, 0x30, 0x28,
+    0x31, 0x29, 0x28, 0x32, 0x29, 0x28, 0x33, 0x29, 0x28, 0x34,
+    0x29, 0x28, 0x35, 0x29, 0x28, 0x36, 0x29, 0x28, 0x37, 0x29,
+    0x28, 0x38, 0x29, 0x28, 0x39, 0x29, 0x28, 0x31, 0x30, 0x29,
+    0x28, 0x31, 0x31, 0x29, 0x28, 0x31, 0x32, 0x29, 0x28, 0x31,
+    0x33, 0x29, 0x28, 0x31, 0x34, 0x29, 0x28, 0x31, 0x35, 0x29,
+    0x28, 0x31, 0x36, 0x29, 0x28, 0x31, 0x37, 0x29, 0x28, 0x31,
+    0x38, 0x29, 0x28, 0x31, 0x39, 0x29, 0x28, 0x32, 0x30, 0x29,
+    0x31, 0x2E, 0x32, 0x2E, 0x33, 0x2E, 0x34, 0x2E, 0x35, 0x2E,
+    0x36, 0x2E, 0x37, 0x2E, 0x38, 0x2E, 0x39, 0x2E, 0x31, 0x30,
+    0x2E, 0x31, 0x31, 0x2E, 0x31, 0x32, 0x2E, 0x31, 0x33, 0x2E,
+    0x31, 0x34, 0x2E, 0x31, 0x35, 0x2E, 0x31, 0x36, 0x2E, 0x31,
+    0x37, 0x2E, 0x31, 0x38, 0x2E, 0x31, 0x39, 0x2E, 0x32, 0x30,
+    0x2E, 0x28, 0x61, 0x29, 0x28, 0x62, 0x29, 0x28, 0x63, 0x29,
+    0x28, 0x64, 0x29, 0x28, 0x65, 0x29, 0x28, 0x66, 0x29, 0x28,
+    0x67, 0x29, 0x28, 0x68, 0x29, 0x28, 0x69, 0x29, 0x28, 0x6A,
+    0x29, 0x28, 0x6B, 0x29, 0x28, 0x6C, 0x29, 0x28, 0x6D, 0x29,
+    0x28, 0x6E, 0x29, 0x28, 0x6F, 0x29, 0x28, 0x70, 0x29, 0x28,
+    0x71, 0x29, 0x28, 0x72, 0x29, 0x28, 0x73, 0x29, 0x28, 0x74,
+    0x29, 0x28, 0x75, 0x29, 0x28, 0x76, 0x29, 0x28, 0x77, 0x29,
+    0x28, 0x78, 0x29, 0x28, 0x79, 0x29, 0x28, 0x7A, 0x29, 0x41,
+    0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B,
+    0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55,
+    0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65,
+    0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F,
+    0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x30, 0x222B, 0x222B, 0x222B, 0x222B, 0x3A, 0x3A, 0x3D, 0x3D,
+    0x3D, 0x3D, 0x3D, 0x3D, 0x2ADD, 0x338, 0x6A, 0x56, 0x2D61, 0x6BCD,
+    0x9F9F, 0x4E00, 0x4E28, 0x4E36, 0x4E3F, 0x4E59, 0x4E85, 0x4E8C, 0x4EA0, 0x4EBA,
+    0x513F, 0x5165, 0x516B, 0x5182, 0x5196, 0x51AB, 0x51E0, 0x51F5, 0x5200, 0x529B,
+    0x52F9, 0x5315, 0x531A, 0x5338, 0x5341, 0x535C, 0x5369, 0x5382, 0x53B6, 0x53C8,
+    0x53E3, 0x56D7, 0x571F, 0x58EB, 0x5902, 0x590A, 0x5915, 0x5927, 0x5973, 0x5B50,
+    0x5B80, 0x5BF8, 0x5C0F, 0x5C22, 0x5C38, 0x5C6E, 0x5C71, 0x5DDB, 0x5DE5, 0x5DF1,
+    0x5DFE, 0x5E72, 0x5E7A, 0x5E7F, 0x5EF4, 0x5EFE, 0x5F0B, 0x5F13, 0x5F50, 0x5F61,
+    0x5F73, 0x5FC3, 0x6208, 0x6236, 0x624B, 0x652F, 0x6534, 0x6587, 0x6597, 0x65A4,
+    0x65B9, 0x65E0, 0x65E5, 0x66F0, 0x6708, 0x6728, 0x6B20, 0x6B62, 0x6B79, 0x6BB3,
+    0x6BCB, 0x6BD4, 0x6BDB, 0x6C0F, 0x6C14, 0x6C34, 0x706B, 0x722A, 0x7236, 0x723B,
+    0x723F, 0x7247, 0x7259, 0x725B, 0x72AC, 0x7384, 0x7389, 0x74DC, 0x74E6, 0x7518,
+    0x751F, 0x7528, 0x7530, 0x758B, 0x7592, 0x7676, 0x767D, 0x76AE, 0x76BF, 0x76EE,
+    0x77DB, 0x77E2, 0x77F3, 0x793A, 0x79B8, 0x79BE, 0x7A74, 0x7ACB, 0x7AF9, 0x7C73,
+    0x7CF8, 0x7F36, 0x7F51, 0x7F8A, 0x7FBD, 0x8001, 0x800C, 0x8012, 0x8033, 0x807F,
+    0x8089, 0x81E3, 0x81EA, 0x81F3, 0x81FC, 0x820C, 0x821B, 0x821F, 0x826E, 0x8272,
+    0x8278, 0x864D, 0x866B, 0x8840, 0x884C, 0x8863, 0x897E, 0x898B, 0x89D2, 0x8A00,
+    0x8C37, 0x8C46, 0x8C55, 0x8C78, 0x8C9D, 0x8D64, 0x8D70, 0x8DB3, 0x8EAB, 0x8ECA,
+    0x8F9B, 0x8FB0, 0x8FB5, 0x9091, 0x9149, 0x91C6, 0x91CC, 0x91D1, 0x9577, 0x9580,
+    0x961C, 0x96B6, 0x96B9, 0x96E8, 0x9751, 0x975E, 0x9762, 0x9769, 0x97CB, 0x97ED,
+    0x97F3, 0x9801, 0x98A8, 0x98DB, 0x98DF, 0x9996, 0x9999, 0x99AC, 0x9AA8, 0x9AD8,
+    0x9ADF, 0x9B25, 0x9B2F, 0x9B32, 0x9B3C, 0x9B5A, 0x9CE5, 0x9E75, 0x9E7F, 0x9EA5,
+    0x9EBB, 0x9EC3, 0x9ECD, 0x9ED1, 0x9EF9, 0x9EFD, 0x9F0E, 0x9F13, 0x9F20, 0x9F3B,
+    0x9F4A, 0x9F52, 0x9F8D, 0x9F9C, 0x9FA0, 0x20, 0x3012, 0x5341, 0x5344, 0x5345,
+    0x304B, 0x3099, 0x304D, 0x3099, 0x304F, 0x3099, 0x3051, 0x3099, 0x3053, 0x3099,
+    0x3055, 0x3099, 0x3057, 0x3099, 0x3059, 0x3099, 0x305B, 0x3099, 0x305D, 0x3099,
+    0x305F, 0x3099, 0x3061, 0x3099, 0x3064, 0x3099, 0x3066, 0x3099, 0x3068, 0x3099,
+    0x306F, 0x3099, 0x306F, 0x309A, 0x3072, 0x3099, 0x3072, 0x309A, 0x3075, 0x3099,
+    0x3075, 0x309A, 0x3078, 0x3099, 0x3078, 0x309A, 0x307B, 0x3099, 0x307B, 0x309A,
+    0x3046, 0x3099, 0x20, 0x3099, 0x20, 0x309A, 0x309D, 0x3099, 0x3088, 0x308A,
+    0x30AB, 0x3099, 0x30AD, 0x3099, 0x30AF, 0x3099, 0x30B1, 0x3099, 0x30B3, 0x3099,
+    0x30B5, 0x3099, 0x30B7, 0x3099, 0x30B9, 0x3099, 0x30BB, 0x3099, 0x30BD, 0x3099,
+    0x30BF, 0x3099, 0x30C1, 0x3099, 0x30C4, 0x3099, 0x30C6, 0x3099, 0x30C8, 0x3099,
+    0x30CF, 0x3099, 0x30CF, 0x309A, 0x30D2, 0x3099, 0x30D2, 0x309A, 0x30D5, 0x3099,
+    0x30D5, 0x309A, 0x30D8, 0x3099, 0x30D8, 0x309A, 0x30DB, 0x3099, 0x30DB, 0x309A,
+    0x30A6, 0x3099, 0x30EF, 0x3099, 0x30F0, 0x3099, 0x30F1, 0x3099, 0x30F2, 0x3099,
+    0x30FD, 0x3099, 0x30B3, 0x30C8, 0x1100, 0x1101, 0x11AA, 0x1102, 0x11AC, 0x11AD,
+    0x1103, 0x1104, 0x1105, 0x11B0, 0x11B1, 0x11B2, 0x11B3, 0x11B4, 0x11B5, 0x111A,
+    0x1106, 0x1107, 0x1108, 0x1121, 0x1109, 0x110A, 0x110B, 0x110C, 0x110D, 0x110E,
+    0x110F, 0x1110, 0x1111, 0x1112, 0x1161, 0x1162, 0x1163, 0x1164, 0x1165, 0x1166,
+    0x1167, 0x1168, 0x1169, 0x116A, 0x116B, 0x116C, 0x116D, 0x116E, 0x116F, 0x1170,
+    0x1171, 0x1172, 0x1173, 0x1174, 0x1175, 0x1160, 0x1114, 0x1115, 0x11C7, 0x11C8,
+    0x11CC, 0x11CE, 0x11D3, 0x11D7, 0x11D9, 0x111C, 0x11DD, 0x11DF, 0x111D, 0x111E,
+    0x1120, 0x1122, 0x1123, 0x1127, 0x1129, 0x112B, 0x112C, 0x112D, 0x112E, 0x112F,
+    0x1132, 0x1136, 0x1140, 0x1147, 0x114C, 0x11F1, 0x11F2, 0x1157, 0x1158, 0x1159,
+    0x1184, 0x1185, 0x1188, 0x1191, 0x1192, 0x1194, 0x119E, 0x11A1, 0x4E00, 0x4E8C,
+    0x4E09, 0x56DB, 0x4E0A, 0x4E2D, 0x4E0B, 0x7532, 0x4E59, 0x4E19, 0x4E01, 0x5929,
+    0x5730, 0x4EBA, 0x28, 0x1100, 0x29, 0x28, 0x1102, 0x29, 0x28, 0x1103,
+    0x29, 0x28, 0x1105, 0x29, 0x28, 0x1106, 0x29, 0x28, 0x1107, 0x29,
+    0x28, 0x1109, 0x29, 0x28, 0x110B, 0x29, 0x28, 0x110C, 0x29, 0x28,
+    0x110E, 0x29, 0x28, 0x110F, 0x29, 0x28, 0x1110, 0x29, 0x28, 0x1111,
+    0x29, 0x28, 0x1112, 0x29, 0x28, 0x1100, 0x1161, 0x29, 0x28, 0x1102,
+    0x1161, 0x29, 0x28, 0x1103, 0x1161, 0x29, 0x28, 0x1105, 0x1161, 0x29,
+    0x28, 0x1106, 0x1161, 0x29, 0x28, 0x1107, 0x1161, 0x29, 0x28, 0x1109,
+    0x1161, 0x29, 0x28, 0x110B, 0x1161, 0x29, 0x28, 0x110C, 0x1161, 0x29,
+    0x28, 0x110E, 0x1161, 0x29, 0x28, 0x110F, 0x1161, 0x29, 0x28, 0x1110,
+    0x1161, 0x29, 0x28, 0x1111, 0x1161, 0x29, 0x28, 0x1112, 0x1161, 0x29,
+    0x28, 0x110C, 0x116E, 0x29, 0x28, 0x110B, 0x1169, 0x110C, 0x1165, 0x11AB,
+    0x29, 0x28, 0x110B, 0x1169, 0x1112, 0x116E, 0x29, 0x28, 0x4E00, 0x29,
+    0x28, 0x4E8C, 0x29, 0x28, 0x4E09, 0x29, 0x28, 0x56DB, 0x29, 0x28,
+    0x4E94, 0x29, 0x28, 0x516D, 0x29, 0x28, 0x4E03, 0x29, 0x28, 0x516B,
+    0x29, 0x28, 0x4E5D, 0x29, 0x28, 0x5341, 0x29, 0x28, 0x6708, 0x29,
+    0x28, 0x706B, 0x29, 0x28, 0x6C34, 0x29, 0x28, 0x6728, 0x29, 0x28,
+    0x91D1, 0x29, 0x28, 0x571F, 0x29, 0x28, 0x65E5, 0x29, 0x28, 0x682A,
+    0x29, 0x28, 0x6709, 0x29, 0x28, 0x793E, 0x29, 0x28, 0x540D, 0x29,
+    0x28, 0x7279, 0x29, 0x28, 0x8CA1, 0x29, 0x28, 0x795D, 0x29, 0x28,
+    0x52B4, 0x29, 0x28, 0x4EE3, 0x29, 0x28, 0x547C, 0x29, 0x28, 0x5B66,
+    0x29, 0x28, 0x76E3, 0x29, 0x28, 0x4F01, 0x29, 0x28, 0x8CC7, 0x29,
+    0x28, 0x5354, 0x29, 0x28, 0x796D, 0x29, 0x28, 0x4F11, 0x29, 0x28,
+    0x81EA, 0x29, 0x28, 0x81F3, 0x29, 0x554F, 0x5E7C, 0x6587, 0x7B8F, 0x50,
+    0x54, 0x45, 0x32, 0x31, 0x32, 0x32, 0x32, 0x33, 0x32, 0x34,
+    0x32, 0x35, 0x32, 0x36, 0x32, 0x37, 0x32, 0x38, 0x32, 0x39,
+    0x33, 0x30, 0x33, 0x31, 0x33, 0x32, 0x33, 0x33, 0x33, 0x34,
+    0x33, 0x35, 0x1100, 0x1102, 0x1103, 0x1105, 0x1106, 0x1107, 0x1109, 0x110B,
+    0x110C, 0x110E, 0x110F, 0x1110, 0x1111, 0x1112, 0x1100, 0x1161, 0x1102, 0x1161,
+    0x1103, 0x1161, 0x1105, 0x1161, 0x1106, 0x1161, 0x1107, 0x1161, 0x1109, 0x1161,
+    0x110B, 0x1161, 0x110C, 0x1161, 0x110E, 0x1161, 0x110F, 0x1161, 0x1110, 0x1161,
+    0x1111, 0x1161, 0x1112, 0x1161, 0x110E, 0x1161, 0x11B7, 0x1100, 0x1169, 0x110C,
+    0x116E, 0x110B, 0x1174, 0x110B, 0x116E, 0x4E00, 0x4E8C, 0x4E09, 0x56DB, 0x4E94,
+    0x516D, 0x4E03, 0x516B, 0x4E5D, 0x5341, 0x6708, 0x706B, 0x6C34, 0x6728, 0x91D1,
+    0x571F, 0x65E5, 0x682A, 0x6709, 0x793E, 0x540D, 0x7279, 0x8CA1, 0x795D, 0x52B4,
+    0x79D8, 0x7537, 0x5973, 0x9069, 0x512A, 0x5370, 0x6CE8, 0x9805, 0x4F11, 0x5199,
+    0x6B63, 0x4E0A, 0x4E2D, 0x4E0B, 0x5DE6, 0x53F3, 0x533B, 0x5B97, 0x5B66, 0x76E3,
+    0x4F01, 0x8CC7, 0x5354, 0x591C, 0x33, 0x36, 0x33, 0x37, 0x33, 0x38,
+    0x33, 0x39, 0x34, 0x30, 0x34, 0x31, 0x34, 0x32, 0x34, 0x33,
+    0x34, 0x34, 0x34, 0x35, 0x34, 0x36, 0x34, 0x37, 0x34, 0x38,
+    0x34, 0x39, 0x35, 0x30, 0x31, 0x6708, 0x32, 0x6708, 0x33, 0x6708,
+    0x34, 0x6708, 0x35, 0x6708, 0x36, 0x6708, 0x37, 0x6708, 0x38, 0x6708,
+    0x39, 0x6708, 0x31, 0x30, 0x6708, 0x31, 0x31, 0x6708, 0x31, 0x32,
+    0x6708, 0x48, 0x67, 0x65, 0x72, 0x67, 0x65, 0x56, 0x4C, 0x54,
+    0x44, 0x30A2, 0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF, 0x30B1,
+    0x30B3, 0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6,
+    0x30C8, 0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8,
+    0x30DB, 0x30DE, 0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9,
+    0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EF, 0x30F0, 0x30F1, 0x30F2, 0x4EE4, 0x548C,
+    0x30A2, 0x30D1, 0x30FC, 0x30C8, 0x30A2, 0x30EB, 0x30D5, 0x30A1, 0x30A2, 0x30F3,
+    0x30DA, 0x30A2, 0x30A2, 0x30FC, 0x30EB, 0x30A4, 0x30CB, 0x30F3, 0x30B0, 0x30A4,
+    0x30F3, 0x30C1, 0x30A6, 0x30A9, 0x30F3, 0x30A8, 0x30B9, 0x30AF, 0x30FC, 0x30C9,
+    0x30A8, 0x30FC, 0x30AB, 0x30FC, 0x30AA, 0x30F3, 0x30B9, 0x30AA, 0x30FC, 0x30E0,
+    0x30AB, 0x30A4, 0x30EA, 0x30AB, 0x30E9, 0x30C3, 0x30C8, 0x30AB, 0x30ED, 0x30EA,
+    0x30FC, 0x30AC, 0x30ED, 0x30F3, 0x30AC, 0x30F3, 0x30DE, 0x30AE, 0x30AC, 0x30AE,
+    0x30CB, 0x30FC, 0x30AD, 0x30E5, 0x30EA, 0x30FC, 0x30AE, 0x30EB, 0x30C0, 0x30FC,
+    0x30AD, 0x30ED, 0x30AD, 0x30ED, 0x30B0, 0x30E9, 0x30E0, 0x30AD, 0x30ED, 0x30E1,
+    0x30FC, 0x30C8, 0x30EB, 0x30AD, 0x30ED, 0x30EF, 0x30C3, 0x30C8, 0x30B0, 0x30E9,
+    0x30E0, 0x30B0, 0x30E9, 0x30E0, 0x30C8, 0x30F3, 0x30AF, 0x30EB, 0x30BC, 0x30A4,
+    0x30ED, 0x30AF, 0x30ED, 0x30FC, 0x30CD, 0x30B1, 0x30FC, 0x30B9, 0x30B3, 0x30EB,
+    0x30CA, 0x30B3, 0x30FC, 0x30DD, 0x30B5, 0x30A4, 0x30AF, 0x30EB, 0x30B5, 0x30F3,
+    0x30C1, 0x30FC, 0x30E0, 0x30B7, 0x30EA, 0x30F3, 0x30B0, 0x30BB, 0x30F3, 0x30C1,
+    0x30BB, 0x30F3, 0x30C8, 0x30C0, 0x30FC, 0x30B9, 0x30C7, 0x30B7, 0x30C9, 0x30EB,
+    0x30C8, 0x30F3, 0x30CA, 0x30CE, 0x30CE, 0x30C3, 0x30C8, 0x30CF, 0x30A4, 0x30C4,
+    0x30D1, 0x30FC, 0x30BB, 0x30F3, 0x30C8, 0x30D1, 0x30FC, 0x30C4, 0x30D0, 0x30FC,
+    0x30EC, 0x30EB, 0x30D4, 0x30A2, 0x30B9, 0x30C8, 0x30EB, 0x30D4, 0x30AF, 0x30EB,
+    0x30D4, 0x30B3, 0x30D3, 0x30EB, 0x30D5, 0x30A1, 0x30E9, 0x30C3, 0x30C9, 0x30D5,
+    0x30A3, 0x30FC, 0x30C8, 0x30D6, 0x30C3, 0x30B7, 0x30A7, 0x30EB, 0x30D5, 0x30E9,
+    0x30F3, 0x30D8, 0x30AF, 0x30BF, 0x30FC, 0x30EB, 0x30DA, 0x30BD, 0x30DA, 0x30CB,
+    0x30D2, 0x30D8, 0x30EB, 0x30C4, 0x30DA, 0x30F3, 0x30B9, 0x30DA, 0x30FC, 0x30B8,
+    0x30D9, 0x30FC, 0x30BF, 0x30DD, 0x30A4, 0x30F3, 0x30C8, 0x30DC, 0x30EB, 0x30C8,
+    0x30DB, 0x30F3, 0x30DD, 0x30F3, 0x30C9, 0x30DB, 0x30FC, 0x30EB, 0x30DB, 0x30FC,
+    0x30F3, 0x30DE, 0x30A4, 0x30AF, 0x30ED, 0x30DE, 0x30A4, 0x30EB, 0x30DE, 0x30C3,
+    0x30CF, 0x30DE, 0x30EB, 0x30AF, 0x30DE, 0x30F3, 0x30B7, 0x30E7, 0x30F3, 0x30DF,
+    0x30AF, 0x30ED, 0x30F3, 0x30DF, 0x30EA, 0x30DF, 0x30EA, 0x30D0, 0x30FC, 0x30EB,
+    0x30E1, 0x30AC, 0x30E1, 0x30AC, 0x30C8, 0x30F3, 0x30E1, 0x30FC, 0x30C8, 0x30EB,
+    0x30E4, 0x30FC, 0x30C9, 0x30E4, 0x30FC, 0x30EB, 0x30E6, 0x30A2, 0x30F3, 0x30EA,
+    0x30C3, 0x30C8, 0x30EB, 0x30EA, 0x30E9, 0x30EB, 0x30D4, 0x30FC, 0x30EB, 0x30FC,
+    0x30D6, 0x30EB, 0x30EC, 0x30E0, 0x30EC, 0x30F3, 0x30C8, 0x30B2, 0x30F3, 0x30EF,
+    0x30C3, 0x30C8, 0x30, 0x70B9, 0x31, 0x70B9, 0x32, 0x70B9, 0x33, 0x70B9,
+    0x34, 0x70B9, 0x35, 0x70B9, 0x36, 0x70B9, 0x37, 0x70B9, 0x38, 0x70B9,
+    0x39, 0x70B9, 0x31, 0x30, 0x70B9, 0x31, 0x31, 0x70B9, 0x31, 0x32,
+    0x70B9, 0x31, 0x33, 0x70B9, 0x31, 0x34, 0x70B9, 0x31, 0x35, 0x70B9,
+    0x31, 0x36, 0x70B9, 0x31, 0x37, 0x70B9, 0x31, 0x38, 0x70B9, 0x31,
+    0x39, 0x70B9, 0x32, 0x30, 0x70B9, 0x32, 0x31, 0x70B9, 0x32, 0x32,
+    0x70B9, 0x32, 0x33, 0x70B9, 0x32, 0x34, 0x70B9, 0x68, 0x50, 0x61,
+    0x64, 0x61, 0x41, 0x55, 0x62, 0x61, 0x72, 0x6F, 0x56, 0x70,
+    0x63, 0x64, 0x6D, 0x64, 0x6D, 0xB2, 0x64, 0x6D, 0xB3, 0x49,
+    0x55, 0x5E73, 0x6210, 0x662D, 0x548C, 0x5927, 0x6B63, 0x660E, 0x6CBB, 0x682A,
+    0x5F0F, 0x4F1A, 0x793E, 0x70, 0x41, 0x6E, 0x41, 0x3BC, 0x41, 0x6D,
+    0x41, 0x6B, 0x41, 0x4B, 0x42, 0x4D, 0x42, 0x47, 0x42, 0x63,
+    0x61, 0x6C, 0x6B, 0x63, 0x61, 0x6C, 0x70, 0x46, 0x6E, 0x46,
+    0x3BC, 0x46, 0x3BC, 0x67, 0x6D, 0x67, 0x6B, 0x67, 0x48, 0x7A,
+    0x6B, 0x48, 0x7A, 0x4D, 0x48, 0x7A, 0x47, 0x48, 0x7A, 0x54,
+    0x48, 0x7A, 0x3BC, 0x2113, 0x6D, 0x2113, 0x64, 0x2113, 0x6B, 0x2113,
+    0x66, 0x6D, 0x6E, 0x6D, 0x3BC, 0x6D, 0x6D, 0x6D, 0x63, 0x6D,
+    0x6B, 0x6D, 0x6D, 0x6D, 0xB2, 0x63, 0x6D, 0xB2, 0x6D, 0xB2,
+    0x6B, 0x6D, 0xB2, 0x6D, 0x6D, 0xB3, 0x63, 0x6D, 0xB3, 0x6D,
+    0xB3, 0x6B, 0x6D, 0xB3, 0x6D, 0x2215, 0x73, 0x6D, 0x2215, 0x73,
+    0xB2, 0x50, 0x61, 0x6B, 0x50, 0x61, 0x4D, 0x50, 0x61, 0x47,
+    0x50, 0x61, 0x72, 0x61, 0x64, 0x72, 0x61, 0x64, 0x2215, 0x73,
+    0x72, 0x61, 0x64, 0x2215, 0x73, 0xB2, 0x70, 0x73, 0x6E, 0x73,
+    0x3BC, 0x73, 0x6D, 0x73, 0x70, 0x56, 0x6E, 0x56, 0x3BC, 0x56,
+    0x6D, 0x56, 0x6B, 0x56, 0x4D, 0x56, 0x70, 0x57, 0x6E, 0x57,
+    0x3BC, 0x57, 0x6D, 0x57, 0x6B, 0x57, 0x4D, 0x57, 0x6B, 0x3A9,
+    0x4D, 0x3A9, 0x61, 0x2E, 0x6D, 0x2E, 0x42, 0x71, 0x63, 0x63,
+    0x63, 0x64, 0x43, 0x2215, 0x6B, 0x67, 0x43, 0x6F, 0x2E, 0x64,
+    0x42, 0x47, 0x79, 0x68, 0x61, 0x48, 0x50, 0x69, 0x6E, 0x4B,
+    0x4B, 0x4B, 0x4D, 0x6B, 0x74, 0x6C, 0x6D, 0x6C, 0x6E, 0x6C,
+    0x6F, 0x67, 0x6C, 0x78, 0x6D, 0x62, 0x6D, 0x69, 0x6C, 0x6D,
+    0x6F, 0x6C, 0x50, 0x48, 0x70, 0x2E, 0x6D, 0x2E, 0x50, 0x50,
+    0x4D, 0x50, 0x52, 0x73, 0x72, 0x53, 0x76, 0x57, 0x62, 0x56,
+    0x2215, 0x6D, 0x41, 0x2215, 0x6D, 0x31, 0x65E5, 0x32, 0x65E5, 0x33,
+    0x65E5, 0x34, 0x65E5, 0x35, 0x65E5, 0x36, 0x65E5, 0x37, 0x65E5, 0x38,
+    0x65E5, 0x39, 0x65E5, 0x31, 0x30, 0x65E5, 0x31, 0x31, 0x65E5, 0x31,
+    0x32, 0x65E5, 0x31, 0x33, 0x65E5, 0x31, 0x34, 0x65E5, 0x31, 0x35,
+    0x65E5, 0x31, 0x36, 0x65E5, 0x31, 0x37, 0x65E5, 0x31, 0x38, 0x65E5,
+    0x31, 0x39, 0x65E5, 0x32, 0x30, 0x65E5, 0x32, 0x31, 0x65E5, 0x32,
+    0x32, 0x65E5, 0x32, 0x33, 0x65E5, 0x32, 0x34, 0x65E5, 0x32, 0x35,
+    0x65E5, 0x32, 0x36, 0x65E5, 0x32, 0x37, 0x65E5, 0x32, 0x38, 0x65E5,
+    0x32, 0x39, 0x65E5, 0x33, 0x30, 0x65E5, 0x33, 0x31, 0x65E5, 0x67,
+    0x61, 0x6C, 0x44A, 0x44C, 0xA76F, 0x43, 0x46, 0x51, 0x126, 0x153,
+    0xA727, 0xAB37, 0x26B, 0xAB52, 0x28D, 0x8C48, 0x66F4, 0x8ECA, 0x8CC8, 0x6ED1,
+    0x4E32, 0x53E5, 0x9F9C, 0x9F9C, 0x5951, 0x91D1, 0x5587, 0x5948, 0x61F6, 0x7669,
+    0x7F85, 0x863F, 0x87BA, 0x88F8, 0x908F, 0x6A02, 0x6D1B, 0x70D9, 0x73DE, 0x843D,
+    0x916A, 0x99F1, 0x4E82, 0x5375, 0x6B04, 0x721B, 0x862D, 0x9E1E, 0x5D50, 0x6FEB,
+    0x85CD, 0x8964, 0x62C9, 0x81D8, 0x881F, 0x5ECA, 0x6717, 0x6D6A, 0x72FC, 0x90CE,
+    0x4F86, 0x51B7, 0x52DE, 0x64C4, 0x6AD3, 0x7210, 0x76E7, 0x8001, 0x8606, 0x865C,
+    0x8DEF, 0x9732, 0x9B6F, 0x9DFA, 0x788C, 0x797F, 0x7DA0, 0x83C9, 0x9304, 0x9E7F,
+    0x8AD6, 0x58DF, 0x5F04, 0x7C60, 0x807E, 0x7262, 0x78CA, 0x8CC2, 0x96F7, 0x58D8,
+    0x5C62, 0x6A13, 0x6DDA, 0x6F0F, 0x7D2F, 0x7E37, 0x964B, 0x52D2, 0x808B, 0x51DC,
+    0x51CC, 0x7A1C, 0x7DBE, 0x83F1, 0x9675, 0x8B80, 0x62CF, 0x6A02, 0x8AFE, 0x4E39,
+    0x5BE7, 0x6012, 0x7387, 0x7570, 0x5317, 0x78FB, 0x4FBF, 0x5FA9, 0x4E0D, 0x6CCC,
+    0x6578, 0x7D22, 0x53C3, 0x585E, 0x7701, 0x8449, 0x8AAA, 0x6BBA, 0x8FB0, 0x6C88,
+    0x62FE, 0x82E5, 0x63A0, 0x7565, 0x4EAE, 0x5169, 0x51C9, 0x6881, 0x7CE7, 0x826F,
+    0x8AD2, 0x91CF, 0x52F5, 0x5442, 0x5973, 0x5EEC, 0x65C5, 0x6FFE, 0x792A, 0x95AD,
+    0x9A6A, 0x9E97, 0x9ECE, 0x529B, 0x66C6, 0x6B77, 0x8F62, 0x5E74, 0x6190, 0x6200,
+    0x649A, 0x6F23, 0x7149, 0x7489, 0x79CA, 0x7DF4, 0x806F, 0x8F26, 0x84EE, 0x9023,
+    0x934A, 0x5217, 0x52A3, 0x54BD, 0x70C8, 0x88C2, 0x8AAA, 0x5EC9, 0x5FF5, 0x637B,
+    0x6BAE, 0x7C3E, 0x7375, 0x4EE4, 0x56F9, 0x5BE7, 0x5DBA, 0x601C, 0x73B2, 0x7469,
+    0x7F9A, 0x8046, 0x9234, 0x96F6, 0x9748, 0x9818, 0x4F8B, 0x79AE, 0x91B4, 0x96B8,
+    0x60E1, 0x4E86, 0x50DA, 0x5BEE, 0x5C3F, 0x6599, 0x6A02, 0x71CE, 0x7642, 0x84FC,
+    0x907C, 0x9F8D, 0x6688, 0x962E, 0x5289, 0x677B, 0x67F3, 0x6D41, 0x6E9C, 0x7409,
+    0x7559, 0x786B, 0x7D10, 0x985E, 0x516D, 0x622E, 0x9678, 0x502B, 0x5D19, 0x6DEA,
+    0x8F2A, 0x5F8B, 0x6144, 0x6817, 0x7387, 0x9686, 0x5229, 0x540F, 0x5C65, 0x6613,
+    0x674E, 0x68A8, 0x6CE5, 0x7406, 0x75E2, 0x7F79, 0x88CF, 0x88E1, 0x91CC, 0x96E2,
+    0x533F, 0x6EBA, 0x541D, 0x71D0, 0x7498, 0x85FA, 0x96A3, 0x9C57, 0x9E9F, 0x6797,
+    0x6DCB, 0x81E8, 0x7ACB, 0x7B20, 0x7C92, 0x72C0, 0x7099, 0x8B58, 0x4EC0, 0x8336,
+    0x523A, 0x5207, 0x5EA6, 0x62D3, 0x7CD6, 0x5B85, 0x6D1E, 0x66B4, 0x8F3B, 0x884C,
+    0x964D, 0x898B, 0x5ED3, 0x5140, 0x55C0, 0x585A, 0x6674, 0x51DE, 0x732A, 0x76CA,
+    0x793C, 0x795E, 0x7965, 0x798F, 0x9756, 0x7CBE, 0x7FBD, 0x8612, 0x8AF8, 0x9038,
+    0x90FD, 0x98EF, 0x98FC, 0x9928, 0x9DB4, 0x90DE, 0x96B7, 0x4FAE, 0x50E7, 0x514D,
+    0x52C9, 0x52E4, 0x5351, 0x559D, 0x5606, 0x5668, 0x5840, 0x58A8, 0x5C64, 0x5C6E,
+    0x6094, 0x6168, 0x618E, 0x61F2, 0x654F, 0x65E2, 0x6691, 0x6885, 0x6D77, 0x6E1A,
+    0x6F22, 0x716E, 0x722B, 0x7422, 0x7891, 0x793E, 0x7949, 0x7948, 0x7950, 0x7956,
+    0x795D, 0x798D, 0x798E, 0x7A40, 0x7A81, 0x7BC0, 0x7DF4, 0x7E09, 0x7E41, 0x7F72,
+    0x8005, 0x81ED, 0x8279, 0x8279, 0x8457, 0x8910, 0x8996, 0x8B01, 0x8B39, 0x8CD3,
+    0x8D08, 0x8FB6, 0x9038, 0x96E3, 0x97FF, 0x983B, 0x6075, 0x242EE, 0x8218, 0x4E26,
+    0x51B5, 0x5168, 0x4F80, 0x5145, 0x5180, 0x52C7, 0x52FA, 0x559D, 0x5555, 0x5599,
+    0x55E2, 0x585A, 0x58B3, 0x5944, 0x5954, 0x5A62, 0x5B28, 0x5ED2, 0x5ED9, 0x5F69,
+    0x5FAD, 0x60D8, 0x614E, 0x6108, 0x618E, 0x6160, 0x61F2, 0x6234, 0x63C4, 0x641C,
+    0x6452, 0x6556, 0x6674, 0x6717, 0x671B, 0x6756, 0x6B79, 0x6BBA, 0x6D41, 0x6EDB,
+    0x6ECB, 0x6F22, 0x701E, 0x716E, 0x77A7, 0x7235, 0x72AF, 0x732A, 0x7471, 0x7506,
+    0x753B, 0x761D, 0x761F, 0x76CA, 0x76DB, 0x76F4, 0x774A, 0x7740, 0x78CC, 0x7AB1,
+    0x7BC0, 0x7C7B, 0x7D5B, 0x7DF4, 0x7F3E, 0x8005, 0x8352, 0x83EF, 0x8779, 0x8941,
+    0x8986, 0x8996, 0x8ABF, 0x8AF8, 0x8ACB, 0x8B01, 0x8AFE, 0x8AED, 0x8B39, 0x8B8A,
+    0x8D08, 0x8F38, 0x9072, 0x9199, 0x9276, 0x967C, 0x96E3, 0x9756, 0x97DB, 0x97FF,
+    0x980B, 0x983B, 0x9B12, 0x9F9C, 0x2284A, 0x22844, 0x233D5, 0x3B9D, 0x4018, 0x4039,
+    0x25249, 0x25CD0, 0x27ED3, 0x9F43, 0x9F8E, 0x66, 0x66, 0x66, 0x69, 0x66,
+    0x6C, 0x66, 0x66, 0x69, 0x66, 0x66, 0x6C, 0x17F, 0x74, 0x73,
+    0x74, 0x574, 0x576, 0x574, 0x565, 0x574, 0x56B, 0x57E, 0x576, 0x574,
+    0x56D, 0x5D9, 0x5B4, 0x5F2, 0x5B7, 0x5E2, 0x5D0, 0x5D3, 0x5D4, 0x5DB,
+    0x5DC, 0x5DD, 0x5E8, 0x5EA, 0x2B, 0x5E9, 0x5C1, 0x5E9, 0x5C2, 0xFB49,
+    0x5C1, 0xFB49, 0x5C2, 0x5D0, 0x5B7, 0x5D0, 0x5B8, 0x5D0, 0x5BC, 0x5D1,
+    0x5BC, 0x5D2, 0x5BC, 0x5D3, 0x5BC, 0x5D4, 0x5BC, 0x5D5, 0x5BC, 0x5D6,
+    0x5BC, 0x5D8, 0x5BC, 0x5D9, 0x5BC, 0x5DA, 0x5BC, 0x5DB, 0x5BC, 0x5DC,
+    0x5BC, 0x5DE, 0x5BC, 0x5E0, 0x5BC, 0x5E1, 0x5BC, 0x5E3, 0x5BC, 0x5E4,
+    0x5BC, 0x5E6, 0x5BC, 0x5E7, 0x5BC, 0x5E8, 0x5BC, 0x5E9, 0x5BC, 0x5EA,
+    0x5BC, 0x5D5, 0x5B9, 0x5D1, 0x5BF, 0x5DB, 0x5BF, 0x5E4, 0x5BF, 0x5D0,
+    0x5DC, 0x671, 0x671, 0x67B, 0x67B, 0x67B, 0x67B, 0x67E, 0x67E, 0x67E,
+    0x67E, 0x680, 0x680, 0x680, 0x680, 0x67A, 0x67A, 0x67A, 0x67A, 0x67F,
+    0x67F, 0x67F, 0x67F, 0x679, 0x679, 0x679, 0x679, 0x6A4, 0x6A4, 0x6A4,
+    0x6A4, 0x6A6, 0x6A6, 0x6A6, 0x6A6, 0x684, 0x684, 0x684, 0x684, 0x683,
+    0x683, 0x683, 0x683, 0x686, 0x686, 0x686, 0x686, 0x687, 0x687, 0x687,
+    0x687, 0x68D, 0x68D, 0x68C, 0x68C, 0x68E, 0x68E, 0x688, 0x688, 0x698,
+    0x698, 0x691, 0x691, 0x6A9, 0x6A9, 0x6A9, 0x6A9, 0x6AF, 0x6AF, 0x6AF,
+    0x6AF, 0x6B3, 0x6B3, 0x6B3, 0x6B3, 0x6B1, 0x6B1, 0x6B1, 0x6B1, 0x6BA,
+    0x6BA, 0x6BB, 0x6BB, 0x6BB, 0x6BB, 0x6C0, 0x6C0, 0x6C1, 0x6C1, 0x6C1,
+    0x6C1, 0x6BE, 0x6BE, 0x6BE, 0x6BE, 0x6D2, 0x6D2, 0x6D3, 0x6D3, 0x6AD,
+    0x6AD, 0x6AD, 0x6AD, 0x6C7, 0x6C7, 0x6C6, 0x6C6, 0x6C8, 0x6C8, 0x677,
+    0x6CB, 0x6CB, 0x6C5, 0x6C5, 0x6C9, 0x6C9, 0x6D0, 0x6D0, 0x6D0, 0x6D0,
+    0x649, 0x649, 0x626, 0x627, 0x626, 0x627, 0x626, 0x6D5, 0x626, 0x6D5,
+    0x626, 0x648, 0x626, 0x648, 0x626, 0x6C7, 0x626, 0x6C7, 0x626, 0x6C6,
+    0x626, 0x6C6, 0x626, 0x6C8, 0x626, 0x6C8, 0x626, 0x6D0, 0x626, 0x6D0,
+    0x626, 0x6D0, 0x626, 0x649, 0x626, 0x649, 0x626, 0x649, 0x6CC, 0x6CC,
+    0x6CC, 0x6CC, 0x626, 0x62C, 0x626, 0x62D, 0x626, 0x645, 0x626, 0x649,
+    0x626, 0x64A, 0x628, 0x62C, 0x628, 0x62D, 0x628, 0x62E, 0x628, 0x645,
+    0x628, 0x649, 0x628, 0x64A, 0x62A, 0x62C, 0x62A, 0x62D, 0x62A, 0x62E,
+    0x62A, 0x645, 0x62A, 0x649, 0x62A, 0x64A, 0x62B, 0x62C, 0x62B, 0x645,
+    0x62B, 0x649, 0x62B, 0x64A, 0x62C, 0x62D, 0x62C, 0x645, 0x62D, 0x62C,
+    0x62D, 0x645, 0x62E, 0x62C, 0x62E, 0x62D, 0x62E, 0x645, 0x633, 0x62C,
+    0x633, 0x62D, 0x633, 0x62E, 0x633, 0x645, 0x635, 0x62D, 0x635, 0x645,
+    0x636, 0x62C, 0x636, 0x62D, 0x636, 0x62E, 0x636, 0x645, 0x637, 0x62D,
+    0x637, 0x645, 0x638, 0x645, 0x639, 0x62C, 0x639, 0x645, 0x63A, 0x62C,
+    0x63A, 0x645, 0x641, 0x62C, 0x641, 0x62D, 0x641, 0x62E, 0x641, 0x645,
+    0x641, 0x649, 0x641, 0x64A, 0x642, 0x62D, 0x642, 0x645, 0x642, 0x649,
+    0x642, 0x64A, 0x643, 0x627, 0x643, 0x62C, 0x643, 0x62D, 0x643, 0x62E,
+    0x643, 0x644, 0x643, 0x645, 0x643, 0x649, 0x643, 0x64A, 0x644, 0x62C,
+    0x644, 0x62D, 0x644, 0x62E, 0x644, 0x645, 0x644, 0x649, 0x644, 0x64A,
+    0x645, 0x62C, 0x645, 0x62D, 0x645, 0x62E, 0x645, 0x645, 0x645, 0x649,
+    0x645, 0x64A, 0x646, 0x62C, 0x646, 0x62D, 0x646, 0x62E, 0x646, 0x645,
+    0x646, 0x649, 0x646, 0x64A, 0x647, 0x62C, 0x647, 0x645, 0x647, 0x649,
+    0x647, 0x64A, 0x64A, 0x62C, 0x64A, 0x62D, 0x64A, 0x62E, 0x64A, 0x645,
+    0x64A, 0x649, 0x64A, 0x64A, 0x630, 0x670, 0x631, 0x670, 0x649, 0x670,
+    0x20, 0x64C, 0x651, 0x20, 0x64D, 0x651, 0x20, 0x64E, 0x651, 0x20,
+    0x64F, 0x651, 0x20, 0x650, 0x651, 0x20, 0x651, 0x670, 0x626, 0x631,
+    0x626, 0x632, 0x626, 0x645, 0x626, 0x646, 0x626, 0x649, 0x626, 0x64A,
+    0x628, 0x631, 0x628, 0x632, 0x628, 0x645, 0x628, 0x646, 0x628, 0x649,
+    0x628, 0x64A, 0x62A, 0x631, 0x62A, 0x632, 0x62A, 0x645, 0x62A, 0x646,
+    0x62A, 0x649, 0x62A, 0x64A, 0x62B, 0x631, 0x62B, 0x632, 0x62B, 0x645,
+    0x62B, 0x646, 0x62B, 0x649, 0x62B, 0x64A, 0x641, 0x649, 0x641, 0x64A,
+    0x642, 0x649, 0x642, 0x64A, 0x643, 0x627, 0x643, 0x644, 0x643, 0x645,
+    0x643, 0x649, 0x643, 0x64A, 0x644, 0x645, 0x644, 0x649, 0x644, 0x64A,
+    0x645, 0x627, 0x645, 0x645, 0x646, 0x631, 0x646, 0x632, 0x646, 0x645,
+    0x646, 0x646, 0x646, 0x649, 0x646, 0x64A, 0x649, 0x670, 0x64A, 0x631,
+    0x64A, 0x632, 0x64A, 0x645, 0x64A, 0x646, 0x64A, 0x649, 0x64A, 0x64A,
+    0x626, 0x62C, 0x626, 0x62D, 0x626, 0x62E, 0x626, 0x645, 0x626, 0x647,
+    0x628, 0x62C, 0x628, 0x62D, 0x628, 0x62E, 0x628, 0x645, 0x628, 0x647,
+    0x62A, 0x62C, 0x62A, 0x62D, 0x62A, 0x62E, 0x62A, 0x645, 0x62A, 0x647,
+    0x62B, 0x645, 0x62C, 0x62D, 0x62C, 0x645, 0x62D, 0x62C, 0x62D, 0x645,
+    0x62E, 0x62C, 0x62E, 0x645, 0x633, 0x62C, 0x633, 0x62D, 0x633, 0x62E,
+    0x633, 0x645, 0x635, 0x62D, 0x635, 0x62E, 0x635, 0x645, 0x636, 0x62C,
+    0x636, 0x62D, 0x636, 0x62E, 0x636, 0x645, 0x637, 0x62D, 0x638, 0x645,
+    0x639, 0x62C, 0x639, 0x645, 0x63A, 0x62C, 0x63A, 0x645, 0x641, 0x62C,
+    0x641, 0x62D, 0x641, 0x62E, 0x641, 0x645, 0x642, 0x62D, 0x642, 0x645,
+    0x643, 0x62C, 0x643, 0x62D, 0x643, 0x62E, 0x643, 0x644, 0x643, 0x645,
+    0x644, 0x62C, 0x644, 0x62D, 0x644, 0x62E, 0x644, 0x645, 0x644, 0x647,
+    0x645, 0x62C, 0x645, 0x62D, 0x645, 0x62E, 0x645, 0x645, 0x646, 0x62C,
+    0x646, 0x62D, 0x646, 0x62E, 0x646, 0x645, 0x646, 0x647, 0x647, 0x62C,
+    0x647, 0x645, 0x647, 0x670, 0x64A, 0x62C, 0x64A, 0x62D, 0x64A, 0x62E,
+    0x64A, 0x645, 0x64A, 0x647, 0x626, 0x645, 0x626, 0x647, 0x628, 0x645,
+    0x628, 0x647, 0x62A, 0x645, 0x62A, 0x647, 0x62B, 0x645, 0x62B, 0x647,
+    0x633, 0x645, 0x633, 0x647, 0x634, 0x645, 0x634, 0x647, 0x643, 0x644,
+    0x643, 0x645, 0x644, 0x645, 0x646, 0x645, 0x646, 0x647, 0x64A, 0x645,
+    0x64A, 0x647, 0x640, 0x64E, 0x651, 0x640, 0x64F, 0x651, 0x640, 0x650,
+    0x651, 0x637, 0x649, 0x637, 0x64A, 0x639, 0x649, 0x639, 0x64A, 0x63A,
+    0x649, 0x63A, 0x64A, 0x633, 0x649, 0x633, 0x64A, 0x634, 0x649, 0x634,
+    0x64A, 0x62D, 0x649, 0x62D, 0x64A, 0x62C, 0x649, 0x62C, 0x64A, 0x62E,
+    0x649, 0x62E, 0x64A, 0x635, 0x649, 0x635, 0x64A, 0x636, 0x649, 0x636,
+    0x64A, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E, 0x634, 0x645, 0x634,
+    0x631, 0x633, 0x631, 0x635, 0x631, 0x636, 0x631, 0x637, 0x649, 0x637,
+    0x64A, 0x639, 0x649, 0x639, 0x64A, 0x63A, 0x649, 0x63A, 0x64A, 0x633,
+    0x649, 0x633, 0x64A, 0x634, 0x649, 0x634, 0x64A, 0x62D, 0x649, 0x62D,
+    0x64A, 0x62C, 0x649, 0x62C, 0x64A, 0x62E, 0x649, 0x62E, 0x64A, 0x635,
+    0x649, 0x635, 0x64A, 0x636, 0x649, 0x636, 0x64A, 0x634, 0x62C, 0x634,
+    0x62D, 0x634, 0x62E, 0x634, 0x645, 0x634, 0x631, 0x633, 0x631, 0x635,
+    0x631, 0x636, 0x631, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E, 0x634,
+    0x645, 0x633, 0x647, 0x634, 0x647, 0x637, 0x645, 0x633, 0x62C, 0x633,
+    0x62D, 0x633, 0x62E, 0x634, 0x62C, 0x634, 0x62D, 0x634, 0x62E, 0x637,
+    0x645, 0x638, 0x645, 0x627, 0x64B, 0x627, 0x64B, 0x62A, 0x62C, 0x645,
+    0x62A, 0x62D, 0x62C, 0x62A, 0x62D, 0x62C, 0x62A, 0x62D, 0x645, 0x62A,
+    0x62E, 0x645, 0x62A, 0x645, 0x62C, 0x62A, 0x645, 0x62D, 0x62A, 0x645,
+    0x62E, 0x62C, 0x645, 0x62D, 0x62C, 0x645, 0x62D, 0x62D, 0x645, 0x64A,
+    0x62D, 0x645, 0x649, 0x633, 0x62D, 0x62C, 0x633, 0x62C, 0x62D, 0x633,
+    0x62C, 0x649, 0x633, 0x645, 0x62D, 0x633, 0x645, 0x62D, 0x633, 0x645,
+    0x62C, 0x633, 0x645, 0x645, 0x633, 0x645, 0x645, 0x635, 0x62D, 0x62D,
+    0x635, 0x62D, 0x62D, 0x635, 0x645, 0x645, 0x634, 0x62D, 0x645, 0x634,
+    0x62D, 0x645, 0x634, 0x62C, 0x64A, 0x634, 0x645, 0x62E, 0x634, 0x645,
+    0x62E, 0x634, 0x645, 0x645, 0x634, 0x645, 0x645, 0x636, 0x62D, 0x649,
+    0x636, 0x62E, 0x645, 0x636, 0x62E, 0x645, 0x637, 0x645, 0x62D, 0x637,
+    0x645, 0x62D, 0x637, 0x645, 0x645, 0x637, 0x645, 0x64A, 0x639, 0x62C,
+    0x645, 0x639, 0x645, 0x645, 0x639, 0x645, 0x645, 0x639, 0x645, 0x649,
+    0x63A, 0x645, 0x645, 0x63A, 0x645, 0x64A, 0x63A, 0x645, 0x649, 0x641,
+    0x62E, 0x645, 0x641, 0x62E, 0x645, 0x642, 0x645, 0x62D, 0x642, 0x645,
+    0x645, 0x644, 0x62D, 0x645, 0x644, 0x62D, 0x64A, 0x644, 0x62D, 0x649,
+    0x644, 0x62C, 0x62C, 0x644, 0x62C, 0x62C, 0x644, 0x62E, 0x645, 0x644,
+    0x62E, 0x645, 0x644, 0x645, 0x62D, 0x644, 0x645, 0x62D, 0x645, 0x62D,
+    0x62C, 0x645, 0x62D, 0x645, 0x645, 0x62D, 0x64A, 0x645, 0x62C, 0x62D,
+    0x645, 0x62C, 0x645, 0x645, 0x62E, 0x62C, 0x645, 0x62E, 0x645, 0x645,
+    0x62C, 0x62E, 0x647, 0x645, 0x62C, 0x647, 0x645, 0x645, 0x646, 0x62D,
+    0x645, 0x646, 0x62D, 0x649, 0x646, 0x62C, 0x645, 0x646, 0x62C, 0x645,
+    0x646, 0x62C, 0x649, 0x646, 0x645, 0x64A, 0x646, 0x645, 0x649, 0x64A,
+    0x645, 0x645, 0x64A, 0x645, 0x645, 0x628, 0x62E, 0x64A, 0x62A, 0x62C,
+    0x64A, 0x62A, 0x62C, 0x649, 0x62A, 0x62E, 0x64A, 0x62A, 0x62E, 0x649,
+    0x62A, 0x645, 0x64A, 0x62A, 0x645, 0x649, 0x62C, 0x645, 0x64A, 0x62C,
+    0x62D, 0x649, 0x62C, 0x645, 0x649, 0x633, 0x62E, 0x649, 0x635, 0x62D,
+    0x64A, 0x634, 0x62D, 0x64A, 0x636, 0x62D, 0x64A, 0x644, 0x62C, 0x64A,
+    0x644, 0x645, 0x64A, 0x64A, 0x62D, 0x64A, 0x64A, 0x62C, 0x64A, 0x64A,
+    0x645, 0x64A, 0x645, 0x645, 0x64A, 0x642, 0x645, 0x64A, 0x646, 0x62D,
+    0x64A, 0x642, 0x645, 0x62D, 0x644, 0x62D, 0x645, 0x639, 0x645, 0x64A,
+    0x643, 0x645, 0x64A, 0x646, 0x62C, 0x62D, 0x645, 0x62E, 0x64A, 0x644,
+    0x62C, 0x645, 0x643, 0x645, 0x645, 0x644, 0x62C, 0x645, 0x646, 0x62C,
+    0x62D, 0x62C, 0x62D, 0x64A, 0x62D, 0x62C, 0x64A, 0x645, 0x62C, 0x64A,
+    0x641, 0x645, 0x64A, 0x628, 0x62D, 0x64A, 0x643, 0x645, 0x645, 0x639,
+    0x62C, 0x645, 0x635, 0x645, 0x645, 0x633, 0x62E, 0x64A, 0x646, 0x62C,
+    0x64A, 0x635, 0x644, 0x6D2, 0x642, 0x644, 0x6D2, 0x627, 0x644, 0x644,
+    0x647, 0x627, 0x643, 0x628, 0x631, 0x645, 0x62D, 0x645, 0x62F, 0x635,
+    0x644, 0x639, 0x645, 0x631, 0x633, 0x648, 0x644, 0x639, 0x644, 0x64A,
+    0x647, 0x648, 0x633, 0x644, 0x645, 0x635, 0x644, 0x649, 0x635, 0x644,
+    0x649, 0x20, 0x627, 0x644, 0x644, 0x647, 0x20, 0x639, 0x644, 0x64A,
+    0x647, 0x20, 0x648, 0x633, 0x644, 0x645, 0x62C, 0x644, 0x20, 0x62C,
+    0x644, 0x627, 0x644, 0x647, 0x631, 0x6CC, 0x627, 0x644, 0x2C, 0x3001,
+    0x3002, 0x3A, 0x3B, 0x21, 0x3F, 0x3016, 0x3017, 0x2026, 0x2025, 0x2014,
+    0x2013, 0x5F, 0x5F, 0x28, 0x29, 0x7B, 0x7D, 0x3014, 0x3015, 0x3010,
+    0x3011, 0x300A, 0x300B, 0x3008, 0x3009, 0x300C, 0x300D, 0x300E, 0x300F, 0x5B,
+    0x5D, 0x203E, 0x203E, 0x203E, 0x203E, 0x5F, 0x5F, 0x5F, 0x2C, 0x3001,
+    0x2E, 0x3B, 0x3A, 0x3F, 0x21, 0x2014, 0x28, 0x29, 0x7B, 0x7D,
+    0x3014, 0x3015, 0x23, 0x26, 0x2A, 0x2B, 0x2D, 0x3C, 0x3E, 0x3D,
+    0x5C, 0x24, 0x25, 0x40, 0x20, 0x64B, 0x640, 0x64B, 0x20, 0x64C,
+    0x20, 0x64D, 0x20, 0x64E, 0x640, 0x64E, 0x20, 0x64F, 0x640, 0x64F,
+    0x20, 0x650, 0x640, 0x650, 0x20, 0x651, 0x640, 0x651, 0x20, 0x652,
+    0x640, 0x652, 0x621, 0x622, 0x622, 0x623, 0x623, 0x624, 0x624, 0x625,
+    0x625, 0x626, 0x626, 0x626, 0x626, 0x627, 0x627, 0x628, 0x628, 0x628,
+    0x628, 0x629, 0x629, 0x62A, 0x62A, 0x62A, 0x62A, 0x62B, 0x62B, 0x62B,
+    0x62B, 0x62C, 0x62C, 0x62C, 0x62C, 0x62D, 0x62D, 0x62D, 0x62D, 0x62E,
+    0x62E, 0x62E, 0x62E, 0x62F, 0x62F, 0x630, 0x630, 0x631, 0x631, 0x632,
+    0x632, 0x633, 0x633, 0x633, 0x633, 0x634, 0x634, 0x634, 0x634, 0x635,
+    0x635, 0x635, 0x635, 0x636, 0x636, 0x636, 0x636, 0x637, 0x637, 0x637,
+    0x637, 0x638, 0x638, 0x638, 0x638, 0x639, 0x639, 0x639, 0x639, 0x63A,
+    0x63A, 0x63A, 0x63A, 0x641, 0x641, 0x641, 0x641, 0x642, 0x642, 0x642,
+    0x642, 0x643, 0x643, 0x643, 0x643, 0x644, 0x644, 0x644, 0x644, 0x645,
+    0x645, 0x645, 0x645, 0x646, 0x646, 0x646, 0x646, 0x647, 0x647, 0x647,
+    0x647, 0x648, 0x648, 0x649, 0x649, 0x64A, 0x64A, 0x64A, 0x64A, 0x644,
+    0x622, 0x644, 0x622, 0x644, 0x623, 0x644, 0x623, 0x644, 0x625, 0x644,
+    0x625, 0x644, 0x627, 0x644, 0x627, 0x21, 0x22, 0x23, 0x24, 0x25,
+    0x26, 0x27, 0x28, 0x29, 0x2A, 0x2B, 0x2C, 0x2D, 0x2E, 0x2F,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39,
+    0x3A, 0x3B, 0x3C, 0x3D, 0x3E, 0x3F, 0x40, 0x41, 0x42, 0x43,
+    0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D,
+    0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x5A, 0x5B, 0x5C, 0x5D, 0x5E, 0x5F, 0x60, 0x61,
+    0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B,
+    0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75,
+    0x76, 0x77, 0x78, 0x79, 0x7A, 0x7B, 0x7C, 0x7D, 0x7E, 0x2985,
+    0x2986, 0x3002, 0x300C, 0x300D, 0x3001, 0x30FB, 0x30F2, 0x30A1, 0x30A3, 0x30A5,
+    0x30A7, 0x30A9, 0x30E3, 0x30E5, 0x30E7, 0x30C3, 0x30FC, 0x30A2, 0x30A4, 0x30A6,
+    0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF, 0x30B1, 0x30B3, 0x30B5, 0x30B7, 0x30B9,
+    0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4, 0x30C6, 0x30C8, 0x30CA, 0x30CB, 0x30CC,
+    0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE, 0x30DF, 0x30E0,
+    0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC, 0x30ED,
+    0x30EF, 0x30F3, 0x3099, 0x309A, 0x3164, 0x3131, 0x3132, 0x3133, 0x3134, 0x3135,
+    0x3136, 0x3137, 0x3138, 0x3139, 0x313A, 0x313B, 0x313C, 0x313D, 0x313E, 0x313F,
+    0x3140, 0x3141, 0x3142, 0x3143, 0x3144, 0x3145, 0x3146, 0x3147, 0x3148, 0x3149,
+    0x314A, 0x314B, 0x314C, 0x314D, 0x314E, 0x314F, 0x3150, 0x3151, 0x3152, 0x3153,
+    0x3154, 0x3155, 0x3156, 0x3157, 0x3158, 0x3159, 0x315A, 0x315B, 0x315C, 0x315D,
+    0x315E, 0x315F, 0x3160, 0x3161, 0x3162, 0x3163, 0xA2, 0xA3, 0xAC, 0xAF,
+    0xA6, 0xA5, 0x20A9, 0x2502, 0x2190, 0x2191, 0x2192, 0x2193, 0x25A0, 0x25CB,
+    0x2D0, 0x2D1, 0xE6, 0x299, 0x253, 0x2A3, 0xAB66, 0x2A5, 0x2A4, 0x256,
+    0x257, 0x1D91, 0x258, 0x25E, 0x2A9, 0x264, 0x262, 0x260, 0x29B, 0x127,
+    0x29C, 0x267, 0x284, 0x2AA, 0x2AB, 0x26C, 0x1DF04, 0xA78E, 0x26E, 0x1DF05,
+    0x28E, 0x1DF06, 0xF8, 0x276, 0x277, 0x71, 0x27A, 0x1DF08, 0x27D, 0x27E,
+    0x280, 0x2A8, 0x2A6, 0xAB67, 0x2A7, 0x288, 0x2C71, 0x28F, 0x2A1, 0x2A2,
+    0x298, 0x1C0, 0x1C1, 0x1C2, 0x1DF0A, 0x1DF1E, 0x11099, 0x110BA, 0x1109B, 0x110BA,
+    0x110A5, 0x110BA, 0x11131, 0x11127, 0x11132, 0x11127, 0x11347, 0x1133E, 0x11347, 0x11357,
+    0x114B9, 0x114BA, 0x114B9, 0x114B0, 0x114B9, 0x114BD, 0x115B8, 0x115AF, 0x115B9, 0x115AF,
+    0x11935, 0x11930, 0x1D157, 0x1D165, 0x1D158, 0x1D165, 0x1D15F, 0x1D16E, 0x1D15F, 0x1D16F,
+    0x1D15F, 0x1D170, 0x1D15F, 0x1D171, 0x1D15F, 0x1D172, 0x1D1B9, 0x1D165, 0x1D1BA, 0x1D165,
+    0x1D1BB, 0x1D16E, 0x1D1BC, 0x1D16E, 0x1D1BB, 0x1D16F, 0x1D1BC, 0x1D16F, 0x41, 0x42,
+    0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A,
+    0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A,
+    0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54,
+    0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64,
+    0x65, 0x66, 0x67, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F,
+    0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79,
+    0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49,
+    0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53,
+    0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63,
+    0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77,
+    0x78, 0x79, 0x7A, 0x41, 0x43, 0x44, 0x47, 0x4A, 0x4B, 0x4E,
+    0x4F, 0x50, 0x51, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59,
+    0x5A, 0x61, 0x62, 0x63, 0x64, 0x66, 0x68, 0x69, 0x6A, 0x6B,
+    0x6C, 0x6D, 0x6E, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+    0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46,
+    0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50,
+    0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A,
+    0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A,
+    0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x44, 0x45,
+    0x46, 0x47, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x61, 0x62, 0x63,
+    0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D,
+    0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77,
+    0x78, 0x79, 0x7A, 0x41, 0x42, 0x44, 0x45, 0x46, 0x47, 0x49,
+    0x4A, 0x4B, 0x4C, 0x4D, 0x4F, 0x53, 0x54, 0x55, 0x56, 0x57,
+    0x58, 0x59, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42,
+    0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A,
+    0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A,
+    0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54,
+    0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64,
+    0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E,
+    0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78,
+    0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48,
+    0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52,
+    0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0x61, 0x62,
+    0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C,
+    0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+    0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46,
+    0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50,
+    0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A,
+    0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0x6A,
+    0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0x73, 0x74,
+    0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42, 0x43, 0x44,
+    0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E,
+    0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68,
+    0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72,
+    0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0x41, 0x42,
+    0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C,
+    0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56,
+    0x57, 0x58, 0x59, 0x5A, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66,
+    0x67, 0x68, 0x69, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70,
+    0x71, 0x72, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A,
+    0x131, 0x237, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398,
+    0x399, 0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1, 0x3F4,
+    0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2,
+    0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC,
+    0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6,
+    0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3F5, 0x3D1, 0x3F0, 0x3D5, 0x3F1, 0x3D6,
+    0x391, 0x392, 0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39A,
+    0x39B, 0x39C, 0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1, 0x3F4, 0x3A3, 0x3A4,
+    0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4,
+    0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE,
+    0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8,
+    0x3C9, 0x2202, 0x3F5, 0x3D1, 0x3F0, 0x3D5, 0x3F1, 0x3D6, 0x391, 0x392,
+    0x393, 0x394, 0x395, 0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C,
+    0x39D, 0x39E, 0x39F, 0x3A0, 0x3A1, 0x3F4, 0x3A3, 0x3A4, 0x3A5, 0x3A6,
+    0x3A7, 0x3A8, 0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6,
+    0x3B7, 0x3B8, 0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0,
+    0x3C1, 0x3C2, 0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202,
+    0x3F5, 0x3D1, 0x3F0, 0x3D5, 0x3F1, 0x3D6, 0x391, 0x392, 0x393, 0x394,
+    0x395, 0x396, 0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D, 0x39E,
+    0x39F, 0x3A0, 0x3A1, 0x3F4, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8,
+    0x3A9, 0x2207, 0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8,
+    0x3B9, 0x3BA, 0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2,
+    0x3C3, 0x3C4, 0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3F5, 0x3D1,
+    0x3F0, 0x3D5, 0x3F1, 0x3D6, 0x391, 0x392, 0x393, 0x394, 0x395, 0x396,
+    0x397, 0x398, 0x399, 0x39A, 0x39B, 0x39C, 0x39D, 0x39E, 0x39F, 0x3A0,
+    0x3A1, 0x3F4, 0x3A3, 0x3A4, 0x3A5, 0x3A6, 0x3A7, 0x3A8, 0x3A9, 0x2207,
+    0x3B1, 0x3B2, 0x3B3, 0x3B4, 0x3B5, 0x3B6, 0x3B7, 0x3B8, 0x3B9, 0x3BA,
+    0x3BB, 0x3BC, 0x3BD, 0x3BE, 0x3BF, 0x3C0, 0x3C1, 0x3C2, 0x3C3, 0x3C4,
+    0x3C5, 0x3C6, 0x3C7, 0x3C8, 0x3C9, 0x2202, 0x3F5, 0x3D1, 0x3F0, 0x3D5,
+    0x3F1, 0x3D6, 0x3DC, 0x3DD, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35,
+    0x36, 0x37, 0x38, 0x39, 0x627, 0x628, 0x62C, 0x62F, 0x648, 0x632,
+    0x62D, 0x637, 0x64A, 0x643, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641,
+    0x635, 0x642, 0x631, 0x634, 0x62A, 0x62B, 0x62E, 0x630, 0x636, 0x638,
+    0x63A, 0x66E, 0x6BA, 0x6A1, 0x66F, 0x628, 0x62C, 0x647, 0x62D, 0x64A,
+    0x643, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x634,
+    0x62A, 0x62B, 0x62E, 0x636, 0x63A, 0x62C, 0x62D, 0x64A, 0x644, 0x646,
+    0x633, 0x639, 0x635, 0x642, 0x634, 0x62E, 0x636, 0x63A, 0x6BA, 0x66F,
+    0x628, 0x62C, 0x647, 0x62D, 0x637, 0x64A, 0x643, 0x645, 0x646, 0x633,
+    0x639, 0x641, 0x635, 0x642, 0x634, 0x62A, 0x62B, 0x62E, 0x636, 0x638,
+    0x63A, 0x66E, 0x6A1, 0x627, 0x628, 0x62C, 0x62F, 0x647, 0x648, 0x632,
+    0x62D, 0x637, 0x64A, 0x644, 0x645, 0x646, 0x633, 0x639, 0x641, 0x635,
+    0x642, 0x631, 0x634, 0x62A, 0x62B, 0x62E, 0x630, 0x636, 0x638, 0x63A,
+    0x628, 0x62C, 0x62F, 0x648, 0x632, 0x62D, 0x637, 0x64A, 0x644, 0x645,
+    0x646, 0x633, 0x639, 0x641, 0x635, 0x642, 0x631, 0x634, 0x62A, 0x62B,
+    0x62E, 0x630, 0x636, 0x638, 0x63A, 0x30, 0x2E, 0x30, 0x2C, 0x31,
+    0x2C, 0x32, 0x2C, 0x33, 0x2C, 0x34, 0x2C, 0x35, 0x2C, 0x36,
+    0x2C, 0x37, 0x2C, 0x38, 0x2C, 0x39, 0x2C, 0x28, 0x41, 0x29,
+    0x28, 0x42, 0x29, 0x28, 0x43, 0x29, 0x28, 0x44, 0x29, 0x28,
+    0x45, 0x29, 0x28, 0x46, 0x29, 0x28, 0x47, 0x29, 0x28, 0x48,
+    0x29, 0x28, 0x49, 0x29, 0x28, 0x4A, 0x29, 0x28, 0x4B, 0x29,
+    0x28, 0x4C, 0x29, 0x28, 0x4D, 0x29, 0x28, 0x4E, 0x29, 0x28,
+    0x4F, 0x29, 0x28, 0x50, 0x29, 0x28, 0x51, 0x29, 0x28, 0x52,
+    0x29, 0x28, 0x53, 0x29, 0x28, 0x54, 0x29, 0x28, 0x55, 0x29,
+    0x28, 0x56, 0x29, 0x28, 0x57, 0x29, 0x28, 0x58, 0x29, 0x28,
+    0x59, 0x29, 0x28, 0x5A, 0x29, 0x3014, 0x53, 0x3015, 0x43, 0x52,
+    0x43, 0x44, 0x57, 0x5A, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46,
+    0x47, 0x48, 0x49, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50,
+    0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A,
+    0x48, 0x56, 0x4D, 0x56, 0x53, 0x44, 0x53, 0x53, 0x50, 0x50,
+    0x56, 0x57, 0x43, 0x4D, 0x43, 0x4D, 0x44, 0x4D, 0x52, 0x44,
+    0x4A, 0x307B, 0x304B, 0x30B3, 0x30B3, 0x30B5, 0x624B, 0x5B57, 0x53CC, 0x30C7,
+    0x4E8C, 0x591A, 0x89E3, 0x5929, 0x4EA4, 0x6620, 0x7121, 0x6599, 0x524D, 0x5F8C,
+    0x518D, 0x65B0, 0x521D, 0x7D42, 0x751F, 0x8CA9, 0x58F0, 0x5439, 0x6F14, 0x6295,
+    0x6355, 0x4E00, 0x4E09, 0x904A, 0x5DE6, 0x4E2D, 0x53F3, 0x6307, 0x8D70, 0x6253,
+    0x7981, 0x7A7A, 0x5408, 0x6E80, 0x6709, 0x6708, 0x7533, 0x5272, 0x55B6, 0x914D,
+    0x3014, 0x672C, 0x3015, 0x3014, 0x4E09, 0x3015, 0x3014, 0x4E8C, 0x3015, 0x3014,
+    0x5B89, 0x3015, 0x3014, 0x70B9, 0x3015, 0x3014, 0x6253, 0x3015, 0x3014, 0x76D7,
+    0x3015, 0x3014, 0x52DD, 0x3015, 0x3014, 0x6557, 0x3015, 0x5F97, 0x53EF, 0x30,
+    0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x4E3D,
+    0x4E38, 0x4E41, 0x20122, 0x4F60, 0x4FAE, 0x4FBB, 0x5002, 0x507A, 0x5099, 0x50E7,
+    0x50CF, 0x349E, 0x2063A, 0x514D, 0x5154, 0x5164, 0x5177, 0x2051C, 0x34B9, 0x5167,
+    0x518D, 0x2054B, 0x5197, 0x51A4, 0x4ECC, 0x51AC, 0x51B5, 0x291DF, 0x51F5, 0x5203,
+    0x34DF, 0x523B, 0x5246, 0x5272, 0x5277, 0x3515, 0x52C7, 0x52C9, 0x52E4, 0x52FA,
+    0x5305, 0x5306, 0x5317, 0x5349, 0x5351, 0x535A, 0x5373, 0x537D, 0x537F, 0x537F,
+    0x537F, 0x20A2C, 0x7070, 0x53CA, 0x53DF, 0x20B63, 0x53EB, 0x53F1, 0x5406, 0x549E,
+    0x5438, 0x5448, 0x5468, 0x54A2, 0x54F6, 0x5510, 0x5553, 0x5563, 0x5584, 0x5584,
+    0x5599, 0x55AB, 0x55B3, 0x55C2, 0x5716, 0x5606, 0x5717, 0x5651, 0x5674, 0x5207,
+    0x58EE, 0x57CE, 0x57F4, 0x580D, 0x578B, 0x5832, 0x5831, 0x58AC, 0x214E4, 0x58F2,
+    0x58F7, 0x5906, 0x591A, 0x5922, 0x5962, 0x216A8, 0x216EA, 0x59EC, 0x5A1B, 0x5A27,
+    0x59D8, 0x5A66, 0x36EE, 0x36FC, 0x5B08, 0x5B3E, 0x5B3E, 0x219C8, 0x5BC3, 0x5BD8,
+    0x5BE7, 0x5BF3, 0x21B18, 0x5BFF, 0x5C06, 0x5F53, 0x5C22, 0x3781, 0x5C60, 0x5C6E,
+    0x5CC0, 0x5C8D, 0x21DE4, 0x5D43, 0x21DE6, 0x5D6E, 0x5D6B, 0x5D7C, 0x5DE1, 0x5DE2,
+    0x382F, 0x5DFD, 0x5E28, 0x5E3D, 0x5E69, 0x3862, 0x22183, 0x387C, 0x5EB0, 0x5EB3,
+    0x5EB6, 0x5ECA, 0x2A392, 0x5EFE, 0x22331, 0x22331, 0x8201, 0x5F22, 0x5F22, 0x38C7,
+    0x232B8, 0x261DA, 0x5F62, 0x5F6B, 0x38E3, 0x5F9A, 0x5FCD, 0x5FD7, 0x5FF9, 0x6081,
+    0x393A, 0x391C, 0x6094, 0x226D4, 0x60C7, 0x6148, 0x614C, 0x614E, 0x614C, 0x617A,
+    0x618E, 0x61B2, 0x61A4, 0x61AF, 0x61DE, 0x61F2, 0x61F6, 0x6210, 0x621B, 0x625D,
+    0x62B1, 0x62D4, 0x6350, 0x22B0C, 0x633D, 0x62FC, 0x6368, 0x6383, 0x63E4, 0x22BF1,
+    0x6422, 0x63C5, 0x63A9, 0x3A2E, 0x6469, 0x647E, 0x649D, 0x6477, 0x3A6C, 0x654F,
+    0x656C, 0x2300A, 0x65E3, 0x66F8, 0x6649, 0x3B19, 0x6691, 0x3B08, 0x3AE4, 0x5192,
+    0x5195, 0x6700, 0x669C, 0x80AD, 0x43D9, 0x6717, 0x671B, 0x6721, 0x675E, 0x6753,
+    0x233C3, 0x3B49, 0x67FA, 0x6785, 0x6852, 0x6885, 0x2346D, 0x688E, 0x681F, 0x6914,
+    0x3B9D, 0x6942, 0x69A3, 0x69EA, 0x6AA8, 0x236A3, 0x6ADB, 0x3C18, 0x6B21, 0x238A7,
+    0x6B54, 0x3C4E, 0x6B72, 0x6B9F, 0x6BBA, 0x6BBB, 0x23A8D, 0x21D0B, 0x23AFA, 0x6C4E,
+    0x23CBC, 0x6CBF, 0x6CCD, 0x6C67, 0x6D16, 0x6D3E, 0x6D77, 0x6D41, 0x6D69, 0x6D78,
+    0x6D85, 0x23D1E, 0x6D34, 0x6E2F, 0x6E6E, 0x3D33, 0x6ECB, 0x6EC7, 0x23ED1, 0x6DF9,
+    0x6F6E, 0x23F5E, 0x23F8E, 0x6FC6, 0x7039, 0x701E, 0x701B, 0x3D96, 0x704A, 0x707D,
+    0x7077, 0x70AD, 0x20525, 0x7145, 0x24263, 0x719C, 0x243AB, 0x7228, 0x7235, 0x7250,
+    0x24608, 0x7280, 0x7295, 0x24735, 0x24814, 0x737A, 0x738B, 0x3EAC, 0x73A5, 0x3EB8,
+    0x3EB8, 0x7447, 0x745C, 0x7471, 0x7485, 0x74CA, 0x3F1B, 0x7524, 0x24C36, 0x753E,
+    0x24C92, 0x7570, 0x2219F, 0x7610, 0x24FA1, 0x24FB8, 0x25044, 0x3FFC, 0x4008, 0x76F4,
+    0x250F3, 0x250F2, 0x25119, 0x25133, 0x771E, 0x771F, 0x771F, 0x774A, 0x4039, 0x778B,
+    0x4046, 0x4096, 0x2541D, 0x784E, 0x788C, 0x78CC, 0x40E3, 0x25626, 0x7956, 0x2569A,
+    0x256C5, 0x798F, 0x79EB, 0x412F, 0x7A40, 0x7A4A, 0x7A4F, 0x2597C, 0x25AA7, 0x25AA7,
+    0x7AEE, 0x4202, 0x25BAB, 0x7BC6, 0x7BC9, 0x4227, 0x25C80, 0x7CD2, 0x42A0, 0x7CE8,
+    0x7CE3, 0x7D00, 0x25F86, 0x7D63, 0x4301, 0x7DC7, 0x7E02, 0x7E45, 0x4334, 0x26228,
+    0x26247, 0x4359, 0x262D9, 0x7F7A, 0x2633E, 0x7F95, 0x7FFA, 0x8005, 0x264DA, 0x26523,
+    0x8060, 0x265A8, 0x8070, 0x2335F, 0x43D5, 0x80B2, 0x8103, 0x440B, 0x813E, 0x5AB5,
+    0x267A7, 0x267B5, 0x23393, 0x2339C, 0x8201, 0x8204, 0x8F9E, 0x446B, 0x8291, 0x828B,
+    0x829D, 0x52B3, 0x82B1, 0x82B3, 0x82BD, 0x82E6, 0x26B3C, 0x82E5, 0x831D, 0x8363,
+    0x83AD, 0x8323, 0x83BD, 0x83E7, 0x8457, 0x8353, 0x83CA, 0x83CC, 0x83DC, 0x26C36,
+    0x26D6B, 0x26CD5, 0x452B, 0x84F1, 0x84F3, 0x8516, 0x273CA, 0x8564, 0x26F2C, 0x455D,
+    0x4561, 0x26FB1, 0x270D2, 0x456B, 0x8650, 0x865C, 0x8667, 0x8669, 0x86A9, 0x8688,
+    0x870E, 0x86E2, 0x8779, 0x8728, 0x876B, 0x8786, 0x45D7, 0x87E1, 0x8801, 0x45F9,
+    0x8860, 0x8863, 0x27667, 0x88D7, 0x88DE, 0x4635, 0x88FA, 0x34BB, 0x278AE, 0x27966,
+    0x46BE, 0x46C7, 0x8AA0, 0x8AED, 0x8B8A, 0x8C55, 0x27CA8, 0x8CAB, 0x8CC1, 0x8D1B,
+    0x8D77, 0x27F2F, 0x20804, 0x8DCB, 0x8DBC, 0x8DF0, 0x208DE, 0x8ED4, 0x8F38, 0x285D2,
+    0x285ED, 0x9094, 0x90F1, 0x9111, 0x2872E, 0x911B, 0x9238, 0x92D7, 0x92D8, 0x927C,
+    0x93F9, 0x9415, 0x28BFA, 0x958B, 0x4995, 0x95B7, 0x28D77, 0x49E6, 0x96C3, 0x5DB2,
+    0x9723, 0x29145, 0x2921A, 0x4A6E, 0x4A76, 0x97E0, 0x2940A, 0x4AB2, 0x29496, 0x980B,
+    0x980B, 0x9829, 0x295B6, 0x98E2, 0x4B33, 0x9929, 0x99A7, 0x99C2, 0x99FE, 0x4BCE,
+    0x29B30, 0x9B12, 0x9C40, 0x9CFD, 0x4CCE, 0x4CED, 0x9D67, 0x2A0CE, 0x4CF8, 0x2A105,
+    0x2A20E, 0x2A291, 0x9EBB, 0x4D56, 0x9EF9, 0x9EFE, 0x9F05, 0x9F0F, 0x9F16, 0x9F3B,
+    0x2A600,
+];
+
+/// Primary composites (excluding Hangul syllables), as `(first, second, composite)`, sorted
+/// by `(first, second)`
+pub(super) static COMPOSITIONS: [(u32, u32, u32); 941] = [
+    (0x3C, 0x338, 0x226E), (0x3D, 0x338, 0x2260), (0x3E, 0x338, 0x226F),
+    (0x41, 0x300, 0xC0), (0x41, 0x301, 0xC1), (0x41, 0x302, 0xC2),
+    (0x41, 0x303, 0xC3), (0x41, 0x304, 0x100), (0x41, 0x306, 0x102),
+    (0x41, 0x307, 0x226), (0x41, 0x308, 0xC4), (0x41, 0x309, 0x1EA2),
+    (0x41, 0x30A, 0xC5), (0x41, 0x30C, 0x1CD), (0x41, 0x30F, 0x200),
+    (0x41, 0x311, 0x202), (0x41, 0x323, 0x1EA0), (0x41, 0x325, 0x1E00),
+    (0x41, 0x328, 0x104), (0x42, 0x307, 0x1E02), (0x42, 0x323, 0x1E04),
+    (0x42, 0x331, 0x1E06), (0x43, 0x301, 0x106), (0x43, 0x302, 0x108),
+    (0x43, 0x307, 0x10A), (0x43, 0x30C, 0x10C), (0x43, 0x327, 0xC7),
+    (0x44, 0x307, 0x1E0A), (0x44, 0x30C, 0x10E), (0x44, 0x323, 0x1E0C),
+    (0x44, 0x327, 0x1E10), (0x44, 0x32D, 0x1E12), (0x44, 0x331, 0x1E0E),
+    (0x45, 0x300, 0xC8), (0x45, 0x301, 0xC9), (0x45, 0x302, 0xCA),
+    (0x45, 0x303, 0x1EBC), (0x45, 0x304, 0x112), (0x45, 0x306, 0x114),
+    (0x45, 0x307, 0x116), (0x45, 0x308, 0xCB), (0x45, 0x309, 0x1EBA),
+    (0x45, 0x30C, 0x11A), (0x45, 0x30F, 0x204), (0x45, 0x311, 0x206),
+    (0x45, 0x323, 0x1EB8), (0x45, 0x327, 0x228), (0x45, 0x328, 0x118),
+    (0x45, 0x32D, 0x1E18), (0x45, 0x330, 0x1E1A), (0x46, 0x307, 0x1E1E),
+    (0x47, 0x301, 0x1F4), (0x47, 0x302, 0x11C), (0x47, 0x304, 0x1E20),
+    (0x47, 0x306, 0x11E), (0x47, 0x307, 0x120), (0x47, 0x30C, 0x1E6),
+    (0x47, 0x327, 0x122), (0x48, 0x302, 0x124), (0x48, 0x307, 0x1E22),
+    (0x48, 0x308, 0x1E26), (0x48, 0x30C, 0x21E), (0x48, 0x323, 0x1E24),
+    (0x48, 0x327, 0x1E28), (0x48, 0x32E, 0x1E2A), (0x49, 0x300, 0xCC),
+    (0x49, 0x301, 0xCD), (0x49, 0x302, 0xCE), (0x49, 0x303, 0x128),
+    (0x49, 0x304, 0x12A), (0x49, 0x306, 0x12C), (0x49, 0x307, 0x130),
+    (0x49, 0x308, 0xCF), (0x49, 0x309, 0x1EC8), (0x49, 0x30C, 0x1CF),
+    (0x49, 0x30F, 0x208), (0x49, 0x311, 0x20A), (0x49, 0x323, 0x1ECA),
+    (0x49, 0x328, 0x12E), (0x49, 0x330, 0x1E2C), (0x4A, 0x302, 0x134),
+    (0x4B, 0x301, 0x1E30), (0x4B, 0x30C, 0x1E8), (0x4B, 0x323, 0x1E32),
+    (0x4B, 0x327, 0x136), (0x4B, 0x331, 0x1E34), (0x4C, 0x301, 0x139),
+    (0x4C, 0x30C, 0x13D), (0x4C, 0x323, 0x1E36), (0x4C, 0x327, 0x13B),
+    (0x4C, 0x32D, 0x1E3C), (0x4C, 0x331, 0x1E3A), (0x4D, 0x301, 0x1E3E),
+    (0x4D, 0x307, 0x1E40), (0x4D, 0x323, 0x1E42), (0x4E, 0x300, 0x1F8),
+    (0x4E, 0x301, 0x143), (0x4E, 0x303, 0xD1), (0x4E, 0x307, 0x1E44),
+    (0x4E, 0x30C, 0x147), (0x4E, 0x323, 0x1E46), (0x4E, 0x327, 0x145),
+    (0x4E, 0x32D, 0x1E4A), (0x4E, 0x331, 0x1E48), (0x4F, 0x300, 0xD2),
+    (0x4F, 0x301, 0xD3), (0x4F, 0x302, 0xD4), (0x4F, 0x303, 0xD5),
+    (0x4F, 0x304, 0x14C), (0x4F, 0x306, 0x14E), (0x4F, 0x307, 0x22E),
+    (0x4F, 0x308, 0xD6), (0x4F, 0x309, 0x1ECE), (0x4F, 0x30B, 0x150),
+    (0x4F, 0x30C, 0x1D1), (0x4F, 0x30F, 0x20C), (0x4F, 0x311, 0x20E),
+    (0x4F, 0x31B, 0x1A0), (0x4F, 0x323, 0x1ECC), (0x4F, 0x328, 0x1EA),
+    (0x50, 0x301, 0x1E54), (0x50, 0x307, 0x1E56), (0x52, 0x301, 0x154),
+    (0x52, 0x307, 0x1E58), (0x52, 0x30C, 0x158), (0x52, 0x30F, 0x210),
+    (0x52, 0x311, 0x212), (0x52, 0x323, 0x1E5A), (0x52, 0x327, 0x156),
+    (0x52, 0x331, 0x1E5E), (0x53, 0x301, 0x15A), (0x53, 0x302, 0x15C),
+    (0x53, 0x307, 0x1E60), (0x53, 0x30C, 0x160), (0x53, 0x323, 0x1E62),
+    (0x53, 0x326, 0x218), (0x53, 0x327, 0x15E), (0x54, 0x307, 0x1E6A),
+    (0x54, 0x30C, 0x164), (0x54, 0x323, 0x1E6C), (0x54, 0x326, 0x21A),
+    (0x54, 0x327, 0x162), (0x54, 0x32D, 0x1E70), (0x54, 0x331, 0x1E6E),
+    (0x55, 0x300, 0xD9), (0x55, 0x301, 0xDA), (0x55, 0x302, 0xDB),
+    (0x55, 0x303, 0x168), (0x55, 0x304, 0x16A), (0x55, 0x306, 0x16C),
+    (0x55, 0x308, 0xDC), (0x55, 0x309, 0x1EE6), (0x55, 0x30A, 0x16E),
+    (0x55, 0x30B, 0x170), (0x55, 0x30C, 0x1D3), (0x55, 0x30F, 0x214),
+    (0x55, 0x311, 0x216), (0x55, 0x31B, 0x1AF), (0x55, 0x323, 0x1EE4),
+    (0x55, 0x324, 0x1E72), (0x55, 0x328, 0x172), (0x55, 0x32D, 0x1E76),
+    (0x55, 0x330, 0x1E74), (0x56, 0x303, 0x1E7C), (0x56, 0x323, 0x1E7E),
+    (0x57, 0x300, 0x1E80), (0x57, 0x301, 0x1E82), (0x57, 0x302, 0x174),
+    (0x57, 0x307, 0x1E86), (0x57, 0x308, 0x1E84), (0x57, 0x323, 0x1E88),
+    (0x58, 0x307, 0x1E8A), (0x58, 0x308, 0x1E8C), (0x59, 0x300, 0x1EF2),
+    (0x59, 0x301, 0xDD), (0x59, 0x302, 0x176), (0x59, 0x303, 0x1EF8),
+    (0x59, 0x304, 0x232), (0x59, 0x307, 0x1E8E), (0x59, 0x308, 0x178),
+    (0x59, 0x309, 0x1EF6), (0x59, 0x323, 0x1EF4), (0x5A, 0x301, 0x179),
+    (0x5A, 0x302, 0x1E90), (0x5A, 0x307, 0x17B), (0x5A, 0x30C, 0x17D),
+    (0x5A, 0x323, 0x1E92), (0x5A, 0x331, 0x1E94), (0x61, 0x300, 0xE0),
+    (0x61, 0x301, 0xE1), (0x61, 0x302, 0xE2), (0x61, 0x303, 0xE3),
+    (0x61, 0x304, 0x101), (0x61, 0x306, 0x103), (0x61, 0x307, 0x227),
+    (0x61, 0x308, 0xE4), (0x61, 0x309, 0x1EA3), (0x61, 0x30A, 0xE5),
+    (0x61, 0x30C, 0x1CE), (0x61, 0x30F, 0x201), (0x61, 0x311, 0x203),
+    (0x61, 0x323, 0x1EA1), (0x61, 0x325, 0x1E01), (0x61, 0x328, 0x105),
+    (0x62, 0x307, 0x1E03), (0x62, 0x323, 0x1E05), (0x62, 0x331, 0x1E07),
+    (0x63, 0x301, 0x107), (0x63, 0x302, 0x109), (0x63, 0x307, 0x10B),
+    (0x63, 0x30C, 0x10D), (0x63, 0x327, 0xE7), (0x64, 0x307, 0x1E0B),
+    (0x64, 0x30C, 0x10F), (0x64, 0x323, 0x1E0D), (0x64, 0x327, 0x1E11),
+    (0x64, 0x32D, 0x1E13), (0x64, 0x331, 0x1E0F), (0x65, 0x300, 0xE8),
+    (0x65, 0x301, 0xE9), (0x65, 0x302, 0xEA), (0x65, 0x303, 0x1EBD),
+    (0x65, 0x304, 0x113), (0x65, 0x306, 0x115), (0x65, 0x307, 0x117),
+    (0x65, 0x308, 0xEB), (0x65, 0x309, 0x1EBB), (0x65, 0x30C, 0x11B),
+    (0x65, 0x30F, 0x205), (0x65, 0x311, 0x207), (0x65, 0x323, 0x1EB9),
+    (0x65, 0x327, 0x229), (0x65, 0x328, 0x119), (0x65, 0x32D, 0x1E19),
+    (0x65, 0x330, 0x1E1B), (0x66, 0x307, 0x1E1F), (0x67, 0x301, 0x1F5),
+    (0x67, 0x302, 0x11D), (0x67, 0x304, 0x1E21), (0x67, 0x306, 0x11F),
+    (0x67, 0x307, 0x121), (0x67, 0x30C, 0x1E7), (0x67, 0x327, 0x123),
+    (0x68, 0x302, 0x125), (0x68, 0x307, 0x1E23), (0x68, 0x308, 0x1E27),
+    (0x68, 0x30C, 0x21F), (0x68, 0x323, 0x1E25), (0x68, 0x327, 0x1E29),
+    (0x68, 0x32E, 0x1E2B), (0x68, 0x331, 0x1E96), (0x69, 0x300, 0xEC),
+    (0x69, 0x301, 0xED), (0x69, 0x302, 0xEE), (0x69, 0x303, 0x129),
+    (0x69, 0x304, 0x12B), (0x69, 0x306, 0x12D), (0x69, 0x308, 0xEF),
+    (0x69, 0x309, 0x1EC9), (0x69, 0x30C, 0x1D0), (0x69, 0x30F, 0x209),
+    (0x69, 0x311, 0x20B), (0x69, 0x323, 0x1ECB), (0x69, 0x328, 0x12F),
+    (0x69, 0x330, 0x1E2D), (0x6A, 0x302, 0x135), (0x6A, 0x30C, 0x1F0),
+    (0x6B, 0x301, 0x1E31), (0x6B, 0x30C, 0x1E9), (0x6B, 0x323, 0x1E33),
+    (0x6B, 0x327, 0x137), (0x6B, 0x331, 0x1E35), (0x6C, 0x301, 0x13A),
+    (0x6C, 0x30C, 0x13E), (0x6C, 0x323, 0x1E37), (0x6C, 0x327, 0x13C),
+    (0x6C, 0x32D, 0x1E3D), (0x6C, 0x331, 0x1E3B), (0x6D, 0x301, 0x1E3F),
+    (0x6D, 0x307, 0x1E41), (0x6D, 0x323, 0x1E43), (0x6E, 0x300, 0x1F9),
+    (0x6E, 0x301, 0x144), (0x6E, 0x303, 0xF1), (0x6E, 0x307, 0x1E45),
+    (0x6E, 0x30C, 0x148), (0x6E, 0x323, 0x1E47), (0x6E, 0x327, 0x146),
+    (0x6E, 0x32D, 0x1E4B), (0x6E, 0x331, 0x1E49), (0x6F, 0x300, 0xF2),
+    (0x6F, 0x301, 0xF3), (0x6F, 0x302, 0xF4), (0x6F, 0x303, 0xF5),
+    (0x6F, 0x304, 0x14D), (0x6F, 0x306, 0x14F), (0x6F, 0x307, 0x22F),
+    (0x6F, 0x308, 0xF6), (0x6F, 0x309, 0x1ECF), (0x6F, 0x30B, 0x151),
+    (0x6F, 0x30C, 0x1D2), (0x6F, 0x30F, 0x20D), (0x6F, 0x311, 0x20F),
+    (0x6F, 0x31B, 0x1A1), (0x6F, 0x323, 0x1ECD), (0x6F, 0x328, 0x1EB),
+    (0x70, 0x301, 0x1E55), (0x70, 0x307, 0x1E57), (0x72, 0x301, 0x155),
+    (0x72, 0x307, 0x1E59), (0x72, 0x30C, 0x159), (0x72, 0x30F, 0x211),
+    (0x72, 0x311, 0x213), (0x72, 0x323, 0x1E5B), (0x72, 0x327, 0x157),
+    (0x72, 0x331, 0x1E5F), (0x73, 0x301, 0x15B), (0x73, 0x302, 0x15D),
+    (0x73, 0x307, 0x1E61), (0x73, 0x30C, 0x161), (0x73, 0x323, 0x1E63),
+    (0x73, 0x326, 0x219), (0x73, 0x327, 0x15F), (0x74, 0x307, 0x1E6B),
+    (0x74, 0x308, 0x1E97), (0x74, 0x30C, 0x165), (0x74, 0x323, 0x1E6D),
+    (0x74, 0x326, 0x21B), (0x74, 0x327, 0x163), (0x74, 0x32D, 0x1E71),
+    (0x74, 0x331, 0x1E6F), (0x75, 0x300, 0xF9), (0x75, 0x301, 0xFA),
+    (0x75, 0x302, 0xFB), (0x75, 0x303, 0x169), (0x75, 0x304, 0x16B),
+    (0x75, 0x306, 0x16D), (0x75, 0x308, 0xFC), (0x75, 0x309, 0x1EE7),
+    (0x75, 0x30A, 0x16F), (0x75, 0x30B, 0x171), (0x75, 0x30C, 0x1D4),
+    (0x75, 0x30F, 0x215), (0x75, 0x311, 0x217), (0x75, 0x31B, 0x1B0),
+    (0x75, 0x323, 0x1EE5), (0x75, 0x324, 0x1E73), (0x75, 0x328, 0x173),
+    (0x75, 0x32D, 0x1E77), (0x75, 0x330, 0x1E75), (0x76, 0x303, 0x1E7D),
+    (0x76, 0x323, 0x1E7F), (0x77, 0x300, 0x1E81), (0x77, 0x301, 0x1E83),
+    (0x77, 0x302, 0x175), (0x77, 0x307, 0x1E87), (0x77, 0x308, 0x1E85),
+    (0x77, 0x30A, 0x1E98), (0x77, 0x323, 0x1E89), (0x78, 0x307, 0x1E8B),
+    (0x78, 0x308, 0x1E8D), (0x79, 0x300, 0x1EF3), (0x79, 0x301, 0xFD),
+    (0x79, 0x302, 0x177), (0x79, 0x303, 0x1EF9), (0x79, 0x304, 0x233),
+    (0x79, 0x307, 0x1E8F), (0x79, 0x308, 0xFF), (0x79, 0x309, 0x1EF7),
+    (0x79, 0x30A, 0x1E99), (0x79, 0x323, 0x1EF5), (0x7A, 0x301, 0x17A),
+    (0x7A, 0x302, 0x1E91), (0x7A, 0x307, 0x17C), (0x7A, 0x30C, 0x17E),
+    (0x7A, 0x323, 0x1E93), (0x7A, 0x331, 0x1E95), (0xA8, 0x300, 0x1FED),
+    (0xA8, 0x301, 0x385), (0xA8, 0x342, 0x1FC1), (0xC2, 0x300, 0x1EA6),
+    (0xC2, 0x301, 0x1EA4), (0xC2, 0x303, 0x1EAA), (0xC2, 0x309, 0x1EA8),
+    (0xC4, 0x304, 0x1DE), (0xC5, 0x301, 0x1FA), (0xC6, 0x301, 0x1FC),
+    (0xC6, 0x304, 0x1E2), (0xC7, 0x301, 0x1E08), (0xCA, 0x300, 0x1EC0),
+    (0xCA, 0x301, 0x1EBE), (0xCA, 0x303, 0x1EC4), (0xCA, 0x309, 0x1EC2),
+    (0xCF, 0x301, 0x1E2E), (0xD4, 0x300, 0x1ED2), (0xD4, 0x301, 0x1ED0),
+    (0xD4, 0x303, 0x1ED6), (0xD4, 0x309, 0x1ED4), (0xD5, 0x301, 0x1E4C),
+    (0xD5, 0x304, 0x22C), (0xD5, 0x308, 0x1E4E), (0xD6, 0x304, 0x22A),
+    (0xD8, 0x301, 0x1FE), (0xDC, 0x300, 0x1DB), (0xDC, 0x301, 0x1D7),
+    (0xDC, 0x304, 0x1D5), (0xDC, 0x30C, 0x1D9), (0xE2, 0x300, 0x1EA7),
+    (0xE2, 0x301, 0x1EA5), (0xE2, 0x303, 0x1EAB), (0xE2, 0x309, 0x1EA9),
+    (0xE4, 0x304, 0x1DF), (0xE5, 0x301, 0x1FB), (0xE6, 0x301, 0x1FD),
+    (0xE6, 0x304, 0x1E3), (0xE7, 0x301, 0x1E09), (0xEA, 0x300, 0x1EC1),
+    (0xEA, 0x301, 0x1EBF), (0xEA, 0x303, 0x1EC5), (0xEA, 0x309, 0x1EC3),
+    (0xEF, 0x301, 0x1E2F), (0xF4, 0x300, 0x1ED3), (0xF4, 0x301, 0x1ED1),
+    (0xF4, 0x303, 0x1ED7), (0xF4, 0x309, 0x1ED5), (0xF5, 0x301, 0x1E4D),
+    (0xF5, 0x304, 0x22D), (0xF5, 0x308, 0x1E4F), (0xF6, 0x304, 0x22B),
+    (0xF8, 0x301, 0x1FF), (0xFC, 0x300, 0x1DC), (0xFC, 0x301, 0x1D8),
+    (0xFC, 0x304, 0x1D6), (0xFC, 0x30C, 0x1DA), (0x102, 0x300, 0x1EB0),
+    (0x102, 0x301, 0x1EAE), (0x102, 0x303, 0x1EB4), (0x102, 0x309, 0x1EB2),
+    (0x103, 0x300, 0x1EB1), (0x103, 0x301, 0x1EAF), (0x103, 0x303, 0x1EB5),
+    (0x103, 0x309, 0x1EB3), (0x112, 0x300, 0x1E14), (0x112, 0x301, 0x1E16),
+    (0x113, 0x300, 0x1E15), (0x113, 0x301, 0x1E17), (0x14C, 0x300, 0x1E50),
+    (0x14C, 0x301, 0x1E52), (0x14D, 0x300, 0x1E51), (0x14D, 0x301, 0x1E53),
+    (0x15A, 0x307, 0x1E64), (0x15B, 0x307, 0x1E65), (0x160, 0x307, 0x1E66),
+    (0x161, 0x307, 0x1E67), (0x168, 0x301, 0x1E78), (0x169, 0x301, 0x1E79),
+    (0x16A, 0x308, 0x1E7A), (0x16B, 0x308, 0x1E7B), (0x17F, 0x307, 0x1E9B),
+    (0x1A0, 0x300, 0x1EDC), (0x1A0, 0x301, 0x1EDA), (0x1A0, 0x303, 0x1EE0),
+    (0x1A0, 0x309, 0x1EDE), (0x1A0, 0x323, 0x1EE2), (0x1A1, 0x300, 0x1EDD),
+    (0x1A1, 0x301, 0x1EDB), (0x1A1, 0x303, 0x1EE1), (0x1A1, 0x309, 0x1EDF),
+    (0x1A1, 0x323, 0x1EE3), (0x1AF, 0x300, 0x1EEA), (0x1AF, 0x301, 0x1EE8),
+    (0x1AF, 0x303, 0x1EEE), (0x1AF, 0x309, 0x1EEC), (0x1AF, 0x323, 0x1EF0),
+    (0x1B0, 0x300, 0x1EEB), (0x1B0, 0x301, 0x1EE9), (0x1B0, 0x303, 0x1EEF),
+    (0x1B0, 0x309, 0x1EED), (0x1B0, 0x323, 0x1EF1), (0x1B7, 0x30C, 0x1EE),
+    (0x1EA, 0x304, 0x1EC), (0x1EB, 0x304, 0x1ED), (0x226, 0x304, 0x1E0),
+    (0x227, 0x304, 0x1E1), (0x228, 0x306, 0x1E1C), (0x229, 0x306, 0x1E1D),
+    (0x22E, 0x304, 0x230), (0x22F, 0x304, 0x231), (0x292, 0x30C, 0x1EF),
+    (0x391, 0x300, 0x1FBA), (0x391, 0x301, 0x386), (0x391, 0x304, 0x1FB9),
+    (0x391, 0x306, 0x1FB8), (0x391, 0x313, 0x1F08), (0x391, 0x314, 0x1F09),
+    (0x391, 0x345, 0x1FBC), (0x395, 0x300, 0x1FC8), (0x395, 0x301, 0x388),
+    (0x395, 0x313, 0x1F18), (0x395, 0x314, 0x1F19), (0x397, 0x300, 0x1FCA),
+    (0x397, 0x301, 0x389), (0x397, 0x313, 0x1F28), (0x397, 0x314, 0x1F29),
+    (0x397, 0x345, 0x1FCC), (0x399, 0x300, 0x1FDA), (0x399, 0x301, 0x38A),
+    (0x399, 0x304, 0x1FD9), (0x399, 0x306, 0x1FD8), (0x399, 0x308, 0x3AA),
+    (0x399, 0x313, 0x1F38), (0x399, 0x314, 0x1F39), (0x39F, 0x300, 0x1FF8),
+    (0x39F, 0x301, 0x38C), (0x39F, 0x313, 0x1F48), (0x39F, 0x314, 0x1F49),
+    (0x3A1, 0x314, 0x1FEC), (0x3A5, 0x300, 0x1FEA), (0x3A5, 0x301, 0x38E),
+    (0x3A5, 0x304, 0x1FE9), (0x3A5, 0x306, 0x1FE8), (0x3A5, 0x308, 0x3AB),
+    (0x3A5, 0x314, 0x1F59), (0x3A9, 0x300, 0x1FFA), (0x3A9, 0x301, 0x38F),
+    (0x3A9, 0x313, 0x1F68), (0x3A9, 0x314, 0x1F69), (0x3A9, 0x345, 0x1FFC),
+    (0x3AC, 0x345, 0x1FB4), (0x3AE, 0x345, 0x1FC4), (0x3B1, 0x300, 0x1F70),
+    (0x3B1, 0x301, 0x3AC), (0x3B1, 0x304, 0x1FB1), (0x3B1, 0x306, 0x1FB0),
+    (0x3B1, 0x313, 0x1F00), (0x3B1, 0x314, 0x1F01), (0x3B1, 0x342, 0x1FB6),
+    (0x3B1, 0x345, 0x1FB3), (0x3B5, 0x300, 0x1F72), (0x3B5, 0x301, 0x3AD),
+    (0x3B5, 0x313, 0x1F10), (0x3B5, 0x314, 0x1F11), (0x3B7, 0x300, 0x1F74),
+    (0x3B7, 0x301, 0x3AE), (0x3B7, 0x313, 0x1F20), (0x3B7, 0x314, 0x1F21),
+    (0x3B7, 0x342, 0x1FC6), (0x3B7, 0x345, 0x1FC3), (0x3B9, 0x300, 0x1F76),
+    (0x3B9, 0x301, 0x3AF), (0x3B9, 0x304, 0x1FD1), (0x3B9, 0x306, 0x1FD0),
+    (0x3B9, 0x308, 0x3CA), (0x3B9, 0x313, 0x1F30), (0x3B9, 0x314, 0x1F31),
+    (0x3B9, 0x342, 0x1FD6), (0x3BF, 0x300, 0x1F78), (0x3BF, 0x301, 0x3CC),
+    (0x3BF, 0x313, 0x1F40), (0x3BF, 0x314, 0x1F41), (0x3C1, 0x313, 0x1FE4),
+    (0x3C1, 0x314, 0x1FE5), (0x3C5, 0x300, 0x1F7A), (0x3C5, 0x301, 0x3CD),
+    (0x3C5, 0x304, 0x1FE1), (0x3C5, 0x306, 0x1FE0), (0x3C5, 0x308, 0x3CB),
+    (0x3C5, 0x313, 0x1F50), (0x3C5, 0x314, 0x1F51), (0x3C5, 0x342, 0x1FE6),
+    (0x3C9, 0x300, 0x1F7C), (0x3C9, 0x301, 0x3CE), (0x3C9, 0x313, 0x1F60),
+    (0x3C9, 0x314, 0x1F61), (0x3C9, 0x342, 0x1FF6), (0x3C9, 0x345, 0x1FF3),
+    (0x3CA, 0x300, 0x1FD2), (0x3CA, 0x301, 0x390), (0x3CA, 0x342, 0x1FD7),
+    (0x3CB, 0x300, 0x1FE2), (0x3CB, 0x301, 0x3B0), (0x3CB, 0x342, 0x1FE7),
+    (0x3CE, 0x345, 0x1FF4), (0x3D2, 0x301, 0x3D3), (0x3D2, 0x308, 0x3D4),
+    (0x406, 0x308, 0x407), (0x410, 0x306, 0x4D0), (0x410, 0x308, 0x4D2),
+    (0x413, 0x301, 0x403), (0x415, 0x300, 0x400), (0x415, 0x306, 0x4D6),
+    (0x415, 0x308, 0x401), (0x416, 0x306, 0x4C1), (0x416, 0x308, 0x4DC),
+    (0x417, 0x308, 0x4DE), (0x418, 0x300, 0x40D), (0x418, 0x304, 0x4E2),
+    (0x418, 0x306, 0x419), (0x418, 0x308, 0x4E4), (0x41A, 0x301, 0x40C),
+    (0x41E, 0x308, 0x4E6), (0x423, 0x304, 0x4EE), (0x423, 0x306, 0x40E),
+    (0x423, 0x308, 0x4F0), (0x423, 0x30B, 0x4F2), (0x427, 0x308, 0x4F4),
+    (0x42B, 0x308, 0x4F8), (0x42D, 0x308, 0x4EC), (0x430, 0x306, 0x4D1),
+    (0x430, 0x308, 0x4D3), (0x433, 0x301, 0x453), (0x435, 0x300, 0x450),
+    (0x435, 0x306, 0x4D7), (0x435, 0x308, 0x451), (0x436, 0x306, 0x4C2),
+    (0x436, 0x308, 0x4DD), (0x437, 0x308, 0x4DF), (0x438, 0x300, 0x45D),
+    (0x438, 0x304, 0x4E3), (0x438, 0x306, 0x439), (0x438, 0x308, 0x4E5),
+    (0x43A, 0x301, 0x45C), (0x43E, 0x308, 0x4E7), (0x443, 0x304, 0x4EF),
+    (0x443, 0x306, 0x45E), (0x443, 0x308, 0x4F1), (0x443, 0x30B, 0x4F3),
+    (0x447, 0x308, 0x4F5), (0x44B, 0x308, 0x4F9), (0x44D, 0x308, 0x4ED),
+    (0x456, 0x308, 0x457), (0x474, 0x30F, 0x476), (0x475, 0x30F, 0x477),
+    (0x4D8, 0x308, 0x4DA), (0x4D9, 0x308, 0x4DB), (0x4E8, 0x308, 0x4EA),
+    (0x4E9, 0x308, 0x4EB), (0x627, 0x653, 0x622), (0x627, 0x654, 0x623),
+    (0x627, 0x655, 0x625), (0x648, 0x654, 0x624), (0x64A, 0x654, 0x626),
+    (0x6C1, 0x654, 0x6C2), (0x6D2, 0x654, 0x6D3), (0x6D5, 0x654, 0x6C0),
+    (0x928, 0x93C, 0x929), (0x930, 0x93C, 0x931), (0x933, 0x93C, 0x934),
+    (0x9C7, 0x9BE, 0x9CB), (0x9C7, 0x9D7, 0x9CC), (0xB47, 0xB3E, 0xB4B),
+    (0xB47, 0xB56, 0xB48), (0xB47, 0xB57, 0xB4C), (0xB92, 0xBD7, 0xB94),
+    (0xBC6, 0xBBE, 0xBCA), (0xBC6, 0xBD7, 0xBCC), (0xBC7, 0xBBE, 0xBCB),
+    (0xC46, 0xC56, 0xC48), (0xCBF, 0xCD5, 0xCC0), (0xCC6, 0xCC2, 0xCCA),
+    (0xCC6, 0xCD5, 0xCC7), (0xCC6, 0xCD6, 0xCC8), (0xCCA, 0xCD5, 0xCCB),
+    (0xD46, 0xD3E, 0xD4A), (0xD46, 0xD57, 0xD4C), (0xD47, 0xD3E, 0xD4B),
+    (0xDD9, 0xDCA, 0xDDA), (0xDD9, 0xDCF, 0xDDC), (0xDD9, 0xDDF, 0xDDE),
+    (0xDDC, 0xDCA, 0xDDD), (0x1025, 0x102E, 0x1026), (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08), (0x1B09, 0x1B35, 0x1B0A), (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E), (0x1B11, 0x1B35, 0x1B12), (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D), (0x1B3E, 0x1B35, 0x1B40), (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43), (0x1E36, 0x304, 0x1E38), (0x1E37, 0x304, 0x1E39),
+    (0x1E5A, 0x304, 0x1E5C), (0x1E5B, 0x304, 0x1E5D), (0x1E62, 0x307, 0x1E68),
+    (0x1E63, 0x307, 0x1E69), (0x1EA0, 0x302, 0x1EAC), (0x1EA0, 0x306, 0x1EB6),
+    (0x1EA1, 0x302, 0x1EAD), (0x1EA1, 0x306, 0x1EB7), (0x1EB8, 0x302, 0x1EC6),
+    (0x1EB9, 0x302, 0x1EC7), (0x1ECC, 0x302, 0x1ED8), (0x1ECD, 0x302, 0x1ED9),
+    (0x1F00, 0x300, 0x1F02), (0x1F00, 0x301, 0x1F04), (0x1F00, 0x342, 0x1F06),
+    (0x1F00, 0x345, 0x1F80), (0x1F01, 0x300, 0x1F03), (0x1F01, 0x301, 0x1F05),
+    (0x1F01, 0x342, 0x1F07), (0x1F01, 0x345, 0x1F81), (0x1F02, 0x345, 0x1F82),
+    (0x1F03, 0x345, 0x1F83), (0x1F04, 0x345, 0x1F84), (0x1F05, 0x345, 0x1F85),
+    (0x1F06, 0x345, 0x1F86), (0x1F07, 0x345, 0x1F87), (0x1F08, 0x300, 0x1F0A),
+    (0x1F08, 0x301, 0x1F0C), (0x1F08, 0x342, 0x1F0E), (0x1F08, 0x345, 0x1F88),
+    (0x1F09, 0x300, 0x1F0B), (0x1F09, 0x301, 0x1F0D), (0x1F09, 0x342, 0x1F0F),
+    (0x1F09, 0x345, 0x1F89), (0x1F0A, 0x345, 0x1F8A), (0x1F0B, 0x345, 0x1F8B),
+    (0x1F0C, 0x345, 0x1F8C), (0x1F0D, 0x345, 0x1F8D), (0x1F0E, 0x345, 0x1F8E),
+    (0x1F0F, 0x345, 0x1F8F), (0x1F10, 0x300, 0x1F12), (0x1F10, 0x301, 0x1F14),
+    (0x1F11, 0x300, 0x1F13), (0x1F11, 0x301, 0x1F15), (0x1F18, 0x300, 0x1F1A),
+    (0x1F18, 0x301, 0x1F1C), (0x1F19, 0x300, 0x1F1B), (0x1F19, 0x301, 0x1F1D),
+    (0x1F20, 0x300, 0x1F22), (0x1F20, 0x301, 0x1F24), (0x1F20, 0x342, 0x1F26),
+    (0x1F20, 0x345, 0x1F90), (0x1F21, 0x300, 0x1F23), (0x1F21, 0x301, 0x1F25),
+    (0x1F21, 0x342, 0x1F27), (0x1F21, 0x345, 0x1F91), (0x1F22, 0x345, 0x1F92),
+    (0x1F23, 0x345, 0x1F93), (0x1F24, 0x345, 0x1F94), (0x1F25, 0x345, 0x1F95),
+    (0x1F26, 0x345, 0x1F96), (0x1F27, 0x345, 0x1F97), (0x1F28, 0x300, 0x1F2A),
+    (0x1F28, 0x301, 0x1F2C), (0x1F28, 0x342, 0x1F2E), (0x1F28, 0x345, 0x1F98),
+    (0x1F29, 0x300, 0x1F2B), (0x1F29, 0x301, 0x1F2D), (0x1F29, 0x342, 0x1F2F),
+    (0x1F29, 0x345, 0x1F99), (0x1F2A, 0x345, 0x1F9A), (0x1F2B, 0x345, 0x1F9B),
+    (0x1F2C, 0x345, 0x1F9C), (0x1F2D, 0x345, 0x1F9D), (0x1F2E, 0x345, 0x1F9E),
+    (0x1F2F, 0x345, 0x1F9F), (0x1F30, 0x300, 0x1F32), (0x1F30, 0x301, 0x1F34),
+    (0x1F30, 0x342, 0x1F36), (0x1F31, 0x300, 0x1F33), (0x1F31, 0x301, 0x1F35),
+    (0x1F31, 0x342, 0x1F37), (0x1F38, 0x300, 0x1F3A), (0x1F38, 0x301, 0x1F3C),
+    (0x1F38, 0x342, 0x1F3E), (0x1F39, 0x300, 0x1F3B), (0x1F39, 0x301, 0x1F3D),
+    (0x1F39, 0x342, 0x1F3F), (0x1F40, 0x300, 0x1F42), (0x1F40, 0x301, 0x1F44),
+    (0x1F41, 0x300, 0x1F43), (0x1F41, 0x301, 0x1F45), (0x1F48, 0x300, 0x1F4A),
+    (0x1F48, 0x301, 0x1F4C), (0x1F49, 0x300, 0x1F4B), (0x1F49, 0x301, 0x1F4D),
+    (0x1F50, 0x300, 0x1F52), (0x1F50, 0x301, 0x1F54), (0x1F50, 0x342, 0x1F56),
+    (0x1F51, 0x300, 0x1F53), (0x1F51, 0x301, 0x1F55), (0x1F51, 0x342, 0x1F57),
+    (0x1F59, 0x300, 0x1F5B), (0x1F59, 0x301, 0x1F5D), (0x1F59, 0x342, 0x1F5F),
+    (0x1F60, 0x300, 0x1F62), (0x1F60, 0x301, 0x1F64), (0x1F60, 0x342, 0x1F66),
+    (0x1F60, 0x345, 0x1FA0), (0x1F61, 0x300, 0x1F63), (0x1F61, 0x301, 0x1F65),
+    (0x1F61, 0x342, 0x1F67), (0x1F61, 0x345, 0x1FA1), (0x1F62, 0x345, 0x1FA2),
+    (0x1F63, 0x345, 0x1FA3), (0x1F64, 0x345, 0x1FA4), (0x1F65, 0x345, 0x1FA5),
+    (0x1F66, 0x345, 0x1FA6), (0x1F67, 0x345, 0x1FA7), (0x1F68, 0x300, 0x1F6A),
+    (0x1F68, 0x301, 0x1F6C), (0x1F68, 0x342, 0x1F6E), (0x1F68, 0x345, 0x1FA8),
+    (0x1F69, 0x300, 0x1F6B), (0x1F69, 0x301, 0x1F6D), (0x1F69, 0x342, 0x1F6F),
+    (0x1F69, 0x345, 0x1FA9), (0x1F6A, 0x345, 0x1FAA), (0x1F6B, 0x345, 0x1FAB),
+    (0x1F6C, 0x345, 0x1FAC), (0x1F6D, 0x345, 0x1FAD), (0x1F6E, 0x345, 0x1FAE),
+    (0x1F6F, 0x345, 0x1FAF), (0x1F70, 0x345, 0x1FB2), (0x1F74, 0x345, 0x1FC2),
+    (0x1F7C, 0x345, 0x1FF2), (0x1FB6, 0x345, 0x1FB7), (0x1FBF, 0x300, 0x1FCD),
+    (0x1FBF, 0x301, 0x1FCE), (0x1FBF, 0x342, 0x1FCF), (0x1FC6, 0x345, 0x1FC7),
+    (0x1FF6, 0x345, 0x1FF7), (0x1FFE, 0x300, 0x1FDD), (0x1FFE, 0x301, 0x1FDE),
+    (0x1FFE, 0x342, 0x1FDF), (0x2190, 0x338, 0x219A), (0x2192, 0x338, 0x219B),
+    (0x2194, 0x338, 0x21AE), (0x21D0, 0x338, 0x21CD), (0x21D2, 0x338, 0x21CF),
+    (0x21D4, 0x338, 0x21CE), (0x2203, 0x338, 0x2204), (0x2208, 0x338, 0x2209),
+    (0x220B, 0x338, 0x220C), (0x2223, 0x338, 0x2224), (0x2225, 0x338, 0x2226),
+    (0x223C, 0x338, 0x2241), (0x2243, 0x338, 0x2244), (0x2245, 0x338, 0x2247),
+    (0x2248, 0x338, 0x2249), (0x224D, 0x338, 0x226D), (0x2261, 0x338, 0x2262),
+    (0x2264, 0x338, 0x2270), (0x2265, 0x338, 0x2271), (0x2272, 0x338, 0x2274),
+    (0x2273, 0x338, 0x2275), (0x2276, 0x338, 0x2278), (0x2277, 0x338, 0x2279),
+    (0x227A, 0x338, 0x2280), (0x227B, 0x338, 0x2281), (0x227C, 0x338, 0x22E0),
+    (0x227D, 0x338, 0x22E1), (0x2282, 0x338, 0x2284), (0x2283, 0x338, 0x2285),
+    (0x2286, 0x338, 0x2288), (0x2287, 0x338, 0x2289), (0x2291, 0x338, 0x22E2),
+    (0x2292, 0x338, 0x22E3), (0x22A2, 0x338, 0x22AC), (0x22A8, 0x338, 0x22AD),
+    (0x22A9, 0x338, 0x22AE), (0x22AB, 0x338, 0x22AF), (0x22B2, 0x338, 0x22EA),
+    (0x22B3, 0x338, 0x22EB), (0x22B4, 0x338, 0x22EC), (0x22B5, 0x338, 0x22ED),
+    (0x3046, 0x3099, 0x3094), (0x304B, 0x3099, 0x304C), (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050), (0x3051, 0x3099, 0x3052), (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056), (0x3057, 0x3099, 0x3058), (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C), (0x305D, 0x3099, 0x305E), (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062), (0x3064, 0x3099, 0x3065), (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069), (0x306F, 0x3099, 0x3070), (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073), (0x3072, 0x309A, 0x3074), (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077), (0x3078, 0x3099, 0x3079), (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C), (0x307B, 0x309A, 0x307D), (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4), (0x30AB, 0x3099, 0x30AC), (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0), (0x30B1, 0x3099, 0x30B2), (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6), (0x30B7, 0x3099, 0x30B8), (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC), (0x30BD, 0x3099, 0x30BE), (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2), (0x30C4, 0x3099, 0x30C5), (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9), (0x30CF, 0x3099, 0x30D0), (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3), (0x30D2, 0x309A, 0x30D4), (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7), (0x30D8, 0x3099, 0x30D9), (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC), (0x30DB, 0x309A, 0x30DD), (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8), (0x30F1, 0x3099, 0x30F9), (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE), (0x11099, 0x110BA, 0x1109A), (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB), (0x11131, 0x11127, 0x1112E), (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B), (0x11347, 0x11357, 0x1134C), (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB), (0x114B9, 0x114BD, 0x114BE), (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB), (0x11935, 0x11930, 0x11938),
+];
+
+/// Characters that can combine with a preceding character (`NFC_Quick_Check=Maybe`), as
+/// `(first, last)` ranges
+pub(super) static COMPOSITION_SECONDS: [(u32, u32); 42] = [
+    (0x300, 0x304), (0x306, 0x30C), (0x30F, 0x30F), (0x311, 0x311),
+    (0x313, 0x314), (0x31B, 0x31B), (0x323, 0x328), (0x32D, 0x32E),
+    (0x330, 0x331), (0x338, 0x338), (0x342, 0x342), (0x345, 0x345),
+    (0x653, 0x655), (0x93C, 0x93C), (0x9BE, 0x9BE), (0x9D7, 0x9D7),
+    (0xB3E, 0xB3E), (0xB56, 0xB57), (0xBBE, 0xBBE), (0xBD7, 0xBD7),
+    (0xC56, 0xC56), (0xCC2, 0xCC2), (0xCD5, 0xCD6), (0xD3E, 0xD3E),
+    (0xD57, 0xD57), (0xDCA, 0xDCA), (0xDCF, 0xDCF), (0xDDF, 0xDDF),
+    (0x102E, 0x102E), (0x1161, 0x1175), (0x11A8, 0x11C2), (0x1B35, 0x1B35),
+    (0x3099, 0x309A), (0x110BA, 0x110BA), (0x11127, 0x11127), (0x1133E, 0x1133E),
+    (0x11357, 0x11357), (0x114B0, 0x114B0), (0x114BA, 0x114BA), (0x114BD, 0x114BD),
+    (0x115AF, 0x115AF), (0x11930, 0x11930),
+];
+
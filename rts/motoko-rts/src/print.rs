@@ -3,6 +3,8 @@
 // Debug functions are not always used
 #![allow(unused)]
 
+use crate::types::SkewedPtr;
+
 use core::fmt;
 
 extern "C" {
@@ -78,6 +80,12 @@ impl<'a> WriteBuf<'a> {
         self.offset = 0;
     }
 
+    /// Returns the written part of the buffer. Only valid when the buffer was not cut off in the
+    /// middle of a UTF-8 sequence.
+    pub(crate) unsafe fn as_str(&self) -> &str {
+        core::str::from_utf8_unchecked(&self.buf[..self.offset])
+    }
+
     pub(crate) unsafe fn print(&self) {
         print_ptr(self.buf.as_ptr() as usize, self.offset as u32)
     }
@@ -103,6 +111,12 @@ pub(crate) unsafe fn print(buf: &WriteBuf) {
     buf.print()
 }
 
+/// Prints the contents of a blob, e.g. a text frozen from a text builder
+pub(crate) unsafe fn print_blob(blob: SkewedPtr) {
+    let blob = blob.as_blob();
+    print_ptr(blob.payload_addr() as usize, blob.len().0)
+}
+
 // pub(crate) unsafe fn print_str(str: &str) {
 //     print_ptr(str.as_ptr() as usize, str.len() as u32)
 // }
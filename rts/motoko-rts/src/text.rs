@@ -28,20 +28,20 @@
 use crate::mem_utils::memcpy_bytes;
 use crate::memory::{alloc_blob, Memory};
 use crate::rts_trap_with;
+use crate::text_index::{blob_char_offset, blob_len_chars};
 use crate::types::{size_of, Blob, Bytes, Concat, SkewedPtr, TAG_BLOB, TAG_CONCAT};
 
 use core::cmp::{min, Ordering};
-use core::{slice, str};
 
 use motoko_rts_macros::ic_mem_fn;
 
-const MAX_STR_SIZE: Bytes<u32> = Bytes((1 << 30) - 1);
+pub(crate) const MAX_STR_SIZE: Bytes<u32> = Bytes((1 << 30) - 1);
 
 // Strings smaller than this must be blobs
 // Make this MAX_STR_SIZE to disable the use of ropes completely, e.g. for debugging
 const MIN_CONCAT_SIZE: Bytes<u32> = Bytes(9);
 
-unsafe fn alloc_text_blob<M: Memory>(mem: &mut M, size: Bytes<u32>) -> SkewedPtr {
+pub(crate) unsafe fn alloc_text_blob<M: Memory>(mem: &mut M, size: Bytes<u32>) -> SkewedPtr {
     if size > MAX_STR_SIZE {
         rts_trap_with("alloc_text_bloc: Text too large");
     }
@@ -120,7 +120,7 @@ struct Crumb {
 }
 
 #[no_mangle]
-unsafe extern "C" fn text_to_buf(mut s: SkewedPtr, mut buf: *mut u8) {
+pub(crate) unsafe extern "C" fn text_to_buf(mut s: SkewedPtr, mut buf: *mut u8) {
     let mut next_crumb: *const Crumb = core::ptr::null();
 
     loop {
@@ -333,24 +333,121 @@ pub(crate) unsafe fn blob_compare(s1: SkewedPtr, s2: SkewedPtr) -> i32 {
     }
 }
 
-/// Length in characters
-#[no_mangle]
-pub unsafe extern "C" fn text_len(text: SkewedPtr) -> u32 {
+/// Length in characters. Large blobs are indexed on first use (see `text_index`).
+#[ic_mem_fn]
+pub unsafe fn text_len<M: Memory>(mem: &mut M, text: SkewedPtr) -> u32 {
     if text.tag() == TAG_BLOB {
-        let blob = text.as_blob();
-        let payload_addr = blob.payload_addr();
-        let len = blob.len();
-
-        str::from_utf8_unchecked(slice::from_raw_parts(
-            payload_addr as *const u8,
-            len.0 as usize,
-        ))
-        .chars()
-        .count() as u32
+        blob_len_chars(mem, text)
     } else {
         let concat = text.as_concat();
-        text_len(concat.text1()) + text_len(concat.text2())
+        let len1 = text_len(mem, concat.text1());
+        len1 + text_len(mem, concat.text2())
+    }
+}
+
+/// Finds the blob that holds the character at index `idx` of a text of `len` characters. Returns
+/// the blob, the byte offset of the character in the blob, and the byte offset of the blob in the
+/// text. `idx` can be the length of the text, in which case the offsets are for the end of the
+/// last blob. Assumes `idx` is in range.
+unsafe fn text_char_position<M: Memory>(
+    mem: &mut M,
+    mut text: SkewedPtr,
+    mut len: u32,
+    mut idx: u32,
+) -> (SkewedPtr, Bytes<u32>, Bytes<u32>) {
+    let mut blob_offset = Bytes(0);
+
+    while text.tag() == TAG_CONCAT {
+        let concat = text.as_concat();
+        let left = concat.text1();
+        let right = concat.text2();
+
+        // Only count the characters of the smaller side. The smaller sides on the path to the
+        // blob are disjoint, so this is linear in the size of the text, also for deep ropes.
+        let left_len = if text_size(left) <= text_size(right) {
+            text_len(mem, left)
+        } else {
+            len - text_len(mem, right)
+        };
+
+        if idx < left_len {
+            text = left;
+            len = left_len;
+        } else {
+            idx -= left_len;
+            len -= left_len;
+            blob_offset += text_size(left);
+            text = right;
+        }
+    }
+
+    (text, blob_char_offset(mem, text, idx), blob_offset)
+}
+
+/// Returns the character at index `idx`. Traps if the index is out of bounds.
+#[ic_mem_fn]
+pub unsafe fn text_char_at<M: Memory>(mem: &mut M, text: SkewedPtr, idx: u32) -> u32 {
+    let len = text_len(mem, text);
+    if idx >= len {
+        rts_trap_with("text_char_at: index out of bounds");
     }
+
+    let (blob, offset, _) = text_char_position(mem, text, len, idx);
+    let mut size = 0;
+    decode_code_point(
+        blob.as_blob().payload_addr().add(offset.as_usize()),
+        &mut size,
+    )
+}
+
+/// Copies `n` bytes of the text, starting from the given offset, to the buffer. Assumes the range
+/// is valid.
+unsafe fn text_range_to_buf(s: SkewedPtr, offset: Bytes<u32>, n: Bytes<u32>, buf: *mut u8) {
+    let (s, offset) = text_get_range(s, offset, n);
+
+    if s.tag() == TAG_CONCAT {
+        let concat = s.as_concat();
+        let left = concat.text1();
+        let n_left = text_size(left) - offset;
+        text_range_to_buf(left, offset, n_left, buf);
+        text_range_to_buf(
+            concat.text2(),
+            Bytes(0),
+            n - n_left,
+            buf.add(n_left.as_usize()),
+        );
+    } else {
+        let payload = s.as_blob().payload_addr();
+        memcpy_bytes(buf as usize, payload.add(offset.as_usize()) as usize, n);
+    }
+}
+
+/// Returns the characters from index `start` (inclusive) to `end` (exclusive). Traps if the range
+/// is out of bounds.
+#[ic_mem_fn]
+pub unsafe fn text_slice_chars<M: Memory>(
+    mem: &mut M,
+    text: SkewedPtr,
+    start: u32,
+    end: u32,
+) -> SkewedPtr {
+    let len = text_len(mem, text);
+    if start > end || end > len {
+        rts_trap_with("text_slice_chars: range out of bounds");
+    }
+
+    let (_, start_offset, start_blob_offset) = text_char_position(mem, text, len, start);
+    let (_, end_offset, end_blob_offset) = text_char_position(mem, text, len, end);
+    let start_offset = start_blob_offset + start_offset;
+    let n = end_blob_offset + end_offset - start_offset;
+
+    if n == text_size(text) {
+        return text;
+    }
+
+    let blob = alloc_text_blob(mem, n);
+    text_range_to_buf(text, start_offset, n, blob.as_blob().payload_addr());
+    blob
 }
 
 /// Decodes the character at the pointer. Returns the character, the size via the `size` parameter
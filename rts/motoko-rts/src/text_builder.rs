@@ -0,0 +1,186 @@
+//! Text builders: mutable buffers for building texts by appending, without allocating a concat
+//! node per append
+//!
+//! The buffer is a blob with the capacity of the builder as its length, which grows geometrically
+//! to keep appends amortized constant time. Freezing a builder shrinks the buffer in place to the
+//! number of bytes written and returns it as the text, so no copy is needed. The builder is empty
+//! after freezing and can be used again.
+
+// Layout of a text builder:
+//
+//      ┌────────────────────┬─────────┬─────┐
+//      │ tag (text builder) │ n_bytes │ buf │
+//      └────────────────────┴─────────┴─────┘
+//
+// `buf` is 0 when nothing has been written to the builder since its creation or last freeze.
+
+use crate::mem_utils::{memcpy_bytes, memzero};
+use crate::memory::Memory;
+use crate::rts_trap_with;
+use crate::text::{alloc_text_blob, text_size, text_to_buf, MAX_STR_SIZE};
+use crate::types::{size_of, Bytes, SkewedPtr, TextBuilder, TAG_TEXT_BUILDER};
+use crate::utf8::utf8_validate;
+
+use core::cmp::{max, min};
+use core::fmt;
+
+use motoko_rts_macros::ic_mem_fn;
+
+/// Capacity of the buffer allocated on the first append
+const MIN_CAPACITY: u32 = 16;
+
+/// Returns a new, empty text builder
+#[ic_mem_fn]
+pub unsafe fn text_builder_new<M: Memory>(mem: &mut M) -> SkewedPtr {
+    let ptr = mem.alloc_words(size_of::<TextBuilder>());
+    let builder = ptr.unskew() as *mut TextBuilder;
+    (*builder).header.tag = TAG_TEXT_BUILDER;
+    (*builder).n_bytes = Bytes(0);
+    (*builder).buf = SkewedPtr(0);
+    ptr
+}
+
+/// Returns the number of bytes written to the builder
+#[no_mangle]
+pub unsafe extern "C" fn text_builder_size(builder: SkewedPtr) -> Bytes<u32> {
+    (*builder.as_text_builder()).n_bytes
+}
+
+/// Makes room for `n` more bytes in the buffer, growing it if necessary. Returns the address to
+/// write the bytes to.
+unsafe fn reserve<M: Memory>(mem: &mut M, builder: SkewedPtr, n: Bytes<u32>) -> *mut u8 {
+    let builder = builder.as_text_builder();
+
+    let len = (*builder).n_bytes;
+    let new_len = match len.0.checked_add(n.0) {
+        Some(new_len) if Bytes(new_len) <= MAX_STR_SIZE => Bytes(new_len),
+        _ => rts_trap_with("text_builder: Text too large"),
+    };
+
+    let buf = (*builder).buf;
+    let capacity = if buf == SkewedPtr(0) {
+        Bytes(0)
+    } else {
+        buf.as_blob().len()
+    };
+
+    if new_len > capacity {
+        let new_capacity = max(
+            new_len,
+            min(Bytes(max(capacity.0 * 2, MIN_CAPACITY)), MAX_STR_SIZE),
+        );
+        let new_buf = alloc_text_blob(mem, new_capacity);
+        if len.0 != 0 {
+            memcpy_bytes(
+                new_buf.as_blob().payload_addr() as usize,
+                buf.as_blob().payload_addr() as usize,
+                len,
+            );
+        }
+        (*builder).buf = new_buf;
+    }
+
+    (*builder).n_bytes = new_len;
+
+    (*builder).buf.as_blob().payload_addr().add(len.as_usize())
+}
+
+unsafe fn append_bytes<M: Memory>(
+    mem: &mut M,
+    builder: SkewedPtr,
+    bytes: *const u8,
+    n: Bytes<u32>,
+) {
+    if n.0 == 0 {
+        return;
+    }
+    let dst = reserve(mem, builder, n);
+    memcpy_bytes(dst as usize, bytes as usize, n);
+}
+
+/// Appends a text to the builder
+#[ic_mem_fn]
+pub unsafe fn text_builder_append_text<M: Memory>(
+    mem: &mut M,
+    builder: SkewedPtr,
+    text: SkewedPtr,
+) {
+    let n = text_size(text);
+    if n.0 == 0 {
+        return;
+    }
+    let dst = reserve(mem, builder, n);
+    text_to_buf(text, dst);
+}
+
+/// Appends a character to the builder
+#[ic_mem_fn]
+pub unsafe fn text_builder_append_char<M: Memory>(mem: &mut M, builder: SkewedPtr, char: u32) {
+    let mut buf = [0u8; 4];
+    let str = core::char::from_u32_unchecked(char).encode_utf8(&mut buf);
+    append_bytes(mem, builder, str.as_ptr(), Bytes(str.len() as u32));
+}
+
+/// Appends the contents of a blob to the builder. Traps if the blob is not valid UTF-8.
+#[ic_mem_fn]
+pub unsafe fn text_builder_append_utf8<M: Memory>(
+    mem: &mut M,
+    builder: SkewedPtr,
+    blob: SkewedPtr,
+) {
+    let blob = blob.as_blob();
+    let len = blob.len();
+    utf8_validate(blob.payload_addr() as *const _, len.0);
+    append_bytes(mem, builder, blob.payload_addr(), len);
+}
+
+/// Returns the text written to the builder, and empties the builder
+#[ic_mem_fn]
+pub unsafe fn text_builder_freeze<M: Memory>(mem: &mut M, builder: SkewedPtr) -> SkewedPtr {
+    let builder = builder.as_text_builder();
+
+    let len = (*builder).n_bytes;
+    let buf = (*builder).buf;
+
+    (*builder).n_bytes = Bytes(0);
+    (*builder).buf = SkewedPtr(0);
+
+    if buf == SkewedPtr(0) {
+        return alloc_text_blob(mem, Bytes(0));
+    }
+
+    // Shrink the buffer in place. The words after the new end are filled with zeros, which the
+    // heap walkers skip (see `object_size`).
+    let blob = buf.as_blob();
+    let old_words = blob.len().to_words();
+    let new_words = len.to_words();
+    memzero(
+        blob.payload_addr().add(new_words.to_bytes().as_usize()) as usize,
+        old_words - new_words,
+    );
+    (*blob).len = len;
+
+    buf
+}
+
+/// Allows the RTS to write formatted text to a text builder with `write!`. The memory is a raw
+/// pointer, so that the code writing can allocate in the same memory, e.g. when rendering Candid
+/// values. There's no GC during RTS calls, so the builder stays in place.
+pub struct TextBuilderWriter<M: Memory> {
+    pub mem: *mut M,
+    pub builder: SkewedPtr,
+}
+
+impl<M: Memory> fmt::Write for TextBuilderWriter<M> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        unsafe {
+            append_bytes(
+                &mut *self.mem,
+                self.builder,
+                s.as_ptr(),
+                Bytes(s.len() as u32),
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+//! Character indices of large text blobs
+//!
+//! Finding the n-th character of a UTF-8 encoded blob needs a scan from the beginning of the blob.
+//! To make `text_len` and indexing by character position fast on repeated use, we compute, on
+//! first use, an index for blobs of at least `MIN_INDEXED_SIZE` bytes. The index is a blob of
+//! 32-bit words:
+//!
+//!  - Word 0 is the number of characters in the blob
+//!  - Word k (k > 0) is the byte offset of character `k * STRIDE`
+//!
+//! When all characters of the blob are ASCII we only store the number of characters, as byte and
+//! character offsets are the same.
+//!
+//! Indices are kept in a small table with round-robin replacement. The table is a heap-allocated
+//! array of pairs of a blob and its index. Like the closure table, the array is a GC root: GC
+//! moves the blobs and indices in the table and updates the table. This keeps the last
+//! `CACHE_SIZE` indexed blobs alive until they are replaced in the table.
+
+use crate::memory::{alloc_array, alloc_blob, Memory};
+use crate::types::{Blob, Bytes, SkewedPtr};
+
+use core::{slice, str};
+
+/// Blobs smaller than this are not indexed, scanning them is fast enough
+const MIN_INDEXED_SIZE: Bytes<u32> = Bytes(512);
+
+/// Number of characters between two byte offsets stored in an index
+const STRIDE: u32 = 64;
+
+/// Number of indices kept in the table
+const CACHE_SIZE: u32 = 16;
+
+/// Skewed pointer to the table, an array of `2 * CACHE_SIZE` elements: blobs at even indices,
+/// their indices at odd indices. Unused entries are 0. The table is allocated on first use, this
+/// needs to be a skewed pointer to be able to pass its location to the GC.
+static mut CACHE: SkewedPtr = SkewedPtr(0);
+
+/// Entry to replace when adding an index to a full table
+static mut NEXT_ENTRY: u32 = 0;
+
+/// Location of the table, to be passed to the GC as a root
+pub unsafe fn text_index_cache_loc() -> *mut SkewedPtr {
+    &mut CACHE
+}
+
+/// Drops the table. Only needed when the heap the table is allocated in is discarded, as in tests.
+pub unsafe fn text_index_cache_reset() {
+    CACHE = SkewedPtr(0);
+    NEXT_ENTRY = 0;
+}
+
+unsafe fn blob_str<'a>(blob: *mut Blob) -> &'a str {
+    str::from_utf8_unchecked(slice::from_raw_parts(
+        blob.payload_addr(),
+        blob.len().as_usize(),
+    ))
+}
+
+/// Returns the index of a blob, building it if it's not in the table. Returns `None` for blobs
+/// that are too small to be indexed.
+unsafe fn blob_index<M: Memory>(mem: &mut M, blob: SkewedPtr) -> Option<*const u32> {
+    let len = blob.as_blob().len();
+
+    if len < MIN_INDEXED_SIZE {
+        return None;
+    }
+
+    if CACHE.0 == 0 {
+        CACHE = alloc_array(mem, 2 * CACHE_SIZE);
+        for i in 0..2 * CACHE_SIZE {
+            CACHE.as_array().set(i, SkewedPtr(0));
+        }
+    }
+
+    let cache = CACHE.as_array();
+    for i in 0..CACHE_SIZE {
+        if cache.get(2 * i).0 == blob.0 {
+            return Some(cache.get(2 * i + 1).as_blob().payload_addr() as *const u32);
+        }
+    }
+
+    let str = blob_str(blob.as_blob());
+    let n_chars = str.chars().count() as u32;
+
+    let n_words = if n_chars == len.0 {
+        1
+    } else {
+        1 + n_chars / STRIDE
+    };
+
+    // NB. Allocation does not move `blob` or the table, GC does not run during RTS calls
+    let index = alloc_blob(mem, Bytes(n_words * 4));
+    let words = index.as_blob().payload_addr() as *mut u32;
+    *words = n_chars;
+
+    if n_words > 1 {
+        let mut k = 1;
+        for (char_idx, (offset, _)) in str.char_indices().enumerate() {
+            if char_idx as u32 == k * STRIDE {
+                *words.add(k as usize) = offset as u32;
+                k += 1;
+            }
+        }
+        // Offset of the end of the blob, when the number of characters is a multiple of `STRIDE`
+        if k < n_words {
+            *words.add(k as usize) = len.0;
+        }
+    }
+
+    cache.set(2 * NEXT_ENTRY, blob);
+    cache.set(2 * NEXT_ENTRY + 1, index);
+    NEXT_ENTRY = (NEXT_ENTRY + 1) % CACHE_SIZE;
+
+    Some(words)
+}
+
+/// Number of characters in a blob
+pub(crate) unsafe fn blob_len_chars<M: Memory>(mem: &mut M, blob: SkewedPtr) -> u32 {
+    match blob_index(mem, blob) {
+        Some(index) => *index,
+        None => blob_str(blob.as_blob()).chars().count() as u32,
+    }
+}
+
+/// Byte offset of the character at index `idx` of a blob. `idx` can be the number of characters
+/// in the blob, in which case the result is the length of the blob. Assumes `idx` is in range.
+pub(crate) unsafe fn blob_char_offset<M: Memory>(
+    mem: &mut M,
+    blob: SkewedPtr,
+    idx: u32,
+) -> Bytes<u32> {
+    let (mut offset, mut n_skip) = match blob_index(mem, blob) {
+        None => (0, idx),
+        Some(index) => {
+            let len = blob.as_blob().len();
+            if *index == len.0 {
+                // ASCII
+                return Bytes(idx);
+            }
+            let k = idx / STRIDE;
+            let offset = if k == 0 { 0 } else { *index.add(k as usize) };
+            (offset, idx % STRIDE)
+        }
+    };
+
+    let payload = blob.as_blob().payload_addr();
+    while n_skip != 0 {
+        let lead = *payload.add(offset as usize);
+        offset += core::cmp::max(lead.leading_ones(), 1);
+        n_skip -= 1;
+    }
+
+    Bytes(offset)
+}
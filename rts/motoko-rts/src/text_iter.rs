@@ -9,11 +9,25 @@
 //! The linked list is a tuple (array) with
 //! 1. A pointer to the text
 //! 2. 0, or a pointer to the next list entry
+//!
+//! Reverse iterators use the same triple, but iterate from the right-most leaf to the left, the
+//! position is the end of the next character, and the list holds the texts to the left of the
+//! current leaf.
+//!
+//! Grapheme cluster iterators are a pair (array) with
+//!
+//! 1. A pointer to a (forward) text iterator
+//! 2. The first character of the next cluster (shifted by two for GC's sake), or `NO_CHAR` when
+//!    the iterator is done
 
+use crate::char::{
+    grapheme_break, is_extended_pictographic, GCB_CONTROL, GCB_CR, GCB_EXTEND, GCB_L, GCB_LF,
+    GCB_LV, GCB_LVT, GCB_PREPEND, GCB_REGIONAL_INDICATOR, GCB_SPACINGMARK, GCB_T, GCB_V, GCB_ZWJ,
+};
 use crate::memory::{alloc_array, Memory};
 use crate::rts_trap_with;
-use crate::text::decode_code_point;
-use crate::types::{SkewedPtr, TAG_BLOB, TAG_CONCAT};
+use crate::text::{decode_code_point, text_concat, text_of_ptr_size};
+use crate::types::{Bytes, SkewedPtr, TAG_BLOB, TAG_CONCAT};
 
 use motoko_rts_macros::ic_mem_fn;
 
@@ -45,6 +59,31 @@ unsafe fn find_leaf<M: Memory>(
     text
 }
 
+/// Find the right-most leaf of a text, putting all the others onto a list. Used by reverse
+/// iterators.
+unsafe fn find_last_leaf<M: Memory>(
+    mem: &mut M,
+    mut text: SkewedPtr,
+    todo: *mut SkewedPtr,
+) -> SkewedPtr {
+    while text.tag() == TAG_CONCAT {
+        let concat = text.as_concat();
+
+        // Add left node to TODOs
+        let new_todo = alloc_array(mem, 2);
+        let new_todo_array = new_todo.as_array();
+        new_todo_array.set(TODO_TEXT_IDX, (*concat).text1);
+        new_todo_array.set(TODO_LINK_IDX, *todo);
+        *todo = new_todo;
+
+        // Follow right node
+        text = (*concat).text2;
+    }
+
+    debug_assert_eq!(text.tag(), TAG_BLOB);
+    text
+}
+
 const ITER_BLOB_IDX: u32 = 0;
 const ITER_POS_IDX: u32 = 1;
 const ITER_TODO_IDX: u32 = 2;
@@ -130,3 +169,287 @@ pub unsafe fn text_iter_next<M: Memory>(mem: &mut M, iter: SkewedPtr) -> u32 {
         char
     }
 }
+
+/// Returns a new reverse iterator for the text
+#[ic_mem_fn]
+pub unsafe fn text_iter_rev<M: Memory>(mem: &mut M, text: SkewedPtr) -> SkewedPtr {
+    let iter = alloc_array(mem, 3);
+    let array = iter.as_array();
+
+    // Initialize the TODO field first, see `text_iter`
+    let todo_addr = array.payload_addr().add(ITER_TODO_IDX as usize) as *mut _;
+    *todo_addr = SkewedPtr(0);
+
+    // Initialize position field, to be able to allocate in `find_last_leaf`
+    array.set(ITER_POS_IDX, SkewedPtr(0));
+
+    // Initialize blob field, then start at the end of it
+    let blob = find_last_leaf(mem, text, todo_addr as *mut _);
+    array.set(ITER_BLOB_IDX, blob);
+    array.set(
+        ITER_POS_IDX,
+        SkewedPtr((blob.as_blob().len().0 << 2) as usize),
+    );
+
+    iter
+}
+
+/// Returns whether the reverse iterator is finished
+#[no_mangle]
+pub unsafe extern "C" fn text_iter_rev_done(iter: SkewedPtr) -> u32 {
+    let array = iter.as_array();
+    let pos = array.get(ITER_POS_IDX).0 >> 2;
+    let todo = array.get(ITER_TODO_IDX);
+
+    if pos == 0 && todo == SkewedPtr(0) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Returns previous character in the reverse iterator, moves the iterator backwards
+#[ic_mem_fn]
+pub unsafe fn text_iter_rev_next<M: Memory>(mem: &mut M, iter: SkewedPtr) -> u32 {
+    let iter_array = iter.as_array();
+
+    let pos = (iter_array.get(ITER_POS_IDX).0 >> 2) as u32;
+
+    // If we are at the start of the current blob, find the previous blob
+    if pos == 0 {
+        let todo = iter_array.get(ITER_TODO_IDX);
+
+        if todo == SkewedPtr(0) {
+            // Caller should check with text_iter_rev_done
+            rts_trap_with("text_iter_rev_next: Iter already done");
+        }
+
+        let todo_array = todo.as_array();
+
+        let text = todo_array.get(TODO_TEXT_IDX);
+
+        let blob = if text.tag() == TAG_CONCAT {
+            // Re-use the todo object for the left node, as in `text_iter_next`
+            let concat = text.as_concat();
+            todo_array.set(TODO_TEXT_IDX, (*concat).text1);
+            let todo_addr = iter_array.payload_addr().add(ITER_TODO_IDX as usize);
+            find_last_leaf(mem, (*concat).text2, todo_addr)
+        } else {
+            debug_assert_eq!(text.tag(), TAG_BLOB);
+            iter_array.set(ITER_TODO_IDX, todo_array.get(TODO_LINK_IDX));
+            text
+        };
+
+        iter_array.set(ITER_BLOB_IDX, blob);
+        iter_array.set(
+            ITER_POS_IDX,
+            SkewedPtr((blob.as_blob().len().0 << 2) as usize),
+        );
+        text_iter_rev_next(mem, iter)
+    } else {
+        // Find the start of the previous character by skipping UTF-8 continuation bytes
+        let blob = iter_array.get(ITER_BLOB_IDX).as_blob();
+        let mut start = pos - 1;
+        while blob.get(start) & 0b1100_0000 == 0b1000_0000 {
+            start -= 1;
+        }
+
+        let mut step: u32 = 0;
+        let char = decode_code_point(
+            blob.payload_addr().add(start as usize),
+            &mut step as *mut u32,
+        );
+        iter_array.set(ITER_POS_IDX, SkewedPtr((start << 2) as usize));
+        char
+    }
+}
+
+const GRAPHEME_ITER_CHARS_IDX: u32 = 0;
+const GRAPHEME_ITER_NEXT_IDX: u32 = 1;
+
+/// Marks a finished grapheme cluster iterator. Not a valid code point.
+const NO_CHAR: u32 = 0x11_0000;
+
+/// Returns a new iterator over the extended grapheme clusters of the text
+#[ic_mem_fn]
+pub unsafe fn text_grapheme_iter<M: Memory>(mem: &mut M, text: SkewedPtr) -> SkewedPtr {
+    let iter = alloc_array(mem, 2);
+    let array = iter.as_array();
+
+    // Initialize fields before allocating the character iterator
+    array.set(GRAPHEME_ITER_CHARS_IDX, SkewedPtr(0));
+    array.set(GRAPHEME_ITER_NEXT_IDX, SkewedPtr((NO_CHAR << 2) as usize));
+
+    let chars = text_iter(mem, text);
+    array.set(GRAPHEME_ITER_CHARS_IDX, chars);
+    if text_iter_done(chars) == 0 {
+        let next = text_iter_next(mem, chars);
+        array.set(GRAPHEME_ITER_NEXT_IDX, SkewedPtr((next << 2) as usize));
+    }
+
+    iter
+}
+
+/// Returns whether the grapheme cluster iterator is finished
+#[no_mangle]
+pub unsafe extern "C" fn text_grapheme_iter_done(iter: SkewedPtr) -> u32 {
+    let next = (iter.as_array().get(GRAPHEME_ITER_NEXT_IDX).0 >> 2) as u32;
+    if next == NO_CHAR {
+        1
+    } else {
+        0
+    }
+}
+
+/// State of the grapheme cluster boundary rules that look further back than the previous
+/// character, for the cluster being collected
+struct GraphemeState {
+    /// Whether the characters so far end with `Extended_Pictographic Extend*` (GB11)
+    pictographic: bool,
+    /// Whether the previous character is a ZWJ following `Extended_Pictographic Extend*` (GB11)
+    pictographic_zwj: bool,
+    /// Number of regional indicators the characters so far end with (GB12, GB13)
+    regional_indicators: u32,
+}
+
+impl GraphemeState {
+    fn new() -> Self {
+        GraphemeState {
+            pictographic: false,
+            pictographic_zwj: false,
+            regional_indicators: 0,
+        }
+    }
+
+    /// Updates the state after adding a character to the cluster
+    fn push(&mut self, c: u32, gcb: u8) {
+        self.pictographic_zwj = gcb == GCB_ZWJ && self.pictographic;
+        self.pictographic = is_extended_pictographic(c) || (self.pictographic && gcb == GCB_EXTEND);
+        if gcb == GCB_REGIONAL_INDICATOR {
+            self.regional_indicators += 1;
+        } else {
+            self.regional_indicators = 0;
+        }
+    }
+}
+
+/// Whether there is an extended grapheme cluster boundary between two characters, following the
+/// rules of UAX #29
+fn is_grapheme_boundary(prev: u8, next: u32, next_gcb: u8, state: &GraphemeState) -> bool {
+    match (prev, next_gcb) {
+        // GB3
+        (GCB_CR, GCB_LF) => false,
+        // GB4, GB5
+        (GCB_CONTROL, _) | (GCB_CR, _) | (GCB_LF, _) => true,
+        (_, GCB_CONTROL) | (_, GCB_CR) | (_, GCB_LF) => true,
+        // GB6, GB7, GB8
+        (GCB_L, GCB_L) | (GCB_L, GCB_V) | (GCB_L, GCB_LV) | (GCB_L, GCB_LVT) => false,
+        (GCB_LV, GCB_V) | (GCB_LV, GCB_T) | (GCB_V, GCB_V) | (GCB_V, GCB_T) => false,
+        (GCB_LVT, GCB_T) | (GCB_T, GCB_T) => false,
+        // GB9, GB9a, GB9b
+        (_, GCB_EXTEND) | (_, GCB_ZWJ) | (_, GCB_SPACINGMARK) | (GCB_PREPEND, _) => false,
+        // GB11
+        (GCB_ZWJ, _) if state.pictographic_zwj && is_extended_pictographic(next) => false,
+        // GB12, GB13
+        (GCB_REGIONAL_INDICATOR, GCB_REGIONAL_INDICATOR) => state.regional_indicators % 2 == 0,
+        // GB999
+        _ => true,
+    }
+}
+
+/// Collects the UTF-8 encoded characters of a grapheme cluster into a text value, without
+/// allocating for short clusters
+struct ClusterBuf {
+    buf: [u8; 64],
+    len: usize,
+    text: Option<SkewedPtr>,
+}
+
+impl ClusterBuf {
+    fn new() -> Self {
+        ClusterBuf {
+            buf: [0; 64],
+            len: 0,
+            text: None,
+        }
+    }
+
+    unsafe fn push<M: Memory>(&mut self, mem: &mut M, c: u32) {
+        if self.len + 4 > self.buf.len() {
+            self.flush(mem);
+        }
+        let encoded = core::char::from_u32_unchecked(c).encode_utf8(&mut self.buf[self.len..]);
+        self.len += encoded.len();
+    }
+
+    unsafe fn flush<M: Memory>(&mut self, mem: &mut M) {
+        let blob = text_of_ptr_size(mem, self.buf.as_ptr(), Bytes(self.len as u32));
+        self.text = Some(match self.text {
+            None => blob,
+            Some(text) => text_concat(mem, text, blob),
+        });
+        self.len = 0;
+    }
+
+    unsafe fn finish<M: Memory>(mut self, mem: &mut M) -> SkewedPtr {
+        if self.len != 0 || self.text.is_none() {
+            self.flush(mem);
+        }
+        self.text.unwrap()
+    }
+}
+
+/// Returns the next extended grapheme cluster in the iterator as text, advances the iterator
+#[ic_mem_fn]
+pub unsafe fn text_grapheme_iter_next<M: Memory>(mem: &mut M, iter: SkewedPtr) -> SkewedPtr {
+    let iter_array = iter.as_array();
+
+    let chars = iter_array.get(GRAPHEME_ITER_CHARS_IDX);
+    let first = (iter_array.get(GRAPHEME_ITER_NEXT_IDX).0 >> 2) as u32;
+
+    if first == NO_CHAR {
+        // Caller should check with text_grapheme_iter_done
+        rts_trap_with("text_grapheme_iter_next: Iter already done");
+    }
+
+    let mut cluster = ClusterBuf::new();
+    let mut state = GraphemeState::new();
+
+    let mut prev = first;
+    let mut prev_gcb = grapheme_break(prev);
+    cluster.push(mem, prev);
+    state.push(prev, prev_gcb);
+
+    let next = loop {
+        if text_iter_done(chars) != 0 {
+            break NO_CHAR;
+        }
+
+        let next = text_iter_next(mem, chars);
+
+        // Fast path: ASCII characters other than CR LF are separate clusters
+        if prev < 0x80 && next < 0x80 {
+            if prev == u32::from(b'\r') && next == u32::from(b'\n') {
+                cluster.push(mem, next);
+                prev = next;
+                prev_gcb = GCB_LF;
+                continue;
+            }
+            break next;
+        }
+
+        let next_gcb = grapheme_break(next);
+        if is_grapheme_boundary(prev_gcb, next, next_gcb, &state) {
+            break next;
+        }
+
+        cluster.push(mem, next);
+        state.push(next, next_gcb);
+        prev = next;
+        prev_gcb = next_gcb;
+    };
+
+    iter_array.set(GRAPHEME_ITER_NEXT_IDX, SkewedPtr((next << 2) as usize));
+
+    cluster.finish(mem)
+}
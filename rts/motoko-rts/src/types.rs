@@ -157,6 +157,11 @@ impl SkewedPtr {
         self.unskew() as *mut Blob
     }
 
+    pub unsafe fn as_text_builder(self) -> *mut TextBuilder {
+        debug_assert_eq!(self.tag(), TAG_TEXT_BUILDER);
+        self.unskew() as *mut TextBuilder
+    }
+
     pub unsafe fn as_bigint(self) -> *mut BigInt {
         debug_assert_eq!(self.tag(), TAG_BIGINT);
         self.unskew() as *mut BigInt
@@ -186,6 +191,7 @@ pub const TAG_BITS32: Tag = 12;
 pub const TAG_BIGINT: Tag = 13;
 pub const TAG_CONCAT: Tag = 14;
 pub const TAG_NULL: Tag = 15;
+pub const TAG_TEXT_BUILDER: Tag = 16;
 
 // Common parts of any object. Other object pointers can be coerced into a pointer to this.
 #[repr(packed)]
@@ -394,6 +400,14 @@ impl Concat {
     }
 }
 
+/// A mutable buffer for building texts, see `text_builder.rs`
+#[repr(packed)]
+pub struct TextBuilder {
+    pub header: Obj,
+    pub n_bytes: Bytes<u32>, // Number of bytes written to the buffer
+    pub buf: SkewedPtr,      // Blob with the capacity of the buffer as length, or 0
+}
+
 #[repr(packed)]
 pub struct Null {
     pub header: Obj,
@@ -412,7 +426,7 @@ pub struct Bits32 {
 }
 
 /// Returns object size in words
-pub(crate) unsafe fn object_size(obj: usize) -> Words<u32> {
+pub unsafe fn object_size(obj: usize) -> Words<u32> {
     let obj = obj as *mut Obj;
     match obj.tag() {
         TAG_OBJECT => {
@@ -463,6 +477,8 @@ pub(crate) unsafe fn object_size(obj: usize) -> Words<u32> {
 
         TAG_NULL => size_of::<Null>(),
 
+        TAG_TEXT_BUILDER => size_of::<TextBuilder>(),
+
         0 => {
             // This can happens when we shrink a blob in principal id functions. The slop between
             // new size and old size is filled with zeros.
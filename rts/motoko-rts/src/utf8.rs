@@ -1,8 +1,11 @@
+/// Message of the error for strings that are not UTF-8
+pub(crate) const INVALID_UTF8: &str = "utf8_validate: string is not UTF-8";
+
 /// Panics if the string is not valid UTF-8
 #[no_mangle]
 pub(crate) unsafe extern "C" fn utf8_validate(str: *const libc::c_char, len: u32) {
     if !utf8_valid(str, len) {
-        crate::rts_trap_with("utf8_validate: string is not UTF-8");
+        crate::rts_trap_with(INVALID_UTF8);
     }
 }
 
@@ -81,6 +81,14 @@ pub unsafe fn visit_pointer_fields<F>(
             }
         }
 
+        TAG_TEXT_BUILDER => {
+            let builder = obj as *mut TextBuilder;
+            let field_addr = &mut (*builder).buf;
+            if pointer_to_dynamic_heap(field_addr, heap_base) {
+                visit_ptr_field(field_addr);
+            }
+        }
+
         TAG_OBJ_IND => {
             let obj_ind = obj as *mut ObjInd;
             let field_addr = &mut (*obj_ind).field;
@@ -787,6 +787,7 @@ module RTS = struct
     E.add_func_import env "rts" "skip_any" [I32Type; I32Type; I32Type; I32Type] [];
     E.add_func_import env "rts" "find_field" [I32Type; I32Type; I32Type; I32Type; I32Type] [I32Type];
     E.add_func_import env "rts" "skip_fields" [I32Type; I32Type; I32Type; I32Type] [];
+    E.add_func_import env "rts" "idl_set_limits" [I32Type; I32Type; I32Type; I32Type] [];
     E.add_func_import env "rts" "remember_closure" [I32Type] [I32Type];
     E.add_func_import env "rts" "recall_closure" [I32Type] [I32Type];
     E.add_func_import env "rts" "closure_count" [] [I32Type];
@@ -802,22 +803,34 @@ module RTS = struct
     E.add_func_import env "rts" "text_singleton" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_size" [I32Type] [I32Type];
     E.add_func_import env "rts" "text_to_buf" [I32Type; I32Type] [];
+    E.add_func_import env "rts" "text_builder_new" [] [I32Type];
+    E.add_func_import env "rts" "text_builder_size" [I32Type] [I32Type];
+    E.add_func_import env "rts" "text_builder_append_text" [I32Type; I32Type] [];
+    E.add_func_import env "rts" "text_builder_append_char" [I32Type; I32Type] [];
+    E.add_func_import env "rts" "text_builder_append_utf8" [I32Type; I32Type] [];
+    E.add_func_import env "rts" "text_builder_freeze" [I32Type] [I32Type];
     E.add_func_import env "rts" "blob_of_principal" [I32Type] [I32Type];
     E.add_func_import env "rts" "principal_of_blob" [I32Type] [I32Type];
     E.add_func_import env "rts" "compute_crc32" [I32Type] [I32Type];
     E.add_func_import env "rts" "blob_iter_done" [I32Type] [I32Type];
     E.add_func_import env "rts" "blob_iter" [I32Type] [I32Type];
     E.add_func_import env "rts" "blob_iter_next" [I32Type] [I32Type];
-    E.add_func_import env "rts" "pow" [F64Type; F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "sin" [F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "cos" [F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "tan" [F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "asin" [F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "acos" [F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "atan" [F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "atan2" [F64Type; F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "exp" [F64Type] [F64Type]; (* musl *)
-    E.add_func_import env "rts" "log" [F64Type] [F64Type]; (* musl *)
+    E.add_func_import env "rts" "pow" [F64Type; F64Type] [F64Type];
+    E.add_func_import env "rts" "sin" [F64Type] [F64Type];
+    E.add_func_import env "rts" "cos" [F64Type] [F64Type];
+    E.add_func_import env "rts" "tan" [F64Type] [F64Type];
+    E.add_func_import env "rts" "asin" [F64Type] [F64Type];
+    E.add_func_import env "rts" "acos" [F64Type] [F64Type];
+    E.add_func_import env "rts" "atan" [F64Type] [F64Type];
+    E.add_func_import env "rts" "atan2" [F64Type; F64Type] [F64Type];
+    E.add_func_import env "rts" "sinh" [F64Type] [F64Type];
+    E.add_func_import env "rts" "cosh" [F64Type] [F64Type];
+    E.add_func_import env "rts" "tanh" [F64Type] [F64Type];
+    E.add_func_import env "rts" "hypot" [F64Type; F64Type] [F64Type];
+    E.add_func_import env "rts" "exp" [F64Type] [F64Type];
+    E.add_func_import env "rts" "log" [F64Type] [F64Type];
+    E.add_func_import env "rts" "log2" [F64Type] [F64Type];
+    E.add_func_import env "rts" "log10" [F64Type] [F64Type];
     E.add_func_import env "rts" "fmod" [F64Type; F64Type] [F64Type]; (* remainder, musl *)
     E.add_func_import env "rts" "float_fmt" [F64Type; I32Type; I32Type] [I32Type];
     E.add_func_import env "rts" "char_to_upper" [I32Type] [I32Type];
@@ -1152,6 +1165,7 @@ module Tagged = struct
     | BigInt
     | Concat (* String concatenation, used by rts/text.c *)
     | Null (* For opt. Static singleton! *)
+    | TextBuilder (* Mutable text buffer, used by motoko-rts/src/text_builder.rs *)
     | StableSeen (* Marker that we have seen this thing before *)
     | CoercionFailure (* Used in the Candid decoder. Static singleton! *)
 
@@ -1171,6 +1185,7 @@ module Tagged = struct
     | BigInt -> 13l
     | Concat -> 14l
     | Null -> 15l
+    | TextBuilder -> 16l
     | CoercionFailure -> 0xfffffffel
     | StableSeen -> 0xffffffffl
 
@@ -2069,13 +2084,14 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
 
      Generally all operations begin with checking whether both arguments are
      already tagged scalars. If so, the arithmetic can be performed in machine
-     registers (fast path). Otherwise one or both arguments need boxing and the
-     arithmetic needs to be carried out on the underlying boxed bignum
-     representation (slow path).
+     registers (fast path). Otherwise the arithmetic is carried out by the
+     underlying bignum representation (slow path).
 
-     The result appears as a boxed number in the latter case, so a check is
-     performed if it can be a tagged scalar. Conversely in the former case the
-     64-bit result can either be a tagged scalar or needs to be boxed.
+     The underlying representation accepts tagged scalars as arguments and
+     returns results as tagged scalars when they fit (see
+     motoko-rts/src/bigint.rs), so neither the arguments nor the results of
+     the slow path need conversion. In the fast path the 64-bit result can
+     either be a tagged scalar or needs to be boxed.
 
      Manipulation of the result is unnecessary for the comparison predicates.
 
@@ -2090,9 +2106,6 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
 
   (* TODO: Does the result of the rem/mod fast path ever needs boxing? *)
 
-  (* examine the skewed pointer and determine if number fits into 31 bits *)
-  let fits_in_vanilla env = Num.fits_signed_bits env 31
-
   (* Tagged scalar to right-0-padded signed i64 *)
   let extend64 = G.i (Convert (Wasm.Values.I64 I64Op.ExtendSI32))
 
@@ -2106,19 +2119,14 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
   (* creates a boxed bignum from a right-0-padded signed i64 *)
   let box64 env = compile_shrS64_const 1L ^^ Num.from_signed_word64 env
 
-  (* creates a boxed bignum from an right-0-padded signed i32 *)
-  let extend_and_box64 env = extend64 ^^ box64 env
-
   (* check if both arguments are tagged scalars,
      if so, promote to right-0-padded, signed i64 and perform the fast path.
-     Otherwise make sure that both arguments are in heap representation,
-     and run the slow path on them.
-     In both cases bring the results into normal form.
+     Otherwise run the slow path on them.
+     In both cases the results are in normal form.
    *)
   let try_unbox2 name fast slow env =
     Func.share_code2 env name (("a", I32Type), ("b", I32Type)) [I32Type]
       (fun env get_a get_b ->
-        let set_res, get_res = new_local env "res" in
         let set_res64, get_res64 = new_local64 env "res64" in
         get_a ^^ get_b ^^
         BitTagged.if_both_tagged_scalar env [I32Type]
@@ -2131,19 +2139,7 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
               (get_res64 ^^ tag_padded)
               (get_res64 ^^ box64 env)
           end
-          begin
-            get_a ^^ BitTagged.if_tagged_scalar env [I32Type]
-              (get_a ^^ extend_and_box64 env)
-              get_a ^^
-            get_b ^^ BitTagged.if_tagged_scalar env [I32Type]
-              (get_b ^^ extend_and_box64 env)
-              get_b ^^
-            slow env ^^ set_res ^^ get_res ^^
-            fits_in_vanilla env ^^
-            G.if_ [I32Type]
-              (get_res ^^ Num.truncate_to_word32 env ^^ BitTagged.tag_i32)
-              get_res
-          end)
+          (get_a ^^ get_b ^^ slow env))
 
   let compile_add = try_unbox2 "B_add" Word64.compile_add Num.compile_add
 
@@ -2161,7 +2157,6 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
   let compile_unsigned_pow env =
     Func.share_code2 env "B_pow" (("a", I32Type), ("b", I32Type)) [I32Type]
     (fun env get_a get_b ->
-    let set_res, get_res = new_local env "res" in
     let set_res64, get_res64 = new_local64 env "res64" in
     get_a ^^ get_b ^^
     BitTagged.if_both_tagged_scalar env [I32Type]
@@ -2188,26 +2183,10 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
           begin
             get_a64 ^^ Num.from_signed_word64 env ^^
             get_b64 ^^ Num.from_signed_word64 env ^^
-            Num.compile_unsigned_pow env ^^ set_res ^^
-            get_res ^^ fits_in_vanilla env ^^
-            G.if_ [I32Type]
-              (get_res ^^ Num.truncate_to_word32 env ^^ BitTagged.tag_i32)
-              get_res
+            Num.compile_unsigned_pow env
           end
       end
-      begin
-        get_a ^^ BitTagged.if_tagged_scalar env [I32Type]
-          (get_a ^^ extend_and_box64 env)
-          get_a ^^
-        get_b ^^ BitTagged.if_tagged_scalar env [I32Type]
-          (get_b ^^ extend_and_box64 env)
-          get_b ^^
-        Num.compile_unsigned_pow env ^^ set_res ^^
-        get_res ^^ fits_in_vanilla env ^^
-        G.if_ [I32Type]
-          (get_res ^^ Num.truncate_to_word32 env ^^ BitTagged.tag_i32)
-          get_res
-      end)
+      (get_a ^^ get_b ^^ Num.compile_unsigned_pow env))
 
   let compile_is_negative env =
     let set_n, get_n = new_local env "n" in
@@ -2247,15 +2226,7 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
             get_b ^^ extend64 ^^
             fast env
           end
-          begin
-            get_a ^^ BitTagged.if_tagged_scalar env [I32Type]
-              (get_a ^^ extend_and_box64 env)
-              get_a ^^
-            get_b ^^ BitTagged.if_tagged_scalar env [I32Type]
-              (get_b ^^ extend_and_box64 env)
-              get_b ^^
-            slow env
-          end)
+          (get_a ^^ get_b ^^ slow env))
 
   let compile_eq = try_comp_unbox2 "B_eq" Word64.compile_eq Num.compile_eq
   let compile_relop env bigintop =
@@ -2325,13 +2296,7 @@ module MakeCompact (Num : BigNumType) : BigNumType = struct
       env
 
   let compile_load_from_data_buf env signed =
-    let set_res, get_res = new_local env "res" in
-    Num.compile_load_from_data_buf env signed ^^
-    set_res ^^
-    get_res ^^ fits_in_vanilla env ^^
-    G.if_ [I32Type]
-      (get_res ^^ Num.truncate_to_word32 env ^^ BitTagged.tag_i32)
-      get_res
+    Num.compile_load_from_data_buf env signed
 
   let compile_store_to_data_buf_unsigned env =
     let set_x, get_x = new_local env "x" in
@@ -3684,15 +3649,6 @@ end (* IC *)
 
 module RTS_Exports = struct
   let system_exports env =
-    let bigint_trap_fi = E.add_fun env "bigint_trap" (
-      Func.of_body env [] [] (fun env ->
-        E.trap_with env "bigint function error"
-      )
-    ) in
-    E.add_export env (nr {
-      name = Wasm.Utf8.decode "bigint_trap";
-      edesc = nr (FuncExport (nr bigint_trap_fi))
-    });
     let rts_trap_fi = E.add_fun env "rts_trap" (
       Func.of_body env ["str", I32Type; "len", I32Type] [] (fun env ->
         let get_str = G.i (LocalGet (nr 0l)) in
@@ -6666,7 +6622,7 @@ let compile_binop env t op =
       env "pow" BigNum.compile_unsigned_pow
       (powInt64_shortcut (Word64.compile_unsigned_pow env))
   | Type.(Prim Nat),                          PowOp -> BigNum.compile_unsigned_pow env
-  | Type.(Prim Float),                        PowOp -> E.call_import env "rts" "pow" (* musl *)
+  | Type.(Prim Float),                        PowOp -> E.call_import env "rts" "pow"
   | Type.(Prim (Nat64|Int64)),                AndOp -> G.i (Binary (Wasm.Values.I64 I64Op.And))
   | Type.(Prim (Nat8|Nat16|Nat32|Int8|Int16|Int32)),
                                               AndOp -> G.i (Binary (Wasm.Values.I32 I32Op.And))
@@ -7071,6 +7027,34 @@ and compile_exp (env : E.t) ae exp =
     | OtherPrim "text_iter_next", [e] ->
       SR.Vanilla, compile_exp_vanilla env ae e ^^ Text.iter_next env
 
+    | OtherPrim "text_builder_new", [] ->
+      SR.Vanilla, E.call_import env "rts" "text_builder_new"
+    | OtherPrim "text_builder_size", [e] ->
+      SR.Vanilla,
+      compile_exp_vanilla env ae e ^^
+      E.call_import env "rts" "text_builder_size" ^^
+      BigNum.from_word32 env
+    | OtherPrim "text_builder_append_text", [e1; e2] ->
+      SR.unit,
+      compile_exp_vanilla env ae e1 ^^
+      compile_exp_vanilla env ae e2 ^^
+      E.call_import env "rts" "text_builder_append_text"
+    | OtherPrim "text_builder_append_char", [e1; e2] ->
+      SR.unit,
+      compile_exp_vanilla env ae e1 ^^
+      compile_exp_vanilla env ae e2 ^^
+      TaggedSmallWord.untag_codepoint ^^
+      E.call_import env "rts" "text_builder_append_char"
+    | OtherPrim "text_builder_append_utf8", [e1; e2] ->
+      SR.unit,
+      compile_exp_vanilla env ae e1 ^^
+      compile_exp_vanilla env ae e2 ^^
+      E.call_import env "rts" "text_builder_append_utf8"
+    | OtherPrim "text_builder_freeze", [e] ->
+      SR.Vanilla,
+      compile_exp_vanilla env ae e ^^
+      E.call_import env "rts" "text_builder_freeze"
+
     | OtherPrim "blob_size", [e] ->
       SR.Vanilla, compile_exp_vanilla env ae e ^^ Blob.len env ^^ BigNum.from_word32 env
     | OtherPrim "blob_vals_iter", [e] ->
@@ -7150,48 +7134,79 @@ and compile_exp (env : E.t) ae exp =
     | OtherPrim "fsin", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "sin" (* musl *)
+      E.call_import env "rts" "sin"
 
     | OtherPrim "fcos", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "cos" (* musl *)
+      E.call_import env "rts" "cos"
 
     | OtherPrim "ftan", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "tan" (* musl *)
+      E.call_import env "rts" "tan"
 
     | OtherPrim "fasin", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "asin" (* musl *)
+      E.call_import env "rts" "asin"
 
     | OtherPrim "facos", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "acos" (* musl *)
+      E.call_import env "rts" "acos"
 
     | OtherPrim "fatan", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "atan" (* musl *)
+      E.call_import env "rts" "atan"
 
     | OtherPrim "fatan2", [y; x] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 y ^^
       compile_exp_as env ae SR.UnboxedFloat64 x ^^
-      E.call_import env "rts" "atan2" (* musl *)
+      E.call_import env "rts" "atan2"
+
+    | OtherPrim "fsinh", [e] ->
+      SR.UnboxedFloat64,
+      compile_exp_as env ae SR.UnboxedFloat64 e ^^
+      E.call_import env "rts" "sinh"
+
+    | OtherPrim "fcosh", [e] ->
+      SR.UnboxedFloat64,
+      compile_exp_as env ae SR.UnboxedFloat64 e ^^
+      E.call_import env "rts" "cosh"
+
+    | OtherPrim "ftanh", [e] ->
+      SR.UnboxedFloat64,
+      compile_exp_as env ae SR.UnboxedFloat64 e ^^
+      E.call_import env "rts" "tanh"
+
+    | OtherPrim "fhypot", [x; y] ->
+      SR.UnboxedFloat64,
+      compile_exp_as env ae SR.UnboxedFloat64 x ^^
+      compile_exp_as env ae SR.UnboxedFloat64 y ^^
+      E.call_import env "rts" "hypot"
 
     | OtherPrim "fexp", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "exp" (* musl *)
+      E.call_import env "rts" "exp"
 
     | OtherPrim "flog", [e] ->
       SR.UnboxedFloat64,
       compile_exp_as env ae SR.UnboxedFloat64 e ^^
-      E.call_import env "rts" "log" (* musl *)
+      E.call_import env "rts" "log"
+
+    | OtherPrim "flog2", [e] ->
+      SR.UnboxedFloat64,
+      compile_exp_as env ae SR.UnboxedFloat64 e ^^
+      E.call_import env "rts" "log2"
+
+    | OtherPrim "flog10", [e] ->
+      SR.UnboxedFloat64,
+      compile_exp_as env ae SR.UnboxedFloat64 e ^^
+      E.call_import env "rts" "log10"
 
     (* Other prims, nullary *)
 
@@ -7231,6 +7246,14 @@ and compile_exp (env : E.t) ae exp =
       SR.Vanilla,
       ClosureTable.size env ^^ Prim.prim_word32toNat env
 
+    | OtherPrim "idl_set_limits", [e1; e2; e3; e4] ->
+      SR.unit,
+      compile_exp_as env ae SR.UnboxedWord32 e1 ^^
+      compile_exp_as env ae SR.UnboxedWord32 e2 ^^
+      compile_exp_as env ae SR.UnboxedWord32 e3 ^^
+      compile_exp_as env ae SR.UnboxedWord32 e4 ^^
+      E.call_import env "rts" "idl_set_limits"
+
     | OtherPrim "crc32Hash", [e] ->
       SR.UnboxedWord32,
       compile_exp_vanilla env ae e ^^
@@ -75,6 +75,43 @@ let prim =
   let via_float f v = Float.(Float (of_float (f (to_float (as_float v))))) in
   let via_float2 f v w = Float.(Float (of_float (f (to_float (as_float v)) (to_float (as_float w))))) in
   let unpack_nat8 v = Nat8.to_int (as_nat8 v) in
+  let shortest_float_text f =
+    (* The shortest digits that convert back to f, closest to f, laid out like the RTS does *)
+    let open Printf in
+    if not (Stdlib.Float.is_finite f) then sprintf "%f" f else
+    if f = 0.0 then (if Stdlib.Float.sign_bit f then "-0" else "0") else
+    let a = Stdlib.Float.abs f in
+    let converts_back (d, x) =
+      float_of_string (sprintf "%se%d" d (x - String.length d + 1)) = a in
+    (* The other neighbour with the same number of digits, for powers of two *)
+    let round_up (d, x) =
+      let d' = string_of_int (int_of_string d + 1) in
+      if String.length d' > String.length d
+      then (String.sub d' 0 (String.length d), x + 1)
+      else (d', x) in
+    let rec go p =
+      let s = sprintf "%.*e" (p - 1) a in
+      let e = String.index s 'e' in
+      let d = String.concat "" (String.split_on_char '.' (String.sub s 0 e)) in
+      let x = int_of_string (String.sub s (e + 1) (String.length s - e - 1)) in
+      if p >= 17 || converts_back (d, x) then (d, x)
+      else if converts_back (round_up (d, x)) then round_up (d, x)
+      else go (p + 1) in
+    let d, x = go 1 in
+    let rec strip d =
+      let n = String.length d in
+      if n > 1 && d.[n - 1] = '0' then strip (String.sub d 0 (n - 1)) else d in
+    let d = strip d in
+    let n = String.length d in
+    let sign = if f < 0.0 then "-" else "" in
+    if x >= 0 && x < 17 then
+      if n <= x + 1 then sign ^ d ^ String.make (x + 1 - n) '0'
+      else sign ^ String.sub d 0 (x + 1) ^ "." ^ String.sub d (x + 1) (n - x - 1)
+    else if x < 0 && x >= -4 then
+      sign ^ "0." ^ String.make (- x - 1) '0' ^ d
+    else
+      let m = if n = 1 then d else String.sub d 0 1 ^ "." ^ String.sub d 1 (n - 1) in
+      sprintf "%s%se%c%02d" sign m (if x < 0 then '-' else '+') (abs x) in
   let float_formatter prec : int -> float -> string =
     let open Printf in
     function
@@ -82,6 +119,7 @@ let prim =
     | 1 -> sprintf "%.*e" prec
     | 2 -> sprintf "%.*g" prec
     | 3 -> sprintf "%.*h" prec
+    | 4 -> shortest_float_text
     | _ -> fun _ -> raise (Invalid_argument "float_formatter: unrecognised mode") in
   function
   | "abs" -> fun _ v k -> k (Int (Nat.abs (as_int v)))
@@ -119,8 +157,22 @@ let prim =
     (match Value.as_tup v with
      | [y; x] -> k (via_float2 Stdlib.atan2 y x)
      | _ -> assert false)
+  | "fsinh" -> fun _ v k -> k (via_float Stdlib.sinh v)
+  | "fcosh" -> fun _ v k -> k (via_float Stdlib.cosh v)
+  | "ftanh" -> fun _ v k -> k (via_float Stdlib.tanh v)
+  | "fhypot" -> fun _ v k ->
+    (match Value.as_tup v with
+     | [x; y] -> k (via_float2 Stdlib.hypot x y)
+     | _ -> assert false)
   | "fexp" -> fun _ v k -> k (via_float Stdlib.exp v)
   | "flog" -> fun _ v k -> k (via_float Stdlib.log v)
+  | "flog2" -> fun _ v k ->
+    (* exact for powers of two, like the RTS *)
+    let log2 x = match Stdlib.frexp x with
+      | (0.5, e) -> Stdlib.float_of_int (e - 1)
+      | _ -> Stdlib.log x /. Stdlib.log 2.0 in
+    k (via_float log2 v)
+  | "flog10" -> fun _ v k -> k (via_float Stdlib.log10 v)
 
   | "popcnt8" | "popcnt16" | "popcnt32" | "popcnt64" ->
      fun _ v k ->
@@ -184,6 +236,7 @@ let prim =
   | "rts_heap_size" -> fun _ v k -> as_unit v; k (Int (Int.of_int 0))
   | "rts_total_allocation" -> fun _ v k -> as_unit v; k (Int (Int.of_int 0))
   | "rts_outstanding_callbacks" -> fun _ v k -> as_unit v; k (Int (Int.of_int 0))
+  | "idl_set_limits" -> fun _ v k -> k unit (* the interpreter doesn't decode Candid *)
   | "time" -> fun _ v k -> as_unit v; k (Value.Nat64 (Numerics.Nat64.of_int 42))
   | "idlHash" -> fun _ v k ->
     let s = as_text v in
@@ -214,6 +267,30 @@ let prim =
     let s = Wasm.Utf8.decode (Value.as_text v) in
     let i = Seq.map (fun c -> Char c) (List.to_seq s) in
     k (Iter (ref i))
+  | "text_builder_new" -> fun _ v k ->
+    as_unit v; k (Mut (ref (Text "")))
+  | "text_builder_size" -> fun _ v k ->
+    k (Int (Nat.of_int (String.length (as_text !(as_mut v)))))
+  | "text_builder_append_text" -> fun _ v k ->
+    let b, t = as_pair v in
+    let r = as_mut b in
+    r := Text (as_text !r ^ as_text t); k unit
+  | "text_builder_append_char" -> fun _ v k ->
+    let b, c = as_pair v in
+    let r = as_mut b in
+    r := Text (as_text !r ^ Wasm.Utf8.encode [as_char c]); k unit
+  | "text_builder_append_utf8" -> fun _ v k ->
+    let b, u = as_pair v in
+    let s = as_blob u in
+    begin match Wasm.Utf8.decode s with
+    | _ -> let r = as_mut b in r := Text (as_text !r ^ s); k unit
+    | exception Wasm.Utf8.Utf8 ->
+      raise (Invalid_argument "utf8_validate: string is not UTF-8")
+    end
+  | "text_builder_freeze" -> fun _ v k ->
+    let r = as_mut v in
+    let t = !r in
+    r := Text ""; k t
   | "Array.init" -> fun _ v k ->
     (match Value.as_tup v with
     | [len; x] ->